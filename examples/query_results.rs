@@ -0,0 +1,63 @@
+// Copyright 2025 N. Dornseif
+//
+// Dual-licensed under Apache 2.0 and MIT terms.
+
+//! Run the test suite on two seeds of RANDU and analyze the results in memory.
+//! Like the binary, the suite prints its progress and writes a result file to the working directory.
+//!
+//! cargo run --release --example query_results
+
+use pearlacid::{
+    report::ResultRow,
+    rng_testing::{test_suite_with_seeds, ExpectedFailures},
+    rngs::{lcg::Randu, RNG},
+};
+
+fn print_rows(rows: &[ResultRow]) {
+    for row in rows {
+        let seed = row
+            .seed
+            .map_or("generator".to_string(), |seed| format!("{:#018x}", seed));
+        println!(
+            "  {:<10} {:<20} p: {:<12} {}",
+            row.record.name,
+            seed,
+            row.record
+                .p
+                .map_or("-".to_string(), |p| format!("{:.6}", p)),
+            row.record.verdict.as_str()
+        );
+    }
+}
+
+fn main() {
+    let seeds = [1, 2];
+    let mut buffer = vec![];
+    let report = test_suite_with_seeds(
+        &mut Randu::new(0),
+        &mut buffer,
+        1 << 22,
+        &seeds,
+        "RANDU",
+        false,
+        &ExpectedFailures::Nothing,
+    );
+
+    println!("\nFive worst results:");
+    print_rows(&report.worst_n(5));
+
+    println!("\nMonobit test on every seed:");
+    print_rows(&report.test_rows("Mono"));
+
+    let failed = report.failed();
+    println!(
+        "\n{} of {} results failed.",
+        failed.len(),
+        report.rows().count()
+    );
+    for seed in seeds {
+        let failed_for_seed = failed.iter().filter(|row| row.seed == Some(seed)).count();
+        let tested = report.seed_results(seed).map_or(0, <[_]>::len);
+        println!("  Seed {}: {} of {} failed", seed, failed_for_seed, tested);
+    }
+}
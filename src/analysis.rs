@@ -0,0 +1,1089 @@
+// Copyright 2025 N. Dornseif
+//
+// Dual-licensed under Apache 2.0 and MIT terms.
+
+//! Analyses that describe an RNGs output in more detail than a single p-value.
+
+use std::collections::VecDeque;
+
+use crate::{conditioning, rngs::RNG};
+
+/// Min-entropy estimate for a single output bit position.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct BitPositionEntropy {
+    /// Fraction of outputs with the bit set.
+    pub ones_ratio: f64,
+    /// Success rate of predicting the bit from its value in the previous output.
+    pub lag1_prediction_rate: f64,
+    /// -log2 of the best prediction rate (bias or lag-1).
+    pub min_entropy: f64,
+}
+
+impl BitPositionEntropy {
+    /// Success rate of the best predictor for this bit.
+    pub fn prediction_rate(&self) -> f64 {
+        self.ones_ratio
+            .max(1.0 - self.ones_ratio)
+            .max(self.lag1_prediction_rate)
+    }
+}
+
+/// Estimate the min-entropy of each of the 64 output bit positions independently.
+/// Considers the bias of the bit and how well it is predicted by the
+/// same bit in the previous output.
+/// Index 0 is the LSB.
+pub fn bit_position_min_entropy(test_data: &[u64]) -> [BitPositionEntropy; 64] {
+    let mut entropies = [BitPositionEntropy::default(); 64];
+    if test_data.len() < 2 {
+        return entropies;
+    }
+    let mut ones = [0usize; 64];
+    // Transition counts from the previous to the current output.
+    let mut zero_to_one = [0usize; 64];
+    let mut one_to_zero = [0usize; 64];
+    let mut one_to_one = [0usize; 64];
+    let mut previous: u64 = test_data[0];
+    ones.iter_mut()
+        .enumerate()
+        .for_each(|(bit, count)| *count += ((previous >> bit) & 1) as usize);
+    for &sample in &test_data[1..] {
+        for bit in 0..64 {
+            let current = (sample >> bit) & 1;
+            ones[bit] += current as usize;
+            match ((previous >> bit) & 1, current) {
+                (0, 1) => zero_to_one[bit] += 1,
+                (1, 0) => one_to_zero[bit] += 1,
+                (1, 1) => one_to_one[bit] += 1,
+                _ => {}
+            }
+        }
+        previous = sample;
+    }
+    let transitions = test_data.len() - 1;
+    for (bit, entropy) in entropies.iter_mut().enumerate() {
+        let zero_to_zero = transitions - zero_to_one[bit] - one_to_zero[bit] - one_to_one[bit];
+        // Best guess for each previous value of the bit.
+        let correct_guesses =
+            zero_to_zero.max(zero_to_one[bit]) + one_to_one[bit].max(one_to_zero[bit]);
+        entropy.ones_ratio = ones[bit] as f64 / test_data.len() as f64;
+        entropy.lag1_prediction_rate = correct_guesses as f64 / transitions as f64;
+        entropy.min_entropy = 0.0 - entropy.prediction_rate().log2();
+    }
+    entropies
+}
+
+/// Prediction rate above which a bit is considered weak.
+/// Four standard deviations above the ideal 0.5 for the given sample count.
+pub fn weak_bit_threshold(sample_count: usize) -> f64 {
+    0.5 + 2.0 / (sample_count as f64).sqrt()
+}
+
+/// Render the per bit min-entropy estimates as a table of 8 rows with 8 bits each.
+/// Weak bits are marked with '!'.
+pub fn format_bit_entropy_table(
+    entropies: &[BitPositionEntropy; 64],
+    sample_count: usize,
+) -> String {
+    let threshold = weak_bit_threshold(sample_count);
+    let mut rows: Vec<String> =
+        vec!["Min-entropy per bit position (bit 0 is LSB, '!' marks weak bits):".to_string()];
+    for (row, chunk) in entropies.chunks_exact(8).enumerate() {
+        let cells: Vec<String> = chunk
+            .iter()
+            .map(|entropy| {
+                format!(
+                    "{:.4}{}",
+                    entropy.min_entropy,
+                    if entropy.prediction_rate() > threshold {
+                        "!"
+                    } else {
+                        " "
+                    }
+                )
+            })
+            .collect();
+        rows.push(format!(
+            "Bits {:>2}-{:>2}: {}",
+            row * 8,
+            row * 8 + 7,
+            cells.join(" ").trim_end()
+        ));
+    }
+    let weak_bits: Vec<usize> = entropies
+        .iter()
+        .enumerate()
+        .filter(|(_, entropy)| entropy.prediction_rate() > threshold)
+        .map(|(bit, _)| bit)
+        .collect();
+    rows.push(format!("Weak bits: {:?}", weak_bits));
+    rows.join("\n")
+}
+
+/// Number of distinct pairs of output bit positions.
+pub const BIT_PAIRS: usize = 64 * 63 / 2;
+/// Family-wise significance level at which correlated pairs of bit positions are flagged.
+pub const CORRELATION_FLAG_LEVEL: f64 = 1e-3;
+/// Number of pixels per matrix entry in the correlation heatmap.
+#[cfg(feature = "images")]
+const HEATMAP_SCALE: usize = 8;
+
+/// Pairwise correlation between the 64 output bit positions.
+#[derive(Debug, Clone)]
+pub struct BitCorrelations {
+    /// Phi coefficient of every pair of bit positions, index 0 is the LSB.
+    /// 0 for pairs with a constant bit, whose correlation is undefined.
+    pub correlations: [[f64; 64]; 64],
+    /// Mask of the bit positions with the same value in every output.
+    pub constant_bits: u64,
+    /// Number of outputs the correlations were measured on.
+    pub sample_count: usize,
+}
+
+impl BitCorrelations {
+    /// Whether the correlation of bit positions `a` and `b` is defined.
+    fn defined(&self, a: usize, b: usize) -> bool {
+        a != b && (self.constant_bits >> a) & 1 == 0 && (self.constant_bits >> b) & 1 == 0
+    }
+
+    /// Number of pairs with a defined correlation.
+    pub fn tested_pairs(&self) -> usize {
+        let variable = 64 - self.constant_bits.count_ones() as usize;
+        variable * variable.saturating_sub(1) / 2
+    }
+
+    /// p value of the correlation of bit positions `a` and `b`, `n * phi^2` is χ² distributed with one degree of freedom.
+    /// Returns None if the correlation is undefined.
+    pub fn pair_p_value(&self, a: usize, b: usize) -> Option<f64> {
+        if !self.defined(a, b) {
+            return None;
+        }
+        Some(statrs::function::erf::erfc(
+            self.correlations[a][b].abs() * (self.sample_count as f64 / 2.0).sqrt(),
+        ))
+    }
+
+    /// Pairs of bit positions correlated at the family-wise level `CORRELATION_FLAG_LEVEL`,
+    /// as (lower bit, higher bit, phi), strongest first.
+    pub fn flagged_pairs(&self) -> Vec<(usize, usize, f64)> {
+        let threshold = CORRELATION_FLAG_LEVEL / self.tested_pairs().max(1) as f64;
+        let mut flagged: Vec<(usize, usize, f64)> = (0..64)
+            .flat_map(|a| (a + 1..64).map(move |b| (a, b)))
+            .filter(|&(a, b)| self.pair_p_value(a, b).is_some_and(|p| p < threshold))
+            .map(|(a, b)| (a, b, self.correlations[a][b]))
+            .collect();
+        flagged.sort_by(|x, y| y.2.abs().total_cmp(&x.2.abs()));
+        flagged
+    }
+
+    /// p value of the most correlated pair, corrected for the number of tested pairs.
+    /// Returns None if fewer than 100 outputs were analysed or no correlation is defined.
+    pub fn p_value(&self) -> Option<f64> {
+        const MIN_SAMPLES: usize = 100;
+        let pairs = self.tested_pairs();
+        if self.sample_count < MIN_SAMPLES || pairs == 0 {
+            return None;
+        }
+        let p_min = (0..64)
+            .flat_map(|a| (a + 1..64).map(move |b| (a, b)))
+            .filter_map(|(a, b)| self.pair_p_value(a, b))
+            .fold(1.0, f64::min);
+        // Probability that the smallest of `pairs` uniform p values is at most p_min.
+        Some((-(pairs as f64 * (-p_min).ln_1p()).exp_m1()).clamp(0.0, 1.0))
+    }
+
+    /// Summarize the correlations, listing up to 8 flagged pairs.
+    pub fn format(&self) -> String {
+        const LISTED_PAIRS: usize = 8;
+        let flagged = self.flagged_pairs();
+        let mut lines = vec![format!(
+            "Bit column correlations: {} of {} pairs flagged{}",
+            flagged.len(),
+            self.tested_pairs(),
+            match self.p_value() {
+                Some(p) => format!(" (p: {:.6})", p),
+                None => String::new(),
+            }
+        )];
+        lines.extend(
+            flagged
+                .iter()
+                .take(LISTED_PAIRS)
+                .map(|(a, b, phi)| format!("Bits {:>2} and {:>2}: phi = {:+.4}", a, b, phi)),
+        );
+        if flagged.len() > LISTED_PAIRS {
+            lines.push(format!("... {} more", flagged.len() - LISTED_PAIRS));
+        }
+        if self.constant_bits != 0 {
+            lines.push(format!("Constant bits: {:#018x}", self.constant_bits));
+        }
+        lines.join("\n")
+    }
+
+    /// Write the correlation matrix as a .ppm heatmap, bit 0 at the top left.
+    /// Positive correlations are red, negative ones blue, at full brightness for pairs
+    /// flagged by `flagged_pairs`. Constant bits are grey.
+    #[cfg(feature = "images")]
+    pub fn write_heatmap(&self, file_path: &str) -> std::io::Result<()> {
+        const GREY: [u8; 3] = [128, 128, 128];
+        let size = 64 * HEATMAP_SCALE;
+        // |phi| at which a pair is flagged.
+        let threshold = CORRELATION_FLAG_LEVEL / self.tested_pairs().max(1) as f64;
+        let flag_phi = statrs::function::erf::erfc_inv(threshold)
+            / (self.sample_count.max(1) as f64 / 2.0).sqrt();
+        let mut image_data: Vec<u8> = Vec::with_capacity(size * size * 3);
+        for y in 0..size {
+            for x in 0..size {
+                let (a, b) = (y / HEATMAP_SCALE, x / HEATMAP_SCALE);
+                let pixel = if a == b {
+                    [255, 255, 255]
+                } else if !self.defined(a, b) {
+                    GREY
+                } else {
+                    let phi = self.correlations[a][b];
+                    let intensity = ((phi.abs() / flag_phi).min(1.0) * 255.0) as u8;
+                    if phi > 0.0 {
+                        [intensity, 0, 0]
+                    } else {
+                        [0, 0, intensity]
+                    }
+                };
+                image_data.extend_from_slice(&pixel);
+            }
+        }
+        crate::utils::create_ppm(file_path, size, size, &image_data)
+    }
+}
+
+/// Measure the phi coefficient between every pair of the 64 output bit positions.
+/// Every bit position is packed into its own bitstream first,
+/// so each pair is counted with one AND and popcount per 64 outputs.
+pub fn bit_column_correlations(test_data: &[u64]) -> BitCorrelations {
+    let columns: Vec<Vec<u64>> = (0..64)
+        .map(|bit| {
+            test_data
+                .chunks(64)
+                .map(|chunk| {
+                    chunk
+                        .iter()
+                        .enumerate()
+                        .fold(0u64, |word, (index, &sample)| {
+                            word | (((sample >> bit) & 1) << index)
+                        })
+                })
+                .collect()
+        })
+        .collect();
+    let ones: Vec<u64> = columns
+        .iter()
+        .map(|column| column.iter().map(|word| word.count_ones() as u64).sum())
+        .collect();
+    let n = test_data.len() as u64;
+    let constant_bits = ones
+        .iter()
+        .enumerate()
+        .filter(|(_, &count)| count == 0 || count == n)
+        .fold(0u64, |mask, (bit, _)| mask | (1 << bit));
+    let mut correlations = [[0.0; 64]; 64];
+    for a in 0..64 {
+        for b in a + 1..64 {
+            if (constant_bits >> a) & 1 == 1 || (constant_bits >> b) & 1 == 1 {
+                continue;
+            }
+            let both: u64 = columns[a]
+                .iter()
+                .zip(&columns[b])
+                .map(|(x, y)| (x & y).count_ones() as u64)
+                .sum();
+            let (n, both, ones_a, ones_b) = (n as f64, both as f64, ones[a] as f64, ones[b] as f64);
+            let phi = (n * both - ones_a * ones_b)
+                / (ones_a * (n - ones_a) * ones_b * (n - ones_b)).sqrt();
+            correlations[a][b] = phi;
+            correlations[b][a] = phi;
+        }
+    }
+    BitCorrelations {
+        correlations,
+        constant_bits,
+        sample_count: test_data.len(),
+    }
+}
+
+/// Number of previous outputs the linear predictor sees.
+/// Linear generators with up to 512 bits of state are predicted perfectly.
+pub const LINEAR_PREDICTOR_OUTPUTS: usize = 8;
+/// Number of input bits of the linear predictor.
+const LINEAR_INPUT_BITS: usize = 64 * LINEAR_PREDICTOR_OUTPUTS;
+/// Number of windows the predictor is fitted on, enough for a full rank with overwhelming probability.
+const LINEAR_TRAINING_WINDOWS: usize = LINEAR_INPUT_BITS + 128;
+/// Smallest number of held-out windows that are predicted.
+const LINEAR_MIN_HELD_OUT_WINDOWS: usize = 64;
+/// Largest number of held-out windows that are predicted.
+const LINEAR_MAX_HELD_OUT_WINDOWS: usize = 1 << 16;
+
+/// Outcome of predicting outputs as a linear function over GF(2) of the previous outputs.
+#[derive(Debug, Copy, Clone)]
+pub struct LinearPrediction {
+    /// Rank over GF(2) of the training inputs, at most `64 * LINEAR_PREDICTOR_OUTPUTS`.
+    /// A deficient rank means the outputs lie in a linear subspace.
+    pub training_rank: usize,
+    /// Number of held-out windows whose last output was predicted.
+    pub held_out_windows: usize,
+    /// Fraction of held-out windows in which each bit position was predicted correctly, index 0 is the LSB.
+    pub bit_accuracy: [f64; 64],
+}
+
+impl LinearPrediction {
+    /// Fraction of all held-out bits predicted correctly. 0.5 is ideal.
+    pub fn accuracy(&self) -> f64 {
+        self.bit_accuracy.iter().sum::<f64>() / 64.0
+    }
+
+    /// Two-sided p value of the number of correctly predicted bits.
+    /// Every held-out bit is predicted correctly with probability 0.5 for random data, independently of the others.
+    pub fn p_value(&self) -> f64 {
+        let total = 64.0 * self.held_out_windows as f64;
+        let z = (2.0 * self.accuracy() - 1.0) * total.sqrt();
+        statrs::function::erf::erfc(z.abs() / std::f64::consts::SQRT_2)
+    }
+
+    /// Bit positions predicted better than `weak_bit_threshold`.
+    pub fn predicted_bits(&self) -> Vec<usize> {
+        let threshold = weak_bit_threshold(self.held_out_windows);
+        (0..64)
+            .filter(|&bit| self.bit_accuracy[bit] > threshold)
+            .collect()
+    }
+
+    /// Summarize the prediction in two lines.
+    pub fn format(&self) -> String {
+        [
+            format!(
+                "GF(2) linear prediction from {} outputs: training rank {} of {}, accuracy {:.4} on {} outputs (p: {:.6})",
+                LINEAR_PREDICTOR_OUTPUTS,
+                self.training_rank,
+                LINEAR_INPUT_BITS,
+                self.accuracy(),
+                self.held_out_windows,
+                self.p_value()
+            ),
+            format!("Linearly predicted bits: {:?}", self.predicted_bits()),
+        ]
+        .join("\n")
+    }
+}
+
+/// Fit a linear function over GF(2) from `LINEAR_PREDICTOR_OUTPUTS` outputs to the next output
+/// and measure how well it predicts held-out data.
+/// The data is split into disjoint windows of `LINEAR_PREDICTOR_OUTPUTS + 1` outputs.
+/// The first windows are reduced to an echelon basis of the inputs, bits of the inputs outside of
+/// the span of the training data contribute nothing to the prediction.
+/// Returns None if there are not enough windows for training and held-out prediction.
+pub fn linear_prediction(test_data: &[u64]) -> Option<LinearPrediction> {
+    const WORDS: usize = LINEAR_PREDICTOR_OUTPUTS;
+    let windows: Vec<&[u64]> = test_data.chunks_exact(WORDS + 1).collect();
+    if windows.len() < LINEAR_TRAINING_WINDOWS + LINEAR_MIN_HELD_OUT_WINDOWS {
+        return None;
+    }
+    let (training, held_out) = windows.split_at(LINEAR_TRAINING_WINDOWS);
+    let held_out = &held_out[..held_out.len().min(LINEAR_MAX_HELD_OUT_WINDOWS)];
+    // basis[c] has its lowest set input bit at c, paired with the XOR of the targets it was built from.
+    let mut basis: Vec<Option<([u64; WORDS], u64)>> = vec![None; LINEAR_INPUT_BITS];
+    let mut training_rank = 0;
+    // Reduce `input` by the basis, returning the remaining input and the accumulated target.
+    let reduce =
+        |basis: &[Option<([u64; WORDS], u64)>], mut input: [u64; WORDS], mut target: u64| {
+            for (column, basis_row) in basis.iter().enumerate() {
+                let (word, bit) = (column / 64, column % 64);
+                if (input[word] >> bit) & 1 == 0 {
+                    continue;
+                }
+                match basis_row {
+                    Some((row, row_target)) => {
+                        input.iter_mut().zip(row).for_each(|(a, b)| *a ^= b);
+                        target ^= row_target;
+                    }
+                    None => return (input, target, Some(column)),
+                }
+            }
+            (input, target, None)
+        };
+    for window in training {
+        let input: [u64; WORDS] = window[..WORDS].try_into().unwrap();
+        if let (input, target, Some(column)) = reduce(&basis, input, window[WORDS]) {
+            basis[column] = Some((input, target));
+            training_rank += 1;
+        }
+    }
+    // Prediction for every single input bit, free input bits predict 0.
+    let weights: Vec<u64> = (0..LINEAR_INPUT_BITS)
+        .map(|column| {
+            let mut unit = [0u64; WORDS];
+            unit[column / 64] = 1 << (column % 64);
+            let mut prediction = 0;
+            let mut input = unit;
+            loop {
+                let (remaining, target, free) = reduce(&basis, input, 0);
+                prediction ^= target;
+                match free {
+                    // Drop the free bit and continue with the higher bits.
+                    Some(column) => {
+                        input = remaining;
+                        input[column / 64] ^= 1 << (column % 64);
+                    }
+                    None => break prediction,
+                }
+            }
+        })
+        .collect();
+    let mut correct = [0usize; 64];
+    for window in held_out {
+        let mut prediction = 0u64;
+        for (word, &input) in window[..WORDS].iter().enumerate() {
+            let mut bits = input;
+            while bits != 0 {
+                prediction ^= weights[word * 64 + bits.trailing_zeros() as usize];
+                bits &= bits - 1;
+            }
+        }
+        let hits = !(prediction ^ window[WORDS]);
+        correct
+            .iter_mut()
+            .enumerate()
+            .for_each(|(bit, count)| *count += ((hits >> bit) & 1) as usize);
+    }
+    let mut bit_accuracy = [0.0; 64];
+    bit_accuracy
+        .iter_mut()
+        .zip(correct)
+        .for_each(|(accuracy, count)| *accuracy = count as f64 / held_out.len() as f64);
+    Some(LinearPrediction {
+        training_rank,
+        held_out_windows: held_out.len(),
+        bit_accuracy,
+    })
+}
+
+/// Result of searching the state sequence of a generator for a cycle.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PeriodCertificate {
+    /// The state repeated. `period` is the exact cycle length.
+    CycleFound { period: u64, steps: u64 },
+    /// No state repeats within the first `lower_bound` steps,
+    /// so the period plus any lead-in is larger than `lower_bound`.
+    NoCycleWithin { lower_bound: u64, steps: u64 },
+}
+
+impl PeriodCertificate {
+    /// Number of steps within which the generator does not cycle, the exact period if a cycle was found.
+    pub fn lower_bound(&self) -> u64 {
+        match *self {
+            PeriodCertificate::CycleFound { period, .. } => period,
+            PeriodCertificate::NoCycleWithin { lower_bound, .. } => lower_bound,
+        }
+    }
+
+    /// Number of generator steps that were performed.
+    pub fn steps(&self) -> u64 {
+        match *self {
+            PeriodCertificate::CycleFound { steps, .. } => steps,
+            PeriodCertificate::NoCycleWithin { steps, .. } => steps,
+        }
+    }
+
+    /// Format the certificate for reports.
+    pub fn format(&self) -> String {
+        match *self {
+            PeriodCertificate::CycleFound { period, steps } => {
+                format!("Cycle found: period {} (after {} steps)", period, steps)
+            }
+            PeriodCertificate::NoCycleWithin { lower_bound, steps } => format!(
+                "Certified period lower bound: no cycle within 2^{} steps ({} steps checked)",
+                lower_bound.ilog2(),
+                steps
+            ),
+        }
+    }
+}
+
+/// Certify a lower bound on the period of a generator by comparing state snapshots.
+/// Uses Brent's algorithm: a snapshot is taken at every power of two steps
+/// and compared to each following state, for at most `budget` steps of `advance(1)`.
+/// Without a repeat, the first 2^k + 1 states are distinct for the largest
+/// completed snapshot window of length 2^k, which is at least half the budget.
+pub fn certify_period<R: RNG + Clone + PartialEq>(rng: &R, budget: u64) -> PeriodCertificate {
+    let mut snapshot = rng.clone();
+    let mut current = rng.clone();
+    current.advance(1);
+    let mut steps: u64 = 1;
+    let mut window: u64 = 1;
+    let mut distance: u64 = 1;
+    while current != snapshot {
+        if distance == window {
+            snapshot = current.clone();
+            window *= 2;
+            distance = 0;
+        }
+        if steps >= budget {
+            // The current window is incomplete, only the previous one counts.
+            return PeriodCertificate::NoCycleWithin {
+                lower_bound: window / 2,
+                steps,
+            };
+        }
+        current.advance(1);
+        steps += 1;
+        distance += 1;
+    }
+    PeriodCertificate::CycleFound {
+        period: distance,
+        steps,
+    }
+}
+
+/// Number of consecutive outputs `find_output_cycle` compares.
+/// A repeat of 256 output bits is taken as a repeat of the state.
+pub const OUTPUT_CYCLE_WINDOW: usize = 4;
+
+/// Cycle of the output sequence of a generator.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct OutputCycle {
+    /// Number of outputs after which the sequence repeats.
+    pub period: u64,
+    /// Number of outputs before the first one that repeats.
+    pub preperiod: u64,
+}
+
+/// The last `OUTPUT_CYCLE_WINDOW` outputs of a generator.
+struct OutputWindow<R: RNG + ?Sized> {
+    rng: Box<R>,
+    outputs: VecDeque<u64>,
+}
+
+impl<R: RNG + ?Sized> OutputWindow<R> {
+    fn new(mut rng: Box<R>) -> Self {
+        let outputs = (0..OUTPUT_CYCLE_WINDOW).map(|_| rng.next()).collect();
+        OutputWindow { rng, outputs }
+    }
+
+    fn step(&mut self) {
+        self.outputs.pop_front();
+        self.outputs.push_back(self.rng.next());
+    }
+}
+
+/// Find the cycle of the output sequence with Brent's algorithm, for generators whose state
+/// can not be compared like in `certify_period`, e.g. a `Box<dyn RNG>` from `rngs::registry()`.
+/// Windows of `OUTPUT_CYCLE_WINDOW` consecutive outputs stand in for the state.
+/// `new_rng` must return identically seeded generators, two of them run in parallel to find the preperiod.
+/// Returns None if no cycle is found within `budget` outputs.
+pub fn find_output_cycle<R: RNG + ?Sized>(
+    new_rng: impl Fn() -> Box<R>,
+    budget: u64,
+) -> Option<OutputCycle> {
+    let mut snapshot = OutputWindow::new(new_rng()).outputs;
+    let mut hare = OutputWindow::new(new_rng());
+    hare.step();
+    let (mut power, mut period, mut steps) = (1u64, 1u64, 1u64);
+    while hare.outputs != snapshot {
+        if steps >= budget {
+            return None;
+        }
+        if power == period {
+            snapshot = hare.outputs.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare.step();
+        period += 1;
+        steps += 1;
+    }
+    // With the hare one period ahead, both meet at the first window that repeats.
+    let mut tortoise = OutputWindow::new(new_rng());
+    let mut hare = OutputWindow::new(new_rng());
+    (0..period).for_each(|_| hare.step());
+    let mut preperiod = 0;
+    while tortoise.outputs != hare.outputs {
+        tortoise.step();
+        hare.step();
+        preperiod += 1;
+    }
+    Some(OutputCycle { period, preperiod })
+}
+
+/// Summary statistics of the output bytes, as reported by the `ent` tool.
+#[derive(Debug, Copy, Clone)]
+pub struct ByteStatistics {
+    /// Shannon entropy in bits per byte. Ideal is 8.
+    pub entropy: f64,
+    /// Chi2 statistic of the byte counts with 255 degrees of freedom.
+    pub chi_squared: f64,
+    /// Mean byte value. Ideal is 127.5.
+    pub mean: f64,
+    /// Estimate of pi from the fraction of points inside the unit circle.
+    /// Each u64 is split into two 32 bit coordinates.
+    pub monte_carlo_pi: f64,
+    /// Correlation of each byte with the next one, wrapping around at the end.
+    /// `None` if all bytes are equal.
+    pub serial_correlation: Option<f64>,
+}
+
+/// Compute `ent` style summary statistics of the bytes in `test_data`.
+/// Bytes are read in little endian order.
+pub fn byte_statistics(test_data: &[u64]) -> ByteStatistics {
+    let mut counts = [0usize; 256];
+    let mut inside_circle: usize = 0;
+    let mut sum: f64 = 0.0;
+    let mut sum_squares: f64 = 0.0;
+    let mut sum_products: f64 = 0.0;
+    let mut previous: Option<f64> = None;
+    let radius_squared: u128 = (u32::MAX as u128).pow(2);
+    for &sample in test_data {
+        let x = (sample as u32) as u128;
+        let y = (sample >> 32) as u128;
+        if x * x + y * y <= radius_squared {
+            inside_circle += 1;
+        }
+        for byte in sample.to_le_bytes() {
+            counts[byte as usize] += 1;
+            let value = byte as f64;
+            sum += value;
+            sum_squares += value * value;
+            if let Some(previous) = previous {
+                sum_products += previous * value;
+            }
+            previous = Some(value);
+        }
+    }
+    let n = (test_data.len() * 8) as f64;
+    if let (Some(last), Some(&first)) = (previous, test_data.first()) {
+        sum_products += last * (first as u8) as f64;
+    }
+    let expected = n / 256.0;
+    let entropy: f64 = counts
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| {
+            let p = count as f64 / n;
+            -p * p.log2()
+        })
+        .sum();
+    let correlation_denominator = n * sum_squares - sum * sum;
+    ByteStatistics {
+        entropy,
+        chi_squared: counts
+            .iter()
+            .map(|&count| (count as f64 - expected).powi(2) / expected)
+            .sum(),
+        mean: sum / n,
+        monte_carlo_pi: 4.0 * inside_circle as f64 / test_data.len() as f64,
+        serial_correlation: (correlation_denominator > 0.0)
+            .then(|| (n * sum_products - sum * sum) / correlation_denominator),
+    }
+}
+
+/// A Monte Carlo estimate of a value that is known in closed form.
+#[derive(Debug, Copy, Clone)]
+pub struct MonteCarloEstimate {
+    pub name: &'static str,
+    pub exact: f64,
+    pub estimate: f64,
+    /// Standard error of the estimate expected for independent uniform floats.
+    pub standard_error: f64,
+    /// Number of points the estimate is averaged over.
+    pub points: usize,
+}
+
+impl MonteCarloEstimate {
+    /// Error of the estimate in units of the expected standard error.
+    /// Approximately standard normal for random data.
+    pub fn z_score(&self) -> f64 {
+        (self.estimate - self.exact) / self.standard_error
+    }
+
+    /// Two-sided p value of the z score.
+    pub fn p_value(&self) -> f64 {
+        statrs::function::erf::erfc(self.z_score().abs() / std::f64::consts::SQRT_2)
+    }
+}
+
+/// An integral over the unit cube estimated by the mean of its integrand at random points.
+struct MonteCarloIntegral {
+    name: &'static str,
+    dimensions: usize,
+    exact: f64,
+    /// Variance of the integrand at a uniformly distributed point.
+    variance: f64,
+    integrand: fn(&[f64]) -> f64,
+}
+
+/// Integrals checked by `monte_carlo_estimates`.
+/// The estimates of pi and the volume of the unit ball count the points inside of the unit sphere,
+/// the others are smooth functions of a single float.
+const MONTE_CARLO_INTEGRALS: [MonteCarloIntegral; 5] = [
+    MonteCarloIntegral {
+        name: "pi",
+        dimensions: 2,
+        exact: std::f64::consts::PI,
+        variance: std::f64::consts::PI * (4.0 - std::f64::consts::PI),
+        integrand: |point| 4.0 * ((point[0] * point[0] + point[1] * point[1] < 1.0) as u8 as f64),
+    },
+    MonteCarloIntegral {
+        name: "Unit ball volume (3D)",
+        dimensions: 3,
+        exact: 4.0 * std::f64::consts::PI / 3.0,
+        variance: 64.0 * (std::f64::consts::PI / 6.0) * (1.0 - std::f64::consts::PI / 6.0),
+        integrand: |point| 8.0 * ((point.iter().map(|x| x * x).sum::<f64>() < 1.0) as u8 as f64),
+    },
+    MonteCarloIntegral {
+        name: "Integral of x^2",
+        dimensions: 1,
+        exact: 1.0 / 3.0,
+        variance: 4.0 / 45.0,
+        integrand: |point| point[0] * point[0],
+    },
+    MonteCarloIntegral {
+        name: "Integral of sin(pi x)",
+        dimensions: 1,
+        exact: 2.0 / std::f64::consts::PI,
+        variance: 0.5 - 4.0 / (std::f64::consts::PI * std::f64::consts::PI),
+        integrand: |point| (std::f64::consts::PI * point[0]).sin(),
+    },
+    MonteCarloIntegral {
+        name: "Integral of e^x",
+        dimensions: 1,
+        exact: std::f64::consts::E - 1.0,
+        variance: (std::f64::consts::E * std::f64::consts::E - 1.0) / 2.0
+            - (std::f64::consts::E - 1.0) * (std::f64::consts::E - 1.0),
+        integrand: |point| point[0].exp(),
+    },
+];
+
+/// Estimate pi and a few integrals over the unit cube from the floats produced by
+/// `conditioning::u64_to_double`, as a simulation would use them.
+/// Every estimate uses all of `test_data`, consecutive floats form the coordinates of a point.
+/// Returns an empty vector if `test_data` holds fewer than three u64s.
+pub fn monte_carlo_estimates(test_data: &[u64]) -> Vec<MonteCarloEstimate> {
+    if test_data.len() < 3 {
+        return vec![];
+    }
+    let floats: Vec<f64> = test_data
+        .iter()
+        .map(|&sample| conditioning::u64_to_double(sample))
+        .collect();
+    MONTE_CARLO_INTEGRALS
+        .iter()
+        .map(|integral| {
+            let points = floats.len() / integral.dimensions;
+            let sum: f64 = floats
+                .chunks_exact(integral.dimensions)
+                .map(integral.integrand)
+                .sum();
+            MonteCarloEstimate {
+                name: integral.name,
+                exact: integral.exact,
+                estimate: sum / points as f64,
+                standard_error: (integral.variance / points as f64).sqrt(),
+                points,
+            }
+        })
+        .collect()
+}
+
+/// Summarize each estimate in one line, with its error in units of the expected standard error.
+pub fn format_monte_carlo_estimates(estimates: &[MonteCarloEstimate]) -> String {
+    estimates
+        .iter()
+        .map(|estimate| {
+            format!(
+                "Monte Carlo {}: {:.9} (exact {:.9}, error {:+.2} standard errors over {} points, p: {:.6})",
+                estimate.name,
+                estimate.estimate,
+                estimate.exact,
+                estimate.z_score(),
+                estimate.points,
+                estimate.p_value()
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Recommend the number of initial outputs to discard after seeding.
+/// Compares the first `max_outputs` outputs of `seed` with those of the 64 seeds
+/// differing from it in a single bit. Outputs are considered independent of the seed
+/// once the mean hamming distance is within 5 standard deviations of 32.
+/// Returns the index after the last dependent output,
+/// or `None` if the outputs still depend on the seed at the end.
+pub fn warmup_recommendation<R: RNG>(seed: u64, max_outputs: usize) -> Option<usize> {
+    let mut base = R::new(seed);
+    let base_outputs: Vec<u64> = (0..max_outputs).map(|_| base.next()).collect();
+    let mut distance_sums: Vec<u32> = vec![0; max_outputs];
+    for bit in 0..64 {
+        let mut flipped = R::new(seed ^ (1 << bit));
+        for (sum, &base_output) in distance_sums.iter_mut().zip(&base_outputs) {
+            *sum += (base_output ^ flipped.next()).count_ones();
+        }
+    }
+    // The mean of 64 distances has a standard deviation of 0.5.
+    const TOLERANCE: f64 = 5.0 * 0.5;
+    let last_dependent = distance_sums
+        .iter()
+        .rposition(|&sum| (sum as f64 / 64.0 - 32.0).abs() > TOLERANCE);
+    match last_dependent {
+        None => Some(0),
+        Some(index) if index + 1 < max_outputs => Some(index + 1),
+        Some(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rngs, stats};
+
+    #[test]
+    fn bit_entropy_constant() {
+        let (test_data, _) = stats::generate_test_data(&mut rngs::testgens::OnlyOne::new(0), 512);
+        for entropy in bit_position_min_entropy(&test_data) {
+            assert_eq!(entropy.min_entropy, 0.0);
+        }
+    }
+
+    #[test]
+    fn bit_entropy_alternating_blocks() {
+        // Unbiased bits that are perfectly predicted by the previous output.
+        let (test_data, _) =
+            stats::generate_test_data(&mut rngs::testgens::AlternatingBlocks::new(0), 512);
+        for entropy in bit_position_min_entropy(&test_data) {
+            assert_eq!(entropy.ones_ratio, 0.5);
+            assert_eq!(entropy.lag1_prediction_rate, 1.0);
+            assert_eq!(entropy.min_entropy, 0.0);
+        }
+    }
+
+    #[test]
+    fn bit_entropy_random() {
+        const SAMPLE_COUNT: usize = 1 << 16;
+        let (test_data, _) =
+            stats::generate_test_data(&mut rngs::ReferenceRand::new(0), SAMPLE_COUNT);
+        let threshold = weak_bit_threshold(SAMPLE_COUNT);
+        for entropy in bit_position_min_entropy(&test_data) {
+            assert!(entropy.prediction_rate() < threshold);
+            assert!(entropy.min_entropy > 0.97);
+        }
+    }
+
+    #[test]
+    fn bit_correlations_random() {
+        let (test_data, _) = stats::generate_test_data(&mut rngs::ReferenceRand::new(0), 1 << 16);
+        let correlations = bit_column_correlations(&test_data);
+        assert_eq!(correlations.tested_pairs(), BIT_PAIRS);
+        assert!(correlations.flagged_pairs().is_empty());
+        assert!(correlations.p_value().unwrap() > 0.01);
+    }
+
+    #[test]
+    fn bit_correlations_coupled() {
+        // Bit 1 copies bit 0, bit 3 is the inverse of bit 2 and bit 63 is stuck.
+        let (test_data, _) = stats::generate_test_data(&mut rngs::ReferenceRand::new(0), 1 << 12);
+        let coupled: Vec<u64> = test_data
+            .iter()
+            .map(|&x| {
+                let x = (x & !0b1010) | ((x & 1) << 1) | ((!x & 0b100) << 1);
+                x & !(1 << 63)
+            })
+            .collect();
+        let correlations = bit_column_correlations(&coupled);
+        assert_eq!(correlations.constant_bits, 1 << 63);
+        assert_eq!(correlations.tested_pairs(), 63 * 62 / 2);
+        let flagged = correlations.flagged_pairs();
+        assert_eq!(flagged.len(), 2, "{:?}", flagged);
+        assert!(flagged.contains(&(0, 1, 1.0)));
+        assert!(flagged.contains(&(2, 3, -1.0)));
+        assert_eq!(correlations.pair_p_value(0, 63), None);
+        assert!(correlations.p_value().unwrap() < 1e-12);
+        assert!(correlations
+            .format()
+            .contains("Bits  0 and  1: phi = +1.0000"));
+    }
+
+    #[test]
+    fn linear_prediction_random() {
+        let (test_data, _) = stats::generate_test_data(&mut rngs::ReferenceRand::new(0), 1 << 16);
+        let prediction = linear_prediction(&test_data).unwrap();
+        assert_eq!(prediction.training_rank, 64 * LINEAR_PREDICTOR_OUTPUTS);
+        assert!((prediction.accuracy() - 0.5).abs() < 0.01);
+        assert!(prediction.predicted_bits().is_empty());
+        assert!(prediction.p_value() > 0.01);
+        assert!(linear_prediction(&test_data[..6000]).is_none());
+    }
+
+    #[test]
+    fn linear_prediction_xorshift() {
+        // 128 bits of state, every output is a linear function of the two previous ones.
+        let (test_data, _) =
+            stats::generate_test_data(&mut rngs::xorshift::XORShift128::new(1), 1 << 16);
+        let prediction = linear_prediction(&test_data).unwrap();
+        assert_eq!(prediction.training_rank, 128);
+        assert_eq!(prediction.accuracy(), 1.0);
+        assert_eq!(prediction.predicted_bits(), (0..64).collect::<Vec<usize>>());
+        assert_eq!(prediction.p_value(), 0.0);
+        // Only the lowest bit of a power of two modulus LCG is linear.
+        let (test_data, _) = stats::generate_test_data(&mut rngs::lcg::Mmix::new(1), 1 << 16);
+        let prediction = linear_prediction(&test_data).unwrap();
+        assert!(prediction.predicted_bits().contains(&0));
+        assert!(prediction.predicted_bits().len() < 64);
+    }
+
+    #[test]
+    fn period_of_testgens() {
+        assert_eq!(
+            certify_period(&rngs::testgens::OnlyOne::new(0), 1 << 10),
+            PeriodCertificate::CycleFound {
+                period: 1,
+                steps: 1
+            }
+        );
+        assert_eq!(
+            certify_period(&rngs::testgens::AlternatingBlocks::new(0), 1 << 10),
+            PeriodCertificate::CycleFound {
+                period: 2,
+                steps: 3
+            }
+        );
+    }
+
+    #[test]
+    fn period_of_randu() {
+        // RANDU has period 2^29 for odd seeds and hits 0 for seed 0.
+        let certificate = certify_period(&rngs::lcg::Randu::new(1), 1 << 16);
+        assert_eq!(
+            certificate,
+            PeriodCertificate::NoCycleWithin {
+                lower_bound: 1 << 15,
+                steps: 1 << 16
+            }
+        );
+        assert_eq!(certificate.lower_bound(), 1 << 15);
+        assert_eq!(
+            certify_period(&rngs::lcg::Randu::new(0), 1 << 16),
+            PeriodCertificate::CycleFound {
+                period: 1,
+                steps: 1
+            }
+        );
+    }
+
+    #[test]
+    fn output_cycles() {
+        /// Shifts its state right, the outputs reach 0 after 63 steps.
+        struct Halving {
+            state: u64,
+        }
+        impl RNG for Halving {
+            fn new(seed: u64) -> Self {
+                Halving { state: seed }
+            }
+            fn next_u32(&mut self) -> u32 {
+                self.next() as u32
+            }
+            fn next(&mut self) -> u64 {
+                self.state >>= 1;
+                self.state
+            }
+            fn advance(&mut self, delta: u64) {
+                (0..delta).for_each(|_| self.state >>= 1);
+            }
+            fn reseed(&mut self, seed: u64) {
+                self.state = seed;
+            }
+        }
+        let cycle = |period, preperiod| Some(OutputCycle { period, preperiod });
+        assert_eq!(
+            find_output_cycle(|| Box::new(Halving::new(u64::MAX)), 1 << 10),
+            cycle(1, 63)
+        );
+        assert_eq!(
+            find_output_cycle(|| Box::new(rngs::testgens::OnlyOne::new(0)), 1 << 10),
+            cycle(1, 0)
+        );
+        assert_eq!(
+            find_output_cycle(
+                || Box::new(rngs::testgens::AlternatingBlocks::new(0)),
+                1 << 10
+            ),
+            cycle(2, 0)
+        );
+        // An all-zero state.
+        assert_eq!(
+            find_output_cycle(|| Box::new(rngs::xorshift::XORShift128::new(0)), 1 << 10),
+            cycle(1, 0)
+        );
+        assert_eq!(
+            find_output_cycle(|| Box::new(rngs::lcg::Randu::new(0)), 1 << 10),
+            cycle(1, 0)
+        );
+        // Boxed trait objects from the registry.
+        let (_, _, factory) = rngs::registry()
+            .find(|(name, _, _)| *name == "RANDU")
+            .unwrap();
+        assert_eq!(find_output_cycle(|| factory(1), 1 << 12), None);
+    }
+
+    #[test]
+    fn byte_statistics_of_counter() {
+        // Every byte value occurs equally often.
+        let test_data: Vec<u64> = (0..256u64).map(|i| i * 0x0101010101010101).collect();
+        let statistics = byte_statistics(&test_data);
+        assert_eq!(statistics.entropy, 8.0);
+        assert_eq!(statistics.chi_squared, 0.0);
+        assert_eq!(statistics.mean, 127.5);
+        assert!(statistics.serial_correlation.unwrap() > 0.9);
+        let constant = byte_statistics(&[u64::MAX; 16]);
+        assert_eq!(constant.entropy, 0.0);
+        assert_eq!(constant.serial_correlation, None);
+    }
+
+    #[test]
+    fn byte_statistics_random() {
+        let (test_data, _) = stats::generate_test_data(&mut rngs::ReferenceRand::new(0), 1 << 16);
+        let statistics = byte_statistics(&test_data);
+        assert!(statistics.entropy > 7.999);
+        assert!((statistics.mean - 127.5).abs() < 0.5);
+        assert!((statistics.monte_carlo_pi - std::f64::consts::PI).abs() < 0.05);
+        assert!(statistics.serial_correlation.unwrap().abs() < 0.01);
+    }
+
+    #[test]
+    fn monte_carlo_random() {
+        let (test_data, _) = stats::generate_test_data(&mut rngs::ReferenceRand::new(0), 1 << 16);
+        let estimates = monte_carlo_estimates(&test_data);
+        assert_eq!(estimates.len(), MONTE_CARLO_INTEGRALS.len());
+        for estimate in estimates {
+            assert!(estimate.z_score().abs() < 5.0, "{:?}", estimate);
+        }
+    }
+
+    #[test]
+    fn monte_carlo_constant() {
+        assert!(monte_carlo_estimates(&[0; 2]).is_empty());
+        // All points at the origin lie inside of the unit sphere.
+        let estimates = monte_carlo_estimates(&[0; 1 << 12]);
+        assert_eq!(estimates[0].estimate, 4.0);
+        for estimate in estimates {
+            assert!(estimate.p_value() < 1e-10, "{:?}", estimate);
+        }
+    }
+
+    #[test]
+    fn warmup_of_generators() {
+        assert_eq!(warmup_recommendation::<rngs::ReferenceRand>(7, 64), Some(0));
+        assert_eq!(
+            warmup_recommendation::<rngs::testgens::OnlyOne>(7, 64),
+            None
+        );
+        // The duplicated seed words need several steps to diffuse.
+        let xorshift = warmup_recommendation::<rngs::xorshift::XORShift128>(7, 1024);
+        assert!(matches!(xorshift, Some(1..)), "{:?}", xorshift);
+    }
+}
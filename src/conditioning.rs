@@ -3,6 +3,7 @@
 // Dual-licensed under Apache 2.0 and MIT terms.
 
 //! Methods to turn random bits into more constrained data types.
+//! Also contains wrappers that condition the output of an RNG to remove bias or correlation.
 
 use crate::rngs::RNG;
 
@@ -17,9 +18,13 @@ pub fn u64_to_double(int: u64) -> f64 {
 
 /// Generate integer between 'lower' (inclusive) and 'upper' (exclusive).
 /// Uses rejection sampling so the number of rng calls required is theoretically unbounded.
+/// Returns 'lower' if the range is empty.
 pub fn rs_random_int(test_rng: &mut impl RNG, lower: i64, upper: i64) -> i64 {
-    let range: u64 = (upper - lower).min(0) as u64;
-    if range == 0 {
+    if upper <= lower {
+        return lower;
+    }
+    let range: u64 = upper.wrapping_sub(lower) as u64;
+    if range == 1 {
         return lower;
     }
     let mask: u64 = u64::MAX >> (range - 1).leading_zeros();
@@ -30,5 +35,526 @@ pub fn rs_random_int(test_rng: &mut impl RNG, lower: i64, upper: i64) -> i64 {
             break;
         }
     }
-    rn as i64 + lower
+    lower.wrapping_add(rn as i64)
+}
+
+/// Samples indices with probability proportional to their weights.
+/// Uses the alias method: after building a table in O(n),
+/// every sample takes constant time and two RNG calls.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeightedIndex {
+    weights: Vec<f64>,
+    /// Probability of keeping the uniformly chosen column.
+    probabilities: Vec<f64>,
+    /// Index returned if the column is not kept.
+    aliases: Vec<usize>,
+}
+
+impl WeightedIndex {
+    /// Build a sampler for the supplied weights.
+    /// Fails if there are no weights, a weight is negative or not finite,
+    /// or all weights are zero.
+    pub fn new(weights: &[f64]) -> Result<Self, String> {
+        let mut weighted_index = WeightedIndex {
+            weights: weights.to_vec(),
+            probabilities: vec![],
+            aliases: vec![],
+        };
+        weighted_index.build_table()?;
+        Ok(weighted_index)
+    }
+
+    /// Change the weights at the supplied indices and rebuild the table once.
+    /// On failure the previous weights are kept.
+    pub fn update_weights(&mut self, updates: &[(usize, f64)]) -> Result<(), String> {
+        let previous_weights = self.weights.clone();
+        for &(index, weight) in updates {
+            match self.weights.get_mut(index) {
+                Some(entry) => *entry = weight,
+                None => {
+                    self.weights = previous_weights;
+                    return Err(format!(
+                        "Weight index {} out of range for {} weights",
+                        index,
+                        self.weights.len()
+                    ));
+                }
+            }
+        }
+        if let Err(message) = self.build_table() {
+            self.weights = previous_weights;
+            self.build_table()?;
+            return Err(message);
+        }
+        Ok(())
+    }
+
+    /// Current weights.
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    /// Draw an index with probability proportional to its weight.
+    pub fn sample(&self, test_rng: &mut impl RNG) -> usize {
+        let column = rs_random_int(test_rng, 0, self.weights.len() as i64) as usize;
+        if u64_to_double(test_rng.next()) < self.probabilities[column] {
+            column
+        } else {
+            self.aliases[column]
+        }
+    }
+
+    /// Build the alias table with Vose's method.
+    fn build_table(&mut self) -> Result<(), String> {
+        if self.weights.is_empty() {
+            return Err("No weights supplied".to_string());
+        }
+        if let Some(weight) = self
+            .weights
+            .iter()
+            .find(|weight| !weight.is_finite() || **weight < 0.0)
+        {
+            return Err(format!("Invalid weight {}", weight));
+        }
+        let total_weight: f64 = self.weights.iter().sum();
+        if total_weight <= 0.0 || !total_weight.is_finite() {
+            return Err("Weights must have a positive finite sum".to_string());
+        }
+        let n = self.weights.len();
+        // Scale so the average column holds exactly 1.
+        let mut scaled: Vec<f64> = self
+            .weights
+            .iter()
+            .map(|weight| weight * n as f64 / total_weight)
+            .collect();
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) =
+            (0..n).partition(|&index| scaled[index] < 1.0);
+        self.probabilities = vec![1.0; n];
+        self.aliases = (0..n).collect();
+        while let (Some(&less), Some(&more)) = (small.last(), large.last()) {
+            small.pop();
+            self.probabilities[less] = scaled[less];
+            self.aliases[less] = more;
+            scaled[more] -= 1.0 - scaled[less];
+            if scaled[more] < 1.0 {
+                large.pop();
+                small.push(more);
+            }
+        }
+        // Leftover columns are full, up to rounding errors.
+        Ok(())
+    }
+}
+
+/// Characters used by `alphanumeric_string`.
+pub const ALPHANUMERIC_CHARS: &[u8; 62] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+/// URL and filename safe base64 alphabet (RFC 4648 section 5).
+pub const BASE64_URL_CHARS: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Fill a vector with 'num_bytes' bytes, taking each u64 in little endian order.
+pub fn random_bytes(test_rng: &mut impl RNG, num_bytes: usize) -> Vec<u8> {
+    let mut bytes: Vec<u8> = Vec::with_capacity(num_bytes + 8);
+    while bytes.len() < num_bytes {
+        bytes.extend_from_slice(&test_rng.next().to_le_bytes());
+    }
+    bytes.truncate(num_bytes);
+    bytes
+}
+
+/// Random version 4 UUID in the canonical lowercase 8-4-4-4-12 form (RFC 9562).
+/// 122 of the 128 bits are random, the rest encode version and variant.
+pub fn uuid_v4(test_rng: &mut impl RNG) -> String {
+    let mut bytes = random_bytes(test_rng, 16);
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = hex_string(&bytes);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+fn hex_string(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Token of 'num_bytes' random bytes as lowercase hex, two characters per byte.
+pub fn hex_token(test_rng: &mut impl RNG, num_bytes: usize) -> String {
+    hex_string(&random_bytes(test_rng, num_bytes))
+}
+
+/// Encode bytes in unpadded URL safe base64.
+fn base64_url(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (i, &byte)| {
+            group | (byte as u32) << (16 - 8 * i)
+        });
+        for i in 0..=chunk.len() {
+            encoded.push(BASE64_URL_CHARS[(group >> (18 - 6 * i)) as usize & 0x3f] as char);
+        }
+    }
+    encoded
+}
+
+/// Token of 'num_bytes' random bytes in unpadded URL safe base64.
+/// The length is ceil(4 * num_bytes / 3).
+pub fn base64_token(test_rng: &mut impl RNG, num_bytes: usize) -> String {
+    base64_url(&random_bytes(test_rng, num_bytes))
+}
+
+/// String of 'length' characters drawn uniformly from `ALPHANUMERIC_CHARS`.
+pub fn alphanumeric_string(test_rng: &mut impl RNG, length: usize) -> String {
+    (0..length)
+        .map(|_| {
+            ALPHANUMERIC_CHARS[rs_random_int(test_rng, 0, ALPHANUMERIC_CHARS.len() as i64) as usize]
+                as char
+        })
+        .collect()
+}
+
+/// Von Neumann extractor.
+/// Splits the inner output into bit pairs, LSB first.
+/// A 01 pair produces a 0, a 10 pair produces a 1, equal pairs are discarded.
+/// Removes bias from independent bits at the cost of consuming at least 4 inner bits per output bit.
+/// The number of inner calls per output is theoretically unbounded
+/// and constant inner output never produces a result.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct VonNeumann<R: RNG> {
+    inner: R,
+    buffer: u128,
+    buffered_bits: u32,
+}
+
+impl<R: RNG> VonNeumann<R> {
+    /// Wrap an existing generator.
+    pub fn from_rng(inner: R) -> Self {
+        VonNeumann {
+            inner,
+            buffer: 0,
+            buffered_bits: 0,
+        }
+    }
+}
+
+impl<R: RNG> RNG for VonNeumann<R> {
+    fn new(seed: u64) -> Self {
+        Self::from_rng(R::new(seed))
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        self.next() as u32
+    }
+
+    fn next(&mut self) -> u64 {
+        while self.buffered_bits < 64 {
+            let sample = self.inner.next();
+            // Lower bit of every pair whose bits differ.
+            let mut unequal_pairs = (sample ^ (sample >> 1)) & 0x5555555555555555;
+            while unequal_pairs != 0 {
+                let position = unequal_pairs.trailing_zeros();
+                self.buffer |= (((sample >> position) & 1) as u128) << self.buffered_bits;
+                self.buffered_bits += 1;
+                unequal_pairs &= unequal_pairs - 1;
+            }
+        }
+        let output = self.buffer as u64;
+        self.buffer >>= 64;
+        self.buffered_bits -= 64;
+        output
+    }
+
+    fn advance(&mut self, delta: u64) {
+        for _ in 0..delta {
+            let _ = self.next();
+        }
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.inner.reseed(seed);
+        self.buffer = 0;
+        self.buffered_bits = 0;
+    }
+}
+
+/// XOR-fold extractor.
+/// Combines two consecutive inner outputs into one with XOR.
+/// Halves the throughput, the result is at least as unbiased as the less biased input.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct XorFold<R: RNG> {
+    inner: R,
+}
+
+impl<R: RNG> XorFold<R> {
+    /// Wrap an existing generator.
+    pub fn from_rng(inner: R) -> Self {
+        XorFold { inner }
+    }
+}
+
+impl<R: RNG> RNG for XorFold<R> {
+    fn new(seed: u64) -> Self {
+        Self::from_rng(R::new(seed))
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        self.next() as u32
+    }
+
+    fn next(&mut self) -> u64 {
+        self.inner.next() ^ self.inner.next()
+    }
+
+    fn advance(&mut self, delta: u64) {
+        // Two inner steps per step, split to avoid overflowing delta.
+        self.inner.advance(delta);
+        self.inner.advance(delta);
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.inner.reseed(seed);
+    }
+}
+
+/// Hash based extractor.
+/// Compresses two consecutive inner outputs into one using a
+/// rapidhash style 128 bit multiply fold followed by a SplitMix64 finalizer.
+/// Breaks up linear structure in the inner output, but does not add entropy.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct HashConditioned<R: RNG> {
+    inner: R,
+}
+
+impl<R: RNG> HashConditioned<R> {
+    const SECRET: [u64; 2] = [0x2d358dccaa6c78a5, 0x8bb84b93962eacc9];
+
+    /// Wrap an existing generator.
+    pub fn from_rng(inner: R) -> Self {
+        HashConditioned { inner }
+    }
+
+    /// Hash two words into one.
+    #[inline]
+    fn compress(a: u64, b: u64) -> u64 {
+        let product = ((a ^ Self::SECRET[0]) as u128).wrapping_mul((b ^ Self::SECRET[1]) as u128);
+        let mut z = (product as u64) ^ ((product >> 64) as u64);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+}
+
+impl<R: RNG> RNG for HashConditioned<R> {
+    fn new(seed: u64) -> Self {
+        Self::from_rng(R::new(seed))
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        self.next() as u32
+    }
+
+    fn next(&mut self) -> u64 {
+        let a = self.inner.next();
+        let b = self.inner.next();
+        Self::compress(a, b)
+    }
+
+    fn advance(&mut self, delta: u64) {
+        // Two inner steps per step, split to avoid overflowing delta.
+        self.inner.advance(delta);
+        self.inner.advance(delta);
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.inner.reseed(seed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rngs::{testgens, ReferenceRand};
+
+    #[test]
+    fn von_neumann_extraction() {
+        // Every bit pair of 0x55.. is 10, producing a 1.
+        let mut extractor = VonNeumann::<testgens::AlternatingBits>::new(0);
+        assert_eq!(extractor.next(), u64::MAX);
+        // Random input stays balanced.
+        let mut extractor = VonNeumann::<ReferenceRand>::new(0);
+        let ones: u32 = (0..1024).map(|_| extractor.next().count_ones()).sum();
+        assert!((32768 - 1024..=32768 + 1024).contains(&ones), "{}", ones);
+    }
+
+    #[test]
+    fn xor_fold_extraction() {
+        let mut extractor = XorFold::<testgens::AlternatingBlocks>::new(0);
+        assert_eq!(extractor.next(), u64::MAX);
+        let mut constant = XorFold::<testgens::OnlyOne>::new(0);
+        assert_eq!(constant.next(), 0);
+    }
+
+    #[test]
+    fn hash_extraction() {
+        let mut extractor = HashConditioned::<testgens::OnlyZero>::new(0);
+        let first = extractor.next();
+        assert_ne!(first, 0);
+        // Constant input can not be made random.
+        assert_eq!(extractor.next(), first);
+        assert_ne!(
+            HashConditioned::<testgens::OnlyZero>::compress(0, 1),
+            HashConditioned::<testgens::OnlyZero>::compress(1, 0)
+        );
+    }
+
+    #[test]
+    fn random_int_range() {
+        let mut rng = ReferenceRand::new(0);
+        let mut seen = [false; 7];
+        for _ in 0..1000 {
+            let value = rs_random_int(&mut rng, -3, 4);
+            assert!((-3..4).contains(&value));
+            seen[(value + 3) as usize] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+        assert_eq!(rs_random_int(&mut rng, 5, 6), 5);
+        assert_eq!(rs_random_int(&mut rng, 5, 5), 5);
+    }
+
+    /// Chi2 p value of the sample counts against the weights.
+    fn weighted_sampling_p(sampler: &WeightedIndex, samples: usize, seed: u64) -> f64 {
+        let mut rng = ReferenceRand::new(seed);
+        let mut counts = vec![0usize; sampler.weights().len()];
+        for _ in 0..samples {
+            counts[sampler.sample(&mut rng)] += 1;
+        }
+        let total_weight: f64 = sampler.weights().iter().sum();
+        let mut degrees_of_freedom = -1.0;
+        let mut chi_squared = 0.0;
+        for (&count, &weight) in counts.iter().zip(sampler.weights()) {
+            if weight == 0.0 {
+                assert_eq!(count, 0);
+                continue;
+            }
+            let expected = samples as f64 * weight / total_weight;
+            chi_squared += (count as f64 - expected).powi(2) / expected;
+            degrees_of_freedom += 1.0;
+        }
+        statrs::function::gamma::gamma_ur(degrees_of_freedom / 2.0, chi_squared / 2.0)
+    }
+
+    #[test]
+    fn weighted_sampling() {
+        let sampler = WeightedIndex::new(&[1.0, 2.0, 0.0, 3.0, 4.0, 0.5]).unwrap();
+        let p = weighted_sampling_p(&sampler, 100_000, 0);
+        assert!((0.001..=0.999).contains(&p), "p-value out of range: {}", p);
+    }
+
+    #[test]
+    fn weighted_sampling_updates() {
+        let mut sampler = WeightedIndex::new(&[1.0, 1.0, 1.0, 1.0]).unwrap();
+        sampler.update_weights(&[(0, 0.0), (3, 10.0)]).unwrap();
+        assert_eq!(sampler.weights(), &[0.0, 1.0, 1.0, 10.0]);
+        let p = weighted_sampling_p(&sampler, 100_000, 1);
+        assert!((0.001..=0.999).contains(&p), "p-value out of range: {}", p);
+        // Invalid updates keep the previous weights.
+        assert!(sampler.update_weights(&[(1, -1.0)]).is_err());
+        assert!(sampler.update_weights(&[(4, 1.0)]).is_err());
+        assert!(sampler
+            .update_weights(&[(1, 0.0), (2, 0.0), (3, 0.0)])
+            .is_err());
+        assert_eq!(sampler.weights(), &[0.0, 1.0, 1.0, 10.0]);
+        assert!(WeightedIndex::new(&[]).is_err());
+        assert!(WeightedIndex::new(&[f64::NAN]).is_err());
+    }
+
+    /// Chi2 p value of counts against a uniform distribution.
+    fn uniform_counts_p(counts: &[usize]) -> f64 {
+        let total: usize = counts.iter().sum();
+        let expected = total as f64 / counts.len() as f64;
+        let chi_squared: f64 = counts
+            .iter()
+            .map(|&count| (count as f64 - expected).powi(2) / expected)
+            .sum();
+        statrs::function::gamma::gamma_ur((counts.len() - 1) as f64 / 2.0, chi_squared / 2.0)
+    }
+
+    #[test]
+    fn uuid_format() {
+        let mut rng = ReferenceRand::new(2);
+        let mut counts = [0usize; 16];
+        for _ in 0..2000 {
+            let uuid = uuid_v4(&mut rng);
+            let groups: Vec<&str> = uuid.split('-').collect();
+            assert_eq!(
+                groups
+                    .iter()
+                    .map(|group| group.len())
+                    .collect::<Vec<usize>>(),
+                vec![8, 4, 4, 4, 12]
+            );
+            assert!(uuid
+                .chars()
+                .all(|c| c == '-' || c.is_ascii_digit() || ('a'..='f').contains(&c)));
+            assert!(groups[2].starts_with('4'));
+            assert!("89ab".contains(&groups[3][..1]));
+            // Only the fully random digits are counted.
+            for (index, c) in uuid.replace('-', "").chars().enumerate() {
+                if index != 12 && index != 16 {
+                    counts[c.to_digit(16).unwrap() as usize] += 1;
+                }
+            }
+        }
+        let p = uniform_counts_p(&counts);
+        assert!((0.001..=0.999).contains(&p), "p-value out of range: {}", p);
+    }
+
+    #[test]
+    fn token_format() {
+        let mut rng = ReferenceRand::new(3);
+        for num_bytes in 0..20 {
+            let hex = hex_token(&mut rng, num_bytes);
+            assert_eq!(hex.len(), 2 * num_bytes);
+            assert!(hex
+                .chars()
+                .all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase()));
+            let base64 = base64_token(&mut rng, num_bytes);
+            assert_eq!(base64.len(), (4 * num_bytes).div_ceil(3));
+            assert!(base64.bytes().all(|c| BASE64_URL_CHARS.contains(&c)));
+        }
+        // RFC 4648 test vectors without padding
+        for (input, output) in [
+            ("f", "Zg"),
+            ("fo", "Zm8"),
+            ("foo", "Zm9v"),
+            ("foobar", "Zm9vYmFy"),
+        ] {
+            assert_eq!(base64_url(input.as_bytes()), output);
+        }
+        assert_eq!(base64_url(&[0xfb, 0xff]), "-_8");
+    }
+
+    #[test]
+    fn token_uniformity() {
+        let mut rng = ReferenceRand::new(4);
+        let mut alphanumeric_counts = [0usize; 62];
+        for c in alphanumeric_string(&mut rng, 62 * 500).bytes() {
+            alphanumeric_counts[ALPHANUMERIC_CHARS.iter().position(|&x| x == c).unwrap()] += 1;
+        }
+        let p = uniform_counts_p(&alphanumeric_counts);
+        assert!((0.001..=0.999).contains(&p), "p-value out of range: {}", p);
+        let mut base64_counts = [0usize; 64];
+        // Multiples of 3 bytes, every character holds 6 random bits.
+        for c in base64_token(&mut rng, 3 * 8000).bytes() {
+            base64_counts[BASE64_URL_CHARS.iter().position(|&x| x == c).unwrap()] += 1;
+        }
+        let p = uniform_counts_p(&base64_counts);
+        assert!((0.001..=0.999).contains(&p), "p-value out of range: {}", p);
+    }
 }
@@ -0,0 +1,912 @@
+// Copyright 2025 N. Dornseif
+//
+// Dual-licensed under Apache 2.0 and MIT terms.
+
+//! Run configuration for the default binary.
+//! Selects which registered generators are tested.
+//! Sources in order of precedence:
+//! command line flag, environment variable, config file.
+
+use std::{fs, io, path::Path};
+
+use crate::{
+    report::ReportOrder,
+    rngs::adapters::StreamView,
+    scoring::{self, Scoring},
+    template::Template,
+};
+
+/// Command line flag taking a comma separated list of generator names.
+pub const RNGS_FLAG: &str = "--rngs";
+/// Command line flag taking the path of a config file.
+pub const CONFIG_FLAG: &str = "--config";
+/// Command line flag allowing non-ASCII characters in the output.
+pub const UNICODE_FLAG: &str = "--unicode";
+/// Command line flag taking the log2 of the step budget for period certification.
+pub const PERIOD_BUDGET_FLAG: &str = "--period-budget";
+/// Command line flag running the conditioning throughput benchmark instead of the test suite.
+pub const EXTRACTION_BENCH_FLAG: &str = "--bench-extraction";
+/// Command line flag printing the state recovery of the truncated LCGs instead of running the test suite.
+/// Takes the seed from `--seed`.
+pub const RECOVER_STATE_FLAG: &str = "--recover-state";
+/// Command line flag printing the spectral test of the implemented LCGs instead of running the test suite.
+pub const SPECTRAL_FLAG: &str = "--spectral";
+/// Command line flag checking that published results are reproduced instead of running the test suite.
+pub const CROSS_VALIDATE_FLAG: &str = "--cross-validate";
+/// Command line flag listing the registered generators instead of running the test suite.
+pub const LIST_RNGS_FLAG: &str = "--list-rngs";
+/// Command line flag taking the name of the scoring of p-values in the output.
+pub const SCORING_FLAG: &str = "--scoring";
+/// Command line flag taking a comma separated list of bit planes, each tested as its own stream.
+/// Planes are bit indices of the native output or `top` for the most significant native bit.
+pub const BIT_PLANES_FLAG: &str = "--bit-planes";
+/// Command line flag taking a comma separated list of strides k, every k-th output is tested as its own stream.
+pub const DECIMATE_FLAG: &str = "--decimate";
+/// Command line flag taking a comma separated list of stream counts k, each of the k interleaved sub-streams is tested as its own stream.
+pub const LEAPFROG_FLAG: &str = "--leapfrog";
+/// Command line flag taking a comma separated list of derived streams, each tested as its own stream.
+pub const VIEWS_FLAG: &str = "--views";
+/// Command line flag taking a range of log2 sample sizes `<min>-<max>`, the battery is run at every second one.
+pub const SWEEP_FLAG: &str = "--sweep";
+/// Largest log2 sample size of a sweep, 2^32 u64s already need 32 GiB.
+pub const MAX_SWEEP_EXPONENT: u32 = 32;
+/// Command that prints a quick overview of a single generator.
+pub const INSPECT_COMMAND: &str = "inspect";
+/// Command that writes samples of a single generator to a memory-mapped file.
+pub const EXPORT_COMMAND: &str = "export";
+/// Command that estimates the min-entropy of a file or of a generator's output.
+pub const ENTROPY_COMMAND: &str = "entropy";
+/// Command that renders the trends of a history file.
+pub const HISTORY_COMMAND: &str = "history";
+/// Command line flag taking the path of a history file, a summary of the run is appended to it.
+pub const HISTORY_FLAG: &str = "--history";
+/// Flag of the inspect, export and entropy commands taking a generator name.
+pub const RNG_FLAG: &str = "--rng";
+/// Flag of the inspect, export and entropy commands taking a seed, decimal or hex with a `0x` prefix.
+pub const SEED_FLAG: &str = "--seed";
+/// Flag of the inspect command taking the path of a .ppm heatmap of the bit position correlations.
+pub const HEATMAP_FLAG: &str = "--heatmap";
+/// Flag of the export, history and entropy commands taking the path of the file to write or read.
+pub const PATH_FLAG: &str = "--path";
+/// Flag of the export command taking the number of u64 samples.
+pub const SAMPLES_FLAG: &str = "--samples";
+/// Number of u64 samples exported if `--samples` is not given.
+pub const DEFAULT_EXPORT_SAMPLES: u64 = 1 << 22;
+/// Command line flag taking the path of the JSON report to write.
+pub const JSON_FLAG: &str = "--json";
+/// Command line flag taking the order of the JSON report, `execution` or `sorted`.
+pub const REPORT_ORDER_FLAG: &str = "--report-order";
+/// Command line flag taking the path of a template to render the reports with, see `template`.
+pub const TEMPLATE_FLAG: &str = "--template";
+/// Command line flag taking the path the rendered template is written to, printed if not given.
+pub const TEMPLATE_OUTPUT_FLAG: &str = "--template-output";
+/// Command line flags without a value.
+pub const SWITCH_FLAGS: [&str; 6] = [
+    UNICODE_FLAG,
+    EXTRACTION_BENCH_FLAG,
+    RECOVER_STATE_FLAG,
+    SPECTRAL_FLAG,
+    CROSS_VALIDATE_FLAG,
+    LIST_RNGS_FLAG,
+];
+/// Command line flags taking a value, as `--flag value` or `--flag=value`.
+pub const VALUE_FLAGS: [&str; 19] = [
+    RNGS_FLAG,
+    CONFIG_FLAG,
+    PERIOD_BUDGET_FLAG,
+    SCORING_FLAG,
+    BIT_PLANES_FLAG,
+    DECIMATE_FLAG,
+    LEAPFROG_FLAG,
+    VIEWS_FLAG,
+    SWEEP_FLAG,
+    HISTORY_FLAG,
+    RNG_FLAG,
+    SEED_FLAG,
+    HEATMAP_FLAG,
+    PATH_FLAG,
+    SAMPLES_FLAG,
+    JSON_FLAG,
+    REPORT_ORDER_FLAG,
+    TEMPLATE_FLAG,
+    TEMPLATE_OUTPUT_FLAG,
+];
+/// Commands, only accepted as the first argument.
+pub const COMMANDS: [&str; 4] = [
+    INSPECT_COMMAND,
+    EXPORT_COMMAND,
+    ENTROPY_COMMAND,
+    HISTORY_COMMAND,
+];
+/// Environment variable holding a comma separated list of generator names.
+pub const RNGS_ENV_VAR: &str = "PEARLACID_RNGS";
+/// Environment variable holding the path of a config file.
+pub const CONFIG_ENV_VAR: &str = "PEARLACID_CONFIG";
+/// Config file that is read if it exists and no other source is specified.
+pub const DEFAULT_CONFIG_FILE: &str = "pearlacid.conf";
+
+/// Split a list of generator names separated by commas or whitespace.
+pub fn parse_rng_list(list: &str) -> Vec<String> {
+    list.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|name| !name.is_empty())
+        .map(|name| name.to_string())
+        .collect()
+}
+
+/// Parse the contents of a config file.
+/// Lists generator names separated by commas or newlines.
+/// Everything after a '#' on a line is a comment.
+pub fn parse_config(contents: &str) -> Vec<String> {
+    contents
+        .lines()
+        .flat_map(|line| parse_rng_list(line.split('#').next().unwrap_or("")))
+        .collect()
+}
+
+/// Read generator names from a config file.
+pub fn read_config_file(file_path: &str) -> io::Result<Vec<String>> {
+    Ok(parse_config(&fs::read_to_string(file_path)?))
+}
+
+/// Return the value following `flag` in the argument list.
+/// Also accepts the `--flag=value` form.
+fn flag_value(args: &[String], flag: &str) -> Option<String> {
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == flag {
+            return iter.next().cloned();
+        }
+        if let Some(value) = arg.strip_prefix(flag).and_then(|v| v.strip_prefix('=')) {
+            return Some(value.to_string());
+        }
+    }
+    None
+}
+
+/// Return the arguments that are neither a known flag, the value of a flag
+/// nor a command in first position. The first element, the program name, is skipped.
+pub fn unknown_args(args: &[String]) -> Vec<String> {
+    let mut unknown = vec![];
+    let mut iter = args.iter().skip(1).enumerate();
+    while let Some((position, arg)) = iter.next() {
+        if SWITCH_FLAGS.contains(&arg.as_str())
+            || (position == 0 && COMMANDS.contains(&arg.as_str()))
+        {
+            continue;
+        }
+        if VALUE_FLAGS.contains(&arg.as_str()) {
+            iter.next();
+            continue;
+        }
+        let with_value = VALUE_FLAGS.iter().any(|flag| {
+            arg.strip_prefix(flag)
+                .is_some_and(|rest| rest.starts_with('='))
+        });
+        if !with_value {
+            unknown.push(arg.clone());
+        }
+    }
+    unknown
+}
+
+/// Check if `flag` is present in the argument list.
+pub fn has_flag(args: &[String], flag: &str) -> bool {
+    args.iter().any(|arg| arg == flag)
+}
+
+/// Read the log2 of the period certification budget from the argument list.
+/// Returns `None` if the flag is not present.
+pub fn period_budget_log2(args: &[String]) -> Result<Option<u32>, String> {
+    match flag_value(args, PERIOD_BUDGET_FLAG) {
+        Some(value) => match value.parse::<u32>() {
+            Ok(budget_log2) if budget_log2 < 64 => Ok(Some(budget_log2)),
+            _ => Err(format!(
+                "Invalid period budget '{}': expected an exponent from 0 to 63",
+                value
+            )),
+        },
+        None => Ok(None),
+    }
+}
+
+/// A bit plane selected on the command line.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PlaneSelection {
+    Index(u32),
+    /// The most significant native bit, which depends on the generator.
+    Top,
+}
+
+impl PlaneSelection {
+    /// Resolve to a bit index, None if it is out of range for `native_bits`.
+    pub fn resolve(&self, native_bits: u32) -> Option<u32> {
+        match *self {
+            PlaneSelection::Index(index) if index < native_bits => Some(index),
+            PlaneSelection::Index(_) => None,
+            PlaneSelection::Top => native_bits.checked_sub(1),
+        }
+    }
+}
+
+impl std::fmt::Display for PlaneSelection {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            PlaneSelection::Index(index) => write!(f, "{}", index),
+            PlaneSelection::Top => write!(f, "top"),
+        }
+    }
+}
+
+/// Read the bit planes to test from the argument list.
+/// Returns `None` if the flag is not present.
+pub fn bit_planes(args: &[String]) -> Result<Option<Vec<PlaneSelection>>, String> {
+    let Some(value) = flag_value(args, BIT_PLANES_FLAG) else {
+        return Ok(None);
+    };
+    let planes = value
+        .split(',')
+        .map(str::trim)
+        .filter(|plane| !plane.is_empty())
+        .map(|plane| match plane {
+            "top" => Ok(PlaneSelection::Top),
+            _ => match plane.parse::<u32>() {
+                Ok(index) if index < 64 => Ok(PlaneSelection::Index(index)),
+                _ => Err(format!(
+                    "Invalid bit plane '{}': expected 0 to 63 or 'top'",
+                    plane
+                )),
+            },
+        })
+        .collect::<Result<Vec<PlaneSelection>, String>>()?;
+    if planes.is_empty() {
+        return Err(format!("{} requires at least one plane", BIT_PLANES_FLAG));
+    }
+    Ok(Some(planes))
+}
+
+/// Read the decimation strides to test from the argument list.
+/// Returns `None` if the flag is not present.
+pub fn decimation_strides(args: &[String]) -> Result<Option<Vec<u64>>, String> {
+    let Some(value) = flag_value(args, DECIMATE_FLAG) else {
+        return Ok(None);
+    };
+    let strides = value
+        .split(',')
+        .map(str::trim)
+        .filter(|stride| !stride.is_empty())
+        .map(|stride| match stride.parse::<u64>() {
+            Ok(stride) if stride > 0 => Ok(stride),
+            _ => Err(format!(
+                "Invalid decimation stride '{}': expected a positive integer",
+                stride
+            )),
+        })
+        .collect::<Result<Vec<u64>, String>>()?;
+    if strides.is_empty() {
+        return Err(format!("{} requires at least one stride", DECIMATE_FLAG));
+    }
+    Ok(Some(strides))
+}
+
+/// Read the numbers of leapfrog sub-streams to test from the argument list.
+/// Returns `None` if the flag is not present.
+pub fn leapfrog_streams(args: &[String]) -> Result<Option<Vec<u64>>, String> {
+    let Some(value) = flag_value(args, LEAPFROG_FLAG) else {
+        return Ok(None);
+    };
+    let stream_counts = value
+        .split(',')
+        .map(str::trim)
+        .filter(|streams| !streams.is_empty())
+        .map(|streams| match streams.parse::<u64>() {
+            Ok(streams) if streams >= 2 => Ok(streams),
+            _ => Err(format!(
+                "Invalid number of leapfrog streams '{}': expected an integer of at least 2",
+                streams
+            )),
+        })
+        .collect::<Result<Vec<u64>, String>>()?;
+    if stream_counts.is_empty() {
+        return Err(format!(
+            "{} requires at least one number of streams",
+            LEAPFROG_FLAG
+        ));
+    }
+    Ok(Some(stream_counts))
+}
+
+/// Read the derived streams to test from the argument list, `all` selects every view.
+/// Returns `None` if the flag is not present.
+pub fn stream_views(args: &[String]) -> Result<Option<Vec<StreamView>>, String> {
+    let Some(value) = flag_value(args, VIEWS_FLAG) else {
+        return Ok(None);
+    };
+    if value.trim() == "all" {
+        return Ok(Some(StreamView::ALL.to_vec()));
+    }
+    let views = value
+        .split(',')
+        .map(str::trim)
+        .filter(|view| !view.is_empty())
+        .map(|view| {
+            StreamView::from_name(view).ok_or_else(|| {
+                format!(
+                    "Invalid view '{}': expected 'all' or one of {:?}",
+                    view,
+                    StreamView::ALL.map(|view| view.name())
+                )
+            })
+        })
+        .collect::<Result<Vec<StreamView>, String>>()?;
+    if views.is_empty() {
+        return Err(format!("{} requires at least one view", VIEWS_FLAG));
+    }
+    Ok(Some(views))
+}
+
+/// Read the log2 sample sizes of a sweep from the argument list, from min to max in steps of two.
+/// A single exponent runs one size. Returns `None` if the flag is not present.
+pub fn sweep_exponents(args: &[String]) -> Result<Option<Vec<u32>>, String> {
+    let Some(value) = flag_value(args, SWEEP_FLAG) else {
+        return Ok(None);
+    };
+    let invalid = || {
+        format!(
+            "Invalid sweep '{}': expected <min>-<max> with 10 <= min <= max <= {}",
+            value, MAX_SWEEP_EXPONENT
+        )
+    };
+    let (min, max) = value.split_once('-').unwrap_or((&value, &value));
+    let (Ok(min), Ok(max)) = (min.trim().parse::<u32>(), max.trim().parse::<u32>()) else {
+        return Err(invalid());
+    };
+    if min < 10 || min > max || max > MAX_SWEEP_EXPONENT {
+        return Err(invalid());
+    }
+    Ok(Some((min..=max).step_by(2).collect()))
+}
+
+/// Options of the inspect command.
+#[derive(Debug, PartialEq, Eq)]
+pub struct InspectOptions {
+    pub rng_name: String,
+    pub seed: u64,
+    pub heatmap_path: Option<String>,
+}
+
+/// Parse a seed given in decimal or in hex with a `0x` prefix.
+pub fn parse_seed(value: &str) -> Result<u64, String> {
+    match value.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => value.parse::<u64>(),
+    }
+    .map_err(|e| format!("Invalid seed '{}': {}", value, e))
+}
+
+/// Parse the arguments of the inspect command.
+/// Returns `None` if the first argument is not the inspect command.
+/// The seed defaults to 0, no heatmap is written by default.
+pub fn inspect_options(args: &[String]) -> Result<Option<InspectOptions>, String> {
+    if args.get(1).is_none_or(|command| command != INSPECT_COMMAND) {
+        return Ok(None);
+    }
+    let rng_name = flag_value(args, RNG_FLAG)
+        .ok_or_else(|| format!("{} requires {} <name>", INSPECT_COMMAND, RNG_FLAG))?;
+    let seed = match flag_value(args, SEED_FLAG) {
+        Some(value) => parse_seed(&value)?,
+        None => 0,
+    };
+    Ok(Some(InspectOptions {
+        rng_name,
+        seed,
+        heatmap_path: flag_value(args, HEATMAP_FLAG),
+    }))
+}
+
+/// Seed of the state recovery report.
+/// Returns `None` if `--recover-state` is not given. The seed defaults to 0.
+pub fn recover_state_seed(args: &[String]) -> Result<Option<u64>, String> {
+    if !has_flag(args, RECOVER_STATE_FLAG) {
+        return Ok(None);
+    }
+    match flag_value(args, SEED_FLAG) {
+        Some(value) => parse_seed(&value).map(Some),
+        None => Ok(Some(0)),
+    }
+}
+
+/// Data assessed by the entropy command.
+#[derive(Debug, PartialEq, Eq)]
+pub enum EntropySource {
+    /// Raw bytes of a file.
+    File(String),
+    /// Little endian outputs of a registered generator.
+    Rng { rng_name: String, seed: u64 },
+}
+
+/// Parse the arguments of the entropy command, which takes either `--path` or `--rng`.
+/// Returns `None` if the first argument is not the entropy command.
+/// The seed of a generator defaults to 0.
+pub fn entropy_source(args: &[String]) -> Result<Option<EntropySource>, String> {
+    if args.get(1).is_none_or(|command| command != ENTROPY_COMMAND) {
+        return Ok(None);
+    }
+    match (flag_value(args, PATH_FLAG), flag_value(args, RNG_FLAG)) {
+        (Some(path), None) => Ok(Some(EntropySource::File(path))),
+        (None, Some(rng_name)) => {
+            let seed = match flag_value(args, SEED_FLAG) {
+                Some(value) => parse_seed(&value)?,
+                None => 0,
+            };
+            Ok(Some(EntropySource::Rng { rng_name, seed }))
+        }
+        _ => Err(format!(
+            "{} requires either {} <path> or {} <name>",
+            ENTROPY_COMMAND, PATH_FLAG, RNG_FLAG
+        )),
+    }
+}
+
+/// Options of the export command.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ExportOptions {
+    pub rng_name: String,
+    pub seed: u64,
+    pub path: String,
+    pub sample_count: u64,
+}
+
+/// Parse the arguments of the export command.
+/// Returns `None` if the first argument is not the export command.
+/// The seed defaults to 0 and the sample count to `DEFAULT_EXPORT_SAMPLES`.
+pub fn export_options(args: &[String]) -> Result<Option<ExportOptions>, String> {
+    if args.get(1).is_none_or(|command| command != EXPORT_COMMAND) {
+        return Ok(None);
+    }
+    let rng_name = flag_value(args, RNG_FLAG)
+        .ok_or_else(|| format!("{} requires {} <name>", EXPORT_COMMAND, RNG_FLAG))?;
+    let path = flag_value(args, PATH_FLAG)
+        .ok_or_else(|| format!("{} requires {} <path>", EXPORT_COMMAND, PATH_FLAG))?;
+    let seed = match flag_value(args, SEED_FLAG) {
+        Some(value) => parse_seed(&value)?,
+        None => 0,
+    };
+    let sample_count = match flag_value(args, SAMPLES_FLAG) {
+        Some(value) => value
+            .parse::<u64>()
+            .map_err(|e| format!("Invalid sample count '{}': {}", value, e))?,
+        None => DEFAULT_EXPORT_SAMPLES,
+    };
+    Ok(Some(ExportOptions {
+        rng_name,
+        seed,
+        path,
+        sample_count,
+    }))
+}
+
+/// Options of the history command.
+#[derive(Debug, PartialEq, Eq)]
+pub struct HistoryOptions {
+    pub path: String,
+    /// Only render the trends of this generator.
+    pub rng_name: Option<String>,
+}
+
+/// Parse the arguments of the history command.
+/// Returns `None` if the first argument is not the history command.
+pub fn history_options(args: &[String]) -> Result<Option<HistoryOptions>, String> {
+    if args.get(1).is_none_or(|command| command != HISTORY_COMMAND) {
+        return Ok(None);
+    }
+    let path = flag_value(args, PATH_FLAG)
+        .ok_or_else(|| format!("{} requires {} <path>", HISTORY_COMMAND, PATH_FLAG))?;
+    Ok(Some(HistoryOptions {
+        path,
+        rng_name: flag_value(args, RNG_FLAG),
+    }))
+}
+
+/// Read the path of the history file from the argument list.
+pub fn history_path(args: &[String]) -> Option<String> {
+    flag_value(args, HISTORY_FLAG)
+}
+
+/// Read the path of the JSON report from the argument list.
+pub fn json_report_path(args: &[String]) -> Option<String> {
+    flag_value(args, JSON_FLAG)
+}
+
+/// Read and parse the report template from the argument list.
+/// Returns `None` if the flag is not present.
+pub fn report_template(args: &[String]) -> Result<Option<Template>, String> {
+    let Some(template_path) = flag_value(args, TEMPLATE_FLAG) else {
+        return Ok(None);
+    };
+    let template = fs::read_to_string(&template_path)
+        .map_err(|e| format!("Could not read template '{}': {}", template_path, e))?;
+    Template::parse(&template)
+        .map(Some)
+        .map_err(|message| format!("Invalid template '{}': {}", template_path, message))
+}
+
+/// Read the path the rendered template is written to from the argument list.
+pub fn template_output_path(args: &[String]) -> Option<String> {
+    flag_value(args, TEMPLATE_OUTPUT_FLAG)
+}
+
+/// Read the order of the JSON report from the argument list.
+/// Defaults to the execution order.
+pub fn report_order(args: &[String]) -> Result<ReportOrder, String> {
+    match flag_value(args, REPORT_ORDER_FLAG) {
+        Some(name) => ReportOrder::from_name(&name).ok_or_else(|| {
+            format!(
+                "Invalid report order '{}': expected 'execution' or 'sorted'",
+                name
+            )
+        }),
+        None => Ok(ReportOrder::Execution),
+    }
+}
+
+/// Read the scoring of p-values from the argument list.
+/// Returns `None` if the flag is not present.
+pub fn scoring(args: &[String]) -> Result<Option<&'static dyn Scoring>, String> {
+    match flag_value(args, SCORING_FLAG) {
+        Some(name) => scoring::from_name(&name).map(Some).ok_or_else(|| {
+            let names: Vec<&str> = scoring::SCORINGS.iter().map(|s| s.name()).collect();
+            format!(
+                "Invalid scoring '{}': expected one of {}",
+                name,
+                names.join(", ")
+            )
+        }),
+        None => Ok(None),
+    }
+}
+
+/// Determine which generators to test.
+/// Returns `None` if no source restricts the selection, meaning all generators are tested.
+/// Fails if the selected config file can not be read.
+pub fn rng_allowlist(args: &[String]) -> Result<Option<Vec<String>>, String> {
+    if let Some(list) = flag_value(args, RNGS_FLAG) {
+        return Ok(Some(parse_rng_list(&list)));
+    }
+    if let Ok(list) = std::env::var(RNGS_ENV_VAR) {
+        return Ok(Some(parse_rng_list(&list)));
+    }
+    let config_path = flag_value(args, CONFIG_FLAG)
+        .or_else(|| std::env::var(CONFIG_ENV_VAR).ok())
+        .or_else(|| {
+            Path::new(DEFAULT_CONFIG_FILE)
+                .exists()
+                .then(|| DEFAULT_CONFIG_FILE.to_string())
+        });
+    match config_path {
+        Some(path) => read_config_file(&path)
+            .map(Some)
+            .map_err(|e| format!("Could not read config file '{}': {}", path, e)),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_arguments() {
+        let args: Vec<String> = [
+            "pearlacid",
+            "inspect",
+            "--rng",
+            "WyRand",
+            "--seed=0x2a",
+            "--unicode",
+            "--heatmap",
+            "--unicode",
+        ]
+        .iter()
+        .map(|arg| arg.to_string())
+        .collect();
+        assert!(unknown_args(&args).is_empty());
+        let args: Vec<String> = [
+            "pearlacid",
+            "--rngs",
+            "WyRand",
+            "--unicod",
+            "inspect",
+            "--list-rngs=1",
+        ]
+        .iter()
+        .map(|arg| arg.to_string())
+        .collect();
+        assert_eq!(
+            unknown_args(&args),
+            vec!["--unicod", "inspect", "--list-rngs=1"]
+        );
+        assert!(unknown_args(&args[..1]).is_empty());
+    }
+
+    #[test]
+    fn rng_list_parsing() {
+        assert_eq!(
+            parse_rng_list("WyRand, RANDU,,MMIX  Lehmer64"),
+            vec!["WyRand", "RANDU", "MMIX", "Lehmer64"]
+        );
+        assert!(parse_rng_list(" , ").is_empty());
+    }
+
+    #[test]
+    fn config_parsing() {
+        let contents = "# Fast generators\nWyRand\nLehmer64, MMIX # Knuth\n\n#RANDU\n";
+        assert_eq!(parse_config(contents), vec!["WyRand", "Lehmer64", "MMIX"]);
+    }
+
+    #[test]
+    fn period_budget_parsing() {
+        let args = |value: &str| vec![PERIOD_BUDGET_FLAG.to_string(), value.to_string()];
+        assert_eq!(period_budget_log2(&args("32")), Ok(Some(32)));
+        assert!(period_budget_log2(&args("64")).is_err());
+        assert!(period_budget_log2(&args("many")).is_err());
+    }
+
+    #[test]
+    fn entropy_parsing() {
+        let args = |extra: &[&str]| -> Vec<String> {
+            ["pearlacid", "entropy"]
+                .iter()
+                .chain(extra)
+                .map(|s| s.to_string())
+                .collect()
+        };
+        assert_eq!(
+            entropy_source(&args(&["--path", "noise.bin"])),
+            Ok(Some(EntropySource::File("noise.bin".to_string())))
+        );
+        assert_eq!(
+            entropy_source(&args(&["--rng", "mmix", "--seed", "3"])),
+            Ok(Some(EntropySource::Rng {
+                rng_name: "mmix".to_string(),
+                seed: 3
+            }))
+        );
+        assert!(entropy_source(&args(&[])).is_err());
+        assert!(entropy_source(&args(&["--path", "a", "--rng", "mmix"])).is_err());
+        assert_eq!(entropy_source(&args(&[])[..1]), Ok(None));
+    }
+
+    #[test]
+    fn recover_state_parsing() {
+        let args = |extra: &[&str]| -> Vec<String> {
+            ["pearlacid"]
+                .iter()
+                .chain(extra)
+                .map(|s| s.to_string())
+                .collect()
+        };
+        assert_eq!(recover_state_seed(&args(&[])), Ok(None));
+        assert_eq!(recover_state_seed(&args(&["--recover-state"])), Ok(Some(0)));
+        assert_eq!(
+            recover_state_seed(&args(&["--recover-state", "--seed", "0x10"])),
+            Ok(Some(16))
+        );
+        assert!(recover_state_seed(&args(&["--recover-state", "--seed", "x"])).is_err());
+    }
+
+    #[test]
+    fn inspect_parsing() {
+        let args: Vec<String> = [
+            "pearlacid",
+            "inspect",
+            "--rng",
+            "xorshift128",
+            "--seed",
+            "0x1f",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+        assert_eq!(
+            inspect_options(&args),
+            Ok(Some(InspectOptions {
+                rng_name: "xorshift128".to_string(),
+                seed: 31,
+                heatmap_path: None
+            }))
+        );
+        let with_heatmap: Vec<String> = args
+            .iter()
+            .cloned()
+            .chain(["--heatmap".to_string(), "bits.ppm".to_string()])
+            .collect();
+        assert_eq!(
+            inspect_options(&with_heatmap)
+                .unwrap()
+                .unwrap()
+                .heatmap_path,
+            Some("bits.ppm".to_string())
+        );
+        assert_eq!(inspect_options(&args[..1]), Ok(None));
+        assert!(inspect_options(&args[..2]).is_err());
+        assert_eq!(parse_seed("7"), Ok(7));
+        assert!(parse_seed("seven").is_err());
+    }
+
+    #[test]
+    fn export_parsing() {
+        let args: Vec<String> = [
+            "pearlacid",
+            "export",
+            "--rng",
+            "WyRand",
+            "--path=/dev/shm/samples.bin",
+            "--samples",
+            "1024",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+        assert_eq!(
+            export_options(&args),
+            Ok(Some(ExportOptions {
+                rng_name: "WyRand".to_string(),
+                seed: 0,
+                path: "/dev/shm/samples.bin".to_string(),
+                sample_count: 1024,
+            }))
+        );
+        assert_eq!(
+            export_options(&args[..5]).map(|options| options.map(|o| o.sample_count)),
+            Ok(Some(DEFAULT_EXPORT_SAMPLES))
+        );
+        assert!(export_options(&args[..4]).is_err());
+        assert_eq!(inspect_options(&args), Ok(None));
+    }
+
+    #[test]
+    fn bit_plane_parsing() {
+        let args = |value: &str| vec![BIT_PLANES_FLAG.to_string(), value.to_string()];
+        assert_eq!(
+            bit_planes(&args("0, 1,top")),
+            Ok(Some(vec![
+                PlaneSelection::Index(0),
+                PlaneSelection::Index(1),
+                PlaneSelection::Top
+            ]))
+        );
+        assert!(bit_planes(&args("64")).is_err());
+        assert!(bit_planes(&args("low")).is_err());
+        assert!(bit_planes(&args(",")).is_err());
+        assert_eq!(bit_planes(&[]), Ok(None));
+        assert_eq!(PlaneSelection::Top.resolve(31), Some(30));
+        assert_eq!(PlaneSelection::Index(40).resolve(31), None);
+    }
+
+    #[test]
+    fn decimation_parsing() {
+        let args = |value: &str| vec![DECIMATE_FLAG.to_string(), value.to_string()];
+        assert_eq!(decimation_strides(&args("2, 3,7")), Ok(Some(vec![2, 3, 7])));
+        assert!(decimation_strides(&args("0")).is_err());
+        assert!(decimation_strides(&args("-2")).is_err());
+        assert!(decimation_strides(&args(",")).is_err());
+        assert_eq!(decimation_strides(&[]), Ok(None));
+    }
+
+    #[test]
+    fn leapfrog_parsing() {
+        let args = |value: &str| vec![LEAPFROG_FLAG.to_string(), value.to_string()];
+        assert_eq!(leapfrog_streams(&args("2, 4")), Ok(Some(vec![2, 4])));
+        assert!(leapfrog_streams(&args("1")).is_err());
+        assert!(leapfrog_streams(&args("x")).is_err());
+        assert!(leapfrog_streams(&args(",")).is_err());
+        assert_eq!(leapfrog_streams(&[]), Ok(None));
+    }
+
+    #[test]
+    fn template_flags() {
+        let args = |path: &str| vec![TEMPLATE_FLAG.to_string(), path.to_string()];
+        assert_eq!(report_template(&[]), Ok(None));
+        assert!(report_template(&args("/nonexistent/report.tpl")).is_err());
+        let path = std::env::temp_dir().join("pearlacid-config-template.tpl");
+        fs::write(&path, "{{#generators}}").unwrap();
+        assert!(report_template(&args(path.to_str().unwrap())).is_err());
+        fs::write(&path, "{{#generators}}{{rng_name}}{{/generators}}").unwrap();
+        assert!(report_template(&args(path.to_str().unwrap()))
+            .unwrap()
+            .is_some());
+        fs::remove_file(&path).unwrap();
+        assert_eq!(template_output_path(&[]), None);
+    }
+
+    #[test]
+    fn sweep_parsing() {
+        let args = |value: &str| vec![SWEEP_FLAG.to_string(), value.to_string()];
+        assert_eq!(
+            sweep_exponents(&args("20-30")),
+            Ok(Some(vec![20, 22, 24, 26, 28, 30]))
+        );
+        assert_eq!(sweep_exponents(&args("17-20")), Ok(Some(vec![17, 19])));
+        assert_eq!(sweep_exponents(&args("16")), Ok(Some(vec![16])));
+        assert!(sweep_exponents(&args("22-20")).is_err());
+        assert!(sweep_exponents(&args("20-33")).is_err());
+        assert!(sweep_exponents(&args("4-8")).is_err());
+        assert!(sweep_exponents(&args("big")).is_err());
+        assert_eq!(sweep_exponents(&[]), Ok(None));
+    }
+
+    #[test]
+    fn view_parsing() {
+        let args = |value: &str| vec![VIEWS_FLAG.to_string(), value.to_string()];
+        assert_eq!(
+            stream_views(&args("xor, bitrev")),
+            Ok(Some(vec![StreamView::Xor, StreamView::BitReverse]))
+        );
+        assert_eq!(
+            stream_views(&args("all")),
+            Ok(Some(StreamView::ALL.to_vec()))
+        );
+        assert!(stream_views(&args("diff")).is_err());
+        assert!(stream_views(&args(",")).is_err());
+        assert_eq!(stream_views(&[]), Ok(None));
+    }
+
+    #[test]
+    fn history_parsing() {
+        let args: Vec<String> = ["pearlacid", "history", "--path", "runs.jsonl", "--rng=MMIX"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(
+            history_options(&args),
+            Ok(Some(HistoryOptions {
+                path: "runs.jsonl".to_string(),
+                rng_name: Some("MMIX".to_string()),
+            }))
+        );
+        assert!(history_options(&args[..2]).is_err());
+        assert_eq!(history_path(&args), None);
+        assert_eq!(
+            history_path(&["--history=runs.jsonl".to_string()]),
+            Some("runs.jsonl".to_string())
+        );
+    }
+
+    #[test]
+    fn report_flag_parsing() {
+        let args: Vec<String> = ["pearlacid", "--json", "out.json", "--report-order=sorted"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(json_report_path(&args), Some("out.json".to_string()));
+        assert_eq!(report_order(&args), Ok(ReportOrder::Sorted));
+        assert_eq!(report_order(&args[..3]), Ok(ReportOrder::Execution));
+        assert!(report_order(&["--report-order".to_string(), "random".to_string()]).is_err());
+        let scoring_args = |name: &str| vec![SCORING_FLAG.to_string(), name.to_string()];
+        assert_eq!(
+            scoring(&scoring_args("z")).map(|s| s.map(|s| s.name())),
+            Ok(Some("z"))
+        );
+        assert!(scoring(&scoring_args("chi")).is_err());
+        assert!(scoring(&args).is_ok_and(|s| s.is_none()));
+    }
+
+    #[test]
+    fn flag_parsing() {
+        let args: Vec<String> = ["pearlacid", "--rngs", "WyRand,MMIX", "--config=a.conf"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(
+            flag_value(&args, RNGS_FLAG),
+            Some("WyRand,MMIX".to_string())
+        );
+        assert_eq!(flag_value(&args, CONFIG_FLAG), Some("a.conf".to_string()));
+        assert_eq!(flag_value(&args, "--seed"), None);
+        assert_eq!(period_budget_log2(&args), Ok(None));
+        assert_eq!(
+            rng_allowlist(&args),
+            Ok(Some(vec!["WyRand".to_string(), "MMIX".to_string()]))
+        );
+    }
+}
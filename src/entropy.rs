@@ -0,0 +1,410 @@
+// Copyright 2025 N. Dornseif
+//
+// Dual-licensed under Apache 2.0 and MIT terms.
+
+//! Non-IID min-entropy estimators of NIST Special Publication 800-90B, section 6.3.
+//! Assesses entropy sources instead of the uniformity of a PRNG.
+//! The most common value, t-tuple and LRS estimates are computed on the bytes,
+//! the collision, Markov and compression estimates are only defined for binary data and are computed
+//! on the bitstream, MSB of every byte first, and scaled to bytes as in 800-90B section 3.1.3.
+//! The min-entropy per byte is the smallest of all estimates.
+
+use std::collections::HashMap;
+
+/// Number of bytes assessed, 800-90B asks for at least one million samples.
+pub const ENTROPY_SAMPLES: usize = 1_000_000;
+/// Upper 99% quantile of the standard normal distribution, used for every confidence bound.
+const Z_ALPHA: f64 = 2.576;
+/// A t-tuple must occur at least this often to enter the t-tuple estimate.
+const TUPLE_CUTOFF: usize = 35;
+/// Longest tuple counted by the t-tuple and LRS estimates.
+/// Data with longer repeats is already bounded far below 8 bits per byte by the shorter tuples.
+const MAX_TUPLE_LENGTH: usize = 32;
+/// Bits per block of the compression estimate.
+const COMPRESSION_BLOCK_BITS: usize = 6;
+/// Blocks that initialize the dictionary of the compression estimate.
+const COMPRESSION_DICTIONARY_BLOCKS: usize = 1000;
+/// Bits the Markov estimate computes the most likely sequence for.
+const MARKOV_SEQUENCE_BITS: i32 = 128;
+
+/// Min-entropy estimate of a single estimator.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct EntropyEstimate {
+    pub name: &'static str,
+    /// Bits of min-entropy per byte, None if the data is too short or the estimator is not applicable.
+    pub min_entropy: Option<f64>,
+}
+
+/// Split bytes into bits, MSB first, one bit per u8.
+pub fn bytes_to_bits(bytes: &[u8]) -> Vec<u8> {
+    bytes
+        .iter()
+        .flat_map(|&byte| (0..8).rev().map(move |bit| (byte >> bit) & 1))
+        .collect()
+}
+
+/// Upper bound of the 99% confidence interval of a probability estimated from `samples` samples.
+fn upper_bound(p: f64, samples: usize) -> f64 {
+    (p + Z_ALPHA * (p * (1.0 - p) / (samples as f64 - 1.0)).sqrt()).min(1.0)
+}
+
+/// Largest p in [low, high] with `f(p) >= target`, for a decreasing `f`.
+/// Returns `low` if `f(low)` is already below the target.
+fn solve_decreasing(f: impl Fn(f64) -> f64, target: f64, mut low: f64, mut high: f64) -> f64 {
+    if f(low) <= target {
+        return low;
+    }
+    for _ in 0..64 {
+        let middle = (low + high) / 2.0;
+        if f(middle) >= target {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    low
+}
+
+/// Most common value estimate (800-90B 6.3.1) in bits per sample.
+/// Returns None for fewer than two samples.
+pub fn most_common_value_estimate(samples: &[u8]) -> Option<f64> {
+    if samples.len() < 2 {
+        return None;
+    }
+    let mut counts = [0usize; 256];
+    samples
+        .iter()
+        .for_each(|&sample| counts[sample as usize] += 1);
+    let p = *counts.iter().max()? as f64 / samples.len() as f64;
+    Some(0.0 - upper_bound(p, samples.len()).log2())
+}
+
+/// Collision estimate (800-90B 6.3.2) of a bitstream in bits per bit.
+/// Returns None if fewer than two collisions are found.
+pub fn collision_estimate(bits: &[u8]) -> Option<f64> {
+    // Number of samples until the first repeated value, 2 or 3 for binary data.
+    let mut times: Vec<f64> = vec![];
+    let mut index = 0;
+    while index + 1 < bits.len() {
+        if bits[index] == bits[index + 1] {
+            times.push(2.0);
+            index += 2;
+        } else if index + 2 < bits.len() {
+            times.push(3.0);
+            index += 3;
+        } else {
+            break;
+        }
+    }
+    let v = times.len() as f64;
+    if times.len() < 2 {
+        return None;
+    }
+    let mean = times.iter().sum::<f64>() / v;
+    let deviation = (times.iter().map(|t| (t - mean).powi(2)).sum::<f64>() / (v - 1.0)).sqrt();
+    let lower_mean = mean - Z_ALPHA * deviation / v.sqrt();
+    // Expected collision time for a most likely bit of probability p.
+    let expected_time = |p: f64| {
+        let q = 1.0 - p;
+        let f = 2.0 * q.powi(3) + 2.0 * q * q + q;
+        let difference = 0.5 * (1.0 / p - 1.0 / q);
+        p / (q * q) * (1.0 + difference) * f - p / q * difference
+    };
+    let p = solve_decreasing(expected_time, lower_mean, 0.5, 1.0 - f64::EPSILON);
+    Some(0.0 - p.log2())
+}
+
+/// Markov estimate (800-90B 6.3.3) of a bitstream in bits per bit.
+/// Returns None for fewer than two bits.
+pub fn markov_estimate(bits: &[u8]) -> Option<f64> {
+    if bits.len() < 2 {
+        return None;
+    }
+    let ones = bits.iter().filter(|&&bit| bit == 1).count() as f64;
+    let p1 = ones / bits.len() as f64;
+    let p0 = 1.0 - p1;
+    let mut transitions = [[0usize; 2]; 2];
+    bits.windows(2)
+        .for_each(|pair| transitions[pair[0] as usize][pair[1] as usize] += 1);
+    let probability = |from: usize, to: usize| {
+        let total = transitions[from][0] + transitions[from][1];
+        match total {
+            0 => 0.0,
+            _ => transitions[from][to] as f64 / total as f64,
+        }
+    };
+    let (p00, p01, p10, p11) = (
+        probability(0, 0),
+        probability(0, 1),
+        probability(1, 0),
+        probability(1, 1),
+    );
+    let n = MARKOV_SEQUENCE_BITS;
+    // Probabilities of the candidates for the most likely sequence of n bits.
+    let p_max = [
+        p0 * p00.powi(n - 1),
+        p0 * p01.powi(n / 2) * p10.powi(n / 2 - 1),
+        p0 * p01 * p11.powi(n - 2),
+        p1 * p10 * p00.powi(n - 2),
+        p1 * p10.powi(n / 2) * p01.powi(n / 2 - 1),
+        p1 * p11.powi(n - 1),
+    ]
+    .into_iter()
+    .fold(0.0, f64::max);
+    Some(((0.0 - p_max.log2()) / n as f64).min(1.0))
+}
+
+/// Compression estimate (800-90B 6.3.4) of a bitstream in bits per bit.
+/// Returns None if there are not more than 1000 blocks of 6 bits.
+pub fn compression_estimate(bits: &[u8]) -> Option<f64> {
+    let b = COMPRESSION_BLOCK_BITS;
+    let d = COMPRESSION_DICTIONARY_BLOCKS;
+    let blocks: Vec<usize> = bits
+        .chunks_exact(b)
+        .map(|block| {
+            block
+                .iter()
+                .fold(0, |value, &bit| value << 1 | bit as usize)
+        })
+        .collect();
+    let total = blocks.len();
+    if total <= d + 1 {
+        return None;
+    }
+    let v = (total - d) as f64;
+    // Last position of every block value, 1 based, 0 if not seen yet.
+    let mut dictionary = vec![0usize; 1 << b];
+    for (i, &block) in blocks[..d].iter().enumerate() {
+        dictionary[block] = i + 1;
+    }
+    let (mut sum, mut sum_squares) = (0.0, 0.0);
+    for (i, &block) in blocks.iter().enumerate().skip(d) {
+        let position = i + 1;
+        let distance = (position - dictionary[block]) as f64;
+        dictionary[block] = position;
+        sum += distance.log2();
+        sum_squares += distance.log2().powi(2);
+    }
+    let mean = sum / v;
+    let deviation = 0.5907 * (sum_squares / (v - 1.0) - mean * mean).max(0.0).sqrt();
+    let lower_mean = mean - Z_ALPHA * deviation / v.sqrt();
+    // Expected mean of log2 of the distances if one block value has probability z.
+    let g = |z: f64| {
+        let mut result = 0.0;
+        let mut power = 1.0;
+        for u in 1..=total {
+            let log_u = (u as f64).log2();
+            // Positions t > u contribute z^2 (1 - z)^(u - 1), t = u contributes z (1 - z)^(u - 1).
+            let later = total - u.max(d);
+            result += log_u * power * (z * z * later as f64 + if u > d { z } else { 0.0 });
+            power *= 1.0 - z;
+            if power < f64::MIN_POSITIVE {
+                break;
+            }
+        }
+        result / v
+    };
+    let n = (1 << b) as f64;
+    let expected_mean = |p: f64| g(p) + (n - 1.0) * g((1.0 - p) / (n - 1.0));
+    let p = solve_decreasing(expected_mean, lower_mean, 1.0 / n, 1.0);
+    Some(((0.0 - p.log2()) / b as f64).min(1.0))
+}
+
+/// Count the occurrences of every tuple of `length` consecutive samples.
+fn tuple_counts(samples: &[u8], length: usize) -> HashMap<&[u8], usize> {
+    let mut counts: HashMap<&[u8], usize> = HashMap::new();
+    for tuple in samples.windows(length) {
+        *counts.entry(tuple).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Length of the longest tuple that occurs at least `TUPLE_CUTOFF` times, at most `MAX_TUPLE_LENGTH`.
+fn longest_common_tuple(samples: &[u8]) -> usize {
+    (1..=MAX_TUPLE_LENGTH.min(samples.len()))
+        .take_while(|&length| {
+            tuple_counts(samples, length)
+                .values()
+                .any(|&count| count >= TUPLE_CUTOFF)
+        })
+        .last()
+        .unwrap_or(0)
+}
+
+/// t-Tuple estimate (800-90B 6.3.5) in bits per sample.
+/// Returns None if no value occurs at least 35 times.
+pub fn t_tuple_estimate(samples: &[u8]) -> Option<f64> {
+    let t = longest_common_tuple(samples);
+    if t == 0 {
+        return None;
+    }
+    let p_max = (1..=t)
+        .map(|length| {
+            let most_common = tuple_counts(samples, length)
+                .into_values()
+                .max()
+                .unwrap_or(0);
+            (most_common as f64 / (samples.len() - length + 1) as f64).powf(1.0 / length as f64)
+        })
+        .fold(0.0, f64::max);
+    Some(0.0 - upper_bound(p_max, samples.len()).log2())
+}
+
+/// Longest repeated substring estimate (800-90B 6.3.6) in bits per sample.
+/// Uses the tuples longer than those of the t-tuple estimate that still repeat.
+/// Returns None if there are no such tuples.
+pub fn lrs_estimate(samples: &[u8]) -> Option<f64> {
+    let first = longest_common_tuple(samples) + 1;
+    let pairs = |count: usize| (count * count.saturating_sub(1) / 2) as f64;
+    let mut p_max: Option<f64> = None;
+    for length in first..=MAX_TUPLE_LENGTH.min(samples.len()) {
+        let counts = tuple_counts(samples, length);
+        if counts.values().all(|&count| count < 2) {
+            break;
+        }
+        let collisions: f64 = counts.into_values().map(pairs).sum();
+        let p = (collisions / pairs(samples.len() - length + 1)).powf(1.0 / length as f64);
+        p_max = Some(p_max.map_or(p, |p_max| p_max.max(p)));
+    }
+    p_max.map(|p_max| 0.0 - upper_bound(p_max, samples.len()).log2())
+}
+
+/// Run every estimator on `bytes`, the estimates are in bits per byte.
+pub fn assess_bytes(bytes: &[u8]) -> Vec<EntropyEstimate> {
+    let bits = bytes_to_bits(bytes);
+    let per_byte = |estimate: Option<f64>| estimate.map(|entropy| 8.0 * entropy);
+    vec![
+        EntropyEstimate {
+            name: "Most common value",
+            min_entropy: most_common_value_estimate(bytes),
+        },
+        EntropyEstimate {
+            name: "Collision (bits)",
+            min_entropy: per_byte(collision_estimate(&bits)),
+        },
+        EntropyEstimate {
+            name: "Markov (bits)",
+            min_entropy: per_byte(markov_estimate(&bits)),
+        },
+        EntropyEstimate {
+            name: "Compression (bits)",
+            min_entropy: per_byte(compression_estimate(&bits)),
+        },
+        EntropyEstimate {
+            name: "t-Tuple",
+            min_entropy: t_tuple_estimate(bytes),
+        },
+        EntropyEstimate {
+            name: "LRS",
+            min_entropy: lrs_estimate(bytes),
+        },
+    ]
+}
+
+/// Smallest applicable estimate in bits per byte.
+pub fn min_entropy(estimates: &[EntropyEstimate]) -> Option<f64> {
+    estimates
+        .iter()
+        .filter_map(|estimate| estimate.min_entropy)
+        .reduce(f64::min)
+}
+
+/// Assess `bytes` and format the estimates as a table.
+pub fn format_entropy_report(bytes: &[u8]) -> String {
+    let estimates = assess_bytes(bytes);
+    let mut lines = vec![format!(
+        "Min-entropy estimates of {} bytes (NIST SP 800-90B, bits per byte):",
+        bytes.len()
+    )];
+    for estimate in &estimates {
+        lines.push(format!(
+            "{:<20}{}",
+            estimate.name,
+            match estimate.min_entropy {
+                Some(entropy) => format!("{:.6}", entropy),
+                None => "N/A".to_string(),
+            }
+        ));
+    }
+    lines.push(format!(
+        "{:<20}{}",
+        "Min-entropy",
+        match min_entropy(&estimates) {
+            Some(entropy) => format!("{:.6}", entropy),
+            None => "N/A".to_string(),
+        }
+    ));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rngs::{self, RNG};
+
+    fn random_bytes(count: usize) -> Vec<u8> {
+        let mut rng = rngs::xorshift::WyRand::new(3);
+        (0..count.div_ceil(8))
+            .flat_map(|_| rng.next().to_le_bytes())
+            .take(count)
+            .collect()
+    }
+
+    #[test]
+    fn estimators_random() {
+        let bytes = random_bytes(1 << 16);
+        let estimates = assess_bytes(&bytes);
+        for estimate in &estimates {
+            let entropy = estimate.min_entropy.unwrap();
+            assert!((6.0..=8.0).contains(&entropy), "{:?}", estimate);
+        }
+        assert!(most_common_value_estimate(&bytes).unwrap() > 7.5);
+        assert!(markov_estimate(&bytes_to_bits(&bytes)).unwrap() > 0.95);
+    }
+
+    #[test]
+    fn estimators_constant() {
+        let bytes = vec![0x55u8; 1 << 12];
+        let bits = bytes_to_bits(&bytes);
+        assert_eq!(most_common_value_estimate(&bytes), Some(0.0));
+        assert_eq!(t_tuple_estimate(&bytes), Some(0.0));
+        // The bits alternate, every transition of the Markov chain is certain.
+        assert!(markov_estimate(&bits).unwrap() < 0.1);
+        assert!(compression_estimate(&bits).unwrap() < 0.1);
+        assert!(min_entropy(&assess_bytes(&bytes)).unwrap() < 0.1);
+    }
+
+    #[test]
+    fn estimators_biased_bits() {
+        // Every bit is one with probability 3/4, 0.415 bits of min-entropy per bit.
+        let mut rng = rngs::xorshift::WyRand::new(5);
+        let bytes: Vec<u8> = (0..1 << 15)
+            .map(|_| {
+                let (a, b) = (rng.next(), rng.next());
+                ((a | b) & 0xff) as u8
+            })
+            .collect();
+        let bits = bytes_to_bits(&bytes);
+        let expected = -(0.75f64).log2();
+        for estimate in [
+            collision_estimate(&bits),
+            markov_estimate(&bits),
+            compression_estimate(&bits),
+        ] {
+            let estimate = estimate.unwrap();
+            assert!(
+                estimate < expected + 0.05 && estimate > expected - 0.2,
+                "{}",
+                estimate
+            );
+        }
+    }
+
+    #[test]
+    fn estimators_short_data() {
+        assert_eq!(most_common_value_estimate(&[1]), None);
+        assert_eq!(compression_estimate(&bytes_to_bits(&[0; 100])), None);
+        assert_eq!(t_tuple_estimate(&random_bytes(256)), None);
+        assert!(min_entropy(&assess_bytes(&[])).is_none());
+    }
+}
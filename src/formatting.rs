@@ -0,0 +1,182 @@
+// Copyright 2025 N. Dornseif
+//
+// Dual-licensed under Apache 2.0 and MIT terms.
+
+//! Human readable formatting of durations and byte counts.
+//! Output is pure ASCII unless unicode output is enabled,
+//! in which case microseconds are displayed as "µs" instead of "us".
+
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+/// Number of decimal digits displayed for durations.
+const DURATION_DECIMAL_DIGITS: usize = 4;
+/// Width of the numeric part of aligned durations.
+/// Six integer digits fit durations below 10^6 s, about 11.5 days.
+const DURATION_WIDTH: usize = DURATION_DECIMAL_DIGITS + 7;
+/// Width of the numeric part of aligned byte counts.
+const BYTE_COUNT_WIDTH: usize = 7;
+
+static UNICODE_OUTPUT: AtomicBool = AtomicBool::new(false);
+
+/// Enable or disable non-ASCII characters in formatted output.
+pub fn set_unicode_output(enabled: bool) {
+    UNICODE_OUTPUT.store(enabled, Ordering::Relaxed);
+}
+
+/// Check if non-ASCII characters are allowed in formatted output.
+pub fn unicode_output() -> bool {
+    UNICODE_OUTPUT.load(Ordering::Relaxed)
+}
+
+/// Scale a duration to a fitting unit.
+/// The value is rounded down to `DURATION_DECIMAL_DIGITS` decimal places.
+fn scale_duration(duration: Duration, unicode: bool) -> (f64, &'static str) {
+    let round_mul: f64 = 10.0_f64.powi(DURATION_DECIMAL_DIGITS as i32);
+    let secs = duration.as_secs_f64();
+    let (value, unit) = if secs >= 1.0 {
+        (secs, "s")
+    } else if secs >= 1e-3 {
+        (secs * 1e3, "ms")
+    } else if secs >= 1e-6 {
+        (secs * 1e6, if unicode { "µs" } else { "us" })
+    } else {
+        (secs * 1e9, "ns")
+    };
+    ((value * round_mul).floor() / round_mul, unit)
+}
+
+/// Scale a byte count to a fitting binary unit.
+fn scale_byte_count(num_bytes: usize) -> (f64, &'static str) {
+    // 2**30 = 1073741824
+    if num_bytes >= 1073741824 {
+        (num_bytes as f64 / 1073741824.0, "GiB")
+    // 2**20 = 1048576
+    } else if num_bytes >= 1048576 {
+        (num_bytes as f64 / 1048576.0, "MiB")
+    // 2**10 = 1024
+    } else if num_bytes >= 1024 {
+        (num_bytes as f64 / 1024.0, "KiB")
+    } else {
+        (num_bytes as f64, "B")
+    }
+}
+
+/// Format a duration into a pretty String.
+/// e.g. 1.5 ms
+pub fn format_duration(duration: Duration) -> String {
+    let (value, unit) = scale_duration(duration, unicode_output());
+    format!("{} {}", value, unit)
+}
+
+/// Format a duration to a fixed width.
+/// Suitable for table columns.
+pub fn format_duration_aligned(duration: Duration) -> String {
+    let (value, unit) = scale_duration(duration, unicode_output());
+    format!("{:<2$} {:<2}", value, unit, DURATION_WIDTH)
+}
+
+/// Format a number of bytes into a pretty String.
+/// e.g. 1048576 is 1.00 MiB
+pub fn format_byte_count(num_bytes: usize) -> String {
+    let (value, unit) = scale_byte_count(num_bytes);
+    format!("{:.2} {}", value, unit)
+}
+
+/// Format a number of bytes to a fixed width.
+/// Suitable for table columns.
+pub fn format_byte_count_aligned(num_bytes: usize) -> String {
+    let (value, unit) = scale_byte_count(num_bytes);
+    format!("{:>2$.2} {:<3}", value, unit, BYTE_COUNT_WIDTH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DURATION_CASES: [(Duration, &str); 14] = [
+        (Duration::ZERO, "0 ns"),
+        (Duration::from_nanos(1), "1 ns"),
+        (Duration::from_nanos(999), "999 ns"),
+        (Duration::from_nanos(1_000), "1 us"),
+        (Duration::from_nanos(1_500), "1.5 us"),
+        (Duration::from_nanos(999_999), "999.999 us"),
+        (Duration::from_micros(1_000), "1 ms"),
+        (Duration::from_nanos(12_345_678), "12.3456 ms"),
+        (Duration::from_millis(1_000), "1 s"),
+        (Duration::from_millis(1_500), "1.5 s"),
+        (Duration::from_secs(3_600), "3600 s"),
+        (Duration::from_nanos(2_000_000_001), "2 s"),
+        (Duration::from_millis(1_234_500), "1234.5 s"),
+        (Duration::from_millis(999_999_500), "999999.5 s"),
+    ];
+
+    const BYTE_COUNT_CASES: [(usize, &str); 9] = [
+        (0, "0.00 B"),
+        (1, "1.00 B"),
+        (1023, "1023.00 B"),
+        (1024, "1.00 KiB"),
+        (1536, "1.50 KiB"),
+        (1048575, "1024.00 KiB"),
+        (1048576, "1.00 MiB"),
+        (1073741824, "1.00 GiB"),
+        (1073741824 / 2 * 5, "2.50 GiB"),
+    ];
+
+    #[test]
+    fn duration_formatting() {
+        for (duration, expected) in DURATION_CASES {
+            assert_eq!(format_duration(duration), expected);
+        }
+    }
+
+    #[test]
+    fn duration_formatting_aligned() {
+        let width = format_duration_aligned(Duration::ZERO).len();
+        for (duration, expected) in DURATION_CASES {
+            let formatted = format_duration_aligned(duration);
+            assert_eq!(formatted.len(), width, "{:?}", formatted);
+            assert_eq!(
+                formatted
+                    .split_whitespace()
+                    .collect::<Vec<&str>>()
+                    .join(" "),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn byte_count_formatting() {
+        for (num_bytes, expected) in BYTE_COUNT_CASES {
+            assert_eq!(format_byte_count(num_bytes), expected);
+        }
+    }
+
+    #[test]
+    fn byte_count_formatting_aligned() {
+        let width = format_byte_count_aligned(0).len();
+        for (num_bytes, expected) in BYTE_COUNT_CASES {
+            let formatted = format_byte_count_aligned(num_bytes);
+            assert_eq!(formatted.len(), width, "{:?}", formatted);
+            assert_eq!(
+                formatted
+                    .split_whitespace()
+                    .collect::<Vec<&str>>()
+                    .join(" "),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn ascii_and_unicode_units() {
+        for (duration, _) in DURATION_CASES {
+            assert!(scale_duration(duration, false).1.is_ascii());
+        }
+        assert_eq!(scale_duration(Duration::from_micros(5), false), (5.0, "us"));
+        assert_eq!(scale_duration(Duration::from_micros(5), true), (5.0, "µs"));
+    }
+}
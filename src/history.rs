@@ -0,0 +1,558 @@
+// Copyright 2025 N. Dornseif
+//
+// Dual-licensed under Apache 2.0 and MIT terms.
+
+//! Local history of test suite runs, stored as JSON lines.
+//! Every run appends one line per generator with a summary of each test,
+//! runs are grouped by generator, battery and sample size to render trends over time.
+
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+};
+
+use crate::{
+    report::{self, SuiteReport, TestRecord, Verdict},
+    scoring, strings,
+};
+
+/// Characters of the trend lines, from low to high mean p log stat.
+const TREND_LEVELS: &[u8] = b" .:-=+*#%@";
+
+/// Summary of one test over all seeds of a run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestSummary {
+    pub name: String,
+    /// Number of applicable results.
+    pub runs: usize,
+    pub failed: usize,
+    /// Mean p log stat of the applicable results.
+    pub mean_logstat: f64,
+}
+
+/// Summary of one generator in one run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryEntry {
+    /// UTC time of the run.
+    pub timestamp: String,
+    /// Crate version that produced the run.
+    pub version: String,
+    pub rng_name: String,
+    /// Identifies the set of tests, changes whenever tests are added, removed or renamed.
+    pub battery: String,
+    pub sample_size: usize,
+    pub passed: bool,
+    pub tests: Vec<TestSummary>,
+}
+
+/// FNV-1a hash of the test names of the battery.
+pub fn battery_id() -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for name in strings::TEST_NAMES.iter().chain([
+        &strings::SEED_AVALANCHE_NAME,
+        &strings::SPLIT_CORRELATION_NAME,
+        &strings::SECOND_LEVEL_NAME,
+    ]) {
+        for byte in name.bytes().chain(std::iter::once(b',')) {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+    format!("{:016x}", hash)
+}
+
+impl HistoryEntry {
+    /// Summarize a suite report, tests in order of their first result.
+    pub fn from_report(suite: &SuiteReport, timestamp: &str) -> Self {
+        let mut tests: Vec<TestSummary> = vec![];
+        let records = suite
+            .seeds
+            .iter()
+            .flat_map(|seed_report| seed_report.results.iter())
+            .chain(suite.generator_results.iter());
+        for record in records {
+            let index = match tests.iter().position(|test| test.name == record.name) {
+                Some(index) => index,
+                None => {
+                    tests.push(TestSummary {
+                        name: record.name.to_string(),
+                        runs: 0,
+                        failed: 0,
+                        mean_logstat: 0.0,
+                    });
+                    tests.len() - 1
+                }
+            };
+            add_record(&mut tests[index], record);
+        }
+        HistoryEntry {
+            timestamp: timestamp.to_string(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            rng_name: suite.rng_name.clone(),
+            battery: battery_id(),
+            sample_size: suite.sample_size,
+            passed: suite.passed,
+            tests,
+        }
+    }
+
+    /// Runs with the same key are comparable.
+    fn key(&self) -> (&str, &str, usize) {
+        (&self.rng_name, &self.battery, self.sample_size)
+    }
+
+    pub fn to_json_line(&self) -> String {
+        let tests: Vec<String> = self
+            .tests
+            .iter()
+            .map(|test| {
+                format!(
+                    "{{\"name\": {}, \"runs\": {}, \"failed\": {}, \"mean_pls\": {:.4}}}",
+                    report::json_string(&test.name),
+                    test.runs,
+                    test.failed,
+                    test.mean_logstat
+                )
+            })
+            .collect();
+        format!(
+            "{{\"timestamp\": {}, \"version\": {}, \"rng\": {}, \"battery\": {}, \"sample_size\": {}, \"passed\": {}, \"tests\": [{}]}}",
+            report::json_string(&self.timestamp),
+            report::json_string(&self.version),
+            report::json_string(&self.rng_name),
+            report::json_string(&self.battery),
+            self.sample_size,
+            self.passed,
+            tests.join(", ")
+        )
+    }
+
+    pub fn from_json_line(line: &str) -> Result<Self, String> {
+        let value = json::parse(line)?;
+        let tests = value
+            .field("tests")?
+            .as_array()?
+            .iter()
+            .map(|test| {
+                Ok(TestSummary {
+                    name: test.field("name")?.as_str()?.to_string(),
+                    runs: test.field("runs")?.as_number()? as usize,
+                    failed: test.field("failed")?.as_number()? as usize,
+                    mean_logstat: test.field("mean_pls")?.as_number()?,
+                })
+            })
+            .collect::<Result<Vec<TestSummary>, String>>()?;
+        Ok(HistoryEntry {
+            timestamp: value.field("timestamp")?.as_str()?.to_string(),
+            version: value.field("version")?.as_str()?.to_string(),
+            rng_name: value.field("rng")?.as_str()?.to_string(),
+            battery: value.field("battery")?.as_str()?.to_string(),
+            sample_size: value.field("sample_size")?.as_number()? as usize,
+            passed: value.field("passed")?.as_bool()?,
+            tests,
+        })
+    }
+}
+
+fn add_record(test: &mut TestSummary, record: &TestRecord) {
+    let Some(p) = record.p else {
+        return;
+    };
+    test.mean_logstat =
+        (test.mean_logstat * test.runs as f64 + scoring::p_log_stat(p)) / (test.runs + 1) as f64;
+    test.runs += 1;
+    if matches!(record.verdict, Verdict::Fail | Verdict::ExpectedFail) {
+        test.failed += 1;
+    }
+}
+
+/// Append one line per report to the history file at `path`.
+pub fn append_history(path: &str, reports: &[SuiteReport]) -> Result<(), String> {
+    let timestamp = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
+    let lines: String = reports
+        .iter()
+        .map(|suite| HistoryEntry::from_report(suite, &timestamp).to_json_line() + "\n")
+        .collect();
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(lines.as_bytes()))
+        .map_err(|e| format!("Could not append to history '{}': {}", path, e))
+}
+
+/// Read all entries of the history file at `path`, empty lines are skipped.
+pub fn read_history(path: &str) -> Result<Vec<HistoryEntry>, String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| format!("Could not read history '{}': {}", path, e))?;
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(number, line)| {
+            HistoryEntry::from_json_line(line)
+                .map_err(|e| format!("{}:{}: {}", path, number + 1, e))
+        })
+        .collect()
+}
+
+/// Map a mean p log stat to a trend character.
+fn trend_char(mean_logstat: f64) -> char {
+    let level = (mean_logstat.max(0.0) as usize).min(TREND_LEVELS.len() - 1);
+    TREND_LEVELS[level] as char
+}
+
+/// Render the trends of all entries, optionally restricted to one generator.
+/// Entries are grouped by generator, battery and sample size in order of first appearance.
+/// Each group lists its runs, followed by one line per test with a character per run
+/// for the mean p log stat, from ' ' below 1 to '@' at 9 and above,
+/// '?' if the test did not run or was not applicable.
+pub fn format_trends(entries: &[HistoryEntry], rng_name: Option<&str>) -> String {
+    let entries: Vec<&HistoryEntry> = entries
+        .iter()
+        .filter(|entry| rng_name.is_none_or(|name| entry.rng_name.eq_ignore_ascii_case(name)))
+        .collect();
+    let mut keys: Vec<(&str, &str, usize)> = vec![];
+    for entry in &entries {
+        if !keys.contains(&entry.key()) {
+            keys.push(entry.key());
+        }
+    }
+    let mut sections: Vec<String> = vec![];
+    for key in keys {
+        let runs: Vec<&&HistoryEntry> = entries.iter().filter(|e| e.key() == key).collect();
+        let mut lines = vec![format!(
+            "{} (battery {}, {} u64s per seed)",
+            key.0, key.1, key.2
+        )];
+        for (index, run) in runs.iter().enumerate() {
+            let failed: usize = run.tests.iter().map(|test| test.failed).sum();
+            lines.push(format!(
+                "  {:>3}  {}  v{:<8} {:<8} {} failed",
+                index + 1,
+                run.timestamp,
+                run.version,
+                if run.passed { "passed" } else { "failed" },
+                failed
+            ));
+        }
+        let mut names: Vec<&str> = vec![];
+        for run in &runs {
+            for test in &run.tests {
+                if !names.contains(&test.name.as_str()) {
+                    names.push(&test.name);
+                }
+            }
+        }
+        for name in names {
+            let trend: String = runs
+                .iter()
+                .map(|run| {
+                    run.tests
+                        .iter()
+                        .find(|test| test.name == name && test.runs > 0)
+                        .map_or('?', |test| trend_char(test.mean_logstat))
+                })
+                .collect();
+            lines.push(format!("  {:<10} |{}|", name, trend));
+        }
+        sections.push(lines.join("\n"));
+    }
+    if sections.is_empty() {
+        return "No history entries".to_string();
+    }
+    sections.join("\n\n")
+}
+
+/// Minimal JSON reader for the history lines.
+mod json {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Value {
+        Null,
+        Bool(bool),
+        Number(f64),
+        String(String),
+        Array(Vec<Value>),
+        Object(Vec<(String, Value)>),
+    }
+
+    impl Value {
+        pub fn field(&self, name: &str) -> Result<&Value, String> {
+            match self {
+                Value::Object(fields) => fields
+                    .iter()
+                    .find(|(key, _)| key == name)
+                    .map(|(_, value)| value)
+                    .ok_or_else(|| format!("Missing field '{}'", name)),
+                _ => Err(format!("Expected an object with field '{}'", name)),
+            }
+        }
+        pub fn as_str(&self) -> Result<&str, String> {
+            match self {
+                Value::String(value) => Ok(value),
+                _ => Err(format!("Expected a string, found {:?}", self)),
+            }
+        }
+        pub fn as_number(&self) -> Result<f64, String> {
+            match self {
+                Value::Number(value) => Ok(*value),
+                _ => Err(format!("Expected a number, found {:?}", self)),
+            }
+        }
+        pub fn as_bool(&self) -> Result<bool, String> {
+            match self {
+                Value::Bool(value) => Ok(*value),
+                _ => Err(format!("Expected a bool, found {:?}", self)),
+            }
+        }
+        pub fn as_array(&self) -> Result<&[Value], String> {
+            match self {
+                Value::Array(values) => Ok(values),
+                _ => Err(format!("Expected an array, found {:?}", self)),
+            }
+        }
+    }
+
+    /// Parse a single JSON value, trailing content is an error.
+    pub fn parse(text: &str) -> Result<Value, String> {
+        let mut parser = Parser {
+            chars: text.chars().collect(),
+            position: 0,
+        };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        if parser.position != parser.chars.len() {
+            return Err(format!("Trailing characters at {}", parser.position));
+        }
+        Ok(value)
+    }
+
+    struct Parser {
+        chars: Vec<char>,
+        position: usize,
+    }
+
+    impl Parser {
+        fn skip_whitespace(&mut self) {
+            while self
+                .chars
+                .get(self.position)
+                .is_some_and(|c| c.is_whitespace())
+            {
+                self.position += 1;
+            }
+        }
+
+        fn expect(&mut self, expected: char) -> Result<(), String> {
+            self.skip_whitespace();
+            if self.chars.get(self.position) == Some(&expected) {
+                self.position += 1;
+                Ok(())
+            } else {
+                Err(format!("Expected '{}' at {}", expected, self.position))
+            }
+        }
+
+        fn literal(&mut self, literal: &str, value: Value) -> Result<Value, String> {
+            for expected in literal.chars() {
+                if self.chars.get(self.position) != Some(&expected) {
+                    return Err(format!("Invalid literal at {}", self.position));
+                }
+                self.position += 1;
+            }
+            Ok(value)
+        }
+
+        fn value(&mut self) -> Result<Value, String> {
+            self.skip_whitespace();
+            match self.chars.get(self.position) {
+                Some('{') => self.object(),
+                Some('[') => self.array(),
+                Some('"') => self.string().map(Value::String),
+                Some('t') => self.literal("true", Value::Bool(true)),
+                Some('f') => self.literal("false", Value::Bool(false)),
+                Some('n') => self.literal("null", Value::Null),
+                Some(_) => self.number(),
+                None => Err("Unexpected end of input".to_string()),
+            }
+        }
+
+        fn object(&mut self) -> Result<Value, String> {
+            self.expect('{')?;
+            let mut fields = vec![];
+            self.skip_whitespace();
+            if self.chars.get(self.position) == Some(&'}') {
+                self.position += 1;
+                return Ok(Value::Object(fields));
+            }
+            loop {
+                self.skip_whitespace();
+                let key = self.string()?;
+                self.expect(':')?;
+                fields.push((key, self.value()?));
+                self.skip_whitespace();
+                match self.chars.get(self.position) {
+                    Some(',') => self.position += 1,
+                    Some('}') => {
+                        self.position += 1;
+                        return Ok(Value::Object(fields));
+                    }
+                    _ => return Err(format!("Expected ',' or '}}' at {}", self.position)),
+                }
+            }
+        }
+
+        fn array(&mut self) -> Result<Value, String> {
+            self.expect('[')?;
+            let mut values = vec![];
+            self.skip_whitespace();
+            if self.chars.get(self.position) == Some(&']') {
+                self.position += 1;
+                return Ok(Value::Array(values));
+            }
+            loop {
+                values.push(self.value()?);
+                self.skip_whitespace();
+                match self.chars.get(self.position) {
+                    Some(',') => self.position += 1,
+                    Some(']') => {
+                        self.position += 1;
+                        return Ok(Value::Array(values));
+                    }
+                    _ => return Err(format!("Expected ',' or ']' at {}", self.position)),
+                }
+            }
+        }
+
+        fn string(&mut self) -> Result<String, String> {
+            self.expect('"')?;
+            let mut value = String::new();
+            loop {
+                let c = *self.chars.get(self.position).ok_or("Unterminated string")?;
+                self.position += 1;
+                match c {
+                    '"' => return Ok(value),
+                    '\\' => {
+                        let escaped =
+                            *self.chars.get(self.position).ok_or("Unterminated string")?;
+                        self.position += 1;
+                        match escaped {
+                            'n' => value.push('\n'),
+                            't' => value.push('\t'),
+                            'r' => value.push('\r'),
+                            'u' => {
+                                let hex: String = self
+                                    .chars
+                                    .get(self.position..self.position + 4)
+                                    .ok_or("Truncated unicode escape")?
+                                    .iter()
+                                    .collect();
+                                self.position += 4;
+                                let code = u32::from_str_radix(&hex, 16)
+                                    .map_err(|e| format!("Invalid unicode escape: {}", e))?;
+                                value.push(char::from_u32(code).unwrap_or('\u{fffd}'));
+                            }
+                            other => value.push(other),
+                        }
+                    }
+                    c => value.push(c),
+                }
+            }
+        }
+
+        fn number(&mut self) -> Result<Value, String> {
+            let start = self.position;
+            while self
+                .chars
+                .get(self.position)
+                .is_some_and(|c| c.is_ascii_digit() || "+-.eE".contains(*c))
+            {
+                self.position += 1;
+            }
+            let text: String = self.chars[start..self.position].iter().collect();
+            text.parse::<f64>()
+                .map(Value::Number)
+                .map_err(|_| format!("Invalid number '{}' at {}", text, start))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::SeedReport;
+
+    fn example_report(p: f64) -> SuiteReport {
+        let record = |name: &'static str, p: Option<f64>, verdict: Verdict| TestRecord {
+            name,
+            p,
+            score: p,
+            verdict,
+        };
+        SuiteReport {
+            rng_name: "Wy\"Rand".to_string(),
+            sample_size: 512,
+            scoring: "pls",
+            bytes_per_second: 1e9,
+            seeds: vec![
+                SeedReport {
+                    seed: 1,
+                    results: vec![
+                        record("Mono", Some(p), Verdict::Pass),
+                        record("Perm", None, Verdict::NotApplicable),
+                    ],
+                },
+                SeedReport {
+                    seed: 2,
+                    results: vec![record("Mono", Some(0.0), Verdict::Fail)],
+                },
+            ],
+            generator_results: vec![record("SeedAval", Some(0.5), Verdict::Pass)],
+            second_level_results: vec![],
+            period: None,
+            metadata: None,
+            seek_consistent: None,
+            state_restorable: None,
+            weak_seeds: None,
+            passed: false,
+        }
+    }
+
+    #[test]
+    fn history_round_trip() {
+        let entry = HistoryEntry::from_report(&example_report(0.5), "2025-01-01T00:00:00Z");
+        assert_eq!(entry.tests.len(), 3);
+        assert_eq!(entry.tests[0].runs, 2);
+        assert_eq!(entry.tests[0].failed, 1);
+        // Mean of 0.4 and 9.9999.
+        assert!((entry.tests[0].mean_logstat - 5.19995).abs() < 1e-9);
+        assert_eq!(entry.tests[1].runs, 0);
+        let line = entry.to_json_line();
+        assert!(!line.contains('\n'));
+        let parsed = HistoryEntry::from_json_line(&line).unwrap();
+        assert_eq!(parsed.rng_name, "Wy\"Rand");
+        assert_eq!(parsed.tests[0].mean_logstat, 5.2);
+        assert_eq!(parsed.tests[2], entry.tests[2]);
+        assert!(HistoryEntry::from_json_line("{\"rng\": 1}").is_err());
+        assert!(HistoryEntry::from_json_line(&line[..line.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn history_trends() {
+        let mut entries = vec![
+            HistoryEntry::from_report(&example_report(0.5), "2025-01-01T00:00:00Z"),
+            HistoryEntry::from_report(&example_report(0.5), "2025-01-02T00:00:00Z"),
+        ];
+        let mut other_size = entries[0].clone();
+        other_size.sample_size = 1024;
+        entries.push(other_size);
+        entries[1].tests.retain(|test| test.name != "SeedAval");
+        let trends = format_trends(&entries, None);
+        assert!(trends.contains("  Mono       |++|"));
+        assert!(trends.contains("  SeedAval   | ?|"));
+        assert!(trends.contains("  Perm       |??|"));
+        assert!(trends.contains("1024 u64s per seed"));
+        assert_eq!(format_trends(&entries, Some("MMIX")), "No history entries");
+        assert_eq!(battery_id(), battery_id());
+    }
+}
@@ -0,0 +1,157 @@
+// Copyright 2025 N. Dornseif
+//
+// Dual-licensed under Apache 2.0 and MIT terms.
+
+//! Quick look at a single generator without running the full test suite.
+
+use crate::{analysis, formatting, rngs::RNG, stats};
+
+/// Number of outputs printed in hex.
+const HEX_OUTPUTS: usize = 8;
+/// Number of outputs rendered in the bitmap, one per row.
+const BITMAP_OUTPUTS: usize = 16;
+/// Number of u64s used for the summary statistics.
+const STATISTICS_SAMPLE_SIZE: usize = 1 << 16;
+/// Maximum number of outputs searched for a cycle of the output.
+const OUTPUT_CYCLE_BUDGET: u64 = 1 << 20;
+/// Maximum number of outputs searched for the warm-up recommendation.
+const WARMUP_SEARCH_OUTPUTS: usize = 1024;
+
+/// Render outputs as rows of 64 bits, MSB first.
+fn format_bitmap(outputs: &[u64]) -> String {
+    let (one, zero) = if formatting::unicode_output() {
+        ('█', '·')
+    } else {
+        ('#', '.')
+    };
+    outputs
+        .iter()
+        .map(|&output| {
+            (0..64)
+                .rev()
+                .map(|bit| if (output >> bit) & 1 == 1 { one } else { zero })
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Format `ent` style summary statistics.
+fn format_byte_statistics(statistics: &analysis::ByteStatistics, num_bytes: usize) -> String {
+    let pi = std::f64::consts::PI;
+    [
+        format!("Summary of {}:", formatting::format_byte_count(num_bytes)),
+        format!("Entropy: {:.6} bits per byte", statistics.entropy),
+        format!(
+            "Chi square: {:.2} (p: {:.6})",
+            statistics.chi_squared,
+            statrs::function::gamma::gamma_ur(255.0 / 2.0, statistics.chi_squared / 2.0)
+        ),
+        format!("Arithmetic mean: {:.4} (127.5 = random)", statistics.mean),
+        format!(
+            "Monte Carlo value for pi: {:.9} (error {:.2}%)",
+            statistics.monte_carlo_pi,
+            (statistics.monte_carlo_pi - pi).abs() / pi * 100.0
+        ),
+        match statistics.serial_correlation {
+            Some(correlation) => format!(
+                "Serial correlation coefficient: {:.6} (0.0 = uncorrelated)",
+                correlation
+            ),
+            None => "Serial correlation coefficient: undefined (all bytes equal)".to_string(),
+        },
+    ]
+    .join("\n")
+}
+
+/// Build a quick overview of generator `R` seeded with `seed`.
+/// Shows the first outputs in hex and as a bitmap, summary statistics, correlated bit positions, Monte Carlo estimates,
+/// the cycle of the output if it is short, the state size and a recommended number of outputs to discard after seeding.
+pub fn inspect_rng<R: RNG>(seed: u64) -> String {
+    let mut rng = R::new(seed);
+    let outputs: Vec<u64> = (0..HEX_OUTPUTS.max(BITMAP_OUTPUTS))
+        .map(|_| rng.next())
+        .collect();
+    let mut lines: Vec<String> = vec![format!("Seed: {:#018x}", seed)];
+    lines.push(format!(
+        "State size: {} bytes, native output: {} bits",
+        std::mem::size_of::<R>(),
+        rng.native_bits()
+    ));
+    lines.push(format!("First {} outputs:", HEX_OUTPUTS));
+    lines.extend(
+        outputs[..HEX_OUTPUTS]
+            .iter()
+            .map(|output| format!("{:#018x}", output)),
+    );
+    lines.push(format!(
+        "Bitmap of the first {} outputs (MSB left):",
+        BITMAP_OUTPUTS
+    ));
+    lines.push(format_bitmap(&outputs[..BITMAP_OUTPUTS]));
+    let (test_data, _) = stats::generate_test_data(&mut R::new(seed), STATISTICS_SAMPLE_SIZE);
+    lines.push(format_byte_statistics(
+        &analysis::byte_statistics(&test_data),
+        STATISTICS_SAMPLE_SIZE * 8,
+    ));
+    lines.push(analysis::bit_column_correlations(&test_data).format());
+    lines.push(analysis::format_monte_carlo_estimates(
+        &analysis::monte_carlo_estimates(&test_data),
+    ));
+    lines.push(
+        match analysis::find_output_cycle(|| Box::new(R::new(seed)), OUTPUT_CYCLE_BUDGET) {
+            Some(cycle) => format!(
+                "Output cycle: period {} after {} outputs",
+                cycle.period, cycle.preperiod
+            ),
+            None => format!("Output cycle: none within {} outputs", OUTPUT_CYCLE_BUDGET),
+        },
+    );
+    lines.push(
+        match analysis::warmup_recommendation::<R>(seed, WARMUP_SEARCH_OUTPUTS) {
+            Some(0) => {
+                "Warm-up: not needed, outputs are independent of single seed bits".to_string()
+            }
+            Some(outputs) => format!("Warm-up: discard the first {} outputs", outputs),
+            None => format!(
+                "Warm-up: outputs still depend on single seed bits after {} outputs",
+                WARMUP_SEARCH_OUTPUTS
+            ),
+        },
+    );
+    lines.join("\n")
+}
+
+/// Write the correlation matrix of the 64 output bit positions of `rng` as a .ppm heatmap,
+/// measured on the same number of outputs as the summary statistics.
+#[cfg(feature = "images")]
+pub fn write_correlation_heatmap(rng: &mut dyn RNG, file_path: &str) -> std::io::Result<()> {
+    let (test_data, _) = stats::generate_test_data(rng, STATISTICS_SAMPLE_SIZE);
+    analysis::bit_column_correlations(&test_data).write_heatmap(file_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rngs;
+
+    #[test]
+    fn bitmap_rendering() {
+        assert_eq!(
+            format_bitmap(&[0x8000000000000001, 0]),
+            format!("#{}#\n{}", ".".repeat(62), ".".repeat(64))
+        );
+    }
+
+    #[test]
+    fn inspect_report() {
+        let report = inspect_rng::<rngs::lcg::Randu>(7);
+        assert!(report.starts_with("Seed: 0x0000000000000007"));
+        assert!(report.contains("State size: 4 bytes, native output: 31 bits"));
+        assert!(report.contains("Bit column correlations:"));
+        assert!(report.contains("Monte Carlo pi: "));
+        assert!(report.contains("Output cycle: none within"));
+        let zero_state = inspect_rng::<rngs::xorshift::XORShift128>(0);
+        assert!(zero_state.contains("Output cycle: period 1 after 0 outputs"));
+    }
+}
@@ -0,0 +1,44 @@
+// Copyright 2025 N. Dornseif
+//
+// Dual-licensed under Apache 2.0 and MIT terms.
+
+//! Collection of PRNGS and methods for statistical analysis.
+//! The generators in `rngs` only depend on the standard library and bnum,
+//! everything else is behind cargo features, see the README.
+
+#[cfg(feature = "suite")]
+pub mod analysis;
+pub mod conditioning;
+#[cfg(feature = "suite")]
+pub mod config;
+pub mod entropy;
+pub mod formatting;
+#[cfg(feature = "json")]
+pub mod history;
+#[cfg(feature = "suite")]
+pub mod inspect;
+#[cfg(feature = "mmap")]
+pub mod mapped;
+#[cfg(feature = "suite")]
+pub mod pareto;
+pub mod recovery;
+#[cfg(feature = "suite")]
+pub mod report;
+#[cfg(feature = "suite")]
+pub mod rng_testing;
+pub mod rngs;
+#[cfg(feature = "suite")]
+pub mod scoring;
+pub mod spectral;
+#[cfg(feature = "suite")]
+pub mod stats;
+#[cfg(feature = "suite")]
+mod strings;
+#[cfg(feature = "suite")]
+pub mod sweep;
+#[cfg(feature = "suite")]
+pub mod template;
+pub mod testdata;
+pub mod utils;
+#[cfg(feature = "suite")]
+pub mod validation;
@@ -2,57 +2,545 @@
 //
 // Dual-licensed under Apache 2.0 and MIT terms.
 
-//! Collection of PRNGS and methods for statistical analysis.
+//! Command line interface of the test suite.
 
-pub mod conditioning;
+use pearlacid::{
+    config, entropy, formatting, history, inspect, mapped, pareto, recovery, report, rng_testing,
+    rngs, scoring, spectral, sweep, validation,
+};
+use rng_testing::{test_suite, test_suite_with_seeds, ExpectedFailures};
+use rngs::RNG;
+use std::io::Read;
 
-pub mod rng_testing;
-pub mod rngs;
-pub mod stats;
-mod strings;
-pub mod testdata;
-pub mod utils;
+/// Generator that can be selected for the default test run.
+struct RegisteredRNG {
+    name: &'static str,
+    /// Runs the test suite with the supplied sample buffer, sample size, name and expected failures.
+    run_suite: fn(&mut Vec<u64>, usize, &str, &ExpectedFailures) -> report::SuiteReport,
+    /// Runs the test suite on one bit plane, see `rng_testing::bit_plane_suite`.
+    run_bit_plane_suite:
+        fn(&mut Vec<u64>, usize, u32, &str, &ExpectedFailures) -> report::SuiteReport,
+    /// Runs the test suite on every k-th output, see `rng_testing::decimated_suite`.
+    run_decimated_suite:
+        fn(&mut Vec<u64>, usize, u64, &str, &ExpectedFailures) -> report::SuiteReport,
+    /// Runs the test suite on each of k interleaved sub-streams, see `rng_testing::leapfrog_suite`.
+    run_leapfrog_suite:
+        fn(&mut Vec<u64>, usize, u64, &str, &ExpectedFailures) -> Vec<report::SuiteReport>,
+    /// Runs the test suite on a derived stream, see `rng_testing::view_suite`.
+    run_view_suite: fn(
+        &mut Vec<u64>,
+        usize,
+        rngs::adapters::StreamView,
+        &str,
+        &ExpectedFailures,
+    ) -> report::SuiteReport,
+    /// Tests the generator is known to fail.
+    expected_failures: ExpectedFailures,
+    /// Returns a quick overview of the generator for the supplied seed.
+    inspect: fn(u64) -> String,
+}
 
-use rng_testing::{test_suite, test_suite_with_seeds};
-use rngs::RNG;
+/// Runs the test suite with the default seeds.
+fn run_suite<R: RNG + Clone + PartialEq>(
+    buffer: &mut Vec<u64>,
+    size: usize,
+    name: &str,
+    expected: &ExpectedFailures,
+) -> report::SuiteReport {
+    test_suite(&mut R::new(0), buffer, size, name, expected)
+}
+
+/// Runs the test suite of a test generator, which ignores the seed,
+/// so a single seed is tested and weak seeds are not searched.
+fn run_test_generator_suite<R: RNG + Clone + PartialEq>(
+    buffer: &mut Vec<u64>,
+    size: usize,
+    name: &str,
+    expected: &ExpectedFailures,
+) -> report::SuiteReport {
+    test_suite_with_seeds(&mut R::new(0), buffer, size, &[0], name, false, expected)
+}
+
+/// Builds `REGISTERED_RNGS` from the generator list of `pearlacid::registered_rngs`.
+/// Test generators are expected to fail every test.
+macro_rules! registered_rng_entries {
+    ($($(#[$attr:meta])* $name:literal => $rng:ty, $family:literal, $test_generator:literal;)*) => {
+        const REGISTERED_RNGS: &[RegisteredRNG] = &[
+            $($(#[$attr])* RegisteredRNG {
+                name: $name,
+                run_suite: if $test_generator {
+                    run_test_generator_suite::<$rng>
+                } else {
+                    run_suite::<$rng>
+                },
+                run_bit_plane_suite: rng_testing::bit_plane_suite::<$rng>,
+                run_decimated_suite: rng_testing::decimated_suite::<$rng>,
+                run_leapfrog_suite: rng_testing::leapfrog_suite::<$rng>,
+                run_view_suite: rng_testing::view_suite::<$rng>,
+                expected_failures: if $test_generator {
+                    ExpectedFailures::All
+                } else {
+                    ExpectedFailures::Nothing
+                },
+                inspect: inspect::inspect_rng::<$rng>,
+            },)*
+        ];
+    };
+}
+
+pearlacid::registered_rngs!(registered_rng_entries);
 
 fn main() {
     let start = std::time::Instant::now();
     const TEST_SIZE_EXPONENT: usize = 22;
     const TEST_SIZE: usize = 1 << TEST_SIZE_EXPONENT;
-    let mut r = rngs::ReferenceRand::new(0);
-    test_suite(&mut r, TEST_SIZE, "Reference");
-    let mut r = rngs::testgens::OnlyOne::new(0);
-    test_suite_with_seeds(&mut r, TEST_SIZE, &[0], "OnlyOnes", false);
-    let mut r = rngs::testgens::OnlyZero::new(0);
-    test_suite_with_seeds(&mut r, TEST_SIZE, &[0], "OnlyZero", false);
-    let mut r = rngs::testgens::AlternatingBlocks::new(0);
-    test_suite_with_seeds(&mut r, TEST_SIZE, &[0], "AlternatingBlocks", false);
-    let mut r = rngs::testgens::AlternatingBytes::new(0);
-    test_suite_with_seeds(&mut r, TEST_SIZE, &[0], "AlternatingBytes", false);
-    let mut r = rngs::testgens::AlternatingBits::new(0);
-    test_suite_with_seeds(&mut r, TEST_SIZE, &[0], "AlternatingBits", false);
-    let mut r = rngs::spn::RijndaelStream::new(0);
-    test_suite(&mut r, TEST_SIZE, "RijndaelStream");
-    let mut r = rngs::xorshift::RapidHashRNG::new(0);
-    test_suite(&mut r, TEST_SIZE, "RapidHashRNG");
-    let mut r = rngs::xorshift::RapidHashRNG2::new(0);
-    test_suite(&mut r, TEST_SIZE, "RapidHashRNG2");
-    let mut r = rngs::xorshift::WyRand::new(0);
-    test_suite(&mut r, TEST_SIZE, "WyRand");
-    let mut r = rngs::lcg::Lehmer64::new(0);
-    test_suite(&mut r, TEST_SIZE, "Lehmer64");
-    let mut r = rngs::lcg::Randu::new(0);
-    test_suite(&mut r, TEST_SIZE, "RANDU");
-    let mut r = rngs::lcg::Mmix::new(0);
-    test_suite(&mut r, TEST_SIZE, "MMIX");
-    let mut r = rngs::lcg::UlsLcg512::new(0);
-    test_suite(&mut r, TEST_SIZE, "UlsLcg512");
-    let mut r = rngs::lcg::UlsLcg512H::new(0);
-    test_suite(&mut r, TEST_SIZE, "UlsLcg512H");
-    let mut r = rngs::xorshift::XORShift128::new(0);
-    test_suite(&mut r, TEST_SIZE, "XORShift128");
-    let mut r = rngs::stream_nlarx::StreamNLARXu128::new(0);
-    test_suite(&mut r, TEST_SIZE, "StreamNLARXu128");
+    let args: Vec<String> = std::env::args().collect();
+    let unknown = config::unknown_args(&args);
+    if !unknown.is_empty() {
+        eprintln!("Unknown arguments: {:?}", unknown);
+        eprintln!(
+            "Valid flags: {:?}",
+            config::SWITCH_FLAGS
+                .iter()
+                .chain(config::VALUE_FLAGS.iter())
+                .collect::<Vec<&&str>>()
+        );
+        eprintln!("Commands: {:?}", config::COMMANDS);
+        std::process::exit(1);
+    }
+    formatting::set_unicode_output(config::has_flag(&args, config::UNICODE_FLAG));
+    match config::period_budget_log2(&args) {
+        Ok(Some(budget_log2)) => rng_testing::set_period_budget_log2(budget_log2),
+        Ok(None) => {}
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(1);
+        }
+    }
+    match config::scoring(&args) {
+        Ok(Some(selected)) => scoring::select(selected),
+        Ok(None) => {}
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(1);
+        }
+    }
+    match config::inspect_options(&args) {
+        Ok(Some(options)) => {
+            match REGISTERED_RNGS
+                .iter()
+                .find(|entry| entry.name.eq_ignore_ascii_case(&options.rng_name))
+            {
+                Some(entry) => println!("{}", (entry.inspect)(options.seed)),
+                None => {
+                    eprintln!("Unknown generator: {}", options.rng_name);
+                    std::process::exit(1);
+                }
+            }
+            if let Some(path) = options.heatmap_path {
+                let Some((_, _, factory)) = rngs::registry()
+                    .find(|(name, _, _)| name.eq_ignore_ascii_case(&options.rng_name))
+                else {
+                    eprintln!(
+                        "No heatmap for unregistered generator: {}",
+                        options.rng_name
+                    );
+                    std::process::exit(1);
+                };
+                match inspect::write_correlation_heatmap(factory(options.seed).as_mut(), &path) {
+                    Ok(()) => println!("Wrote bit correlation heatmap to {}", path),
+                    Err(e) => {
+                        eprintln!("Could not write {}: {}", path, e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            return;
+        }
+        Ok(None) => {}
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(1);
+        }
+    }
+    match config::history_options(&args) {
+        Ok(Some(options)) => {
+            match history::read_history(&options.path) {
+                Ok(entries) => println!(
+                    "{}",
+                    history::format_trends(&entries, options.rng_name.as_deref())
+                ),
+                Err(message) => {
+                    eprintln!("{}", message);
+                    std::process::exit(1);
+                }
+            }
+            return;
+        }
+        Ok(None) => {}
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(1);
+        }
+    }
+    match config::entropy_source(&args) {
+        Ok(Some(source)) => {
+            let bytes: Vec<u8> = match source {
+                config::EntropySource::File(path) => {
+                    let mut bytes: Vec<u8> = vec![];
+                    if let Err(e) = std::fs::File::open(&path).and_then(|file| {
+                        file.take(entropy::ENTROPY_SAMPLES as u64)
+                            .read_to_end(&mut bytes)
+                    }) {
+                        eprintln!("Could not read {}: {}", path, e);
+                        std::process::exit(1);
+                    }
+                    bytes
+                }
+                config::EntropySource::Rng { rng_name, seed } => {
+                    let Some((_, _, factory)) =
+                        rngs::registry().find(|(name, _, _)| name.eq_ignore_ascii_case(&rng_name))
+                    else {
+                        eprintln!("Unknown generator: {}", rng_name);
+                        std::process::exit(1);
+                    };
+                    let mut rng = factory(seed);
+                    (0..entropy::ENTROPY_SAMPLES.div_ceil(8))
+                        .flat_map(|_| rng.next().to_le_bytes())
+                        .take(entropy::ENTROPY_SAMPLES)
+                        .collect()
+                }
+            };
+            println!("{}", entropy::format_entropy_report(&bytes));
+            return;
+        }
+        Ok(None) => {}
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(1);
+        }
+    }
+    match config::export_options(&args) {
+        Ok(Some(options)) => {
+            let Some((name, _, factory)) =
+                rngs::registry().find(|(name, _, _)| name.eq_ignore_ascii_case(&options.rng_name))
+            else {
+                eprintln!("Unknown generator: {}", options.rng_name);
+                std::process::exit(1);
+            };
+            let header = mapped::SampleHeader {
+                rng_name: name.to_string(),
+                seed: options.seed,
+                sample_count: options.sample_count,
+            };
+            match mapped::write_mapped_samples(
+                &options.path,
+                factory(options.seed).as_mut(),
+                &header,
+            ) {
+                Ok(()) => println!(
+                    "Wrote {} samples of {} to {}",
+                    options.sample_count, name, options.path
+                ),
+                Err(message) => {
+                    eprintln!("{}", message);
+                    std::process::exit(1);
+                }
+            }
+            return;
+        }
+        Ok(None) => {}
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(1);
+        }
+    }
+    if config::has_flag(&args, config::EXTRACTION_BENCH_FLAG) {
+        println!("{}", rng_testing::extraction_benchmark(TEST_SIZE));
+        println!("Full program runtime: {:?}", start.elapsed());
+        return;
+    }
+    if config::has_flag(&args, config::LIST_RNGS_FLAG) {
+        println!("{}", rngs::format_registry());
+        return;
+    }
+    if config::has_flag(&args, config::CROSS_VALIDATE_FLAG) {
+        let (table, all_reproduced) = validation::cross_validate();
+        println!("{}", table);
+        println!("Full program runtime: {:?}", start.elapsed());
+        if !all_reproduced {
+            std::process::exit(1);
+        }
+        return;
+    }
+    match config::recover_state_seed(&args) {
+        Ok(Some(seed)) => {
+            println!("{}", recovery::format_recovery_report(seed));
+            println!("Full program runtime: {:?}", start.elapsed());
+            return;
+        }
+        Ok(None) => {}
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(1);
+        }
+    }
+    if config::has_flag(&args, config::SPECTRAL_FLAG) {
+        let reports: Vec<String> = spectral::crate_lcgs()
+            .iter()
+            .map(|(name, parameters)| spectral::format_spectral_report(name, parameters))
+            .collect();
+        println!("{}", reports.join("\n\n"));
+        println!("Full program runtime: {:?}", start.elapsed());
+        return;
+    }
+    let allowlist: Option<Vec<String>> = match config::rng_allowlist(&args) {
+        Ok(allowlist) => allowlist,
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(1);
+        }
+    };
+    if let Some(names) = &allowlist {
+        let unknown: Vec<&String> = names
+            .iter()
+            .filter(|name| {
+                !REGISTERED_RNGS
+                    .iter()
+                    .any(|entry| entry.name.eq_ignore_ascii_case(name))
+            })
+            .collect();
+        if !unknown.is_empty() {
+            eprintln!("Unknown generators: {:?}", unknown);
+            eprintln!(
+                "Registered generators: {:?}",
+                REGISTERED_RNGS
+                    .iter()
+                    .map(|entry| entry.name)
+                    .collect::<Vec<&str>>()
+            );
+            std::process::exit(1);
+        }
+    }
+    let report_order = match config::report_order(&args) {
+        Ok(order) => order,
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(1);
+        }
+    };
+    let bit_planes = match config::bit_planes(&args) {
+        Ok(planes) => planes,
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(1);
+        }
+    };
+    let strides = match config::decimation_strides(&args) {
+        Ok(strides) => strides,
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(1);
+        }
+    };
+    let leapfrog_streams = match config::leapfrog_streams(&args) {
+        Ok(stream_counts) => stream_counts,
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(1);
+        }
+    };
+    let views = match config::stream_views(&args) {
+        Ok(views) => views,
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(1);
+        }
+    };
+    let sweep_exponents = match config::sweep_exponents(&args) {
+        Ok(exponents) => exponents,
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(1);
+        }
+    };
+    let run_modes = [
+        bit_planes.is_some(),
+        strides.is_some(),
+        leapfrog_streams.is_some(),
+        views.is_some(),
+        sweep_exponents.is_some(),
+    ];
+    if run_modes.iter().filter(|&&selected| selected).count() > 1 {
+        eprintln!(
+            "Only one of {}, {}, {}, {} and {} can be used at a time",
+            config::BIT_PLANES_FLAG,
+            config::DECIMATE_FLAG,
+            config::LEAPFROG_FLAG,
+            config::VIEWS_FLAG,
+            config::SWEEP_FLAG
+        );
+        std::process::exit(1);
+    }
+    let template = match config::report_template(&args) {
+        Ok(template) => template,
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(1);
+        }
+    };
+    let mut reports: Vec<report::SuiteReport> = vec![];
+    let mut fingerprints: Vec<sweep::FailureFingerprint> = vec![];
+    // Shared by all generators, so the samples are only allocated once.
+    let mut buffer: Vec<u64> = Vec::with_capacity(TEST_SIZE);
+    for entry in REGISTERED_RNGS.iter() {
+        let selected = allowlist.as_ref().is_none_or(|names| {
+            names
+                .iter()
+                .any(|name| entry.name.eq_ignore_ascii_case(name))
+        });
+        if !selected {
+            continue;
+        }
+        let available = rngs::registry()
+            .find(|(name, _, _)| *name == entry.name)
+            .is_none_or(|(_, _, factory)| factory(0).is_available());
+        if !available {
+            println!(
+                "Skipping {}, it is not available on this machine",
+                entry.name
+            );
+            continue;
+        }
+        if let Some(exponents) = &sweep_exponents {
+            let mut sweep_reports: Vec<(u32, report::SuiteReport)> = vec![];
+            for &exponent in exponents {
+                let report = (entry.run_suite)(
+                    &mut buffer,
+                    1 << exponent,
+                    &format!("{}[2^{}]", entry.name, exponent),
+                    &entry.expected_failures,
+                );
+                sweep_reports.push((exponent, report));
+                let fingerprint =
+                    sweep::FailureFingerprint::from_reports(entry.name, &sweep_reports);
+                if fingerprint.all_failed() && exponent != exponents[exponents.len() - 1] {
+                    println!(
+                        "Every test of {} failed at 2^{} samples, skipping larger sizes",
+                        entry.name, exponent
+                    );
+                    break;
+                }
+            }
+            fingerprints.push(sweep::FailureFingerprint::from_reports(
+                entry.name,
+                &sweep_reports,
+            ));
+            reports.extend(sweep_reports.into_iter().map(|(_, report)| report));
+            continue;
+        }
+        if let Some(stream_counts) = &leapfrog_streams {
+            for &streams in stream_counts {
+                reports.extend((entry.run_leapfrog_suite)(
+                    &mut buffer,
+                    TEST_SIZE,
+                    streams,
+                    entry.name,
+                    &entry.expected_failures,
+                ));
+            }
+            continue;
+        }
+        match (&bit_planes, &strides, &views) {
+            (None, None, None) => reports.push((entry.run_suite)(
+                &mut buffer,
+                TEST_SIZE,
+                entry.name,
+                &entry.expected_failures,
+            )),
+            (None, None, Some(views)) => {
+                for &view in views {
+                    reports.push((entry.run_view_suite)(
+                        &mut buffer,
+                        TEST_SIZE,
+                        view,
+                        entry.name,
+                        &entry.expected_failures,
+                    ));
+                }
+            }
+            (None, Some(strides), _) => {
+                for &stride in strides {
+                    reports.push((entry.run_decimated_suite)(
+                        &mut buffer,
+                        TEST_SIZE,
+                        stride,
+                        entry.name,
+                        &entry.expected_failures,
+                    ));
+                }
+            }
+            (Some(planes), _, _) => {
+                let native_bits = rngs::registry()
+                    .find(|(name, _, _)| *name == entry.name)
+                    .map_or(64, |(_, _, factory)| factory(0).native_bits());
+                for selection in planes {
+                    let Some(plane) = selection.resolve(native_bits) else {
+                        println!(
+                            "Skipping bit plane {} of {}, it has {} native bits",
+                            selection, entry.name, native_bits
+                        );
+                        continue;
+                    };
+                    reports.push((entry.run_bit_plane_suite)(
+                        &mut buffer,
+                        TEST_SIZE,
+                        plane,
+                        entry.name,
+                        &entry.expected_failures,
+                    ));
+                }
+            }
+        }
+    }
+    if !fingerprints.is_empty() {
+        println!("\nlog2 of the smallest sample size at which each test failed:");
+        println!("{}", sweep::format_fingerprints(&fingerprints));
+    }
+    if reports.len() > 1 {
+        println!("\nSpeed and quality of all tested generators:");
+        println!("{}", pareto::format_pareto_front(&reports));
+    }
+    if let Some(history_path) = config::history_path(&args) {
+        if let Err(message) = history::append_history(&history_path, &reports) {
+            eprintln!("{}", message);
+            std::process::exit(1);
+        }
+    }
+    if let Some(json_path) = config::json_report_path(&args) {
+        if let Err(e) = std::fs::write(&json_path, report::reports_to_json(&reports, report_order))
+        {
+            eprintln!("Could not write JSON report '{}': {}", json_path, e);
+            std::process::exit(1);
+        }
+    }
+    if let Some(template) = template {
+        let rendered = match template.render(&report::order_reports(&reports, report_order)) {
+            Ok(rendered) => rendered,
+            Err(message) => {
+                eprintln!("Could not render template: {}", message);
+                std::process::exit(1);
+            }
+        };
+        match config::template_output_path(&args) {
+            Some(output_path) => {
+                if let Err(e) = std::fs::write(&output_path, rendered) {
+                    eprintln!("Could not write rendered template '{}': {}", output_path, e);
+                    std::process::exit(1);
+                }
+            }
+            None => print!("{}", rendered),
+        }
+    }
     println!("Full program runtime: {:?}", start.elapsed());
 }
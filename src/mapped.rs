@@ -0,0 +1,225 @@
+// Copyright 2025 N. Dornseif
+//
+// Dual-licensed under Apache 2.0 and MIT terms.
+
+//! Samples written to a memory-mapped file for external analyzers.
+//! A file in a tmpfs like `/dev/shm` acts as a shared memory region,
+//! so tools written in Python or C can map the same samples without copies.
+//!
+//! Layout, all values little endian:
+//!
+//! | Offset | Bytes | Field |
+//! |---|---|---|
+//! | 0 | 8 | Magic `PEARLACD` |
+//! | 8 | 4 | Format version |
+//! | 12 | 4 | Header size in bytes, the samples start here |
+//! | 16 | 8 | Number of u64 samples |
+//! | 24 | 8 | Seed |
+//! | 32 | 32 | Generator name, UTF-8 padded with zeros |
+//! | 64 | 8 * count | Samples as u64 |
+//!
+//! Mappings are page aligned, so the samples are aligned to 8 bytes.
+
+use std::fs::{File, OpenOptions};
+
+use memmap2::{Mmap, MmapMut};
+
+use crate::rngs::RNG;
+
+/// Identifies a mapped sample file.
+pub const MAGIC: [u8; 8] = *b"PEARLACD";
+/// Version of the layout, increased on incompatible changes.
+pub const FORMAT_VERSION: u32 = 1;
+/// Size of the header, a multiple of 8 so the samples stay aligned.
+pub const HEADER_BYTES: usize = 64;
+/// Space reserved for the generator name.
+const NAME_BYTES: usize = 32;
+
+/// Description of the samples in a mapped file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SampleHeader {
+    pub rng_name: String,
+    pub seed: u64,
+    pub sample_count: u64,
+}
+
+impl SampleHeader {
+    fn to_bytes(&self) -> Result<[u8; HEADER_BYTES], String> {
+        let name = self.rng_name.as_bytes();
+        if name.len() > NAME_BYTES {
+            return Err(format!(
+                "Generator name '{}' is longer than {} bytes",
+                self.rng_name, NAME_BYTES
+            ));
+        }
+        let mut bytes = [0u8; HEADER_BYTES];
+        bytes[0..8].copy_from_slice(&MAGIC);
+        bytes[8..12].copy_from_slice(&FORMAT_VERSION.to_le_bytes());
+        bytes[12..16].copy_from_slice(&(HEADER_BYTES as u32).to_le_bytes());
+        bytes[16..24].copy_from_slice(&self.sample_count.to_le_bytes());
+        bytes[24..32].copy_from_slice(&self.seed.to_le_bytes());
+        bytes[32..32 + name.len()].copy_from_slice(name);
+        Ok(bytes)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        if bytes.len() < HEADER_BYTES || bytes[0..8] != MAGIC {
+            return Err("Not a mapped sample file".to_string());
+        }
+        let field = |range: std::ops::Range<usize>| -> u64 {
+            let mut value = [0u8; 8];
+            value[..range.len()].copy_from_slice(&bytes[range]);
+            u64::from_le_bytes(value)
+        };
+        let version = field(8..12);
+        if version != FORMAT_VERSION as u64 {
+            return Err(format!("Unsupported format version {}", version));
+        }
+        let header_bytes = field(12..16);
+        if header_bytes != HEADER_BYTES as u64 {
+            return Err(format!("Unsupported header size {}", header_bytes));
+        }
+        let name = &bytes[32..HEADER_BYTES];
+        let name_length = name.iter().position(|&b| b == 0).unwrap_or(NAME_BYTES);
+        let rng_name = std::str::from_utf8(&name[..name_length])
+            .map_err(|e| format!("Invalid generator name: {}", e))?
+            .to_string();
+        Ok(SampleHeader {
+            rng_name,
+            seed: field(24..32),
+            sample_count: field(16..24),
+        })
+    }
+}
+
+/// Write `header.sample_count` outputs of `test_rng` to a memory-mapped file at `path`.
+/// The file is created or truncated and sized to fit the header and the samples.
+pub fn write_mapped_samples(
+    path: &str,
+    test_rng: &mut (impl RNG + ?Sized),
+    header: &SampleHeader,
+) -> Result<(), String> {
+    let header_bytes = header.to_bytes()?;
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)
+        .map_err(|e| format!("Could not create '{}': {}", path, e))?;
+    let file_size = header
+        .sample_count
+        .checked_mul(8)
+        .and_then(|size| size.checked_add(HEADER_BYTES as u64))
+        .ok_or_else(|| format!("Too many samples: {}", header.sample_count))?;
+    file.set_len(file_size)
+        .map_err(|e| format!("Could not resize '{}': {}", path, e))?;
+    // Safety: the file was just created by us, other processes must not truncate it.
+    let mut map = unsafe { MmapMut::map_mut(&file) }
+        .map_err(|e| format!("Could not map '{}': {}", path, e))?;
+    map[..HEADER_BYTES].copy_from_slice(&header_bytes);
+    for sample in map[HEADER_BYTES..].chunks_exact_mut(8) {
+        sample.copy_from_slice(&test_rng.next().to_le_bytes());
+    }
+    map.flush()
+        .map_err(|e| format!("Could not flush '{}': {}", path, e))
+}
+
+/// Read-only view of a mapped sample file.
+pub struct MappedSamples {
+    header: SampleHeader,
+    map: Mmap,
+}
+
+impl MappedSamples {
+    /// Map the file at `path` and validate its header and size.
+    pub fn open(path: &str) -> Result<Self, String> {
+        let file = File::open(path).map_err(|e| format!("Could not open '{}': {}", path, e))?;
+        // Safety: the file must not be truncated while it is mapped.
+        let map =
+            unsafe { Mmap::map(&file) }.map_err(|e| format!("Could not map '{}': {}", path, e))?;
+        let header = SampleHeader::from_bytes(&map)?;
+        let expected_size = header
+            .sample_count
+            .checked_mul(8)
+            .and_then(|size| size.checked_add(HEADER_BYTES as u64));
+        if expected_size != Some(map.len() as u64) {
+            return Err(format!(
+                "File size {} does not match {} samples",
+                map.len(),
+                header.sample_count
+            ));
+        }
+        Ok(MappedSamples { header, map })
+    }
+
+    pub fn header(&self) -> &SampleHeader {
+        &self.header
+    }
+
+    /// Iterate over the samples, decoded independently of the target endianness.
+    pub fn samples(&self) -> impl Iterator<Item = u64> + '_ {
+        self.map[HEADER_BYTES..].chunks_exact(8).map(|sample| {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(sample);
+            u64::from_le_bytes(bytes)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rngs::xorshift::WyRand;
+
+    fn temp_path(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("pearlacid-{}-{}", std::process::id(), name))
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[test]
+    fn mapped_round_trip() {
+        let path = temp_path("round-trip.bin");
+        let header = SampleHeader {
+            rng_name: "WyRand".to_string(),
+            seed: 7,
+            sample_count: 1000,
+        };
+        write_mapped_samples(&path, &mut WyRand::new(7), &header).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        assert_eq!(bytes.len(), HEADER_BYTES + 8000);
+        assert_eq!(&bytes[0..8], b"PEARLACD");
+        assert_eq!(&bytes[32..38], b"WyRand");
+        let mapped = MappedSamples::open(&path).unwrap();
+        assert_eq!(mapped.header(), &header);
+        let mut reference = WyRand::new(7);
+        assert!(mapped.samples().eq((0..1000).map(|_| reference.next())));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn invalid_files() {
+        let path = temp_path("invalid.bin");
+        let long_name = SampleHeader {
+            rng_name: "x".repeat(NAME_BYTES + 1),
+            seed: 0,
+            sample_count: 1,
+        };
+        assert!(write_mapped_samples(&path, &mut WyRand::new(0), &long_name).is_err());
+        std::fs::write(&path, [0u8; HEADER_BYTES]).unwrap();
+        assert!(MappedSamples::open(&path).is_err());
+        let header = SampleHeader {
+            rng_name: "WyRand".to_string(),
+            seed: 0,
+            sample_count: 4,
+        };
+        write_mapped_samples(&path, &mut WyRand::new(0), &header).unwrap();
+        let mut truncated = std::fs::read(&path).unwrap();
+        truncated.truncate(HEADER_BYTES + 24);
+        std::fs::write(&path, truncated).unwrap();
+        assert!(MappedSamples::open(&path).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
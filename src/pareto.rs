@@ -0,0 +1,197 @@
+// Copyright 2025 N. Dornseif
+//
+// Dual-licensed under Apache 2.0 and MIT terms.
+
+//! Speed versus quality trade-off across all tested generators.
+//! A generator is on the Pareto front if no other generator
+//! is at least as fast and fails at most as often, while being better in one of both.
+
+use crate::{
+    formatting,
+    report::{SuiteReport, Verdict},
+};
+
+/// Width of the plot area in characters.
+const PLOT_WIDTH: usize = 60;
+/// Height of the plot area in lines.
+const PLOT_HEIGHT: usize = 12;
+
+/// Speed and quality of a single generator.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParetoPoint {
+    pub rng_name: String,
+    pub bytes_per_second: f64,
+    /// Fraction of the applicable results that failed, including expected failures.
+    pub failure_rate: f64,
+}
+
+impl ParetoPoint {
+    pub fn from_report(report: &SuiteReport) -> Self {
+        let records = report
+            .seeds
+            .iter()
+            .flat_map(|seed_report| seed_report.results.iter())
+            .chain(report.generator_results.iter());
+        let (mut applicable, mut failed) = (0usize, 0usize);
+        for record in records {
+            match record.verdict {
+                Verdict::NotApplicable => continue,
+                Verdict::Fail | Verdict::ExpectedFail => failed += 1,
+                _ => {}
+            }
+            applicable += 1;
+        }
+        ParetoPoint {
+            rng_name: report.rng_name.clone(),
+            bytes_per_second: report.bytes_per_second,
+            failure_rate: if applicable == 0 {
+                0.0
+            } else {
+                failed as f64 / applicable as f64
+            },
+        }
+    }
+
+    fn dominates(&self, other: &ParetoPoint) -> bool {
+        self.bytes_per_second >= other.bytes_per_second
+            && self.failure_rate <= other.failure_rate
+            && (self.bytes_per_second > other.bytes_per_second
+                || self.failure_rate < other.failure_rate)
+    }
+}
+
+/// Check for each point if it is on the Pareto front.
+pub fn pareto_front(points: &[ParetoPoint]) -> Vec<bool> {
+    points
+        .iter()
+        .map(|point| !points.iter().any(|other| other.dominates(point)))
+        .collect()
+}
+
+/// Label of the point at `index` in the plot.
+fn point_label(index: usize) -> char {
+    const LABELS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+    LABELS.get(index).map_or('?', |&label| label as char)
+}
+
+/// Plot failure rate over speed, speed on a log scale.
+/// Points on the front are labeled with upper case letters in the table order, others with lower case.
+fn plot(points: &[ParetoPoint], on_front: &[bool]) -> String {
+    let log_speeds: Vec<f64> = points
+        .iter()
+        .map(|point| point.bytes_per_second.max(1.0).log10())
+        .collect();
+    let min_speed = log_speeds.iter().copied().fold(f64::INFINITY, f64::min);
+    let max_speed = log_speeds.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let max_failure_rate = points
+        .iter()
+        .map(|point| point.failure_rate)
+        .fold(0.0, f64::max)
+        .max(f64::MIN_POSITIVE);
+    let mut grid = vec![vec![' '; PLOT_WIDTH]; PLOT_HEIGHT];
+    for (index, (point, &log_speed)) in points.iter().zip(&log_speeds).enumerate() {
+        let x = if max_speed > min_speed {
+            ((log_speed - min_speed) / (max_speed - min_speed) * (PLOT_WIDTH - 1) as f64).round()
+        } else {
+            0.0
+        } as usize;
+        let y = ((1.0 - point.failure_rate / max_failure_rate) * (PLOT_HEIGHT - 1) as f64).round()
+            as usize;
+        let label = point_label(index);
+        let cell = &mut grid[y][x];
+        *cell = if *cell != ' ' {
+            '+'
+        } else if on_front[index] {
+            label
+        } else {
+            label.to_ascii_lowercase()
+        };
+    }
+    let mut lines: Vec<String> = grid
+        .iter()
+        .enumerate()
+        .map(|(row, cells)| {
+            let axis = if row == 0 {
+                format!("{:>6.1}%", max_failure_rate * 100.0)
+            } else if row == PLOT_HEIGHT - 1 {
+                format!("{:>6.1}%", 0.0)
+            } else {
+                " ".repeat(7)
+            };
+            format!("{} |{}", axis, cells.iter().collect::<String>())
+        })
+        .collect();
+    lines.push(format!("{} +{}", " ".repeat(7), "-".repeat(PLOT_WIDTH)));
+    let slowest = formatting::format_byte_count(10f64.powf(min_speed) as usize);
+    let fastest = formatting::format_byte_count(10f64.powf(max_speed) as usize);
+    lines.push(format!(
+        "{}{}/s{:>width$}/s",
+        " ".repeat(9),
+        slowest,
+        fastest,
+        width = PLOT_WIDTH.saturating_sub(slowest.len() + 2)
+    ));
+    lines.join("\n")
+}
+
+/// Table of all generators sorted by speed with their failure rate,
+/// followed by a plot of failure rate over speed.
+pub fn format_pareto_front(reports: &[SuiteReport]) -> String {
+    let mut points: Vec<ParetoPoint> = reports.iter().map(ParetoPoint::from_report).collect();
+    points.sort_by(|a, b| b.bytes_per_second.total_cmp(&a.bytes_per_second));
+    let on_front = pareto_front(&points);
+    let mut lines = vec![format!(
+        "   {:<20}{:>14}{:>14}  Pareto front",
+        "Name", "Speed", "Failed"
+    )];
+    for (index, (point, &front)) in points.iter().zip(&on_front).enumerate() {
+        lines.push(format!(
+            "{}  {:<20}{:>12}/s{:>13.2}%  {}",
+            point_label(index),
+            point.rng_name,
+            formatting::format_byte_count(point.bytes_per_second as usize),
+            point.failure_rate * 100.0,
+            if front { "yes" } else { "" }
+        ));
+    }
+    lines.push(String::new());
+    lines.push(plot(&points, &on_front));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(rng_name: &str, bytes_per_second: f64, failure_rate: f64) -> ParetoPoint {
+        ParetoPoint {
+            rng_name: rng_name.to_string(),
+            bytes_per_second,
+            failure_rate,
+        }
+    }
+
+    #[test]
+    fn front_membership() {
+        let points = [
+            point("fast bad", 8e9, 0.5),
+            point("fast good", 4e9, 0.0),
+            point("slow good", 1e9, 0.0),
+            point("slow bad", 1e9, 0.5),
+            point("tie", 4e9, 0.0),
+        ];
+        assert_eq!(pareto_front(&points), vec![true, true, false, false, true]);
+        assert_eq!(pareto_front(&[]), Vec::<bool>::new());
+    }
+
+    #[test]
+    fn front_plot() {
+        let points = [point("fast", 8e9, 0.5), point("slow", 1e9, 0.0)];
+        let text = plot(&points, &pareto_front(&points));
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), PLOT_HEIGHT + 2);
+        assert!(lines[0].starts_with("  50.0% |"));
+        assert!(lines[0].ends_with('A'));
+        assert!(lines[PLOT_HEIGHT - 1].starts_with("   0.0% |B"));
+    }
+}
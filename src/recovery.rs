@@ -0,0 +1,353 @@
+// Copyright 2025 N. Dornseif
+//
+// Dual-licensed under Apache 2.0 and MIT terms.
+
+//! State recovery of LCGs that only output the upper bits of their state.
+//! Two consecutive outputs fix the hidden lower bits x_0, x_1 up to x_1 ≡ a x_0 + t (mod m) with a known t,
+//! so (x_0, x_1 - t) is a point of the 2-dimensional lattice {(u, v) : v ≡ a u (mod m)} close to a known target.
+//! The lattice is reduced exactly with Lagrange's algorithm, no LLL is needed in two dimensions,
+//! the lattice points near the target are enumerated and every candidate state is checked against later outputs.
+//! A recovered state predicts every following output, a practical verdict that no p-value gives.
+
+use bnum::cast::As;
+
+use crate::{
+    rngs::{lcg, RNG},
+    spectral::{self, Int, LcgParameters, Modulus},
+};
+
+/// Number of outputs the state is recovered from, two for the lattice and the rest to reject wrong candidates.
+pub const RECOVERY_OUTPUTS: usize = 4;
+/// Number of outputs predicted after the recovery outputs.
+pub const PREDICTED_OUTPUTS: usize = 16;
+/// Largest number of lattice points enumerated, skewed lattices of bad multipliers need more.
+const MAX_CANDIDATES: usize = 1 << 16;
+
+/// An LCG whose outputs are the state without its lowest `hidden_bits` bits.
+#[derive(Debug, Clone, Copy)]
+pub struct TruncatedLcg {
+    pub name: &'static str,
+    /// The modulus must be a power of two.
+    pub parameters: LcgParameters,
+    pub hidden_bits: u32,
+    /// Returns `count` consecutive outputs for `seed`.
+    pub outputs: fn(u64, usize) -> Vec<u128>,
+}
+
+fn mmix_outputs(seed: u64, count: usize) -> Vec<u128> {
+    let mut rng = lcg::Mmix::new(seed);
+    (0..count).map(|_| rng.next() as u128).collect()
+}
+
+fn mmix_upper_outputs(seed: u64, count: usize) -> Vec<u128> {
+    let mut rng = lcg::Mmix::new(seed);
+    (0..count).map(|_| (rng.next() >> 32) as u128).collect()
+}
+
+fn lehmer64_outputs(seed: u64, count: usize) -> Vec<u128> {
+    let mut rng = lcg::Lehmer64::new(seed);
+    (0..count).map(|_| rng.next() as u128).collect()
+}
+
+const MMIX_PARAMETERS: LcgParameters = LcgParameters {
+    multiplier: lcg::Mmix::MULTIPLIER as u128,
+    increment: lcg::Mmix::INCREMENT as u128,
+    modulus: Modulus::PowerOfTwo(64),
+};
+
+/// Truncated LCGs of the crate.
+pub const TARGETS: &[TruncatedLcg] = &[
+    TruncatedLcg {
+        name: "MMIX",
+        parameters: MMIX_PARAMETERS,
+        hidden_bits: 0,
+        outputs: mmix_outputs,
+    },
+    // The upper half, as used by generators that only output 32 bits to hide the weak low bits.
+    TruncatedLcg {
+        name: "MMIX >> 32",
+        parameters: MMIX_PARAMETERS,
+        hidden_bits: 32,
+        outputs: mmix_upper_outputs,
+    },
+    TruncatedLcg {
+        name: "Lehmer64",
+        parameters: LcgParameters {
+            multiplier: lcg::Lehmer64::MULTIPLIER,
+            increment: 0,
+            modulus: Modulus::PowerOfTwo(128),
+        },
+        hidden_bits: 64,
+        outputs: lehmer64_outputs,
+    },
+];
+
+/// Advance the state by one step.
+fn step(parameters: &LcgParameters, modulus_bits: u32, state: u128) -> u128 {
+    let next = parameters
+        .multiplier
+        .wrapping_mul(state)
+        .wrapping_add(parameters.increment);
+    if modulus_bits == 128 {
+        next
+    } else {
+        next & ((1 << modulus_bits) - 1)
+    }
+}
+
+/// Whether `state` produces `outputs`, starting with its own output.
+fn produces(
+    parameters: &LcgParameters,
+    modulus_bits: u32,
+    hidden_bits: u32,
+    mut state: u128,
+    outputs: &[u128],
+) -> bool {
+    outputs.iter().all(|&output| {
+        let matches = state >> hidden_bits == output;
+        state = step(parameters, modulus_bits, state);
+        matches
+    })
+}
+
+/// Lagrange reduction of a 2-dimensional lattice basis, the first vector is a shortest lattice vector.
+fn lagrange_reduce(mut b1: [Int; 2], mut b2: [Int; 2]) -> ([Int; 2], [Int; 2]) {
+    if spectral::dot(&b1, &b1) > spectral::dot(&b2, &b2) {
+        std::mem::swap(&mut b1, &mut b2);
+    }
+    loop {
+        let mu = spectral::round_div(spectral::dot(&b1, &b2), spectral::dot(&b1, &b1));
+        b2 = [b2[0] - mu * b1[0], b2[1] - mu * b1[1]];
+        if spectral::dot(&b2, &b2) >= spectral::dot(&b1, &b1) {
+            return (b1, b2);
+        }
+        std::mem::swap(&mut b1, &mut b2);
+    }
+}
+
+/// Recover the state that produced the first of `outputs`,
+/// each output being the state without its lowest `hidden_bits` bits.
+/// At least two outputs are needed, the remaining ones reject wrong candidates.
+/// Returns an error if the modulus is not a power of two, if no or several states produce the outputs,
+/// or if the lattice is too skewed to enumerate.
+pub fn recover_state(
+    parameters: &LcgParameters,
+    hidden_bits: u32,
+    outputs: &[u128],
+) -> Result<u128, String> {
+    let Modulus::PowerOfTwo(modulus_bits) = parameters.modulus else {
+        return Err("Only power of two moduli are supported".to_string());
+    };
+    if hidden_bits >= modulus_bits {
+        return Err("Every bit of the state is hidden".to_string());
+    }
+    let consistent = |state: u128| produces(parameters, modulus_bits, hidden_bits, state, outputs);
+    if hidden_bits == 0 {
+        // The output is the state.
+        let state = *outputs.first().ok_or("No outputs")?;
+        return match consistent(state) {
+            true => Ok(state),
+            false => Err("The outputs do not follow the LCG".to_string()),
+        };
+    }
+    if outputs.len() < 2 {
+        return Err("At least two outputs are needed".to_string());
+    }
+    let m = Int::ONE << modulus_bits;
+    let a = Int::from(parameters.multiplier).rem_euclid(m);
+    let c = Int::from(parameters.increment).rem_euclid(m);
+    let scale = Int::ONE << hidden_bits;
+    let half = Int::ONE << (hidden_bits - 1);
+    let (y0, y1) = (Int::from(outputs[0]), Int::from(outputs[1]));
+    // x1 ≡ a x0 + t (mod m), the hidden bits are centered around `half`.
+    let t = (a * y0 * scale + c - y1 * scale).rem_euclid(m);
+    let target = [half, (half - t).rem_euclid(m)];
+    let (b1, b2) = lagrange_reduce([Int::ONE, a], [Int::ZERO, m]);
+    let det = b1[0] * b2[1] - b1[1] * b2[0];
+    let (det, sign) = if det < Int::ZERO {
+        (-det, -Int::ONE)
+    } else {
+        (det, Int::ONE)
+    };
+    // Coordinates of the target in the reduced basis, rounded.
+    let c1 = spectral::round_div(sign * (target[0] * b2[1] - target[1] * b2[0]), det);
+    let c2 = spectral::round_div(sign * (b1[0] * target[1] - b1[1] * target[0]), det);
+    // The hidden bits are at most `half` away from the target in both coordinates.
+    let radius = |b: &[Int; 2]| half * (b[0].abs() + b[1].abs()) / det + Int::ONE;
+    let (r1, r2) = (radius(&b2), radius(&b1));
+    let bound = Int::from(MAX_CANDIDATES as u64);
+    if r1 >= bound || r2 >= bound || (r1 * Int::TWO + Int::ONE) * (r2 * Int::TWO + Int::ONE) > bound
+    {
+        return Err(format!(
+            "More than {} lattice points to enumerate",
+            MAX_CANDIDATES
+        ));
+    }
+    let (r1, r2): (i64, i64) = (r1.as_(), r2.as_());
+    let mut states: Vec<u128> = vec![];
+    for i in -r1..=r1 {
+        for j in -r2..=r2 {
+            let (k1, k2) = (c1 + Int::from(i), c2 + Int::from(j));
+            let x0 = k1 * b1[0] + k2 * b2[0];
+            let x1 = (k1 * b1[1] + k2 * b2[1] + t).rem_euclid(m);
+            if x0 < Int::ZERO || x0 >= scale || x1 >= scale {
+                continue;
+            }
+            let state: u128 = (y0 * scale + x0).as_();
+            if consistent(state) && !states.contains(&state) {
+                states.push(state);
+            }
+        }
+    }
+    let candidates = (2 * r1 as usize + 1) * (2 * r2 as usize + 1);
+    match states[..] {
+        [state] => Ok(state),
+        [] => Err(format!(
+            "No state among {} candidates produces the outputs",
+            candidates
+        )),
+        _ => Err(format!(
+            "{} states produce the outputs, more are needed",
+            states.len()
+        )),
+    }
+}
+
+/// Outcome of recovering the state of a truncated LCG and predicting its following outputs.
+#[derive(Debug, Clone)]
+pub struct PredictionVerdict {
+    pub name: &'static str,
+    pub hidden_bits: u32,
+    /// Recovered state or the reason the recovery failed.
+    pub recovered: Result<u128, String>,
+    /// Number of the `PREDICTED_OUTPUTS` outputs predicted correctly.
+    pub correct_predictions: usize,
+}
+
+impl PredictionVerdict {
+    /// Whether every following output was predicted.
+    pub fn predicted(&self) -> bool {
+        self.recovered.is_ok() && self.correct_predictions == PREDICTED_OUTPUTS
+    }
+}
+
+/// Recover the state of `target` seeded with `seed` from `RECOVERY_OUTPUTS` outputs
+/// and predict the next `PREDICTED_OUTPUTS` outputs.
+pub fn predict(target: &TruncatedLcg, seed: u64) -> PredictionVerdict {
+    let outputs = (target.outputs)(seed, RECOVERY_OUTPUTS + PREDICTED_OUTPUTS);
+    let (observed, following) = outputs.split_at(RECOVERY_OUTPUTS);
+    let recovered = recover_state(&target.parameters, target.hidden_bits, observed);
+    let correct_predictions = match (&recovered, target.parameters.modulus) {
+        (Ok(state), Modulus::PowerOfTwo(modulus_bits)) => {
+            let mut state = *state;
+            for _ in 0..RECOVERY_OUTPUTS {
+                state = step(&target.parameters, modulus_bits, state);
+            }
+            following
+                .iter()
+                .filter(|&&output| {
+                    let predicted = state >> target.hidden_bits;
+                    state = step(&target.parameters, modulus_bits, state);
+                    predicted == output
+                })
+                .count()
+        }
+        _ => 0,
+    };
+    PredictionVerdict {
+        name: target.name,
+        hidden_bits: target.hidden_bits,
+        recovered,
+        correct_predictions,
+    }
+}
+
+/// Run `predict` on every target and format the verdicts as a table.
+pub fn format_recovery_report(seed: u64) -> String {
+    let mut lines: Vec<String> = vec![format!(
+        "State recovery from {} outputs, seed {:#x}:",
+        RECOVERY_OUTPUTS, seed
+    )];
+    lines.push(format!(
+        "{:<12}{:>12}  {:<14}{}",
+        "Name", "Hidden bits", "Predicted", "State"
+    ));
+    for target in TARGETS {
+        let verdict = predict(target, seed);
+        lines.push(format!(
+            "{:<12}{:>12}  {:<14}{}",
+            verdict.name,
+            verdict.hidden_bits,
+            format!("{}/{}", verdict.correct_predictions, PREDICTED_OUTPUTS),
+            match &verdict.recovered {
+                Ok(state) => format!("{:#x}", state),
+                Err(message) => format!("not recovered: {}", message),
+            }
+        ));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rngs::xorshift;
+
+    #[test]
+    fn recover_crate_lcgs() {
+        for seed in [0, 1, 0x0123456789abcdef, u64::MAX] {
+            for target in TARGETS {
+                let verdict = predict(target, seed);
+                assert!(
+                    verdict.predicted(),
+                    "{} {}: {:?}",
+                    target.name,
+                    seed,
+                    verdict
+                );
+            }
+        }
+        // Lehmer64 multiplies the seed, repeated in both halves, before its first output.
+        let seed: u64 = 0x0123456789abcdef;
+        let state = predict(&TARGETS[2], seed).recovered.unwrap();
+        assert_eq!(
+            state,
+            lcg::Lehmer64::MULTIPLIER.wrapping_mul((seed as u128) << 64 | seed as u128)
+        );
+    }
+
+    #[test]
+    fn recover_small_lcg() {
+        // Every state of a 16 bit LCG with 8 hidden bits is recovered from four outputs.
+        let parameters = LcgParameters {
+            multiplier: 0x6d2d,
+            increment: 0x3b,
+            modulus: Modulus::PowerOfTwo(16),
+        };
+        for initial in (0..1u128 << 16).step_by(97) {
+            let mut state = initial;
+            let outputs: Vec<u128> = (0..4)
+                .map(|_| {
+                    let output = state >> 8;
+                    state = step(&parameters, 16, state);
+                    output
+                })
+                .collect();
+            assert_eq!(recover_state(&parameters, 8, &outputs), Ok(initial));
+        }
+    }
+
+    #[test]
+    fn reject_other_generators() {
+        let mut rng = xorshift::WyRand::new(7);
+        let outputs: Vec<u128> = (0..RECOVERY_OUTPUTS).map(|_| rng.next() as u128).collect();
+        assert!(recover_state(&TARGETS[2].parameters, 64, &outputs).is_err());
+        assert!(recover_state(&TARGETS[0].parameters, 0, &outputs).is_err());
+        let prime_modulus = LcgParameters {
+            multiplier: 16807,
+            increment: 0,
+            modulus: Modulus::Value((1 << 31) - 1),
+        };
+        assert!(recover_state(&prime_modulus, 1, &outputs).is_err());
+    }
+}
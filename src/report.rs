@@ -0,0 +1,470 @@
+// Copyright 2025 N. Dornseif
+//
+// Dual-licensed under Apache 2.0 and MIT terms.
+
+//! Machine-readable reports of test suite runs.
+//! The JSON output only contains values derived from the generated data,
+//! with fixed decimal places and without timings or timestamps,
+//! so two runs over identical data produce byte-identical files.
+
+#[cfg(feature = "json")]
+use std::fmt::Write;
+
+use crate::{analysis::PeriodCertificate, rngs::RngMetadata, scoring::p_log_stat};
+
+/// Version of the JSON layout, increased on incompatible changes.
+#[cfg(feature = "json")]
+pub const JSON_FORMAT_VERSION: u32 = 1;
+/// Decimal places of p-values in the JSON output.
+#[cfg(feature = "json")]
+const P_DECIMAL_PLACES: usize = 10;
+
+/// Outcome of a single test.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Marginal,
+    Fail,
+    ExpectedFail,
+    UnexpectedPass,
+    NotApplicable,
+}
+
+impl Verdict {
+    /// Stable identifier used in machine-readable output.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "pass",
+            Verdict::Marginal => "marginal",
+            Verdict::Fail => "fail",
+            Verdict::ExpectedFail => "expected_fail",
+            Verdict::UnexpectedPass => "unexpected_pass",
+            Verdict::NotApplicable => "not_applicable",
+        }
+    }
+}
+
+/// Result of a single test. Tests that were not applicable have no p-value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestRecord {
+    pub name: &'static str,
+    pub p: Option<f64>,
+    /// The p-value transformed by the scoring of the suite.
+    pub score: Option<f64>,
+    pub verdict: Verdict,
+}
+
+/// Results of all tests run on the data of one seed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeedReport {
+    pub seed: u64,
+    pub results: Vec<TestRecord>,
+}
+
+/// Results of a full test suite run for one generator.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuiteReport {
+    pub rng_name: String,
+    /// Number of u64s generated per seed.
+    pub sample_size: usize,
+    /// Name of the scoring used for the scores of the results.
+    pub scoring: &'static str,
+    /// Generation speed, not written to JSON since timings are not reproducible.
+    pub bytes_per_second: f64,
+    pub seeds: Vec<SeedReport>,
+    /// Tests that evaluate the generator as a whole, like the seed avalanche test.
+    pub generator_results: Vec<TestRecord>,
+    /// Uniformity of the p values of every test over all seeds, one result per test.
+    pub second_level_results: Vec<TestRecord>,
+    pub period: Option<PeriodCertificate>,
+    /// Registry metadata with the certified period lower bound, `None` for unregistered generators
+    /// like bit planes or views.
+    pub metadata: Option<RngMetadata>,
+    /// Whether seeking agrees with stepping, `None` if the generator is not seekable.
+    pub seek_consistent: Option<bool>,
+    /// Whether a restored state resumes identically, `None` if state export is unsupported.
+    pub state_restorable: Option<bool>,
+    /// `None` if weak seeds were not tested.
+    pub weak_seeds: Option<Vec<u64>>,
+    pub passed: bool,
+}
+
+/// A single result together with the generator and seed it belongs to.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ResultRow<'a> {
+    pub rng_name: &'a str,
+    /// `None` for tests that evaluate the generator as a whole.
+    pub seed: Option<u64>,
+    pub record: &'a TestRecord,
+}
+
+impl SuiteReport {
+    /// All results as flat rows, the seeds in run order followed by the generator results.
+    pub fn rows(&self) -> impl Iterator<Item = ResultRow<'_>> {
+        let seed_rows = self.seeds.iter().flat_map(move |seed_report| {
+            seed_report.results.iter().map(move |record| ResultRow {
+                rng_name: &self.rng_name,
+                seed: Some(seed_report.seed),
+                record,
+            })
+        });
+        let generator_rows = self.generator_results.iter().map(move |record| ResultRow {
+            rng_name: &self.rng_name,
+            seed: None,
+            record,
+        });
+        seed_rows.chain(generator_rows)
+    }
+
+    /// Results of the test with the name `test_name` for every seed.
+    pub fn test_rows(&self, test_name: &str) -> Vec<ResultRow<'_>> {
+        self.rows()
+            .filter(|row| row.record.name == test_name)
+            .collect()
+    }
+
+    /// Results of all tests run on the data of `seed`, None if the seed was not tested.
+    pub fn seed_results(&self, seed: u64) -> Option<&[TestRecord]> {
+        self.seeds
+            .iter()
+            .find(|seed_report| seed_report.seed == seed)
+            .map(|seed_report| seed_report.results.as_slice())
+    }
+
+    /// Results that failed unexpectedly.
+    pub fn failed(&self) -> Vec<ResultRow<'_>> {
+        self.rows()
+            .filter(|row| row.record.verdict == Verdict::Fail)
+            .collect()
+    }
+
+    /// The `n` results with the highest p log stat, worst first.
+    /// Results without a p-value are skipped.
+    pub fn worst_n(&self, n: usize) -> Vec<ResultRow<'_>> {
+        let mut rows: Vec<ResultRow> = self.rows().filter(|row| row.record.p.is_some()).collect();
+        rows.sort_by(|a, b| {
+            let score = |row: &ResultRow| p_log_stat(row.record.p.unwrap_or(0.5));
+            score(b).total_cmp(&score(a))
+        });
+        rows.truncate(n);
+        rows
+    }
+}
+
+/// Order in which generators, seeds and tests are listed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ReportOrder {
+    /// Order in which they were run.
+    Execution,
+    /// Generators and tests by name, seeds by value.
+    Sorted,
+}
+
+impl ReportOrder {
+    /// Parse the name of an order as given on the command line.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "execution" => Some(ReportOrder::Execution),
+            "sorted" => Some(ReportOrder::Sorted),
+            _ => None,
+        }
+    }
+}
+
+/// Return the reports in the requested order.
+pub fn order_reports(reports: &[SuiteReport], order: ReportOrder) -> Vec<SuiteReport> {
+    let mut reports = reports.to_vec();
+    if order == ReportOrder::Sorted {
+        reports.sort_by_key(|report| report.rng_name.to_ascii_lowercase());
+        for report in reports.iter_mut() {
+            report.seeds.sort_by_key(|seed_report| seed_report.seed);
+            for seed_report in report.seeds.iter_mut() {
+                seed_report.results.sort_by_key(|record| record.name);
+            }
+            report.generator_results.sort_by_key(|record| record.name);
+            report
+                .second_level_results
+                .sort_by_key(|record| record.name);
+            if let Some(weak_seeds) = report.weak_seeds.as_mut() {
+                weak_seeds.sort();
+            }
+        }
+    }
+    reports
+}
+
+/// Escape a string for use in JSON.
+#[cfg(feature = "json")]
+pub(crate) fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// Format a p-value or score with fixed decimal places. Missing and non finite values become null.
+#[cfg(feature = "json")]
+fn json_p_value(p: Option<f64>) -> String {
+    match p {
+        Some(p) if p.is_finite() => format!("{:.1$}", p, P_DECIMAL_PLACES),
+        _ => "null".to_string(),
+    }
+}
+
+/// Seeds are written as hex strings, JSON numbers can not hold every u64 exactly.
+#[cfg(feature = "json")]
+fn json_seed(seed: u64) -> String {
+    format!("\"{:#018x}\"", seed)
+}
+
+#[cfg(feature = "json")]
+fn json_records(records: &[TestRecord], indent: &str) -> String {
+    if records.is_empty() {
+        return "[]".to_string();
+    }
+    let lines: Vec<String> = records
+        .iter()
+        .map(|record| {
+            format!(
+                "{}  {{\"name\": {}, \"p\": {}, \"score\": {}, \"verdict\": {}}}",
+                indent,
+                json_string(record.name),
+                json_p_value(record.p),
+                json_p_value(record.score),
+                json_string(record.verdict.as_str())
+            )
+        })
+        .collect();
+    format!("[\n{}\n{}]", lines.join(",\n"), indent)
+}
+
+#[cfg(feature = "json")]
+fn json_period(period: &Option<PeriodCertificate>) -> String {
+    match period {
+        Some(PeriodCertificate::CycleFound { period, steps }) => format!(
+            "{{\"cycle_found\": true, \"period\": {}, \"steps\": {}}}",
+            period, steps
+        ),
+        Some(PeriodCertificate::NoCycleWithin { lower_bound, steps }) => format!(
+            "{{\"cycle_found\": false, \"lower_bound\": {}, \"steps\": {}}}",
+            lower_bound, steps
+        ),
+        None => "null".to_string(),
+    }
+}
+
+#[cfg(feature = "json")]
+fn json_metadata(metadata: &Option<RngMetadata>) -> String {
+    match metadata {
+        Some(metadata) => format!(
+            "{{\"family\": {}, \"state_bytes\": {}, \"test_generator\": {}, \"period_lower_bound\": {}}}",
+            json_string(metadata.family),
+            metadata.state_bytes,
+            metadata.test_generator,
+            match metadata.period_lower_bound {
+                Some(lower_bound) => lower_bound.to_string(),
+                None => "null".to_string(),
+            }
+        ),
+        None => "null".to_string(),
+    }
+}
+
+#[cfg(feature = "json")]
+fn json_suite_report(report: &SuiteReport) -> String {
+    let seeds: Vec<String> = report
+        .seeds
+        .iter()
+        .map(|seed_report| {
+            format!(
+                "        {{\n          \"seed\": {},\n          \"results\": {}\n        }}",
+                json_seed(seed_report.seed),
+                json_records(&seed_report.results, "          ")
+            )
+        })
+        .collect();
+    let weak_seeds = match &report.weak_seeds {
+        Some(weak_seeds) => format!(
+            "[{}]",
+            weak_seeds
+                .iter()
+                .map(|&seed| json_seed(seed))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        None => "null".to_string(),
+    };
+    [
+        "    {".to_string(),
+        format!("      \"name\": {},", json_string(&report.rng_name)),
+        format!("      \"sample_size\": {},", report.sample_size),
+        format!("      \"scoring\": {},", json_string(report.scoring)),
+        format!("      \"passed\": {},", report.passed),
+        format!("      \"seeds\": [\n{}\n      ],", seeds.join(",\n")),
+        format!(
+            "      \"generator_results\": {},",
+            json_records(&report.generator_results, "      ")
+        ),
+        format!(
+            "      \"second_level_results\": {},",
+            json_records(&report.second_level_results, "      ")
+        ),
+        format!("      \"period\": {},", json_period(&report.period)),
+        format!("      \"metadata\": {},", json_metadata(&report.metadata)),
+        format!(
+            "      \"seek_consistent\": {},",
+            match report.seek_consistent {
+                Some(consistent) => consistent.to_string(),
+                None => "null".to_string(),
+            }
+        ),
+        format!(
+            "      \"state_restorable\": {},",
+            match report.state_restorable {
+                Some(restorable) => restorable.to_string(),
+                None => "null".to_string(),
+            }
+        ),
+        format!("      \"weak_seeds\": {}", weak_seeds),
+        "    }".to_string(),
+    ]
+    .join("\n")
+}
+
+/// Render reports as JSON in the requested order.
+#[cfg(feature = "json")]
+pub fn reports_to_json(reports: &[SuiteReport], order: ReportOrder) -> String {
+    let generators: Vec<String> = order_reports(reports, order)
+        .iter()
+        .map(json_suite_report)
+        .collect();
+    format!(
+        "{{\n  \"format_version\": {},\n  \"generators\": [\n{}\n  ]\n}}\n",
+        JSON_FORMAT_VERSION,
+        generators.join(",\n")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_report(rng_name: &str) -> SuiteReport {
+        SuiteReport {
+            rng_name: rng_name.to_string(),
+            sample_size: 512,
+            scoring: "pls",
+            bytes_per_second: 1e9,
+            seeds: vec![
+                SeedReport {
+                    seed: 2,
+                    results: vec![
+                        TestRecord {
+                            name: "Runs",
+                            p: Some(0.5),
+                            score: Some(0.4),
+                            verdict: Verdict::Pass,
+                        },
+                        TestRecord {
+                            name: "Mono",
+                            p: Some(f64::NAN),
+                            score: Some(9.9999),
+                            verdict: Verdict::Fail,
+                        },
+                        TestRecord {
+                            name: "Perm",
+                            p: None,
+                            score: None,
+                            verdict: Verdict::NotApplicable,
+                        },
+                    ],
+                },
+                SeedReport {
+                    seed: 1,
+                    results: vec![],
+                },
+            ],
+            generator_results: vec![],
+            second_level_results: vec![],
+            period: Some(PeriodCertificate::CycleFound {
+                period: 2,
+                steps: 3,
+            }),
+            metadata: Some(RngMetadata {
+                family: "xorshift",
+                state_bytes: 8,
+                test_generator: false,
+                period_lower_bound: Some(2),
+            }),
+            seek_consistent: None,
+            state_restorable: None,
+            weak_seeds: None,
+            passed: false,
+        }
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_is_deterministic() {
+        let reports = vec![example_report("WyRand"), example_report("MMIX")];
+        let json = reports_to_json(&reports, ReportOrder::Execution);
+        assert_eq!(json, reports_to_json(&reports, ReportOrder::Execution));
+        assert!(json.contains("\"p\": 0.5000000000, \"score\": 0.4000000000"));
+        assert!(json.contains("\"p\": null, \"score\": 9.9999000000, \"verdict\": \"fail\""));
+        assert!(json.contains("\"p\": null, \"score\": null, \"verdict\": \"not_applicable\""));
+        assert!(json.contains("\"scoring\": \"pls\""));
+        assert!(json.contains("\"seed\": \"0x0000000000000002\""));
+        assert!(json
+            .contains("\"state_bytes\": 8, \"test_generator\": false, \"period_lower_bound\": 2}"));
+        assert!(json.find("WyRand") < json.find("MMIX"));
+    }
+
+    #[test]
+    fn sorted_order() {
+        let reports = vec![example_report("WyRand"), example_report("MMIX")];
+        let sorted = order_reports(&reports, ReportOrder::Sorted);
+        assert_eq!(sorted[0].rng_name, "MMIX");
+        assert_eq!(sorted[0].seeds[0].seed, 1);
+        assert_eq!(sorted[0].seeds[1].results[0].name, "Mono");
+    }
+
+    #[test]
+    fn result_queries() {
+        let report = example_report("WyRand");
+        assert_eq!(report.rows().count(), 3);
+        let rows = report.test_rows("Mono");
+        assert_eq!(rows.len(), 1);
+        assert_eq!((rows[0].rng_name, rows[0].seed), ("WyRand", Some(2)));
+        assert!(report.test_rows("Bytes").is_empty());
+        assert_eq!(report.seed_results(2).map(<[TestRecord]>::len), Some(3));
+        assert_eq!(report.seed_results(1), Some(&[][..]));
+        assert_eq!(report.seed_results(3), None);
+        let failed = report.failed();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].record.name, "Mono");
+        let worst: Vec<&str> = report
+            .worst_n(5)
+            .iter()
+            .map(|row| row.record.name)
+            .collect();
+        assert_eq!(worst, vec!["Mono", "Runs"]);
+        assert_eq!(report.worst_n(1).len(), 1);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn string_escaping() {
+        assert_eq!(json_string("a\"b\\c\n"), "\"a\\\"b\\\\c\\n\"");
+    }
+}
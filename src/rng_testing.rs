@@ -4,11 +4,23 @@
 
 //! Statistical testing of an RNGs output.
 
-use std::{ops::Mul, time::Duration, time::Instant};
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+    time::Instant,
+};
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::_rdtsc;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::_rdtsc;
 
 use crate::utils::write_and_print;
 use crate::{
+    analysis, conditioning, formatting,
+    report::{self, SeedReport, SuiteReport, Verdict},
     rngs::{self, RNG},
+    scoring::{self, p_log_stat, Scoring},
     stats, strings, testdata, utils,
 };
 
@@ -18,8 +30,35 @@ const P_LOG_STAT_LIMIT_FAIL: f64 = 4.0;
 /// while returning a passed overall result.
 const MAX_MARGINAL_FRACTION: f64 = 0.05;
 const TEST_SEED_COUNT: usize = 16;
+/// Number of outputs compared per seed pair in the seed avalanche test.
+const SEED_AVALANCHE_OUTPUTS: usize = 16;
+/// Number of outputs of parent and child compared per seed in the split correlation test.
+const SPLIT_CORRELATION_OUTPUTS: usize = 256;
+/// Positions checked by the seek consistency test, not aligned to blocks and past 2^32 outputs.
+const SEEK_POSITIONS: [u64; 4] = [0, 3, 1003, (1 << 40) + 5];
+/// Positions below this are reached by single steps in the seek consistency test, above it with `advance`.
+const SEEK_STEP_LIMIT: u64 = 1 << 16;
+/// Number of outputs compared after every seek.
+const SEEK_OUTPUTS: usize = 8;
+/// Number of native outputs generated before the state is exported in the state round trip test.
+const STATE_EXPORT_OFFSET: usize = 1003;
+/// Number of native outputs compared after restoring an exported state.
+const STATE_RESTORE_OUTPUTS: usize = 64;
+/// Default number of steps, as a power of two, searched for a cycle
+/// when certifying a period lower bound.
+pub const DEFAULT_PERIOD_BUDGET_LOG2: u32 = 24;
+
+static PERIOD_BUDGET_LOG2: AtomicU32 = AtomicU32::new(DEFAULT_PERIOD_BUDGET_LOG2);
+
+/// Set the number of steps, as a power of two, searched for a cycle by the test suite.
+pub fn set_period_budget_log2(budget_log2: u32) {
+    PERIOD_BUDGET_LOG2.store(budget_log2, Ordering::Relaxed);
+}
+
+/// A test of the battery. Returns the p value, or None if the test is not applicable to the data.
+pub type TestFunction = fn(&[u64]) -> Option<f64>;
 
-const TEST_F_POINTERS: [fn(&[u64]) -> f64; 7] = [
+const TEST_F_POINTERS: [TestFunction; 33] = [
     stats::byte_distribution_test,
     stats::leading_zeros_frequency_test,
     stats::monobit_test,
@@ -27,41 +66,159 @@ const TEST_F_POINTERS: [fn(&[u64]) -> f64; 7] = [
     stats::u64_block_bit_frequency_test,
     stats::longest_ones_run,
     stats::matrix_ranks,
+    stats::approximate_entropy_test,
+    stats::cumulative_sums_forward_test,
+    stats::cumulative_sums_backward_test,
+    stats::random_excursions_test,
+    stats::zero_count_distribution_test,
+    stats::random_excursions_variant_test,
+    stats::poker_test,
+    stats::permutation_test,
+    stats::collision_test,
+    stats::opso_test,
+    stats::oqso_test,
+    stats::dna_test,
+    stats::bit_position_frequency_test,
+    stats::hamming_weight_test,
+    stats::adjacent_distance_test,
+    stats::compression_test,
+    stats::book_stack_test,
+    stats::borel_normality_test,
+    stats::kolmogorov_smirnov_test,
+    stats::anderson_darling_test,
+    stats::overlapping_window_test,
+    stats::matrix_ranks_64,
+    stats::bit_correlation_test,
+    stats::linear_prediction_test,
+    stats::gorilla_test,
+    stats::random_walk_extremes_test,
 ];
 
+/// Tests a registered generator is expected to fail, e.g. the deliberately bad testgens.
+/// Expected failures are reported as OK, while an expected failure that passes is an error.
 #[derive(Debug, Copy, Clone)]
-struct TestResult {
-    test_id: usize,
-    p: f64,
+pub enum ExpectedFailures {
+    /// Every failing test is a regression.
+    Nothing,
+    /// Every test is expected to fail.
+    All,
+    /// The tests with the listed names are expected to fail.
+    Tests(&'static [&'static str]),
+}
+
+impl ExpectedFailures {
+    /// Check if the test named `test_name` is expected to fail.
+    pub fn contains(&self, test_name: &str) -> bool {
+        match self {
+            ExpectedFailures::Nothing => false,
+            ExpectedFailures::All => true,
+            ExpectedFailures::Tests(names) => names.contains(&test_name),
+        }
+    }
+}
+
+/// Result of a single test run.
+/// A p value of None means the test was not applicable to the data,
+/// e.g. because there was too little of it.
+#[derive(Debug, Copy, Clone)]
+pub struct TestResult {
+    name: &'static str,
+    p: Option<f64>,
     time_used: Duration,
+    expected_failure: bool,
 }
 
 impl TestResult {
-    pub fn logstat(&self) -> f64 {
-        p_log_stat(self.p)
+    /// Result without timing information, expected to pass.
+    pub fn new(name: &'static str, p: Option<f64>) -> Self {
+        TestResult {
+            name,
+            p,
+            time_used: Duration::ZERO,
+            expected_failure: false,
+        }
+    }
+    /// The p log stat, verdicts are based on it regardless of the selected scoring.
+    pub fn logstat(&self) -> Option<f64> {
+        self.p.map(p_log_stat)
+    }
+    pub fn not_applicable(&self) -> bool {
+        self.p.is_none()
     }
     pub fn passed(&self) -> bool {
-        self.logstat() < P_LOG_STAT_LIMIT_MARGINAL
+        self.logstat()
+            .is_some_and(|logstat| logstat < P_LOG_STAT_LIMIT_MARGINAL)
     }
     pub fn marginal(&self) -> bool {
-        (P_LOG_STAT_LIMIT_MARGINAL..=P_LOG_STAT_LIMIT_FAIL).contains(&self.logstat())
+        self.logstat().is_some_and(|logstat| {
+            (P_LOG_STAT_LIMIT_MARGINAL..=P_LOG_STAT_LIMIT_FAIL).contains(&logstat)
+        })
     }
     pub fn failed(&self) -> bool {
-        self.logstat() > P_LOG_STAT_LIMIT_FAIL
+        self.logstat()
+            .is_some_and(|logstat| logstat > P_LOG_STAT_LIMIT_FAIL)
+    }
+    /// Expected to fail and did not pass. Marginal results count as failures here.
+    pub fn failed_as_expected(&self) -> bool {
+        self.expected_failure && !self.not_applicable() && !self.passed()
+    }
+    pub fn passed_unexpectedly(&self) -> bool {
+        self.expected_failure && self.passed()
+    }
+    /// Mark the result as expected to fail if it is listed in `expected_failures`.
+    pub fn with_expectation(mut self, expected_failures: &ExpectedFailures) -> Self {
+        self.expected_failure = expected_failures.contains(self.name);
+        self
+    }
+    pub fn verdict(&self) -> Verdict {
+        if self.not_applicable() {
+            Verdict::NotApplicable
+        } else if self.failed_as_expected() {
+            Verdict::ExpectedFail
+        } else if self.passed_unexpectedly() {
+            Verdict::UnexpectedPass
+        } else if self.passed() {
+            Verdict::Pass
+        } else if self.marginal() {
+            Verdict::Marginal
+        } else {
+            Verdict::Fail
+        }
     }
+    pub fn record(&self) -> report::TestRecord {
+        report::TestRecord {
+            name: self.name,
+            p: self.p,
+            score: self.p.map(|p| scoring::selected().score(p)),
+            verdict: self.verdict(),
+        }
+    }
+    /// Format the result with the selected scoring.
     pub fn format(&self) -> String {
+        self.format_with(scoring::selected())
+    }
+    pub fn format_with(&self, scoring: &dyn Scoring) -> String {
+        let (p, score) = match self.p {
+            Some(p) => (format!("{:.6}", p), format!("{:.4}", scoring.score(p))),
+            None => (
+                strings::NOT_APPLICABLE_STR.to_string(),
+                strings::NOT_APPLICABLE_STR.to_string(),
+            ),
+        };
         format!(
-            "{:<10}: Time: {}     p: {:.6}     pls: {:.4}   - {}",
-            strings::TEST_NAMES[self.test_id],
-            utils::format_elapsed_time(self.time_used),
-            self.p,
-            self.logstat(),
-            if self.passed() {
-                strings::PASS_STR
-            } else if self.marginal() {
-                strings::MARGINAL_STR
-            } else {
-                strings::FAIL_STR
+            "{:<10}: Time: {}     p: {:<8}     {}: {:<6}   - {}",
+            self.name,
+            formatting::format_duration_aligned(self.time_used),
+            p,
+            scoring.label(),
+            score,
+            match self.verdict() {
+                Verdict::Pass => strings::PASS_STR,
+                Verdict::Marginal => strings::MARGINAL_STR,
+                Verdict::Fail => strings::FAIL_STR,
+                Verdict::ExpectedFail => strings::EXPECTED_FAIL_STR,
+                Verdict::UnexpectedPass => strings::UNEXPECTED_PASS_STR,
+                Verdict::NotApplicable => strings::NOT_APPLICABLE_STR,
             }
         )
     }
@@ -77,76 +234,255 @@ fn get_result_file_path(rng_name: &str) -> String {
     strvec.join("")
 }
 
+/// Look up a test of the battery by its name in `strings::TEST_NAMES`.
+pub fn battery_test(name: &str) -> Option<TestFunction> {
+    strings::TEST_NAMES
+        .iter()
+        .position(|known| *known == name)
+        .map(|test_id| TEST_F_POINTERS[test_id])
+}
+
 /// Run a test function located at `TEST_F_POINTERS[test_id]`
 /// and return the result and excution time.
 fn run_single_test(test_data: &[u64], test_id: usize) -> TestResult {
     let start: Instant = Instant::now();
-    let p: f64 = TEST_F_POINTERS[test_id](test_data);
+    let p: Option<f64> = TEST_F_POINTERS[test_id](test_data);
     let time_used: Duration = start.elapsed();
     TestResult {
-        test_id,
+        name: strings::TEST_NAMES[test_id],
         p,
         time_used,
+        expected_failure: false,
     }
 }
 
 /// Measure the speed of the rand crates default RNG.
 /// Return in bytes per second.
-fn measure_reference_speed(sample_size: usize) -> f64 {
+fn measure_reference_speed(buffer: &mut Vec<u64>, sample_size: usize) -> f64 {
     let mut ref_rng = rngs::ReferenceRand::new(0);
-    let (_, speed) = stats::generate_test_data(&mut ref_rng, sample_size);
-    speed
+    stats::fill_test_data(&mut ref_rng, buffer, sample_size)
 }
 
-/// Logarithmic quantity to specify how close to 1.0 or 0.0 a p-value is.
-/// Has a range of 0-9.9999.
-/// -0.2 * (log2(min(p, 1-p)) - 1) clamped to 9.9999
-fn p_log_stat(p: f64) -> f64 {
-    (p.min(1.0 - p).log2() - 1.0).mul(-0.2).min(9.9999)
+/// Read the time stamp counter, None on targets without one.
+fn read_cycle_counter() -> Option<u64> {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    return Some(unsafe { _rdtsc() });
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+    return None;
 }
 
 /// Measure rng speed over sample size and report in bytes/s and cycles/bytes.
 /// Also reports speed relative to reference speed.
-fn speed_test(test_rng: &mut impl RNG, sample_size: usize) -> String {
+/// Cycles are only reported on targets with a time stamp counter.
+/// Returns the report and the speed in bytes/s.
+fn speed_test(test_rng: &mut impl RNG, buffer: &mut Vec<u64>, sample_size: usize) -> (String, f64) {
     test_rng.reseed(testdata::rng_test::STATIC_TEST_SEEDS[0]);
-    let pre_clock: u64 = unsafe { core::arch::x86_64::_rdtsc() };
-    let (_, speed) = stats::generate_test_data(test_rng, sample_size);
-    let cycle_count: f64 = unsafe { core::arch::x86_64::_rdtsc() - pre_clock } as f64;
-    let ref_speed: f64 = measure_reference_speed(sample_size);
+    // Allocate up front, so the timing does not include the allocation.
+    buffer.reserve(sample_size);
+    let pre_clock: Option<u64> = read_cycle_counter();
+    let speed = stats::fill_test_data(test_rng, buffer, sample_size);
+    let post_clock: Option<u64> = read_cycle_counter();
+    let ref_speed: f64 = measure_reference_speed(buffer, sample_size);
     let rel_speed: f64 = (speed / ref_speed) * 100.0;
-    format!(
-        "Generated {} test data. (Speed: {}/s  ({:.4}%)) ({} cycles ({:.4} cycles/byte))",
-        utils::format_byte_count(sample_size * 8),
-        utils::format_byte_count(speed as usize),
+    let cycles: String = match pre_clock.zip(post_clock) {
+        Some((pre_clock, post_clock)) => {
+            let cycle_count: f64 = post_clock.wrapping_sub(pre_clock) as f64;
+            format!(
+                " ({} cycles ({:.4} cycles/byte))",
+                cycle_count,
+                cycle_count / (sample_size as f64 * 8.0)
+            )
+        }
+        None => String::new(),
+    };
+    let line = format!(
+        "Generated {} test data. (Speed: {}/s  ({:.4}%)){}",
+        formatting::format_byte_count(sample_size * 8),
+        formatting::format_byte_count(speed as usize),
         rel_speed,
-        cycle_count,
-        cycle_count / (sample_size as f64 * 8.0)
-    )
+        cycles
+    );
+    (line, speed)
+}
+
+/// Measure the throughput of the conditioning wrappers over a fast inner generator (WyRand).
+/// Reports speed in bytes/s of conditioned output, relative to the unconditioned generator.
+pub fn extraction_benchmark(sample_size: usize) -> String {
+    type Inner = rngs::xorshift::WyRand;
+    let seed = testdata::rng_test::STATIC_TEST_SEEDS[0];
+    let (_, raw_speed) = stats::generate_test_data(&mut Inner::new(seed), sample_size);
+    let measurements: [(&str, f64); 4] = [
+        ("WyRand", raw_speed),
+        (
+            "VonNeumann",
+            stats::generate_test_data(
+                &mut conditioning::VonNeumann::<Inner>::new(seed),
+                sample_size,
+            )
+            .1,
+        ),
+        (
+            "XorFold",
+            stats::generate_test_data(&mut conditioning::XorFold::<Inner>::new(seed), sample_size)
+                .1,
+        ),
+        (
+            "Hash",
+            stats::generate_test_data(
+                &mut conditioning::HashConditioned::<Inner>::new(seed),
+                sample_size,
+            )
+            .1,
+        ),
+    ];
+    let mut lines: Vec<String> = vec![format!(
+        "Conditioning throughput for {} of output:",
+        formatting::format_byte_count(sample_size * 8)
+    )];
+    for (name, speed) in measurements {
+        lines.push(format!(
+            "{:<10}: Speed: {}/s  ({:.4}%)",
+            name,
+            formatting::format_byte_count_aligned(speed as usize),
+            (speed / raw_speed) * 100.0
+        ));
+    }
+    lines.join("\n")
 }
 
-/// Peform all tests listed in `TEST_F_POINTERS` and add the results to `test_results`.
+/// Peform all tests listed in `TEST_F_POINTERS` and return the results.
 fn test_single_seed(
     test_rng: &mut impl RNG,
+    buffer: &mut Vec<u64>,
     sample_size: usize,
     seed: u64,
-    test_results: &mut Vec<TestResult>,
+    expected_failures: &ExpectedFailures,
     result_file_path: &str,
-) {
+) -> Vec<TestResult> {
     test_rng.reseed(seed);
     write_and_print(
         format!("Testing for seed: {:#018x}", seed),
         result_file_path,
     );
-    let (test_data, _) = stats::generate_test_data(test_rng, sample_size);
+    stats::fill_test_data(test_rng, buffer, sample_size);
+    let mut test_results: Vec<TestResult> = vec![];
     for test_id in 0..TEST_F_POINTERS.len() {
-        let rslt = run_single_test(&test_data, test_id);
+        let rslt = run_single_test(buffer, test_id).with_expectation(expected_failures);
         write_and_print(rslt.format(), result_file_path);
         test_results.push(rslt);
     }
+    test_results
+}
+
+/// Run the seed avalanche test over all test seeds.
+fn seed_avalanche_single_test(test_rng: &mut impl RNG, seeds: &[u64]) -> TestResult {
+    let start: Instant = Instant::now();
+    let p: Option<f64> = stats::seed_avalanche_test(test_rng, seeds, SEED_AVALANCHE_OUTPUTS);
+    TestResult {
+        name: strings::SEED_AVALANCHE_NAME,
+        p,
+        time_used: start.elapsed(),
+        expected_failure: false,
+    }
+}
+
+/// Run the split correlation test over all test seeds.
+fn split_correlation_single_test(test_rng: &mut impl RNG, seeds: &[u64]) -> TestResult {
+    let start: Instant = Instant::now();
+    let p: Option<f64> = stats::split_correlation_test(test_rng, seeds, SPLIT_CORRELATION_OUTPUTS);
+    TestResult {
+        name: strings::SPLIT_CORRELATION_NAME,
+        p,
+        time_used: start.elapsed(),
+        expected_failure: false,
+    }
+}
+
+/// Run the second level test on the p values of all results not expected to fail.
+fn second_level_single_test(test_results: &[TestResult]) -> TestResult {
+    let start: Instant = Instant::now();
+    let p_values: Vec<f64> = test_results
+        .iter()
+        .filter(|rslt| !rslt.expected_failure)
+        .filter_map(|rslt| rslt.p)
+        .collect();
+    TestResult {
+        name: strings::SECOND_LEVEL_NAME,
+        p: stats::second_level_test(&p_values),
+        time_used: start.elapsed(),
+        expected_failure: false,
+    }
+}
+
+/// Run the second level test of every test of the battery on its p values over all seeds.
+fn second_level_per_test(
+    test_results: &[TestResult],
+    expected_failures: &ExpectedFailures,
+) -> Vec<TestResult> {
+    strings::TEST_NAMES
+        .iter()
+        .map(|&name| {
+            let start: Instant = Instant::now();
+            let p_values: Vec<f64> = test_results
+                .iter()
+                .filter(|rslt| rslt.name == name)
+                .filter_map(|rslt| rslt.p)
+                .collect();
+            TestResult {
+                name,
+                p: stats::second_level_ks_test(&p_values),
+                time_used: start.elapsed(),
+                expected_failure: false,
+            }
+            .with_expectation(expected_failures)
+        })
+        .collect()
+}
+
+/// Certify a lower bound on the period of the generator for a single seed.
+fn period_report(
+    test_rng: &mut (impl RNG + Clone + PartialEq),
+    seed: u64,
+) -> (analysis::PeriodCertificate, String) {
+    test_rng.reseed(seed);
+    let budget: u64 = 1 << PERIOD_BUDGET_LOG2.load(Ordering::Relaxed);
+    let start = Instant::now();
+    let certificate = analysis::certify_period(test_rng, budget);
+    let line = format!(
+        "{} in {}",
+        certificate.format(),
+        formatting::format_duration(start.elapsed())
+    );
+    (certificate, line)
+}
+
+/// Estimate the min-entropy of each output bit position, the correlations between them
+/// and how well they are predicted linearly for a single seed.
+fn bit_position_report(
+    test_rng: &mut impl RNG,
+    buffer: &mut Vec<u64>,
+    sample_size: usize,
+    seed: u64,
+) -> String {
+    test_rng.reseed(seed);
+    stats::fill_test_data(test_rng, buffer, sample_size);
+    let report = format!(
+        "{}\n{}",
+        analysis::format_bit_entropy_table(
+            &analysis::bit_position_min_entropy(buffer),
+            sample_size
+        ),
+        analysis::bit_column_correlations(buffer).format()
+    );
+    match analysis::linear_prediction(buffer) {
+        Some(prediction) => format!("{}\n{}", report, prediction.format()),
+        None => report,
+    }
 }
 
 fn weak_seeds_tests(
     test_rng: &mut impl RNG,
+    buffer: &mut Vec<u64>,
     sample_size: usize,
     result_file_path: &str,
 ) -> Vec<u64> {
@@ -157,10 +493,10 @@ fn weak_seeds_tests(
             result_file_path,
         );
         test_rng.reseed(seed);
-        let (test_data, _) = stats::generate_test_data(test_rng, sample_size);
+        stats::fill_test_data(test_rng, buffer, sample_size);
         let mut seed_test_results: Vec<TestResult> = vec![];
         for test_id in 0..TEST_F_POINTERS.len() {
-            let rslt = run_single_test(&test_data, test_id);
+            let rslt = run_single_test(buffer, test_id);
             write_and_print(rslt.format(), result_file_path);
             seed_test_results.push(rslt);
         }
@@ -174,18 +510,91 @@ fn weak_seeds_tests(
     found_weak_seeds
 }
 
+/// Check that seeking agrees with stepping for a single seed.
+/// Seeking to `n` has to give the outputs and positions of `n` native steps from position 0.
+/// None if the generator does not implement `SeekableRNG`.
+pub fn seek_consistency(test_rng: &mut dyn RNG, seed: u64) -> Option<bool> {
+    let seekable = test_rng.as_seekable()?;
+    seekable.reseed(seed);
+    let mut consistent = true;
+    for position in SEEK_POSITIONS {
+        seekable.seek(0);
+        if position < SEEK_STEP_LIMIT {
+            for _ in 0..position {
+                seekable.next_native();
+            }
+        } else {
+            seekable.advance(position);
+        }
+        consistent &= seekable.position() == position as u128;
+        let stepped: Vec<u64> = (0..SEEK_OUTPUTS).map(|_| seekable.next_native()).collect();
+        seekable.seek(position as u128);
+        consistent &= seekable.position() == position as u128;
+        let seeked: Vec<u64> = (0..SEEK_OUTPUTS).map(|_| seekable.next_native()).collect();
+        consistent &= seeked == stepped;
+        consistent &= seekable.position() == position as u128 + SEEK_OUTPUTS as u128;
+    }
+    Some(consistent)
+}
+
+/// Check that exporting and importing the state resumes the generator exactly.
+/// The restored generator has to equal the original, export the same bytes
+/// and continue with the same outputs.
+/// None if the generator does not support state export.
+pub fn state_round_trip<R: RNG + PartialEq>(test_rng: &mut R, seed: u64) -> Option<bool> {
+    test_rng.reseed(seed);
+    for _ in 0..STATE_EXPORT_OFFSET {
+        test_rng.next_native();
+    }
+    let exported = test_rng.state_bytes()?;
+    let Ok(mut restored) = R::from_state_bytes(&exported) else {
+        return Some(false);
+    };
+    let mut consistent = restored == *test_rng;
+    consistent &= restored.state_bytes().as_ref() == Some(&exported);
+    for _ in 0..STATE_RESTORE_OUTPUTS {
+        consistent &= restored.next_native() == test_rng.next_native();
+    }
+    Some(consistent)
+}
+
+/// Check if a set of results passes overall.
+/// Fails on any failure or unexpected pass, or if too many results are marginal.
+/// Results that are not applicable are ignored.
+pub fn overall_passed(test_results: &[TestResult]) -> bool {
+    let count = |predicate: fn(&TestResult) -> bool| {
+        test_results.iter().filter(|rslt| predicate(rslt)).count()
+    };
+    let failed_tests = count(|rslt| rslt.verdict() == Verdict::Fail);
+    let unexpected_passed_tests = count(TestResult::passed_unexpectedly);
+    let marginal_tests = count(|rslt| rslt.verdict() == Verdict::Marginal);
+    let applicable_tests = test_results.len() - count(TestResult::not_applicable);
+    failed_tests == 0
+        && unexpected_passed_tests == 0
+        && marginal_tests as f64 <= MAX_MARGINAL_FRACTION * applicable_tests as f64
+}
+
 /// Format a vec of `TestResults` and print a summary of the results.
-fn format_test_results_summary(test_results: &Vec<TestResult>) -> String {
+pub fn format_test_results_summary(test_results: &[TestResult]) -> String {
     const P_LOG_STAT_BINS: usize = 10;
     let mut p_logstat_bins = [0u32; P_LOG_STAT_BINS];
     let mut failed_tests = 0usize;
     let mut marginal_tests = 0usize;
+    let mut expected_failed_tests = 0usize;
+    let mut unexpected_passed_tests = 0usize;
+    let mut not_applicable_tests = 0usize;
     for rslt in test_results {
-        p_logstat_bins[rslt.logstat().floor() as usize] += 1;
-        if rslt.marginal() {
-            marginal_tests += 1;
-        } else if rslt.failed() {
-            failed_tests += 1;
+        if let Some(logstat) = rslt.logstat() {
+            let bin = (logstat.floor().max(0.0) as usize).min(P_LOG_STAT_BINS - 1);
+            p_logstat_bins[bin] += 1;
+        }
+        match rslt.verdict() {
+            Verdict::Pass => {}
+            Verdict::Marginal => marginal_tests += 1,
+            Verdict::Fail => failed_tests += 1,
+            Verdict::ExpectedFail => expected_failed_tests += 1,
+            Verdict::UnexpectedPass => unexpected_passed_tests += 1,
+            Verdict::NotApplicable => not_applicable_tests += 1,
         }
     }
     let logstat_summary: String = p_logstat_bins
@@ -202,61 +611,254 @@ fn format_test_results_summary(test_results: &Vec<TestResult>) -> String {
         .join("");
     let total_tests: usize = test_results.len();
     format!(
-        "P log stats: \n{}\nOverall result: {}          ( {} passed; {} marginal; {} failed; {} total)",
+        "P log stats: \n{}\nOverall result: {}          ( {} passed; {} marginal; {} failed; {} expected fail; {} unexpected pass; {} n/a; {} total)",
         logstat_summary,
-        if failed_tests > 0 || marginal_tests as f64 > MAX_MARGINAL_FRACTION * total_tests as f64{
-            strings::FAIL_STR
-        } else {
+        if overall_passed(test_results) {
             strings::PASS_STR
+        } else {
+            strings::FAIL_STR
         },
-        total_tests - failed_tests - marginal_tests,
+        total_tests
+            - failed_tests
+            - marginal_tests
+            - expected_failed_tests
+            - unexpected_passed_tests
+            - not_applicable_tests,
         marginal_tests,
         failed_tests,
+        expected_failed_tests,
+        unexpected_passed_tests,
+        not_applicable_tests,
         total_tests
     )
 }
 /// Perform performance tests for supplied RNG.
-pub fn test_suite(test_rng: &mut impl RNG, sample_size: usize, rng_name: &str) {
+/// The samples are generated into `buffer`, which can be reused across runs.
+pub fn test_suite(
+    test_rng: &mut (impl RNG + Clone + PartialEq),
+    buffer: &mut Vec<u64>,
+    sample_size: usize,
+    rng_name: &str,
+    expected_failures: &ExpectedFailures,
+) -> SuiteReport {
     test_suite_with_seeds(
         test_rng,
+        buffer,
         sample_size,
         &testdata::rng_test::STATIC_TEST_SEEDS[0..TEST_SEED_COUNT],
         rng_name,
         true,
-    );
+        expected_failures,
+    )
+}
+/// Run the test suite on a single bit plane of the native output of `R`, see `rngs::adapters::BitPlane`.
+/// The plane is reported as `<rng_name>[bit <plane>]`.
+/// Panics if `plane` is not below the native bits of `R`.
+pub fn bit_plane_suite<R: RNG + Clone + PartialEq>(
+    buffer: &mut Vec<u64>,
+    sample_size: usize,
+    plane: u32,
+    rng_name: &str,
+    expected_failures: &ExpectedFailures,
+) -> SuiteReport {
+    test_suite(
+        &mut rngs::adapters::BitPlane::from_rng(R::new(0), plane),
+        buffer,
+        sample_size,
+        &format!("{}[bit {}]", rng_name, plane),
+        expected_failures,
+    )
+}
+
+/// Run the test suite on every `stride`-th output of `R`, see `rngs::adapters::Decimated`.
+/// The stream is reported as `<rng_name>[stride <stride>]`.
+/// Panics if `stride` is zero.
+pub fn decimated_suite<R: RNG + Clone + PartialEq>(
+    buffer: &mut Vec<u64>,
+    sample_size: usize,
+    stride: u64,
+    rng_name: &str,
+    expected_failures: &ExpectedFailures,
+) -> SuiteReport {
+    test_suite(
+        &mut rngs::adapters::Decimated::from_rng(R::new(0), stride),
+        buffer,
+        sample_size,
+        &format!("{}[stride {}]", rng_name, stride),
+        expected_failures,
+    )
+}
+
+/// Run the test suite on each of `streams` interleaved sub-streams of `R`, see `rngs::adapters::Leapfrog`.
+/// The sub-streams are reported as `<rng_name>[leapfrog <index> of <streams>]`.
+/// Panics if `streams` is zero.
+pub fn leapfrog_suite<R: RNG + Clone + PartialEq>(
+    buffer: &mut Vec<u64>,
+    sample_size: usize,
+    streams: u64,
+    rng_name: &str,
+    expected_failures: &ExpectedFailures,
+) -> Vec<SuiteReport> {
+    rngs::adapters::Leapfrog::partition(R::new(0), streams)
+        .iter_mut()
+        .map(|sub_stream| {
+            let name = format!(
+                "{}[leapfrog {} of {}]",
+                rng_name,
+                sub_stream.index(),
+                streams
+            );
+            test_suite(sub_stream, buffer, sample_size, &name, expected_failures)
+        })
+        .collect()
 }
+
+/// Run the test suite on a derived stream of `R`, see `rngs::adapters::View`.
+/// The stream is reported as `<rng_name>[<view>]`.
+pub fn view_suite<R: RNG + Clone + PartialEq>(
+    buffer: &mut Vec<u64>,
+    sample_size: usize,
+    view: rngs::adapters::StreamView,
+    rng_name: &str,
+    expected_failures: &ExpectedFailures,
+) -> SuiteReport {
+    test_suite(
+        &mut rngs::adapters::View::from_rng(R::new(0), view),
+        buffer,
+        sample_size,
+        &format!("{}[{}]", rng_name, view.name()),
+        expected_failures,
+    )
+}
+
 /// Perform performance tests for supplied RNG.
+/// Tests listed in `expected_failures` are reported as OK if they fail.
 /// Allows supplying a custom list of seeds for testing.
+/// The samples are generated into `buffer`, which can be reused across runs.
 pub fn test_suite_with_seeds(
-    test_rng: &mut impl RNG,
+    test_rng: &mut (impl RNG + Clone + PartialEq),
+    buffer: &mut Vec<u64>,
     sample_size: usize,
     seeds: &[u64],
     rng_name: &str,
     test_weak_seeds: bool,
-) {
+    expected_failures: &ExpectedFailures,
+) -> SuiteReport {
     let full_start = std::time::Instant::now();
     let result_file_path = get_result_file_path(rng_name);
     utils::write_and_print(format!("\nTesting: {}", rng_name), &result_file_path);
+    utils::write_and_print(
+        format!("Scoring: {}", scoring::selected().name()),
+        &result_file_path,
+    );
     let mut test_results: Vec<TestResult> = vec![];
-    utils::write_and_print(speed_test(test_rng, sample_size), &result_file_path);
+    let mut seed_reports: Vec<SeedReport> = vec![];
+    let (speed_line, bytes_per_second) = speed_test(test_rng, buffer, sample_size);
+    utils::write_and_print(speed_line, &result_file_path);
     for &seed in seeds.iter() {
-        test_single_seed(
+        let seed_results = test_single_seed(
             test_rng,
+            buffer,
             sample_size,
             seed,
-            &mut test_results,
+            expected_failures,
             &result_file_path,
         );
+        seed_reports.push(SeedReport {
+            seed,
+            results: seed_results.iter().map(TestResult::record).collect(),
+        });
+        test_results.extend(seed_results);
     }
-    if test_weak_seeds {
+    let seed_avalanche_result =
+        seed_avalanche_single_test(test_rng, seeds).with_expectation(expected_failures);
+    write_and_print(seed_avalanche_result.format(), &result_file_path);
+    test_results.push(seed_avalanche_result);
+    let split_correlation_result =
+        split_correlation_single_test(test_rng, seeds).with_expectation(expected_failures);
+    write_and_print(split_correlation_result.format(), &result_file_path);
+    test_results.push(split_correlation_result);
+    let second_level_result =
+        second_level_single_test(&test_results).with_expectation(expected_failures);
+    write_and_print(second_level_result.format(), &result_file_path);
+    test_results.push(second_level_result);
+    let second_level_results = second_level_per_test(&test_results, expected_failures);
+    write_and_print(
+        format!("Second level, p values over {} seeds:", seeds.len()),
+        &result_file_path,
+    );
+    for rslt in &second_level_results {
+        write_and_print(rslt.format(), &result_file_path);
+    }
+    test_results.extend(second_level_results.iter().copied());
+    let mut period: Option<analysis::PeriodCertificate> = None;
+    if let Some(&seed) = seeds.first() {
+        utils::write_and_print(
+            bit_position_report(test_rng, buffer, sample_size, seed),
+            &result_file_path,
+        );
+        // The buffer still holds the outputs of the first seed.
+        utils::write_and_print(
+            analysis::format_monte_carlo_estimates(&analysis::monte_carlo_estimates(buffer)),
+            &result_file_path,
+        );
+        if test_rng.is_deterministic() {
+            let (certificate, line) = period_report(test_rng, seed);
+            utils::write_and_print(line, &result_file_path);
+            period = Some(certificate);
+        } else {
+            utils::write_and_print(
+                "Period: not applicable, the output is not reproducible".to_string(),
+                &result_file_path,
+            );
+        }
+    }
+    let metadata =
+        rngs::registry()
+            .find(|&(name, _, _)| name == rng_name)
+            .map(|(_, metadata, _)| rngs::RngMetadata {
+                period_lower_bound: period
+                    .as_ref()
+                    .map(analysis::PeriodCertificate::lower_bound),
+                ..metadata
+            });
+    let seek_consistent = seek_consistency(test_rng, seeds.first().copied().unwrap_or(0));
+    if let Some(consistent) = seek_consistent {
+        utils::write_and_print(
+            format!(
+                "Seek consistency: {}",
+                if consistent {
+                    strings::PASS_STR
+                } else {
+                    strings::FAIL_STR
+                }
+            ),
+            &result_file_path,
+        );
+    }
+    let state_restorable = state_round_trip(test_rng, seeds.first().copied().unwrap_or(0));
+    if let Some(restorable) = state_restorable {
         utils::write_and_print(
             format!(
-                "Found weak seeds: {:?}",
-                weak_seeds_tests(test_rng, sample_size, &result_file_path)
+                "State round trip: {}",
+                if restorable {
+                    strings::PASS_STR
+                } else {
+                    strings::FAIL_STR
+                }
             ),
             &result_file_path,
         );
     }
+    let mut weak_seeds: Option<Vec<u64>> = None;
+    if test_weak_seeds {
+        let found_weak_seeds = weak_seeds_tests(test_rng, buffer, sample_size, &result_file_path);
+        utils::write_and_print(
+            format!("Found weak seeds: {:?}", found_weak_seeds),
+            &result_file_path,
+        );
+        weak_seeds = Some(found_weak_seeds);
+    }
     utils::write_and_print(format!("\nSummary for: {}", rng_name), &result_file_path);
     utils::write_and_print(
         format_test_results_summary(&test_results),
@@ -266,4 +868,238 @@ pub fn test_suite_with_seeds(
         format!("Total runtime: {:?}", full_start.elapsed()),
         &result_file_path,
     );
+    SuiteReport {
+        rng_name: rng_name.to_string(),
+        sample_size,
+        scoring: scoring::selected().name(),
+        bytes_per_second,
+        seeds: seed_reports,
+        generator_results: vec![
+            seed_avalanche_result.record(),
+            split_correlation_result.record(),
+            second_level_result.record(),
+        ],
+        second_level_results: second_level_results
+            .iter()
+            .map(TestResult::record)
+            .collect(),
+        period,
+        metadata,
+        seek_consistent,
+        state_restorable,
+        weak_seeds,
+        passed: overall_passed(&test_results)
+            && seek_consistent != Some(false)
+            && state_restorable != Some(false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scoring::P_LOG_STAT_MAX;
+
+    fn result_with_p(p: f64, expected_failures: &ExpectedFailures) -> TestResult {
+        TestResult::new(strings::TEST_NAMES[0], Some(p)).with_expectation(expected_failures)
+    }
+
+    #[test]
+    fn expected_failure_verdicts() {
+        let expected = ExpectedFailures::Tests(&["Bytes"]);
+        assert!(result_with_p(0.0, &expected).failed_as_expected());
+        assert!(result_with_p(0.5, &expected).passed_unexpectedly());
+        assert!(!result_with_p(0.0, &ExpectedFailures::Nothing).failed_as_expected());
+        assert!(ExpectedFailures::All.contains("SeedAval"));
+        assert!(!expected.contains("Mono"));
+    }
+
+    #[test]
+    fn expected_failure_summary() {
+        let passing = format_test_results_summary(&[
+            result_with_p(0.0, &ExpectedFailures::All),
+            result_with_p(0.5, &ExpectedFailures::Nothing),
+        ]);
+        assert!(passing.contains(&format!("Overall result: {}", strings::PASS_STR)));
+        let unexpected = format_test_results_summary(&[result_with_p(0.5, &ExpectedFailures::All)]);
+        assert!(unexpected.contains(&format!("Overall result: {}", strings::FAIL_STR)));
+        assert!(unexpected.contains("1 unexpected pass"));
+    }
+
+    #[test]
+    fn threshold_verdicts() {
+        // p log stat is exactly 2.0 at p = 2^-9 and 4.0 at p = 2^-19, both are marginal.
+        let at_marginal = result_with_p(2f64.powi(-9), &ExpectedFailures::Nothing);
+        assert_eq!(at_marginal.logstat(), Some(P_LOG_STAT_LIMIT_MARGINAL));
+        assert_eq!(at_marginal.verdict(), Verdict::Marginal);
+        let at_fail = result_with_p(2f64.powi(-19), &ExpectedFailures::Nothing);
+        assert_eq!(at_fail.logstat(), Some(P_LOG_STAT_LIMIT_FAIL));
+        assert_eq!(at_fail.verdict(), Verdict::Marginal);
+        let below_marginal = result_with_p(2f64.powi(-9) * 1.01, &ExpectedFailures::Nothing);
+        assert_eq!(below_marginal.verdict(), Verdict::Pass);
+        let beyond_fail = result_with_p(1.0 - 2f64.powi(-20), &ExpectedFailures::Nothing);
+        assert_eq!(beyond_fail.verdict(), Verdict::Fail);
+        assert_eq!(
+            result_with_p(0.5, &ExpectedFailures::Nothing).logstat(),
+            Some(0.4)
+        );
+        // Exactly one of the three categories applies.
+        for p in [0.0, 2f64.powi(-19), 2f64.powi(-9), 0.3, 0.5, 1.0, f64::NAN] {
+            let result = result_with_p(p, &ExpectedFailures::Nothing);
+            let categories = [result.passed(), result.marginal(), result.failed()];
+            assert_eq!(categories.iter().filter(|&&c| c).count(), 1, "p = {}", p);
+        }
+    }
+
+    #[test]
+    fn invalid_p_values() {
+        for p in [f64::NAN, -0.5, 1.5, f64::INFINITY, f64::NEG_INFINITY] {
+            let result = result_with_p(p, &ExpectedFailures::Nothing);
+            assert_eq!(result.logstat(), Some(P_LOG_STAT_MAX));
+            assert_eq!(result.verdict(), Verdict::Fail);
+            assert!(!result.format().is_empty());
+        }
+        let summary = format_test_results_summary(&[
+            result_with_p(f64::NAN, &ExpectedFailures::Nothing),
+            result_with_p(-1.0, &ExpectedFailures::Nothing),
+        ]);
+        assert!(summary.contains(" 9+ : 0002"));
+        assert!(summary.contains(&format!("Overall result: {}", strings::FAIL_STR)));
+        assert!(summary.contains("2 failed"));
+    }
+
+    #[test]
+    fn marginal_summary() {
+        // 5% of all results may be marginal.
+        let marginal = result_with_p(0.001, &ExpectedFailures::Nothing);
+        let passing = result_with_p(0.5, &ExpectedFailures::Nothing);
+        let mut results = vec![passing; 19];
+        results.push(marginal);
+        assert!(overall_passed(&results));
+        results[0] = marginal;
+        assert!(!overall_passed(&results));
+        let all_marginal = format_test_results_summary(&[marginal; 20]);
+        assert!(all_marginal.contains(&format!("Overall result: {}", strings::FAIL_STR)));
+        assert!(all_marginal.contains("( 0 passed; 20 marginal; 0 failed;"));
+        let empty = format_test_results_summary(&[]);
+        assert!(empty.contains("0 total"));
+    }
+
+    #[test]
+    fn not_applicable_results() {
+        let not_applicable =
+            TestResult::new(strings::TEST_NAMES[0], None).with_expectation(&ExpectedFailures::All);
+        assert_eq!(not_applicable.logstat(), None);
+        assert_eq!(not_applicable.verdict(), Verdict::NotApplicable);
+        assert!(!not_applicable.failed_as_expected());
+        assert!(!not_applicable.passed_unexpectedly());
+        assert!(not_applicable.format().contains("p: N/A"));
+        assert!(not_applicable
+            .format_with(&scoring::ZScore)
+            .contains("z: N/A"));
+        // Not applicable results neither fail a run nor dilute the marginal fraction.
+        let marginal = result_with_p(0.001, &ExpectedFailures::Nothing);
+        let mut results = vec![result_with_p(0.5, &ExpectedFailures::Nothing); 19];
+        results.push(marginal);
+        assert!(overall_passed(&results));
+        results.push(not_applicable);
+        assert!(overall_passed(&results));
+        results[0] = not_applicable;
+        assert!(!overall_passed(&results));
+        let summary = format_test_results_summary(&[
+            not_applicable,
+            result_with_p(0.5, &ExpectedFailures::Nothing),
+        ]);
+        assert!(summary.contains(" 0 : 0001| 1 : 0000|"));
+        assert!(summary.contains("( 1 passed;"));
+        assert!(summary.contains("1 n/a; 2 total"));
+        assert!(summary.contains(&format!("Overall result: {}", strings::PASS_STR)));
+    }
+
+    #[test]
+    fn battery_p_values_valid() {
+        // Degenerate or short data gives a valid p value or None, never NaN.
+        for length in [0, 1, 15, 100, 512] {
+            let streams: [Vec<u64>; 4] = [
+                stats::generate_test_data(&mut rngs::testgens::OnlyOne::new(0), length).0,
+                stats::generate_test_data(&mut rngs::testgens::OnlyZero::new(0), length).0,
+                stats::generate_test_data(&mut rngs::testgens::AlternatingBits::new(0), length).0,
+                stats::generate_test_data(&mut rngs::ReferenceRand::new(0), length).0,
+            ];
+            for test_data in &streams {
+                for (name, test) in strings::TEST_NAMES.iter().zip(TEST_F_POINTERS) {
+                    if let Some(p) = test(test_data) {
+                        assert!((0.0..=1.0).contains(&p), "{} at {}: {}", name, length, p);
+                    }
+                }
+            }
+        }
+        for test in TEST_F_POINTERS {
+            assert_eq!(test(&[]), None);
+        }
+    }
+
+    #[test]
+    fn golden_p_values() {
+        // p values of the battery for a fixed stream, identical on every target
+        // up to rounding differences of the math library.
+        // The monkey tests need 2^21 words and are not applicable to this stream.
+        const GOLDEN_P_VALUES: [Option<f64>; 33] = [
+            Some(0.13313096853172712),
+            Some(0.24182035368347268),
+            Some(0.6937916883097353),
+            Some(0.06498085928158473),
+            Some(0.9098633253300833),
+            Some(0.09221812000870229),
+            Some(0.25542511335171986),
+            Some(0.9359551446075028),
+            Some(0.6101212886449732),
+            Some(0.3416054498507695),
+            Some(0.29722599626800816),
+            Some(0.3134209143037472),
+            Some(0.48024975622137167),
+            Some(0.04943548498380359),
+            Some(0.8534513109907511),
+            Some(0.02526133580830564),
+            None,
+            None,
+            None,
+            Some(0.4506011120051377),
+            Some(0.6419383348836432),
+            Some(0.7206855637821536),
+            Some(0.07010407613610421),
+            Some(0.8984392063678036),
+            Some(0.8089961476441715),
+            Some(0.8974235825384512),
+            Some(0.9844077593479712),
+            Some(0.6678715221054524),
+            Some(0.1827114159299864),
+            Some(0.06523351677368106),
+            Some(0.10234873240022188),
+            Some(0.8032332185000222),
+            Some(0.569984408094167),
+        ];
+        // Smallest sample size at which every other test is applicable.
+        let (test_data, _) = stats::generate_test_data(
+            &mut rngs::xorshift::WyRand::new(0x0123456789abcdef),
+            33 << 14,
+        );
+        assert_eq!(strings::TEST_NAMES.len(), TEST_F_POINTERS.len());
+        assert_eq!(GOLDEN_P_VALUES.len(), TEST_F_POINTERS.len());
+        for ((name, test), expected) in strings::TEST_NAMES
+            .iter()
+            .zip(TEST_F_POINTERS)
+            .zip(GOLDEN_P_VALUES)
+        {
+            match (test(&test_data), expected) {
+                (Some(p), Some(expected)) => assert!(
+                    (p - expected).abs() < 1e-9,
+                    "{}: {} != {}",
+                    name,
+                    p,
+                    expected
+                ),
+                (p, expected) => assert_eq!(p, expected, "{}", name),
+            }
+        }
+    }
 }
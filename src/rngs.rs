@@ -3,13 +3,15 @@
 // Dual-licensed under Apache 2.0 and MIT terms.
 
 //! Implementation of various rngs.
-//! All implement the RNG interface, some feature additional methods like:
-//! seek(delta: usize)
+//! All implement the RNG interface, generators that jump to any position of their stream
+//! also implement SeekableRNG.
 
 /// General trait for PRNGs
 pub trait RNG {
     /// Initialize with specified seed.
-    fn new(seed: u64) -> Self;
+    fn new(seed: u64) -> Self
+    where
+        Self: Sized;
     /// Generate u32 and advance the state one step.
     fn next_u32(&mut self) -> u32;
     /// Generate u64 and advance the state one step.
@@ -19,15 +21,84 @@ pub trait RNG {
     /// Advance the generator state by the specified amount of steps.
     /// For generators that dont support seek this takes a similar
     /// amount of time to generating (delta) outputs.
-    fn advance(&mut self, delta: usize);
+    fn advance(&mut self, delta: u64);
     /// Reset to inital state, equivalent to repalcing with ::new(seed).
     fn reseed(&mut self, seed: u64);
+    /// Number of bits the generator natively produces per step.
+    fn native_bits(&self) -> u32 {
+        64
+    }
+    /// Generate one native output and advance the state one step.
+    /// The output occupies the lower `native_bits()` bits.
+    fn next_native(&mut self) -> u64 {
+        self.next()
+    }
+    /// Whether the generator can run on this machine, false for hardware generators
+    /// on CPUs without the instruction.
+    fn is_available(&self) -> bool {
+        true
+    }
+    /// Whether the output is determined by the seed, false for hardware and operating system
+    /// entropy sources. Their state comparisons and periods say nothing about the output.
+    fn is_deterministic(&self) -> bool {
+        true
+    }
+    /// Derive a child generator, e.g. for the subtasks of a tree of parallel tasks.
+    /// The child is seeded with the SplitMix64 hash of the next output, so the parent advances one step
+    /// and repeating the same splits gives the same generators.
+    fn split(&mut self) -> Self
+    where
+        Self: Sized,
+    {
+        Self::new(splitmix::mix64(self.next()))
+    }
+    /// The complete state, encoded with `state::Encode`, from which `from_state_bytes` resumes exactly.
+    /// `None` for generators whose state can not be exported, like hardware generators.
+    fn state_bytes(&self) -> Option<Vec<u8>> {
+        None
+    }
+    /// Restore a generator from the output of `state_bytes`.
+    /// Fails if the bytes have the wrong length or describe an invalid state, or if the generator does not export its state.
+    fn from_state_bytes(bytes: &[u8]) -> Result<Self, String>
+    where
+        Self: Sized,
+    {
+        let _ = bytes;
+        Err("Generator does not support state export".to_string())
+    }
+    /// The generator as a `SeekableRNG`, if it implements it. Lets the test suite check seeking
+    /// without knowing the type.
+    fn as_seekable(&mut self) -> Option<&mut dyn SeekableRNG> {
+        None
+    }
 }
 
+/// Generators that jump to any position of their stream in O(1), like counter based generators.
+/// Positions count native outputs from the start of the current stream, which is selected by the seed or key:
+/// `seek(n)` gives the state reached by `n` native steps from position 0, wrapping around at the end of the stream.
+/// Implementations also return themselves from `RNG::as_seekable`.
+pub trait SeekableRNG: RNG {
+    /// Jump to `position` in the current stream.
+    fn seek(&mut self, position: u128);
+    /// Number of native outputs since the start of the current stream, modulo the stream length.
+    fn position(&self) -> u128;
+}
+
+/// Generators with several streams selected by an identifier, so parallel workers can derive
+/// distinct streams from one seed: `let mut rng = R::new(seed); rng.set_stream(worker);`.
+pub trait Streamed: RNG {
+    /// Switch to stream `stream`. Setting the same stream twice has no further effect.
+    fn set_stream(&mut self, stream: u64);
+}
+
+/// Wrapper around the StdRng of the rand crate, used as the reference for speed and quality.
+#[cfg(feature = "rand-compat")]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ReferenceRand {
     rng: rand::rngs::StdRng,
 }
 
+#[cfg(feature = "rand-compat")]
 impl RNG for ReferenceRand {
     fn new(seed: u64) -> Self {
         ReferenceRand {
@@ -43,7 +114,7 @@ impl RNG for ReferenceRand {
         rand::RngCore::next_u64(&mut self.rng)
     }
 
-    fn advance(&mut self, delta: usize) {
+    fn advance(&mut self, delta: u64) {
         for _ in 0..delta {
             let _ = self.next();
         }
@@ -54,14 +125,78 @@ impl RNG for ReferenceRand {
     }
 }
 
+/// Words per read from the operating system.
+#[cfg(feature = "rand-compat")]
+const OS_ENTROPY_WORDS: usize = 32;
+
+/// The CSPRNG of the operating system, read through the OsRng of the rand crate,
+/// which calls getrandom or reads /dev/urandom. The seed is ignored and the output can not be reproduced.
+/// Reads `OS_ENTROPY_WORDS` words at a time, the .next_u32() method uses the lower half of a word.
+#[cfg(feature = "rand-compat")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OsEntropy {
+    buffer: [u64; OS_ENTROPY_WORDS],
+    /// Index of the next word of `buffer`, `OS_ENTROPY_WORDS` once it is used up.
+    index: usize,
+}
+
+#[cfg(feature = "rand-compat")]
+impl OsEntropy {
+    /// Fetch 64 bits, reading from the operating system if the buffer is used up.
+    pub fn try_next(&mut self) -> Result<u64, String> {
+        if self.index >= OS_ENTROPY_WORDS {
+            let mut bytes = [0u8; 8 * OS_ENTROPY_WORDS];
+            rand::TryRngCore::try_fill_bytes(&mut rand::rngs::OsRng, &mut bytes)
+                .map_err(|error| format!("Reading from the OS entropy source failed: {}", error))?;
+            for (word, bytes) in self.buffer.iter_mut().zip(bytes.chunks_exact(8)) {
+                *word = u64::from_le_bytes(bytes.try_into().unwrap());
+            }
+            self.index = 0;
+        }
+        self.index += 1;
+        Ok(self.buffer[self.index - 1])
+    }
+}
+
+#[cfg(feature = "rand-compat")]
+impl RNG for OsEntropy {
+    /// The seed is ignored.
+    fn new(_seed: u64) -> Self {
+        OsEntropy {
+            buffer: [0; OS_ENTROPY_WORDS],
+            index: OS_ENTROPY_WORDS,
+        }
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        self.next() as u32
+    }
+
+    fn next(&mut self) -> u64 {
+        self.try_next().unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    fn advance(&mut self, delta: u64) {
+        for _ in 0..delta {
+            let _ = self.next();
+        }
+    }
+
+    fn reseed(&mut self, _seed: u64) {}
+
+    fn is_deterministic(&self) -> bool {
+        false
+    }
+}
+
 /// Steam cipher based, add–rotate–XOR PRNG with non linear step.
-/// Allows seeking to any position in the output stream.
+/// Implements SeekableRNG.
 pub mod stream_nlarx {
-    use super::RNG;
+    use super::{state, SeekableRNG, Streamed, RNG};
     const INITIAL_STATE: u64 = 0;
     const N_ROUNDS: usize = 6;
 
-    #[derive(Debug, Copy, Clone)]
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
     pub struct StreamNLARXu128 {
         state: u128,
     }
@@ -91,7 +226,7 @@ pub mod stream_nlarx {
                 state: (seed as u128) << 64 | INITIAL_STATE as u128,
             }
         }
-        fn advance(&mut self, delta: usize) {
+        fn advance(&mut self, delta: u64) {
             self.state = (self.state & 0xffffffffffffffff0000000000000000)
                 | (self.state.wrapping_add(delta as u128) & 0x0000000000000000ffffffffffffffff);
         }
@@ -108,44 +243,95 @@ pub mod stream_nlarx {
         fn reseed(&mut self, seed: u64) {
             self.state = (seed as u128) << 64 | INITIAL_STATE as u128;
         }
+
+        fn as_seekable(&mut self) -> Option<&mut dyn SeekableRNG> {
+            Some(self)
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&self.state))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            Ok(Self {
+                state: state::decode(bytes)?,
+            })
+        }
+    }
+    /// The stream has 2^64 outputs, the position is the lower half of the state.
+    impl SeekableRNG for StreamNLARXu128 {
+        fn seek(&mut self, position: u128) {
+            self.state =
+                (self.state & 0xffffffffffffffff0000000000000000) | position as u64 as u128;
+        }
+
+        fn position(&self) -> u128 {
+            self.state as u64 as u128
+        }
     }
-    impl StreamNLARXu128 {
-        pub fn seek(&mut self, counter: u64) {
-            self.state = (self.state & 0xffffffffffffffff0000000000000000) | counter as u128;
+    /// The stream is the upper half of the state, which `new` sets to the seed.
+    /// Switching keeps the position.
+    impl Streamed for StreamNLARXu128 {
+        fn set_stream(&mut self, stream: u64) {
+            self.state = (stream as u128) << 64 | self.state as u64 as u128;
         }
     }
 }
 
-// Xorshift PRNGs
-pub mod xorshift {
-    use super::RNG;
-    #[derive(Debug, Copy, Clone)]
-    pub struct XORShift128 {
+/// Tyche and Tyche-i of Neves and Araujo, small state ARX generators built from the ChaCha quarter round.
+/// The state update is nonlinear, there is no seek and the expected cycle length of a random state is about 2^127.
+/// See https://eden.dei.uc.pt/~sneves/pubs/2011-snfa2.pdf
+pub mod tyche {
+    use super::{state, RNG};
+
+    /// Rounds discarded after seeding.
+    const WARMUP_ROUNDS: usize = 20;
+    /// Initial value of the third state word.
+    const SEED_CONSTANT_C: u32 = 2654435769;
+    /// Xored with the stream index to form the initial fourth state word.
+    const SEED_CONSTANT_D: u32 = 1367130551;
+
+    /// Tyche, one ChaCha quarter round per step, outputs the second state word.
+    pub type TycheRng = Tyche<false>;
+    /// Tyche-i, the inverse quarter round per step, which has a shorter dependency chain and is faster.
+    /// Outputs the first state word.
+    pub type TycheI = Tyche<true>;
+
+    /// Tyche-i if `INVERSE` is set, Tyche otherwise.
+    /// The .next() method uses two outputs, the first output fills the upper half.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Tyche<const INVERSE: bool> {
         state: [u32; 4],
     }
 
-    impl RNG for XORShift128 {
+    impl<const INVERSE: bool> RNG for Tyche<INVERSE> {
         fn new(seed: u64) -> Self {
-            XORShift128 {
-                state: [
-                    seed as u32,
-                    (seed >> 32) as u32,
-                    seed as u32,
-                    (seed >> 32) as u32,
-                ],
-            }
+            Self::with_stream(seed, 0)
         }
 
         fn next_u32(&mut self) -> u32 {
-            let mut t: u32 = self.state[3];
-            let s: u32 = self.state[0];
-            self.state[3] = self.state[2];
-            self.state[2] = self.state[1];
-            self.state[1] = s;
-            t ^= t << 11;
-            t ^= t >> 8;
-            self.state[0] = t ^ s ^ (s >> 19);
-            self.state[0]
+            let [a, b, c, d] = &mut self.state;
+            if INVERSE {
+                *b = b.rotate_left(25) ^ *c;
+                *c = c.wrapping_sub(*d);
+                *d = d.rotate_left(24) ^ *a;
+                *a = a.wrapping_sub(*b);
+                *b = b.rotate_left(20) ^ *c;
+                *c = c.wrapping_sub(*d);
+                *d = d.rotate_left(16) ^ *a;
+                *a = a.wrapping_sub(*b);
+                *a
+            } else {
+                *a = a.wrapping_add(*b);
+                *d = (*d ^ *a).rotate_left(16);
+                *c = c.wrapping_add(*d);
+                *b = (*b ^ *c).rotate_left(12);
+                *a = a.wrapping_add(*b);
+                *d = (*d ^ *a).rotate_left(8);
+                *c = c.wrapping_add(*d);
+                *b = (*b ^ *c).rotate_left(7);
+                *b
+            }
         }
 
         fn next(&mut self) -> u64 {
@@ -154,536 +340,7733 @@ pub mod xorshift {
             (a << 32) | b
         }
 
-        fn advance(&mut self, delta: usize) {
+        fn advance(&mut self, delta: u64) {
             for _ in 0..delta {
                 let _ = self.next_u32();
             }
         }
 
         fn reseed(&mut self, seed: u64) {
-            self.state = [
-                seed as u32,
-                (seed >> 32) as u32,
-                seed as u32,
-                (seed >> 32) as u32,
-            ];
+            *self = Self::new(seed);
         }
-    }
 
-    /// RapidHash-based PRNG implementation
-    pub struct RapidHashRNG {
-        state: u64,
-    }
+        fn native_bits(&self) -> u32 {
+            32
+        }
 
-    impl RNG for RapidHashRNG {
-        fn new(seed: u64) -> Self {
-            let mut rng = Self { state: seed };
-            // Mix the initial state to avoid poor quality with simple seeds
-            for _ in 0..8 {
-                rng.next();
-            }
-            rng
+        fn next_native(&mut self) -> u64 {
+            self.next_u32() as u64
         }
 
-        fn next(&mut self) -> u64 {
-            // RapidHash-inspired mixing function
-            // This is a simplified version focusing on good avalanche properties
-            let mut value = self.state;
-            
-            // First mixing round
-            value ^= value >> 32;
-            value = value.wrapping_mul(0x9e3779b97f4a7c15);
-            value ^= value >> 32;
-            
-            // Second mixing round with different constants
-            value = value.wrapping_mul(0xbf58476d1ce4e5b9);
-            value ^= value >> 32;
-            
-            // Third mixing round
-            value = value.wrapping_mul(0x94d049bb133111eb);
-            value ^= value >> 32;
-            
-            // Update state with the mixed value
-            self.state = self.state.wrapping_add(1);
-            
-            value
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&self.state))
         }
 
-        fn next_u32(&mut self) -> u32 {
-            self.next() as u32
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            Ok(Self {
+                state: state::decode(bytes)?,
+            })
         }
+    }
 
-        fn advance(&mut self, delta: usize) {
-            // Simply advance the state counter by the specified amount
-            self.state = self.state.wrapping_add(delta as u64);
+    impl<const INVERSE: bool> Tyche<INVERSE> {
+        /// Initialize like the reference implementation, with the seed in the first two words
+        /// and the stream index mixed into the fourth, then discard 20 rounds.
+        /// Different streams of the same seed start in different states.
+        pub fn with_stream(seed: u64, stream: u32) -> Self {
+            let mut rng = Self::from_state([
+                (seed >> 32) as u32,
+                seed as u32,
+                SEED_CONSTANT_C,
+                SEED_CONSTANT_D ^ stream,
+            ]);
+            rng.advance(WARMUP_ROUNDS as u64);
+            rng
         }
 
-        fn reseed(&mut self, seed: u64) {
-            *self = Self::new(seed);
+        /// Start from the raw state words a, b, c and d without discarding rounds.
+        pub fn from_state(state: [u32; 4]) -> Self {
+            Self { state }
         }
     }
+}
 
-    pub struct WyRand {
-        seed: u64,
+/// Byte encoding of generator states for `RNG::state_bytes` and `RNG::from_state_bytes`.
+/// Fields are concatenated in declaration order without padding or a header, integers are little endian
+/// and `usize` is stored as a u64, so the encoding is the same on every platform.
+/// Buffers that are derived from the rest of the state, like the keystream blocks of counter based generators, are not stored.
+pub mod state {
+    /// Value with a fixed size byte encoding.
+    pub trait Encode: Sized {
+        fn encode(&self, bytes: &mut Vec<u8>);
+        /// Decode a value from the front of `bytes` and remove the used bytes.
+        fn decode(bytes: &mut &[u8]) -> Result<Self, String>;
     }
 
-    impl RNG for WyRand {
-        fn new(seed: u64) -> Self {
-            let mut new_rng = Self { seed };
-            for _ in 0..8 {
-                let _ = new_rng.next();
-            }
-            new_rng
-        }
+    macro_rules! impl_encode_integer {
+        ($($int:ty),*) => {
+            $(
+                impl Encode for $int {
+                    fn encode(&self, bytes: &mut Vec<u8>) {
+                        bytes.extend_from_slice(&self.to_le_bytes());
+                    }
 
-        fn next(&mut self) -> u64 {
-            self.seed = self.seed.wrapping_add(0xa0761d6478bd642f);
-            let t = (self.seed as u128).wrapping_mul((self.seed ^ 0xe7037ed1a0b428db) as u128);
-            (t.wrapping_shr(64) ^ t) as u64
-        }
+                    fn decode(bytes: &mut &[u8]) -> Result<Self, String> {
+                        let Some((word, rest)) = bytes.split_first_chunk() else {
+                            return Err("State is too short".to_string());
+                        };
+                        *bytes = rest;
+                        Ok(<$int>::from_le_bytes(*word))
+                    }
+                }
+            )*
+        };
+    }
 
-        fn next_u32(&mut self) -> u32 {
-            self.next() as u32
-        }
+    impl_encode_integer!(u8, u16, u32, u64, u128);
 
-        fn advance(&mut self, delta: usize) {
-            for _ in 0..delta {
-                let _ = self.next();
-            }
+    impl Encode for usize {
+        fn encode(&self, bytes: &mut Vec<u8>) {
+            (*self as u64).encode(bytes);
         }
 
-        fn reseed(&mut self, seed: u64) {
-            self.seed = seed;
+        fn decode(bytes: &mut &[u8]) -> Result<Self, String> {
+            usize::try_from(u64::decode(bytes)?).map_err(|_| "Index out of range".to_string())
         }
     }
 
-    pub struct RapidHashRNG2 {
-        state: u64,
-        seed: u64,
-    }
+    impl<T: Encode, const N: usize> Encode for [T; N] {
+        fn encode(&self, bytes: &mut Vec<u8>) {
+            for item in self {
+                item.encode(bytes);
+            }
+        }
 
-    impl RapidHashRNG2 {
-        /// A simple, fast mixing function (inspired by SplitMix64/rapidhash)
-        #[inline]
-        fn hash64(x: u64) -> u64 {
-            let mut z = x.wrapping_add(0x9E3779B97F4A7C15);
-            z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
-            z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
-            z ^ (z >> 31)
+        fn decode(bytes: &mut &[u8]) -> Result<Self, String> {
+            let mut items: Vec<T> = Vec::with_capacity(N);
+            for _ in 0..N {
+                items.push(T::decode(bytes)?);
+            }
+            match items.try_into() {
+                Ok(array) => Ok(array),
+                Err(_) => unreachable!("exactly N items were decoded"),
+            }
         }
     }
 
-    impl RNG for RapidHashRNG2 {
-        fn new(seed: u64) -> Self {
-            let mut new_rng = Self { state: 0, seed };
-            for _ in 0..8 {
-                let _ = new_rng.next();
+    macro_rules! impl_encode_tuple {
+        ($($name:ident),*) => {
+            impl<$($name: Encode),*> Encode for ($($name,)*) {
+                #[allow(non_snake_case)]
+                fn encode(&self, bytes: &mut Vec<u8>) {
+                    let ($($name,)*) = self;
+                    $($name.encode(bytes);)*
+                }
+
+                fn decode(bytes: &mut &[u8]) -> Result<Self, String> {
+                    Ok(($($name::decode(bytes)?,)*))
+                }
             }
-            new_rng
-        }
+        };
+    }
 
-        fn next(&mut self) -> u64 {
-            let result = Self::hash64(self.seed.wrapping_add(self.state));
-            self.state = self.state.wrapping_add(1);
-            result
-        }
+    impl_encode_tuple!(A, B);
+    impl_encode_tuple!(A, B, C);
+    impl_encode_tuple!(A, B, C, D);
+    impl_encode_tuple!(A, B, C, D, E);
+    impl_encode_tuple!(A, B, C, D, E, F);
 
-        fn next_u32(&mut self) -> u32 {
-            self.next() as u32
-        }
+    /// Encode a complete state.
+    pub fn encode<T: Encode>(value: &T) -> Vec<u8> {
+        let mut bytes: Vec<u8> = vec![];
+        value.encode(&mut bytes);
+        bytes
+    }
 
-        fn advance(&mut self, delta: usize) {
-            self.state = self.state.wrapping_add(delta as u64);
+    /// Decode a complete state, fails if `bytes` is too short or too long.
+    pub fn decode<T: Encode>(mut bytes: &[u8]) -> Result<T, String> {
+        let value = T::decode(&mut bytes)?;
+        if !bytes.is_empty() {
+            return Err(format!("State has {} bytes too many", bytes.len()));
         }
+        Ok(value)
+    }
 
-        fn reseed(&mut self, seed: u64) {
-            self.seed = seed;
-            self.state = 0;
+    /// Check that a decoded buffer index is in range.
+    pub fn check_index(index: usize, limit: usize) -> Result<usize, String> {
+        if index > limit {
+            return Err(format!("Index {} out of range, at most {}", index, limit));
         }
+        Ok(index)
     }
 }
 
-// Linear congruential generators
-pub mod lcg {
-    use super::RNG;
-    /// Ill concieved early LCG, that fails the spectral test badly.
-    /// Only has output space of 0-2**31-1.
-    /// The .next() method uses three RANDU calls to fill the 64 bit output space,
-    /// The .next_u32() method uses two RANDU calls.
-    /// the .next_small() method returns the reduced original output space.
-    #[derive(Debug, Copy, Clone)]
-    pub struct Randu {
-        state: u32,
+/// Polynomials over GF(2) for jumping ahead in generators whose state update is linear over GF(2).
+/// A polynomial is a little endian bit vector of u64 words, bit j is the coefficient of x^j.
+/// A characteristic polynomial of degree k is stored without its leading term x^k.
+/// If p is the characteristic polynomial of the state update T, then T^n = (x^n mod p)(T),
+/// so n steps cost k steps and O(k^2 log n) bit operations.
+pub mod gf2 {
+    /// State of a linear generator, a vector over GF(2).
+    pub trait Vector: Copy {
+        const ZERO: Self;
+        /// Add `other` to `self`, a bitwise xor.
+        fn add(&mut self, other: &Self);
     }
 
-    impl RNG for Randu {
-        fn new(seed: u64) -> Self {
-            Randu { state: seed as u32 }
-        }
+    macro_rules! impl_vector {
+        ($($word:ty),*) => {
+            $(
+                impl Vector for $word {
+                    const ZERO: Self = 0;
 
-        fn next_u32(&mut self) -> u32 {
-            let a: u32 = self.next_small();
-            let b: u32 = self.next_small();
-            a << 15 | (b & 0xffff)
-        }
+                    fn add(&mut self, other: &Self) {
+                        *self ^= other;
+                    }
+                }
 
-        fn next(&mut self) -> u64 {
-            let a: u64 = self.next_small() as u64;
-            let b: u64 = self.next_small() as u64;
-            let c: u64 = self.next_small() as u64;
-            (a << 42) | ((b & 0x3fffff) << 20) | (c & 0xfffff)
-        }
+                impl<const N: usize> Vector for [$word; N] {
+                    const ZERO: Self = [0; N];
 
-        fn advance(&mut self, delta: usize) {
-            for _ in 0..delta {
-                let _ = self.next_small();
+                    fn add(&mut self, other: &Self) {
+                        for (word, other) in self.iter_mut().zip(other) {
+                            *word ^= other;
+                        }
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_vector!(u32, u64, u128);
+
+    /// Minimal polynomial of a bit sequence whose linear complexity is at most `max_degree`,
+    /// by Berlekamp–Massey on the first 2 `max_degree` bits. Returns the polynomial without its leading term and its degree.
+    /// For a generator with the maximal period 2^k - 1, any nonzero state and any output bit give its characteristic polynomial.
+    pub fn minimal_polynomial(mut bit: impl FnMut() -> bool, max_degree: u32) -> (Vec<u64>, u32) {
+        let bits = 2 * max_degree as usize;
+        let sequence: Vec<bool> = (0..bits).map(|_| bit()).collect();
+        // Connection polynomial 1 + c_1 x + ... + c_L x^L with s_i = c_1 s_(i - 1) + ... + c_L s_(i - L).
+        let mut connection = vec![false; bits + 1];
+        connection[0] = true;
+        let mut previous = connection.clone();
+        let mut length = 0;
+        let mut shift = 1;
+        for i in 0..bits {
+            let discrepancy =
+                (0..=length).fold(false, |sum, j| sum ^ (connection[j] & sequence[i - j]));
+            if !discrepancy {
+                shift += 1;
+                continue;
+            }
+            let old = connection.clone();
+            for j in shift..=bits {
+                connection[j] ^= previous[j - shift];
+            }
+            if 2 * length <= i {
+                length = i + 1 - length;
+                previous = old;
+                shift = 1;
+            } else {
+                shift += 1;
             }
         }
-
-        fn reseed(&mut self, seed: u64) {
-            self.state = seed as u32;
+        // The characteristic polynomial is the reciprocal x^L + c_1 x^(L - 1) + ... + c_L.
+        let mut polynomial = vec![0; length.div_ceil(64)];
+        for j in (1..=length).filter(|&j| connection[j]) {
+            polynomial[(length - j) / 64] |= 1 << ((length - j) % 64);
         }
+        (polynomial, length as u32)
     }
-    impl Randu {
-        /// Generate a number in the original reduced output space of 0 to 2**31 - 1.
-        fn next_small(&mut self) -> u32 {
-            self.state = self.state.wrapping_mul(65539) & 0x7fffffff;
-            self.state
+
+    /// x^exponent modulo the characteristic polynomial `characteristic` of degree `degree`,
+    /// the jump polynomial for `exponent` steps. The exponent is given as little endian u64 words, e.g. `[0, 0, 1]` for 2^128.
+    pub fn jump_polynomial(exponent: &[u64], characteristic: &[u64], degree: u32) -> Vec<u64> {
+        let mut result = vec![0; degree.div_ceil(64) as usize];
+        result[0] = 1;
+        if degree == 0 {
+            return result;
         }
-    }
-    /// Originaly designed by Donald Knuth
-    #[derive(Debug, Copy, Clone)]
-    pub struct Mmix {
-        state: u64,
+        for bit in (0..64 * exponent.len()).rev() {
+            result = multiply(&result, &result, characteristic, degree);
+            if exponent[bit / 64] >> (bit % 64) & 1 == 1 {
+                result = times_x(result, characteristic, degree);
+            }
+        }
+        result
     }
 
-    impl RNG for Mmix {
-        fn new(seed: u64) -> Self {
-            Mmix { state: seed }
+    /// a * b modulo the characteristic polynomial.
+    fn multiply(a: &[u64], b: &[u64], characteristic: &[u64], degree: u32) -> Vec<u64> {
+        let mut product = vec![0; a.len()];
+        for bit in (0..degree as usize).rev() {
+            product = times_x(product, characteristic, degree);
+            if a[bit / 64] >> (bit % 64) & 1 == 1 {
+                for (word, &b) in product.iter_mut().zip(b) {
+                    *word ^= b;
+                }
+            }
         }
+        product
+    }
 
-        fn next_u32(&mut self) -> u32 {
-            self.next() as u32
+    /// x * a modulo the characteristic polynomial.
+    fn times_x(mut a: Vec<u64>, characteristic: &[u64], degree: u32) -> Vec<u64> {
+        let top = (degree - 1) as usize;
+        let overflow = a[top / 64] >> (top % 64) & 1 == 1;
+        for i in (0..a.len()).rev() {
+            a[i] = a[i] << 1 | if i > 0 { a[i - 1] >> 63 } else { 0 };
         }
-
-        fn next(&mut self) -> u64 {
-            self.state = self.state.wrapping_mul(0x5851f42d4c957f2d);
-            self.state = self.state.wrapping_add(0x14057b7ef767814f);
-            self.state
+        if !degree.is_multiple_of(64) {
+            a[top / 64] &= (1 << (degree % 64)) - 1;
         }
-
-        fn advance(&mut self, delta: usize) {
-            for _ in 0..delta {
-                let _ = self.next();
+        if overflow {
+            for (word, &coefficient) in a.iter_mut().zip(characteristic) {
+                *word ^= coefficient;
             }
         }
+        a
+    }
 
-        fn reseed(&mut self, seed: u64) {
-            self.state = seed;
+    /// Replace `state` by the sum of T^j state over the coefficients x^j of `polynomial`,
+    /// where `step` applies the state update T. With a jump polynomial this advances the state.
+    pub fn jump<V: Vector>(
+        state: &mut V,
+        polynomial: &[u64],
+        degree: u32,
+        mut step: impl FnMut(&mut V),
+    ) {
+        let mut jumped = V::ZERO;
+        for j in 0..degree as usize {
+            if polynomial[j / 64] >> (j % 64) & 1 == 1 {
+                jumped.add(state);
+            }
+            step(state);
         }
+        *state = jumped;
     }
-    #[derive(Debug, Copy, Clone)]
-    pub struct UlsLcg512 {
-        state: [u128; 4],
+}
+
+// Xorshift PRNGs
+pub mod xorshift {
+    use super::{gf2, splitmix, state, RNG};
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct XORShift128 {
+        state: [u32; 4],
     }
 
-    impl RNG for UlsLcg512 {
+    impl RNG for XORShift128 {
         fn new(seed: u64) -> Self {
-            UlsLcg512 {
+            XORShift128 {
                 state: [
-                    (!seed as u128) << 64 | !seed as u128,
-                    (seed as u128) << 64 | seed as u128,
-                    (seed as u128) << 64 | !seed as u128,
-                    (!seed as u128) << 64 | seed as u128,
+                    seed as u32,
+                    (seed >> 32) as u32,
+                    seed as u32,
+                    (seed >> 32) as u32,
+                ],
+            }
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            Self::step(&mut self.state);
+            self.state[0]
+        }
+
+        fn next(&mut self) -> u64 {
+            let a: u64 = self.next_u32() as u64;
+            let b: u64 = self.next_u32() as u64;
+            (a << 32) | b
+        }
+
+        /// Jumps with the characteristic polynomial for deltas of at least 128 steps.
+        fn advance(&mut self, delta: u64) {
+            if delta < 128 {
+                for _ in 0..delta {
+                    Self::step(&mut self.state);
+                }
+                return;
+            }
+            let polynomial = gf2::jump_polynomial(&[delta], &Self::CHARACTERISTIC_POLYNOMIAL, 128);
+            gf2::jump(&mut self.state, &polynomial, 128, Self::step);
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            self.state = [
+                seed as u32,
+                (seed >> 32) as u32,
+                seed as u32,
+                (seed >> 32) as u32,
+            ];
+        }
+
+        fn native_bits(&self) -> u32 {
+            32
+        }
+
+        fn next_native(&mut self) -> u64 {
+            self.next_u32() as u64
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&self.state))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            Ok(Self {
+                state: state::decode(bytes)?,
+            })
+        }
+    }
+
+    impl XORShift128 {
+        /// Characteristic polynomial of the state update without the leading term x^128, primitive for the period 2^128 - 1.
+        pub const CHARACTERISTIC_POLYNOMIAL: [u64; 2] = [0xf985d65ffd3c8001, 0x000000010046d8b3];
+        /// Jump polynomial for 2^64 steps, x^(2^64) modulo the characteristic polynomial.
+        pub const JUMP: [u64; 2] = [0x821e534335aac71c, 0xd8cd644ef52e65c4];
+
+        fn step(state: &mut [u32; 4]) {
+            let mut t: u32 = state[3];
+            let s: u32 = state[0];
+            state[3] = state[2];
+            state[2] = state[1];
+            state[1] = s;
+            t ^= t << 11;
+            t ^= t >> 8;
+            state[0] = t ^ s ^ (s >> 19);
+        }
+
+        /// Advance 2^64 steps, to split the period into 2^64 non-overlapping subsequences.
+        pub fn jump(&mut self) {
+            gf2::jump(&mut self.state, &Self::JUMP, 128, Self::step);
+        }
+    }
+
+    /// xorwow of Marsaglia, a 160 bit xorshift generator whose output is offset by a Weyl sequence.
+    /// The default generator of cuRAND, the state layout, seeding and outputs match curand_init and curand
+    /// with subsequence 0 and offset 0, the offset corresponds to `advance`.
+    /// The .next() method uses two outputs, the first output fills the upper half.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Xorwow {
+        state: [u32; 5],
+        weyl: u32,
+    }
+
+    impl RNG for Xorwow {
+        /// Initialize like curand_init of cuRAND.
+        fn new(seed: u64) -> Self {
+            let t0 = 1099087573u32.wrapping_mul(seed as u32 ^ 0xaad26b49);
+            let t1 = 2591861531u32.wrapping_mul((seed >> 32) as u32 ^ 0xf7dcefdd);
+            Self::from_state(
+                [
+                    Self::DEFAULT_STATE[0].wrapping_add(t0),
+                    Self::DEFAULT_STATE[1] ^ t0,
+                    Self::DEFAULT_STATE[2].wrapping_add(t1),
+                    Self::DEFAULT_STATE[3] ^ t1,
+                    Self::DEFAULT_STATE[4].wrapping_add(t0),
                 ],
+                Self::DEFAULT_WEYL.wrapping_add(t1).wrapping_add(t0),
+            )
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            Self::step(&mut self.state);
+            self.weyl = self.weyl.wrapping_add(Self::WEYL_INCREMENT);
+            self.state[4].wrapping_add(self.weyl)
+        }
+
+        fn next(&mut self) -> u64 {
+            let a: u64 = self.next_u32() as u64;
+            let b: u64 = self.next_u32() as u64;
+            (a << 32) | b
+        }
+
+        /// Jumps the xorshift words with the characteristic polynomial for deltas of at least 160 steps, like skipahead of cuRAND.
+        fn advance(&mut self, delta: u64) {
+            self.weyl = self
+                .weyl
+                .wrapping_add((delta as u32).wrapping_mul(Self::WEYL_INCREMENT));
+            if delta < 160 {
+                for _ in 0..delta {
+                    Self::step(&mut self.state);
+                }
+                return;
+            }
+            let polynomial = gf2::jump_polynomial(&[delta], &Self::CHARACTERISTIC_POLYNOMIAL, 160);
+            gf2::jump(&mut self.state, &polynomial, 160, Self::step);
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            *self = Self::new(seed);
+        }
+
+        fn native_bits(&self) -> u32 {
+            32
+        }
+
+        fn next_native(&mut self) -> u64 {
+            self.next_u32() as u64
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&(self.state, self.weyl)))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            let (state, weyl) = state::decode(bytes)?;
+            Ok(Self { state, weyl })
+        }
+    }
+
+    impl Xorwow {
+        pub const WEYL_INCREMENT: u32 = 362437;
+        /// Initial xorshift words of Marsaglia's paper, reached by `new` with the seed 0xf7dcefddaad26b49.
+        pub const DEFAULT_STATE: [u32; 5] = [123456789, 362436069, 521288629, 88675123, 5783321];
+        /// Initial Weyl counter of Marsaglia's paper.
+        pub const DEFAULT_WEYL: u32 = 6615241;
+        /// Characteristic polynomial of the xorshift words without the leading term x^160, primitive for the period 2^160 - 1.
+        pub const CHARACTERISTIC_POLYNOMIAL: [u64; 3] =
+            [0x0861003000060001, 0x0f3c003500062121, 0x0000000000000f0e];
+        /// Jump polynomial for 2^64 steps, x^(2^64) modulo the characteristic polynomial.
+        pub const JUMP: [u64; 3] = [0x22ed68326bd93537, 0x13e131d38b017aaf, 0x000000009c6b67eb];
+
+        /// Start from the xorshift words v[0], ..., v[4] and the Weyl counter d of curandStateXORWOW.
+        /// The xorshift words must not all be zero.
+        pub fn from_state(state: [u32; 5], weyl: u32) -> Self {
+            Xorwow { state, weyl }
+        }
+
+        fn step(state: &mut [u32; 5]) {
+            let t = state[0] ^ (state[0] >> 2);
+            state[0] = state[1];
+            state[1] = state[2];
+            state[2] = state[3];
+            state[3] = state[4];
+            state[4] = (state[4] ^ (state[4] << 4)) ^ (t ^ (t << 1));
+        }
+
+        /// Advance 2^64 steps, to split the period into 2^96 non-overlapping subsequences.
+        /// The Weyl counter has period 2^32 and is unchanged.
+        pub fn jump(&mut self) {
+            gf2::jump(&mut self.state, &Self::JUMP, 160, Self::step);
+        }
+    }
+
+    /// Output function of `Xorshift`.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub enum Scrambler {
+        /// The state itself.
+        None,
+        /// The state times an odd constant, like xorshift*.
+        Multiply(u64),
+        /// The sum of the state and the previous state, like xorshift+.
+        Plus,
+    }
+
+    /// Shift triple, word size and scrambler of `Xorshift`.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct XorshiftParameters {
+        /// a, b and c of x ^= x << a; x ^= x >> b; x ^= x << c.
+        pub shifts: [u32; 3],
+        /// Word size, 32 or 64.
+        pub bits: u32,
+        pub scrambler: Scrambler,
+    }
+
+    /// Prime factors of 2^32 - 1 and 2^64 - 1.
+    const PERIOD_FACTORS_32: [u64; 5] = [3, 5, 17, 257, 65537];
+    const PERIOD_FACTORS_64: [u64; 7] = [3, 5, 17, 257, 641, 65537, 6700417];
+
+    impl XorshiftParameters {
+        /// The 64 bit xorshift of Marsaglia (2003) with the triple 13, 7, 17.
+        pub const XORSHIFT64: Self = XorshiftParameters {
+            shifts: [13, 7, 17],
+            bits: 64,
+            scrambler: Scrambler::None,
+        };
+        /// The 32 bit xorshift of Marsaglia (2003) with the triple 13, 17, 5.
+        pub const XORSHIFT32: Self = XorshiftParameters {
+            shifts: [13, 17, 5],
+            bits: 32,
+            scrambler: Scrambler::None,
+        };
+
+        /// Check the word size, the shifts and the multiplier.
+        pub fn validate(&self) -> Result<(), String> {
+            if self.bits != 32 && self.bits != 64 {
+                return Err(format!("Word size {} is not 32 or 64.", self.bits));
+            }
+            if let Some(shift) = self
+                .shifts
+                .iter()
+                .find(|&&shift| !(1..self.bits).contains(&shift))
+            {
+                return Err(format!("Shift {} is outside of 1..{}.", shift, self.bits));
+            }
+            if let Scrambler::Multiply(multiplier) = self.scrambler {
+                if multiplier & 1 == 0 {
+                    return Err("The multiplier of the scrambler must be odd.".to_string());
+                }
+            }
+            Ok(())
+        }
+
+        /// Whether the state update has the maximal period 2^bits - 1, the criterion behind the tables of Marsaglia's paper:
+        /// the update matrix T over GF(2) satisfies T^(2^bits - 1) = I and T^((2^bits - 1) / p) != I for each prime factor p.
+        /// The parameters must be valid.
+        pub fn has_full_period(&self) -> bool {
+            let order = self.mask();
+            let factors: &[u64] = if self.bits == 32 {
+                &PERIOD_FACTORS_32
+            } else {
+                &PERIOD_FACTORS_64
+            };
+            let transition = self.transition_matrix();
+            let identity: Vec<u64> = (0..self.bits).map(|bit| 1 << bit).collect();
+            matrix_power(&transition, order) == identity
+                && factors
+                    .iter()
+                    .all(|factor| matrix_power(&transition, order / factor) != identity)
+        }
+
+        fn mask(&self) -> u64 {
+            u64::MAX >> (64 - self.bits)
+        }
+
+        fn step(&self, mut x: u64) -> u64 {
+            let [a, b, c] = self.shifts;
+            x ^= x << a;
+            x &= self.mask();
+            x ^= x >> b;
+            x ^= x << c;
+            x & self.mask()
+        }
+
+        /// Columns of the state update over GF(2), column j is the update of bit j alone.
+        fn transition_matrix(&self) -> Vec<u64> {
+            (0..self.bits).map(|bit| self.step(1 << bit)).collect()
+        }
+    }
+
+    /// Product of two GF(2) matrices given by their columns, `a` applied after `b`.
+    fn matrix_multiply(a: &[u64], b: &[u64]) -> Vec<u64> {
+        b.iter()
+            .map(|&column| {
+                (0..a.len())
+                    .filter(|bit| column >> bit & 1 == 1)
+                    .fold(0, |sum, bit| sum ^ a[bit])
+            })
+            .collect()
+    }
+
+    fn matrix_power(matrix: &[u64], mut exponent: u64) -> Vec<u64> {
+        let mut result: Vec<u64> = (0..matrix.len()).map(|bit| 1 << bit).collect();
+        let mut square = matrix.to_vec();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = matrix_multiply(&square, &result);
+            }
+            square = matrix_multiply(&square, &square);
+            exponent >>= 1;
+        }
+        result
+    }
+
+    /// Single word xorshift of Marsaglia with any shift triple, a 32 or 64 bit word and an optional scrambler,
+    /// to explore shift constants with the test suite. The parameters survive `reseed`.
+    /// For 32 bit words the .next() method uses two outputs, the first output fills the upper half.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Xorshift {
+        state: u64,
+        parameters: XorshiftParameters,
+    }
+
+    impl Xorshift {
+        /// Start from a nonzero state taken from the SplitMix64 output for `seed`.
+        pub fn with_parameters(seed: u64, parameters: XorshiftParameters) -> Result<Self, String> {
+            parameters.validate()?;
+            let mut rng = Xorshift {
+                state: 0,
+                parameters,
+            };
+            rng.reseed(seed);
+            Ok(rng)
+        }
+
+        /// Start from a raw state, which must be nonzero and fit the word size.
+        pub fn from_state(state: u64, parameters: XorshiftParameters) -> Result<Self, String> {
+            parameters.validate()?;
+            if state == 0 || state & !parameters.mask() != 0 {
+                return Err(format!(
+                    "State {:#x} is zero or wider than {} bits.",
+                    state, parameters.bits
+                ));
             }
+            Ok(Xorshift { state, parameters })
+        }
+
+        pub fn parameters(&self) -> XorshiftParameters {
+            self.parameters
+        }
+    }
+
+    impl RNG for Xorshift {
+        /// Uses `XorshiftParameters::XORSHIFT64`.
+        fn new(seed: u64) -> Self {
+            Self::with_parameters(seed, XorshiftParameters::XORSHIFT64).unwrap()
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.next_native() as u32
+        }
+
+        fn next(&mut self) -> u64 {
+            if self.parameters.bits == 64 {
+                return self.next_native();
+            }
+            let a: u64 = self.next_native();
+            let b: u64 = self.next_native();
+            (a << 32) | b
+        }
+
+        /// Raises the update matrix to the power `delta` for deltas of at least one step per bit.
+        fn advance(&mut self, delta: u64) {
+            if delta < self.parameters.bits as u64 {
+                for _ in 0..delta {
+                    self.state = self.parameters.step(self.state);
+                }
+                return;
+            }
+            let transition = matrix_power(&self.parameters.transition_matrix(), delta);
+            self.state = matrix_multiply(&transition, &[self.state])[0];
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            self.state = splitmix::expand_seed::<1>(seed)[0] & self.parameters.mask();
+            if self.state == 0 {
+                self.state = 1;
+            }
+        }
+
+        fn native_bits(&self) -> u32 {
+            self.parameters.bits
+        }
+
+        fn next_native(&mut self) -> u64 {
+            let previous = self.state;
+            self.state = self.parameters.step(self.state);
+            let output = match self.parameters.scrambler {
+                Scrambler::None => self.state,
+                Scrambler::Multiply(multiplier) => self.state.wrapping_mul(multiplier),
+                Scrambler::Plus => self.state.wrapping_add(previous),
+            };
+            output & self.parameters.mask()
+        }
+    }
+
+    /// RapidHash-based PRNG implementation
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct RapidHashRNG {
+        state: u64,
+    }
+
+    impl RNG for RapidHashRNG {
+        fn new(seed: u64) -> Self {
+            let mut rng = Self { state: seed };
+            // Mix the initial state to avoid poor quality with simple seeds
+            for _ in 0..8 {
+                rng.next();
+            }
+            rng
+        }
+
+        fn next(&mut self) -> u64 {
+            // RapidHash-inspired mixing function
+            // This is a simplified version focusing on good avalanche properties
+            let mut value = self.state;
+
+            // First mixing round
+            value ^= value >> 32;
+            value = value.wrapping_mul(0x9e3779b97f4a7c15);
+            value ^= value >> 32;
+
+            // Second mixing round with different constants
+            value = value.wrapping_mul(0xbf58476d1ce4e5b9);
+            value ^= value >> 32;
+
+            // Third mixing round
+            value = value.wrapping_mul(0x94d049bb133111eb);
+            value ^= value >> 32;
+
+            // Update state with the mixed value
+            self.state = self.state.wrapping_add(1);
+
+            value
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.next() as u32
+        }
+
+        fn advance(&mut self, delta: u64) {
+            // Simply advance the state counter by the specified amount
+            self.state = self.state.wrapping_add(delta);
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            *self = Self::new(seed);
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&self.state))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            Ok(Self {
+                state: state::decode(bytes)?,
+            })
+        }
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct WyRand {
+        seed: u64,
+    }
+
+    impl RNG for WyRand {
+        fn new(seed: u64) -> Self {
+            let mut new_rng = Self { seed };
+            for _ in 0..8 {
+                let _ = new_rng.next();
+            }
+            new_rng
+        }
+
+        fn next(&mut self) -> u64 {
+            self.seed = self.seed.wrapping_add(0xa0761d6478bd642f);
+            let t = (self.seed as u128).wrapping_mul((self.seed ^ 0xe7037ed1a0b428db) as u128);
+            (t.wrapping_shr(64) ^ t) as u64
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.next() as u32
+        }
+
+        fn advance(&mut self, delta: u64) {
+            for _ in 0..delta {
+                let _ = self.next();
+            }
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            self.seed = seed;
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&self.seed))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            Ok(Self {
+                seed: state::decode(bytes)?,
+            })
+        }
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct RapidHashRNG2 {
+        state: u64,
+        seed: u64,
+    }
+
+    impl RapidHashRNG2 {
+        /// A simple, fast mixing function (inspired by SplitMix64/rapidhash)
+        #[inline]
+        fn hash64(x: u64) -> u64 {
+            let mut z = x.wrapping_add(0x9E3779B97F4A7C15);
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+            z ^ (z >> 31)
+        }
+    }
+
+    impl RNG for RapidHashRNG2 {
+        fn new(seed: u64) -> Self {
+            let mut new_rng = Self { state: 0, seed };
+            for _ in 0..8 {
+                let _ = new_rng.next();
+            }
+            new_rng
+        }
+
+        fn next(&mut self) -> u64 {
+            let result = Self::hash64(self.seed.wrapping_add(self.state));
+            self.state = self.state.wrapping_add(1);
+            result
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.next() as u32
+        }
+
+        fn advance(&mut self, delta: u64) {
+            self.state = self.state.wrapping_add(delta);
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            self.seed = seed;
+            self.state = 0;
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&(self.state, self.seed)))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            let (state, seed) = state::decode(bytes)?;
+            Ok(Self { state, seed })
+        }
+    }
+}
+
+/// Small fast generators of Bob Jenkins (JSF), chaotic add/rotate/xor mixers of four words without a fixed period.
+/// See https://burtleburtle.net/bob/rand/smallprng.html
+pub mod jsf {
+    use super::{state, RNG};
+
+    /// Initial value of the first state word.
+    const SEED_CONSTANT: u32 = 0xf1ea5eed;
+    /// Rounds discarded after seeding.
+    const WARMUP_ROUNDS: usize = 20;
+
+    /// jsf32, the 32 bit small fast generator with the rotations 27 and 17.
+    /// The .next() method uses two outputs, the first output fills the upper half.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Jsf32 {
+        state: [u32; 4],
+    }
+
+    impl RNG for Jsf32 {
+        /// Initialize like `raninit` with the lower half of `seed`, the upper half is xored into the seed constant.
+        /// Seeds below 2^32 give the same outputs as the reference implementation.
+        fn new(seed: u64) -> Self {
+            let low = seed as u32;
+            let mut rng = Jsf32 {
+                state: [SEED_CONSTANT ^ (seed >> 32) as u32, low, low, low],
+            };
+            rng.advance(WARMUP_ROUNDS as u64);
+            rng
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            let [a, b, c, d] = &mut self.state;
+            let e = a.wrapping_sub(b.rotate_left(27));
+            *a = *b ^ c.rotate_left(17);
+            *b = c.wrapping_add(*d);
+            *c = d.wrapping_add(e);
+            *d = e.wrapping_add(*a);
+            *d
+        }
+
+        fn next(&mut self) -> u64 {
+            let a: u64 = self.next_u32() as u64;
+            let b: u64 = self.next_u32() as u64;
+            (a << 32) | b
+        }
+
+        fn advance(&mut self, delta: u64) {
+            for _ in 0..delta {
+                let _ = self.next_u32();
+            }
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            *self = Self::new(seed);
+        }
+
+        fn native_bits(&self) -> u32 {
+            32
+        }
+
+        fn next_native(&mut self) -> u64 {
+            self.next_u32() as u64
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&self.state))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            Ok(Self {
+                state: state::decode(bytes)?,
+            })
+        }
+    }
+
+    /// jsf64, the 64 bit small fast generator with the rotations 7, 13 and 37.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Jsf64 {
+        state: [u64; 4],
+    }
+
+    impl RNG for Jsf64 {
+        /// Initialize like `raninit` of the reference implementation.
+        fn new(seed: u64) -> Self {
+            let mut rng = Jsf64 {
+                state: [SEED_CONSTANT as u64, seed, seed, seed],
+            };
+            rng.advance(WARMUP_ROUNDS as u64);
+            rng
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.next() as u32
+        }
+
+        fn next(&mut self) -> u64 {
+            let [a, b, c, d] = &mut self.state;
+            let e = a.wrapping_sub(b.rotate_left(7));
+            *a = *b ^ c.rotate_left(13);
+            *b = c.wrapping_add(d.rotate_left(37));
+            *c = d.wrapping_add(e);
+            *d = e.wrapping_add(*a);
+            *d
+        }
+
+        fn advance(&mut self, delta: u64) {
+            for _ in 0..delta {
+                let _ = self.next();
+            }
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            *self = Self::new(seed);
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&self.state))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            Ok(Self {
+                state: state::decode(bytes)?,
+            })
+        }
+    }
+}
+
+/// The small chaotic generator of David Blackman's gjrand test suite, four 64 bit words mixed by additions,
+/// rotations and xors, with a Weyl counter in the fourth word that guarantees a period of at least 2^64.
+/// See http://gjrand.sourceforge.net
+pub mod gjrand {
+    use super::{state, RNG};
+
+    /// Initial value of the third state word.
+    const SEED_CONSTANT: u64 = 2000001;
+    /// Increment of the Weyl counter in the fourth state word.
+    const WEYL_INCREMENT: u64 = 0x55aa96a5;
+    /// Rounds discarded after seeding.
+    const WARMUP_ROUNDS: usize = 14;
+
+    /// gjrand64, outputs the first state word after each crank.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Gjrand64 {
+        state: [u64; 4],
+    }
+
+    impl RNG for Gjrand64 {
+        /// Initialize like `gjrand_init64` and discard 14 rounds.
+        fn new(seed: u64) -> Self {
+            let mut rng = Gjrand64 {
+                state: [seed, 0, SEED_CONSTANT, 0],
+            };
+            rng.advance(WARMUP_ROUNDS as u64);
+            rng
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.next() as u32
+        }
+
+        fn next(&mut self) -> u64 {
+            let [a, b, c, d] = &mut self.state;
+            *b = b.wrapping_add(*c);
+            *a = a.rotate_left(32);
+            *c ^= *b;
+            *d = d.wrapping_add(WEYL_INCREMENT);
+            *a = a.wrapping_add(*b);
+            *c = c.rotate_left(23);
+            *b ^= *a;
+            *a = a.wrapping_add(*c);
+            *b = b.rotate_left(19);
+            *c = c.wrapping_add(*a);
+            *b = b.wrapping_add(*d);
+            *a
+        }
+
+        fn advance(&mut self, delta: u64) {
+            for _ in 0..delta {
+                let _ = self.next();
+            }
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            *self = Self::new(seed);
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&self.state))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            Ok(Self {
+                state: state::decode(bytes)?,
+            })
+        }
+    }
+}
+
+/// SplitMix64 of Steele, Lea and Flood: a Weyl sequence passed through a strong 64 bit mixing function.
+/// Also used to expand a single u64 seed into the larger state of other generators.
+pub mod splitmix {
+    use super::{state, SeekableRNG, RNG};
+
+    /// Increment of the Weyl sequence, the golden ratio scaled to 64 bits.
+    pub const GAMMA: u64 = 0x9e3779b97f4a7c15;
+    /// Multiplicative inverse of `GAMMA` modulo 2^64.
+    const GAMMA_INVERSE: u64 = 0xf1de83e19937733d;
+
+    /// Output function of SplitMix64, a bijection with full avalanche (Stafford's Mix13).
+    pub fn mix64(mut z: u64) -> u64 {
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Expand `seed` into `N` words, the first `N` outputs of SplitMix64 seeded with `seed`.
+    /// Neighbouring seeds give unrelated states, and the words are distinct, so at most one of them is zero.
+    /// Meant for `new` and `reseed` of generators whose state breaks down for simple seeds, like all-zero or repeated words.
+    pub fn expand_seed<const N: usize>(seed: u64) -> [u64; N] {
+        let mut rng = SplitMix64::new(seed);
+        std::array::from_fn(|_| rng.next())
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct SplitMix64 {
+        state: u64,
+    }
+
+    impl RNG for SplitMix64 {
+        fn new(seed: u64) -> Self {
+            SplitMix64 { state: seed }
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.next() as u32
+        }
+
+        fn next(&mut self) -> u64 {
+            self.state = self.state.wrapping_add(GAMMA);
+            mix64(self.state)
+        }
+
+        fn advance(&mut self, delta: u64) {
+            self.state = self.state.wrapping_add(delta.wrapping_mul(GAMMA));
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            self.state = seed;
+        }
+
+        fn as_seekable(&mut self) -> Option<&mut dyn SeekableRNG> {
+            Some(self)
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&self.state))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            Ok(Self {
+                state: state::decode(bytes)?,
+            })
+        }
+    }
+
+    /// All seeds share one cycle of 2^64 outputs that starts at state zero,
+    /// the seed only selects the starting position.
+    impl SeekableRNG for SplitMix64 {
+        fn seek(&mut self, position: u128) {
+            self.state = (position as u64).wrapping_mul(GAMMA);
+        }
+
+        fn position(&self) -> u128 {
+            self.state.wrapping_mul(GAMMA_INVERSE) as u128
+        }
+    }
+}
+
+/// xoshiro256 generators of Blackman and Vigna, a 256 bit xor/shift/rotate linear engine with a
+/// non-linear output scrambler. See https://prng.di.unimi.it
+pub mod xoshiro {
+    use super::{gf2, splitmix, state, RNG};
+
+    /// Characteristic polynomial of the linear engine without the leading term x^256, primitive for the period 2^256 - 1.
+    pub const CHARACTERISTIC_POLYNOMIAL: [u64; 4] = [
+        0x9d116f2bb0f0f001,
+        0x0280002bcefd1a5e,
+        0x04b4edcf26259f85,
+        0x0003c03c3f3ecb19,
+    ];
+    /// Coefficients of the jump polynomial for 2^128 steps, x^(2^128) modulo the characteristic polynomial.
+    pub const JUMP: [u64; 4] = [
+        0x180ec6d33cfd0aba,
+        0xd5a61266f0c9392c,
+        0xa9582618e03fc9aa,
+        0x39abdc4529b1661c,
+    ];
+    /// Coefficients of the jump polynomial for 2^192 steps, x^(2^192) modulo the characteristic polynomial.
+    pub const LONG_JUMP: [u64; 4] = [
+        0x76e15d3efefdcbbf,
+        0xc5004e441c522fb3,
+        0x77710069854ee241,
+        0x39109bb02acbe635,
+    ];
+
+    /// Advance the linear engine one step.
+    fn step(state: &mut [u64; 4]) {
+        let t = state[1] << 17;
+        state[2] ^= state[0];
+        state[3] ^= state[1];
+        state[1] ^= state[2];
+        state[0] ^= state[3];
+        state[2] ^= t;
+        state[3] = state[3].rotate_left(45);
+    }
+
+    /// Advance the state by the number of steps encoded in the jump polynomial `polynomial`.
+    fn jump_state(state: &mut [u64; 4], polynomial: &[u64]) {
+        gf2::jump(state, polynomial, 256, step);
+    }
+
+    /// Advance the state by `delta` steps, with the characteristic polynomial for deltas of at least 256 steps.
+    fn advance_state(state: &mut [u64; 4], delta: u64) {
+        if delta < 256 {
+            for _ in 0..delta {
+                step(state);
+            }
+            return;
+        }
+        jump_state(
+            state,
+            &gf2::jump_polynomial(&[delta], &CHARACTERISTIC_POLYNOMIAL, 256),
+        );
+    }
+
+    /// xoshiro256**, the all-purpose generator of the family. The output is scrambled by multiplication and rotation.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Xoshiro256StarStar {
+        state: [u64; 4],
+    }
+
+    impl RNG for Xoshiro256StarStar {
+        fn new(seed: u64) -> Self {
+            Self {
+                state: splitmix::expand_seed(seed),
+            }
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            (self.next() >> 32) as u32
+        }
+
+        fn next(&mut self) -> u64 {
+            let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+            step(&mut self.state);
+            result
+        }
+
+        fn advance(&mut self, delta: u64) {
+            advance_state(&mut self.state, delta);
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            self.state = splitmix::expand_seed(seed);
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&self.state))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            Ok(Self {
+                state: state::decode(bytes)?,
+            })
+        }
+    }
+
+    impl Xoshiro256StarStar {
+        /// Start from a raw state, e.g. to reproduce the reference implementation.
+        /// The state must not be all zero.
+        pub fn from_state(state: [u64; 4]) -> Self {
+            Self { state }
+        }
+
+        /// Advance 2^128 steps, to generate 2^128 non-overlapping subsequences.
+        pub fn jump(&mut self) {
+            jump_state(&mut self.state, &JUMP);
+        }
+
+        /// Advance 2^192 steps, to generate 2^64 starting points that can each be split by `jump`.
+        pub fn long_jump(&mut self) {
+            jump_state(&mut self.state, &LONG_JUMP);
+        }
+    }
+
+    /// xoshiro256++, scrambled by addition and rotation. Equally fast, with a different scrambler than xoshiro256**.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Xoshiro256PlusPlus {
+        state: [u64; 4],
+    }
+
+    impl RNG for Xoshiro256PlusPlus {
+        fn new(seed: u64) -> Self {
+            Self {
+                state: splitmix::expand_seed(seed),
+            }
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            (self.next() >> 32) as u32
+        }
+
+        fn next(&mut self) -> u64 {
+            let result = self.state[0]
+                .wrapping_add(self.state[3])
+                .rotate_left(23)
+                .wrapping_add(self.state[0]);
+            step(&mut self.state);
+            result
+        }
+
+        fn advance(&mut self, delta: u64) {
+            advance_state(&mut self.state, delta);
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            self.state = splitmix::expand_seed(seed);
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&self.state))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            Ok(Self {
+                state: state::decode(bytes)?,
+            })
+        }
+    }
+
+    impl Xoshiro256PlusPlus {
+        /// Start from a raw state, e.g. to reproduce the reference implementation.
+        /// The state must not be all zero.
+        pub fn from_state(state: [u64; 4]) -> Self {
+            Self { state }
+        }
+
+        /// Advance 2^128 steps, to generate 2^128 non-overlapping subsequences.
+        pub fn jump(&mut self) {
+            jump_state(&mut self.state, &JUMP);
+        }
+
+        /// Advance 2^192 steps, to generate 2^64 starting points that can each be split by `jump`.
+        pub fn long_jump(&mut self) {
+            jump_state(&mut self.state, &LONG_JUMP);
+        }
+    }
+}
+
+// Linear congruential generators
+pub mod lcg {
+    use super::{splitmix, state, RNG};
+    use bnum::{cast::As, types::U256};
+
+    /// Multiplier and increment of `delta` steps of x -> (multiplier * x + increment) mod modulus,
+    /// a modulus of 0 stands for 2^128. Uses O(log delta) multiplications,
+    /// following Brown (1994), "Random Number Generation with Arbitrary Strides".
+    /// Power of two moduli below 2^128 give the same result as reducing the coefficients for 2^128.
+    pub fn jump_coefficients(
+        multiplier: u128,
+        increment: u128,
+        modulus: u128,
+        mut delta: u128,
+    ) -> (u128, u128) {
+        let (mut step_multiplier, mut step_increment) = (multiplier, increment);
+        let (mut jump_multiplier, mut jump_increment) = (reduce(1, modulus), 0);
+        while delta > 0 {
+            if delta & 1 == 1 {
+                jump_multiplier = mul_mod(jump_multiplier, step_multiplier, modulus);
+                jump_increment = add_mod(
+                    mul_mod(jump_increment, step_multiplier, modulus),
+                    step_increment,
+                    modulus,
+                );
+            }
+            step_increment = mul_mod(
+                add_mod(step_multiplier, 1, modulus),
+                step_increment,
+                modulus,
+            );
+            step_multiplier = mul_mod(step_multiplier, step_multiplier, modulus);
+            delta >>= 1;
+        }
+        (jump_multiplier, jump_increment)
+    }
+
+    /// x mod modulus, a modulus of 0 stands for 2^128.
+    fn reduce(x: u128, modulus: u128) -> u128 {
+        if modulus == 0 {
+            x
+        } else {
+            x % modulus
+        }
+    }
+
+    fn add_mod(a: u128, b: u128, modulus: u128) -> u128 {
+        let (a, b) = (reduce(a, modulus), reduce(b, modulus));
+        let (sum, overflow) = a.overflowing_add(b);
+        if modulus != 0 && (overflow || sum >= modulus) {
+            sum.wrapping_sub(modulus)
+        } else {
+            sum
+        }
+    }
+
+    fn mul_mod(a: u128, b: u128, modulus: u128) -> u128 {
+        if modulus == 0 {
+            a.wrapping_mul(b)
+        } else if modulus <= 1 << 64 {
+            // Both factors are below 2^64, the product fits.
+            (a % modulus) * (b % modulus) % modulus
+        } else {
+            (U256::from(a) * U256::from(b) % U256::from(modulus)).as_::<u128>()
+        }
+    }
+
+    /// Ill concieved early LCG, that fails the spectral test badly.
+    /// Only has output space of 0-2**31-1.
+    /// The .next() method uses three RANDU calls to fill the 64 bit output space,
+    /// The .next_u32() method uses two RANDU calls.
+    /// the .next_small() and .next_native() methods return the reduced original output space.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Randu {
+        state: u32,
+    }
+
+    impl RNG for Randu {
+        fn new(seed: u64) -> Self {
+            Randu { state: seed as u32 }
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            let a: u32 = self.next_small();
+            let b: u32 = self.next_small();
+            a << 15 | (b & 0xffff)
+        }
+
+        fn next(&mut self) -> u64 {
+            let a: u64 = self.next_small() as u64;
+            let b: u64 = self.next_small() as u64;
+            let c: u64 = self.next_small() as u64;
+            (a << 42) | ((b & 0x3fffff) << 20) | (c & 0xfffff)
+        }
+
+        fn advance(&mut self, delta: u64) {
+            let (multiplier, _) = jump_coefficients(
+                Self::MULTIPLIER as u128,
+                0,
+                1 << Self::MODULUS_BITS,
+                delta as u128,
+            );
+            self.state = mul_mod(self.state as u128, multiplier, 1 << Self::MODULUS_BITS) as u32;
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            self.state = seed as u32;
+        }
+
+        fn native_bits(&self) -> u32 {
+            31
+        }
+
+        fn next_native(&mut self) -> u64 {
+            self.next_small() as u64
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&self.state))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            Ok(Self {
+                state: state::decode(bytes)?,
+            })
+        }
+    }
+    impl Randu {
+        pub const MULTIPLIER: u32 = 65539;
+        /// The state is kept modulo 2**MODULUS_BITS.
+        pub const MODULUS_BITS: u32 = 31;
+
+        /// Generate a number in the original reduced output space of 0 to 2**31 - 1.
+        fn next_small(&mut self) -> u32 {
+            self.state =
+                self.state.wrapping_mul(Self::MULTIPLIER) & ((1 << Self::MODULUS_BITS) - 1);
+            self.state
+        }
+    }
+    /// Originaly designed by Donald Knuth
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Mmix {
+        state: u64,
+    }
+
+    impl RNG for Mmix {
+        fn new(seed: u64) -> Self {
+            Mmix { state: seed }
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.next() as u32
+        }
+
+        fn next(&mut self) -> u64 {
+            self.state = self.state.wrapping_mul(Self::MULTIPLIER);
+            self.state = self.state.wrapping_add(Self::INCREMENT);
+            self.state
+        }
+
+        fn advance(&mut self, delta: u64) {
+            let (multiplier, increment) = jump_coefficients(
+                Self::MULTIPLIER as u128,
+                Self::INCREMENT as u128,
+                0,
+                delta as u128,
+            );
+            self.state = self
+                .state
+                .wrapping_mul(multiplier as u64)
+                .wrapping_add(increment as u64);
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            self.state = seed;
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&self.state))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            Ok(Self {
+                state: state::decode(bytes)?,
+            })
+        }
+    }
+    impl Mmix {
+        pub const MULTIPLIER: u64 = 0x5851f42d4c957f2d;
+        pub const INCREMENT: u64 = 0x14057b7ef767814f;
+    }
+    /// Combines four 128 bit LCGs by XORing their upper halves.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct UlsLcg512 {
+        state: [u128; 4],
+    }
+
+    impl RNG for UlsLcg512 {
+        fn new(seed: u64) -> Self {
+            UlsLcg512 {
+                state: [
+                    (!seed as u128) << 64 | !seed as u128,
+                    (seed as u128) << 64 | seed as u128,
+                    (seed as u128) << 64 | !seed as u128,
+                    (!seed as u128) << 64 | seed as u128,
+                ],
+            }
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.next() as u32
+        }
+
+        fn next(&mut self) -> u64 {
+            for (state, (multiplier, increment)) in self
+                .state
+                .iter_mut()
+                .zip(Self::MULTIPLIERS.iter().zip(Self::INCREMENTS.iter()))
+            {
+                *state = state.wrapping_mul(*multiplier).wrapping_add(*increment);
+            }
+            ((self.state[0] >> 64) as u64)
+                ^ ((self.state[1] >> 64) as u64)
+                ^ ((self.state[2] >> 64) as u64)
+                ^ ((self.state[3] >> 64) as u64)
+        }
+
+        fn advance(&mut self, delta: u64) {
+            for (state, (multiplier, increment)) in self
+                .state
+                .iter_mut()
+                .zip(Self::MULTIPLIERS.iter().zip(Self::INCREMENTS.iter()))
+            {
+                let (multiplier, increment) =
+                    jump_coefficients(*multiplier, *increment, 0, delta as u128);
+                *state = state.wrapping_mul(multiplier).wrapping_add(increment);
+            }
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            self.state = [
+                (!seed as u128) << 64 | !seed as u128,
+                (seed as u128) << 64 | seed as u128,
+                (seed as u128) << 64 | !seed as u128,
+                (!seed as u128) << 64 | seed as u128,
+            ];
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&self.state))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            Ok(Self {
+                state: state::decode(bytes)?,
+            })
+        }
+    }
+    impl UlsLcg512 {
+        pub const MULTIPLIERS: [u128; 4] = [
+            0x59ca1b2888a0a80fc054cd25b1fde311,
+            0xade47f9859546ba094573e7c2194a93c,
+            0x85fec39e4833d57dd07f903f191ecfd3,
+            0xcdf30907584f7e1551c0667353108b63,
+        ];
+        pub const INCREMENTS: [u128; 4] = [
+            0xa53a3854d740d22b4802f2e6ea01e350,
+            0xc77a0728309148b95143795d657a29f2,
+            0x77421f2a59df2305739f337afcad9edb,
+            0x935fec88eaba8c39e94503587c22ce99,
+        ];
+    }
+    /// Combines four 128 bit LCGs by adding their states and taking the upper half.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct UlsLcg512H {
+        state: [u128; 4],
+    }
+
+    impl RNG for UlsLcg512H {
+        fn new(seed: u64) -> Self {
+            UlsLcg512H {
+                state: [
+                    (!seed as u128) << 64 | !seed as u128,
+                    (seed as u128) << 64 | seed as u128,
+                    (seed as u128) << 64 | !seed as u128,
+                    (!seed as u128) << 64 | seed as u128,
+                ],
+            }
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.next() as u32
+        }
+
+        fn next(&mut self) -> u64 {
+            for (state, (multiplier, increment)) in self
+                .state
+                .iter_mut()
+                .zip(Self::MULTIPLIERS.iter().zip(Self::INCREMENTS.iter()))
+            {
+                *state = state.wrapping_mul(*multiplier).wrapping_add(*increment);
+            }
+            (self.state[0].wrapping_add(
+                self.state[1].wrapping_add(self.state[2].wrapping_add(self.state[3])),
+            ) >> 64) as u64
+        }
+
+        fn advance(&mut self, delta: u64) {
+            for (state, (multiplier, increment)) in self
+                .state
+                .iter_mut()
+                .zip(Self::MULTIPLIERS.iter().zip(Self::INCREMENTS.iter()))
+            {
+                let (multiplier, increment) =
+                    jump_coefficients(*multiplier, *increment, 0, delta as u128);
+                *state = state.wrapping_mul(multiplier).wrapping_add(increment);
+            }
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            self.state = [
+                (!seed as u128) << 64 | !seed as u128,
+                (seed as u128) << 64 | seed as u128,
+                (seed as u128) << 64 | !seed as u128,
+                (!seed as u128) << 64 | seed as u128,
+            ];
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&self.state))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            Ok(Self {
+                state: state::decode(bytes)?,
+            })
+        }
+    }
+
+    impl UlsLcg512H {
+        pub const MULTIPLIERS: [u128; 4] = [
+            0xe7513927bf96492135e503ed7f5b837e,
+            0x6420fafa38bd7d81fc02e8cbfac57698,
+            0x3072f956f9d4a9531efd7c4bd3f684f5,
+            0xa7b5b12dc766a03cfdbaf54bacac8382,
+        ];
+        pub const INCREMENTS: [u128; 4] = [
+            0x126b06c2bfe2dac7725ee66c0e1efe69,
+            0xd2a884d8ed65a425999f67abfa901eba,
+            0x2f18c679c54a581aef3f88efa973d2c9,
+            0xb12c82d5df1c4e33fd207ba107b9c620,
+        ];
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Lehmer64 {
+        state: u128,
+    }
+    impl RNG for Lehmer64 {
+        fn new(seed: u64) -> Self {
+            Lehmer64 {
+                state: (seed as u128) << 64 | seed as u128,
+            }
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.next() as u32
+        }
+
+        fn next(&mut self) -> u64 {
+            self.state = self.state.wrapping_mul(Self::MULTIPLIER);
+            (self.state >> 64) as u64
+        }
+
+        fn advance(&mut self, delta: u64) {
+            let (multiplier, _) = jump_coefficients(Self::MULTIPLIER, 0, 0, delta as u128);
+            self.state = self.state.wrapping_mul(multiplier);
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            self.state = (seed as u128) << 64 | seed as u128;
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&self.state))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            Ok(Self {
+                state: state::decode(bytes)?,
+            })
+        }
+    }
+    impl Lehmer64 {
+        pub const MULTIPLIER: u128 = 0xda942042e4dd58b5;
+    }
+
+    /// Bit exact clone of java.util.Random, a 48 bit LCG that outputs the upper bits of its state.
+    /// The .next_u32() method is nextInt(), the .next() method is nextLong(),
+    /// which adds the sign extended second output to the first output shifted up by 32 bits.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct JavaRandom {
+        state: u64,
+    }
+
+    impl RNG for JavaRandom {
+        /// Initialize like `new Random(seed)`, the seed is scrambled with the multiplier.
+        fn new(seed: u64) -> Self {
+            JavaRandom {
+                state: (seed ^ Self::MULTIPLIER) & Self::MASK,
+            }
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.next_bits(32)
+        }
+
+        fn next(&mut self) -> u64 {
+            let a = self.next_bits(32) as i32 as i64;
+            let b = self.next_bits(32) as i32 as i64;
+            (a << 32).wrapping_add(b) as u64
+        }
+
+        fn advance(&mut self, delta: u64) {
+            let (multiplier, increment) = jump_coefficients(
+                Self::MULTIPLIER as u128,
+                Self::INCREMENT as u128,
+                Self::MASK as u128 + 1,
+                delta as u128,
+            );
+            self.state = add_mod(
+                mul_mod(self.state as u128, multiplier, Self::MASK as u128 + 1),
+                increment,
+                Self::MASK as u128 + 1,
+            ) as u64;
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            *self = Self::new(seed);
+        }
+
+        fn native_bits(&self) -> u32 {
+            32
+        }
+
+        fn next_native(&mut self) -> u64 {
+            self.next_bits(32) as u64
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&self.state))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            Ok(Self {
+                state: state::decode(bytes)?,
+            })
+        }
+    }
+
+    impl JavaRandom {
+        pub const MULTIPLIER: u64 = 0x5deece66d;
+        pub const INCREMENT: u64 = 0xb;
+        /// The state is kept modulo 2**48.
+        pub const MASK: u64 = (1 << 48) - 1;
+
+        /// Advance one step and return the upper `bits` bits of the state, like the protected next(bits).
+        pub fn next_bits(&mut self, bits: u32) -> u32 {
+            self.state = self
+                .state
+                .wrapping_mul(Self::MULTIPLIER)
+                .wrapping_add(Self::INCREMENT)
+                & Self::MASK;
+            (self.state >> (48 - bits)) as u32
+        }
+
+        /// nextInt(bound), a number in 0..bound, rejecting the outputs of the last partial interval.
+        /// Panics if `bound` is not positive.
+        pub fn next_int_bounded(&mut self, bound: i32) -> i32 {
+            assert!(bound > 0, "bound must be positive");
+            let mut r = self.next_bits(31) as i32;
+            let m = bound - 1;
+            if bound & m == 0 {
+                return ((bound as i64 * r as i64) >> 31) as i32;
+            }
+            let mut u = r;
+            loop {
+                r = u % bound;
+                if u.wrapping_sub(r).wrapping_add(m) >= 0 {
+                    return r;
+                }
+                u = self.next_bits(31) as i32;
+            }
+        }
+
+        /// nextDouble(), 53 bits from two steps scaled to [0, 1).
+        pub fn next_double(&mut self) -> f64 {
+            let a = (self.next_bits(26) as u64) << 27;
+            let b = self.next_bits(27) as u64;
+            (a + b) as f64 * (1.0 / (1u64 << 53) as f64)
+        }
+    }
+
+    /// LCG x -> (A * x + C) mod M with arbitrary constants, a modulus of 0 stands for 2^128.
+    /// The native output is the state for moduli up to 2^64 and its upper 64 bits above,
+    /// the spectral test of the constants is `spectral::LcgParameters::of::<A, C, M>()`.
+    /// For native outputs below 64 bits the .next() method concatenates native outputs LSB first
+    /// and discards the bits that do not fit, the .next_u32() method uses the upper 32 bits of one native output.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Lcg<const A: u128, const C: u128, const M: u128> {
+        state: u128,
+    }
+
+    /// MINSTD of Park and Miller (1993), the multiplicative LCG modulo the prime 2^31 - 1.
+    pub type Minstd = Lcg<48271, 0, 0x7fffffff>;
+
+    impl<const A: u128, const C: u128, const M: u128> RNG for Lcg<A, C, M> {
+        /// Start from the seed reduced modulo M.
+        /// Without an increment a state of 0 is a fixed point and replaced by 1.
+        fn new(seed: u64) -> Self {
+            let () = Self::VALID_MODULUS;
+            let mut state = Self::reduce(seed as u128);
+            if C == 0 && state == 0 {
+                state = 1;
+            }
+            Lcg { state }
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            if Self::NATIVE_BITS >= 32 {
+                return (self.next_native() >> (Self::NATIVE_BITS.min(64) - 32)) as u32;
+            }
+            self.next() as u32
+        }
+
+        fn next(&mut self) -> u64 {
+            if Self::NATIVE_BITS >= 64 {
+                return self.next_native();
+            }
+            let mut output: u64 = 0;
+            let mut shift = 0;
+            while shift < 64 {
+                output |= self.next_native() << shift;
+                shift += Self::NATIVE_BITS;
+            }
+            output
+        }
+
+        fn advance(&mut self, delta: u64) {
+            let (multiplier, increment) = jump_coefficients(A, C, M, delta as u128);
+            self.state = add_mod(mul_mod(self.state, multiplier, M), increment, M);
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            *self = Self::new(seed);
+        }
+
+        fn native_bits(&self) -> u32 {
+            Self::NATIVE_BITS.min(64)
+        }
+
+        fn next_native(&mut self) -> u64 {
+            self.step();
+            if Self::NATIVE_BITS > 64 {
+                (self.state >> (Self::NATIVE_BITS - 64)) as u64
+            } else {
+                self.state as u64
+            }
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&self.state))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            let state: u128 = state::decode(bytes)?;
+            if M != 0 && state >= M {
+                return Err("State is not below the modulus".to_string());
+            }
+            Ok(Self { state })
+        }
+    }
+
+    impl<const A: u128, const C: u128, const M: u128> Lcg<A, C, M> {
+        pub const MULTIPLIER: u128 = A;
+        pub const INCREMENT: u128 = C;
+        /// Modulus, 0 stands for 2^128.
+        pub const MODULUS: u128 = M;
+        /// Bits of the largest state, M - 1.
+        const NATIVE_BITS: u32 = if M == 0 {
+            128
+        } else {
+            128 - (M - 1).leading_zeros()
+        };
+        const VALID_MODULUS: () = assert!(M != 1, "The modulus of an LCG must not be 1");
+
+        /// Start from a raw state, which is reduced modulo M.
+        pub fn from_state(state: u128) -> Self {
+            let () = Self::VALID_MODULUS;
+            Lcg {
+                state: Self::reduce(state),
+            }
+        }
+
+        fn reduce(x: u128) -> u128 {
+            reduce(x, M)
+        }
+
+        fn step(&mut self) {
+            self.state = if M == 0 {
+                self.state.wrapping_mul(A).wrapping_add(C)
+            } else if M <= 1 << 64 {
+                // Both factors are below 2^64, the sum stays below 2^128.
+                (self.state * (A % M) + C % M) % M
+            } else {
+                let product = U256::from(self.state) * U256::from(A % M) + U256::from(C % M);
+                (product % U256::from(M)).as_::<u128>()
+            };
+        }
+    }
+
+    /// Multiply-with-carry generator of Marsaglia with base 2^32 and lag `LAG`:
+    /// x_n = (MULTIPLIER * x_{n - LAG} + c_{n - 1}) mod 2^32, with the carry c_n the upper 32 bits of the product.
+    /// Equivalent to a Lehmer generator modulo MULTIPLIER * 2^(32 LAG) - 1, which should be a prime
+    /// in which 2^32 has a large order, e.g. a safe prime.
+    /// The .next() method uses two outputs, the first output fills the upper half.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Mwc<const LAG: usize, const MULTIPLIER: u32> {
+        lags: [u32; LAG],
+        carry: u32,
+        /// Index of x_{n - LAG} in `lags`.
+        index: usize,
+    }
+
+    /// Lag 1 multiply-with-carry, 4294957665 * 2^32 - 1 is a safe prime and the period about 2^63.
+    pub type Mwc1 = Mwc<1, 4294957665>;
+
+    impl<const LAG: usize, const MULTIPLIER: u32> RNG for Mwc<LAG, MULTIPLIER> {
+        /// Fill the lags and the carry with outputs of SplitMix64.
+        fn new(seed: u64) -> Self {
+            let mut mixer = splitmix::SplitMix64::new(seed);
+            let lags = std::array::from_fn(|_| (mixer.next() >> 32) as u32);
+            Self::from_state(lags, (mixer.next() % MULTIPLIER as u64) as u32)
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            let t = MULTIPLIER as u64 * self.lags[self.index] as u64 + self.carry as u64;
+            self.carry = (t >> 32) as u32;
+            self.lags[self.index] = t as u32;
+            self.index = (self.index + 1) % LAG;
+            t as u32
+        }
+
+        fn next(&mut self) -> u64 {
+            let a: u64 = self.next_u32() as u64;
+            let b: u64 = self.next_u32() as u64;
+            (a << 32) | b
+        }
+
+        fn advance(&mut self, delta: u64) {
+            for _ in 0..delta {
+                let _ = self.next_u32();
+            }
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            *self = Self::new(seed);
+        }
+
+        fn native_bits(&self) -> u32 {
+            32
+        }
+
+        fn next_native(&mut self) -> u64 {
+            self.next_u32() as u64
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&(self.lags, self.carry, self.index)))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            let (lags, carry, index) = state::decode(bytes)?;
+            Ok(Self {
+                lags,
+                carry,
+                index: state::check_index(index, LAG - 1)?,
+            })
+        }
+    }
+
+    impl<const LAG: usize, const MULTIPLIER: u32> Mwc<LAG, MULTIPLIER> {
+        /// Start from the lags x_{-LAG}, ..., x_{-1} and the carry.
+        /// The carry must be below `MULTIPLIER`, and the state must not be all zero
+        /// or all lags 2^32 - 1 with the carry `MULTIPLIER` - 1, both are fixed points.
+        pub fn from_state(lags: [u32; LAG], carry: u32) -> Self {
+            Self {
+                lags,
+                carry,
+                index: 0,
+            }
+        }
+    }
+
+    /// Complementary multiply-with-carry generator of Marsaglia with base 2^32 - 1 and lag `LAG`,
+    /// which outputs the complement b - 1 - x_n. Equivalent to a Lehmer generator modulo
+    /// MULTIPLIER * (2^32 - 1)^LAG + 1, which should be a prime, and has a period close to that modulus.
+    /// Outputs are below 2^32 - 1. The .next() method uses two outputs, the first output fills the upper half.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Cmwc<const LAG: usize, const MULTIPLIER: u32> {
+        lags: [u32; LAG],
+        carry: u32,
+        /// Index of x_{n - LAG} in `lags`.
+        index: usize,
+    }
+
+    /// CMWC4096 of Marsaglia (2003), with a period of about 2^131086.
+    pub type Cmwc4096 = Cmwc<4096, 18782>;
+
+    impl<const LAG: usize, const MULTIPLIER: u32> RNG for Cmwc<LAG, MULTIPLIER> {
+        /// Fill the lags and the carry with outputs of SplitMix64.
+        fn new(seed: u64) -> Self {
+            let mut mixer = splitmix::SplitMix64::new(seed);
+            let lags = std::array::from_fn(|_| ((mixer.next() >> 32) as u32).min(u32::MAX - 1));
+            Self::from_state(lags, (mixer.next() % MULTIPLIER as u64) as u32)
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            let t = MULTIPLIER as u64 * self.lags[self.index] as u64 + self.carry as u64;
+            let mut carry = (t >> 32) as u32;
+            // t mod (2^32 - 1), with the carry corrected to the quotient.
+            let mut x = (t as u32).wrapping_add(carry);
+            if x < carry {
+                x = x.wrapping_add(1);
+                carry += 1;
+            }
+            self.carry = carry;
+            self.lags[self.index] = 0xfffffffe - x;
+            let output = self.lags[self.index];
+            self.index = (self.index + 1) % LAG;
+            output
+        }
+
+        fn next(&mut self) -> u64 {
+            let a: u64 = self.next_u32() as u64;
+            let b: u64 = self.next_u32() as u64;
+            (a << 32) | b
+        }
+
+        fn advance(&mut self, delta: u64) {
+            for _ in 0..delta {
+                let _ = self.next_u32();
+            }
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            *self = Self::new(seed);
+        }
+
+        fn native_bits(&self) -> u32 {
+            32
+        }
+
+        fn next_native(&mut self) -> u64 {
+            self.next_u32() as u64
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&(self.lags, self.carry, self.index)))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            let (lags, carry, index) = state::decode(bytes)?;
+            Ok(Self {
+                lags,
+                carry,
+                index: state::check_index(index, LAG - 1)?,
+            })
+        }
+    }
+
+    impl<const LAG: usize, const MULTIPLIER: u32> Cmwc<LAG, MULTIPLIER> {
+        /// Start from the lags x_{-LAG}, ..., x_{-1} and the carry, like `Q` and `c` of Marsaglia's CMWC4096.
+        /// The lags must be below 2^32 - 1 and the carry below `MULTIPLIER`.
+        pub fn from_state(lags: [u32; LAG], carry: u32) -> Self {
+            Self {
+                lags,
+                carry,
+                index: 0,
+            }
+        }
+    }
+}
+
+/// Marsaglia's KISS combined generators, "keep it simple stupid": the sum of an LCG, an xorshift and an MWC generator.
+/// Each component is weak on its own, the combination was long a standard recommendation.
+pub mod kiss {
+    use super::{lcg, splitmix, state, RNG};
+
+    /// KISS of Marsaglia's 1999 Usenet post: two 16 bit multiply-with-carry generators, SHR3 and a 69069 LCG.
+    /// The period is about 2^123. The .next() method uses two outputs, the first output fills the upper half.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Kiss99 {
+        z: u32,
+        w: u32,
+        jsr: u32,
+        jcong: u32,
+    }
+
+    impl Kiss99 {
+        /// Seeds of the post.
+        pub const DEFAULT_STATE: [u32; 4] = [362436069, 521288629, 123456789, 380116160];
+
+        /// Start from the raw state `[z, w, jsr, jcong]`. The upper halves of z and w are the carries
+        /// and must be below 36969 and 18000, the lower halves must not both be zero with a zero carry, and jsr must not be zero.
+        pub fn from_state(state: [u32; 4]) -> Self {
+            let [z, w, jsr, jcong] = state;
+            Self { z, w, jsr, jcong }
+        }
+    }
+
+    /// Valid 16 bit multiply-with-carry state from 32 random bits.
+    fn mwc16_state(bits: u32, multiplier: u32) -> u32 {
+        let state = (bits & 0xffff) | (((bits >> 16) % multiplier) << 16);
+        state.max(1)
+    }
+
+    impl RNG for Kiss99 {
+        fn new(seed: u64) -> Self {
+            let [a, b] = splitmix::expand_seed::<2>(seed);
+            Self::from_state([
+                mwc16_state(a as u32, 36969),
+                mwc16_state((a >> 32) as u32, 18000),
+                (b as u32).max(1),
+                (b >> 32) as u32,
+            ])
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.z = 36969 * (self.z & 0xffff) + (self.z >> 16);
+            self.w = 18000 * (self.w & 0xffff) + (self.w >> 16);
+            let mwc = (self.z << 16).wrapping_add(self.w);
+            self.jsr ^= self.jsr << 17;
+            self.jsr ^= self.jsr >> 13;
+            self.jsr ^= self.jsr << 5;
+            self.jcong = self.jcong.wrapping_mul(69069).wrapping_add(1234567);
+            (mwc ^ self.jcong).wrapping_add(self.jsr)
+        }
+
+        fn next(&mut self) -> u64 {
+            let a: u64 = self.next_u32() as u64;
+            let b: u64 = self.next_u32() as u64;
+            (a << 32) | b
+        }
+
+        fn advance(&mut self, delta: u64) {
+            for _ in 0..delta {
+                let _ = self.next_u32();
+            }
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            *self = Self::new(seed);
+        }
+
+        fn native_bits(&self) -> u32 {
+            32
+        }
+
+        fn next_native(&mut self) -> u64 {
+            self.next_u32() as u64
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&(self.z, self.w, self.jsr, self.jcong)))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            let (z, w, jsr, jcong) = state::decode(bytes)?;
+            Ok(Self { z, w, jsr, jcong })
+        }
+    }
+
+    /// JKISS of Jones, "Good practice in (pseudo) random number generation for bioinformatics applications" (2010):
+    /// KISS with a full 32 bit lag 1 multiply-with-carry generator and a 32 bit xorshift. The period is about 2^127.
+    /// The .next() method uses two outputs, the first output fills the upper half.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Jkiss {
+        x: u32,
+        y: u32,
+        mwc: lcg::Mwc<1, 4294584393>,
+    }
+
+    impl Jkiss {
+        /// Seeds of the paper.
+        pub const DEFAULT_STATE: [u32; 4] = [123456789, 987654321, 43219876, 6543217];
+
+        /// Start from the raw state `[x, y, z, c]`. y must not be zero, z and c not both zero, and c should be below 698769069.
+        pub fn from_state(state: [u32; 4]) -> Self {
+            let [x, y, z, c] = state;
+            Self {
+                x,
+                y,
+                mwc: lcg::Mwc::from_state([z], c),
+            }
+        }
+    }
+
+    impl RNG for Jkiss {
+        fn new(seed: u64) -> Self {
+            let [a, b] = splitmix::expand_seed::<2>(seed);
+            Self::from_state([
+                a as u32,
+                ((a >> 32) as u32).max(1),
+                (b as u32).max(1),
+                ((b >> 32) as u32) % 698769069,
+            ])
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.x = self.x.wrapping_mul(314527869).wrapping_add(1234567);
+            self.y ^= self.y << 5;
+            self.y ^= self.y >> 7;
+            self.y ^= self.y << 22;
+            self.x
+                .wrapping_add(self.y)
+                .wrapping_add(self.mwc.next_u32())
+        }
+
+        fn next(&mut self) -> u64 {
+            let a: u64 = self.next_u32() as u64;
+            let b: u64 = self.next_u32() as u64;
+            (a << 32) | b
+        }
+
+        fn advance(&mut self, delta: u64) {
+            for _ in 0..delta {
+                let _ = self.next_u32();
+            }
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            *self = Self::new(seed);
+        }
+
+        fn native_bits(&self) -> u32 {
+            32
+        }
+
+        fn next_native(&mut self) -> u64 {
+            self.next_u32() as u64
+        }
+
+        /// The state of x and y followed by the state of the MWC component.
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            let mut bytes = state::encode(&(self.x, self.y));
+            bytes.extend(self.mwc.state_bytes()?);
+            Some(bytes)
+        }
+
+        fn from_state_bytes(mut bytes: &[u8]) -> Result<Self, String> {
+            let (x, y) = state::Encode::decode(&mut bytes)?;
+            Ok(Self {
+                x,
+                y,
+                mwc: lcg::Mwc::from_state_bytes(bytes)?,
+            })
+        }
+    }
+
+    /// 64 bit KISS of Marsaglia's 2009 post: a multiply-with-carry generator with multiplier 2^58 + 1,
+    /// a 64 bit xorshift and an LCG. The period is about 2^250.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Kiss64 {
+        x: u64,
+        c: u64,
+        y: u64,
+        z: u64,
+    }
+
+    impl Kiss64 {
+        /// Seeds of the post.
+        pub const DEFAULT_STATE: [u64; 4] = [
+            1234567890987654321,
+            123456123456123456,
+            362436362436362436,
+            1066149217761810,
+        ];
+
+        /// Start from the raw state `[x, c, y, z]`. c should be below 2^58 + 1 and y must not be zero.
+        pub fn from_state(state: [u64; 4]) -> Self {
+            let [x, c, y, z] = state;
+            Self { x, c, y, z }
+        }
+    }
+
+    impl RNG for Kiss64 {
+        fn new(seed: u64) -> Self {
+            let [x, c, y, z] = splitmix::expand_seed::<4>(seed);
+            Self::from_state([x, c >> 6, y.max(1), z])
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.next() as u32
+        }
+
+        fn next(&mut self) -> u64 {
+            let t = (self.x << 58).wrapping_add(self.c);
+            self.c = self.x >> 6;
+            self.x = self.x.wrapping_add(t);
+            self.c += (self.x < t) as u64;
+            self.y ^= self.y << 13;
+            self.y ^= self.y >> 17;
+            self.y ^= self.y << 43;
+            self.z = self.z.wrapping_mul(6906969069).wrapping_add(1234567);
+            self.x.wrapping_add(self.y).wrapping_add(self.z)
+        }
+
+        fn advance(&mut self, delta: u64) {
+            for _ in 0..delta {
+                let _ = self.next();
+            }
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            *self = Self::new(seed);
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&(self.x, self.c, self.y, self.z)))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            let (x, c, y, z) = state::decode(bytes)?;
+            Ok(Self { x, c, y, z })
+        }
+    }
+}
+
+/// Permuted congruential generators of O'Neill: an LCG whose output is scrambled by a permutation
+/// selected by the high state bits. See https://www.pcg-random.org
+pub mod pcg {
+    use super::{state, Streamed, RNG};
+
+    /// Jump an LCG with the supplied constants ahead by `delta` steps in O(log delta),
+    /// by composing the affine step with itself (Brown, Random number generation with arbitrary strides, 1994).
+    /// The result is exact modulo every power of two up to 2^128, so it also serves the 64 bit state of PCG32.
+    fn lcg_jump(state: u128, multiplier: u128, increment: u128, mut delta: u128) -> u128 {
+        let (mut jump_multiplier, mut jump_increment): (u128, u128) = (1, 0);
+        let (mut step_multiplier, mut step_increment) = (multiplier, increment);
+        while delta > 0 {
+            if delta & 1 == 1 {
+                jump_multiplier = jump_multiplier.wrapping_mul(step_multiplier);
+                jump_increment = jump_increment
+                    .wrapping_mul(step_multiplier)
+                    .wrapping_add(step_increment);
+            }
+            step_increment = step_multiplier.wrapping_add(1).wrapping_mul(step_increment);
+            step_multiplier = step_multiplier.wrapping_mul(step_multiplier);
+            delta >>= 1;
+        }
+        jump_multiplier
+            .wrapping_mul(state)
+            .wrapping_add(jump_increment)
+    }
+
+    /// PCG32 (XSH-RR): 64 bit LCG state, 32 bit output by an xorshift of the high bits and a random rotation.
+    /// The increment selects one of 2^63 streams.
+    /// The .next() method uses two steps, the first output fills the upper half.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Pcg32 {
+        state: u64,
+        increment: u64,
+    }
+
+    impl RNG for Pcg32 {
+        fn new(seed: u64) -> Self {
+            Self::with_stream(seed, Self::DEFAULT_STREAM)
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            let old_state = self.state;
+            self.step();
+            let xorshifted = (((old_state >> 18) ^ old_state) >> 27) as u32;
+            xorshifted.rotate_right((old_state >> 59) as u32)
+        }
+
+        fn next(&mut self) -> u64 {
+            let a: u64 = self.next_u32() as u64;
+            let b: u64 = self.next_u32() as u64;
+            (a << 32) | b
+        }
+
+        fn advance(&mut self, delta: u64) {
+            self.state = lcg_jump(
+                self.state as u128,
+                Self::MULTIPLIER as u128,
+                self.increment as u128,
+                delta as u128,
+            ) as u64;
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            *self = Self::with_stream(seed, Self::DEFAULT_STREAM);
+        }
+
+        fn native_bits(&self) -> u32 {
+            32
+        }
+
+        fn next_native(&mut self) -> u64 {
+            self.next_u32() as u64
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&(self.state, self.increment)))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            let (state, increment) = state::decode(bytes)?;
+            Ok(Self { state, increment })
+        }
+    }
+
+    /// Selects the increment like `with_stream` and keeps the state, the highest bit of `stream` is ignored.
+    impl Streamed for Pcg32 {
+        fn set_stream(&mut self, stream: u64) {
+            self.increment = (stream << 1) | 1;
+        }
+    }
+
+    impl Pcg32 {
+        pub const MULTIPLIER: u64 = 6364136223846793005;
+        /// Stream of the reference implementation's default generator, increment 0xda3e39cb94b95bdb.
+        pub const DEFAULT_STREAM: u64 = 0xda3e39cb94b95bdb >> 1;
+
+        /// Seed like `pcg32_srandom_r` of the reference implementation.
+        /// Only the lower 63 bits of `stream` are used.
+        pub fn with_stream(seed: u64, stream: u64) -> Self {
+            let mut rng = Pcg32 {
+                state: 0,
+                increment: (stream << 1) | 1,
+            };
+            rng.step();
+            rng.state = rng.state.wrapping_add(seed);
+            rng.step();
+            rng
+        }
+
+        fn step(&mut self) {
+            self.state = self
+                .state
+                .wrapping_mul(Self::MULTIPLIER)
+                .wrapping_add(self.increment);
+        }
+    }
+
+    /// Default stream of the 128 bit reference generators, increment 0x5851f42d4c957f2d14057b7ef767814f.
+    const DEFAULT_STREAM_128: u128 = 0x5851f42d4c957f2d14057b7ef767814f >> 1;
+
+    /// PCG64 (XSL-RR): 128 bit LCG state, 64 bit output by xoring both halves and a random rotation.
+    /// Identical to `PCG64` of NumPy, which outputs the state after the step.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Pcg64 {
+        state: u128,
+        increment: u128,
+    }
+
+    impl RNG for Pcg64 {
+        fn new(seed: u64) -> Self {
+            Self::with_stream(seed as u128, DEFAULT_STREAM_128)
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.next() as u32
+        }
+
+        fn next(&mut self) -> u64 {
+            self.step();
+            ((self.state >> 64) as u64 ^ self.state as u64).rotate_right((self.state >> 122) as u32)
+        }
+
+        fn advance(&mut self, delta: u64) {
+            self.advance_u128(delta as u128);
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            *self = Self::new(seed);
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&(self.state, self.increment)))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            let (state, increment) = state::decode(bytes)?;
+            Ok(Self { state, increment })
+        }
+    }
+
+    /// Selects the increment like `with_stream` and keeps the state. Streams with related increments are correlated, see `Pcg64Dxsm`.
+    impl Streamed for Pcg64 {
+        fn set_stream(&mut self, stream: u64) {
+            self.increment = ((stream as u128) << 1) | 1;
+        }
+    }
+
+    impl Pcg64 {
+        pub const MULTIPLIER: u128 = 0x2360ed051fc65da44385df649fccf645;
+
+        /// Seed like `pcg64_srandom_r` of the reference implementation.
+        /// Only the lower 127 bits of `stream` are used.
+        pub fn with_stream(seed: u128, stream: u128) -> Self {
+            let mut rng = Self::from_state(0, (stream << 1) | 1);
+            rng.step();
+            rng.state = rng.state.wrapping_add(seed);
+            rng.step();
+            rng
+        }
+
+        /// Start from a raw state, e.g. the `state` and `inc` values of the `state` dictionary of NumPy's `PCG64`.
+        /// The increment is made odd.
+        pub fn from_state(state: u128, increment: u128) -> Self {
+            Pcg64 {
+                state,
+                increment: increment | 1,
+            }
+        }
+
+        /// Advance the state by a 128 bit number of steps in O(log delta).
+        pub fn advance_u128(&mut self, delta: u128) {
+            self.state = lcg_jump(self.state, Self::MULTIPLIER, self.increment, delta);
+        }
+
+        fn step(&mut self) {
+            self.state = self
+                .state
+                .wrapping_mul(Self::MULTIPLIER)
+                .wrapping_add(self.increment);
+        }
+    }
+
+    /// PCG64-DXSM: 128 bit LCG state with a 64 bit multiplier, 64 bit output by the double xorshift multiply permutation.
+    /// Identical to `PCG64DXSM` of NumPy, which outputs the state before the step.
+    /// Fixes the correlations between streams of PCG64 with related increments.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Pcg64Dxsm {
+        state: u128,
+        increment: u128,
+    }
+
+    impl RNG for Pcg64Dxsm {
+        fn new(seed: u64) -> Self {
+            Self::with_stream(seed as u128, DEFAULT_STREAM_128)
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.next() as u32
+        }
+
+        fn next(&mut self) -> u64 {
+            let mut high = (self.state >> 64) as u64;
+            let low = self.state as u64 | 1;
+            high ^= high >> 32;
+            high = high.wrapping_mul(Self::MULTIPLIER as u64);
+            high ^= high >> 48;
+            high = high.wrapping_mul(low);
+            self.step();
+            high
+        }
+
+        fn advance(&mut self, delta: u64) {
+            self.advance_u128(delta as u128);
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            *self = Self::new(seed);
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&(self.state, self.increment)))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            let (state, increment) = state::decode(bytes)?;
+            Ok(Self { state, increment })
+        }
+    }
+
+    /// Selects the increment like `with_stream` and keeps the state.
+    impl Streamed for Pcg64Dxsm {
+        fn set_stream(&mut self, stream: u64) {
+            self.increment = ((stream as u128) << 1) | 1;
+        }
+    }
+
+    impl Pcg64Dxsm {
+        /// The 64 bit "cheap multiplier", used for the LCG step and the output permutation.
+        pub const MULTIPLIER: u128 = 0xda942042e4dd58b5;
+
+        /// Seed like `pcg_cm_srandom_r` of NumPy, which `PCG64DXSM` calls with the words of its seed sequence.
+        /// Only the lower 127 bits of `stream` are used.
+        pub fn with_stream(seed: u128, stream: u128) -> Self {
+            let mut rng = Self::from_state(0, (stream << 1) | 1);
+            rng.step();
+            rng.state = rng.state.wrapping_add(seed);
+            rng.step();
+            rng
+        }
+
+        /// Start from a raw state, e.g. the `state` and `inc` values of the `state` dictionary of NumPy's `PCG64DXSM`.
+        /// The increment is made odd.
+        pub fn from_state(state: u128, increment: u128) -> Self {
+            Pcg64Dxsm {
+                state,
+                increment: increment | 1,
+            }
+        }
+
+        /// Advance the state by a 128 bit number of steps in O(log delta).
+        pub fn advance_u128(&mut self, delta: u128) {
+            self.state = lcg_jump(self.state, Self::MULTIPLIER, self.increment, delta);
+        }
+
+        fn step(&mut self) {
+            self.state = self
+                .state
+                .wrapping_mul(Self::MULTIPLIER)
+                .wrapping_add(self.increment);
+        }
+    }
+}
+
+/// Mersenne Twisters of Matsumoto and Nishimura: twisted GFSRs with 19937 bits of state,
+/// whose outputs are tempered by a linear bijection. Equidistributed in up to 623 (32 bit) or 311 (64 bit) dimensions.
+/// The output is linear over GF(2), so they fail linear complexity tests on sequences longer than the state.
+pub mod mt {
+    use super::{state, RNG};
+
+    const N: usize = 624;
+    const M: usize = 397;
+    const MATRIX_A: u32 = 0x9908b0df;
+    const UPPER_MASK: u32 = 0x80000000;
+
+    /// MT19937, 32 bit output. The .next() method uses two outputs, the first output fills the upper half.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Mt19937 {
+        state: [u32; N],
+        /// Index of the next state word to temper, `N` once all words are used.
+        index: usize,
+    }
+
+    impl RNG for Mt19937 {
+        /// Initialize with the 32 bit words of `seed` as the key of `from_key`, identical to
+        /// `random.seed(seed)` of Python, whose `random.getrandbits(32)` returns the output of `next_u32`.
+        fn new(seed: u64) -> Self {
+            if seed >> 32 == 0 {
+                Self::from_key(&[seed as u32])
+            } else {
+                Self::from_key(&[seed as u32, (seed >> 32) as u32])
+            }
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            if self.index >= N {
+                self.twist();
+            }
+            let mut y = self.state[self.index];
+            self.index += 1;
+            y ^= y >> 11;
+            y ^= (y << 7) & 0x9d2c5680;
+            y ^= (y << 15) & 0xefc60000;
+            y ^ (y >> 18)
+        }
+
+        fn next(&mut self) -> u64 {
+            let a: u64 = self.next_u32() as u64;
+            let b: u64 = self.next_u32() as u64;
+            (a << 32) | b
+        }
+
+        fn advance(&mut self, delta: u64) {
+            for _ in 0..delta {
+                let _ = self.next_u32();
+            }
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            *self = Self::new(seed);
+        }
+
+        fn native_bits(&self) -> u32 {
+            32
+        }
+
+        fn next_native(&mut self) -> u64 {
+            self.next_u32() as u64
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&(self.state, self.index)))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            let (state, index) = state::decode(bytes)?;
+            Ok(Self {
+                state,
+                index: state::check_index(index, N)?,
+            })
+        }
+    }
+
+    impl Mt19937 {
+        /// Initialize like `init_genrand` of the reference implementation, the seeding of C++ `std::mt19937`.
+        pub fn from_u32_seed(seed: u32) -> Self {
+            let mut state = [0u32; N];
+            state[0] = seed;
+            for i in 1..N {
+                state[i] = 1812433253u32
+                    .wrapping_mul(state[i - 1] ^ (state[i - 1] >> 30))
+                    .wrapping_add(i as u32);
+            }
+            Mt19937 { state, index: N }
+        }
+
+        /// Initialize like `init_by_array` of the reference implementation, an empty key is treated as [0].
+        pub fn from_key(key: &[u32]) -> Self {
+            let key: &[u32] = if key.is_empty() { &[0] } else { key };
+            let mut rng = Self::from_u32_seed(19650218);
+            let state = &mut rng.state;
+            let (mut i, mut j) = (1, 0);
+            for _ in 0..N.max(key.len()) {
+                state[i] = (state[i] ^ (state[i - 1] ^ (state[i - 1] >> 30)).wrapping_mul(1664525))
+                    .wrapping_add(key[j])
+                    .wrapping_add(j as u32);
+                i += 1;
+                j += 1;
+                if i >= N {
+                    state[0] = state[N - 1];
+                    i = 1;
+                }
+                if j >= key.len() {
+                    j = 0;
+                }
+            }
+            for _ in 0..N - 1 {
+                state[i] = (state[i]
+                    ^ (state[i - 1] ^ (state[i - 1] >> 30)).wrapping_mul(1566083941))
+                .wrapping_sub(i as u32);
+                i += 1;
+                if i >= N {
+                    state[0] = state[N - 1];
+                    i = 1;
+                }
+            }
+            state[0] = UPPER_MASK;
+            rng
+        }
+
+        /// Generate the next `N` state words.
+        fn twist(&mut self) {
+            for i in 0..N {
+                let y = (self.state[i] & UPPER_MASK) | (self.state[(i + 1) % N] & !UPPER_MASK);
+                let mag = if y & 1 == 1 { MATRIX_A } else { 0 };
+                self.state[i] = self.state[(i + M) % N] ^ (y >> 1) ^ mag;
+            }
+            self.index = 0;
+        }
+    }
+
+    const N_64: usize = 312;
+    const M_64: usize = 156;
+    const MATRIX_A_64: u64 = 0xb5026f5aa96619e9;
+    const UPPER_MASK_64: u64 = 0xffffffff80000000;
+
+    /// MT19937-64, the 64 bit variant with its own recurrence and tempering.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Mt19937_64 {
+        state: [u64; N_64],
+        /// Index of the next state word to temper, `N_64` once all words are used.
+        index: usize,
+    }
+
+    impl RNG for Mt19937_64 {
+        /// Initialize like `init_genrand64` of the reference implementation, the seeding of C++ `std::mt19937_64`.
+        fn new(seed: u64) -> Self {
+            let mut state = [0u64; N_64];
+            state[0] = seed;
+            for i in 1..N_64 {
+                state[i] = 6364136223846793005u64
+                    .wrapping_mul(state[i - 1] ^ (state[i - 1] >> 62))
+                    .wrapping_add(i as u64);
+            }
+            Mt19937_64 { state, index: N_64 }
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.next() as u32
+        }
+
+        fn next(&mut self) -> u64 {
+            if self.index >= N_64 {
+                self.twist();
+            }
+            let mut x = self.state[self.index];
+            self.index += 1;
+            x ^= (x >> 29) & 0x5555555555555555;
+            x ^= (x << 17) & 0x71d67fffeda60000;
+            x ^= (x << 37) & 0xfff7eee000000000;
+            x ^ (x >> 43)
+        }
+
+        fn advance(&mut self, delta: u64) {
+            for _ in 0..delta {
+                let _ = self.next();
+            }
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            *self = Self::new(seed);
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&(self.state, self.index)))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            let (state, index) = state::decode(bytes)?;
+            Ok(Self {
+                state,
+                index: state::check_index(index, N_64)?,
+            })
+        }
+    }
+
+    impl Mt19937_64 {
+        /// Generate the next `N_64` state words.
+        fn twist(&mut self) {
+            for i in 0..N_64 {
+                let x =
+                    (self.state[i] & UPPER_MASK_64) | (self.state[(i + 1) % N_64] & !UPPER_MASK_64);
+                let mag = if x & 1 == 1 { MATRIX_A_64 } else { 0 };
+                self.state[i] = self.state[(i + M_64) % N_64] ^ (x >> 1) ^ mag;
+            }
+            self.index = 0;
+        }
+    }
+}
+
+/// WELL generators of Panneton, L'Ecuyer and Matsumoto: linear recurrences over GF(2) like the Mersenne Twister,
+/// with better equidistribution and faster recovery from states with few one bits.
+pub mod well {
+    use super::{splitmix, state, RNG};
+
+    /// WELL512a, 512 bits of state in 16 words. Identical to `WELLRNG512a` of the reference implementation.
+    /// The .next() method uses two outputs, the first output fills the upper half.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Well512a {
+        state: [u32; 16],
+        index: usize,
+    }
+
+    impl RNG for Well512a {
+        fn new(seed: u64) -> Self {
+            let words: [u64; 8] = splitmix::expand_seed(seed);
+            Self::from_state(std::array::from_fn(|i| {
+                (words[i / 2] >> (32 * (i % 2))) as u32
+            }))
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            let v0 = self.state[self.index];
+            let vm1 = self.state[(self.index + 13) & 15];
+            let vm2 = self.state[(self.index + 9) & 15];
+            let z0 = self.state[(self.index + 15) & 15];
+            let z1 = (v0 ^ (v0 << 16)) ^ (vm1 ^ (vm1 << 15));
+            let z2 = vm2 ^ (vm2 >> 11);
+            let new_v1 = z1 ^ z2;
+            self.state[self.index] = new_v1;
+            self.index = (self.index + 15) & 15;
+            self.state[self.index] = (z0 ^ (z0 << 2))
+                ^ (z1 ^ (z1 << 18))
+                ^ (z2 << 28)
+                ^ (new_v1 ^ ((new_v1 << 5) & 0xda442d24));
+            self.state[self.index]
+        }
+
+        fn next(&mut self) -> u64 {
+            let a: u64 = self.next_u32() as u64;
+            let b: u64 = self.next_u32() as u64;
+            (a << 32) | b
+        }
+
+        fn advance(&mut self, delta: u64) {
+            for _ in 0..delta {
+                let _ = self.next_u32();
+            }
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            *self = Self::new(seed);
+        }
+
+        fn native_bits(&self) -> u32 {
+            32
+        }
+
+        fn next_native(&mut self) -> u64 {
+            self.next_u32() as u64
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&(self.state, self.index)))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            let (state, index) = state::decode(bytes)?;
+            Ok(Self {
+                state,
+                index: state::check_index(index, 15)?,
+            })
+        }
+    }
+
+    impl Well512a {
+        /// Start from a raw state, like `InitWELLRNG512a` of the reference implementation.
+        /// The state must not be all zero.
+        pub fn from_state(state: [u32; 16]) -> Self {
+            Self { state, index: 0 }
+        }
+    }
+}
+
+/// Linear feedback shift registers in Galois and Fibonacci form, over u32, u64 or u128 with selectable taps.
+/// One step shifts out one bit, the output is linear in the state and fails linear complexity tests by design.
+/// Useful as a teaching target and as a component of combined generators.
+pub mod lfsr {
+    use super::{gf2, splitmix, state, RNG};
+    use std::fmt::Debug;
+    use std::ops::{BitAnd, BitOr, BitXor, Shl, Shr};
+
+    /// Unsigned integer holding the register.
+    pub trait Word:
+        gf2::Vector
+        + state::Encode
+        + Debug
+        + Eq
+        + BitAnd<Output = Self>
+        + BitOr<Output = Self>
+        + BitXor<Output = Self>
+        + Shl<u32, Output = Self>
+        + Shr<u32, Output = Self>
+    {
+        const BITS: u32;
+        const ONE: Self;
+        /// Primitive polynomial of degree `BITS`, from the maximal length tap table of Xilinx XAPP052.
+        const DEFAULT_POLYNOMIAL: Self;
+        /// Lowest `BITS` bits of `words`, the first word is the least significant.
+        fn from_words(words: [u64; 2]) -> Self;
+        /// Inverse of `from_words`, the unused bits are zero.
+        fn to_words(self) -> [u64; 2];
+        fn parity(self) -> bool;
+        fn reverse_bits(self) -> Self;
+    }
+
+    macro_rules! impl_word {
+        ($word:ty, $polynomial:expr) => {
+            impl Word for $word {
+                const BITS: u32 = <$word>::BITS;
+                const ONE: Self = 1;
+                const DEFAULT_POLYNOMIAL: Self = $polynomial;
+
+                fn from_words(words: [u64; 2]) -> Self {
+                    (((words[1] as u128) << 64) | words[0] as u128) as $word
+                }
+
+                fn to_words(self) -> [u64; 2] {
+                    [self as u64, (self as u128 >> 64) as u64]
+                }
+
+                fn parity(self) -> bool {
+                    self.count_ones() & 1 == 1
+                }
+
+                fn reverse_bits(self) -> Self {
+                    <$word>::reverse_bits(self)
+                }
+            }
+        };
+    }
+
+    // x^32 + x^22 + x^2 + x + 1
+    impl_word!(u32, 0x0040_0007);
+    // x^64 + x^63 + x^61 + x^60 + 1
+    impl_word!(u64, 0xb000_0000_0000_0001);
+    // x^128 + x^126 + x^101 + x^99 + 1
+    impl_word!(u128, 0x4000_0028_0000_0000_0000_0000_0000_0001);
+
+    /// Feedback polynomial from a tap list in the XAPP052 notation, e.g. `[32, 22, 2, 1]` for x^32 + x^22 + x^2 + x + 1.
+    /// Bit j of the result is the coefficient of x^j, the leading term x^BITS is implicit and the constant term always set.
+    pub fn polynomial<W: Word>(taps: &[u32]) -> Result<W, String> {
+        if !taps.contains(&W::BITS) {
+            return Err(format!(
+                "Taps must include the register length {}.",
+                W::BITS
+            ));
+        }
+        let mut polynomial = W::ONE;
+        for &tap in taps {
+            match tap {
+                tap if tap == W::BITS => {}
+                tap if (1..W::BITS).contains(&tap) => polynomial = polynomial | (W::ONE << tap),
+                _ => return Err(format!("Tap {} is outside of 1..={}.", tap, W::BITS)),
+            }
+        }
+        Ok(polynomial)
+    }
+
+    fn check<W: Word>(state: W, polynomial: W) -> Result<(), String> {
+        if state == W::ZERO {
+            return Err("LFSR state must not be zero.".to_string());
+        }
+        if polynomial & W::ONE == W::ZERO {
+            return Err("Feedback polynomial must have a constant term.".to_string());
+        }
+        Ok(())
+    }
+
+    /// Nonzero register for `seed`, taken from the SplitMix64 output.
+    fn state_from_seed<W: Word>(seed: u64) -> W {
+        let state = W::from_words(splitmix::expand_seed(seed));
+        if state == W::ZERO {
+            W::ONE
+        } else {
+            state
+        }
+    }
+
+    /// Fibonacci LFSR, the feedback bit is the parity of the tapped state bits and enters at the top.
+    /// The state always holds the next `BITS` output bits, lowest bit first.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Fibonacci<W: Word> {
+        state: W,
+        polynomial: W,
+    }
+
+    impl<W: Word> Fibonacci<W> {
+        /// Start from a raw state with the feedback polynomial from `polynomial`.
+        /// Only primitive polynomials reach the maximal period 2^BITS - 1.
+        pub fn with_polynomial(state: W, polynomial: W) -> Result<Self, String> {
+            check(state, polynomial)?;
+            Ok(Self { state, polynomial })
+        }
+
+        /// Shift out one bit.
+        pub fn step(&mut self) -> bool {
+            let output = self.state & W::ONE;
+            let feedback = if (self.state & self.polynomial).parity() {
+                W::ONE << (W::BITS - 1)
+            } else {
+                W::ZERO
+            };
+            self.state = (self.state >> 1) | feedback;
+            output == W::ONE
+        }
+
+        pub fn state(&self) -> W {
+            self.state
+        }
+
+        pub fn polynomial(&self) -> W {
+            self.polynomial
+        }
+    }
+
+    /// Galois LFSR, the output bit toggles the tapped state bits.
+    /// Produces the same bit sequence as `Fibonacci` with the same polynomial, at a different phase for the same state.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Galois<W: Word> {
+        state: W,
+        toggle: W,
+    }
+
+    impl<W: Word> Galois<W> {
+        /// Start from a raw state with the feedback polynomial from `polynomial`.
+        /// Only primitive polynomials reach the maximal period 2^BITS - 1.
+        pub fn with_polynomial(state: W, polynomial: W) -> Result<Self, String> {
+            check(state, polynomial)?;
+            Ok(Self {
+                state,
+                toggle: polynomial.reverse_bits(),
+            })
+        }
+
+        /// Shift out one bit.
+        pub fn step(&mut self) -> bool {
+            let output = self.state & W::ONE;
+            self.state = self.state >> 1;
+            if output == W::ONE {
+                self.state = self.state ^ self.toggle;
+            }
+            output == W::ONE
+        }
+
+        pub fn state(&self) -> W {
+            self.state
+        }
+
+        pub fn polynomial(&self) -> W {
+            self.toggle.reverse_bits()
+        }
+    }
+
+    macro_rules! impl_rng {
+        ($lfsr:ident) => {
+            /// Seeded from SplitMix64 with `Word::DEFAULT_POLYNOMIAL`.
+            /// The .next_u32() and .next() methods take one step per bit, the first bit fills the lowest bit.
+            impl<W: Word> RNG for $lfsr<W> {
+                fn new(seed: u64) -> Self {
+                    Self::with_polynomial(state_from_seed(seed), W::DEFAULT_POLYNOMIAL)
+                        .expect("default state and polynomial are valid")
+                }
+
+                fn next_u32(&mut self) -> u32 {
+                    (0..32).fold(0, |word, bit| word | ((self.step() as u32) << bit))
+                }
+
+                fn next(&mut self) -> u64 {
+                    (0..64).fold(0, |word, bit| word | ((self.step() as u64) << bit))
+                }
+
+                /// Jumps with the feedback polynomial, the characteristic polynomial of the update,
+                /// for deltas of at least one step per bit.
+                fn advance(&mut self, delta: u64) {
+                    if delta < W::BITS as u64 {
+                        for _ in 0..delta {
+                            let _ = self.step();
+                        }
+                        return;
+                    }
+                    let polynomial =
+                        gf2::jump_polynomial(&[delta], &self.polynomial().to_words(), W::BITS);
+                    let mut lfsr = *self;
+                    gf2::jump(&mut self.state, &polynomial, W::BITS, |state| {
+                        lfsr.state = *state;
+                        lfsr.step();
+                        *state = lfsr.state;
+                    });
+                }
+
+                fn reseed(&mut self, seed: u64) {
+                    *self = Self::new(seed);
+                }
+
+                fn native_bits(&self) -> u32 {
+                    1
+                }
+
+                fn next_native(&mut self) -> u64 {
+                    self.step() as u64
+                }
+
+                /// The state and the feedback polynomial.
+                fn state_bytes(&self) -> Option<Vec<u8>> {
+                    Some(state::encode(&(self.state(), self.polynomial())))
+                }
+
+                fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+                    let (state, polynomial) = state::decode(bytes)?;
+                    Self::with_polynomial(state, polynomial)
+                }
+            }
+        };
+    }
+
+    impl_rng!(Fibonacci);
+    impl_rng!(Galois);
+}
+
+/// Counter based generators in the style of Random123 (Salmon et al., "Parallel random numbers: as easy as 1, 2, 3", 2011).
+/// Every output block is a keyed bijection of a counter, so any position of any stream is computed directly.
+/// The key selects the stream, for parallel use each worker gets its own key and starts at counter 0.
+pub mod counter {
+    use super::{state, SeekableRNG, RNG};
+    use std::{fmt::Debug, marker::PhantomData};
+
+    /// Keyed bijection from a 128 bit counter to a block of output words.
+    pub trait Bijection {
+        type Key: Copy + Debug + PartialEq + Eq + state::Encode;
+        /// Bits of every output word, 32 or 64.
+        const WORD_BITS: u32;
+        /// Output words per block, at most 4.
+        const WORDS: usize;
+        /// Key of the stream selected by `new(seed)`.
+        fn key_from_seed(seed: u64) -> Self::Key;
+        /// Output block of `counter` under `key`, in the first `WORDS` entries.
+        fn block(counter: u128, key: &Self::Key) -> [u64; 4];
+    }
+
+    /// Generator that outputs the blocks of counter 0, 1, 2, ... of a `Bijection`.
+    /// The .next() method of 32 bit bijections uses two outputs, the first output fills the lower half.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct CounterRng<B: Bijection> {
+        /// Counter of the block in `buffer`.
+        counter: u128,
+        key: B::Key,
+        buffer: [u64; 4],
+        /// Index of the next word of `buffer`, `B::WORDS` once the block is used up.
+        index: usize,
+        bijection: PhantomData<B>,
+    }
+
+    impl<B: Bijection> RNG for CounterRng<B> {
+        fn new(seed: u64) -> Self {
+            Self::from_key_counter(B::key_from_seed(seed), 0)
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.next_native() as u32
+        }
+
+        fn next(&mut self) -> u64 {
+            if B::WORD_BITS == 64 {
+                return self.next_native();
+            }
+            let low: u64 = self.next_native();
+            let high: u64 = self.next_native();
+            (high << 32) | low
+        }
+
+        /// Skip `delta` words in O(1).
+        fn advance(&mut self, delta: u64) {
+            let position = self.index as u64 as u128 + delta as u128;
+            let blocks = position / B::WORDS as u128;
+            self.index = (position % B::WORDS as u128) as usize;
+            if blocks > 0 {
+                self.counter = self.counter.wrapping_add(blocks);
+                self.refill();
+            }
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            *self = Self::new(seed);
+        }
+
+        fn native_bits(&self) -> u32 {
+            B::WORD_BITS
+        }
+
+        fn next_native(&mut self) -> u64 {
+            if self.index >= B::WORDS {
+                self.counter = self.counter.wrapping_add(1);
+                self.index = 0;
+                self.refill();
+            }
+            let word = self.buffer[self.index];
+            self.index += 1;
+            word
+        }
+
+        fn as_seekable(&mut self) -> Option<&mut dyn SeekableRNG> {
+            Some(self)
+        }
+
+        /// The counter, the key and the index, the block is recomputed.
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&(self.counter, self.key, self.index)))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            let (counter, key, index) = state::decode(bytes)?;
+            let mut rng = Self::from_key_counter(key, counter);
+            rng.index = state::check_index(index, B::WORDS)?;
+            Ok(rng)
+        }
+    }
+
+    /// Positions count words, block `counter` starts at `counter * B::WORDS`.
+    /// Streams with more than one word per block are longer than 2^128 words,
+    /// `from_key_counter` and `advance_u128` reach the blocks past the range of `seek`.
+    impl<B: Bijection> SeekableRNG for CounterRng<B> {
+        fn seek(&mut self, position: u128) {
+            self.counter = position / B::WORDS as u128;
+            self.index = (position % B::WORDS as u128) as usize;
+            self.refill();
+        }
+
+        fn position(&self) -> u128 {
+            self.counter
+                .wrapping_mul(B::WORDS as u128)
+                .wrapping_add(self.index as u128)
+        }
+    }
+
+    impl<B: Bijection> CounterRng<B> {
+        /// Start at the first word of block `counter` of the stream `key`.
+        pub fn from_key_counter(key: B::Key, counter: u128) -> Self {
+            let mut rng = Self {
+                counter,
+                key,
+                buffer: [0; 4],
+                index: 0,
+                bijection: PhantomData,
+            };
+            rng.refill();
+            rng
+        }
+
+        /// Counter of the current block.
+        pub fn counter(&self) -> u128 {
+            self.counter
+        }
+
+        /// Key of the current stream.
+        pub fn key(&self) -> B::Key {
+            self.key
+        }
+
+        /// Switch to the stream `key`, keeping the position.
+        pub fn set_key(&mut self, key: B::Key) {
+            self.key = key;
+            self.refill();
+        }
+
+        /// Advance `delta` blocks, wrapping around at 2^128.
+        pub fn advance_u128(&mut self, delta: u128) {
+            self.counter = self.counter.wrapping_add(delta);
+            self.refill();
+        }
+
+        fn refill(&mut self) {
+            self.buffer = B::block(self.counter, &self.key);
+        }
+    }
+}
+
+/// Philox generators, which mix the counter with wide multiplications and a Weyl sequence of round keys.
+pub mod philox {
+    use super::counter::{Bijection, CounterRng};
+
+    const MULTIPLIERS: [u32; 2] = [0xd2511f53, 0xcd9e8d57];
+    /// Weyl increments of the key between rounds, the golden ratio and sqrt(3) - 1 scaled to 32 bits.
+    const KEY_INCREMENTS: [u32; 2] = [0x9e3779b9, 0xbb67ae85];
+    const ROUNDS: usize = 10;
+
+    /// Philox4x32-10, the bijection from `counter` to an output block under `key`.
+    /// Identical to `philox4x32` of the Random123 library.
+    pub fn philox4x32(mut counter: [u32; 4], mut key: [u32; 2]) -> [u32; 4] {
+        for round in 0..ROUNDS {
+            if round > 0 {
+                key[0] = key[0].wrapping_add(KEY_INCREMENTS[0]);
+                key[1] = key[1].wrapping_add(KEY_INCREMENTS[1]);
+            }
+            let product0 = MULTIPLIERS[0] as u64 * counter[0] as u64;
+            let product1 = MULTIPLIERS[1] as u64 * counter[2] as u64;
+            counter = [
+                (product1 >> 32) as u32 ^ counter[1] ^ key[0],
+                product1 as u32,
+                (product0 >> 32) as u32 ^ counter[3] ^ key[1],
+                product0 as u32,
+            ];
+        }
+        counter
+    }
+
+    /// `philox4x32` with the 128 bit counter split into words, word 0 is the least significant.
+    /// `new` uses the seed as the 64 bit key.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Philox4x32Block;
+
+    impl Bijection for Philox4x32Block {
+        type Key = [u32; 2];
+        const WORD_BITS: u32 = 32;
+        const WORDS: usize = 4;
+
+        fn key_from_seed(seed: u64) -> Self::Key {
+            [seed as u32, (seed >> 32) as u32]
+        }
+
+        fn block(counter: u128, key: &Self::Key) -> [u64; 4] {
+            let words: [u32; 4] = std::array::from_fn(|i| (counter >> (32 * i)) as u32);
+            philox4x32(words, *key).map(|word| word as u64)
+        }
+    }
+
+    /// Philox4x32-10 with a 128 bit block counter and a 64 bit key.
+    pub type Philox4x32 = CounterRng<Philox4x32Block>;
+}
+
+/// Threefry generators, reduced-round Threefish block ciphers of the Skein hash function
+/// built only from additions, rotations and xors.
+pub mod threefry {
+    use super::counter::{Bijection, CounterRng};
+
+    /// Parity constant of the Threefish key schedule.
+    const KEY_PARITY: u64 = 0x1bd11bdaa9fc1a22;
+    const ROUNDS: usize = 20;
+    const ROTATIONS_2X64: [u32; 8] = [16, 42, 12, 31, 16, 32, 24, 21];
+    const ROTATIONS_4X64: [[u32; 2]; 8] = [
+        [14, 16],
+        [52, 57],
+        [23, 40],
+        [5, 37],
+        [25, 33],
+        [46, 12],
+        [58, 22],
+        [32, 32],
+    ];
+
+    /// Threefry2x64-20, identical to `threefry2x64` of the Random123 library.
+    pub fn threefry2x64(counter: [u64; 2], key: [u64; 2]) -> [u64; 2] {
+        let schedule = [key[0], key[1], KEY_PARITY ^ key[0] ^ key[1]];
+        let mut x = [
+            counter[0].wrapping_add(schedule[0]),
+            counter[1].wrapping_add(schedule[1]),
+        ];
+        for round in 0..ROUNDS {
+            x[0] = x[0].wrapping_add(x[1]);
+            x[1] = x[1].rotate_left(ROTATIONS_2X64[round % 8]) ^ x[0];
+            if round % 4 == 3 {
+                let injection = round / 4 + 1;
+                x[0] = x[0].wrapping_add(schedule[injection % 3]);
+                x[1] = x[1]
+                    .wrapping_add(schedule[(injection + 1) % 3])
+                    .wrapping_add(injection as u64);
+            }
+        }
+        x
+    }
+
+    /// Threefry4x64-20, identical to `threefry4x64` of the Random123 library.
+    pub fn threefry4x64(counter: [u64; 4], key: [u64; 4]) -> [u64; 4] {
+        let schedule = [
+            key[0],
+            key[1],
+            key[2],
+            key[3],
+            KEY_PARITY ^ key[0] ^ key[1] ^ key[2] ^ key[3],
+        ];
+        let mut x: [u64; 4] = std::array::from_fn(|i| counter[i].wrapping_add(schedule[i]));
+        for round in 0..ROUNDS {
+            // Even rounds mix the pairs (0, 1) and (2, 3), odd rounds (0, 3) and (2, 1).
+            let (a, b) = if round % 2 == 0 { (1, 3) } else { (3, 1) };
+            let [rotation_a, rotation_b] = ROTATIONS_4X64[round % 8];
+            x[0] = x[0].wrapping_add(x[a]);
+            x[a] = x[a].rotate_left(rotation_a) ^ x[0];
+            x[2] = x[2].wrapping_add(x[b]);
+            x[b] = x[b].rotate_left(rotation_b) ^ x[2];
+            if round % 4 == 3 {
+                let injection = round / 4 + 1;
+                for (i, word) in x.iter_mut().enumerate() {
+                    *word = word.wrapping_add(schedule[(injection + i) % 5]);
+                }
+                x[3] = x[3].wrapping_add(injection as u64);
+            }
+        }
+        x
+    }
+
+    /// `threefry2x64` with the 128 bit counter split into words, word 0 is the least significant.
+    /// `new` uses the seed as the first key word.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Threefry2x64Block;
+
+    impl Bijection for Threefry2x64Block {
+        type Key = [u64; 2];
+        const WORD_BITS: u32 = 64;
+        const WORDS: usize = 2;
+
+        fn key_from_seed(seed: u64) -> Self::Key {
+            [seed, 0]
+        }
+
+        fn block(counter: u128, key: &Self::Key) -> [u64; 4] {
+            let [a, b] = threefry2x64([counter as u64, (counter >> 64) as u64], *key);
+            [a, b, 0, 0]
+        }
+    }
+
+    /// `threefry4x64` with the 128 bit counter in the two lower words, the upper words are zero.
+    /// `new` uses the seed as the first key word.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Threefry4x64Block;
+
+    impl Bijection for Threefry4x64Block {
+        type Key = [u64; 4];
+        const WORD_BITS: u32 = 64;
+        const WORDS: usize = 4;
+
+        fn key_from_seed(seed: u64) -> Self::Key {
+            [seed, 0, 0, 0]
+        }
+
+        fn block(counter: u128, key: &Self::Key) -> [u64; 4] {
+            threefry4x64([counter as u64, (counter >> 64) as u64, 0, 0], *key)
+        }
+    }
+
+    /// Threefry2x64-20 with a 128 bit block counter and a 128 bit key.
+    pub type Threefry2x64 = CounterRng<Threefry2x64Block>;
+    /// Threefry4x64-20 with a 128 bit block counter and a 256 bit key.
+    pub type Threefry4x64 = CounterRng<Threefry4x64Block>;
+}
+
+/// Middle square generators of Widynski: von Neumann's middle square method, kept from collapsing by a Weyl sequence.
+/// See https://arxiv.org/abs/1704.00358 and https://arxiv.org/abs/2004.06278
+pub mod msws {
+    use super::{
+        counter::{Bijection, CounterRng},
+        splitmix, state, RNG,
+    };
+
+    /// Weyl increment of the reference implementation.
+    pub const DEFAULT_INCREMENT: u64 = 0xb5ad4eceda1ce2a9;
+
+    /// Middle square Weyl sequence: the square of the state plus the Weyl sequence, with the halves swapped.
+    /// The .next() method uses two outputs, the first output fills the upper half.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Msws {
+        x: u64,
+        weyl: u64,
+        increment: u64,
+    }
+
+    impl RNG for Msws {
+        /// Start from zero with the odd increment `key_from_seed(seed)`.
+        fn new(seed: u64) -> Self {
+            Self::from_state(0, 0, key_from_seed(seed))
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.weyl = self.weyl.wrapping_add(self.increment);
+            self.x = self.x.wrapping_mul(self.x).wrapping_add(self.weyl);
+            self.x = self.x.rotate_left(32);
+            self.x as u32
+        }
+
+        fn next(&mut self) -> u64 {
+            let a: u64 = self.next_u32() as u64;
+            let b: u64 = self.next_u32() as u64;
+            (a << 32) | b
+        }
+
+        fn advance(&mut self, delta: u64) {
+            for _ in 0..delta {
+                let _ = self.next_u32();
+            }
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            *self = Self::new(seed);
+        }
+
+        fn native_bits(&self) -> u32 {
+            32
+        }
+
+        fn next_native(&mut self) -> u64 {
+            self.next_u32() as u64
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&(self.x, self.weyl, self.increment)))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            let (x, weyl, increment) = state::decode(bytes)?;
+            Ok(Self { x, weyl, increment })
+        }
+    }
+
+    impl Msws {
+        /// Start from a raw state, the reference implementation starts with `from_state(0, 0, DEFAULT_INCREMENT)`.
+        /// The increment must be odd, and should have irregular bits in both halves.
+        pub fn from_state(x: u64, weyl: u64, increment: u64) -> Self {
+            Self { x, weyl, increment }
+        }
+    }
+
+    /// One middle square round, the square plus `offset` with the halves swapped.
+    fn round(x: u64, offset: u64) -> u64 {
+        x.wrapping_mul(x).wrapping_add(offset).rotate_left(32)
+    }
+
+    /// Squares, the 32 bit counter based variant: four middle square rounds of `counter * key`.
+    /// Identical to `squares32` of the reference implementation.
+    pub fn squares32(counter: u64, key: u64) -> u32 {
+        let y = counter.wrapping_mul(key);
+        let z = y.wrapping_add(key);
+        let x = round(round(round(y, y), z), y);
+        (x.wrapping_mul(x).wrapping_add(z) >> 32) as u32
+    }
+
+    /// Squares, the 64 bit counter based variant with a fifth round. Identical to `squares64` of the reference implementation.
+    pub fn squares64(counter: u64, key: u64) -> u64 {
+        let y = counter.wrapping_mul(key);
+        let z = y.wrapping_add(key);
+        let x = round(round(round(y, y), z), y);
+        let t = x.wrapping_mul(x).wrapping_add(z);
+        let x = t.rotate_left(32);
+        t ^ (x.wrapping_mul(x).wrapping_add(y) >> 32)
+    }
+
+    /// Odd key or increment for `seed`, the first output of SplitMix64.
+    /// Widynski recommends keys with irregular bits, which rules out simple keys like the seed itself,
+    /// and plain `mix64`, which maps 0 to 0.
+    fn key_from_seed(seed: u64) -> u64 {
+        splitmix::expand_seed::<1>(seed)[0] | 1
+    }
+
+    /// `squares32` with the lower 64 bits of the counter, the period of a stream is 2^64.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Squares32Block;
+
+    impl Bijection for Squares32Block {
+        type Key = u64;
+        const WORD_BITS: u32 = 32;
+        const WORDS: usize = 1;
+
+        fn key_from_seed(seed: u64) -> Self::Key {
+            key_from_seed(seed)
+        }
+
+        fn block(counter: u128, key: &Self::Key) -> [u64; 4] {
+            [squares32(counter as u64, *key) as u64, 0, 0, 0]
+        }
+    }
+
+    /// `squares64` with the lower 64 bits of the counter, the period of a stream is 2^64.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Squares64Block;
+
+    impl Bijection for Squares64Block {
+        type Key = u64;
+        const WORD_BITS: u32 = 64;
+        const WORDS: usize = 1;
+
+        fn key_from_seed(seed: u64) -> Self::Key {
+            key_from_seed(seed)
+        }
+
+        fn block(counter: u128, key: &Self::Key) -> [u64; 4] {
+            [squares64(counter as u64, *key), 0, 0, 0]
+        }
+    }
+
+    /// Squares with 32 bit outputs.
+    pub type Squares32 = CounterRng<Squares32Block>;
+    /// Squares with 64 bit outputs.
+    pub type Squares64 = CounterRng<Squares64Block>;
+}
+
+/// SipHash of Aumasson and Bernstein, a keyed hash for short inputs, run over a counter with the seed as the key.
+/// A pseudorandom function like the block ciphers of `spn` at a fraction of their cost,
+/// `siphash` is also usable on its own to hash seeds or other data under a key.
+/// See https://www.aumasson.jp/siphash/siphash.pdf
+pub mod siphash {
+    use super::counter::{Bijection, CounterRng};
+
+    /// SipHash-C-D of `data` under `key`, with `C` compression rounds per 8 byte word and `D` finalization rounds.
+    /// Identical to the reference implementation with the key bytes in little endian order.
+    pub fn siphash<const C: usize, const D: usize>(key: [u64; 2], data: &[u8]) -> u64 {
+        let mut v = [
+            key[0] ^ 0x736f6d6570736575,
+            key[1] ^ 0x646f72616e646f6d,
+            key[0] ^ 0x6c7967656e657261,
+            key[1] ^ 0x7465646279746573,
+        ];
+        let chunks = data.chunks_exact(8);
+        let mut last = [0u8; 8];
+        last[..chunks.remainder().len()].copy_from_slice(chunks.remainder());
+        last[7] = data.len() as u8;
+        for word in chunks
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+            .chain(std::iter::once(u64::from_le_bytes(last)))
+        {
+            v[3] ^= word;
+            for _ in 0..C {
+                round(&mut v);
+            }
+            v[0] ^= word;
+        }
+        v[2] ^= 0xff;
+        for _ in 0..D {
+            round(&mut v);
+        }
+        v[0] ^ v[1] ^ v[2] ^ v[3]
+    }
+
+    /// SipHash-2-4, the standard variant.
+    pub fn siphash24(key: [u64; 2], data: &[u8]) -> u64 {
+        siphash::<2, 4>(key, data)
+    }
+
+    /// SipHash-1-3, the faster variant used by the hash tables of Rust and Python.
+    pub fn siphash13(key: [u64; 2], data: &[u8]) -> u64 {
+        siphash::<1, 3>(key, data)
+    }
+
+    /// One SipRound.
+    fn round(v: &mut [u64; 4]) {
+        v[0] = v[0].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(13);
+        v[1] ^= v[0];
+        v[0] = v[0].rotate_left(32);
+        v[2] = v[2].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(16);
+        v[3] ^= v[2];
+        v[0] = v[0].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(21);
+        v[3] ^= v[0];
+        v[2] = v[2].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(17);
+        v[1] ^= v[2];
+        v[2] = v[2].rotate_left(32);
+    }
+
+    /// `siphash` of the 16 little endian bytes of the counter.
+    /// `new` uses the seed as the first key word and 0 as the second.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct SipHashBlock<const C: usize, const D: usize>;
+
+    impl<const C: usize, const D: usize> Bijection for SipHashBlock<C, D> {
+        type Key = [u64; 2];
+        const WORD_BITS: u32 = 64;
+        const WORDS: usize = 1;
+
+        fn key_from_seed(seed: u64) -> Self::Key {
+            [seed, 0]
+        }
+
+        fn block(counter: u128, key: &Self::Key) -> [u64; 4] {
+            [siphash::<C, D>(*key, &counter.to_le_bytes()), 0, 0, 0]
+        }
+    }
+
+    /// SipHash-2-4 over a 128 bit counter with a 128 bit key.
+    pub type SipHash24Rng = CounterRng<SipHashBlock<2, 4>>;
+    /// SipHash-1-3 over a 128 bit counter with a 128 bit key.
+    pub type SipHash13Rng = CounterRng<SipHashBlock<1, 3>>;
+}
+
+/// RNGs based on permutation substitution networks.
+#[cfg(feature = "crypto-rngs")]
+pub mod spn {
+    #[cfg(all(feature = "simd", target_arch = "aarch64"))]
+    use std::arch::aarch64::*;
+    #[cfg(all(feature = "simd", target_arch = "x86"))]
+    use std::arch::x86::*;
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    use std::arch::x86_64::*;
+
+    use super::{state, SeekableRNG, Streamed, RNG};
+
+    /// AES-128 in counter mode with `ROUNDS` rounds, 1 to 10, and the full key schedule.
+    /// A reduced round variant is AES-128 stopped early: the first round key is added,
+    /// `ROUNDS - 1` full rounds follow and the last round omits MixColumns, as in the cryptanalysis literature.
+    /// Uses the x86 AES instructions or the ARMv8 cryptographic extension with the `simd` feature if the CPU supports them,
+    /// detected at runtime, and `soft_aes_round` otherwise, all produce identical outputs.
+    /// Each step encrypts the next little endian 128 bit counter block and outputs its lower 64 bits.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Rijndael<const ROUNDS: usize> {
+        counter: u128,
+        aes: Aes128,
+    }
+
+    /// AES-128 in counter mode with all 10 rounds.
+    pub type RijndaelStream = Rijndael<10>;
+
+    impl<const ROUNDS: usize> RNG for Rijndael<ROUNDS> {
+        /// The key is the little endian seed followed by its complement.
+        fn new(seed: u64) -> Self {
+            let mut key: [u8; 16] = [0; 16];
+            key[0..8].clone_from_slice(&seed.to_le_bytes());
+            key[8..16].clone_from_slice(&(!seed).to_le_bytes());
+            Self::from_key(key)
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.next() as u32
+        }
+
+        fn next(&mut self) -> u64 {
+            self.advance(1);
+            u128::from_le_bytes(self.aes.encrypt_rounds(self.counter.to_le_bytes(), ROUNDS)) as u64
+        }
+
+        fn advance(&mut self, delta: u64) {
+            self.advance_u128(delta as u128);
+        }
+
+        /// Replace the key, the counter is kept.
+        fn reseed(&mut self, seed: u64) {
+            self.aes = Self::new(seed).aes;
+        }
+
+        fn as_seekable(&mut self) -> Option<&mut dyn SeekableRNG> {
+            Some(self)
+        }
+
+        /// The counter and the key, the round keys are recomputed.
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&(self.counter, self.aes.key())))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            let (counter, key) = state::decode(bytes)?;
+            let mut rng = Self::from_key(key);
+            rng.counter = counter;
+            Ok(rng)
+        }
+    }
+
+    /// The position is the 128 bit block counter.
+    impl<const ROUNDS: usize> SeekableRNG for Rijndael<ROUNDS> {
+        fn seek(&mut self, position: u128) {
+            self.counter = position;
+        }
+
+        fn position(&self) -> u128 {
+            self.counter
+        }
+    }
+
+    /// The stream is the upper half of the key, `new` uses the complement of the seed.
+    /// Switching keeps the lower half of the key and the counter.
+    impl<const ROUNDS: usize> Streamed for Rijndael<ROUNDS> {
+        fn set_stream(&mut self, stream: u64) {
+            let mut key = self.aes.key();
+            key[8..16].clone_from_slice(&stream.to_le_bytes());
+            self.aes = Aes128::new(key);
+        }
+    }
+
+    impl<const ROUNDS: usize> Rijndael<ROUNDS> {
+        const VALID_ROUNDS: () = assert!(
+            ROUNDS >= 1 && ROUNDS <= 10,
+            "AES-128 has between 1 and 10 rounds"
+        );
+
+        /// Start at counter zero with a raw AES-128 key.
+        pub fn from_key(key: [u8; 16]) -> Self {
+            let () = Self::VALID_ROUNDS;
+            Self {
+                counter: 0,
+                aes: Aes128::new(key),
+            }
+        }
+
+        /// Advance the 128 bit block counter, wrapping around at 2^128.
+        pub fn advance_u128(&mut self, delta: u128) {
+            self.counter = self.counter.wrapping_add(delta);
+        }
+    }
+
+    /// True if the x86 AES instructions or the ARMv8 AES instructions are compiled in and supported by the CPU.
+    /// The result of the detection is cached by the standard library.
+    pub fn hardware_aes() -> bool {
+        #[cfg(all(feature = "simd", any(target_arch = "x86", target_arch = "x86_64")))]
+        {
+            is_x86_feature_detected!("aes")
+        }
+        #[cfg(all(feature = "simd", target_arch = "aarch64"))]
+        {
+            std::arch::is_aarch64_feature_detected!("aes")
+        }
+        #[cfg(not(all(
+            feature = "simd",
+            any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")
+        )))]
+        {
+            false
+        }
+    }
+
+    /// Multiplication in GF(2^8) modulo the AES polynomial x^8 + x^4 + x^3 + x + 1.
+    const fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+        let mut product: u8 = 0;
+        while b != 0 {
+            if b & 1 != 0 {
+                product ^= a;
+            }
+            a = (a << 1) ^ if a & 0x80 != 0 { 0x1b } else { 0 };
+            b >>= 1;
+        }
+        product
+    }
+
+    /// AES S-box: multiplicative inverse (x^254) followed by the affine transformation.
+    const SBOX: [u8; 256] = {
+        let mut table = [0u8; 256];
+        let mut x: usize = 0;
+        while x < 256 {
+            let mut inverse: u8 = 1;
+            let mut i = 0;
+            while i < 254 {
+                inverse = gf_mul(inverse, x as u8);
+                i += 1;
+            }
+            table[x] = inverse
+                ^ inverse.rotate_left(1)
+                ^ inverse.rotate_left(2)
+                ^ inverse.rotate_left(3)
+                ^ inverse.rotate_left(4)
+                ^ 0x63;
+            x += 1;
+        }
+        table
+    };
+
+    /// ShiftRows followed by SubBytes, the first half of an AES round.
+    fn shift_sub(block: [u8; 16]) -> [u8; 16] {
+        std::array::from_fn(|i| {
+            let (row, column) = (i % 4, i / 4);
+            SBOX[block[row + 4 * ((column + row) % 4)] as usize]
+        })
+    }
+
+    /// Portable equivalent of the x86 AESENC instruction:
+    /// ShiftRows, SubBytes and MixColumns followed by adding the round key.
+    /// Byte i of the block is row i % 4 of column i / 4 of the AES state.
+    /// SubBytes is a table lookup, so the timing depends on the data and the key, which is irrelevant for statistical testing
+    /// but means this is not a constant time AES implementation.
+    pub fn soft_aes_round(block: [u8; 16], round_key: &[u8; 16]) -> [u8; 16] {
+        let state = shift_sub(block);
+        let mut output = [0u8; 16];
+        for (column, mixed) in state.chunks_exact(4).zip(output.chunks_exact_mut(4)) {
+            for row in 0..4 {
+                mixed[row] = gf_mul(column[row], 2)
+                    ^ gf_mul(column[(row + 1) % 4], 3)
+                    ^ column[(row + 2) % 4]
+                    ^ column[(row + 3) % 4];
+            }
+        }
+        for (byte, key_byte) in output.iter_mut().zip(round_key) {
+            *byte ^= key_byte;
+        }
+        output
+    }
+
+    /// Portable equivalent of the x86 AESENCLAST instruction, the final AES round without MixColumns.
+    pub fn soft_aes_last_round(block: [u8; 16], round_key: &[u8; 16]) -> [u8; 16] {
+        let mut output = shift_sub(block);
+        for (byte, key_byte) in output.iter_mut().zip(round_key) {
+            *byte ^= key_byte;
+        }
+        output
+    }
+
+    /// AES-128 block cipher of FIPS 197. Uses the x86 AES instructions under the same conditions as `Rijndael`, see `hardware_aes`.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Aes128 {
+        round_keys: [[u8; 16]; 11],
+    }
+
+    impl Aes128 {
+        /// Expand `key` into the 11 round keys.
+        pub fn new(key: [u8; 16]) -> Self {
+            let mut words = [[0u8; 4]; 44];
+            for (i, word) in key.chunks_exact(4).enumerate() {
+                words[i].copy_from_slice(word);
+            }
+            let mut round_constant: u8 = 1;
+            for i in 4..44 {
+                let mut temp = words[i - 1];
+                if i % 4 == 0 {
+                    temp = [
+                        SBOX[temp[1] as usize] ^ round_constant,
+                        SBOX[temp[2] as usize],
+                        SBOX[temp[3] as usize],
+                        SBOX[temp[0] as usize],
+                    ];
+                    round_constant = gf_mul(round_constant, 2);
+                }
+                words[i] = std::array::from_fn(|j| words[i - 4][j] ^ temp[j]);
+            }
+            Self {
+                round_keys: std::array::from_fn(|round| {
+                    std::array::from_fn(|i| words[4 * round + i / 4][i % 4])
+                }),
+            }
+        }
+
+        pub fn encrypt(&self, block: [u8; 16]) -> [u8; 16] {
+            self.encrypt_rounds(block, 10)
+        }
+
+        /// The cipher key, which is also the first round key.
+        pub fn key(&self) -> [u8; 16] {
+            self.round_keys[0]
+        }
+
+        /// Encrypt with only the first `rounds` rounds, the last of them without MixColumns.
+        /// Panics if `rounds` is not between 1 and 10.
+        pub fn encrypt_rounds(&self, block: [u8; 16], rounds: usize) -> [u8; 16] {
+            assert!(
+                (1..=10).contains(&rounds),
+                "AES-128 has between 1 and 10 rounds"
+            );
+            aes128_encrypt(block, &self.round_keys[..=rounds])
+        }
+    }
+
+    /// Encrypt with `round_keys.len() - 1` rounds.
+    fn aes128_encrypt(block: [u8; 16], round_keys: &[[u8; 16]]) -> [u8; 16] {
+        #[cfg(all(feature = "simd", any(target_arch = "x86", target_arch = "x86_64")))]
+        if hardware_aes() {
+            // SAFETY: the CPU supports the AES instructions.
+            return unsafe { aes128_encrypt_aesni(block, round_keys) };
+        }
+        #[cfg(all(feature = "simd", target_arch = "aarch64"))]
+        if hardware_aes() {
+            // SAFETY: the CPU supports the AES instructions.
+            return unsafe { aes128_encrypt_armv8(block, round_keys) };
+        }
+        soft_aes128_encrypt(block, round_keys)
+    }
+
+    fn soft_aes128_encrypt(block: [u8; 16], round_keys: &[[u8; 16]]) -> [u8; 16] {
+        let (last_key, round_keys) = round_keys.split_last().unwrap();
+        let state: [u8; 16] = std::array::from_fn(|i| block[i] ^ round_keys[0][i]);
+        let state = round_keys[1..].iter().fold(state, soft_aes_round);
+        soft_aes_last_round(state, last_key)
+    }
+
+    #[cfg(all(feature = "simd", any(target_arch = "x86", target_arch = "x86_64")))]
+    #[target_feature(enable = "aes")]
+    unsafe fn aes128_encrypt_aesni(block: [u8; 16], round_keys: &[[u8; 16]]) -> [u8; 16] {
+        let mut encrypted = [0u8; 16];
+        let load = |bytes: &[u8; 16]| _mm_loadu_si128(bytes.as_ptr() as *const __m128i);
+        let (last_key, round_keys) = round_keys.split_last().unwrap();
+        let mut state = _mm_xor_si128(load(&block), load(&round_keys[0]));
+        for round_key in &round_keys[1..] {
+            state = _mm_aesenc_si128(state, load(round_key));
+        }
+        state = _mm_aesenclast_si128(state, load(last_key));
+        _mm_storeu_si128(encrypted.as_mut_ptr() as *mut __m128i, state);
+        encrypted
+    }
+
+    /// AESE adds the round key before SubBytes and ShiftRows, so every round key but the last enters an AESE,
+    /// followed by AESMC for MixColumns except in the last round, and the last round key is added at the end.
+    #[cfg(all(feature = "simd", target_arch = "aarch64"))]
+    #[target_feature(enable = "neon,aes")]
+    unsafe fn aes128_encrypt_armv8(block: [u8; 16], round_keys: &[[u8; 16]]) -> [u8; 16] {
+        let mut encrypted = [0u8; 16];
+        let (last_key, round_keys) = round_keys.split_last().unwrap();
+        let (final_key, round_keys) = round_keys.split_last().unwrap();
+        let mut state = vld1q_u8(block.as_ptr());
+        for round_key in round_keys {
+            state = vaesmcq_u8(vaeseq_u8(state, vld1q_u8(round_key.as_ptr())));
+        }
+        state = vaeseq_u8(state, vld1q_u8(final_key.as_ptr()));
+        state = veorq_u8(state, vld1q_u8(last_key.as_ptr()));
+        vst1q_u8(encrypted.as_mut_ptr(), state);
+        encrypted
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn soft_aes_round_vector() {
+            // AESENC example from the Intel AES-NI white paper, as little endian xmm values.
+            let state: u128 = 0x7b5b54657374566563746f725d53475d;
+            let round_key: u128 = 0x48692853686179295b477565726f6e5d;
+            let result: u128 = 0xa8311c2f9fdba3c58b104b58ded7e595;
+            assert_eq!(
+                soft_aes_round(state.to_le_bytes(), &round_key.to_le_bytes()),
+                result.to_le_bytes()
+            );
+        }
+
+        #[test]
+        fn aes128_vector() {
+            // FIPS 197, appendix C.1.
+            let aes = Aes128::new(std::array::from_fn(|i| i as u8));
+            let plaintext: u128 = 0x00112233445566778899aabbccddeeff;
+            let ciphertext: u128 = 0x69c4e0d86a7b0430d8cdb78070b4c55a;
+            assert_eq!(
+                aes.encrypt(plaintext.to_be_bytes()),
+                ciphertext.to_be_bytes()
+            );
+            let state: [u8; 16] =
+                std::array::from_fn(|i| plaintext.to_be_bytes()[i] ^ aes.round_keys[0][i]);
+            let state = aes.round_keys[1..10].iter().fold(state, soft_aes_round);
+            assert_eq!(
+                soft_aes_last_round(state, &aes.round_keys[10]),
+                ciphertext.to_be_bytes()
+            );
+        }
+
+        #[test]
+        fn reduced_rounds() {
+            // FIPS 197, appendix C.1: one round is round[1].s_row xor round[1].k_sch.
+            let aes = Aes128::new(std::array::from_fn(|i| i as u8));
+            let plaintext: u128 = 0x00112233445566778899aabbccddeeff;
+            let one_round: u128 =
+                0x6353e08c0960e104cd70b751bacad0e7 ^ 0xd6aa74fdd2af72fadaa678f1d6ab76fe;
+            assert_eq!(
+                aes.encrypt_rounds(plaintext.to_be_bytes(), 1),
+                one_round.to_be_bytes()
+            );
+            let mut rng = Rijndael::<4>::from_key(std::array::from_fn(|i| i as u8));
+            rng.seek(0x0123456789abcdef);
+            let block = 0x0123456789abcdf0u128.to_le_bytes();
+            assert_eq!(
+                rng.next(),
+                u128::from_le_bytes(aes.encrypt_rounds(block, 4)) as u64
+            );
+        }
+
+        #[test]
+        fn soft_aes_matches_native() {
+            let aes = Aes128::new(std::array::from_fn(|i| (i * 37 + 11) as u8));
+            for counter in [0u128, 1, 0x0123456789abcdef, u128::MAX] {
+                let block = counter.to_le_bytes();
+                for rounds in 1..=10 {
+                    assert_eq!(
+                        aes128_encrypt(block, &aes.round_keys[..=rounds]),
+                        soft_aes128_encrypt(block, &aes.round_keys[..=rounds])
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// RNGs based on stream ciphers.
+#[cfg(feature = "crypto-rngs")]
+pub mod stream {
+    use super::{state, SeekableRNG, Streamed, RNG};
+
+    /// "expand 32-byte k", the first four words of every ChaCha block.
+    const CONSTANTS: [u32; 4] = [0x61707865, 0x3320646e, 0x79622d32, 0x6b206574];
+
+    /// Apply the quarter round to the words `a`, `b`, `c` and `d` of `x`.
+    fn quarter_round(x: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+        x[a] = x[a].wrapping_add(x[b]);
+        x[d] = (x[d] ^ x[a]).rotate_left(16);
+        x[c] = x[c].wrapping_add(x[d]);
+        x[b] = (x[b] ^ x[c]).rotate_left(12);
+        x[a] = x[a].wrapping_add(x[b]);
+        x[d] = (x[d] ^ x[a]).rotate_left(8);
+        x[c] = x[c].wrapping_add(x[d]);
+        x[b] = (x[b] ^ x[c]).rotate_left(7);
+    }
+
+    /// ChaCha keystream of Bernstein with `ROUNDS` rounds, in the original layout with a 64 bit block counter
+    /// and a 64 bit stream (nonce), identical to `ChaChaRng` of the rand_chacha crate.
+    /// The .next() method uses two outputs, the first output fills the lower half.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct ChaCha<const ROUNDS: usize> {
+        key: [u32; 8],
+        /// Block counter of the block in `buffer`.
+        counter: u64,
+        stream: u64,
+        buffer: [u32; 16],
+        /// Index of the next word of `buffer`, 16 once the block is used up.
+        index: usize,
+    }
+
+    /// ChaCha with 8 rounds, the fastest variant without a known attack.
+    pub type ChaCha8 = ChaCha<8>;
+    /// ChaCha with 12 rounds, used by the StdRng of the rand crate.
+    pub type ChaCha12 = ChaCha<12>;
+    /// ChaCha20, the cipher of RFC 8439.
+    pub type ChaCha20 = ChaCha<20>;
+
+    impl<const ROUNDS: usize> RNG for ChaCha<ROUNDS> {
+        /// Use `seed` as the lower 64 bits of the key, the rest of the key is zero.
+        fn new(seed: u64) -> Self {
+            Self::from_key([seed as u32, (seed >> 32) as u32, 0, 0, 0, 0, 0, 0])
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            if self.index >= 16 {
+                self.counter = self.counter.wrapping_add(1);
+                self.index = 0;
+                self.refill();
+            }
+            let word = self.buffer[self.index];
+            self.index += 1;
+            word
+        }
+
+        fn next(&mut self) -> u64 {
+            let low: u64 = self.next_u32() as u64;
+            let high: u64 = self.next_u32() as u64;
+            (high << 32) | low
+        }
+
+        /// Skip `delta` words of the keystream in O(1).
+        fn advance(&mut self, delta: u64) {
+            let position = self.index as u64 as u128 + delta as u128;
+            let blocks = (position / 16) as u64;
+            self.index = (position % 16) as usize;
+            if blocks > 0 {
+                self.counter = self.counter.wrapping_add(blocks);
+                self.refill();
+            }
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            *self = Self::new(seed);
+        }
+
+        fn native_bits(&self) -> u32 {
+            32
+        }
+
+        fn next_native(&mut self) -> u64 {
+            self.next_u32() as u64
+        }
+
+        fn as_seekable(&mut self) -> Option<&mut dyn SeekableRNG> {
+            Some(self)
+        }
+
+        /// The key, the block counter, the stream and the index, the block is recomputed.
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&(
+                self.key,
+                self.counter,
+                self.stream,
+                self.index,
+            )))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            let (key, counter, stream, index) = state::decode(bytes)?;
+            let mut rng = Self::from_key(key);
+            rng.counter = counter;
+            rng.stream = stream;
+            rng.index = state::check_index(index, 16)?;
+            rng.refill();
+            Ok(rng)
+        }
+    }
+
+    /// The stream has 2^68 words, 2^64 blocks of 16 words.
+    impl<const ROUNDS: usize> SeekableRNG for ChaCha<ROUNDS> {
+        fn seek(&mut self, position: u128) {
+            self.counter = (position / 16) as u64;
+            self.index = (position % 16) as usize;
+            self.refill();
+        }
+
+        fn position(&self) -> u128 {
+            (self.counter as u128 * 16 + self.index as u128) % (1 << 68)
+        }
+    }
+
+    /// The stream is the 64 bit nonce, one of 2^64 independent streams. Switching keeps the position in the keystream.
+    impl<const ROUNDS: usize> Streamed for ChaCha<ROUNDS> {
+        fn set_stream(&mut self, stream: u64) {
+            self.stream = stream;
+            self.refill();
+        }
+    }
+
+    impl<const ROUNDS: usize> ChaCha<ROUNDS> {
+        /// Start at block 0 of stream 0 for the 256 bit `key`, given as little endian words.
+        pub fn from_key(key: [u32; 8]) -> Self {
+            let mut rng = Self {
+                key,
+                counter: 0,
+                stream: 0,
+                buffer: [0; 16],
+                index: 0,
+            };
+            rng.refill();
+            rng
+        }
+
+        /// Compute the block `counter` of the keystream.
+        fn refill(&mut self) {
+            let mut input = [0u32; 16];
+            input[..4].copy_from_slice(&CONSTANTS);
+            input[4..12].copy_from_slice(&self.key);
+            input[12] = self.counter as u32;
+            input[13] = (self.counter >> 32) as u32;
+            input[14] = self.stream as u32;
+            input[15] = (self.stream >> 32) as u32;
+            let mut x = input;
+            for _ in 0..ROUNDS / 2 {
+                quarter_round(&mut x, 0, 4, 8, 12);
+                quarter_round(&mut x, 1, 5, 9, 13);
+                quarter_round(&mut x, 2, 6, 10, 14);
+                quarter_round(&mut x, 3, 7, 11, 15);
+                quarter_round(&mut x, 0, 5, 10, 15);
+                quarter_round(&mut x, 1, 6, 11, 12);
+                quarter_round(&mut x, 2, 7, 8, 13);
+                quarter_round(&mut x, 3, 4, 9, 14);
+            }
+            for (word, (mixed, original)) in self.buffer.iter_mut().zip(x.iter().zip(input)) {
+                *word = mixed.wrapping_add(original);
+            }
+        }
+    }
+
+    /// Golden ratio, the initial value of the ISAAC key schedule.
+    const ISAAC_GOLDEN: u32 = 0x9e3779b9;
+
+    /// Mix the eight words of the ISAAC key schedule.
+    fn isaac_mix(x: &mut [u32; 8]) {
+        let [a, b, c, d, e, f, g, h] = x;
+        *a ^= *b << 11;
+        *d = d.wrapping_add(*a);
+        *b = b.wrapping_add(*c);
+        *b ^= *c >> 2;
+        *e = e.wrapping_add(*b);
+        *c = c.wrapping_add(*d);
+        *c ^= *d << 8;
+        *f = f.wrapping_add(*c);
+        *d = d.wrapping_add(*e);
+        *d ^= *e >> 16;
+        *g = g.wrapping_add(*d);
+        *e = e.wrapping_add(*f);
+        *e ^= *f << 10;
+        *h = h.wrapping_add(*e);
+        *f = f.wrapping_add(*g);
+        *f ^= *g >> 4;
+        *a = a.wrapping_add(*f);
+        *g = g.wrapping_add(*h);
+        *g ^= *h << 8;
+        *b = b.wrapping_add(*g);
+        *h = h.wrapping_add(*a);
+        *h ^= *a >> 9;
+        *c = c.wrapping_add(*h);
+        *a = a.wrapping_add(*b);
+    }
+
+    /// ISAAC of Bob Jenkins (1996), an RC4 inspired generator indirecting through a table of 256 words.
+    /// Outputs the result blocks back to front, like the `rand` macro of the reference implementation.
+    /// The .next() method uses two outputs, the first output fills the lower half.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Isaac {
+        memory: [u32; 256],
+        results: [u32; 256],
+        a: u32,
+        b: u32,
+        c: u32,
+        /// Number of unused words in `results`.
+        count: usize,
+    }
+
+    impl RNG for Isaac {
+        /// Initialize with `seed` as the first two words of the seed array of `from_seed`, the rest is zero.
+        fn new(seed: u64) -> Self {
+            let mut words = [0u32; 256];
+            words[0] = seed as u32;
+            words[1] = (seed >> 32) as u32;
+            Self::from_seed(&words)
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            if self.count == 0 {
+                self.isaac();
+                self.count = 256;
+            }
+            self.count -= 1;
+            self.results[self.count]
+        }
+
+        fn next(&mut self) -> u64 {
+            let low: u64 = self.next_u32() as u64;
+            let high: u64 = self.next_u32() as u64;
+            (high << 32) | low
+        }
+
+        fn advance(&mut self, delta: u64) {
+            for _ in 0..delta {
+                let _ = self.next_u32();
+            }
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            *self = Self::new(seed);
+        }
+
+        fn native_bits(&self) -> u32 {
+            32
+        }
+
+        fn next_native(&mut self) -> u64 {
+            self.next_u32() as u64
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&(
+                self.memory,
+                self.results,
+                self.a,
+                self.b,
+                self.c,
+                self.count,
+            )))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            let (memory, results, a, b, c, count) = state::decode(bytes)?;
+            Ok(Self {
+                memory,
+                results,
+                a,
+                b,
+                c,
+                count: state::check_index(count, 256)?,
+            })
+        }
+    }
+
+    impl Isaac {
+        /// Initialize like `randinit` of the reference implementation with `flag` set, `seed` fills `randrsl`.
+        pub fn from_seed(seed: &[u32; 256]) -> Self {
+            let mut x = [ISAAC_GOLDEN; 8];
+            for _ in 0..4 {
+                isaac_mix(&mut x);
+            }
+            let mut memory = [0u32; 256];
+            // The second pass spreads every seed word over the whole table.
+            for pass in 0..2 {
+                let source = if pass == 0 { *seed } else { memory };
+                for (block, words) in memory.chunks_exact_mut(8).zip(source.chunks_exact(8)) {
+                    for (word, &seed_word) in x.iter_mut().zip(words) {
+                        *word = word.wrapping_add(seed_word);
+                    }
+                    isaac_mix(&mut x);
+                    block.copy_from_slice(&x);
+                }
+            }
+            let mut rng = Self {
+                memory,
+                results: [0; 256],
+                a: 0,
+                b: 0,
+                c: 0,
+                count: 256,
+            };
+            rng.isaac();
+            rng
+        }
+
+        /// Generate the next 256 results.
+        fn isaac(&mut self) {
+            self.c = self.c.wrapping_add(1);
+            self.b = self.b.wrapping_add(self.c);
+            for i in 0..256 {
+                let x = self.memory[i];
+                self.a ^= match i % 4 {
+                    0 => self.a << 13,
+                    1 => self.a >> 6,
+                    2 => self.a << 2,
+                    _ => self.a >> 16,
+                };
+                self.a = self.memory[(i + 128) % 256].wrapping_add(self.a);
+                let y = self.memory[(x >> 2) as usize % 256]
+                    .wrapping_add(self.a)
+                    .wrapping_add(self.b);
+                self.memory[i] = y;
+                self.b = self.memory[(y >> 10) as usize % 256].wrapping_add(x);
+                self.results[i] = self.b;
+            }
+        }
+    }
+
+    /// RC4 keystream of Rivest, one byte per step. Broken as a cipher, its biases make it a known positive for the byte tests:
+    /// the second byte after the key setup is zero twice as often as it should be (Mantin and Shamir 2001),
+    /// and pairs of consecutive bytes are biased at any position (Fluhrer and McGrew 2000), visible after about 2^30 bytes.
+    /// The .next() and .next_u32() methods concatenate bytes, the first byte fills the lowest bits.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Rc4 {
+        permutation: [u8; 256],
+        i: u8,
+        j: u8,
+    }
+
+    impl RNG for Rc4 {
+        /// Initialize with the eight little endian bytes of `seed` as the key, nothing is dropped.
+        fn new(seed: u64) -> Self {
+            Self::from_key(&seed.to_le_bytes()).unwrap()
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            u32::from_le_bytes(std::array::from_fn(|_| self.next_byte()))
+        }
+
+        fn next(&mut self) -> u64 {
+            u64::from_le_bytes(std::array::from_fn(|_| self.next_byte()))
+        }
+
+        fn advance(&mut self, delta: u64) {
+            for _ in 0..delta {
+                let _ = self.next_byte();
+            }
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            *self = Self::new(seed);
+        }
+
+        fn native_bits(&self) -> u32 {
+            8
+        }
+
+        fn next_native(&mut self) -> u64 {
+            self.next_byte() as u64
+        }
+
+        fn state_bytes(&self) -> Option<Vec<u8>> {
+            Some(state::encode(&(self.permutation, self.i, self.j)))
+        }
+
+        fn from_state_bytes(bytes: &[u8]) -> Result<Self, String> {
+            let (permutation, i, j) = state::decode(bytes)?;
+            Ok(Self { permutation, i, j })
+        }
+    }
+
+    impl Rc4 {
+        /// Run the key scheduling algorithm with a key of 1 to 256 bytes.
+        /// RC4-drop[n] is `from_key` followed by `advance(n)`.
+        pub fn from_key(key: &[u8]) -> Result<Self, String> {
+            if key.is_empty() || key.len() > 256 {
+                return Err(format!(
+                    "RC4 key length {} is outside of 1..=256.",
+                    key.len()
+                ));
+            }
+            let mut permutation: [u8; 256] = std::array::from_fn(|i| i as u8);
+            let mut j: u8 = 0;
+            for i in 0..256 {
+                j = j
+                    .wrapping_add(permutation[i])
+                    .wrapping_add(key[i % key.len()]);
+                permutation.swap(i, j as usize);
+            }
+            Ok(Self {
+                permutation,
+                i: 0,
+                j: 0,
+            })
+        }
+
+        /// Generate one keystream byte.
+        pub fn next_byte(&mut self) -> u8 {
+            self.i = self.i.wrapping_add(1);
+            self.j = self.j.wrapping_add(self.permutation[self.i as usize]);
+            self.permutation.swap(self.i as usize, self.j as usize);
+            let index =
+                self.permutation[self.i as usize].wrapping_add(self.permutation[self.j as usize]);
+            self.permutation[index as usize]
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn rc4_keystream() {
+            // Keystream for the key "Key", from the original test vectors.
+            let mut rng = Rc4::from_key(b"Key").unwrap();
+            let keystream: [u8; 10] = std::array::from_fn(|_| rng.next_byte());
+            assert_eq!(
+                keystream,
+                [0xeb, 0x9f, 0x77, 0x81, 0xb7, 0x34, 0xca, 0x72, 0xa7, 0x19]
+            );
+            let mut rng = Rc4::from_key(b"Key").unwrap();
+            assert_eq!(rng.next_u32(), 0x81779feb);
+            assert!(Rc4::from_key(&[]).is_err());
+            // Mantin and Shamir: the second byte is zero with probability 2/256 instead of 1/256.
+            let keys = 40000;
+            let zeros = (0..keys)
+                .filter(|&seed| {
+                    let mut rng = Rc4::new(seed);
+                    rng.next_byte();
+                    rng.next_byte() == 0
+                })
+                .count();
+            assert!(zeros > keys as usize * 3 / 2 / 256, "{} zeros", zeros);
+        }
+
+        #[test]
+        fn chacha20_block_vector() {
+            // Block function test vector of RFC 8439, 2.3.2: counter 1 and nonce 0x000000090000004a00000000.
+            let mut rng = ChaCha20::from_key(std::array::from_fn(|i| {
+                u32::from_le_bytes(std::array::from_fn(|j| (4 * i + j) as u8))
+            }));
+            rng.set_stream(0x4a000000);
+            rng.seek(16 * (1 | (0x09000000 << 32)));
+            let block: [u32; 16] = std::array::from_fn(|_| rng.next_u32());
+            assert_eq!(
+                block,
+                [
+                    0xe4e7f110, 0x15593bd1, 0x1fdd0f50, 0xc47120a3, 0xc7f4d1c7, 0x0368c033,
+                    0x9aaa2204, 0x4e6cd4c3, 0x466482d2, 0x09aa9f07, 0x05d7c214, 0xa2028bd9,
+                    0xd19c12b5, 0xb94e16de, 0xe883d0cb, 0x4e3c50a2
+                ]
+            );
+        }
+
+        #[test]
+        fn isaac_reference_outputs() {
+            // randvect.txt of the reference implementation, the second block of results for an all zero seed.
+            let mut rng = Isaac::from_seed(&[0; 256]);
+            rng.advance(256);
+            let mut block: [u32; 256] = std::array::from_fn(|_| rng.next_u32());
+            block.reverse();
+            assert_eq!(
+                block[..8],
+                [
+                    0xf650e4c8, 0xe448e96d, 0x98db2fb4, 0xf5fad54f, 0x433f1afb, 0xedec154a,
+                    0xd8370487, 0x46ca4f9a
+                ]
+            );
+        }
+
+        #[cfg(feature = "rand-compat")]
+        #[test]
+        fn chacha12_matches_std_rng() {
+            let seed: [u8; 32] = std::array::from_fn(|i| (i * 37 + 11) as u8);
+            let mut reference = <rand::rngs::StdRng as rand::SeedableRng>::from_seed(seed);
+            let mut rng = ChaCha12::from_key(std::array::from_fn(|i| {
+                u32::from_le_bytes(seed[4 * i..4 * i + 4].try_into().unwrap())
+            }));
+            for _ in 0..40 {
+                assert_eq!(rng.next(), rand::RngCore::next_u64(&mut reference));
+            }
+        }
+
+        #[test]
+        fn chacha_advance_and_seek() {
+            let mut stepped = ChaCha8::new(5);
+            for _ in 0..37 {
+                stepped.next_u32();
+            }
+            let mut advanced = ChaCha8::new(5);
+            advanced.advance(30);
+            advanced.advance(7);
+            assert_eq!(advanced, stepped);
+            let mut seeked = ChaCha8::new(5);
+            seeked.seek(32);
+            seeked.advance(5);
+            assert_eq!(seeked, stepped);
+            assert_eq!(stepped.position(), 37);
+            let mut wrapped = ChaCha8::new(5);
+            wrapped.advance(u64::MAX);
+            wrapped.advance(1);
+            let mut expected = ChaCha8::new(5);
+            expected.seek(1 << 64);
+            assert_eq!(wrapped, expected);
+            assert_eq!(wrapped.position(), 1 << 64);
+        }
+    }
+}
+
+/// Deterministic random bit generators of NIST SP 800-90A with SHA-256 and AES-128.
+/// The mechanisms follow the instantiate, reseed and generate functions of the specification,
+/// `DrbgRng` maps them onto the RNG interface.
+#[cfg(feature = "crypto-rngs")]
+pub mod drbg {
+    use super::{splitmix, spn::Aes128, RNG};
+
+    /// Largest number of generate requests between reseeds, table 2 of SP 800-90A.
+    pub const MAX_RESEED_INTERVAL: u64 = 1 << 48;
+    /// Largest generate request, 2^19 bits.
+    pub const MAX_REQUEST_BYTES: usize = 1 << 16;
+
+    /// Round constants of SHA-256, the fractional parts of the cube roots of the first 64 primes.
+    const K: [u32; 64] = [
+        0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4,
+        0xab1c5ed5, 0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe,
+        0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f,
+        0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7,
+        0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc,
+        0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b,
+        0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116,
+        0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+        0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+        0xc67178f2,
+    ];
+
+    /// SHA-256 of FIPS 180-4.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Sha256 {
+        state: [u32; 8],
+        block: [u8; 64],
+        /// Bytes of `block` in use.
+        filled: usize,
+        /// Total message length in bytes.
+        length: u64,
+    }
+
+    impl Default for Sha256 {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl Sha256 {
+        pub fn new() -> Self {
+            Self {
+                state: [
+                    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c,
+                    0x1f83d9ab, 0x5be0cd19,
+                ],
+                block: [0; 64],
+                filled: 0,
+                length: 0,
+            }
+        }
+
+        pub fn update(&mut self, mut data: &[u8]) {
+            self.length = self.length.wrapping_add(data.len() as u64);
+            while !data.is_empty() {
+                let take = (64 - self.filled).min(data.len());
+                self.block[self.filled..self.filled + take].copy_from_slice(&data[..take]);
+                self.filled += take;
+                data = &data[take..];
+                if self.filled == 64 {
+                    compress(&mut self.state, &self.block);
+                    self.filled = 0;
+                }
+            }
+        }
+
+        pub fn finalize(mut self) -> [u8; 32] {
+            let bits = self.length.wrapping_mul(8);
+            self.update(&[0x80]);
+            while self.filled != 56 {
+                self.update(&[0]);
+            }
+            self.update(&bits.to_be_bytes());
+            let mut digest = [0; 32];
+            for (bytes, word) in digest.chunks_exact_mut(4).zip(self.state) {
+                bytes.copy_from_slice(&word.to_be_bytes());
+            }
+            digest
+        }
+    }
+
+    /// The SHA-256 compression function.
+    fn compress(state: &mut [u32; 8], block: &[u8; 64]) {
+        let mut w = [0u32; 64];
+        for (word, bytes) in w.iter_mut().zip(block.chunks_exact(4)) {
+            *word = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+        for i in 16..64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16]
+                .wrapping_add(s0)
+                .wrapping_add(w[i - 7])
+                .wrapping_add(s1);
+        }
+        let mut s = *state;
+        for i in 0..64 {
+            let s1 = s[4].rotate_right(6) ^ s[4].rotate_right(11) ^ s[4].rotate_right(25);
+            let choice = (s[4] & s[5]) ^ (!s[4] & s[6]);
+            let t1 = s[7]
+                .wrapping_add(s1)
+                .wrapping_add(choice)
+                .wrapping_add(K[i])
+                .wrapping_add(w[i]);
+            let s0 = s[0].rotate_right(2) ^ s[0].rotate_right(13) ^ s[0].rotate_right(22);
+            let majority = (s[0] & s[1]) ^ (s[0] & s[2]) ^ (s[1] & s[2]);
+            let t2 = s0.wrapping_add(majority);
+            s = [
+                t1.wrapping_add(t2),
+                s[0],
+                s[1],
+                s[2],
+                s[3].wrapping_add(t1),
+                s[4],
+                s[5],
+                s[6],
+            ];
+        }
+        for (word, new) in state.iter_mut().zip(s) {
+            *word = word.wrapping_add(new);
+        }
+    }
+
+    /// SHA-256 of the concatenation of `parts`.
+    fn hash(parts: &[&[u8]]) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        for part in parts {
+            hasher.update(part);
+        }
+        hasher.finalize()
+    }
+
+    /// HMAC-SHA256 of RFC 2104 over the concatenation of `parts`.
+    fn hmac(key: &[u8], parts: &[&[u8]]) -> [u8; 32] {
+        let mut padded = [0u8; 64];
+        if key.len() > 64 {
+            padded[..32].copy_from_slice(&hash(&[key]));
+        } else {
+            padded[..key.len()].copy_from_slice(key);
+        }
+        let mut inner = Sha256::new();
+        inner.update(&padded.map(|byte| byte ^ 0x36));
+        for part in parts {
+            inner.update(part);
+        }
+        hash(&[&padded.map(|byte| byte ^ 0x5c), &inner.finalize()])
+    }
+
+    pub fn sha256(data: &[u8]) -> [u8; 32] {
+        hash(&[data])
+    }
+
+    pub fn hmac_sha256(key: &[u8], data: &[u8]) -> [u8; 32] {
+        hmac(key, &[data])
+    }
+
+    /// A DRBG mechanism of SP 800-90A, without prediction resistance.
+    pub trait Drbg {
+        /// Security strength of the mechanism, the minimum entropy input.
+        const SECURITY_STRENGTH_BYTES: usize;
+        /// Instantiate from at least `SECURITY_STRENGTH_BYTES` of entropy input and half as much nonce.
+        fn instantiate(
+            entropy: &[u8],
+            nonce: &[u8],
+            personalization: &[u8],
+        ) -> Result<Self, String>
+        where
+            Self: Sized;
+        /// Mix fresh entropy input and optional additional input into the state, resets the reseed counter.
+        fn reseed_with_entropy(&mut self, entropy: &[u8], additional: &[u8]) -> Result<(), String>;
+        /// Fill `output` with one generate request. Fails once `reseed_interval` requests were served since the last reseed.
+        fn generate(&mut self, output: &mut [u8], additional: &[u8]) -> Result<(), String>;
+        /// Limit the number of generate requests between reseeds, at most `MAX_RESEED_INTERVAL`.
+        fn set_reseed_interval(&mut self, interval: u64);
+    }
+
+    fn check_entropy<D: Drbg>(entropy: &[u8]) -> Result<(), String> {
+        if entropy.len() < D::SECURITY_STRENGTH_BYTES {
+            return Err(format!(
+                "Entropy input must be at least {} bytes, got {}.",
+                D::SECURITY_STRENGTH_BYTES,
+                entropy.len()
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_request(
+        output: &[u8],
+        reseed_counter: u64,
+        reseed_interval: u64,
+    ) -> Result<(), String> {
+        if output.len() > MAX_REQUEST_BYTES {
+            return Err(format!(
+                "Generate requests are limited to {} bytes, got {}.",
+                MAX_REQUEST_BYTES,
+                output.len()
+            ));
+        }
+        if reseed_counter > reseed_interval {
+            return Err("Reseed required, the reseed interval is exhausted.".to_string());
+        }
+        Ok(())
+    }
+
+    /// Length of V and C of Hash_DRBG with SHA-256, seedlen is 440 bits.
+    const SEED_BYTES: usize = 55;
+
+    /// Add the big endian `value` to `target` modulo 2^440.
+    fn add_into(target: &mut [u8; SEED_BYTES], value: &[u8]) {
+        let mut carry = 0u16;
+        let mut value = value.iter().rev();
+        for byte in target.iter_mut().rev() {
+            let sum = *byte as u16 + *value.next().unwrap_or(&0) as u16 + carry;
+            *byte = sum as u8;
+            carry = sum >> 8;
+        }
+    }
+
+    /// Hash_df, derives 440 bits from the concatenation of `parts`.
+    fn hash_df(parts: &[&[u8]]) -> [u8; SEED_BYTES] {
+        let bits = (SEED_BYTES as u32 * 8).to_be_bytes();
+        let mut output = [0; SEED_BYTES];
+        for (counter, chunk) in output.chunks_mut(32).enumerate() {
+            let mut hasher = Sha256::new();
+            hasher.update(&[counter as u8 + 1]);
+            hasher.update(&bits);
+            for part in parts {
+                hasher.update(part);
+            }
+            chunk.copy_from_slice(&hasher.finalize()[..chunk.len()]);
+        }
+        output
+    }
+
+    /// Hash_DRBG with SHA-256, section 10.1.1 of SP 800-90A.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct HashDrbg {
+        v: [u8; SEED_BYTES],
+        c: [u8; SEED_BYTES],
+        reseed_counter: u64,
+        reseed_interval: u64,
+    }
+
+    impl Drbg for HashDrbg {
+        const SECURITY_STRENGTH_BYTES: usize = 32;
+
+        fn instantiate(
+            entropy: &[u8],
+            nonce: &[u8],
+            personalization: &[u8],
+        ) -> Result<Self, String> {
+            check_entropy::<Self>(entropy)?;
+            let v = hash_df(&[entropy, nonce, personalization]);
+            Ok(Self {
+                v,
+                c: hash_df(&[&[0], &v]),
+                reseed_counter: 1,
+                reseed_interval: MAX_RESEED_INTERVAL,
+            })
+        }
+
+        fn reseed_with_entropy(&mut self, entropy: &[u8], additional: &[u8]) -> Result<(), String> {
+            check_entropy::<Self>(entropy)?;
+            self.v = hash_df(&[&[1], &self.v, entropy, additional]);
+            self.c = hash_df(&[&[0], &self.v]);
+            self.reseed_counter = 1;
+            Ok(())
+        }
+
+        fn generate(&mut self, output: &mut [u8], additional: &[u8]) -> Result<(), String> {
+            check_request(output, self.reseed_counter, self.reseed_interval)?;
+            if !additional.is_empty() {
+                let w = hash(&[&[2], &self.v, additional]);
+                add_into(&mut self.v, &w);
+            }
+            let mut data = self.v;
+            for chunk in output.chunks_mut(32) {
+                chunk.copy_from_slice(&hash(&[&data])[..chunk.len()]);
+                add_into(&mut data, &[1]);
+            }
+            let h = hash(&[&[3], &self.v]);
+            add_into(&mut self.v, &h);
+            add_into(&mut self.v, &self.c);
+            add_into(&mut self.v, &self.reseed_counter.to_be_bytes());
+            self.reseed_counter += 1;
+            Ok(())
+        }
+
+        fn set_reseed_interval(&mut self, interval: u64) {
+            self.reseed_interval = interval.min(MAX_RESEED_INTERVAL);
+        }
+    }
+
+    /// HMAC_DRBG with HMAC-SHA256, section 10.1.2 of SP 800-90A.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct HmacDrbg {
+        key: [u8; 32],
+        v: [u8; 32],
+        reseed_counter: u64,
+        reseed_interval: u64,
+    }
+
+    impl HmacDrbg {
+        /// HMAC_DRBG_Update with the concatenation of `provided` as the provided data.
+        fn update(&mut self, provided: &[&[u8]]) {
+            let mut parts: Vec<&[u8]> = vec![&self.v, &[0]];
+            parts.extend_from_slice(provided);
+            self.key = hmac(&self.key, &parts);
+            self.v = hmac(&self.key, &[&self.v]);
+            if provided.iter().all(|part| part.is_empty()) {
+                return;
+            }
+            let mut parts: Vec<&[u8]> = vec![&self.v, &[1]];
+            parts.extend_from_slice(provided);
+            self.key = hmac(&self.key, &parts);
+            self.v = hmac(&self.key, &[&self.v]);
+        }
+    }
+
+    impl Drbg for HmacDrbg {
+        const SECURITY_STRENGTH_BYTES: usize = 32;
+
+        fn instantiate(
+            entropy: &[u8],
+            nonce: &[u8],
+            personalization: &[u8],
+        ) -> Result<Self, String> {
+            check_entropy::<Self>(entropy)?;
+            let mut drbg = Self {
+                key: [0; 32],
+                v: [1; 32],
+                reseed_counter: 1,
+                reseed_interval: MAX_RESEED_INTERVAL,
+            };
+            drbg.update(&[entropy, nonce, personalization]);
+            Ok(drbg)
+        }
+
+        fn reseed_with_entropy(&mut self, entropy: &[u8], additional: &[u8]) -> Result<(), String> {
+            check_entropy::<Self>(entropy)?;
+            self.update(&[entropy, additional]);
+            self.reseed_counter = 1;
+            Ok(())
+        }
+
+        fn generate(&mut self, output: &mut [u8], additional: &[u8]) -> Result<(), String> {
+            check_request(output, self.reseed_counter, self.reseed_interval)?;
+            if !additional.is_empty() {
+                self.update(&[additional]);
+            }
+            for chunk in output.chunks_mut(32) {
+                self.v = hmac(&self.key, &[&self.v]);
+                chunk.copy_from_slice(&self.v[..chunk.len()]);
+            }
+            self.update(&[additional]);
+            self.reseed_counter += 1;
+            Ok(())
+        }
+
+        fn set_reseed_interval(&mut self, interval: u64) {
+            self.reseed_interval = interval.min(MAX_RESEED_INTERVAL);
+        }
+    }
+
+    /// Key length, block length and seedlen of CTR_DRBG with AES-128.
+    const AES_KEY_BYTES: usize = 16;
+    const AES_BLOCK_BYTES: usize = 16;
+    const CTR_SEED_BYTES: usize = AES_KEY_BYTES + AES_BLOCK_BYTES;
+
+    /// BCC, the CBC-MAC of `blocks` under `aes`.
+    fn bcc(aes: &Aes128, blocks: &[u8]) -> [u8; AES_BLOCK_BYTES] {
+        blocks
+            .chunks_exact(AES_BLOCK_BYTES)
+            .fold([0; AES_BLOCK_BYTES], |chaining, block| {
+                aes.encrypt(std::array::from_fn(|i| chaining[i] ^ block[i]))
+            })
+    }
+
+    /// Block_Cipher_df, derives seedlen bits from the concatenation of `parts`.
+    fn block_cipher_df(parts: &[&[u8]]) -> [u8; CTR_SEED_BYTES] {
+        let length: usize = parts.iter().map(|part| part.len()).sum();
+        // IV || L || N || input || 0x80, padded to whole blocks. The IV is filled in per output block.
+        let mut s = vec![0; AES_BLOCK_BYTES];
+        s.extend_from_slice(&(length as u32).to_be_bytes());
+        s.extend_from_slice(&(CTR_SEED_BYTES as u32).to_be_bytes());
+        for part in parts {
+            s.extend_from_slice(part);
+        }
+        s.push(0x80);
+        s.resize(s.len().next_multiple_of(AES_BLOCK_BYTES), 0);
+        let aes = Aes128::new(std::array::from_fn(|i| i as u8));
+        let mut temp = [0; CTR_SEED_BYTES];
+        for (i, chunk) in temp.chunks_exact_mut(AES_BLOCK_BYTES).enumerate() {
+            s[..4].copy_from_slice(&(i as u32).to_be_bytes());
+            chunk.copy_from_slice(&bcc(&aes, &s));
+        }
+        let aes = Aes128::new(std::array::from_fn(|i| temp[i]));
+        let mut x: [u8; AES_BLOCK_BYTES] = std::array::from_fn(|i| temp[AES_KEY_BYTES + i]);
+        for chunk in temp.chunks_exact_mut(AES_BLOCK_BYTES) {
+            x = aes.encrypt(x);
+            chunk.copy_from_slice(&x);
+        }
+        temp
+    }
+
+    /// CTR_DRBG with AES-128 and the derivation function, section 10.2.1 of SP 800-90A.
+    /// The counter is the whole block.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct CtrDrbg {
+        aes: Aes128,
+        v: u128,
+        reseed_counter: u64,
+        reseed_interval: u64,
+    }
+
+    impl CtrDrbg {
+        /// Next keystream block, increments V first.
+        fn block(&mut self) -> [u8; AES_BLOCK_BYTES] {
+            self.v = self.v.wrapping_add(1);
+            self.aes.encrypt(self.v.to_be_bytes())
+        }
+
+        /// CTR_DRBG_Update, the next key and V are the keystream xor `provided`.
+        fn update(&mut self, provided: &[u8; CTR_SEED_BYTES]) {
+            let mut temp = [0; CTR_SEED_BYTES];
+            for chunk in temp.chunks_exact_mut(AES_BLOCK_BYTES) {
+                chunk.copy_from_slice(&self.block());
+            }
+            for (byte, provided) in temp.iter_mut().zip(provided) {
+                *byte ^= provided;
+            }
+            self.aes = Aes128::new(std::array::from_fn(|i| temp[i]));
+            self.v = u128::from_be_bytes(std::array::from_fn(|i| temp[AES_KEY_BYTES + i]));
+        }
+    }
+
+    impl Drbg for CtrDrbg {
+        const SECURITY_STRENGTH_BYTES: usize = 16;
+
+        fn instantiate(
+            entropy: &[u8],
+            nonce: &[u8],
+            personalization: &[u8],
+        ) -> Result<Self, String> {
+            check_entropy::<Self>(entropy)?;
+            let mut drbg = Self {
+                aes: Aes128::new([0; AES_KEY_BYTES]),
+                v: 0,
+                reseed_counter: 1,
+                reseed_interval: MAX_RESEED_INTERVAL,
+            };
+            drbg.update(&block_cipher_df(&[entropy, nonce, personalization]));
+            Ok(drbg)
+        }
+
+        fn reseed_with_entropy(&mut self, entropy: &[u8], additional: &[u8]) -> Result<(), String> {
+            check_entropy::<Self>(entropy)?;
+            self.update(&block_cipher_df(&[entropy, additional]));
+            self.reseed_counter = 1;
+            Ok(())
+        }
+
+        fn generate(&mut self, output: &mut [u8], additional: &[u8]) -> Result<(), String> {
+            check_request(output, self.reseed_counter, self.reseed_interval)?;
+            let additional = if additional.is_empty() {
+                [0; CTR_SEED_BYTES]
+            } else {
+                let additional = block_cipher_df(&[additional]);
+                self.update(&additional);
+                additional
+            };
+            for chunk in output.chunks_mut(AES_BLOCK_BYTES) {
+                chunk.copy_from_slice(&self.block()[..chunk.len()]);
+            }
+            self.update(&additional);
+            self.reseed_counter += 1;
+            Ok(())
+        }
+
+        fn set_reseed_interval(&mut self, interval: u64) {
+            self.reseed_interval = interval.min(MAX_RESEED_INTERVAL);
+        }
+    }
+
+    /// Bytes of one generate request of `DrbgRng`.
+    const REQUEST_BYTES: usize = 128;
+
+    /// Serves the outputs of `REQUEST_BYTES` byte generate requests as little endian words.
+    /// The .next() method uses two outputs, the first output fills the lower half.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct DrbgRng<D: Drbg> {
+        drbg: D,
+        buffer: [u8; REQUEST_BYTES],
+        /// Byte offset of the next word of `buffer`, `REQUEST_BYTES` once the request is used up.
+        index: usize,
+    }
+
+    /// Hash_DRBG on the RNG interface.
+    pub type HashDrbgRng = DrbgRng<HashDrbg>;
+    /// HMAC_DRBG on the RNG interface.
+    pub type HmacDrbgRng = DrbgRng<HmacDrbg>;
+    /// CTR_DRBG on the RNG interface.
+    pub type CtrDrbgRng = DrbgRng<CtrDrbg>;
+
+    impl<D: Drbg> RNG for DrbgRng<D> {
+        /// Instantiate with 32 bytes of entropy input and a 16 byte nonce from SplitMix64 outputs of `seed`.
+        fn new(seed: u64) -> Self {
+            let words: [u64; 6] = splitmix::expand_seed(seed);
+            let bytes: Vec<u8> = words.iter().flat_map(|word| word.to_le_bytes()).collect();
+            let drbg = D::instantiate(&bytes[..32], &bytes[32..], &[])
+                .expect("entropy input has the security strength");
+            Self::from_drbg(drbg)
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            if self.index >= REQUEST_BYTES {
+                self.refill();
+            }
+            let bytes = &self.buffer[self.index..self.index + 4];
+            self.index += 4;
+            u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+        }
+
+        fn next(&mut self) -> u64 {
+            let low: u64 = self.next_u32() as u64;
+            let high: u64 = self.next_u32() as u64;
+            (high << 32) | low
+        }
+
+        fn advance(&mut self, delta: u64) {
+            for _ in 0..delta {
+                let _ = self.next_u32();
+            }
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            *self = Self::new(seed);
+        }
+
+        fn native_bits(&self) -> u32 {
+            32
+        }
+
+        fn next_native(&mut self) -> u64 {
+            self.next_u32() as u64
+        }
+    }
+
+    impl<D: Drbg> DrbgRng<D> {
+        /// Serve outputs of an instantiated DRBG, starting with a new generate request.
+        pub fn from_drbg(drbg: D) -> Self {
+            Self {
+                drbg,
+                buffer: [0; REQUEST_BYTES],
+                index: REQUEST_BYTES,
+            }
+        }
+
+        /// Reseed the DRBG, the rest of the current request is discarded.
+        pub fn reseed_with_entropy(
+            &mut self,
+            entropy: &[u8],
+            additional: &[u8],
+        ) -> Result<(), String> {
+            self.index = REQUEST_BYTES;
+            self.drbg.reseed_with_entropy(entropy, additional)
+        }
+
+        pub fn drbg(&self) -> &D {
+            &self.drbg
+        }
+
+        /// Panics if the reseed interval is exhausted, which takes 2^48 requests unless it was lowered.
+        fn refill(&mut self) {
+            self.drbg
+                .generate(&mut self.buffer, &[])
+                .expect("reseed interval exhausted, reseed with reseed_with_entropy");
+            self.index = 0;
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn from_hex(text: &str) -> Vec<u8> {
+            (0..text.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(&text[i..i + 2], 16).unwrap())
+                .collect()
+        }
+
+        #[test]
+        fn sha256_vectors() {
+            // FIPS 180-4 examples and RFC 4231 test cases 2 and 6.
+            assert_eq!(
+                sha256(b"").to_vec(),
+                from_hex("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")
+            );
+            assert_eq!(
+                sha256(b"abc").to_vec(),
+                from_hex("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+            );
+            assert_eq!(
+                sha256(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq").to_vec(),
+                from_hex("248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1")
+            );
+            assert_eq!(
+                hmac_sha256(b"Jefe", b"what do ya want for nothing?").to_vec(),
+                from_hex("5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843")
+            );
+            assert_eq!(
+                hmac_sha256(
+                    &[0xaa; 131],
+                    b"Test Using Larger Than Block-Size Key - Hash Key First"
+                )
+                .to_vec(),
+                from_hex("60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54")
+            );
+        }
+
+        /// First CAVP test of a mechanism without prediction resistance or reseed:
+        /// instantiate, generate `bytes` twice and return the second output.
+        fn cavp_returned_bits<D: Drbg>(entropy: &str, nonce: &str, bytes: usize) -> Vec<u8> {
+            let mut drbg = D::instantiate(&from_hex(entropy), &from_hex(nonce), &[]).unwrap();
+            let mut output = vec![0; bytes];
+            drbg.generate(&mut output, &[]).unwrap();
+            drbg.generate(&mut output, &[]).unwrap();
+            output
+        }
+
+        /// Outputs with personalization, additional input and a reseed, from a Python implementation of SP 800-90A.
+        fn check_additional_and_reseed<D: Drbg>(first: &str, second: &str) {
+            let entropy: Vec<u8> = (0..32).collect();
+            let nonce: Vec<u8> = (32..48).collect();
+            let mut drbg = D::instantiate(&entropy, &nonce, b"personal").unwrap();
+            let mut output = [0; 40];
+            drbg.generate(&mut output, b"additional").unwrap();
+            assert_eq!(output.to_vec(), from_hex(first));
+            let entropy: Vec<u8> = (100..140).collect();
+            drbg.reseed_with_entropy(&entropy, b"more").unwrap();
+            let mut output = [0; 16];
+            drbg.generate(&mut output, &[]).unwrap();
+            assert_eq!(output.to_vec(), from_hex(second));
+        }
+
+        #[test]
+        fn hash_drbg_vectors() {
+            assert_eq!(
+                cavp_returned_bits::<HashDrbg>(
+                    "a65ad0f345db4e0effe875c3a2e71f42c7129d620ff5c119a9ef55f05185e0fb",
+                    "8581f9317517276e06e9607ddbcbcc2e",
+                    128
+                ),
+                from_hex(
+                    "d3e160c35b99f340b2628264d1751060e0045da383ff57a57d73a673d2b8d80d\
+                     aaf6a6c35a91bb4579d73fd0c8fed111b0391306828adfed528f018121b3febd\
+                     c343e797b87dbb63db1333ded9d1ece177cfa6b71fe8ab1da46624ed6415e51c\
+                     cde2c7ca86e283990eeaeb91120415528b2295910281b02dd431f4c9f70427df"
+                )
+            );
+            check_additional_and_reseed::<HashDrbg>(
+                "d249133c44fb0918678678b8f1a4401abf41372f8c57e9a5afd3dd175860e271589c48f75609e9e4",
+                "42b11a9c42ee56fea545c4cd14f4efeb",
+            );
+        }
+
+        #[test]
+        fn hmac_drbg_vectors() {
+            assert_eq!(
+                cavp_returned_bits::<HmacDrbg>(
+                    "ca851911349384bffe89de1cbdc46e6831e44d34a4fb935ee285dd14b71a7488",
+                    "659ba96c601dc69fc902940805ec0ca8",
+                    128
+                ),
+                from_hex(
+                    "e528e9abf2dece54d47c7e75e5fe302149f817ea9fb4bee6f4199697d04d5b89\
+                     d54fbb978a15b5c443c9ec21036d2460b6f73ebad0dc2aba6e624abf07745bc1\
+                     07694bb7547bb0995f70de25d6b29e2d3011bb19d27676c07162c8b5ccde0668\
+                     961df86803482cb37ed6d5c0bb8d50cf1f50d476aa0458bdaba806f48be9dcb8"
+                )
+            );
+            check_additional_and_reseed::<HmacDrbg>(
+                "e6e6bf43ce19952c994e33e12513dab8b3af97966a896c09a3f4eee67fe7a8ab111ea87d6fd2c892",
+                "1042f87ef124f95c0902b3b117701697",
+            );
+        }
+
+        #[test]
+        fn ctr_drbg_vectors() {
+            assert_eq!(
+                cavp_returned_bits::<CtrDrbg>(
+                    "890eb067acf7382eff80b0c73bc872c6",
+                    "aad471ef3ef1d203",
+                    64
+                ),
+                from_hex(
+                    "a5514ed7095f64f3d0d3a5760394ab42062f373a25072a6ea6bcfd8489e94af6\
+                     cf18659fea22ed1ca0a9e33f718b115ee536b12809c31b72b08ddd8be1910fa3"
+                )
+            );
+            check_additional_and_reseed::<CtrDrbg>(
+                "afb42fbab4db30e0fffbed36419ef4f2f2ec0ce15081bd49c4291538c97a7fd21eda000888a8d7a1",
+                "f9295a2f0aad72d0e8c04319d1076be1",
+            );
+        }
+
+        #[test]
+        fn drbg_limits() {
+            assert!(HmacDrbg::instantiate(&[0; 31], &[0; 16], &[]).is_err());
+            assert!(CtrDrbg::instantiate(&[0; 15], &[0; 8], &[]).is_err());
+            assert!(CtrDrbg::instantiate(&[0; 16], &[0; 8], &[]).is_ok());
+            let mut drbg = HashDrbg::instantiate(&[0; 32], &[0; 16], &[]).unwrap();
+            assert!(drbg
+                .generate(&mut vec![0; MAX_REQUEST_BYTES + 1], &[])
+                .is_err());
+            drbg.set_reseed_interval(2);
+            drbg.generate(&mut [0; 4], &[]).unwrap();
+            drbg.generate(&mut [0; 4], &[]).unwrap();
+            assert!(drbg.generate(&mut [0; 4], &[]).is_err());
+            drbg.reseed_with_entropy(&[1; 32], &[]).unwrap();
+            drbg.generate(&mut [0; 4], &[]).unwrap();
+            // The RNG interface serves the bytes of generate requests as little endian words.
+            let mut rng = HmacDrbgRng::new(12345);
+            let mut drbg = rng.drbg().clone();
+            let mut output = [0; 128];
+            drbg.generate(&mut output, &[]).unwrap();
+            assert_eq!(
+                rng.next(),
+                u64::from_le_bytes(output[..8].try_into().unwrap())
+            );
+        }
+    }
+}
+
+/// Hardware generators of x86 CPUs, backed by the RDRAND and RDSEED instructions.
+/// Availability is detected at runtime, the seed is ignored and the output is not reproducible.
+pub mod hardware {
+    use super::RNG;
+
+    /// Output of the RDRAND instruction, a DRBG in the CPU that is reseeded from its entropy source.
+    /// Panics on output if the CPU does not support RDRAND, check with `RNG::is_available`.
+    pub type Rdrand = Hardware<false>;
+    /// Output of the RDSEED instruction, conditioned entropy straight from the entropy source. Slower than RDRAND.
+    /// Panics on output if the CPU does not support RDSEED, check with `RNG::is_available`.
+    pub type Rdseed = Hardware<true>;
+
+    /// The RDSEED instruction if `SEED` is set, RDRAND otherwise.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Hardware<const SEED: bool>;
+
+    /// Attempts before RDRAND is considered broken, the retry count recommended by Intel.
+    const RDRAND_ATTEMPTS: usize = 10;
+    /// Attempts for RDSEED, which fails whenever the entropy source is drained.
+    const RDSEED_ATTEMPTS: usize = 1000;
+
+    impl<const SEED: bool> Hardware<SEED> {
+        fn name() -> &'static str {
+            if SEED {
+                "RDSEED"
+            } else {
+                "RDRAND"
+            }
+        }
+
+        /// Fetch 64 bits, retrying while the instruction reports failure.
+        pub fn try_next(&mut self) -> Result<u64, String> {
+            if !self.is_available() {
+                return Err(format!("{} is not available on this CPU.", Self::name()));
+            }
+            let attempts = if SEED {
+                RDSEED_ATTEMPTS
+            } else {
+                RDRAND_ATTEMPTS
+            };
+            for _ in 0..attempts {
+                // Safety: the instruction is available.
+                if let Some(value) = unsafe { instructions::step(SEED) } {
+                    return Ok(value);
+                }
+                std::hint::spin_loop();
+            }
+            Err(format!(
+                "{} failed {} times in a row.",
+                Self::name(),
+                attempts
+            ))
+        }
+    }
+
+    impl<const SEED: bool> RNG for Hardware<SEED> {
+        /// The seed is ignored.
+        fn new(_seed: u64) -> Self {
+            Self
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.next() as u32
+        }
+
+        fn next(&mut self) -> u64 {
+            self.try_next().unwrap_or_else(|error| panic!("{}", error))
+        }
+
+        fn advance(&mut self, delta: u64) {
+            for _ in 0..delta {
+                let _ = self.next();
+            }
+        }
+
+        fn reseed(&mut self, _seed: u64) {}
+
+        fn is_available(&self) -> bool {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            {
+                if SEED {
+                    is_x86_feature_detected!("rdseed")
+                } else {
+                    is_x86_feature_detected!("rdrand")
+                }
+            }
+            #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+            {
+                false
+            }
+        }
+
+        fn is_deterministic(&self) -> bool {
+            false
+        }
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    mod instructions {
+        use std::arch::x86_64::{_rdrand64_step, _rdseed64_step};
+
+        #[target_feature(enable = "rdrand")]
+        unsafe fn rdrand() -> Option<u64> {
+            let mut value = 0;
+            (_rdrand64_step(&mut value) == 1).then_some(value)
+        }
+
+        #[target_feature(enable = "rdseed")]
+        unsafe fn rdseed() -> Option<u64> {
+            let mut value = 0;
+            (_rdseed64_step(&mut value) == 1).then_some(value)
+        }
+
+        /// One attempt of RDSEED or RDRAND, None if the instruction reports failure.
+        /// The instruction must be available.
+        pub unsafe fn step(seed: bool) -> Option<u64> {
+            if seed {
+                rdseed()
+            } else {
+                rdrand()
+            }
+        }
+    }
+
+    #[cfg(target_arch = "x86")]
+    mod instructions {
+        use std::arch::x86::{_rdrand32_step, _rdseed32_step};
+
+        #[target_feature(enable = "rdrand")]
+        unsafe fn rdrand() -> Option<u32> {
+            let mut value = 0;
+            (_rdrand32_step(&mut value) == 1).then_some(value)
+        }
+
+        #[target_feature(enable = "rdseed")]
+        unsafe fn rdseed() -> Option<u32> {
+            let mut value = 0;
+            (_rdseed32_step(&mut value) == 1).then_some(value)
+        }
+
+        /// One attempt of RDSEED or RDRAND for each half, None if the instruction reports failure.
+        /// The instruction must be available.
+        pub unsafe fn step(seed: bool) -> Option<u64> {
+            let step = || if seed { rdseed() } else { rdrand() };
+            Some((step()? as u64) << 32 | step()? as u64)
+        }
+    }
+
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+    mod instructions {
+        /// Never called, `is_available` is false.
+        pub unsafe fn step(_seed: bool) -> Option<u64> {
+            None
+        }
+    }
+}
+
+pub mod testgens {
+    use super::RNG;
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct OnlyOne {}
+    impl RNG for OnlyOne {
+        fn new(_seed: u64) -> Self {
+            OnlyOne {}
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            u32::MAX
+        }
+
+        fn next(&mut self) -> u64 {
+            u64::MAX
+        }
+
+        fn advance(&mut self, _delta: u64) {}
+
+        fn reseed(&mut self, _seed: u64) {}
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct OnlyZero {}
+    impl RNG for OnlyZero {
+        fn new(_seed: u64) -> Self {
+            OnlyZero {}
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            0
+        }
+
+        fn next(&mut self) -> u64 {
+            0
+        }
+
+        fn advance(&mut self, _delta: u64) {}
+
+        fn reseed(&mut self, _seed: u64) {}
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct AlternatingBlocks {
+        state: u64,
+    }
+    impl RNG for AlternatingBlocks {
+        fn new(_seed: u64) -> Self {
+            AlternatingBlocks { state: 0 }
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.next() as u32
+        }
+
+        fn next(&mut self) -> u64 {
+            self.advance(1);
+            self.state
+        }
+
+        fn advance(&mut self, delta: u64) {
+            if delta & 1 == 1 {
+                self.state = !self.state;
+            }
+        }
+
+        fn reseed(&mut self, _seed: u64) {}
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct AlternatingBytes {}
+    impl RNG for AlternatingBytes {
+        fn new(_seed: u64) -> Self {
+            AlternatingBytes {}
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            0xff00ff00
+        }
+
+        fn next(&mut self) -> u64 {
+            0xff00ff00ff00ff00
+        }
+
+        fn advance(&mut self, _delta: u64) {}
+
+        fn reseed(&mut self, _seed: u64) {}
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct AlternatingBits {}
+    impl RNG for AlternatingBits {
+        fn new(_seed: u64) -> Self {
+            AlternatingBits {}
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            0x55555555
+        }
+
+        fn next(&mut self) -> u64 {
+            0x5555555555555555
+        }
+
+        fn advance(&mut self, _delta: u64) {}
+
+        fn reseed(&mut self, _seed: u64) {}
+    }
+}
+
+/// Wrappers that change how the output of an RNG is presented.
+pub mod adapters {
+    use super::{splitmix, RNG};
+
+    /// Packs the native outputs of an RNG into a continuous bitstream without discarding bits.
+    /// For generators with fewer than 64 native bits this exposes the stream the generator
+    /// actually produces, instead of the synthesized u64s of `.next()`.
+    /// Bits are packed LSB first. One step of the wrapper is one u64 of packed output.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct NativePacked<R: RNG> {
+        inner: R,
+        buffer: u128,
+        buffered_bits: u32,
+    }
+
+    impl<R: RNG> NativePacked<R> {
+        /// Wrap an existing generator.
+        pub fn from_rng(inner: R) -> Self {
+            NativePacked {
+                inner,
+                buffer: 0,
+                buffered_bits: 0,
+            }
+        }
+    }
+
+    impl<R: RNG> RNG for NativePacked<R> {
+        fn new(seed: u64) -> Self {
+            Self::from_rng(R::new(seed))
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.next() as u32
+        }
+
+        fn next(&mut self) -> u64 {
+            let native_bits = self.inner.native_bits();
+            while self.buffered_bits < 64 {
+                let sample = self.inner.next_native() & (u64::MAX >> (64 - native_bits));
+                self.buffer |= (sample as u128) << self.buffered_bits;
+                self.buffered_bits += native_bits;
+            }
+            let output = self.buffer as u64;
+            self.buffer >>= 64;
+            self.buffered_bits -= 64;
+            output
+        }
+
+        fn advance(&mut self, delta: u64) {
+            for _ in 0..delta {
+                let _ = self.next();
+            }
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            self.inner.reseed(seed);
+            self.buffer = 0;
+            self.buffered_bits = 0;
+        }
+    }
+
+    /// Extracts a single bit plane, one bit position of every native output,
+    /// and packs 64 consecutive bits LSB first into each u64.
+    /// Whole word tests average over all bit positions, which hides weak individual bits,
+    /// like the short period low bits of power-of-two modulus LCGs.
+    /// One step of the wrapper is 64 steps of the inner generator.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct BitPlane<R: RNG> {
+        inner: R,
+        plane: u32,
+    }
+
+    impl<R: RNG> BitPlane<R> {
+        /// Wrap an existing generator, extracting bit `plane` of its native output.
+        /// Panics if `plane` is not below the native bits of the generator.
+        pub fn from_rng(inner: R, plane: u32) -> Self {
+            assert!(
+                plane < inner.native_bits(),
+                "Bit plane {} out of range for {} native bits",
+                plane,
+                inner.native_bits()
+            );
+            BitPlane { inner, plane }
+        }
+    }
+
+    impl<R: RNG> RNG for BitPlane<R> {
+        /// Extracts the lowest bit plane.
+        fn new(seed: u64) -> Self {
+            Self::from_rng(R::new(seed), 0)
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.next() as u32
+        }
+
+        fn next(&mut self) -> u64 {
+            let mut output: u64 = 0;
+            for bit in 0..64 {
+                output |= ((self.inner.next_native() >> self.plane) & 1) << bit;
+            }
+            output
+        }
+
+        fn advance(&mut self, delta: u64) {
+            // 64 inner steps per step, split to avoid overflowing delta.
+            for _ in 0..64 {
+                self.inner.advance(delta);
+            }
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            self.inner.reseed(seed);
+        }
+
+        /// Splits the inner generator and keeps the plane.
+        fn split(&mut self) -> Self {
+            Self::from_rng(self.inner.split(), self.plane)
+        }
+    }
+
+    /// Keeps every `stride`-th native output of the inner generator and skips the others with `advance`.
+    /// Some generators only show correlations at specific strides,
+    /// and broken `advance` implementations show up as failing tests.
+    /// For fewer than 64 native bits `.next()` concatenates whole native outputs LSB first
+    /// and discards the bits that do not fit.
+    /// One step of the wrapper is `stride` steps of the inner generator.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Decimated<R: RNG> {
+        inner: R,
+        stride: u64,
+    }
+
+    impl<R: RNG> Decimated<R> {
+        /// Wrap an existing generator, keeping every `stride`-th native output.
+        /// Panics if `stride` is zero.
+        pub fn from_rng(inner: R, stride: u64) -> Self {
+            assert!(stride > 0, "Decimation stride must be at least 1");
+            Decimated { inner, stride }
+        }
+    }
+
+    impl<R: RNG> RNG for Decimated<R> {
+        /// Keeps every second output.
+        fn new(seed: u64) -> Self {
+            Self::from_rng(R::new(seed), 2)
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.next() as u32
+        }
+
+        fn next(&mut self) -> u64 {
+            let native_bits = self.native_bits();
+            let mut output: u64 = 0;
+            let mut shift = 0;
+            while shift < 64 {
+                output |= self.next_native() << shift;
+                shift += native_bits;
+            }
+            output
+        }
+
+        fn advance(&mut self, delta: u64) {
+            // `stride` inner steps per step, split to avoid overflowing delta.
+            for _ in 0..self.stride {
+                self.inner.advance(delta);
+            }
+        }
+
+        fn reseed(&mut self, seed: u64) {
+            self.inner.reseed(seed);
+        }
+
+        /// Splits the inner generator and keeps the stride.
+        fn split(&mut self) -> Self {
+            Self::from_rng(self.inner.split(), self.stride)
+        }
+
+        fn native_bits(&self) -> u32 {
+            self.inner.native_bits()
+        }
+
+        fn next_native(&mut self) -> u64 {
+            let native_bits = self.inner.native_bits();
+            let sample = self.inner.next_native() & (u64::MAX >> (64 - native_bits));
+            self.inner.advance(self.stride - 1);
+            sample
+        }
+    }
+
+    /// Sub-stream `index` of `streams` interleaved sub-streams of the inner generator,
+    /// the native outputs index, index + streams, index + 2 * streams and so on.
+    /// Lets parallel workers share one seeded generator without overlapping, see `Leapfrog::partition`.
+    /// The other sub-streams are skipped with `advance` like in `Decimated`,
+    /// so leapfrogging is efficient for generators with a jump ahead.
+    /// One step of the wrapper is `streams` steps of the inner generator.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Leapfrog<R: RNG> {
+        decimated: Decimated<R>,
+        index: u64,
+    }
+
+    impl<R: RNG> Leapfrog<R> {
+        /// Wrap an existing generator, keeping sub-stream `index` of `streams`.
+        /// Panics if `index` is not below `streams`.
+        pub fn from_rng(mut inner: R, streams: u64, index: u64) -> Self {
+            assert!(
+                index < streams,
+                "Leapfrog sub-stream {} out of range for {} streams",
+                index,
+                streams
+            );
+            inner.advance(index);
+            Leapfrog {
+                decimated: Decimated::from_rng(inner, streams),
+                index,
+            }
+        }
+
+        /// All `streams` sub-streams of `inner`, which together cover its native outputs exactly once.
+        /// Panics if `streams` is zero.
+        pub fn partition(inner: R, streams: u64) -> Vec<Self>
+        where
+            R: Clone,
+        {
+            assert!(streams > 0, "Leapfrog needs at least one stream");
+            (0..streams)
+                .map(|index| Self::from_rng(inner.clone(), streams, index))
+                .collect()
+        }
+
+        /// Number of interleaved sub-streams.
+        pub fn streams(&self) -> u64 {
+            self.decimated.stride
+        }
+
+        /// Index of this sub-stream.
+        pub fn index(&self) -> u64 {
+            self.index
+        }
+    }
+
+    impl<R: RNG> RNG for Leapfrog<R> {
+        /// Sub-stream 0 of 2.
+        fn new(seed: u64) -> Self {
+            Self::from_rng(R::new(seed), 2, 0)
         }
 
         fn next_u32(&mut self) -> u32 {
-            self.next() as u32
+            self.decimated.next_u32()
         }
 
         fn next(&mut self) -> u64 {
-            self.state[0] = self.state[0].wrapping_mul(0x59ca1b2888a0a80fc054cd25b1fde311);
-            self.state[0] = self.state[0].wrapping_add(0xa53a3854d740d22b4802f2e6ea01e350);
-            self.state[1] = self.state[1].wrapping_mul(0xade47f9859546ba094573e7c2194a93c);
-            self.state[1] = self.state[1].wrapping_add(0xc77a0728309148b95143795d657a29f2);
-            self.state[2] = self.state[2].wrapping_mul(0x85fec39e4833d57dd07f903f191ecfd3);
-            self.state[2] = self.state[2].wrapping_add(0x77421f2a59df2305739f337afcad9edb);
-            self.state[3] = self.state[3].wrapping_mul(0xcdf30907584f7e1551c0667353108b63);
-            self.state[3] = self.state[3].wrapping_add(0x935fec88eaba8c39e94503587c22ce99);
-            ((self.state[0] >> 64) as u64)
-                ^ ((self.state[1] >> 64) as u64)
-                ^ ((self.state[2] >> 64) as u64)
-                ^ ((self.state[3] >> 64) as u64)
+            self.decimated.next()
         }
 
-        fn advance(&mut self, delta: usize) {
-            for _ in 0..delta {
-                let _ = self.next();
-            }
+        fn advance(&mut self, delta: u64) {
+            self.decimated.advance(delta);
         }
 
+        /// Reseeds the inner generator and moves to the start of the sub-stream.
         fn reseed(&mut self, seed: u64) {
-            self.state = [
-                (!seed as u128) << 64 | !seed as u128,
-                (seed as u128) << 64 | seed as u128,
-                (seed as u128) << 64 | !seed as u128,
-                (!seed as u128) << 64 | seed as u128,
-            ];
+            self.decimated.reseed(seed);
+            self.decimated.inner.advance(self.index);
+        }
+
+        /// Splits the inner generator and keeps the sub-stream.
+        fn split(&mut self) -> Self {
+            Self::from_rng(self.decimated.inner.split(), self.streams(), self.index)
+        }
+
+        fn native_bits(&self) -> u32 {
+            self.decimated.native_bits()
+        }
+
+        fn next_native(&mut self) -> u64 {
+            self.decimated.next_native()
         }
     }
-    #[derive(Debug, Copy, Clone)]
-    pub struct UlsLcg512H {
-        state: [u128; 4],
+
+    /// Transformation of the output stream, see `View`.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub enum StreamView {
+        /// x[i] - x[i-1], wrapping.
+        Delta,
+        /// x[i] ^ x[i-1].
+        Xor,
+        /// x[i] with the bit order reversed.
+        BitReverse,
+        /// x[i] with the byte order reversed.
+        ByteSwap,
     }
 
-    impl RNG for UlsLcg512H {
-        fn new(seed: u64) -> Self {
-            UlsLcg512H {
-                state: [
-                    (!seed as u128) << 64 | !seed as u128,
-                    (seed as u128) << 64 | seed as u128,
-                    (seed as u128) << 64 | !seed as u128,
-                    (!seed as u128) << 64 | seed as u128,
-                ],
+    impl StreamView {
+        pub const ALL: [StreamView; 4] = [
+            StreamView::Delta,
+            StreamView::Xor,
+            StreamView::BitReverse,
+            StreamView::ByteSwap,
+        ];
+
+        /// Stable identifier, used on the command line and in suite names.
+        pub fn name(&self) -> &'static str {
+            match self {
+                StreamView::Delta => "delta",
+                StreamView::Xor => "xor",
+                StreamView::BitReverse => "bitrev",
+                StreamView::ByteSwap => "byteswap",
+            }
+        }
+
+        pub fn from_name(name: &str) -> Option<Self> {
+            Self::ALL.into_iter().find(|view| view.name() == name)
+        }
+    }
+
+    /// Derived stream computed from the u64 outputs of the inner generator.
+    /// Counter based and additive generators often only fail on such views.
+    /// The views of adjacent outputs consume one extra output before the first step.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct View<R: RNG> {
+        inner: R,
+        view: StreamView,
+        previous: Option<u64>,
+    }
+
+    impl<R: RNG> View<R> {
+        /// Wrap an existing generator.
+        pub fn from_rng(inner: R, view: StreamView) -> Self {
+            View {
+                inner,
+                view,
+                previous: None,
             }
         }
+    }
+
+    impl<R: RNG> RNG for View<R> {
+        /// Uses the delta view.
+        fn new(seed: u64) -> Self {
+            Self::from_rng(R::new(seed), StreamView::Delta)
+        }
 
         fn next_u32(&mut self) -> u32 {
             self.next() as u32
         }
 
         fn next(&mut self) -> u64 {
-            self.state[0] = self.state[0].wrapping_mul(0xe7513927bf96492135e503ed7f5b837e);
-            self.state[0] = self.state[0].wrapping_add(0x126b06c2bfe2dac7725ee66c0e1efe69);
-            self.state[1] = self.state[1].wrapping_mul(0x6420fafa38bd7d81fc02e8cbfac57698);
-            self.state[1] = self.state[1].wrapping_add(0xd2a884d8ed65a425999f67abfa901eba);
-            self.state[2] = self.state[2].wrapping_mul(0x3072f956f9d4a9531efd7c4bd3f684f5);
-            self.state[2] = self.state[2].wrapping_add(0x2f18c679c54a581aef3f88efa973d2c9);
-            self.state[3] = self.state[3].wrapping_mul(0xa7b5b12dc766a03cfdbaf54bacac8382);
-            self.state[3] = self.state[3].wrapping_add(0xb12c82d5df1c4e33fd207ba107b9c620);
-            (self.state[0].wrapping_add(
-                self.state[1].wrapping_add(self.state[2].wrapping_add(self.state[3])),
-            ) >> 64) as u64
+            match self.view {
+                StreamView::BitReverse => self.inner.next().reverse_bits(),
+                StreamView::ByteSwap => self.inner.next().swap_bytes(),
+                StreamView::Delta | StreamView::Xor => {
+                    let previous = match self.previous {
+                        Some(previous) => previous,
+                        None => self.inner.next(),
+                    };
+                    let current = self.inner.next();
+                    self.previous = Some(current);
+                    if self.view == StreamView::Delta {
+                        current.wrapping_sub(previous)
+                    } else {
+                        current ^ previous
+                    }
+                }
+            }
         }
 
-        fn advance(&mut self, delta: usize) {
+        fn advance(&mut self, delta: u64) {
             for _ in 0..delta {
                 let _ = self.next();
             }
         }
 
         fn reseed(&mut self, seed: u64) {
-            self.state = [
-                (!seed as u128) << 64 | !seed as u128,
-                (seed as u128) << 64 | seed as u128,
-                (seed as u128) << 64 | !seed as u128,
-                (!seed as u128) << 64 | seed as u128,
-            ];
+            self.inner.reseed(seed);
+            self.previous = None;
+        }
+
+        /// Splits the inner generator and keeps the view.
+        fn split(&mut self) -> Self {
+            Self::from_rng(self.inner.split(), self.view)
         }
     }
 
-    #[derive(Debug, Copy, Clone)]
-    pub struct Lehmer64 {
-        state: u128,
+    /// How `CombinedRNG` merges the outputs of its two generators.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub enum Combination {
+        /// a ^ b.
+        Xor,
+        /// a + b, wrapping.
+        Add,
     }
-    impl RNG for Lehmer64 {
-        fn new(seed: u64) -> Self {
-            Lehmer64 {
-                state: (seed as u128) << 64 | seed as u128,
+
+    /// Merges the outputs of two independently seeded generators, like the components of KISS.
+    /// The combination is at least as uniform as the better of the two generators,
+    /// the suite shows how much it improves on weak ones.
+    /// One step of the wrapper is one step of each inner generator.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct CombinedRNG<A: RNG, B: RNG> {
+        a: A,
+        b: B,
+        combination: Combination,
+    }
+
+    impl<A: RNG, B: RNG> CombinedRNG<A, B> {
+        /// Combine two existing generators.
+        pub fn from_rngs(a: A, b: B, combination: Combination) -> Self {
+            CombinedRNG { a, b, combination }
+        }
+
+        fn combine(&self, a: u64, b: u64) -> u64 {
+            match self.combination {
+                Combination::Xor => a ^ b,
+                Combination::Add => a.wrapping_add(b),
             }
         }
+    }
+
+    impl<A: RNG, B: RNG> RNG for CombinedRNG<A, B> {
+        /// XORs the outputs, the generators are seeded with the first two outputs of SplitMix64.
+        fn new(seed: u64) -> Self {
+            let [seed_a, seed_b] = splitmix::expand_seed::<2>(seed);
+            Self::from_rngs(A::new(seed_a), B::new(seed_b), Combination::Xor)
+        }
 
         fn next_u32(&mut self) -> u32 {
-            self.next() as u32
+            let (a, b) = (self.a.next_u32(), self.b.next_u32());
+            self.combine(a as u64, b as u64) as u32
         }
 
         fn next(&mut self) -> u64 {
-            self.state = self.state.wrapping_mul(0xda942042e4dd58b5);
-            (self.state >> 64) as u64
+            let (a, b) = (self.a.next(), self.b.next());
+            self.combine(a, b)
         }
 
-        fn advance(&mut self, delta: usize) {
-            for _ in 0..delta {
-                let _ = self.next();
-            }
+        fn advance(&mut self, delta: u64) {
+            self.a.advance(delta);
+            self.b.advance(delta);
         }
 
         fn reseed(&mut self, seed: u64) {
-            self.state = (seed as u128) << 64 | seed as u128;
+            let [seed_a, seed_b] = splitmix::expand_seed::<2>(seed);
+            self.a.reseed(seed_a);
+            self.b.reseed(seed_b);
         }
-    }
-}
 
-/// RNGs based on permutation substitution networks.
-pub mod spn {
-    use std::arch::x86_64::*;
+        /// Splits both generators and keeps the combination.
+        fn split(&mut self) -> Self {
+            Self::from_rngs(self.a.split(), self.b.split(), self.combination)
+        }
 
-    use super::RNG;
+        fn is_available(&self) -> bool {
+            self.a.is_available() && self.b.is_available()
+        }
 
-    /// Implementation is x86 architecture specific.
-    /// Will crash if x86 AES instruction set is not available.
-    #[derive(Debug, Copy, Clone)]
-    pub struct RijndaelStream {
-        counter: u128,
-        key: [u8; 16],
+        fn is_deterministic(&self) -> bool {
+            self.a.is_deterministic() && self.b.is_deterministic()
+        }
     }
-    impl RNG for RijndaelStream {
-        fn new(seed: u64) -> Self {
-            let mut key: [u8; 16] = [0; 16];
-            key[0..8].clone_from_slice(&seed.to_le_bytes());
-            key[8..16].clone_from_slice(&(!seed).to_le_bytes());
-            RijndaelStream { counter: 0, key }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::rngs::lcg::{Mmix, Randu};
+
+        #[test]
+        fn split_keeps_configuration() {
+            let mut parent = BitPlane::from_rng(Randu::new(1), 30);
+            let mut inner = Randu::new(1);
+            let mut child = parent.split();
+            let mut expected = BitPlane::from_rng(inner.split(), 30);
+            assert_eq!(child.next(), expected.next());
+            assert_eq!(parent.inner, inner);
         }
 
-        fn next_u32(&mut self) -> u32 {
-            self.next() as u32
+        #[test]
+        fn bit_plane_extraction() {
+            let mut reference = Randu::new(1);
+            let mut plane = BitPlane::from_rng(Randu::new(1), 30);
+            let sample = plane.next();
+            for bit in 0..64 {
+                assert_eq!((sample >> bit) & 1, reference.next_native() >> 30);
+            }
+            let mut advanced = BitPlane::from_rng(Randu::new(1), 30);
+            advanced.advance(1);
+            assert_eq!(advanced.next(), plane.next());
+            // The lowest bit of a power-of-two modulus LCG with odd multiplier and increment alternates.
+            let mut low_bit = BitPlane::<Mmix>::new(0);
+            let first = low_bit.next();
+            assert!(first == 0x5555555555555555 || first == 0xaaaaaaaaaaaaaaaa);
         }
 
-        fn next(&mut self) -> u64 {
-            self.advance(1);
+        #[test]
+        #[should_panic]
+        fn bit_plane_out_of_range() {
+            let _ = BitPlane::from_rng(Randu::new(1), 31);
+        }
 
-            let mut encrypted = [0u8; 16];
-            unsafe {
-                // Load key and block into SIMD registers
-                let key = _mm_loadu_si128(self.key.as_ptr() as *const __m128i);
-                let mut block =
-                    _mm_loadu_si128(self.counter.to_le_bytes().as_ptr() as *const __m128i);
+        #[test]
+        fn decimation() {
+            let mut reference = Mmix::new(1);
+            let mut decimated = Decimated::from_rng(Mmix::new(1), 3);
+            for _ in 0..8 {
+                assert_eq!(decimated.next(), reference.next());
+                reference.advance(2);
+            }
+            let mut advanced = Decimated::from_rng(Mmix::new(1), 3);
+            advanced.advance(8);
+            assert_eq!(advanced, decimated);
+            // Three 31 bit outputs per u64, the top 29 bits of the third are discarded.
+            let mut reference = Randu::new(1);
+            let mut narrow = Decimated::from_rng(Randu::new(1), 2);
+            let expected = (0..3).fold(0u64, |output, word| {
+                let sample = reference.next_native();
+                reference.advance(1);
+                output | sample << (31 * word)
+            });
+            assert_eq!(narrow.next(), expected);
+            assert_eq!(
+                Decimated::from_rng(Randu::new(1), 1).next_native(),
+                Randu::new(1).next_native()
+            );
+        }
 
-                for _ in 0..4 {
-                    block = _mm_aesenc_si128(block, key);
+        #[test]
+        fn leapfrog_partition() {
+            let mut reference = Mmix::new(1);
+            let mut streams = Leapfrog::partition(Mmix::new(1), 3);
+            for _ in 0..4 {
+                for stream in streams.iter_mut() {
+                    assert_eq!(stream.next_native(), reference.next_native());
                 }
-                _mm_storeu_si128(encrypted.as_mut_ptr() as *mut __m128i, block);
             }
-            u128::from_le_bytes(encrypted) as u64
+            let mut reseeded = Leapfrog::from_rng(Mmix::new(7), 3, 2);
+            reseeded.reseed(1);
+            assert_eq!(reseeded, Leapfrog::from_rng(Mmix::new(1), 3, 2));
+            let mut narrow = Leapfrog::partition(Randu::new(1), 2);
+            let mut narrow_reference = Randu::new(1);
+            narrow_reference.next_native();
+            assert_eq!(narrow[1].next_native(), narrow_reference.next_native());
         }
 
-        fn advance(&mut self, delta: usize) {
-            self.counter += delta as u128;
+        #[test]
+        #[should_panic]
+        fn leapfrog_index_out_of_range() {
+            let _ = Leapfrog::from_rng(Randu::new(1), 3, 3);
         }
 
-        fn reseed(&mut self, seed: u64) {
-            let mut key: [u8; 16] = [0; 16];
-            key[0..8].clone_from_slice(&seed.to_le_bytes());
-            key[8..16].clone_from_slice(&(!seed).to_le_bytes());
-            self.key = key;
+        #[test]
+        #[should_panic]
+        fn decimation_zero_stride() {
+            let _ = Decimated::from_rng(Randu::new(1), 0);
         }
-    }
-    impl RijndaelStream {
-        pub fn seek(&mut self, counter: u64) {
-            self.counter = counter as u128;
+
+        #[test]
+        fn stream_views() {
+            let outputs: Vec<u64> = {
+                let mut reference = Mmix::new(1);
+                (0..4).map(|_| reference.next()).collect()
+            };
+            let view_outputs = |view: StreamView| {
+                let mut rng = View::from_rng(Mmix::new(1), view);
+                [rng.next(), rng.next()]
+            };
+            assert_eq!(
+                view_outputs(StreamView::Delta),
+                [
+                    outputs[1].wrapping_sub(outputs[0]),
+                    outputs[2].wrapping_sub(outputs[1])
+                ]
+            );
+            assert_eq!(
+                view_outputs(StreamView::Xor),
+                [outputs[1] ^ outputs[0], outputs[2] ^ outputs[1]]
+            );
+            assert_eq!(
+                view_outputs(StreamView::BitReverse),
+                [outputs[0].reverse_bits(), outputs[1].reverse_bits()]
+            );
+            assert_eq!(
+                view_outputs(StreamView::ByteSwap),
+                [outputs[0].swap_bytes(), outputs[1].swap_bytes()]
+            );
+            let mut reseeded = View::from_rng(Mmix::new(2), StreamView::Xor);
+            reseeded.next();
+            reseeded.reseed(1);
+            assert_eq!(reseeded.next(), outputs[1] ^ outputs[0]);
+            for view in StreamView::ALL {
+                assert_eq!(StreamView::from_name(view.name()), Some(view));
+            }
+            assert_eq!(StreamView::from_name("diff"), None);
+        }
+
+        #[test]
+        fn combination() {
+            let [seed_a, seed_b] = splitmix::expand_seed::<2>(1);
+            let mut a = Mmix::new(seed_a);
+            let mut b = Randu::new(seed_b);
+            let mut combined = CombinedRNG::<Mmix, Randu>::new(1);
+            assert_eq!(combined.next(), a.next() ^ b.next());
+            assert_eq!(combined.next_u32(), a.next_u32() ^ b.next_u32());
+            let mut added = CombinedRNG::from_rngs(a, b, Combination::Add);
+            assert_eq!(added.next(), a.next().wrapping_add(b.next()));
+            combined.reseed(1);
+            assert_eq!(combined, CombinedRNG::<Mmix, Randu>::new(1));
+        }
+
+        #[test]
+        fn native_packing() {
+            let mut reference = Randu::new(1);
+            let mut packed = NativePacked::<Randu>::new(1);
+            // 64 native outputs of 31 bits fill exactly 31 u64s.
+            let natives: Vec<u64> = (0..64).map(|_| reference.next_native()).collect();
+            for word in 0..31 {
+                let sample = packed.next();
+                for bit in 0..64 {
+                    let position = word * 64 + bit;
+                    assert_eq!(
+                        (sample >> bit) & 1,
+                        (natives[position / 31] >> (position % 31)) & 1
+                    );
+                }
+            }
         }
     }
 }
 
-pub mod testgens {
-    use super::RNG;
+/// Static description of a registered generator.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RngMetadata {
+    /// Family of the generator, the module it is implemented in.
+    pub family: &'static str,
+    /// Size of the generator state in bytes.
+    pub state_bytes: usize,
+    /// Deliberately bad generator, only useful to validate the tests.
+    pub test_generator: bool,
+    /// Number of steps the state was certified not to repeat within by the test suite,
+    /// `None` in the registry since it is measured at run time.
+    pub period_lower_bound: Option<u64>,
+}
 
-    #[derive(Debug, Copy, Clone)]
-    pub struct OnlyOne {}
-    impl RNG for OnlyOne {
-        fn new(_seed: u64) -> Self {
-            OnlyOne {}
+/// Creates a generator seeded with the supplied seed.
+pub type RngFactory = fn(u64) -> Box<dyn RNG>;
+
+const fn entry<R: RNG + 'static>(
+    name: &'static str,
+    family: &'static str,
+    test_generator: bool,
+) -> (&'static str, RngMetadata, RngFactory) {
+    (
+        name,
+        RngMetadata {
+            family,
+            state_bytes: std::mem::size_of::<R>(),
+            test_generator,
+            period_lower_bound: None,
+        },
+        |seed| Box::new(R::new(seed)),
+    )
+}
+
+/// Calls the macro `$callback` with every registered generator, in the order the test suite runs them,
+/// as a list of `"name" => type, "family", test_generator;` with optional `#[cfg]` attributes.
+/// Both the registry and the test suite of the binary are generated from this list.
+#[macro_export]
+macro_rules! registered_rngs {
+    ($callback:ident) => {
+        $callback! {
+            #[cfg(feature = "rand-compat")]
+            "Reference" => $crate::rngs::ReferenceRand, "reference", false;
+            "OnlyOnes" => $crate::rngs::testgens::OnlyOne, "testgens", true;
+            "OnlyZero" => $crate::rngs::testgens::OnlyZero, "testgens", true;
+            "AlternatingBlocks" => $crate::rngs::testgens::AlternatingBlocks, "testgens", true;
+            "AlternatingBytes" => $crate::rngs::testgens::AlternatingBytes, "testgens", true;
+            "AlternatingBits" => $crate::rngs::testgens::AlternatingBits, "testgens", true;
+            #[cfg(feature = "crypto-rngs")]
+            "RijndaelStream" => $crate::rngs::spn::RijndaelStream, "spn", false;
+            #[cfg(feature = "crypto-rngs")]
+            "ChaCha8" => $crate::rngs::stream::ChaCha8, "stream", false;
+            #[cfg(feature = "crypto-rngs")]
+            "ChaCha12" => $crate::rngs::stream::ChaCha12, "stream", false;
+            #[cfg(feature = "crypto-rngs")]
+            "ChaCha20" => $crate::rngs::stream::ChaCha20, "stream", false;
+            #[cfg(feature = "crypto-rngs")]
+            "ISAAC" => $crate::rngs::stream::Isaac, "stream", false;
+            #[cfg(feature = "crypto-rngs")]
+            "RC4" => $crate::rngs::stream::Rc4, "stream", false;
+            #[cfg(feature = "crypto-rngs")]
+            "HashDRBG" => $crate::rngs::drbg::HashDrbgRng, "drbg", false;
+            #[cfg(feature = "crypto-rngs")]
+            "HMACDRBG" => $crate::rngs::drbg::HmacDrbgRng, "drbg", false;
+            #[cfg(feature = "crypto-rngs")]
+            "CTRDRBG" => $crate::rngs::drbg::CtrDrbgRng, "drbg", false;
+            "RapidHashRNG" => $crate::rngs::xorshift::RapidHashRNG, "xorshift", false;
+            "RapidHashRNG2" => $crate::rngs::xorshift::RapidHashRNG2, "xorshift", false;
+            "WyRand" => $crate::rngs::xorshift::WyRand, "xorshift", false;
+            "JSF32" => $crate::rngs::jsf::Jsf32, "jsf", false;
+            "JSF64" => $crate::rngs::jsf::Jsf64, "jsf", false;
+            "gjrand64" => $crate::rngs::gjrand::Gjrand64, "gjrand", false;
+            "Lehmer64" => $crate::rngs::lcg::Lehmer64, "lcg", false;
+            "JavaRandom" => $crate::rngs::lcg::JavaRandom, "lcg", false;
+            "MINSTD" => $crate::rngs::lcg::Minstd, "lcg", false;
+            "MWC1" => $crate::rngs::lcg::Mwc1, "lcg", false;
+            "CMWC4096" => $crate::rngs::lcg::Cmwc4096, "lcg", false;
+            "KISS99" => $crate::rngs::kiss::Kiss99, "kiss", false;
+            "JKISS" => $crate::rngs::kiss::Jkiss, "kiss", false;
+            "KISS64" => $crate::rngs::kiss::Kiss64, "kiss", false;
+            "RANDU" => $crate::rngs::lcg::Randu, "lcg", false;
+            "RANDU-Native" => $crate::rngs::adapters::NativePacked<$crate::rngs::lcg::Randu>, "adapters", false;
+            "MMIX-XOR-XORShift128" => $crate::rngs::adapters::CombinedRNG<
+                $crate::rngs::lcg::Mmix,
+                $crate::rngs::xorshift::XORShift128,
+            >, "adapters", false;
+            "MMIX" => $crate::rngs::lcg::Mmix, "lcg", false;
+            "UlsLcg512" => $crate::rngs::lcg::UlsLcg512, "lcg", false;
+            "UlsLcg512H" => $crate::rngs::lcg::UlsLcg512H, "lcg", false;
+            "PCG32" => $crate::rngs::pcg::Pcg32, "pcg", false;
+            "PCG64" => $crate::rngs::pcg::Pcg64, "pcg", false;
+            "PCG64DXSM" => $crate::rngs::pcg::Pcg64Dxsm, "pcg", false;
+            "MT19937" => $crate::rngs::mt::Mt19937, "mt", false;
+            "MT19937-64" => $crate::rngs::mt::Mt19937_64, "mt", false;
+            "WELL512a" => $crate::rngs::well::Well512a, "well", false;
+            "LFSR64-Fibonacci" => $crate::rngs::lfsr::Fibonacci<u64>, "lfsr", false;
+            "LFSR64-Galois" => $crate::rngs::lfsr::Galois<u64>, "lfsr", false;
+            "Philox4x32" => $crate::rngs::philox::Philox4x32, "philox", false;
+            "Threefry2x64" => $crate::rngs::threefry::Threefry2x64, "threefry", false;
+            "Threefry4x64" => $crate::rngs::threefry::Threefry4x64, "threefry", false;
+            "MSWS" => $crate::rngs::msws::Msws, "msws", false;
+            "Squares32" => $crate::rngs::msws::Squares32, "msws", false;
+            "Squares64" => $crate::rngs::msws::Squares64, "msws", false;
+            "SipHash24" => $crate::rngs::siphash::SipHash24Rng, "siphash", false;
+            "SipHash13" => $crate::rngs::siphash::SipHash13Rng, "siphash", false;
+            "SplitMix64" => $crate::rngs::splitmix::SplitMix64, "splitmix", false;
+            "XORShift128" => $crate::rngs::xorshift::XORShift128, "xorshift", false;
+            "Xorwow" => $crate::rngs::xorshift::Xorwow, "xorshift", false;
+            "Xorshift64" => $crate::rngs::xorshift::Xorshift, "xorshift", false;
+            "Xoshiro256StarStar" => $crate::rngs::xoshiro::Xoshiro256StarStar, "xoshiro", false;
+            "Xoshiro256PlusPlus" => $crate::rngs::xoshiro::Xoshiro256PlusPlus, "xoshiro", false;
+            "StreamNLARXu128" => $crate::rngs::stream_nlarx::StreamNLARXu128, "stream_nlarx", false;
+            "Tyche" => $crate::rngs::tyche::TycheRng, "tyche", false;
+            "Tyche-i" => $crate::rngs::tyche::TycheI, "tyche", false;
+            "RDRAND" => $crate::rngs::hardware::Rdrand, "hardware", false;
+            "RDSEED" => $crate::rngs::hardware::Rdseed, "hardware", false;
+            #[cfg(feature = "rand-compat")]
+            "OsEntropy" => $crate::rngs::OsEntropy, "os", false;
         }
+    };
+}
 
-        fn next_u32(&mut self) -> u32 {
-            u32::MAX
+macro_rules! registry_entries {
+    ($($(#[$attr:meta])* $name:literal => $rng:ty, $family:literal, $test_generator:literal;)*) => {
+        const REGISTRY: &[(&str, RngMetadata, RngFactory)] = &[
+            $($(#[$attr])* entry::<$rng>($name, $family, $test_generator),)*
+        ];
+    };
+}
+
+registered_rngs!(registry_entries);
+
+/// Iterate over every generator the crate provides as (name, metadata, factory),
+/// in the order the test suite runs them.
+pub fn registry() -> impl Iterator<Item = (&'static str, RngMetadata, RngFactory)> {
+    REGISTRY.iter().copied()
+}
+
+/// Format the registry as a table of names, families, native output bits and state sizes.
+pub fn format_registry() -> String {
+    let mut lines: Vec<String> = vec![format!(
+        "{:<18} {:<13} {:>11} {:>11}",
+        "Name", "Family", "Native bits", "State bytes"
+    )];
+    for (name, metadata, factory) in registry() {
+        lines.push(format!(
+            "{:<18} {:<13} {:>11} {:>11}{}",
+            name,
+            metadata.family,
+            factory(0).native_bits(),
+            metadata.state_bytes,
+            if metadata.test_generator {
+                "  (test generator)"
+            } else {
+                ""
+            }
+        ));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conditioning::XorFold;
+
+    // Deltas above u32::MAX, which overflowed usize on 32-bit targets.
+    const LARGE_DELTA: u64 = (1 << 40) + 3;
+
+    /// First outputs for `GOLDEN_SEED`.
+    /// Must be identical on every target, regardless of pointer width and endianness.
+    const GOLDEN_SEED: u64 = 0x0123456789abcdef;
+
+    fn first_outputs<R: RNG>() -> [u64; 3] {
+        let mut rng = R::new(GOLDEN_SEED);
+        std::array::from_fn(|_| rng.next())
+    }
+
+    #[test]
+    fn golden_vectors() {
+        let golden: [(&str, [u64; 3], [u64; 3]); 53] = [
+            (
+                "StreamNLARXu128",
+                first_outputs::<stream_nlarx::StreamNLARXu128>(),
+                [0xf672457a9e481a6c, 0xd0429bdbcf1c268e, 0x269b51ee7dc2ba8f],
+            ),
+            (
+                "Xorwow",
+                first_outputs::<xorshift::Xorwow>(),
+                [0x1a4442103fcca938, 0xac7c5504ea64f653, 0x7f87ba72ab8a78b0],
+            ),
+            (
+                "Tyche",
+                first_outputs::<tyche::TycheRng>(),
+                [0x93fdb15b24ec7ed0, 0x40951c120b939b48, 0x47fa6a8a4bf58aab],
+            ),
+            (
+                "Tyche-i",
+                first_outputs::<tyche::TycheI>(),
+                [0x551e9c3ecb769318, 0xc5cd11c591845436, 0xcff3c605ca494280],
+            ),
+            (
+                "Xorshift64",
+                first_outputs::<xorshift::Xorshift>(),
+                [0x20c0c3f0186fb288, 0x423796b98a38cfed, 0x7ce3bb74a486e532],
+            ),
+            (
+                "XORShift128",
+                first_outputs::<xorshift::XORShift128>(),
+                [0x92b8a9c045abcacd, 0x5eb8b76289abcdef, 0xde0a83b3c5ecc71d],
+            ),
+            (
+                "SplitMix64",
+                first_outputs::<splitmix::SplitMix64>(),
+                [0x157a3807a48faa9d, 0xd573529b34a1d093, 0x2f90b72e996dccbe],
+            ),
+            (
+                "Xoshiro256StarStar",
+                first_outputs::<xoshiro::Xoshiro256StarStar>(),
+                [0xa2c2a42038d4ec3d, 0x05fc25d0738e7b0f, 0x625e7bff938e701e],
+            ),
+            (
+                "Xoshiro256PlusPlus",
+                first_outputs::<xoshiro::Xoshiro256PlusPlus>(),
+                [0xb2f2a310e96bd1c5, 0xb54062465b950493, 0x87aca4a9668814b0],
+            ),
+            (
+                "RapidHashRNG",
+                first_outputs::<xorshift::RapidHashRNG>(),
+                [0x43ea30c9c49a1705, 0x658a207639a2efc0, 0xea2629e35272f84c],
+            ),
+            (
+                "WyRand",
+                first_outputs::<xorshift::WyRand>(),
+                [0xf21a392ffc8494c3, 0xfd53e6c86db4558c, 0xd56feb565072472e],
+            ),
+            (
+                "JSF32",
+                first_outputs::<jsf::Jsf32>(),
+                [0xda774680094ab9e7, 0x59d909067f431dad, 0x5b3638a4287ba5ac],
+            ),
+            (
+                "JSF64",
+                first_outputs::<jsf::Jsf64>(),
+                [0x43526f6e3ac54b42, 0xbff8927dcd72b566, 0x59eb2a58286546bc],
+            ),
+            (
+                "gjrand64",
+                first_outputs::<gjrand::Gjrand64>(),
+                [0xece5f095489183a2, 0xc0b7eebb6e16e99b, 0x1e0294b940e106b5],
+            ),
+            (
+                "RapidHashRNG2",
+                first_outputs::<xorshift::RapidHashRNG2>(),
+                [0x785b7cd8b86f18f9, 0xa5002a5e19b416ec, 0xb9a4d2fb2a6ebc3f],
+            ),
+            (
+                "RANDU",
+                first_outputs::<lcg::Randu>(),
+                [0xc9a73643d673b835, 0xc0a27ef79ddb6d97, 0x6523150da4f18eed],
+            ),
+            (
+                "MMIX",
+                first_outputs::<lcg::Mmix>(),
+                [0x2ce32d2335df4552, 0xca18dd5ae3c45eb9, 0x860f53667996eed4],
+            ),
+            (
+                "UlsLcg512",
+                first_outputs::<lcg::UlsLcg512>(),
+                [0x58a105c224429195, 0x4f1797db4c889fc7, 0xcfce625fe4e3429f],
+            ),
+            (
+                "UlsLcg512H",
+                first_outputs::<lcg::UlsLcg512H>(),
+                [0x453aefb8e37c72c5, 0xdd295901b3ea0747, 0x1e609b764f02dc62],
+            ),
+            (
+                "Lehmer64",
+                first_outputs::<lcg::Lehmer64>(),
+                [0x9bf82f820876e23a, 0x26664d56f05045b8, 0xf3cf48e8a467812b],
+            ),
+            (
+                "JavaRandom",
+                first_outputs::<lcg::JavaRandom>(),
+                [0xec29f23c7886e0d1, 0xd5193402b0ee90fb, 0x764808b57cca1915],
+            ),
+            (
+                "MINSTD",
+                first_outputs::<lcg::Minstd>(),
+                [0x166d7c61a58b2bbb, 0x5e51f979c1d04e00, 0xc3091cb93dfb199c],
+            ),
+            (
+                "MWC1",
+                first_outputs::<lcg::Mwc1>(),
+                [0x75ccc7e152668b20, 0x7600adb1eee00915, 0xb78aef4edafefe88],
+            ),
+            (
+                "CMWC4096",
+                first_outputs::<lcg::Cmwc4096>(),
+                [0x43254db6bd1934c1, 0x44a0544dbaeef7f9, 0x3475226badf33fca],
+            ),
+            (
+                "KISS99",
+                first_outputs::<kiss::Kiss99>(),
+                [0x9cc34319fb91baf0, 0xd74ef3fb7f93e64e, 0x7ed9a75dba100610],
+            ),
+            (
+                "JKISS",
+                first_outputs::<kiss::Jkiss>(),
+                [0x1ff362eb4a23140a, 0xc697b2c08f45a92f, 0x354e04fef4a23429],
+            ),
+            (
+                "KISS64",
+                first_outputs::<kiss::Kiss64>(),
+                [0xbafa334b37d73ea5, 0x862094a2f3647749, 0x65623d6cf7fb24cb],
+            ),
+            (
+                "PCG32",
+                first_outputs::<pcg::Pcg32>(),
+                [0x1502c991029d922d, 0x33fdbfd286a43dee, 0x1de925e4377b9b92],
+            ),
+            (
+                "PCG64",
+                first_outputs::<pcg::Pcg64>(),
+                [0xa00c20f5986850ba, 0x23851bb77d6466ea, 0x540fe93238ce9957],
+            ),
+            (
+                "PCG64DXSM",
+                first_outputs::<pcg::Pcg64Dxsm>(),
+                [0xebf9d3d4d0d33804, 0x893cda3f0f5afe4e, 0x1ef845351c4cc6a8],
+            ),
+            (
+                "MT19937",
+                first_outputs::<mt::Mt19937>(),
+                [0xe58d45a759363fc1, 0x64a9486c470c2e3d, 0xdce1213582b1ae25],
+            ),
+            (
+                "MT19937-64",
+                first_outputs::<mt::Mt19937_64>(),
+                [0xb6c31645639e9724, 0x04bc4bd824f74ca2, 0x7e1ebafbd80e5002],
+            ),
+            (
+                "WELL512a",
+                first_outputs::<well::Well512a>(),
+                [0x2ac04ce37eed2c6d, 0xdf0d74c01d357331, 0xc6bc434464cc959d],
+            ),
+            (
+                "LFSR64-Fibonacci",
+                first_outputs::<lfsr::Fibonacci<u64>>(),
+                [0x157a3807a48faa9d, 0x93e602aaf23660d4, 0xa57b24a7cc4b2008],
+            ),
+            (
+                "LFSR64-Galois",
+                first_outputs::<lfsr::Galois<u64>>(),
+                [0xe2211edb352a1113, 0x32725430c53bb701, 0xe1fa94e1ce57d749],
+            ),
+            (
+                "Philox4x32",
+                first_outputs::<philox::Philox4x32>(),
+                [0xc58cb04bb850222e, 0x7a84fff914a7a020, 0x523e0d85adca1466],
+            ),
+            (
+                "Threefry2x64",
+                first_outputs::<threefry::Threefry2x64>(),
+                [0x29f2ff468d14ebf8, 0x197723684631f6a4, 0xfd8dbcee62d0db26],
+            ),
+            (
+                "Threefry4x64",
+                first_outputs::<threefry::Threefry4x64>(),
+                [0xb834d96a372a83ae, 0x0cbc03502a91c608, 0x2b4454c1189a049c],
+            ),
+            (
+                "MSWS",
+                first_outputs::<msws::Msws>(),
+                [0x157a380766e5bc2f, 0x4576ed80a3f0db65, 0x248329acd7d21589],
+            ),
+            (
+                "Squares32",
+                first_outputs::<msws::Squares32>(),
+                [0x825cb82b632678ce, 0xb1d31037deb37e46, 0x450332b602ba950d],
+            ),
+            (
+                "SipHash24",
+                first_outputs::<siphash::SipHash24Rng>(),
+                [0x0dac20d3bd3174ed, 0x4350de52db42dd94, 0x4201bc39d2430b20],
+            ),
+            (
+                "SipHash13",
+                first_outputs::<siphash::SipHash13Rng>(),
+                [0x533ee11eee8de3a1, 0x3506e282a5a683fd, 0xa796285d371bb91d],
+            ),
+            (
+                "Squares64",
+                first_outputs::<msws::Squares64>(),
+                [0x632678ce0434557e, 0x825cb82b11a0b202, 0xdeb37e467e96d769],
+            ),
+            (
+                "RijndaelStream",
+                first_outputs::<spn::RijndaelStream>(),
+                [0xb84014babafd31f0, 0x2b2bbe3d934dd506, 0xdf813039c8a58f26],
+            ),
+            (
+                "ChaCha8",
+                first_outputs::<stream::ChaCha8>(),
+                [0x14c45d4a515d352d, 0x81beede6cafb6e7a, 0x5568f626589137ac],
+            ),
+            (
+                "ChaCha12",
+                first_outputs::<stream::ChaCha12>(),
+                [0x18b7ddb90fed6885, 0x0128af2cc6829645, 0xb500f60a0c2ac9fc],
+            ),
+            (
+                "ChaCha20",
+                first_outputs::<stream::ChaCha20>(),
+                [0x4fb0e90c4f17ff81, 0xfcb649772ba310fb, 0xf8d5a067ad4088c7],
+            ),
+            (
+                "ISAAC",
+                first_outputs::<stream::Isaac>(),
+                [0x8992f16a3a9d82b8, 0x0596a22885e0e795, 0xeda0273b91962cb0],
+            ),
+            (
+                "RC4",
+                first_outputs::<stream::Rc4>(),
+                [0x0e089a0a51852efe, 0x3000dc048f114b53, 0x06d96bde06a43d40],
+            ),
+            (
+                "HashDRBG",
+                first_outputs::<drbg::HashDrbgRng>(),
+                [0x95e171ce46e418fa, 0xf7bbf4cdedfe7a4a, 0xb8d711fff1a4f8ed],
+            ),
+            (
+                "HMACDRBG",
+                first_outputs::<drbg::HmacDrbgRng>(),
+                [0x873f2c4d3e8d39cf, 0x8e6bd5c4275febe2, 0x29010e007b0fede9],
+            ),
+            (
+                "CTRDRBG",
+                first_outputs::<drbg::CtrDrbgRng>(),
+                [0x247cbb66af0775c1, 0xfc452696b553bef6, 0xd09ec18aee48391e],
+            ),
+            (
+                "NativePacked<RANDU>",
+                first_outputs::<adapters::NativePacked<lcg::Randu>>(),
+                [0x55521eb3eaf269cd, 0xde060513ef54ee0d, 0x172b5b6cbf92f79d],
+            ),
+        ];
+        for (name, outputs, expected) in golden {
+            assert_eq!(outputs, expected, "{}", name);
         }
+    }
 
-        fn next(&mut self) -> u64 {
-            u64::MAX
+    #[test]
+    fn splitmix_reference_outputs() {
+        // Outputs of the reference implementation for the seed 1234567.
+        let expected: [u64; 5] = [
+            6457827717110365317,
+            3203168211198807973,
+            9817491932198370423,
+            4593380528125082431,
+            16408922859458223821,
+        ];
+        let mut rng = splitmix::SplitMix64::new(1234567);
+        let outputs: [u64; 5] = std::array::from_fn(|_| rng.next());
+        assert_eq!(outputs, expected);
+        assert_eq!(splitmix::expand_seed::<5>(1234567), expected);
+        let mut advanced = splitmix::SplitMix64::new(1234567);
+        advanced.advance(4);
+        assert_eq!(advanced.next(), expected[4]);
+    }
+
+    #[test]
+    fn mt_reference_outputs() {
+        // Outputs of random.getrandbits(32) in Python after random.seed(seed).
+        for (seed, expected) in [
+            (0, [3626764237, 1654615998, 3255389356, 3823568514]),
+            (5489, [3382763572, 956215839, 417760592, 166104981]),
+            (
+                GOLDEN_SEED,
+                [3851240871, 1496727489, 1688815724, 1191980605],
+            ),
+        ] {
+            let mut rng = mt::Mt19937::new(seed);
+            let outputs: [u32; 4] = std::array::from_fn(|_| rng.next_u32());
+            assert_eq!(outputs, expected, "{}", seed);
         }
+        // The C++ standard requires these 10000th outputs for the default seed 5489.
+        let mut rng = mt::Mt19937::from_u32_seed(5489);
+        rng.advance(9999);
+        assert_eq!(rng.next_u32(), 4123659995);
+        let mut rng = mt::Mt19937_64::new(5489);
+        rng.advance(9999);
+        assert_eq!(rng.next(), 9981545732273789042);
+    }
 
-        fn advance(&mut self, _delta: usize) {}
+    #[test]
+    fn well512a_reference_outputs() {
+        // Outputs of the reference implementation for the state [1, 2, ..., 16].
+        let mut rng = well::Well512a::from_state(std::array::from_fn(|i| i as u32 + 1));
+        let outputs: [u32; 5] = std::array::from_fn(|_| rng.next_u32());
+        assert_eq!(
+            outputs,
+            [2692481146, 2447117626, 752362814, 4237304894, 3767796794]
+        );
+        rng.advance(994);
+        assert_eq!(rng.next_u32(), 3934506550);
+    }
 
-        fn reseed(&mut self, _seed: u64) {}
+    /// First `count` output bits of an LFSR.
+    fn lfsr_bits(mut step: impl FnMut() -> bool, count: usize) -> Vec<bool> {
+        (0..count).map(|_| step()).collect()
     }
 
-    #[derive(Debug, Copy, Clone)]
-    pub struct OnlyZero {}
-    impl RNG for OnlyZero {
-        fn new(_seed: u64) -> Self {
-            OnlyZero {}
+    /// Checks s[k + BITS] = sum of s[k + j] over the coefficients j of the polynomial.
+    fn assert_lfsr_recurrence<W: lfsr::Word + Into<u128>>(bits: &[bool], polynomial: W) {
+        let polynomial: u128 = polynomial.into();
+        let n = W::BITS as usize;
+        for k in 0..bits.len() - n {
+            let feedback = (0..n)
+                .filter(|j| (polynomial >> j) & 1 == 1)
+                .fold(false, |acc, j| acc ^ bits[k + j]);
+            assert_eq!(bits[k + n], feedback, "{} bit {}", n, k + n);
         }
+    }
 
-        fn next_u32(&mut self) -> u32 {
-            0
-        }
+    fn check_lfsr_width<W: lfsr::Word + Into<u128>>() {
+        let mut fibonacci = lfsr::Fibonacci::<W>::new(GOLDEN_SEED);
+        let mut galois = lfsr::Galois::<W>::new(GOLDEN_SEED);
+        let n = W::BITS as usize;
+        let fibonacci_bits = lfsr_bits(|| fibonacci.step(), 3 * n);
+        let galois_bits = lfsr_bits(|| galois.step(), 3 * n);
+        assert_lfsr_recurrence(&fibonacci_bits, W::DEFAULT_POLYNOMIAL);
+        assert_lfsr_recurrence(&galois_bits, W::DEFAULT_POLYNOMIAL);
+        // The Fibonacci state is the next BITS output bits, so it can continue any Galois sequence.
+        let state = galois_bits[2 * n..]
+            .iter()
+            .rev()
+            .fold(W::ZERO, |state, &bit| {
+                (state << 1) | if bit { W::ONE } else { W::ZERO }
+            });
+        let mut fibonacci = lfsr::Fibonacci::with_polynomial(state, galois.polynomial()).unwrap();
+        let expected = lfsr_bits(|| fibonacci.step(), 4 * n);
+        assert_eq!(&expected[..n], &galois_bits[2 * n..]);
+        assert_eq!(&expected[n..], &lfsr_bits(|| galois.step(), 3 * n)[..]);
+    }
 
-        fn next(&mut self) -> u64 {
-            0
+    #[test]
+    fn lfsr_sequences() {
+        check_lfsr_width::<u32>();
+        check_lfsr_width::<u64>();
+        check_lfsr_width::<u128>();
+        let mut rng = lfsr::Galois::<u64>::new(GOLDEN_SEED);
+        let mut bits = rng;
+        let word = rng.next();
+        for bit in 0..64 {
+            assert_eq!((word >> bit) & 1, bits.next_native());
         }
+        assert_eq!(rng, bits);
+    }
 
-        fn advance(&mut self, _delta: usize) {}
+    #[test]
+    fn lfsr_configuration() {
+        assert_eq!(lfsr::polynomial::<u32>(&[32, 22, 2, 1]), Ok(0x0040_0007));
+        assert_eq!(
+            lfsr::polynomial::<u64>(&[64, 63, 61, 60]),
+            Ok(<u64 as lfsr::Word>::DEFAULT_POLYNOMIAL)
+        );
+        assert!(lfsr::polynomial::<u32>(&[22, 2, 1]).is_err());
+        assert!(lfsr::polynomial::<u32>(&[32, 33]).is_err());
+        assert!(lfsr::polynomial::<u32>(&[32, 0]).is_err());
+        assert!(lfsr::Fibonacci::<u32>::with_polynomial(0, 0x0040_0007).is_err());
+        assert!(lfsr::Galois::<u32>::with_polynomial(1, 0x0040_0006).is_err());
+        let galois = lfsr::Galois::<u128>::with_polynomial(1, 0x8000_0001).unwrap();
+        assert_eq!(galois.polynomial(), 0x8000_0001);
+    }
 
-        fn reseed(&mut self, _seed: u64) {}
+    #[test]
+    fn philox_reference_outputs() {
+        // Known answer tests of the Random123 library.
+        assert_eq!(
+            philox::philox4x32([0; 4], [0; 2]),
+            [0x6627e8d5, 0xe169c58d, 0xbc57ac4c, 0x9b00dbd8]
+        );
+        assert_eq!(
+            philox::philox4x32([u32::MAX; 4], [u32::MAX; 2]),
+            [0x408f276d, 0x41c83b0e, 0xa20bc7c6, 0x6d5451fd]
+        );
+        assert_eq!(
+            philox::philox4x32(
+                [0x243f6a88, 0x85a308d3, 0x13198a2e, 0x03707344],
+                [0xa4093822, 0x299f31d0]
+            ),
+            [0xd16cfe09, 0x94fdcceb, 0x5001e420, 0x24126ea1]
+        );
+        let mut rng = philox::Philox4x32::from_key_counter([0xa4093822, 0x299f31d0], 5);
+        rng.advance(6);
+        assert_eq!(rng.counter(), 6);
+        let mut seeked = philox::Philox4x32::from_key_counter([0xa4093822, 0x299f31d0], 0);
+        seeked.seek(24);
+        seeked.next_u32();
+        seeked.next_u32();
+        assert_eq!(seeked, rng);
+        assert_eq!(rng.position(), 26);
+        let mut wrapped = philox::Philox4x32::new(5);
+        wrapped.advance_u128(u128::MAX);
+        wrapped.advance(4);
+        assert_eq!(wrapped, philox::Philox4x32::new(5));
     }
 
-    #[derive(Debug, Copy, Clone)]
-    pub struct AlternatingBlocks {
-        state: u64,
+    #[test]
+    fn threefry_reference_outputs() {
+        // Known answer tests of the Random123 library.
+        assert_eq!(
+            threefry::threefry2x64([0; 2], [0; 2]),
+            [0xc2b6e3a8c2c69865, 0x6f81ed42f350084d]
+        );
+        assert_eq!(
+            threefry::threefry4x64([0; 4], [0; 4]),
+            [
+                0x09218ebde6c85537,
+                0x55941f5266d86105,
+                0x4bd25e16282434dc,
+                0xee29ec846bd2e40b
+            ]
+        );
+        assert_eq!(
+            threefry::threefry4x64([u64::MAX; 4], [u64::MAX; 4]),
+            [
+                0x29c24097942bba1b,
+                0x0371bbfb0f6f4e11,
+                0x3c231ffa33f83a1c,
+                0xcd29113fde32d168
+            ]
+        );
+        let mut rng = threefry::Threefry2x64::from_key_counter([1, 2], 3);
+        assert_eq!(rng.next(), threefry::threefry2x64([3, 0], [1, 2])[0]);
+        rng.advance(3);
+        assert_eq!(rng.counter(), 5);
+        assert_eq!(rng.next(), threefry::threefry2x64([5, 0], [1, 2])[0]);
     }
-    impl RNG for AlternatingBlocks {
-        fn new(_seed: u64) -> Self {
-            AlternatingBlocks { state: 0 }
-        }
 
-        fn next_u32(&mut self) -> u32 {
-            self.next() as u32
+    #[test]
+    fn java_random_reference_outputs() {
+        // Outputs of java.util.Random.
+        let mut rng = lcg::JavaRandom::new(0);
+        assert_eq!(rng.next_u32() as i32, -1155484576);
+        let mut rng = lcg::JavaRandom::new(0);
+        assert_eq!(rng.next() as i64, -4962768465676381896);
+        let mut rng = lcg::JavaRandom::new(42);
+        assert_eq!(rng.next_u32() as i32, -1170105035);
+        let mut rng = lcg::JavaRandom::new(0);
+        assert_eq!(rng.next_double(), 0.730967787376657);
+        let mut rng = lcg::JavaRandom::new(42);
+        let bounded: [i32; 5] = std::array::from_fn(|_| rng.next_int_bounded(10));
+        assert_eq!(bounded, [0, 3, 8, 4, 0]);
+        assert!((0..1000).all(|_| (0..1 << 20).contains(&rng.next_int_bounded(1 << 20))));
+    }
+
+    #[test]
+    fn generic_lcg() {
+        // The 10000th output of minstd_rand and minstd_rand0 of the C++ standard library, seeded with 1.
+        let mut rng = lcg::Minstd::new(1);
+        rng.advance(9999);
+        assert_eq!(rng.next_native(), 399268537);
+        let mut rng = lcg::Lcg::<16807, 0, 0x7fffffff>::new(1);
+        rng.advance(9999);
+        assert_eq!(rng.next_native(), 1043618065);
+        assert_eq!(lcg::Minstd::new(0).native_bits(), 31);
+        assert_eq!(lcg::Minstd::new(0), lcg::Minstd::new(1));
+        let mut reference = lcg::Mmix::new(5);
+        let mut rng = lcg::Lcg::<
+            { lcg::Mmix::MULTIPLIER as u128 },
+            { lcg::Mmix::INCREMENT as u128 },
+            { 1 << 64 },
+        >::new(5);
+        assert!((0..4).all(|_| rng.next() == reference.next()));
+        let mut reference = lcg::Lehmer64::new(5);
+        let mut rng = lcg::Lcg::<{ lcg::Lehmer64::MULTIPLIER }, 0, 0>::from_state(5 << 64 | 5);
+        assert!((0..4).all(|_| rng.next() == reference.next()));
+        let mut rng =
+            lcg::Lcg::<0x1234567890abcdef1234, 7, { (1 << 127) - 1 }>::from_state(1 << 100);
+        rng.advance(2);
+        assert_eq!(rng.next(), 11828455118910624938);
+    }
+
+    /// Check `advance` against single steps of `next_native`.
+    fn advance_matches_steps<R: RNG + PartialEq + std::fmt::Debug>(seed: u64, delta: u64) {
+        let mut stepped = R::new(seed);
+        for _ in 0..delta {
+            stepped.next_native();
         }
+        let mut advanced = R::new(seed);
+        advanced.advance(delta);
+        assert_eq!(stepped, advanced);
+    }
 
-        fn next(&mut self) -> u64 {
-            self.advance(1);
-            self.state
+    #[test]
+    fn lcg_jump_ahead() {
+        for delta in [0, 1, 2, 1000] {
+            advance_matches_steps::<lcg::Randu>(5, delta);
+            advance_matches_steps::<lcg::Mmix>(5, delta);
+            advance_matches_steps::<lcg::UlsLcg512>(5, delta);
+            advance_matches_steps::<lcg::UlsLcg512H>(5, delta);
+            advance_matches_steps::<lcg::Lehmer64>(5, delta);
+            advance_matches_steps::<lcg::JavaRandom>(5, delta);
+            advance_matches_steps::<lcg::Minstd>(5, delta);
+            advance_matches_steps::<lcg::Lcg<0x1234567890abcdef1234, 7, { (1 << 127) - 1 }>>(
+                5, delta,
+            );
         }
+        // Full periods return to the start.
+        let mut rng = lcg::JavaRandom::new(5);
+        rng.advance(1 << 48);
+        assert_eq!(rng, lcg::JavaRandom::new(5));
+        let mut rng = lcg::Randu::new(5);
+        rng.advance(1 << 29);
+        assert_eq!(rng, lcg::Randu::new(5));
+        let mut rng = lcg::Mmix::new(5);
+        rng.advance(u64::MAX);
+        rng.advance(1);
+        assert_eq!(rng, lcg::Mmix::new(5));
+        let mut rng = lcg::Minstd::new(5);
+        rng.advance(0x7ffffffe);
+        assert_eq!(rng, lcg::Minstd::new(5));
+        let mut twice = lcg::UlsLcg512::new(5);
+        twice.advance(1 << 48);
+        twice.advance(1 << 48);
+        let mut once = lcg::UlsLcg512::new(5);
+        once.advance(1 << 49);
+        assert_eq!(twice, once);
+        assert_eq!(
+            lcg::jump_coefficients(lcg::Mmix::MULTIPLIER as u128, 1, 1 << 64, 1 << 64),
+            (1, 0)
+        );
+    }
 
-        fn advance(&mut self, delta: usize) {
-            if delta & 1 == 1 {
-                self.state = !self.state;
-            }
+    #[test]
+    fn jsf_reference_outputs() {
+        // Outputs of the reference implementation for the seeds 0 and 1234567.
+        let mut rng = jsf::Jsf32::new(0);
+        let outputs: [u32; 4] = std::array::from_fn(|_| rng.next_u32());
+        assert_eq!(outputs, [446393351, 2589264021, 4046186614, 151173657]);
+        let mut rng = jsf::Jsf32::new(1234567);
+        let outputs: [u32; 4] = std::array::from_fn(|_| rng.next_u32());
+        assert_eq!(outputs, [3013890079, 2198592533, 2051900942, 74794898]);
+        let mut rng = jsf::Jsf64::new(0);
+        let outputs: [u64; 4] = std::array::from_fn(|_| rng.next());
+        assert_eq!(
+            outputs,
+            [
+                5420579327082221045,
+                12601856710328663849,
+                3486099297865454798,
+                9209813893562929851
+            ]
+        );
+        let mut rng = jsf::Jsf64::new(1234567);
+        let outputs: [u64; 4] = std::array::from_fn(|_| rng.next());
+        assert_eq!(
+            outputs,
+            [
+                693300800486250155,
+                15014887806406466148,
+                15216436932491731294,
+                17894305043982194963
+            ]
+        );
+    }
+
+    #[test]
+    fn mwc_reference_outputs() {
+        // Outputs of Marsaglia's CMWC4096 with Q[i] = (2654435769 i mod 2^32) mod (2^32 - 2) and c = 362436.
+        let mut rng = lcg::Cmwc4096::from_state(
+            std::array::from_fn(|i| (i as u32).wrapping_mul(2654435769) % 0xfffffffe),
+            362436,
+        );
+        let outputs: [u32; 4] = std::array::from_fn(|_| rng.next_u32());
+        assert_eq!(outputs, [4294604858, 367747001, 735501178, 1103255354]);
+        rng.advance(9995);
+        assert_eq!(rng.next_u32(), 2895785976);
+        let mut rng = lcg::Mwc1::from_state([123456789], 362436);
+        let outputs: [u32; 4] = std::array::from_fn(|_| rng.next_u32());
+        assert_eq!(outputs, [693968569, 3776248345, 1429218845, 44760066]);
+        let mut rng = lcg::Mwc::<3, 1791398085>::from_state([1, 2, 3], 4);
+        let outputs: [u32; 6] = std::array::from_fn(|_| rng.next_u32());
+        assert_eq!(
+            outputs,
+            [1791398089, 3582796170, 1079226959, 297040558, 4189944091, 2363538073]
+        );
+    }
+
+    #[test]
+    fn tyche_inverse_outputs() {
+        // Tyche-i runs the rounds of Tyche backwards, from the state three Tyche rounds after [1, 2, 3, 4]
+        // it outputs the first words of the earlier states.
+        let mut rng = tyche::TycheRng::from_state([1, 2, 3, 4]);
+        let outputs: [u32; 3] = std::array::from_fn(|_| rng.next_u32());
+        assert_eq!(outputs, [2341059003, 423917475, 1856689714]);
+        assert_eq!(
+            rng,
+            tyche::TycheRng::from_state([425638129, 1856689714, 1814582342, 1682135891])
+        );
+        let mut rng = tyche::TycheI::from_state([425638129, 1856689714, 1814582342, 1682135891]);
+        let outputs: [u32; 3] = std::array::from_fn(|_| rng.next_u32());
+        assert_eq!(outputs, [4146413412, 1879052291, 1]);
+        assert_ne!(
+            tyche::TycheI::with_stream(5, 0).next(),
+            tyche::TycheI::with_stream(5, 1).next()
+        );
+    }
+
+    #[test]
+    fn parameterized_xorshift() {
+        // Outputs of the code in Marsaglia's paper with its seeds.
+        let parameters = xorshift::XorshiftParameters::XORSHIFT64;
+        let mut rng = xorshift::Xorshift::from_state(88172645463325252, parameters).unwrap();
+        let outputs: [u64; 3] = std::array::from_fn(|_| rng.next());
+        assert_eq!(
+            outputs,
+            [
+                8748534153485358512,
+                3040900993826735515,
+                3453997556048239312
+            ]
+        );
+        let parameters = xorshift::XorshiftParameters::XORSHIFT32;
+        let mut rng = xorshift::Xorshift::from_state(2463534242, parameters).unwrap();
+        let outputs: [u32; 3] = std::array::from_fn(|_| rng.next_u32());
+        assert_eq!(outputs, [723471715, 2497366906, 2064144800]);
+        rng.reseed(3);
+        assert_eq!(rng.parameters(), parameters);
+        assert!(xorshift::XorshiftParameters::XORSHIFT64.has_full_period());
+        assert!(parameters.has_full_period());
+        let mut weak = parameters;
+        weak.shifts = [1, 1, 1];
+        assert!(!weak.has_full_period());
+        // The scramblers only change the output, not the state sequence.
+        let mut plain = xorshift::Xorshift::from_state(5, parameters).unwrap();
+        let mut scrambled = parameters;
+        scrambled.scrambler = xorshift::Scrambler::Plus;
+        let mut plus = xorshift::Xorshift::from_state(5, scrambled).unwrap();
+        let (first, second) = (plain.next_u32(), plain.next_u32());
+        plus.next_u32();
+        assert_eq!(plus.next_u32(), first.wrapping_add(second));
+        scrambled.scrambler = xorshift::Scrambler::Multiply(0x2545f4914f6cdd1d);
+        let mut star = xorshift::Xorshift::from_state(5, scrambled).unwrap();
+        assert_eq!(
+            star.next_u32(),
+            (first as u64).wrapping_mul(0x2545f4914f6cdd1d) as u32
+        );
+        scrambled.scrambler = xorshift::Scrambler::Multiply(2);
+        assert!(scrambled.validate().is_err());
+        weak.shifts = [13, 32, 5];
+        assert!(weak.validate().is_err());
+        assert!(xorshift::Xorshift::from_state(1 << 32, parameters).is_err());
+    }
+
+    #[test]
+    fn xorwow_reference_outputs() {
+        // Outputs of the code in Marsaglia's paper, which curand_init reaches with the seed 0xf7dcefddaad26b49.
+        let mut rng = xorshift::Xorwow::from_state(
+            xorshift::Xorwow::DEFAULT_STATE,
+            xorshift::Xorwow::DEFAULT_WEYL,
+        );
+        assert_eq!(rng, xorshift::Xorwow::new(0xf7dcefddaad26b49));
+        let outputs: [u32; 4] = std::array::from_fn(|_| rng.next_u32());
+        assert_eq!(outputs, [246875399, 3690007200, 1264581005, 3906711041]);
+    }
+
+    #[test]
+    fn kiss_reference_outputs() {
+        // Outputs of the reference implementations with their default seeds.
+        let mut rng = kiss::Kiss99::from_state(kiss::Kiss99::DEFAULT_STATE);
+        let outputs: [u32; 4] = std::array::from_fn(|_| rng.next_u32());
+        assert_eq!(outputs, [769445856, 742012328, 2121196314, 2805620942]);
+        rng.advance(999995);
+        assert_eq!(rng.next_u32(), 2711819028);
+        let mut rng = kiss::Jkiss::from_state(kiss::Jkiss::DEFAULT_STATE);
+        let outputs: [u32; 4] = std::array::from_fn(|_| rng.next_u32());
+        assert_eq!(outputs, [560241513, 2602615593, 2542353780, 3322652092]);
+        let mut rng = kiss::Kiss64::from_state(kiss::Kiss64::DEFAULT_STATE);
+        let outputs: [u64; 4] = std::array::from_fn(|_| rng.next());
+        assert_eq!(
+            outputs,
+            [
+                8932985056925012148,
+                5710300428094272059,
+                18342510866933518593,
+                14303636270573868250
+            ]
+        );
+    }
+
+    #[test]
+    fn msws_reference_outputs() {
+        // Outputs of the reference implementations, squares with the key 0xc58efd154ce32f6d.
+        let mut rng = msws::Msws::from_state(0, 0, msws::DEFAULT_INCREMENT);
+        let outputs: [u32; 4] = std::array::from_fn(|_| rng.next_u32());
+        assert_eq!(outputs, [3048033998, 3746490460, 411637087, 3336355023]);
+        let key = 0xc58efd154ce32f6d;
+        let outputs: [u32; 4] = std::array::from_fn(|i| msws::squares32(i as u64, key));
+        assert_eq!(outputs, [2203244565, 1298422897, 1600539444, 1426199198]);
+        let outputs: [u64; 4] = std::array::from_fn(|i| msws::squares64(i as u64, key));
+        assert_eq!(
+            outputs,
+            [
+                9462863352113132047,
+                5576683879226033212,
+                6874264570996313208,
+                6125478917032950016
+            ]
+        );
+        assert_eq!(msws::squares64(u64::MAX, key), 9932901355575921035);
+        let mut rng = msws::Squares32::from_key_counter(key, 2);
+        assert_eq!(rng.next_u32(), 1600539444);
+    }
+
+    #[test]
+    fn siphash_reference_outputs() {
+        // Test vectors of the reference implementations, the key is the bytes 0, 1, ..., 15.
+        let key = [0x0706050403020100, 0x0f0e0d0c0b0a0908];
+        let message: Vec<u8> = (0..15).collect();
+        assert_eq!(siphash::siphash24(key, &[]), 0x726fdb47dd0e0e31);
+        assert_eq!(siphash::siphash24(key, &message[..8]), 0x93f5f5799a932462);
+        assert_eq!(siphash::siphash24(key, &message), 0xa129ca6149be45e5);
+        assert_eq!(siphash::siphash13(key, &[]), 0xabac0158050fc4dc);
+        let mut rng = siphash::SipHash24Rng::from_key_counter(key, 7);
+        assert_eq!(rng.next(), siphash::siphash24(key, &7u128.to_le_bytes()));
+    }
+
+    #[test]
+    fn xoshiro_reference_outputs() {
+        // Outputs of the reference implementation for the state [1, 2, 3, 4].
+        let mut star_star = xoshiro::Xoshiro256StarStar::from_state([1, 2, 3, 4]);
+        let outputs: [u64; 6] = std::array::from_fn(|_| star_star.next());
+        assert_eq!(
+            outputs,
+            [
+                11520,
+                0,
+                1509978240,
+                1215971899390074240,
+                1216172134540287360,
+                607988272756665600
+            ]
+        );
+        let mut plus_plus = xoshiro::Xoshiro256PlusPlus::from_state([1, 2, 3, 4]);
+        let outputs: [u64; 6] = std::array::from_fn(|_| plus_plus.next());
+        assert_eq!(
+            outputs,
+            [
+                41943041,
+                58720359,
+                3588806011781223,
+                3591011842654386,
+                9228616714210784205,
+                9973669472204895162
+            ]
+        );
+    }
+
+    #[test]
+    fn xoshiro_jumps() {
+        let fresh = xoshiro::Xoshiro256StarStar::new(5);
+        let mut jumped = fresh;
+        jumped.jump();
+        let mut long_jumped = fresh;
+        long_jumped.long_jump();
+        assert_ne!(jumped, fresh);
+        assert_ne!(long_jumped, fresh);
+        assert_ne!(long_jumped, jumped);
+        // Jumps are powers of the state transition, so they commute with stepping.
+        let mut stepped = fresh;
+        stepped.advance(3);
+        stepped.jump();
+        jumped.advance(3);
+        assert_eq!(stepped, jumped);
+        let mut stepped = xoshiro::Xoshiro256PlusPlus::new(5);
+        stepped.advance(3);
+        stepped.long_jump();
+        let mut long_jumped = xoshiro::Xoshiro256PlusPlus::new(5);
+        long_jumped.long_jump();
+        long_jumped.advance(3);
+        assert_eq!(stepped, long_jumped);
+    }
+
+    #[test]
+    fn gf2_jump_ahead() {
+        // Characteristic polynomials from the output bits, which are linear in the state.
+        let mut rng = xorshift::XORShift128::new(5);
+        let characteristic = gf2::minimal_polynomial(|| rng.next_u32() & 1 == 1, 128);
+        assert_eq!(
+            characteristic,
+            (
+                xorshift::XORShift128::CHARACTERISTIC_POLYNOMIAL.to_vec(),
+                128
+            )
+        );
+        assert_eq!(
+            gf2::jump_polynomial(&[0, 1], &characteristic.0, 128),
+            xorshift::XORShift128::JUMP
+        );
+        let mut rng = xorshift::Xorwow::from_state([1, 2, 3, 4, 5], 0);
+        let mut weyl: u32 = 0;
+        let characteristic = gf2::minimal_polynomial(
+            || {
+                weyl = weyl.wrapping_add(xorshift::Xorwow::WEYL_INCREMENT);
+                rng.next_u32().wrapping_sub(weyl) & 1 == 1
+            },
+            160,
+        );
+        assert_eq!(
+            characteristic,
+            (xorshift::Xorwow::CHARACTERISTIC_POLYNOMIAL.to_vec(), 160)
+        );
+        assert_eq!(
+            gf2::jump_polynomial(&[0, 1], &characteristic.0, 160),
+            xorshift::Xorwow::JUMP
+        );
+        // xoshiro256** outputs an invertible function of the second word.
+        let mut rng = xoshiro::Xoshiro256StarStar::new(5);
+        let characteristic = gf2::minimal_polynomial(
+            || {
+                let word = rng
+                    .next()
+                    .wrapping_mul(0x8e38e38e38e38e39)
+                    .rotate_right(7)
+                    .wrapping_mul(0xcccccccccccccccd);
+                word & 1 == 1
+            },
+            256,
+        );
+        assert_eq!(
+            characteristic,
+            (xoshiro::CHARACTERISTIC_POLYNOMIAL.to_vec(), 256)
+        );
+        // The published jump constants of the reference implementation.
+        assert_eq!(
+            gf2::jump_polynomial(&[0, 0, 1], &characteristic.0, 256),
+            xoshiro::JUMP
+        );
+        assert_eq!(
+            gf2::jump_polynomial(&[0, 0, 0, 1], &characteristic.0, 256),
+            xoshiro::LONG_JUMP
+        );
+
+        for delta in [0, 1, 1000] {
+            advance_matches_steps::<xorshift::XORShift128>(5, delta);
+            advance_matches_steps::<xorshift::Xorwow>(5, delta);
+            advance_matches_steps::<xorshift::Xorshift>(5, delta);
+            advance_matches_steps::<xoshiro::Xoshiro256StarStar>(5, delta);
+            advance_matches_steps::<xoshiro::Xoshiro256PlusPlus>(5, delta);
+            advance_matches_steps::<lfsr::Fibonacci<u32>>(5, delta);
+            advance_matches_steps::<lfsr::Galois<u64>>(5, delta);
+            advance_matches_steps::<lfsr::Fibonacci<u128>>(5, delta);
         }
+        let mut rng =
+            xorshift::Xorshift::with_parameters(5, xorshift::XorshiftParameters::XORSHIFT32)
+                .unwrap();
+        let mut stepped = rng;
+        rng.advance(1000);
+        (0..1000).for_each(|_| {
+            stepped.next_native();
+        });
+        assert_eq!(rng, stepped);
 
-        fn reseed(&mut self, _seed: u64) {}
+        let mut jumped = xorshift::XORShift128::new(5);
+        jumped.jump();
+        let mut advanced = xorshift::XORShift128::new(5);
+        advanced.advance(1 << 63);
+        advanced.advance(1 << 63);
+        assert_eq!(jumped, advanced);
+        let mut jumped = xorshift::Xorwow::new(5);
+        jumped.jump();
+        let mut advanced = xorshift::Xorwow::new(5);
+        advanced.advance(1 << 63);
+        advanced.advance(1 << 63);
+        assert_eq!(jumped, advanced);
+        let mut twice = xoshiro::Xoshiro256PlusPlus::new(5);
+        twice.advance(1 << 62);
+        twice.advance(1 << 62);
+        let mut once = xoshiro::Xoshiro256PlusPlus::new(5);
+        once.advance(1 << 63);
+        assert_eq!(twice, once);
+        // Full periods return to the start.
+        let mut rng = xorshift::Xorshift::new(5);
+        rng.advance(u64::MAX);
+        assert_eq!(rng, xorshift::Xorshift::new(5));
+        let mut rng = lfsr::Galois::<u32>::new(5);
+        rng.advance(u32::MAX as u64);
+        assert_eq!(rng, lfsr::Galois::<u32>::new(5));
+        let mut rng = lfsr::Fibonacci::<u64>::new(5);
+        rng.advance(u64::MAX);
+        assert_eq!(rng, lfsr::Fibonacci::<u64>::new(5));
     }
 
-    #[derive(Debug, Copy, Clone)]
-    pub struct AlternatingBytes {}
-    impl RNG for AlternatingBytes {
-        fn new(_seed: u64) -> Self {
-            AlternatingBytes {}
+    #[test]
+    fn pcg32_reference_outputs() {
+        // Output of the pcg32-demo program of the reference implementation.
+        let mut rng = pcg::Pcg32::with_stream(42, 54);
+        let outputs: [u32; 6] = std::array::from_fn(|_| rng.next_u32());
+        assert_eq!(
+            outputs,
+            [0xa15c02b7, 0x7b47f409, 0xba1d3330, 0x83d2f293, 0xbfa4784b, 0xcbed606e]
+        );
+    }
+
+    #[test]
+    fn pcg32_advance() {
+        let mut stepped = pcg::Pcg32::new(5);
+        for _ in 0..1000 {
+            let _ = stepped.next_u32();
         }
+        let mut advanced = pcg::Pcg32::new(5);
+        advanced.advance(1000);
+        assert_eq!(advanced, stepped);
+        // The LCG has a period of 2^64.
+        advanced.advance(u64::MAX);
+        advanced.advance(1);
+        assert_eq!(advanced, stepped);
+        assert_ne!(pcg::Pcg32::with_stream(5, 1), pcg::Pcg32::with_stream(5, 2));
+    }
 
-        fn next_u32(&mut self) -> u32 {
-            0xff00ff00
+    #[test]
+    fn pcg64_reference_outputs() {
+        // Output of the pcg64-demo program of the reference implementation.
+        let mut rng = pcg::Pcg64::with_stream(42, 54);
+        let outputs: [u64; 6] = std::array::from_fn(|_| rng.next());
+        assert_eq!(
+            outputs,
+            [
+                0x86b1da1d72062b68,
+                0x1304aa46c9853d39,
+                0xa3670e9e0dd50358,
+                0xf9090e529a7dae00,
+                0xc85b9fd837996f2c,
+                0x606121f8e3919196
+            ]
+        );
+    }
+
+    #[test]
+    fn pcg64_advance() {
+        let mut stepped = pcg::Pcg64::new(5);
+        let mut stepped_dxsm = pcg::Pcg64Dxsm::new(5);
+        for _ in 0..1000 {
+            let _ = stepped.next();
+            let _ = stepped_dxsm.next();
         }
+        let mut advanced = pcg::Pcg64::new(5);
+        advanced.advance(1000);
+        assert_eq!(advanced, stepped);
+        let mut advanced_dxsm = pcg::Pcg64Dxsm::new(5);
+        advanced_dxsm.advance(1000);
+        assert_eq!(advanced_dxsm, stepped_dxsm);
+        // The LCGs have a period of 2^128.
+        advanced.advance_u128(u128::MAX);
+        advanced.advance(1);
+        assert_eq!(advanced, stepped);
+        advanced_dxsm.advance_u128(u128::MAX);
+        advanced_dxsm.advance(1);
+        assert_eq!(advanced_dxsm, stepped_dxsm);
+    }
 
-        fn next(&mut self) -> u64 {
-            0xff00ff00ff00ff00
+    fn check_hardware<const SEED: bool>() {
+        let mut rng = hardware::Hardware::<SEED>::new(0);
+        assert!(!rng.is_deterministic());
+        if !rng.is_available() {
+            assert!(rng.try_next().is_err());
+            return;
         }
+        // Four equal 64 bit outputs would be a broken instruction.
+        let outputs: [u64; 4] = std::array::from_fn(|_| rng.next());
+        assert!(outputs.windows(2).any(|pair| pair[0] != pair[1]));
+    }
 
-        fn advance(&mut self, _delta: usize) {}
+    #[test]
+    fn hardware_outputs() {
+        check_hardware::<false>();
+        check_hardware::<true>();
+    }
 
-        fn reseed(&mut self, _seed: u64) {}
+    #[test]
+    fn os_entropy_outputs() {
+        let mut rng = OsEntropy::new(0);
+        assert!(!rng.is_deterministic());
+        // Crosses a read from the operating system.
+        let outputs: Vec<u64> = (0..OS_ENTROPY_WORDS + 4).map(|_| rng.next()).collect();
+        assert!(outputs.windows(2).all(|pair| pair[0] != pair[1]));
     }
 
-    #[derive(Debug, Copy, Clone)]
-    pub struct AlternatingBits {}
-    impl RNG for AlternatingBits {
-        fn new(_seed: u64) -> Self {
-            AlternatingBits {}
+    #[test]
+    fn registry_factories() {
+        let names: Vec<String> = registry()
+            .map(|(name, _, _)| name.to_ascii_lowercase())
+            .collect();
+        for (i, name) in names.iter().enumerate() {
+            assert!(!names[i + 1..].contains(name), "Duplicate name: {}", name);
         }
+        let (_, metadata, factory) = registry().find(|&(name, _, _)| name == "WyRand").unwrap();
+        assert_eq!(metadata.family, "xorshift");
+        assert_eq!(metadata.state_bytes, 8);
+        let mut boxed = factory(GOLDEN_SEED);
+        let (outputs, _) = crate::stats::generate_test_data(boxed.as_mut(), 3);
+        assert_eq!(outputs, first_outputs::<xorshift::WyRand>());
+        assert!(format_registry().contains("RANDU-Native"));
+    }
 
-        fn next_u32(&mut self) -> u32 {
-            0x55555555
-        }
+    #[test]
+    fn advance_large_delta_counter() {
+        let mut advanced = stream_nlarx::StreamNLARXu128::new(5);
+        advanced.advance(LARGE_DELTA);
+        let mut seeked = stream_nlarx::StreamNLARXu128::new(5);
+        seeked.seek(LARGE_DELTA as u128);
+        assert_eq!(advanced, seeked);
+        assert_eq!(advanced.position(), LARGE_DELTA as u128);
+        let mut wide = spn::RijndaelStream::new(5);
+        wide.advance_u128(LARGE_DELTA as u128);
+        let mut narrow = spn::RijndaelStream::new(5);
+        narrow.advance(LARGE_DELTA);
+        assert_eq!(wide, narrow);
+    }
 
-        fn next(&mut self) -> u64 {
-            0x5555555555555555
+    fn check_streams<R: Streamed + Clone + PartialEq + std::fmt::Debug>() {
+        let mut first = R::new(GOLDEN_SEED);
+        first.set_stream(1);
+        let mut second = R::new(GOLDEN_SEED);
+        second.set_stream(2);
+        let mut again = second.clone();
+        again.set_stream(2);
+        assert_eq!(again, second);
+        let first_outputs: Vec<u64> = (0..8).map(|_| first.next()).collect();
+        let second_outputs: Vec<u64> = (0..8).map(|_| second.next()).collect();
+        assert_ne!(first_outputs, second_outputs);
+    }
+
+    #[test]
+    fn streamed_generators() {
+        check_streams::<pcg::Pcg32>();
+        check_streams::<pcg::Pcg64>();
+        check_streams::<pcg::Pcg64Dxsm>();
+        check_streams::<spn::RijndaelStream>();
+        check_streams::<stream::ChaCha8>();
+        check_streams::<stream_nlarx::StreamNLARXu128>();
+        let mut pcg = pcg::Pcg64::from_state(0, 0);
+        pcg.set_stream(2);
+        assert_eq!(pcg, pcg::Pcg64::from_state(0, 5));
+        let mut nlarx = stream_nlarx::StreamNLARXu128::new(5);
+        nlarx.set_stream(7);
+        assert_eq!(nlarx, stream_nlarx::StreamNLARXu128::new(7));
+        let mut rijndael = spn::RijndaelStream::new(5);
+        rijndael.set_stream(0);
+        rijndael.set_stream(!5);
+        assert_eq!(rijndael, spn::RijndaelStream::new(5));
+    }
+
+    #[test]
+    fn seekable_registry() {
+        let mut seekable: Vec<&str> = vec![];
+        for (name, _, factory) in registry() {
+            let mut rng = factory(5);
+            if let Some(consistent) = crate::rng_testing::seek_consistency(rng.as_mut(), 5) {
+                assert!(consistent, "{}", name);
+                seekable.push(name);
+            }
         }
+        for name in [
+            "StreamNLARXu128",
+            "SplitMix64",
+            "Philox4x32",
+            "Threefry4x64",
+            "Squares32",
+            "SipHash13",
+        ] {
+            assert!(seekable.contains(&name), "{}", name);
+        }
+        assert!(!seekable.contains(&"Xoshiro256StarStar"));
+        let mut splitmix = splitmix::SplitMix64::new(0);
+        splitmix.advance(12345);
+        assert_eq!(splitmix.position(), 12345);
+    }
 
-        fn advance(&mut self, _delta: usize) {}
+    fn check_state_round_trip<R: RNG + PartialEq>() {
+        let name = std::any::type_name::<R>();
+        let mut rng = R::new(GOLDEN_SEED);
+        assert_eq!(
+            crate::rng_testing::state_round_trip(&mut rng, GOLDEN_SEED),
+            Some(true),
+            "{}",
+            name
+        );
+        let bytes = rng.state_bytes().unwrap();
+        assert!(R::from_state_bytes(&bytes[1..]).is_err(), "{}", name);
+        let mut longer = bytes.clone();
+        longer.push(0);
+        assert!(R::from_state_bytes(&longer).is_err(), "{}", name);
+    }
 
-        fn reseed(&mut self, _seed: u64) {}
+    #[test]
+    fn state_round_trips() {
+        check_state_round_trip::<stream_nlarx::StreamNLARXu128>();
+        check_state_round_trip::<tyche::TycheRng>();
+        check_state_round_trip::<tyche::TycheI>();
+        check_state_round_trip::<xorshift::XORShift128>();
+        check_state_round_trip::<xorshift::Xorwow>();
+        check_state_round_trip::<xorshift::RapidHashRNG>();
+        check_state_round_trip::<xorshift::WyRand>();
+        check_state_round_trip::<xorshift::RapidHashRNG2>();
+        check_state_round_trip::<jsf::Jsf32>();
+        check_state_round_trip::<jsf::Jsf64>();
+        check_state_round_trip::<gjrand::Gjrand64>();
+        check_state_round_trip::<splitmix::SplitMix64>();
+        check_state_round_trip::<xoshiro::Xoshiro256StarStar>();
+        check_state_round_trip::<xoshiro::Xoshiro256PlusPlus>();
+        check_state_round_trip::<lcg::Randu>();
+        check_state_round_trip::<lcg::Mmix>();
+        check_state_round_trip::<lcg::UlsLcg512>();
+        check_state_round_trip::<lcg::UlsLcg512H>();
+        check_state_round_trip::<lcg::Lehmer64>();
+        check_state_round_trip::<lcg::JavaRandom>();
+        check_state_round_trip::<lcg::Minstd>();
+        check_state_round_trip::<lcg::Mwc1>();
+        check_state_round_trip::<lcg::Cmwc4096>();
+        check_state_round_trip::<kiss::Kiss99>();
+        check_state_round_trip::<kiss::Jkiss>();
+        check_state_round_trip::<kiss::Kiss64>();
+        check_state_round_trip::<pcg::Pcg32>();
+        check_state_round_trip::<pcg::Pcg64>();
+        check_state_round_trip::<pcg::Pcg64Dxsm>();
+        check_state_round_trip::<mt::Mt19937>();
+        check_state_round_trip::<mt::Mt19937_64>();
+        check_state_round_trip::<well::Well512a>();
+        check_state_round_trip::<lfsr::Fibonacci<u64>>();
+        check_state_round_trip::<lfsr::Galois<u128>>();
+        check_state_round_trip::<philox::Philox4x32>();
+        check_state_round_trip::<threefry::Threefry4x64>();
+        check_state_round_trip::<msws::Squares64>();
+        check_state_round_trip::<siphash::SipHash13Rng>();
+        check_state_round_trip::<msws::Msws>();
+        check_state_round_trip::<spn::RijndaelStream>();
+        check_state_round_trip::<stream::ChaCha20>();
+        check_state_round_trip::<stream::Isaac>();
+        check_state_round_trip::<stream::Rc4>();
+    }
+
+    #[test]
+    fn state_import_validation() {
+        let unsupported = xorshift::Xorshift::new(5);
+        assert_eq!(unsupported.state_bytes(), None);
+        assert!(xorshift::Xorshift::from_state_bytes(&[]).is_err());
+        let minstd = state::encode(&0x7fffffffu128);
+        assert!(lcg::Minstd::from_state_bytes(&minstd).is_err());
+        let mut bytes = mt::Mt19937::new(5).state_bytes().unwrap();
+        let index_offset = bytes.len() - 8;
+        bytes[index_offset..].copy_from_slice(&625u64.to_le_bytes());
+        assert!(mt::Mt19937::from_state_bytes(&bytes).is_err());
+        // An exhausted buffer is valid, it is twisted before the next output.
+        bytes[index_offset..].copy_from_slice(&624u64.to_le_bytes());
+        assert!(mt::Mt19937::from_state_bytes(&bytes).is_ok());
+        let zero = state::encode(&(0u64, lfsr::Galois::<u64>::new(5).polynomial()));
+        assert!(lfsr::Galois::<u64>::from_state_bytes(&zero).is_err());
+    }
+
+    #[test]
+    fn advance_wraps_around() {
+        let fresh = xorshift::RapidHashRNG::new(5);
+        let mut wrapped = fresh;
+        wrapped.advance(u64::MAX);
+        wrapped.advance(1);
+        assert_eq!(wrapped, fresh);
+        let mut counter = stream_nlarx::StreamNLARXu128::new(5);
+        counter.advance(u64::MAX);
+        counter.advance(1);
+        assert_eq!(counter, stream_nlarx::StreamNLARXu128::new(5));
+        let mut block_counter = spn::RijndaelStream::new(5);
+        block_counter.advance_u128(u128::MAX);
+        block_counter.advance(1);
+        assert_eq!(block_counter, spn::RijndaelStream::new(5));
+    }
+
+    #[test]
+    fn advance_wrapper_does_not_overflow() {
+        // Two inner steps per step, 2 * u64::MAX wraps to -2 in the inner counter.
+        let mut folded = XorFold::<xorshift::RapidHashRNG>::new(5);
+        folded.advance(u64::MAX);
+        folded.advance(1);
+        assert_eq!(folded, XorFold::<xorshift::RapidHashRNG>::new(5));
     }
 }
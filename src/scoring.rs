@@ -0,0 +1,164 @@
+// Copyright 2025 N. Dornseif
+//
+// Dual-licensed under Apache 2.0 and MIT terms.
+
+//! Scores derived from p-values for display.
+//! Verdicts are always based on the p log stat,
+//! the selected scoring only changes the displayed and reported score.
+
+use std::{
+    ops::Mul,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use statrs::distribution::{ContinuousCDF, Normal};
+
+/// Largest p log stat, also assigned to invalid p-values.
+pub const P_LOG_STAT_MAX: f64 = 9.9999;
+/// Largest magnitude of a z-score.
+const Z_SCORE_MAX: f64 = 9.9999;
+
+/// Transform of a p-value into a score.
+/// Except for the p log stat, invalid p-values (NaN or outside 0-1) score NaN.
+pub trait Scoring: Sync {
+    /// Stable identifier, used on the command line and in reports.
+    fn name(&self) -> &'static str;
+    /// Short column label in the text output.
+    fn label(&self) -> &'static str;
+    fn score(&self, p: f64) -> f64;
+}
+
+/// -0.2 * (log2(min(p, 1-p)) - 1), the default.
+pub struct PLogStat;
+/// The p-value itself.
+pub struct RawP;
+/// -log10(min(p, 1-p)).
+pub struct NegLog10;
+/// Standard normal quantile of 1-p, large for p near 0 and negative for p near 1.
+pub struct ZScore;
+
+/// Logarithmic quantity to specify how close to 1.0 or 0.0 a p-value is.
+/// Has a range of 0.4-9.9999.
+/// -0.2 * (log2(min(p, 1-p)) - 1) clamped to 9.9999
+/// Invalid p-values (NaN or outside 0-1) map to 9.9999 and fail.
+pub fn p_log_stat(p: f64) -> f64 {
+    if !(0.0..=1.0).contains(&p) {
+        return P_LOG_STAT_MAX;
+    }
+    (p.min(1.0 - p).log2() - 1.0).mul(-0.2).min(P_LOG_STAT_MAX)
+}
+
+impl Scoring for PLogStat {
+    fn name(&self) -> &'static str {
+        "pls"
+    }
+    fn label(&self) -> &'static str {
+        "pls"
+    }
+    fn score(&self, p: f64) -> f64 {
+        p_log_stat(p)
+    }
+}
+
+impl Scoring for RawP {
+    fn name(&self) -> &'static str {
+        "p"
+    }
+    fn label(&self) -> &'static str {
+        "p"
+    }
+    fn score(&self, p: f64) -> f64 {
+        p
+    }
+}
+
+impl Scoring for NegLog10 {
+    fn name(&self) -> &'static str {
+        "neglog10"
+    }
+    fn label(&self) -> &'static str {
+        "-log10"
+    }
+    /// p-values of exactly 0 or 1 are clamped to the smallest positive f64.
+    fn score(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            return f64::NAN;
+        }
+        -p.min(1.0 - p).max(f64::MIN_POSITIVE).log10()
+    }
+}
+
+impl Scoring for ZScore {
+    fn name(&self) -> &'static str {
+        "z"
+    }
+    fn label(&self) -> &'static str {
+        "z"
+    }
+    fn score(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            return f64::NAN;
+        }
+        let standard_normal = Normal::standard();
+        standard_normal
+            .inverse_cdf(1.0 - p)
+            .clamp(-Z_SCORE_MAX, Z_SCORE_MAX)
+    }
+}
+
+/// All scorings, the first one is the default.
+pub const SCORINGS: [&dyn Scoring; 4] = [&PLogStat, &RawP, &NegLog10, &ZScore];
+
+static SELECTED_SCORING: AtomicUsize = AtomicUsize::new(0);
+
+/// Find a scoring by its name.
+pub fn from_name(name: &str) -> Option<&'static dyn Scoring> {
+    SCORINGS
+        .iter()
+        .find(|scoring| scoring.name() == name)
+        .copied()
+}
+
+/// Select the scoring used by the test suite.
+pub fn select(scoring: &dyn Scoring) {
+    if let Some(index) = SCORINGS.iter().position(|s| s.name() == scoring.name()) {
+        SELECTED_SCORING.store(index, Ordering::Relaxed);
+    }
+}
+
+/// The scoring used by the test suite.
+pub fn selected() -> &'static dyn Scoring {
+    SCORINGS[SELECTED_SCORING.load(Ordering::Relaxed)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scoring_values() {
+        assert_eq!(PLogStat.score(0.5), 0.4);
+        assert_eq!(RawP.score(0.25), 0.25);
+        assert!((NegLog10.score(0.001) - 3.0).abs() < 1e-12);
+        assert!((NegLog10.score(0.999) - 3.0).abs() < 1e-9);
+        assert!(NegLog10.score(0.0).is_finite());
+        assert!(ZScore.score(0.5).abs() < 1e-12);
+        assert!((ZScore.score(0.025) - 1.959964).abs() < 1e-6);
+        assert!((ZScore.score(0.975) + 1.959964).abs() < 1e-6);
+        assert_eq!(ZScore.score(0.0), Z_SCORE_MAX);
+        assert_eq!(ZScore.score(1.0), -Z_SCORE_MAX);
+        for scoring in [&NegLog10 as &dyn Scoring, &ZScore] {
+            assert!(scoring.score(f64::NAN).is_nan());
+            assert!(scoring.score(1.5).is_nan());
+        }
+    }
+
+    #[test]
+    fn scoring_names() {
+        for scoring in SCORINGS {
+            assert_eq!(from_name(scoring.name()).unwrap().name(), scoring.name());
+        }
+        assert!(from_name("chi").is_none());
+        assert_eq!(selected().name(), "pls");
+    }
+}
@@ -0,0 +1,471 @@
+// Copyright 2025 N. Dornseif
+//
+// Dual-licensed under Apache 2.0 and MIT terms.
+
+//! Spectral test of linear congruential generators.
+//! Computes the length of the shortest vector ν_t of the dual lattice
+//! formed by t consecutive outputs, following Knuth's Algorithm S (TAOCP 3.3.4).
+//! 1 / ν_t is the distance between the hyperplanes that cover all output tuples,
+//! so a small ν_t means the outputs lie on few hyperplanes.
+
+use bnum::cast::As;
+use bnum::types::I512;
+
+use crate::rngs::lcg;
+
+/// Highest dimension with a known Hermite constant to normalize against.
+pub const MAX_DIMENSION: usize = 8;
+/// Hermite constants γ_t^t for t = 2..=8.
+const HERMITE_CONSTANTS_POWER: [f64; MAX_DIMENSION - 1] =
+    [4.0 / 3.0, 2.0, 4.0, 8.0, 64.0 / 3.0, 64.0, 256.0];
+
+/// Intermediate values reach about t * m^3, 512 bits are enough for moduli up to 2^128.
+pub(crate) type Int = I512;
+
+/// Modulus of an LCG.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Modulus {
+    /// 2^k with k at most 128.
+    PowerOfTwo(u32),
+    Value(u128),
+}
+
+impl Modulus {
+    fn to_int(self) -> Int {
+        match self {
+            Modulus::PowerOfTwo(bits) => Int::ONE << bits,
+            Modulus::Value(value) => Int::from(value),
+        }
+    }
+
+    fn log2(self) -> f64 {
+        match self {
+            Modulus::PowerOfTwo(bits) => bits as f64,
+            Modulus::Value(value) => (value as f64).log2(),
+        }
+    }
+}
+
+/// Parameters of the LCG x -> (multiplier * x + increment) mod modulus.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct LcgParameters {
+    pub multiplier: u128,
+    pub increment: u128,
+    pub modulus: Modulus,
+}
+
+impl LcgParameters {
+    /// Checks the Hull–Dobell conditions for a power of two modulus of at least 8.
+    /// With an increment the period is the full modulus,
+    /// without one it is a quarter of the modulus for odd seeds.
+    /// Returns `None` for other moduli.
+    pub fn has_maximal_period(&self) -> Option<bool> {
+        match self.modulus {
+            Modulus::PowerOfTwo(bits) if bits >= 3 => Some(if self.increment == 0 {
+                self.multiplier % 8 == 3 || self.multiplier % 8 == 5
+            } else {
+                self.increment % 2 == 1 && self.multiplier % 4 == 1
+            }),
+            _ => None,
+        }
+    }
+
+    /// Parameters of `lcg::Lcg<A, C, M>`.
+    pub const fn of<const A: u128, const C: u128, const M: u128>() -> Self {
+        LcgParameters {
+            multiplier: A,
+            increment: C,
+            modulus: if M == 0 {
+                Modulus::PowerOfTwo(128)
+            } else if M.is_power_of_two() {
+                Modulus::PowerOfTwo(M.trailing_zeros())
+            } else {
+                Modulus::Value(M)
+            },
+        }
+    }
+}
+
+/// Spectral test result for one dimension.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SpectralResult {
+    pub dimension: usize,
+    /// ν_t squared, exact as long as it fits the f64 mantissa.
+    pub nu_squared: f64,
+    /// ν_t divided by its upper bound for the modulus, between 0 and 1.
+    /// Values below 0.1 indicate a bad multiplier.
+    pub figure_of_merit: f64,
+}
+
+impl SpectralResult {
+    pub fn nu(&self) -> f64 {
+        self.nu_squared.sqrt()
+    }
+}
+
+pub(crate) fn dot(x: &[Int], y: &[Int]) -> Int {
+    x.iter().zip(y).fold(Int::ZERO, |sum, (a, b)| sum + *a * *b)
+}
+
+/// Rounds n / d to the nearest integer, d must be positive.
+pub(crate) fn round_div(n: Int, d: Int) -> Int {
+    (n * Int::TWO + d).div_euclid(d * Int::TWO)
+}
+
+/// Floor of the square root of a non-negative n.
+fn isqrt(n: Int) -> Int {
+    if n <= Int::ONE {
+        return n;
+    }
+    // Start above the root, Newton's method then decreases monotonically.
+    let mut x = Int::from((n.as_::<f64>().sqrt() * (1.0 + 1e-9)) as u128 + 1);
+    loop {
+        let y = (x + n / x) / Int::TWO;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// Run the spectral test for dimensions 2 up to `max_dimension` (at most `MAX_DIMENSION`).
+/// The increment does not influence the lattice and is ignored.
+pub fn spectral_test(parameters: &LcgParameters, max_dimension: usize) -> Vec<SpectralResult> {
+    let max_dimension = max_dimension.min(MAX_DIMENSION);
+    let m = parameters.modulus.to_int();
+    let a = Int::from(parameters.multiplier).rem_euclid(m);
+    let mut results: Vec<SpectralResult> = vec![];
+    if max_dimension < 2 {
+        return results;
+    }
+    let mut push_result = |dimension: usize, s: Int| {
+        let nu_squared = s.as_::<f64>();
+        let bound = HERMITE_CONSTANTS_POWER[dimension - 2].powf(1.0 / (2.0 * dimension as f64))
+            * (parameters.modulus.log2() / dimension as f64).exp2();
+        results.push(SpectralResult {
+            dimension,
+            nu_squared,
+            figure_of_merit: nu_squared.sqrt() / bound,
+        });
+    };
+
+    // Dimension 2 with Euclid's algorithm, steps S1 to S3.
+    let (mut h, mut h_prev, mut p, mut p_prev) = (a, m, Int::ONE, Int::ZERO);
+    let mut s = Int::ONE + a * a;
+    while h != Int::ZERO {
+        let q = h_prev / h;
+        let u = h_prev - q * h;
+        let v = p_prev - q * p;
+        if u * u + v * v < s {
+            s = u * u + v * v;
+            (h_prev, h, p_prev, p) = (h, u, p, v);
+        } else {
+            let (u, v) = (u - h, v - p);
+            if u * u + v * v < s {
+                s = u * u + v * v;
+                (h_prev, p_prev) = (u, v);
+            }
+            break;
+        }
+    }
+    push_result(2, s);
+    // Rows of u span the dual lattice, rows of v the primal lattice scaled by m,
+    // with u[i] · v[j] = m if i == j and 0 otherwise.
+    let mut u: Vec<Vec<Int>> = vec![vec![-h, p], vec![-h_prev, p_prev]];
+    let mut v: Vec<Vec<Int>> = vec![vec![p_prev, h_prev], vec![-p, -h]];
+    if p * h_prev - p_prev * h != m {
+        v.iter_mut().flatten().for_each(|x| *x = -*x);
+    }
+
+    // a^(t-1) mod m
+    let mut r = a;
+    for t in 3..=max_dimension {
+        // Extend the bases by one dimension, step S4.
+        r = (a * r).rem_euclid(m);
+        let mut new_u = vec![Int::ZERO; t];
+        new_u[0] = -r;
+        new_u[t - 1] = Int::ONE;
+        for (u_row, v_row) in u.iter_mut().zip(v.iter_mut()) {
+            let q = round_div(v_row[0] * r, m);
+            v_row.push(v_row[0] * r - q * m);
+            u_row.push(Int::ZERO);
+            for (x, y) in new_u.iter_mut().zip(u_row.iter()) {
+                *x += q * *y;
+            }
+        }
+        s = s.min(dot(&new_u, &new_u));
+        u.push(new_u);
+        let mut new_v = vec![Int::ZERO; t];
+        new_v[t - 1] = m;
+        v.push(new_v);
+
+        // Reduce until a full pass changes nothing, steps S5 and S6.
+        let (mut j, mut k) = (0, t - 1);
+        loop {
+            let v_jj = dot(&v[j], &v[j]);
+            for i in (0..t).filter(|&i| i != j) {
+                let v_ij = dot(&v[i], &v[j]);
+                if Int::TWO * v_ij.abs() > v_jj {
+                    let q = round_div(v_ij, v_jj);
+                    for index in 0..t {
+                        let v_j = v[j][index];
+                        v[i][index] -= q * v_j;
+                        let u_i = u[i][index];
+                        u[j][index] += q * u_i;
+                    }
+                    s = s.min(dot(&u[j], &u[j]));
+                    k = j;
+                }
+            }
+            j = (j + 1) % t;
+            if j == k {
+                break;
+            }
+        }
+
+        // Exhaustive search over all short combinations of u, steps S7 to S10.
+        // The coefficient of u[j] is y · v[j] / m, which bounds it by |y| |v[j]| / m.
+        let limits: Vec<Int> = v
+            .iter()
+            .map(|v_row| isqrt(dot(v_row, v_row) * s / (m * m)))
+            .collect();
+        let mut x = vec![Int::ZERO; t];
+        let mut y = vec![Int::ZERO; t];
+        // Only vectors with a positive first non-zero coefficient are searched, y and -y are equivalent.
+        let mut k = t - 1;
+        loop {
+            if x[k] != limits[k] {
+                x[k] += Int::ONE;
+                for (y_i, u_i) in y.iter_mut().zip(u[k].iter()) {
+                    *y_i += *u_i;
+                }
+                for l in k + 1..t {
+                    x[l] = -limits[l];
+                    for (y_i, u_i) in y.iter_mut().zip(u[l].iter()) {
+                        *y_i -= Int::TWO * limits[l] * *u_i;
+                    }
+                }
+                s = s.min(dot(&y, &y));
+                k = t - 1;
+            } else if k == 0 {
+                break;
+            } else {
+                k -= 1;
+            }
+        }
+        push_result(t, s);
+    }
+    results
+}
+
+/// Parameters of the LCGs implemented in this crate.
+pub fn crate_lcgs() -> Vec<(String, LcgParameters)> {
+    let mut lcgs = vec![
+        (
+            "RANDU".to_string(),
+            LcgParameters {
+                multiplier: lcg::Randu::MULTIPLIER as u128,
+                increment: 0,
+                modulus: Modulus::PowerOfTwo(lcg::Randu::MODULUS_BITS),
+            },
+        ),
+        (
+            "MMIX".to_string(),
+            LcgParameters {
+                multiplier: lcg::Mmix::MULTIPLIER as u128,
+                increment: lcg::Mmix::INCREMENT as u128,
+                modulus: Modulus::PowerOfTwo(64),
+            },
+        ),
+        (
+            "Lehmer64".to_string(),
+            LcgParameters {
+                multiplier: lcg::Lehmer64::MULTIPLIER,
+                increment: 0,
+                modulus: Modulus::PowerOfTwo(128),
+            },
+        ),
+    ];
+    for (name, multipliers, increments) in [
+        (
+            "UlsLcg512",
+            lcg::UlsLcg512::MULTIPLIERS,
+            lcg::UlsLcg512::INCREMENTS,
+        ),
+        (
+            "UlsLcg512H",
+            lcg::UlsLcg512H::MULTIPLIERS,
+            lcg::UlsLcg512H::INCREMENTS,
+        ),
+    ] {
+        for (index, (&multiplier, &increment)) in multipliers.iter().zip(&increments).enumerate() {
+            lcgs.push((
+                format!("{}[{}]", name, index),
+                LcgParameters {
+                    multiplier,
+                    increment,
+                    modulus: Modulus::PowerOfTwo(128),
+                },
+            ));
+        }
+    }
+    lcgs.push((
+        "MINSTD".to_string(),
+        LcgParameters::of::<
+            { lcg::Minstd::MULTIPLIER },
+            { lcg::Minstd::INCREMENT },
+            { lcg::Minstd::MODULUS },
+        >(),
+    ));
+    lcgs
+}
+
+/// Format the spectral test results of an LCG as a table.
+pub fn format_spectral_report(name: &str, parameters: &LcgParameters) -> String {
+    let mut lines: Vec<String> = vec![format!(
+        "{}: multiplier {:#x}, increment {:#x}, modulus {}",
+        name,
+        parameters.multiplier,
+        parameters.increment,
+        match parameters.modulus {
+            Modulus::PowerOfTwo(bits) => format!("2^{}", bits),
+            Modulus::Value(value) => value.to_string(),
+        }
+    )];
+    lines.push(match parameters.has_maximal_period() {
+        Some(true) => "Maximal period: yes".to_string(),
+        Some(false) => "Maximal period: NO".to_string(),
+        None => "Maximal period: unknown".to_string(),
+    });
+    lines.push(format!(
+        "{:>3} {:>24} {:>10} {:>8}",
+        "t", "nu_t^2", "log2 nu_t", "merit"
+    ));
+    for result in spectral_test(parameters, MAX_DIMENSION) {
+        lines.push(format!(
+            "{:>3} {:>24.0} {:>10.2} {:>8.4}",
+            result.dimension,
+            result.nu_squared,
+            result.nu().log2(),
+            result.figure_of_merit
+        ));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Shortest non-zero vector of the dual lattice by enumerating every short vector.
+    fn brute_force_nu_squared(multiplier: u64, modulus: u64, dimension: usize) -> u64 {
+        // Every coordinate is bounded by ν_2 <= sqrt(4/3 m).
+        let bound: i64 = (4.0 / 3.0 * modulus as f64).sqrt() as i64 + 1;
+        let mut best = u64::MAX;
+        let mut x = vec![-bound; dimension - 1];
+        loop {
+            // Choose the first coordinate with the smallest absolute value.
+            let mut sum: i128 = 0;
+            let mut power: i128 = 1;
+            let mut norm: u64 = 0;
+            for &x_i in x.iter() {
+                power = power * multiplier as i128 % modulus as i128;
+                sum += power * x_i as i128;
+                norm += (x_i * x_i) as u64;
+            }
+            let mut first = (-sum).rem_euclid(modulus as i128);
+            if first > modulus as i128 / 2 {
+                first -= modulus as i128;
+            }
+            norm += (first * first) as u64;
+            if norm != 0 {
+                best = best.min(norm);
+            }
+            let mut index = 0;
+            while index < x.len() && x[index] == bound {
+                x[index] = -bound;
+                index += 1;
+            }
+            if index == x.len() {
+                return best;
+            }
+            x[index] += 1;
+        }
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        for (multiplier, modulus) in [
+            (37u64, 256u64),
+            (141, 1024),
+            (2053, 4096),
+            (3, 1009),
+            (36, 1024),
+        ] {
+            let parameters = LcgParameters {
+                multiplier: multiplier as u128,
+                increment: 1,
+                modulus: Modulus::Value(modulus as u128),
+            };
+            for result in spectral_test(&parameters, 4) {
+                assert_eq!(
+                    result.nu_squared as u64,
+                    brute_force_nu_squared(multiplier, modulus, result.dimension),
+                    "a = {}, m = {}, t = {}",
+                    multiplier,
+                    modulus,
+                    result.dimension
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn randu_planes() {
+        let parameters = LcgParameters {
+            multiplier: 65539,
+            increment: 0,
+            modulus: Modulus::PowerOfTwo(31),
+        };
+        let results = spectral_test(&parameters, MAX_DIMENSION);
+        assert_eq!(results.len(), 7);
+        // 9 x_k - 6 x_(k+1) + x_(k+2) = 0 mod 2^31
+        assert_eq!(results[1].nu_squared, 118.0);
+        assert!(results[1].figure_of_merit < 0.01);
+        assert!(results
+            .windows(2)
+            .all(|pair| pair[1].nu_squared <= pair[0].nu_squared));
+    }
+
+    #[test]
+    fn maximal_period() {
+        let lcgs = crate_lcgs();
+        // RANDU reaches a quarter of its modulus, MMIX the full modulus.
+        assert_eq!(lcgs[0].1.has_maximal_period(), Some(true));
+        assert_eq!(lcgs[1].1.has_maximal_period(), Some(true));
+        // Even multipliers lose one state bit per step.
+        assert_eq!(lcgs[4].0, "UlsLcg512[1]");
+        assert_eq!(lcgs[4].1.has_maximal_period(), Some(false));
+        assert_eq!(
+            LcgParameters {
+                multiplier: 5,
+                increment: 0,
+                modulus: Modulus::PowerOfTwo(16),
+            }
+            .has_maximal_period(),
+            Some(true)
+        );
+        assert_eq!(
+            LcgParameters::of::<5, 0, { 1 << 16 }>().has_maximal_period(),
+            Some(true)
+        );
+        assert_eq!(
+            LcgParameters::of::<48271, 0, 0x7fffffff>().modulus,
+            Modulus::Value(0x7fffffff)
+        );
+        assert_eq!(
+            LcgParameters::of::<5, 1, 0>().modulus,
+            Modulus::PowerOfTwo(128)
+        );
+    }
+}
@@ -6,7 +6,6 @@
 
 //TODO:
 // Interesing tests:
-// - Seed output difference
 // - Birthday spacings test
 // - Blocks average hamming distance.
 
@@ -14,10 +13,9 @@ use core::f64;
 use std::{
     fs::File,
     io::{BufWriter, Write},
-    path::Path,
 };
 
-use crate::{rngs::RNG, utils};
+use crate::{analysis, conditioning, rngs::RNG, utils};
 
 /// Generate 'sample size' u64s using the supplied rng.
 ///     -> generates 'sample_size' * 8 bytes.
@@ -40,28 +38,43 @@ pub fn fill_test_file(
 /// filled with u64 generated using the supplied RNG.
 /// Measures the time taken to generate the specified amount of samples.
 /// Returns RNG speed in bytes per second.
-pub fn generate_test_data(test_rng: &mut impl RNG, sample_size: usize) -> (Vec<u64>, f64) {
+pub fn generate_test_data(
+    test_rng: &mut (impl RNG + ?Sized),
+    sample_size: usize,
+) -> (Vec<u64>, f64) {
     let mut testdata: Vec<u64> = vec![];
+    let speed = fill_test_data(test_rng, &mut testdata, sample_size);
+    (testdata, speed)
+}
+
+/// Like `generate_test_data`, but replaces the contents of 'buffer' with the samples.
+/// The allocation of 'buffer' is reused, so repeated runs with the same
+/// sample size do not allocate. Allocation happens before the timing starts.
+/// Returns RNG speed in bytes per second.
+pub fn fill_test_data(
+    test_rng: &mut (impl RNG + ?Sized),
+    buffer: &mut Vec<u64>,
+    sample_size: usize,
+) -> f64 {
+    buffer.clear();
+    buffer.reserve(sample_size);
     let start = std::time::Instant::now();
     for _ in 0..sample_size {
-        testdata.push(test_rng.next());
+        buffer.push(test_rng.next());
     }
     let timer = start.elapsed();
-    (
-        testdata,
-        ((sample_size as f64) * 8.0) / ((timer.as_nanos() as f64) / 1e9),
-    )
+    ((sample_size as f64) * 8.0) / ((timer.as_nanos() as f64) / 1e9)
 }
 
 /// Generate a ppm image and fill it with random data from supplied RNG.
+#[cfg(feature = "images")]
 pub fn fill_test_image(
     file_path: &str,
     test_rng: &mut impl RNG,
     width: usize,
     height: usize,
 ) -> std::io::Result<()> {
-    let path = Path::new(file_path);
-    let file = File::create(path)?;
+    let file = File::create(file_path)?;
     let mut writer = BufWriter::new(file);
     let header = format!("P6 {} {} 255\n", width, height);
     writer.write_all(header.as_bytes())?;
@@ -74,9 +87,9 @@ pub fn fill_test_image(
 
 /// Measures the distribution among the bytes.
 /// Returns p value based on the chi2 statistic.
-pub fn byte_distribution_test(test_data: &[u64]) -> f64 {
+pub fn byte_distribution_test(test_data: &[u64]) -> Option<f64> {
     if test_data.is_empty() {
-        return 0.0;
+        return None;
     }
     let mut counts: [usize; 256] = [0; 256];
     for block in test_data.iter() {
@@ -91,22 +104,27 @@ pub fn byte_distribution_test(test_data: &[u64]) -> f64 {
         chi_squared += (value as f64 - expected).powi(2) / expected;
     }
     if chi_squared == 0.0 {
-        return 0.0;
+        return Some(0.0);
     }
-    statrs::function::gamma::gamma_lr(255.0 / 2.0, chi_squared / 2.0).clamp(0.0, 1.0)
+    Some(statrs::function::gamma::gamma_lr(255.0 / 2.0, chi_squared / 2.0).clamp(0.0, 1.0))
 }
 
 /// Examines the average distance between u64 values with 'zero_count' leading zeroes.
+/// Requires a threshold of at least 6 bits (more than 2^19 u64s), so every bin spans at least one distance.
 /// Returns p value based on the chi2 statistic.
-pub fn leading_zeros_frequency_test(test_data: &[u64]) -> f64 {
+pub fn leading_zeros_frequency_test(test_data: &[u64]) -> Option<f64> {
     const BIN_COUNT: usize = 256;
     const EXPECTED_SAMPLE_COUNT: u64 = 16384;
+    const MIN_ZERO_COUNT: u32 = 6;
 
-    if test_data.is_empty() {
-        return 0.0;
+    if (test_data.len() as u64) < EXPECTED_SAMPLE_COUNT {
+        return None;
     }
     // Adjust leading zero threshold so the correct amount of distance are expected.
-    let zero_count: u32 = utils::fast_log2(test_data.len() as u64 / EXPECTED_SAMPLE_COUNT).max(1);
+    let zero_count: u32 = utils::fast_log2(test_data.len() as u64 / EXPECTED_SAMPLE_COUNT);
+    if zero_count < MIN_ZERO_COUNT {
+        return None;
+    }
     let expected_spacing: usize = 1 << zero_count;
     let max_bin: usize = 4 * expected_spacing;
     let base_p: f64 = 1.0 / expected_spacing as f64;
@@ -130,7 +148,7 @@ pub fn leading_zeros_frequency_test(test_data: &[u64]) -> f64 {
 
     let total_samples: f64 = bins.iter().sum();
     if total_samples == 0.0 {
-        return 0.0;
+        return Some(0.0);
     }
     for (i, entry) in expected.iter_mut().enumerate() {
         *entry = if i == BIN_COUNT - 1 {
@@ -146,26 +164,69 @@ pub fn leading_zeros_frequency_test(test_data: &[u64]) -> f64 {
         .map(|(bin, exp)| (*bin - exp).powi(2) / exp)
         .sum();
     if chi_squared == 0.0 {
-        return 0.0;
+        return Some(0.0);
     }
-    statrs::function::gamma::gamma_lr((BIN_COUNT as f64 - 1.0) / 2.0, chi_squared / 2.0)
-        .clamp(0.0, 1.0)
+    Some(
+        statrs::function::gamma::gamma_lr((BIN_COUNT as f64 - 1.0) / 2.0, chi_squared / 2.0)
+            .clamp(0.0, 1.0),
+    )
 }
 /// Measures the difference between the number of ones and zeros generated.
 /// NIST Special Publication 800-22 Test 2.1
 /// Returns p value
-pub fn monobit_test(test_data: &[u64]) -> f64 {
+pub fn monobit_test(test_data: &[u64]) -> Option<f64> {
     if test_data.is_empty() {
-        return 0.0;
+        return None;
     }
     let mut difference: i64 = 0;
     for sample in test_data.iter() {
         difference += (sample.count_ones() as i64) - 32;
     }
-    statrs::function::erf::erfc(
-        (difference.abs() as f64 / f64::sqrt(test_data.len() as f64 * 64.0)) * utils::INV_ROOT2,
+    Some(
+        statrs::function::erf::erfc(
+            (difference.abs() as f64 / f64::sqrt(test_data.len() as f64 * 64.0)) * utils::INV_ROOT2,
+        )
+        .clamp(0.0, 1.0),
     )
-    .clamp(0.0, 1.0)
+}
+
+/// Counts the ones of each of the 64 bit positions separately and evaluates the worst position.
+/// The chi2 statistic (1 degree of freedom) of the most biased position is converted to a p value
+/// for the minimum of 64 independent p values, so a single stuck or biased bit is not diluted by the others.
+/// Returns None if the data is shorter than 100 u64s.
+pub fn bit_position_frequency_test(test_data: &[u64]) -> Option<f64> {
+    const MIN_SAMPLES: usize = 100;
+    if test_data.len() < MIN_SAMPLES {
+        return None;
+    }
+    let mut ones = [0u64; 64];
+    for &sample in test_data {
+        for (bit, count) in ones.iter_mut().enumerate() {
+            *count += (sample >> bit) & 1;
+        }
+    }
+    let samples = test_data.len() as f64;
+    let worst_chi_squared = ones
+        .iter()
+        .map(|&count| (2.0 * count as f64 - samples).powi(2) / samples)
+        .fold(0.0, f64::max);
+    let p_min = statrs::function::erf::erfc((worst_chi_squared / 2.0).sqrt());
+    // Probability that the smallest of 64 uniform p values is at most p_min.
+    Some((-(64.0 * (-p_min).ln_1p()).exp_m1()).clamp(0.0, 1.0))
+}
+
+/// Measures the correlation between every pair of the 64 output bit positions, see `analysis::bit_column_correlations`.
+/// The p value of the most correlated of the 2016 pairs is corrected for the number of pairs.
+/// Returns None if the data is shorter than 100 u64s or every bit is constant.
+pub fn bit_correlation_test(test_data: &[u64]) -> Option<f64> {
+    analysis::bit_column_correlations(test_data).p_value()
+}
+
+/// Predicts every output as a linear function over GF(2) of the previous outputs, see `analysis::linear_prediction`.
+/// The number of correctly predicted held-out bits is binomially distributed for random data.
+/// Returns None if the data is shorter than 6336 u64s.
+pub fn linear_prediction_test(test_data: &[u64]) -> Option<f64> {
+    analysis::linear_prediction(test_data).map(|prediction| prediction.p_value())
 }
 
 /// Measures the difference between the number of ones and zeroes in the bitstream.
@@ -184,9 +245,9 @@ pub fn count_excess_ones(test_data: &[u64]) -> f64 {
 /// Measures the ratio of ones and zeroes in each u64
 /// NIST Special Publication 800-22 Test 2.2
 /// Returns p value
-pub fn u64_block_bit_frequency_test(test_data: &[u64]) -> f64 {
+pub fn u64_block_bit_frequency_test(test_data: &[u64]) -> Option<f64> {
     if test_data.is_empty() {
-        return 0.0;
+        return None;
     }
     let mut chi_squared: f64 = 0.0;
     let expected: f64 = 0.5;
@@ -194,19 +255,21 @@ pub fn u64_block_bit_frequency_test(test_data: &[u64]) -> f64 {
         chi_squared += ((sample.count_ones() as f64) / 64.0 - expected).powi(2);
     }
     if chi_squared == 0.0 {
-        return 0.0;
+        return Some(0.0);
     }
     chi_squared *= 4.0 * 64.0;
-    statrs::function::gamma::gamma_lr((test_data.len() as f64) / 2.0, chi_squared / 2.0)
-        .clamp(0.0, 1.0)
+    Some(
+        statrs::function::gamma::gamma_lr((test_data.len() as f64) / 2.0, chi_squared / 2.0)
+            .clamp(0.0, 1.0),
+    )
 }
 
 /// Meansures the number of unintterupted sequence of ones/zeroes.
 /// NIST Special Publication 800-22 Test 2.3
-/// Returns p value
-pub fn runs_test(test_data: &[u64]) -> f64 {
+/// Returns p value, or None if the data is empty or constant.
+pub fn runs_test(test_data: &[u64]) -> Option<f64> {
     if test_data.is_empty() {
-        return 0.0;
+        return None;
     }
     let mut runs: f64 = 0.0;
     let mut last_bit = (test_data[0] >> 63) & 1; // Extract the MSB of the first word
@@ -226,25 +289,30 @@ pub fn runs_test(test_data: &[u64]) -> f64 {
             runs -= 1.0;
         }
     }
-    if runs == 0.0 {
-        return 0.0;
-    }
     let num_bits: f64 = test_data.len() as f64 * 64.0;
+    // A stream of only ones or only zeros leaves the expected number of runs undefined.
+    // NIST skips the test for it, the monobit test already fails such a stream.
+    if runs == 0.0 || excess_ones.abs() == num_bits / 2.0 {
+        return None;
+    }
     let ones_ratio: f64 = ((num_bits / 2.0) + excess_ones) / num_bits;
-    statrs::function::erf::erfc(
-        (runs - (2.0 * ones_ratio * num_bits * (1.0 - ones_ratio))).abs()
-            / (2.0 * f64::sqrt(2.0 * num_bits) * ones_ratio * (1.0 - ones_ratio)),
+    Some(
+        statrs::function::erf::erfc(
+            (runs - (2.0 * ones_ratio * num_bits * (1.0 - ones_ratio))).abs()
+                / (2.0 * f64::sqrt(2.0 * num_bits) * ones_ratio * (1.0 - ones_ratio)),
+        )
+        .clamp(0.0, 1.0),
     )
-    .clamp(0.0, 1.0)
 }
 
 /// Divide stream into 8192-bit (1 kiB, 128*u64)blocks.
 /// Discarding excess bits.
 /// Save the longest run of ones in the block
 /// Produces bad results with test data shorter than 100 kiB.
+/// Requires at least one block.
 /// NIST Special Publication 800-22 Test 2.4
 /// Returns p value
-pub fn longest_ones_run(test_data: &[u64]) -> f64 {
+pub fn longest_ones_run(test_data: &[u64]) -> Option<f64> {
     const BIN_COUNT: usize = 5;
     const PI_TABLE: [f64; BIN_COUNT + 1] = [
         0.1344793662428856,
@@ -254,8 +322,8 @@ pub fn longest_ones_run(test_data: &[u64]) -> f64 {
         0.10381045937538147,
         0.11756019294261932,
     ];
-    if test_data.is_empty() {
-        return 0.0;
+    if test_data.len() < 128 {
+        return None;
     }
     let mut last_bit = 0;
     let mut current_run = 0;
@@ -304,40 +372,64 @@ pub fn longest_ones_run(test_data: &[u64]) -> f64 {
         chi_squared += (bins[i] - (n * PI_TABLE[i])).powi(2) / (n * PI_TABLE[i])
     }
     if chi_squared == 0.0 {
-        return 0.0;
+        return Some(0.0);
     }
-    statrs::function::gamma::gamma_ur(BIN_COUNT as f64 / 2.0, chi_squared / 2.0).clamp(0.0, 1.0)
+    Some(
+        statrs::function::gamma::gamma_ur(BIN_COUNT as f64 / 2.0, chi_squared / 2.0)
+            .clamp(0.0, 1.0),
+    )
 }
 
 /// Divides the bitstream into 32x32 bit binary matrices.
 /// NIST Special Publication 800-22 Test 2.5
 /// Each matrix is 1024 bits (128 bytes, 16 * u64).
+/// See `matrix_ranks_with_size`.
+/// Returns p value
+pub fn matrix_ranks(test_data: &[u64]) -> Option<f64> {
+    matrix_ranks_with_size(test_data, 32)
+}
+
+/// Divides the bitstream into 64x64 bit binary matrices, one u64 per row.
+/// Each matrix is 4096 bits (512 bytes, 64 * u64), more linear dependencies
+/// of the output are covered per byte than with 32x32 matrices.
+/// See `matrix_ranks_with_size`.
+/// Returns p value
+pub fn matrix_ranks_64(test_data: &[u64]) -> Option<f64> {
+    matrix_ranks_with_size(test_data, 64)
+}
+
+/// Divides the bitstream into 'size' x 'size' bit binary matrices, rows are read MSB first.
 /// Determines the rank of each matrix over GF(2)
 /// and bins the results into three categories.
 /// Determine p-value via the chi2 statistic.
+/// Requires at least one matrix and a size dividing 64, otherwise returns None.
 /// Returns p value
-pub fn matrix_ranks(test_data: &[u64]) -> f64 {
-    // All matrices are square.
-    const MATRIX_SIZE: usize = 32;
+pub fn matrix_ranks_with_size(test_data: &[u64], size: usize) -> Option<f64> {
+    if size == 0 || 64 % size != 0 || test_data.len() < (size * size) / 64 {
+        return None;
+    }
     // Matrix ranks are binned as follows:
     // Full rank, one less than full rank, any lower rank
     // Expected distributions for 32x32 matrix come from:
-    // NIST Special Publication 800-22 Section 3.5
-    const EXPECTED_DISTRIBUTION: [f64; 3] = [0.2888, 0.5776, 0.1336];
-    if test_data.is_empty() {
-        return 0.0;
-    }
+    // NIST Special Publication 800-22 Section 3.5, with more digits
+    // from binary_matrix_rank_probability.
+    let full_rank = binary_matrix_rank_probability(size, size);
+    let one_less = binary_matrix_rank_probability(size, size - 1);
+    let expected_distribution: [f64; 3] = [full_rank, one_less, 1.0 - full_rank - one_less];
+    let rows_per_u64 = 64 / size;
+    let row_mask: u64 = u64::MAX >> (64 - size);
     let mut matrix_ranks: [f64; 3] = [0.0; 3];
-    for chunks in test_data.chunks_exact((MATRIX_SIZE * MATRIX_SIZE) / 64) {
-        let mut matrix: [u32; MATRIX_SIZE] = [0; MATRIX_SIZE];
+    let mut matrix: Vec<u64> = vec![0; size];
+    for chunks in test_data.chunks_exact((size * size) / 64) {
         for (i, &block) in chunks.iter().enumerate() {
-            matrix[2 * i] = (block >> 32) as u32;
-            matrix[2 * i + 1] = block as u32;
+            for j in 0..rows_per_u64 {
+                matrix[rows_per_u64 * i + j] = (block >> (64 - size * (j + 1))) & row_mask;
+            }
         }
-        let rank: usize = utils::rank_binary_matrix(matrix);
-        if rank == MATRIX_SIZE {
+        let rank: usize = utils::rank_binary_matrix_rows(&matrix, size);
+        if rank == size {
             matrix_ranks[0] += 1.0;
-        } else if rank == MATRIX_SIZE - 1 {
+        } else if rank == size - 1 {
             matrix_ranks[1] += 1.0;
         } else {
             matrix_ranks[2] += 1.0;
@@ -346,86 +438,2215 @@ pub fn matrix_ranks(test_data: &[u64]) -> f64 {
     let n: f64 = matrix_ranks.iter().fold(0.0, |acc, x| acc + { *x });
     let mut chi_squared: f64 = 0.0;
     for (i, bin) in matrix_ranks.iter().enumerate() {
-        chi_squared += (bin - EXPECTED_DISTRIBUTION[i] * n).powi(2) / (EXPECTED_DISTRIBUTION[i] * n)
+        chi_squared += (bin - expected_distribution[i] * n).powi(2) / (expected_distribution[i] * n)
     }
-    ((-1.0 * chi_squared) / 2.0).exp().clamp(0.0, 1.0)
+    Some((-chi_squared / 2.0).exp().clamp(0.0, 1.0))
 }
 
-#[cfg(test)]
-mod tests {
-    // Specified in number of u64 blocks.
-    const TEST_DATA_LENGTH: f64 = 512.0;
-    const DEFAULT_PMAX: f64 = 1.0;
-    const DEFAULT_PMIN: f64 = 0.0;
-    use super::*;
-    use crate::rngs;
+/// Probability that a uniformly random 'size' x 'size' binary matrix has rank 'rank' over GF(2):
+/// 2^(r(2n - r) - n^2) * prod_(i < r) (1 - 2^(i - n))^2 / (1 - 2^(i - r))
+/// NIST Special Publication 800-22 Section 3.5
+pub fn binary_matrix_rank_probability(size: usize, rank: usize) -> f64 {
+    if rank > size {
+        return 0.0;
+    }
+    let (n, r) = (size as i32, rank as i32);
+    (0..r).fold(2f64.powi(r * (2 * n - r) - n * n), |probability, i| {
+        probability * (1.0 - 2f64.powi(i - n)).powi(2) / (1.0 - 2f64.powi(i - r))
+    })
+}
 
-    fn rng_test_verification(
-        test_rng: &mut impl RNG,
-        max_p: f64,
-        min_p: f64,
-        test_func: fn(&[u64]) -> f64,
-    ) {
-        let (test_data, _) = generate_test_data(test_rng, TEST_DATA_LENGTH as usize);
-        let p = test_func(&test_data);
-        assert!(
-            (min_p..=max_p).contains(&p),
-            "p-value out of range: expected [{}, {}], got {}",
-            min_p,
-            max_p,
-            p
-        );
+/// Compares the frequencies of overlapping m-bit and (m+1)-bit patterns.
+/// The bitstream is read LSB first and wrapped around at the end.
+/// m is 10, or smaller if the test data is too short.
+/// NIST Special Publication 800-22 Test 2.12
+/// Returns p value
+pub fn approximate_entropy_test(test_data: &[u64]) -> Option<f64> {
+    const MAX_BLOCK_LENGTH: u32 = 10;
+    if test_data.is_empty() {
+        return None;
     }
+    let num_bits: usize = test_data.len() * 64;
+    // NIST recommends m < floor(log2(n)) - 5
+    let block_length: u32 = MAX_BLOCK_LENGTH.min(num_bits.ilog2().saturating_sub(6).max(1));
+    let mask: usize = (1 << (block_length + 1)) - 1;
+    let mut counts: Vec<usize> = vec![0; 1 << (block_length + 1)];
 
-    #[test]
-    fn monobit_verification_onlyone() {
-        rng_test_verification(
-            &mut rngs::testgens::OnlyOne::new(0),
-            DEFAULT_PMIN,
-            DEFAULT_PMIN,
-            monobit_test,
-        );
+    // Prefill the window with the last m bits to wrap the stream around.
+    let last_block = test_data[test_data.len() - 1];
+    let mut window: usize = 0;
+    for bit in (64 - block_length)..64 {
+        window = (window << 1) | ((last_block >> bit) & 1) as usize;
+    }
+    for &sample in test_data {
+        for bit in 0..64 {
+            window = ((window << 1) | ((sample >> bit) & 1) as usize) & mask;
+            counts[window] += 1;
+        }
     }
 
-    #[test]
-    fn monobit_verification_onlyzero() {
-        rng_test_verification(
-            &mut rngs::testgens::OnlyZero::new(0),
-            DEFAULT_PMIN,
-            DEFAULT_PMIN,
-            monobit_test,
-        );
+    fn phi(pattern_counts: impl Iterator<Item = usize>, num_bits: usize) -> f64 {
+        pattern_counts
+            .filter(|&count| count != 0)
+            .map(|count| {
+                let ratio = count as f64 / num_bits as f64;
+                ratio * ratio.ln()
+            })
+            .sum()
     }
+    // The m-bit pattern counts are the (m+1)-bit counts summed over the newest bit.
+    let phi_m: f64 = phi(
+        counts.chunks_exact(2).map(|pair| pair[0] + pair[1]),
+        num_bits,
+    );
+    let phi_m1: f64 = phi(counts.iter().copied(), num_bits);
+    let approximate_entropy: f64 = phi_m - phi_m1;
+    let chi_squared: f64 = 2.0 * num_bits as f64 * (f64::consts::LN_2 - approximate_entropy);
+    Some(
+        statrs::function::gamma::gamma_ur(
+            (1u64 << (block_length - 1)) as f64,
+            chi_squared.max(0.0) / 2.0,
+        )
+        .clamp(0.0, 1.0),
+    )
+}
 
-    #[test]
-    fn monobit_verification_alternating_bytes() {
-        rng_test_verification(
-            &mut rngs::testgens::AlternatingBytes::new(0),
-            DEFAULT_PMAX,
-            DEFAULT_PMAX,
-            monobit_test,
-        );
+/// Largest absolute value reached by the partial sums of the ±1 mapped bitstream.
+/// The stream is read LSB first, or MSB first from the end if `reverse` is set.
+fn max_partial_sum_excursion(test_data: &[u64], reverse: bool) -> i64 {
+    // Per byte value: total sum, maximum and minimum of the partial sums.
+    let mut byte_table: [(i64, i64, i64); 256] = [(0, 0, 0); 256];
+    for (byte, entry) in byte_table.iter_mut().enumerate() {
+        let mut sum: i64 = 0;
+        for i in 0..8 {
+            let bit = if reverse { byte >> (7 - i) } else { byte >> i } & 1;
+            sum += if bit == 1 { 1 } else { -1 };
+            entry.1 = entry.1.max(sum);
+            entry.2 = entry.2.min(sum);
+        }
+        entry.0 = sum;
     }
-    #[test]
-    fn monobit_verification_alternating_bits() {
-        rng_test_verification(
-            &mut rngs::testgens::AlternatingBits::new(0),
-            DEFAULT_PMAX,
-            DEFAULT_PMAX,
-            monobit_test,
-        );
+    let mut partial_sum: i64 = 0;
+    let mut max_excursion: i64 = 0;
+    let mut add_byte = |byte: u8| {
+        let (sum, max, min) = byte_table[byte as usize];
+        max_excursion = max_excursion
+            .max((partial_sum + max).abs())
+            .max((partial_sum + min).abs());
+        partial_sum += sum;
+    };
+    if reverse {
+        for sample in test_data.iter().rev() {
+            sample.to_be_bytes().into_iter().for_each(&mut add_byte);
+        }
+    } else {
+        for sample in test_data.iter() {
+            sample.to_le_bytes().into_iter().for_each(&mut add_byte);
+        }
     }
-    #[test]
-    fn monobit_verification_alternating_blocks() {
-        rng_test_verification(
-            &mut rngs::testgens::AlternatingBlocks::new(0),
-            DEFAULT_PMAX,
-            DEFAULT_PMAX,
-            monobit_test,
-        );
+    max_excursion
+}
+
+/// p value of the cumulative sums test for a maximum excursion 'z' over 'n' bits.
+fn cumulative_sums_p_value(z: f64, n: f64) -> f64 {
+    let normal_cdf = |x: f64| 0.5 * statrs::function::erf::erfc(-x * utils::INV_ROOT2);
+    let root_n = n.sqrt();
+    let mut p: f64 = 1.0;
+    let start = ((-n / z + 1.0) / 4.0).floor() as i64;
+    let end = ((n / z - 1.0) / 4.0).floor() as i64;
+    for k in start..=end {
+        let k = k as f64;
+        p -= normal_cdf((4.0 * k + 1.0) * z / root_n) - normal_cdf((4.0 * k - 1.0) * z / root_n);
     }
-    #[test]
-    fn monobit_verification_random() {
-        rng_test_verification(&mut rngs::ReferenceRand::new(0), 0.999, 0.001, monobit_test);
+    let start = ((-n / z - 3.0) / 4.0).floor() as i64;
+    for k in start..=end {
+        let k = k as f64;
+        p += normal_cdf((4.0 * k + 3.0) * z / root_n) - normal_cdf((4.0 * k + 1.0) * z / root_n);
+    }
+    p.clamp(0.0, 1.0)
+}
+
+/// Measures the maximum excursion of the random walk
+/// formed by the ±1 mapped bitstream, read from the start.
+/// NIST Special Publication 800-22 Test 2.13
+/// Returns p value
+pub fn cumulative_sums_forward_test(test_data: &[u64]) -> Option<f64> {
+    if test_data.is_empty() {
+        return None;
+    }
+    let z = max_partial_sum_excursion(test_data, false);
+    Some(cumulative_sums_p_value(
+        z as f64,
+        test_data.len() as f64 * 64.0,
+    ))
+}
+
+/// Measures the maximum excursion of the random walk
+/// formed by the ±1 mapped bitstream, read from the end.
+/// NIST Special Publication 800-22 Test 2.13
+/// Returns p value
+pub fn cumulative_sums_backward_test(test_data: &[u64]) -> Option<f64> {
+    if test_data.is_empty() {
+        return None;
+    }
+    let z = max_partial_sum_excursion(test_data, true);
+    Some(cumulative_sums_p_value(
+        z as f64,
+        test_data.len() as f64 * 64.0,
+    ))
+}
+
+/// Number of u64s per block of the random walk extremes test, 1024 steps.
+const WALK_BLOCK_LENGTH: usize = 16;
+
+/// Probability that a ±1 random walk of `steps` steps stays within 0..=`width`,
+/// for every starting point in 0..=`width`.
+fn walk_confinement_probabilities(steps: usize, width: usize) -> Vec<f64> {
+    let mut stay: Vec<f64> = vec![1.0; width + 1];
+    let mut next: Vec<f64> = vec![0.0; width + 1];
+    for _ in 0..steps {
+        for start in 0..=width {
+            let down = if start > 0 { stay[start - 1] } else { 0.0 };
+            let up = stay.get(start + 1).copied().unwrap_or(0.0);
+            next[start] = 0.5 * (down + up);
+        }
+        std::mem::swap(&mut stay, &mut next);
+    }
+    stay
+}
+
+/// Exact distributions of the maximum excursion, the largest absolute partial sum,
+/// and of the range, maximum minus minimum partial sum, of a ±1 random walk of `steps` steps starting at 0.
+/// Entry k of each returned vector is P(value <= k), computed up to the first k with P(value > k) < `tail`
+/// or the end of the distribution.
+/// The walk stays within an interval of width w containing 0 for exactly w - range + 1 placements of the interval,
+/// so the number of placements of width w minus those of width w - 1 is 1 if the range is at most w and 0 otherwise.
+fn walk_extreme_distributions(steps: usize, tail: f64) -> (Vec<f64>, Vec<f64>) {
+    let mut excursion_cdf: Vec<f64> = vec![];
+    let mut range_cdf: Vec<f64> = vec![];
+    let converged = |cdf: &[f64]| cdf.last().is_some_and(|&p| 1.0 - p < tail);
+    let mut previous_placements: f64 = 0.0;
+    let mut width: usize = 0;
+    // At width 2 * `steps` both distributions are complete.
+    while (!converged(&excursion_cdf) || !converged(&range_cdf)) && width <= 2 * steps {
+        let stay = walk_confinement_probabilities(steps, width);
+        let placements: f64 = stay.iter().sum();
+        if !converged(&range_cdf) {
+            range_cdf.push((placements - previous_placements).clamp(0.0, 1.0));
+        }
+        if width.is_multiple_of(2) && !converged(&excursion_cdf) {
+            excursion_cdf.push(stay[width / 2]);
+        }
+        previous_placements = placements;
+        width += 1;
+    }
+    (excursion_cdf, range_cdf)
+}
+
+/// Chi2 p value of observed values against the distribution given by `cdf`.
+/// Values beyond the end of `cdf` share a tail bin.
+fn cdf_chi_squared_p_value(counts: &[usize], cdf: &[f64]) -> Option<f64> {
+    const MIN_EXPECTED: f64 = 5.0;
+    let n: f64 = counts.iter().sum::<usize>() as f64;
+    let mut previous: f64 = 0.0;
+    let mut bins: Vec<(f64, f64)> = vec![];
+    for (&count, &p) in counts.iter().zip(cdf.iter().chain([1.0].iter())) {
+        bins.push((count as f64, n * (p - previous).max(0.0)));
+        previous = p;
+    }
+    merged_chi_squared_p_value(bins, MIN_EXPECTED)
+}
+
+/// Splits the ±1 mapped bitstream into random walks of 1024 steps that each start at 0.
+/// The maximum excursion and the range of the partial sums of each walk are compared
+/// to their exact distributions using the chi2 statistic.
+/// Complements the cumulative sums tests, which only see the largest excursion of the whole stream.
+/// Returns the p values of both distributions combined with the Cauchy combination test,
+/// or None if the data is too short to fill two bins.
+pub fn random_walk_extremes_test(test_data: &[u64]) -> Option<f64> {
+    const MIN_EXPECTED: f64 = 5.0;
+    let blocks = test_data.len() / WALK_BLOCK_LENGTH;
+    if blocks == 0 {
+        return None;
+    }
+    let (excursion_cdf, range_cdf) =
+        walk_extreme_distributions(WALK_BLOCK_LENGTH * 64, MIN_EXPECTED / blocks as f64);
+    // The last entries count the values beyond the computed distributions.
+    let mut excursion_counts: Vec<usize> = vec![0; excursion_cdf.len() + 1];
+    let mut range_counts: Vec<usize> = vec![0; range_cdf.len() + 1];
+    for block in test_data.chunks_exact(WALK_BLOCK_LENGTH) {
+        let (mut max, mut min): (i64, i64) = (0, 0);
+        for_each_walk_step(block, |partial_sum| {
+            max = max.max(partial_sum);
+            min = min.min(partial_sum);
+        });
+        excursion_counts[(max.max(-min) as usize).min(excursion_cdf.len())] += 1;
+        range_counts[((max - min) as usize).min(range_cdf.len())] += 1;
+    }
+    let p_values = [
+        cdf_chi_squared_p_value(&excursion_counts, &excursion_cdf)?,
+        cdf_chi_squared_p_value(&range_counts, &range_cdf)?,
+    ];
+    Some(cauchy_combination(&p_values))
+}
+
+/// Call 'step' with every partial sum of the ±1 mapped bitstream.
+/// The stream is read LSB first.
+fn for_each_walk_step(test_data: &[u64], mut step: impl FnMut(i64)) {
+    let mut partial_sum: i64 = 0;
+    for &sample in test_data {
+        for bit in 0..64 {
+            partial_sum += ((sample >> bit) & 1) as i64 * 2 - 1;
+            step(partial_sum);
+        }
+    }
+}
+
+/// Probability of a random walk over 'num_bits' steps returning to zero at most 'cycles' times.
+/// The number of returns divided by sqrt(num_bits) approaches the half-normal distribution.
+fn few_cycles_p_value(cycles: usize, num_bits: usize) -> f64 {
+    statrs::function::erf::erf(cycles as f64 / (2.0 * num_bits as f64).sqrt()).clamp(0.0, 1.0)
+}
+
+/// Combine p values of correlated tests into a single p value (Cauchy combination test).
+/// Exact if the tests are independent or identical, and approximately valid for
+/// arbitrary dependence in between.
+fn cauchy_combination(p_values: &[f64]) -> f64 {
+    let statistic: f64 = p_values
+        .iter()
+        .map(|p| ((0.5 - p.clamp(f64::MIN_POSITIVE, 1.0 - f64::EPSILON)) * f64::consts::PI).tan())
+        .sum::<f64>()
+        / p_values.len() as f64;
+    (0.5 - statistic.atan() / f64::consts::PI).clamp(0.0, 1.0)
+}
+
+/// Divides the ±1 random walk of the bitstream into cycles that start and end at zero.
+/// For each of the states -4..-1 and 1..4 counts the number of cycles
+/// that visit the state exactly 0, 1, 2, 3, 4 and 5 or more times.
+/// The p value of each state is based on the chi2 statistic.
+/// The chi2 approximation requires at least 500 cycles (around 2^18 bits).
+/// For fewer cycles the p value of observing so few cycles is returned instead.
+/// NIST Special Publication 800-22 Test 2.14
+/// Returns the smallest of the eight p values, Šidák corrected for the number of states.
+pub fn random_excursions_test(test_data: &[u64]) -> Option<f64> {
+    const MAX_STATE: usize = 4;
+    const MAX_VISITS: usize = 5;
+    const MIN_CYCLES: usize = 500;
+    if test_data.is_empty() {
+        return None;
+    }
+    // Index i corresponds to state i - MAX_STATE.
+    let mut cycle_visits = [0usize; 2 * MAX_STATE + 1];
+    let mut visit_counts = [[0usize; MAX_VISITS + 1]; 2 * MAX_STATE + 1];
+    let mut cycles: usize = 0;
+    let mut end_cycle = |cycle_visits: &mut [usize; 2 * MAX_STATE + 1]| {
+        for (state, visits) in cycle_visits.iter_mut().enumerate() {
+            visit_counts[state][(*visits).min(MAX_VISITS)] += 1;
+            *visits = 0;
+        }
+        cycles += 1;
+    };
+    let mut last_sum: i64 = 0;
+    for_each_walk_step(test_data, |partial_sum| {
+        if partial_sum == 0 {
+            end_cycle(&mut cycle_visits);
+        } else if partial_sum.unsigned_abs() as usize <= MAX_STATE {
+            cycle_visits[(partial_sum + MAX_STATE as i64) as usize] += 1;
+        }
+        last_sum = partial_sum;
+    });
+    // The walk is closed by an implicit return to zero.
+    if last_sum != 0 {
+        end_cycle(&mut cycle_visits);
+    }
+    if cycles < MIN_CYCLES {
+        return Some(few_cycles_p_value(cycles, test_data.len() * 64));
+    }
+
+    let mut min_p: f64 = 1.0;
+    for (state_index, counts) in visit_counts.iter().enumerate() {
+        if state_index == MAX_STATE {
+            continue;
+        }
+        let state = (state_index as f64 - MAX_STATE as f64).abs();
+        let return_p = 1.0 - 1.0 / (2.0 * state);
+        let chi_squared: f64 = counts
+            .iter()
+            .enumerate()
+            .map(|(visits, &count)| {
+                let expected_ratio = match visits {
+                    0 => return_p,
+                    MAX_VISITS => return_p.powi(MAX_VISITS as i32 - 1) / (2.0 * state),
+                    _ => return_p.powi(visits as i32 - 1) / (4.0 * state * state),
+                };
+                let expected = expected_ratio * cycles as f64;
+                (count as f64 - expected).powi(2) / expected
+            })
+            .sum();
+        let p = statrs::function::gamma::gamma_ur(MAX_VISITS as f64 / 2.0, chi_squared / 2.0);
+        min_p = min_p.min(p);
+    }
+    Some((1.0 - (1.0 - min_p).powi(2 * MAX_STATE as i32)).clamp(0.0, 1.0))
+}
+
+/// Bins the leading_zeros() and trailing_zeros() counts of each u64
+/// and compares them to the geometric distribution, P(k) = 2^-(k+1).
+/// Counts that are too rare to be expected at least 5 times are lumped into the last bin.
+/// Stresses the word boundaries of generators that build u64s from smaller outputs.
+/// Returns p value based on the chi2 statistic of both distributions.
+pub fn zero_count_distribution_test(test_data: &[u64]) -> Option<f64> {
+    const MIN_EXPECTED: usize = 5;
+    if test_data.len() < 2 * MIN_EXPECTED {
+        return None;
+    }
+    // Counts from 0 to last_bin - 1 get their own bin, last_bin collects the rest.
+    let last_bin: usize = ((test_data.len() / MIN_EXPECTED).ilog2() as usize).min(64);
+    let mut leading_bins: Vec<usize> = vec![0; last_bin + 1];
+    let mut trailing_bins: Vec<usize> = vec![0; last_bin + 1];
+    for &sample in test_data {
+        leading_bins[(sample.leading_zeros() as usize).min(last_bin)] += 1;
+        trailing_bins[(sample.trailing_zeros() as usize).min(last_bin)] += 1;
+    }
+    let n = test_data.len() as f64;
+    let expected = |k: usize| -> f64 {
+        if k == last_bin {
+            n * 0.5f64.powi(last_bin as i32)
+        } else {
+            n * 0.5f64.powi(k as i32 + 1)
+        }
+    };
+    let chi_squared: f64 = leading_bins
+        .iter()
+        .chain(trailing_bins.iter())
+        .enumerate()
+        .map(|(i, &count)| {
+            let exp = expected(i % (last_bin + 1));
+            (count as f64 - exp).powi(2) / exp
+        })
+        .sum();
+    Some(statrs::function::gamma::gamma_ur(last_bin as f64, chi_squared / 2.0).clamp(0.0, 1.0))
+}
+
+/// p value of 'visits' total visits to a state at distance 'state' from zero in a walk of 'cycles' cycles.
+/// The visits are approximately normal with mean 'cycles' and variance 2 * cycles * (4 * state - 2).
+fn excursion_visits_p_value(visits: usize, cycles: usize, state: usize) -> f64 {
+    let deviation = (visits as f64 - cycles as f64).abs();
+    statrs::function::erf::erfc(
+        deviation / (2.0 * cycles as f64 * (4.0 * state as f64 - 2.0)).sqrt(),
+    )
+    .clamp(0.0, 1.0)
+}
+
+/// Counts the total number of visits to each of the states -9..-1 and 1..9
+/// in the ±1 random walk of the bitstream and compares them to the number of cycles
+/// (returns to zero). For random data the visits to every state match the cycle count.
+/// The p value of each state is based on the normal approximation of its visits.
+/// Like the random excursions test at least 500 cycles are required,
+/// otherwise the p value of observing so few cycles is returned.
+/// The visits to states of the same sign are strongly correlated, neighbouring states up to 0.87,
+/// and the smallest of the eighteen p values is distributed like that of about seven independent ones.
+/// NIST Special Publication 800-22 Test 2.15
+/// Returns the smallest of the eighteen p values, Šidák corrected for seven independent states.
+pub fn random_excursions_variant_test(test_data: &[u64]) -> Option<f64> {
+    const MAX_STATE: usize = 9;
+    const EFFECTIVE_STATES: i32 = 7;
+    const MIN_CYCLES: usize = 500;
+    if test_data.is_empty() {
+        return None;
+    }
+    // Index i corresponds to state i - MAX_STATE.
+    let mut visits = [0usize; 2 * MAX_STATE + 1];
+    let mut cycles: usize = 0;
+    let mut last_sum: i64 = 0;
+    for_each_walk_step(test_data, |partial_sum| {
+        if partial_sum == 0 {
+            cycles += 1;
+        } else if partial_sum.unsigned_abs() as usize <= MAX_STATE {
+            visits[(partial_sum + MAX_STATE as i64) as usize] += 1;
+        }
+        last_sum = partial_sum;
+    });
+    // The walk is closed by an implicit return to zero.
+    if last_sum != 0 {
+        cycles += 1;
+    }
+    if cycles < MIN_CYCLES {
+        return Some(few_cycles_p_value(cycles, test_data.len() * 64));
+    }
+    let min_p: f64 = visits
+        .iter()
+        .enumerate()
+        .filter(|&(state_index, _)| state_index != MAX_STATE)
+        .map(|(state_index, &state_visits)| {
+            excursion_visits_p_value(state_visits, cycles, state_index.abs_diff(MAX_STATE))
+        })
+        .fold(1.0, f64::min);
+    Some((1.0 - (1.0 - min_p).powi(EFFECTIVE_STATES)).clamp(0.0, 1.0))
+}
+
+/// Seeds the generator with pairs of seeds that differ in a single bit,
+/// once for every bit of each base seed, and measures the hamming distance
+/// between the first `outputs_per_seed` outputs of both seeds.
+/// For a well seeded generator the distances follow a binomial distribution
+/// with n = 64 and p = 0.5. Exposes seeding routines that do not mix the seed.
+/// Returns p value based on the chi2 statistic of the distance distribution,
+/// or None if too few outputs are compared to fill the bins.
+pub fn seed_avalanche_test(
+    test_rng: &mut impl RNG,
+    base_seeds: &[u64],
+    outputs_per_seed: usize,
+) -> Option<f64> {
+    let mut distance_counts = [0usize; 65];
+    let mut base_outputs: Vec<u64> = vec![0; outputs_per_seed];
+    for &base_seed in base_seeds {
+        test_rng.reseed(base_seed);
+        base_outputs.fill_with(|| test_rng.next());
+        for bit in 0..64 {
+            test_rng.reseed(base_seed ^ (1 << bit));
+            for &base_output in &base_outputs {
+                distance_counts[(base_output ^ test_rng.next()).count_ones() as usize] += 1;
+            }
+        }
+    }
+    hamming_distance_p_value(&distance_counts)
+}
+
+/// Splits a child off the generator seeded with each base seed and measures the hamming distance
+/// between the next `outputs_per_seed` outputs of parent and child, aligned and with the child
+/// shifted by up to two outputs in either direction.
+/// For independent streams the distances follow a binomial distribution with n = 64 and p = 0.5.
+/// Exposes `split` implementations whose child overlaps or correlates with the parent.
+/// Returns p value based on the chi2 statistic of the distance distribution,
+/// or None if too few outputs are compared to fill the bins.
+pub fn split_correlation_test(
+    test_rng: &mut impl RNG,
+    base_seeds: &[u64],
+    outputs_per_seed: usize,
+) -> Option<f64> {
+    const MAX_LAG: usize = 2;
+    let mut distance_counts = [0usize; 65];
+    let mut parent_outputs: Vec<u64> = vec![0; outputs_per_seed + MAX_LAG];
+    let mut child_outputs: Vec<u64> = vec![0; outputs_per_seed + MAX_LAG];
+    for &base_seed in base_seeds {
+        test_rng.reseed(base_seed);
+        let mut child = test_rng.split();
+        parent_outputs.fill_with(|| test_rng.next());
+        child_outputs.fill_with(|| child.next());
+        for lag in 0..=MAX_LAG {
+            for i in 0..outputs_per_seed {
+                distance_counts
+                    [(parent_outputs[i + lag] ^ child_outputs[i]).count_ones() as usize] += 1;
+                if lag > 0 {
+                    distance_counts
+                        [(parent_outputs[i] ^ child_outputs[i + lag]).count_ones() as usize] += 1;
+                }
+            }
+        }
+    }
+    hamming_distance_p_value(&distance_counts)
+}
+
+/// Compares counts of hamming distances between pairs of u64s to the binomial distribution
+/// with n = 64 and p = 0.5 using the chi2 statistic.
+/// The tails are merged until they expect at least 5 pairs, None if that takes more than 30 distances.
+fn hamming_distance_p_value(distance_counts: &[usize; 65]) -> Option<f64> {
+    const MIN_EXPECTED: f64 = 5.0;
+    let n = distance_counts.iter().sum::<usize>() as f64;
+    let expected: Vec<f64> = (0..=64)
+        .map(|k| n * statrs::function::factorial::binomial(64, k) * 0.5f64.powi(64))
+        .collect();
+    // Distances up to `tail` and from 64 - `tail` are merged into one bin per side.
+    let mut tail: usize = 0;
+    while expected[..=tail].iter().sum::<f64>() < MIN_EXPECTED {
+        tail += 1;
+        if tail >= 31 {
+            return None;
+        }
+    }
+    let merged_bin = |range: std::ops::RangeInclusive<usize>| -> (f64, f64) {
+        (
+            range.clone().map(|k| distance_counts[k] as f64).sum(),
+            range.map(|k| expected[k]).sum(),
+        )
+    };
+    let mut bins: Vec<(f64, f64)> = vec![merged_bin(0..=tail), merged_bin(64 - tail..=64)];
+    bins.extend((tail + 1..64 - tail).map(|k| (distance_counts[k] as f64, expected[k])));
+    let chi_squared: f64 = bins
+        .iter()
+        .map(|&(count, exp)| (count - exp).powi(2) / exp)
+        .sum();
+    Some(
+        statrs::function::gamma::gamma_ur((bins.len() - 1) as f64 / 2.0, chi_squared / 2.0)
+            .clamp(0.0, 1.0),
+    )
+}
+
+/// Splits the bitstream into digits of `digit_bits` bits, LSB first,
+/// and groups consecutive digits into hands of `hand_size` digits.
+/// Each hand is classified by the number of distinct digits it contains
+/// and the counts are compared to the expected frequencies using the chi2 statistic.
+/// Classes with too few expected hands are merged into their neighbor.
+/// `digit_bits` has to divide 64 and be at most 16, returns None for invalid parameters
+/// or if the data is too short.
+/// Based on the poker test in Knuth, The Art of Computer Programming Vol. 2, 3.3.2.D
+pub fn poker_test_with_params(test_data: &[u64], digit_bits: u32, hand_size: usize) -> Option<f64> {
+    const MIN_EXPECTED: f64 = 5.0;
+    if digit_bits == 0 || digit_bits > 16 || 64 % digit_bits != 0 || hand_size < 2 {
+        return None;
+    }
+    let digit_mask: u64 = (1 << digit_bits) - 1;
+    let digit_count: usize = 1 << digit_bits;
+    let mut class_counts: Vec<usize> = vec![0; hand_size + 1];
+    let mut seen: Vec<bool> = vec![false; digit_count];
+    let mut hand: Vec<usize> = Vec::with_capacity(hand_size);
+    for &sample in test_data {
+        for shift in (0..64).step_by(digit_bits as usize) {
+            hand.push(((sample >> shift) & digit_mask) as usize);
+            if hand.len() == hand_size {
+                let distinct = hand
+                    .iter()
+                    .filter(|&&digit| !std::mem::replace(&mut seen[digit], true))
+                    .count();
+                class_counts[distinct] += 1;
+                hand.iter().for_each(|&digit| seen[digit] = false);
+                hand.clear();
+            }
+        }
+    }
+    let hands: usize = class_counts.iter().sum();
+    // Probability of r distinct digits: d * (d - 1) * .. * (d - r + 1) * S(k, r) / d^k
+    // with the Stirling numbers of the second kind S.
+    let mut stirling: Vec<f64> = vec![0.0; hand_size + 1];
+    stirling[0] = 1.0;
+    for k in 1..=hand_size {
+        for r in (1..=k).rev() {
+            stirling[r] = r as f64 * stirling[r] + stirling[r - 1];
+        }
+        stirling[0] = 0.0;
+    }
+    let mut falling_factorial: f64 = 1.0;
+    let mut expected: Vec<f64> = vec![0.0; hand_size + 1];
+    for r in 1..=hand_size.min(digit_count) {
+        falling_factorial *= (digit_count - r + 1) as f64;
+        expected[r] = hands as f64 * falling_factorial * stirling[r]
+            / (digit_count as f64).powi(hand_size as i32);
+    }
+    merged_chi_squared_p_value(
+        (1..=hand_size)
+            .filter(|&r| expected[r] > 0.0)
+            .map(|r| (class_counts[r] as f64, expected[r]))
+            .collect(),
+        MIN_EXPECTED,
+    )
+}
+
+/// Chi2 p value of (observed, expected) bins ordered by class.
+/// Bins are merged into their neighbor from both ends until every bin
+/// has at least `min_expected` expected counts. Returns None if fewer than two bins remain.
+fn merged_chi_squared_p_value(mut bins: Vec<(f64, f64)>, min_expected: f64) -> Option<f64> {
+    while bins.len() > 1 && bins[0].1 < min_expected {
+        let (count, exp) = bins.remove(0);
+        bins[0].0 += count;
+        bins[0].1 += exp;
+    }
+    while bins.len() > 1 && bins[bins.len() - 1].1 < min_expected {
+        let (count, exp) = bins.pop().unwrap_or_default();
+        let last = bins.len() - 1;
+        bins[last].0 += count;
+        bins[last].1 += exp;
+    }
+    if bins.len() < 2 {
+        return None;
+    }
+    let chi_squared: f64 = bins
+        .iter()
+        .map(|&(count, exp)| (count - exp).powi(2) / exp)
+        .sum();
+    if chi_squared == 0.0 {
+        return Some(1.0);
+    }
+    Some(
+        statrs::function::gamma::gamma_ur((bins.len() - 1) as f64 / 2.0, chi_squared / 2.0)
+            .clamp(0.0, 1.0),
+    )
+}
+
+/// Chi2 p value of counts of 64 bit hamming weights or distances against Binomial(64, 0.5).
+/// Rare tail classes are merged until every bin is expected at least 5 times.
+fn binomial_64_p_value(counts: &[usize; 65]) -> Option<f64> {
+    const MIN_EXPECTED: f64 = 5.0;
+    let n: usize = counts.iter().sum();
+    merged_chi_squared_p_value(
+        counts
+            .iter()
+            .enumerate()
+            .map(|(k, &count)| {
+                (
+                    count as f64,
+                    n as f64
+                        * statrs::function::factorial::binomial(64, k as u64)
+                        * 0.5f64.powi(64),
+                )
+            })
+            .collect(),
+        MIN_EXPECTED,
+    )
+}
+
+/// Histograms the number of ones of each u64 and compares it to Binomial(64, 0.5).
+/// Sees skew in the weight of single words that averages out in the
+/// total bit count, e.g. words that are too often balanced.
+/// Returns p value based on the chi2 statistic, or None if the data is too short to fill two bins.
+pub fn hamming_weight_test(test_data: &[u64]) -> Option<f64> {
+    let mut weight_counts = [0usize; 65];
+    for &sample in test_data {
+        weight_counts[sample.count_ones() as usize] += 1;
+    }
+    binomial_64_p_value(&weight_counts)
+}
+
+/// Histograms the hamming distance between consecutive u64s and compares it to Binomial(64, 0.5).
+/// Catches slowly evolving states and counter-like outputs,
+/// whose consecutive words differ in too few bits, while each word on its own looks random.
+/// Returns p value based on the chi2 statistic, or None if the data is too short to fill two bins.
+pub fn adjacent_distance_test(test_data: &[u64]) -> Option<f64> {
+    let mut distance_counts = [0usize; 65];
+    for pair in test_data.windows(2) {
+        distance_counts[(pair[0] ^ pair[1]).count_ones() as usize] += 1;
+    }
+    binomial_64_p_value(&distance_counts)
+}
+
+/// Bytes a match of the compression test can refer back.
+const LZ_WINDOW: usize = 1 << 12;
+/// Shortest and longest match of the compression test.
+const LZ_MIN_MATCH: usize = 3;
+const LZ_MAX_MATCH: usize = LZ_MIN_MATCH + 255;
+/// Compressed size of a literal: flag and byte.
+const LZ_LITERAL_BITS: f64 = 9.0;
+/// Compressed size of a match: flag, offset and length.
+const LZ_MATCH_BITS: f64 = 21.0;
+
+/// Compresses the bytes with a small LZ77 compressor and returns the size in bits.
+/// The most recent position of every two byte prefix is the only match candidate,
+/// the first `LZ_WINDOW` bytes only fill the table and are emitted as literals.
+fn lz_compressed_bits(bytes: &[u8]) -> f64 {
+    let prefix = |position: usize| u16::from_le_bytes([bytes[position], bytes[position + 1]]);
+    let mut last_position: Vec<usize> = vec![usize::MAX; 1 << 16];
+    let mut compressed_bits = 0.0;
+    let mut position = 0;
+    while position < bytes.len() {
+        let mut match_length = 0;
+        if position >= LZ_WINDOW && position + LZ_MIN_MATCH <= bytes.len() {
+            let candidate = last_position[prefix(position) as usize];
+            if candidate != usize::MAX && position - candidate <= LZ_WINDOW {
+                let max_length = LZ_MAX_MATCH.min(bytes.len() - position);
+                match_length = (0..max_length)
+                    .take_while(|&offset| bytes[candidate + offset] == bytes[position + offset])
+                    .count();
+            }
+        }
+        let step = if match_length >= LZ_MIN_MATCH {
+            compressed_bits += LZ_MATCH_BITS;
+            match_length
+        } else {
+            compressed_bits += LZ_LITERAL_BITS;
+            1
+        };
+        for covered in position..(position + step).min(bytes.len() - 1) {
+            last_position[prefix(covered) as usize] = covered;
+        }
+        position += step;
+    }
+    compressed_bits
+}
+
+/// Compresses the data as little endian bytes with a built in LZ77 compressor
+/// and compares the compressed size to its distribution for random bytes.
+/// For random bytes a match starts with probability q / 256,
+/// q being the chance that the two byte prefix occurred within the window,
+/// and extends by another byte with probability 1 / 256.
+/// Mean and variance of the size follow from treating literals and matches as a renewal process.
+/// Trivially compressible data fails with a p-value near 0.
+/// Returns p value based on the normal approximation, or None for less than 1 MiB of data.
+pub fn compression_test(test_data: &[u64]) -> Option<f64> {
+    const MIN_BYTES: usize = 1 << 20;
+    let bytes: Vec<u8> = test_data
+        .iter()
+        .flat_map(|sample| sample.to_le_bytes())
+        .collect();
+    if bytes.len() < MIN_BYTES {
+        return None;
+    }
+    // Match length distribution, P(length >= MIN + k) = 256^-k up to the longest match.
+    let length_probability = |extra: usize| {
+        let at_least = 256f64.powi(-(extra as i32));
+        if extra == LZ_MAX_MATCH - LZ_MIN_MATCH {
+            at_least
+        } else {
+            at_least * (1.0 - 1.0 / 256.0)
+        }
+    };
+    let lengths = (0..=LZ_MAX_MATCH - LZ_MIN_MATCH)
+        .map(|extra| ((LZ_MIN_MATCH + extra) as f64, length_probability(extra)))
+        .take_while(|&(_, probability)| probability > 0.0);
+    let mean_length: f64 = lengths.clone().map(|(length, p)| length * p).sum();
+    let prefix_seen_anywhere = 1.0 - (1.0 - 2f64.powi(-16)).powi(LZ_WINDOW as i32);
+    // Positions inside a match are skipped, all but the last would have found their prefix,
+    // so the prefix is found less often at the positions where a literal or match starts.
+    let prefix_seen =
+        prefix_seen_anywhere / (1.0 + (mean_length - 2.0) * (1.0 - prefix_seen_anywhere) / 256.0);
+    let match_probability = prefix_seen / 256.0;
+    let mean_consumed = 1.0 - match_probability + match_probability * mean_length;
+    let mean_bits = (1.0 - match_probability) * LZ_LITERAL_BITS + match_probability * LZ_MATCH_BITS;
+    let bits_per_byte = mean_bits / mean_consumed;
+    let match_deviation: f64 = lengths
+        .map(|(length, p)| p * (LZ_MATCH_BITS - bits_per_byte * length).powi(2))
+        .sum();
+    let variance_per_byte = ((1.0 - match_probability) * (LZ_LITERAL_BITS - bits_per_byte).powi(2)
+        + match_probability * match_deviation)
+        / mean_consumed;
+    let coded_bytes = (bytes.len() - LZ_WINDOW) as f64;
+    let expected_bits = LZ_WINDOW as f64 * LZ_LITERAL_BITS + coded_bytes * bits_per_byte;
+    let z = (lz_compressed_bits(&bytes) - expected_bits) / (coded_bytes * variance_per_byte).sqrt();
+    Some((0.5 * statrs::function::erf::erfc(-z * utils::INV_ROOT2)).clamp(0.0, 1.0))
+}
+
+/// Position of every word in a move-to-front list of all `1 << word_bits` words,
+/// initially ordered by value with 0 at the front, before it is moved to the front.
+/// The list is represented by the time of the last access of every word,
+/// the position of a word is the number of words accessed after it, counted with a Fenwick tree.
+fn move_to_front_positions(words: &[u16], word_bits: u32) -> Vec<usize> {
+    let alphabet_size = 1usize << word_bits;
+    let time_count = alphabet_size + words.len();
+    // Fenwick tree over access times, 1-based.
+    let mut tree = vec![0u32; time_count + 1];
+    let update = |tree: &mut Vec<u32>, time: usize, add: bool| {
+        let mut index = time + 1;
+        while index <= time_count {
+            if add {
+                tree[index] += 1;
+            } else {
+                tree[index] -= 1;
+            }
+            index += index & index.wrapping_neg();
+        }
+    };
+    let accessed_until = |tree: &Vec<u32>, time: usize| {
+        let mut index = time + 1;
+        let mut count = 0;
+        while index > 0 {
+            count += tree[index] as usize;
+            index -= index & index.wrapping_neg();
+        }
+        count
+    };
+    let mut last_access: Vec<usize> = (0..alphabet_size)
+        .map(|word| alphabet_size - 1 - word)
+        .collect();
+    for &time in &last_access {
+        update(&mut tree, time, true);
+    }
+    words
+        .iter()
+        .enumerate()
+        .map(|(step, &word)| {
+            let time = last_access[word as usize];
+            let position = alphabet_size - accessed_until(&tree, time);
+            update(&mut tree, time, false);
+            update(&mut tree, alphabet_size + step, true);
+            last_access[word as usize] = alphabet_size + step;
+            position
+        })
+        .collect()
+}
+
+/// Book stack test by Ryabko and Pestunov on 16 bit words.
+/// Every word is looked up in a move-to-front list of all words and moved to the front.
+/// For random words the position is uniform over the list, regardless of its order.
+/// Repeated or clustered words show up too close to the front.
+/// Returns p value based on the chi2 statistic of positions grouped into 256 equal ranges,
+/// or None if fewer than 5 words per range are expected.
+pub fn book_stack_test(test_data: &[u64]) -> Option<f64> {
+    const WORD_BITS: u32 = 16;
+    const BIN_COUNT: usize = 256;
+    const MIN_EXPECTED: usize = 5;
+    let words: Vec<u16> = test_data
+        .iter()
+        .flat_map(|sample| {
+            let bytes = sample.to_le_bytes();
+            [0, 2, 4, 6].map(|i| u16::from_le_bytes([bytes[i], bytes[i + 1]]))
+        })
+        .collect();
+    if words.len() < MIN_EXPECTED * BIN_COUNT {
+        return None;
+    }
+    let positions_per_bin = (1 << WORD_BITS) / BIN_COUNT;
+    let mut counts = [0usize; BIN_COUNT];
+    for position in move_to_front_positions(&words, WORD_BITS) {
+        counts[position / positions_per_bin] += 1;
+    }
+    let expected = words.len() as f64 / BIN_COUNT as f64;
+    merged_chi_squared_p_value(
+        counts
+            .iter()
+            .map(|&count| (count as f64, expected))
+            .collect(),
+        MIN_EXPECTED as f64,
+    )
+}
+
+/// Counts the non-overlapping `block_bits` bit blocks of the bitstream, bits are read LSB first.
+/// Blocks may span two u64s, the incomplete block at the end is discarded.
+fn bit_block_counts(test_data: &[u64], block_bits: u32) -> Vec<usize> {
+    let mut counts = vec![0usize; 1 << block_bits];
+    if block_bits == 1 {
+        let ones: usize = test_data.iter().map(|s| s.count_ones() as usize).sum();
+        counts[0] = test_data.len() * 64 - ones;
+        counts[1] = ones;
+        return counts;
+    }
+    let mut block: usize = 0;
+    let mut filled: u32 = 0;
+    for &sample in test_data {
+        let mut word = sample;
+        let mut available: u32 = 64;
+        while available > 0 {
+            let taken = (block_bits - filled).min(available);
+            block |= ((word & ((1 << taken) - 1)) as usize) << filled;
+            word >>= taken;
+            available -= taken;
+            filled += taken;
+            if filled == block_bits {
+                counts[block] += 1;
+                block = 0;
+                filled = 0;
+            }
+        }
+    }
+    counts
+}
+
+/// Largest block length checked by the Borel normality test for `num_bits` bits, floor(log2(log2(num_bits))).
+fn borel_max_block_bits(num_bits: usize) -> u32 {
+    (num_bits as f64).log2().log2().floor() as u32
+}
+
+/// Checks if the bitstream is Borel normal in the sense of Calude:
+/// for every block length k from 1 to floor(log2(log2(n))) the frequency of every k bit block
+/// differs from 2^-k by at most sqrt(log2(n) / n), n being the number of bits.
+pub fn borel_normal(test_data: &[u64]) -> bool {
+    let num_bits = test_data.len() * 64;
+    let bound = ((num_bits as f64).log2() / num_bits as f64).sqrt();
+    (1..=borel_max_block_bits(num_bits)).all(|block_bits| {
+        let blocks = (num_bits / block_bits as usize) as f64;
+        let expected = 1.0 / (1u64 << block_bits) as f64;
+        bit_block_counts(test_data, block_bits)
+            .iter()
+            .all(|&count| (count as f64 / blocks - expected).abs() <= bound)
+    })
+}
+
+/// Borel normality test.
+/// The frequencies of all non-overlapping k bit blocks for k from 1 to floor(log2(log2(n))) are checked against
+/// the Borel bound, see `borel_normal`. The bound is several standard deviations wide,
+/// so random data only exceeds it with negligible probability and data exceeding it returns 0.
+/// Otherwise the chi2 p values of the block counts for every k are combined with the Cauchy combination test.
+/// Returns p value, or None if the data is empty.
+pub fn borel_normality_test(test_data: &[u64]) -> Option<f64> {
+    if test_data.is_empty() {
+        return None;
+    }
+    if !borel_normal(test_data) {
+        return Some(0.0);
+    }
+    let num_bits = test_data.len() * 64;
+    let p_values: Vec<f64> = (1..=borel_max_block_bits(num_bits))
+        .filter_map(|block_bits| {
+            let counts = bit_block_counts(test_data, block_bits);
+            let expected = (num_bits / block_bits as usize) as f64 / counts.len() as f64;
+            merged_chi_squared_p_value(
+                counts
+                    .iter()
+                    .map(|&count| (count as f64, expected))
+                    .collect(),
+                5.0,
+            )
+        })
+        .collect();
+    Some(cauchy_combination(&p_values))
+}
+
+/// Maps every u64 to a float in 0..1 with `conditioning::u64_to_double` and sorts the floats.
+fn sorted_unit_floats(test_data: &[u64]) -> Vec<f64> {
+    let mut floats: Vec<f64> = test_data
+        .iter()
+        .map(|&sample| conditioning::u64_to_double(sample))
+        .collect();
+    floats.sort_unstable_by(f64::total_cmp);
+    floats
+}
+
+/// Probability that the Kolmogorov distribution exceeds `lambda`.
+/// Uses the alternating series for large `lambda` and the Jacobi theta form of the CDF for small `lambda`,
+/// both converge after a few terms in their range.
+fn kolmogorov_p_value(lambda: f64) -> f64 {
+    if lambda <= 0.0 {
+        return 1.0;
+    }
+    if lambda < 1.18 {
+        let cdf: f64 = (1..=8)
+            .map(|j| {
+                let odd = (2 * j - 1) as f64;
+                (-(odd * odd) * f64::consts::PI * f64::consts::PI / (8.0 * lambda * lambda)).exp()
+            })
+            .sum::<f64>()
+            * (2.0 * f64::consts::PI).sqrt()
+            / lambda;
+        (1.0 - cdf).clamp(0.0, 1.0)
+    } else {
+        let sum: f64 = (1..=8)
+            .map(|j| {
+                let sign = if j % 2 == 1 { 1.0 } else { -1.0 };
+                sign * (-2.0 * (j * j) as f64 * lambda * lambda).exp()
+            })
+            .sum();
+        (2.0 * sum).clamp(0.0, 1.0)
+    }
+}
+
+/// Kolmogorov-Smirnov statistic of sorted values against the uniform distribution on 0..1,
+/// the largest distance between the empirical and the uniform CDF.
+pub fn kolmogorov_smirnov_statistic(sorted: &[f64]) -> f64 {
+    let n = sorted.len() as f64;
+    sorted
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| ((i + 1) as f64 / n - x).max(x - i as f64 / n))
+        .fold(0.0, f64::max)
+}
+
+/// Probability that the Kolmogorov-Smirnov statistic of `n` uniform values exceeds `statistic`,
+/// using Stephens' approximation of the finite sample distribution.
+pub fn kolmogorov_smirnov_p_value(statistic: f64, n: usize) -> f64 {
+    let root_n = (n as f64).sqrt();
+    kolmogorov_p_value((root_n + 0.12 + 0.11 / root_n) * statistic)
+}
+
+/// Kolmogorov-Smirnov test of the floats produced by `conditioning::u64_to_double` against the uniform distribution on 0..1.
+/// Tests the value scale instead of single bits, and the conversion used by the conditioning wrappers.
+/// Only the lower 52 bits of every u64 are used.
+/// Returns p value, or None if the data is shorter than 100 u64s.
+pub fn kolmogorov_smirnov_test(test_data: &[u64]) -> Option<f64> {
+    const MIN_SAMPLES: usize = 100;
+    if test_data.len() < MIN_SAMPLES {
+        return None;
+    }
+    let floats = sorted_unit_floats(test_data);
+    Some(kolmogorov_smirnov_p_value(
+        kolmogorov_smirnov_statistic(&floats),
+        floats.len(),
+    ))
+}
+
+/// Second level test as in TestU01: Kolmogorov-Smirnov test of the uniformity of the p values
+/// of a single test over several seeds.
+/// Returns p value, or None for fewer than 8 p values.
+pub fn second_level_ks_test(p_values: &[f64]) -> Option<f64> {
+    const MIN_P_VALUES: usize = 8;
+    if p_values.len() < MIN_P_VALUES {
+        return None;
+    }
+    let mut sorted = p_values.to_vec();
+    sorted.sort_unstable_by(f64::total_cmp);
+    Some(kolmogorov_smirnov_p_value(
+        kolmogorov_smirnov_statistic(&sorted),
+        sorted.len(),
+    ))
+}
+
+/// Anderson-Darling statistic A2 of sorted values against the uniform distribution on 0..1.
+/// Weights the tails of the distribution more than the Kolmogorov-Smirnov statistic.
+/// Values at or outside the bounds are clamped, so a value of exactly 0 or 1 gives a large but finite statistic.
+pub fn anderson_darling_statistic(sorted: &[f64]) -> f64 {
+    let n = sorted.len();
+    let sum: f64 = sorted
+        .iter()
+        .zip(sorted.iter().rev())
+        .enumerate()
+        .map(|(i, (&low, &high))| {
+            let low = low.clamp(f64::MIN_POSITIVE, 1.0 - f64::EPSILON);
+            let high = high.clamp(f64::MIN_POSITIVE, 1.0 - f64::EPSILON);
+            (2 * i + 1) as f64 * (low.ln() + (-high).ln_1p())
+        })
+        .sum();
+    -(n as f64) - sum / n as f64
+}
+
+/// Probability that the Anderson-Darling statistic of `n` uniform values exceeds `statistic`.
+/// Asymptotic distribution with the finite sample correction of Marsaglia and Marsaglia (2004),
+/// accurate to about 1e-5 for any `n` outside the extreme upper tail.
+pub fn anderson_darling_p_value(statistic: f64, n: usize) -> f64 {
+    if statistic <= 0.0 {
+        return 1.0;
+    }
+    let z = statistic;
+    let asymptotic = if z < 2.0 {
+        (-1.2337141 / z).exp() / z.sqrt()
+            * (2.00012
+                + (0.247105 - (0.0649821 - (0.0347962 - (0.011672 - 0.00168691 * z) * z) * z) * z)
+                    * z)
+    } else {
+        (-(1.0776
+            - (2.30695 - (0.43424 - (0.082433 - (0.008056 - 0.0003146 * z) * z) * z) * z) * z)
+            .exp())
+        .exp()
+    };
+    let n = n as f64;
+    let x = asymptotic;
+    let correction = if x > 0.8 {
+        // The fitted correction does not vanish at x = 1, it is faded out in the last percent of the tail
+        // so it can not put a floor under small p values.
+        (-130.2137
+            + (745.2337 - (1705.091 - (1950.646 - (1116.360 - 255.7844 * x) * x) * x) * x) * x)
+            / n
+            * ((1.0 - x) / 0.01).min(1.0)
+    } else {
+        let c = 0.01265 + 0.1757 / n;
+        if x < c {
+            let t = x / c;
+            let t = t.sqrt() * (1.0 - t) * (49.0 * t - 102.0);
+            t * (0.0037 / (n * n) + 0.00078 / n + 0.00006) / n
+        } else {
+            let t = (x - c) / (0.8 - c);
+            let t = -0.00022633
+                + (6.54034 - (14.6538 - (14.458 - (8.259 - 1.91864 * t) * t) * t) * t) * t;
+            t * (0.04213 + 0.01365 / n) / n
+        }
+    };
+    (1.0 - (asymptotic + correction)).clamp(0.0, 1.0)
+}
+
+/// Anderson-Darling test of the floats produced by `conditioning::u64_to_double` against the uniform distribution on 0..1.
+/// More sensitive to defects in the tails than `kolmogorov_smirnov_test`.
+/// Only the lower 52 bits of every u64 are used.
+/// Returns p value, or None if the data is shorter than 100 u64s.
+pub fn anderson_darling_test(test_data: &[u64]) -> Option<f64> {
+    const MIN_SAMPLES: usize = 100;
+    if test_data.len() < MIN_SAMPLES {
+        return None;
+    }
+    let floats = sorted_unit_floats(test_data);
+    Some(anderson_darling_p_value(
+        anderson_darling_statistic(&floats),
+        floats.len(),
+    ))
+}
+
+/// Second level test: Anderson-Darling test of the uniformity of a collection of p values.
+/// A good generator produces uniform p values over many runs of the same tests,
+/// so p values that cluster, even without a single failure, are detected.
+/// Returns p value, or None for fewer than 8 p values.
+pub fn second_level_test(p_values: &[f64]) -> Option<f64> {
+    const MIN_P_VALUES: usize = 8;
+    if p_values.len() < MIN_P_VALUES {
+        return None;
+    }
+    let mut sorted = p_values.to_vec();
+    sorted.sort_unstable_by(f64::total_cmp);
+    Some(anderson_darling_p_value(
+        anderson_darling_statistic(&sorted),
+        sorted.len(),
+    ))
+}
+
+/// Counts the 16 bit windows starting at every bit position of the bitstream, bits are read LSB first.
+/// The stream is treated as circular, the last windows wrap around to the first bits.
+fn overlapping_window_counts(test_data: &[u64]) -> Vec<usize> {
+    let mut counts = vec![0usize; 1 << 16];
+    for (i, &sample) in test_data.iter().enumerate() {
+        let next = test_data[(i + 1) % test_data.len()];
+        let combined = sample as u128 | (next as u128) << 64;
+        for offset in 0..64 {
+            counts[((combined >> offset) & 0xffff) as usize] += 1;
+        }
+    }
+    counts
+}
+
+/// Overlapping 16 bit window frequency test, the serial test of NIST Special Publication 800-22 Test 2.11 with m = 16.
+/// Counts the windows starting at every bit position in a 65536 entry table.
+/// Overlapping counts are not independent, so the statistics are the differences
+/// of psi2 for 16, 15 and 14 bit windows, which are chi2 distributed with 2^15 and 2^14 degrees of freedom.
+/// The shorter window counts are sums of the 16 bit counts.
+/// Returns the p values of both differences combined with the Cauchy combination test,
+/// or None if fewer than 5 windows of each value are expected.
+pub fn overlapping_window_test(test_data: &[u64]) -> Option<f64> {
+    const WINDOW_BITS: u32 = 16;
+    const MIN_EXPECTED: usize = 5;
+    let num_bits = test_data.len() * 64;
+    if num_bits < MIN_EXPECTED << WINDOW_BITS {
+        return None;
+    }
+    let mut counts = overlapping_window_counts(test_data);
+    let n = num_bits as f64;
+    let mut psi_squared = [0.0; 3];
+    for psi in psi_squared.iter_mut() {
+        let squares: f64 = counts.iter().map(|&count| (count as f64).powi(2)).sum();
+        *psi = counts.len() as f64 / n * squares - n;
+        let half = counts.len() / 2;
+        counts = (0..half).map(|x| counts[x] + counts[x + half]).collect();
+    }
+    let first_difference = psi_squared[0] - psi_squared[1];
+    let second_difference = psi_squared[0] - 2.0 * psi_squared[1] + psi_squared[2];
+    let p_value = |statistic: f64, degrees: u32| {
+        statrs::function::gamma::gamma_ur((1u64 << degrees) as f64 / 2.0, statistic.max(0.0) / 2.0)
+            .clamp(0.0, 1.0)
+    };
+    Some(cauchy_combination(&[
+        p_value(first_difference, WINDOW_BITS - 1),
+        p_value(second_difference, WINDOW_BITS - 2),
+    ]))
+}
+
+/// Poker test on 4 bit digits with hands of 5 digits.
+/// See `poker_test_with_params`.
+pub fn poker_test(test_data: &[u64]) -> Option<f64> {
+    poker_test_with_params(test_data, 4, 5)
+}
+
+/// Splits the data into non-overlapping tuples of `k` consecutive u64s
+/// and classifies the relative ordering of each tuple into one of the k! permutations.
+/// Ties are ordered by position. Compares the permutation counts to the uniform distribution.
+/// Returns p value based on the chi2 statistic, or None if `k` is below 2 or there are
+/// fewer than 5 tuples per permutation.
+pub fn permutation_test_with_k(test_data: &[u64], k: usize) -> Option<f64> {
+    const MIN_EXPECTED: usize = 5;
+    if k < 2 {
+        return None;
+    }
+    let permutation_count: usize = (1..=k).product();
+    let tuple_count = test_data.len() / k;
+    if tuple_count < MIN_EXPECTED * permutation_count {
+        return None;
+    }
+    let mut counts: Vec<usize> = vec![0; permutation_count];
+    for tuple in test_data.chunks_exact(k) {
+        // Lehmer code of the tuple: for each element the number of smaller later elements.
+        let index: usize = tuple.iter().enumerate().fold(0, |index, (i, &value)| {
+            let smaller_later = tuple[i + 1..]
+                .iter()
+                .filter(|&&other| other < value)
+                .count();
+            index * (k - i) + smaller_later
+        });
+        counts[index] += 1;
+    }
+    let expected = tuple_count as f64 / permutation_count as f64;
+    let chi_squared: f64 = counts
+        .iter()
+        .map(|&count| (count as f64 - expected).powi(2) / expected)
+        .sum();
+    Some(
+        statrs::function::gamma::gamma_ur((permutation_count - 1) as f64 / 2.0, chi_squared / 2.0)
+            .clamp(0.0, 1.0),
+    )
+}
+
+/// Permutation orderings test for tuples of 3, 4 and 5 u64s.
+/// Sensitive to monotone correlations between neighboring outputs.
+/// Tuple sizes with too little data are skipped, returns None if all are skipped.
+/// The p values of the tuple sizes are combined with the Cauchy combination test.
+pub fn permutation_test(test_data: &[u64]) -> Option<f64> {
+    let p_values: Vec<f64> = (3..=5)
+        .filter_map(|k| permutation_test_with_k(test_data, k))
+        .collect();
+    if p_values.is_empty() {
+        return None;
+    }
+    Some(cauchy_combination(&p_values))
+}
+
+/// Distribution of the number of collisions when throwing `balls` balls
+/// uniformly into `buckets` buckets, truncated after `max_collisions`.
+/// A collision is a ball landing in an already occupied bucket.
+fn collision_distribution(balls: usize, buckets: f64, max_collisions: usize) -> Vec<f64> {
+    let mut probabilities: Vec<f64> = vec![0.0; max_collisions + 1];
+    probabilities[0] = 1.0;
+    for thrown in 0..balls {
+        // With c collisions so far, thrown - c buckets are occupied.
+        for c in (0..=max_collisions.min(thrown)).rev() {
+            let miss = probabilities[c] * (1.0 - (thrown - c) as f64 / buckets);
+            let hit = if c > 0 {
+                probabilities[c - 1] * (thrown - c + 1) as f64 / buckets
+            } else {
+                0.0
+            };
+            probabilities[c] = miss + hit;
+        }
+    }
+    probabilities
+}
+
+/// Maps each u64 to one of 2^`bucket_bits` buckets using the bits starting at `shift`
+/// and throws `balls` consecutive u64s per trial.
+/// The number of collisions of each trial is compared to the exact distribution using the chi2 statistic.
+/// With far fewer balls than buckets collisions are rare, so a reduced
+/// effective output range shows up as an excess of collisions.
+/// Returns None for invalid parameters or if the data is too short.
+/// Based on the collision test in Knuth, The Art of Computer Programming Vol. 2, 3.3.2.I
+pub fn collision_test_with_params(
+    test_data: &[u64],
+    bucket_bits: u32,
+    shift: u32,
+    balls: usize,
+) -> Option<f64> {
+    const MIN_EXPECTED: f64 = 5.0;
+    if bucket_bits == 0 || bucket_bits > 32 || shift + bucket_bits > 64 || balls < 2 {
+        return None;
+    }
+    let trials = test_data.len() / balls;
+    if trials == 0 {
+        return None;
+    }
+    let bucket_mask: u64 = (1 << bucket_bits) - 1;
+    let buckets = (1u64 << bucket_bits) as f64;
+    let mean = (balls * (balls - 1)) as f64 / (2.0 * buckets);
+    let max_collisions = ((mean + 20.0 * mean.sqrt() + 20.0) as usize).min(balls - 1);
+    // Sized in u64 so 2^32 buckets do not overflow usize on 32-bit targets.
+    let mut occupied: Vec<u64> = vec![0; ((1u64 << bucket_bits) / 64).max(1) as usize];
+    let mut collision_counts: Vec<usize> = vec![0; max_collisions + 1];
+    for trial in test_data.chunks_exact(balls) {
+        let mut collisions: usize = 0;
+        for &sample in trial {
+            let bucket = ((sample >> shift) & bucket_mask) as usize;
+            let bit = 1 << (bucket % 64);
+            if occupied[bucket / 64] & bit != 0 {
+                collisions += 1;
+            } else {
+                occupied[bucket / 64] |= bit;
+            }
+        }
+        collision_counts[collisions.min(max_collisions)] += 1;
+        occupied.iter_mut().for_each(|word| *word = 0);
+    }
+    let probabilities = collision_distribution(balls, buckets, max_collisions);
+    let mut bins: Vec<(f64, f64)> = (0..max_collisions)
+        .map(|c| (collision_counts[c] as f64, trials as f64 * probabilities[c]))
+        .collect();
+    // The last bin holds everything from max_collisions upwards.
+    let below_max: f64 = probabilities[..max_collisions].iter().sum();
+    bins.push((
+        collision_counts[max_collisions] as f64,
+        trials as f64 * (1.0 - below_max).max(0.0),
+    ));
+    merged_chi_squared_p_value(bins, MIN_EXPECTED)
+}
+
+/// Collision test with 2^20 buckets and 2^14 balls per trial,
+/// about 128 expected collisions per trial.
+/// Run on the high, middle and low bits of each u64,
+/// the p values are combined with the Cauchy combination test.
+/// See `collision_test_with_params`.
+pub fn collision_test(test_data: &[u64]) -> Option<f64> {
+    const BUCKET_BITS: u32 = 20;
+    const BALLS: usize = 1 << 14;
+    let p_values: Vec<f64> = [64 - BUCKET_BITS, 32 - BUCKET_BITS / 2, 0]
+        .iter()
+        .map(|&shift| collision_test_with_params(test_data, BUCKET_BITS, shift, BALLS))
+        .collect::<Option<Vec<f64>>>()?;
+    Some(cauchy_combination(&p_values))
+}
+
+/// Bits of the words counted by the monkey tests, 2^20 possible words.
+const MONKEY_WORD_BITS: u32 = 20;
+/// Number of overlapping words counted by the monkey tests.
+const MONKEY_WORDS: usize = 1 << 21;
+
+/// Monkey test counting the words of `word_letters` letters that never occur
+/// among 2^21 overlapping words.
+/// Each u64 provides one letter, the `letter_bits` bits starting at bit `shift`.
+/// The letters of a word must add up to 20 bits. The number of missing words
+/// is approximately normal with mean 2^20 * e^-2 and standard deviation `sigma`.
+/// Returns None for invalid parameters or if the data is too short.
+/// Based on the OPSO, OQSO and DNA tests of Marsaglia's Diehard battery.
+pub fn monkey_test_with_params(
+    test_data: &[u64],
+    letter_bits: u32,
+    word_letters: u32,
+    shift: u32,
+    sigma: f64,
+) -> Option<f64> {
+    if letter_bits == 0
+        || letter_bits * word_letters != MONKEY_WORD_BITS
+        || shift + letter_bits > 64
+    {
+        return None;
+    }
+    let letters = test_data.get(..MONKEY_WORDS + word_letters as usize - 1)?;
+    let letter_mask: u64 = (1 << letter_bits) - 1;
+    let word_mask: u64 = (1 << MONKEY_WORD_BITS) - 1;
+    let mut seen: Vec<u64> = vec![0; 1 << (MONKEY_WORD_BITS - 6)];
+    let mut word: u64 = 0;
+    for (i, &sample) in letters.iter().enumerate() {
+        word = ((word << letter_bits) | ((sample >> shift) & letter_mask)) & word_mask;
+        if i + 1 >= word_letters as usize {
+            seen[(word / 64) as usize] |= 1 << (word % 64);
+        }
+    }
+    let present: u32 = seen.iter().map(|bits| bits.count_ones()).sum();
+    let missing = (1u64 << MONKEY_WORD_BITS) as f64 - present as f64;
+    let mean = (1u64 << MONKEY_WORD_BITS) as f64 * (-2.0f64).exp();
+    let z = (missing - mean) / sigma;
+    Some((0.5 * statrs::function::erf::erfc(-z * utils::INV_ROOT2)).clamp(0.0, 1.0))
+}
+
+/// Run a monkey test on the high, middle and low bits of each u64,
+/// the p values are combined with the Cauchy combination test.
+fn monkey_test(test_data: &[u64], letter_bits: u32, sigma: f64) -> Option<f64> {
+    let word_letters = MONKEY_WORD_BITS / letter_bits;
+    let p_values: Vec<f64> = [64 - letter_bits, 32 - letter_bits / 2, 0]
+        .iter()
+        .map(|&shift| monkey_test_with_params(test_data, letter_bits, word_letters, shift, sigma))
+        .collect::<Option<Vec<f64>>>()?;
+    Some(cauchy_combination(&p_values))
+}
+
+/// Bits of the words counted by the gorilla test.
+const GORILLA_WORD_BITS: u32 = 26;
+/// Standard deviation of the number of missing 26-bit words, from Marsaglia and Tsang.
+const GORILLA_SIGMA: f64 = 4170.0;
+
+/// Gorilla test on a single bit position: counts the words of `word_bits` bits that never occur among
+/// 2^`word_bits` overlapping words, built from bit `bit` of consecutive u64s.
+/// The number of missing words is approximately normal with mean 2^`word_bits` * e^-1.
+/// The standard deviation of 4170 for 26-bit words is scaled with the square root of the number of words.
+/// Returns None for words shorter than 6 or longer than 32 bits, a bit position above 63
+/// or data shorter than 2^`word_bits` + `word_bits` - 1 u64s.
+pub fn gorilla_test_with_params(test_data: &[u64], word_bits: u32, bit: u32) -> Option<f64> {
+    if !(6..=32).contains(&word_bits) || bit >= 64 {
+        return None;
+    }
+    let num_words = 1usize << word_bits;
+    let stream = test_data.get(..num_words + word_bits as usize - 1)?;
+    let word_mask: u64 = (1 << word_bits) - 1;
+    let mut seen: Vec<u64> = vec![0; num_words / 64];
+    let mut word: u64 = 0;
+    for (i, &sample) in stream.iter().enumerate() {
+        word = ((word << 1) | ((sample >> bit) & 1)) & word_mask;
+        if i + 1 >= word_bits as usize {
+            seen[(word / 64) as usize] |= 1 << (word % 64);
+        }
+    }
+    let present: u64 = seen.iter().map(|bits| bits.count_ones() as u64).sum();
+    let missing = num_words as f64 - present as f64;
+    let mean = num_words as f64 * (-1.0f64).exp();
+    let sigma = GORILLA_SIGMA * (num_words as f64 / (1u64 << GORILLA_WORD_BITS) as f64).sqrt();
+    let z = (missing - mean) / sigma;
+    Some((0.5 * statrs::function::erf::erfc(-z * utils::INV_ROOT2)).clamp(0.0, 1.0))
+}
+
+/// Run the gorilla test with words of `word_bits` bits on all 64 bit positions,
+/// the p values are combined with the second level test.
+fn gorilla_test_with_word_bits(test_data: &[u64], word_bits: u32) -> Option<f64> {
+    let p_values: Vec<f64> = (0..64)
+        .map(|bit| gorilla_test_with_params(test_data, word_bits, bit))
+        .collect::<Option<Vec<f64>>>()?;
+    second_level_test(&p_values)
+}
+
+/// Gorilla test of Marsaglia and Tsang, a monkey test on the stream of a single bit position,
+/// run on every bit position. See `gorilla_test_with_params`.
+/// Uses the longest words of at most 26 bits the data allows, 26-bit words need 2^26 + 25 u64s.
+/// Returns None for less than 2^6 + 5 u64s.
+pub fn gorilla_test(test_data: &[u64]) -> Option<f64> {
+    let word_bits = (6..=GORILLA_WORD_BITS)
+        .rev()
+        .find(|&word_bits| (1usize << word_bits) + word_bits as usize - 1 <= test_data.len())?;
+    gorilla_test_with_word_bits(test_data, word_bits)
+}
+
+/// Overlapping pairs sparse occupancy: two letters of 10 bits.
+/// See `monkey_test_with_params`.
+pub fn opso_test(test_data: &[u64]) -> Option<f64> {
+    monkey_test(test_data, 10, 290.0)
+}
+
+/// Overlapping quadruples sparse occupancy: four letters of 5 bits.
+/// See `monkey_test_with_params`.
+pub fn oqso_test(test_data: &[u64]) -> Option<f64> {
+    monkey_test(test_data, 5, 295.0)
+}
+
+/// DNA: ten letters of 2 bits.
+/// See `monkey_test_with_params`.
+pub fn dna_test(test_data: &[u64]) -> Option<f64> {
+    monkey_test(test_data, 2, 339.0)
+}
+
+#[cfg(test)]
+mod tests {
+    // Specified in number of u64 blocks.
+    const TEST_DATA_LENGTH: f64 = 512.0;
+    const DEFAULT_PMAX: f64 = 1.0;
+    const DEFAULT_PMIN: f64 = 0.0;
+    use super::*;
+    use crate::{rngs, testdata};
+
+    fn rng_test_verification(
+        test_rng: &mut impl RNG,
+        max_p: f64,
+        min_p: f64,
+        test_func: fn(&[u64]) -> Option<f64>,
+    ) {
+        let (test_data, _) = generate_test_data(test_rng, TEST_DATA_LENGTH as usize);
+        let p = test_func(&test_data).expect("test not applicable");
+        assert!(
+            (min_p..=max_p).contains(&p),
+            "p-value out of range: expected [{}, {}], got {}",
+            min_p,
+            max_p,
+            p
+        );
+    }
+
+    #[test]
+    fn monobit_verification_onlyone() {
+        rng_test_verification(
+            &mut rngs::testgens::OnlyOne::new(0),
+            DEFAULT_PMIN,
+            DEFAULT_PMIN,
+            monobit_test,
+        );
+    }
+
+    #[test]
+    fn monobit_verification_onlyzero() {
+        rng_test_verification(
+            &mut rngs::testgens::OnlyZero::new(0),
+            DEFAULT_PMIN,
+            DEFAULT_PMIN,
+            monobit_test,
+        );
+    }
+
+    #[test]
+    fn runs_not_applicable_constant() {
+        let (only_one, _) = generate_test_data(&mut rngs::testgens::OnlyOne::new(0), 1 << 10);
+        let (only_zero, _) = generate_test_data(&mut rngs::testgens::OnlyZero::new(0), 1 << 10);
+        assert_eq!(runs_test(&only_one), None);
+        assert_eq!(runs_test(&only_zero), None);
+    }
+
+    #[test]
+    fn monobit_verification_alternating_bytes() {
+        rng_test_verification(
+            &mut rngs::testgens::AlternatingBytes::new(0),
+            DEFAULT_PMAX,
+            DEFAULT_PMAX,
+            monobit_test,
+        );
+    }
+    #[test]
+    fn monobit_verification_alternating_bits() {
+        rng_test_verification(
+            &mut rngs::testgens::AlternatingBits::new(0),
+            DEFAULT_PMAX,
+            DEFAULT_PMAX,
+            monobit_test,
+        );
+    }
+    #[test]
+    fn monobit_verification_alternating_blocks() {
+        rng_test_verification(
+            &mut rngs::testgens::AlternatingBlocks::new(0),
+            DEFAULT_PMAX,
+            DEFAULT_PMAX,
+            monobit_test,
+        );
+    }
+    #[test]
+    fn monobit_verification_random() {
+        rng_test_verification(&mut rngs::ReferenceRand::new(0), 0.999, 0.001, monobit_test);
+    }
+
+    #[test]
+    fn approximate_entropy_verification_onlyzero() {
+        rng_test_verification(
+            &mut rngs::testgens::OnlyZero::new(0),
+            DEFAULT_PMIN,
+            DEFAULT_PMIN,
+            approximate_entropy_test,
+        );
+    }
+    #[test]
+    fn approximate_entropy_verification_alternating_bits() {
+        rng_test_verification(
+            &mut rngs::testgens::AlternatingBits::new(0),
+            DEFAULT_PMIN,
+            DEFAULT_PMIN,
+            approximate_entropy_test,
+        );
+    }
+    #[test]
+    fn approximate_entropy_verification_random() {
+        rng_test_verification(
+            &mut rngs::ReferenceRand::new(0),
+            0.999,
+            0.001,
+            approximate_entropy_test,
+        );
+    }
+
+    #[test]
+    fn cumulative_sums_verification_onlyone() {
+        rng_test_verification(
+            &mut rngs::testgens::OnlyOne::new(0),
+            DEFAULT_PMIN,
+            DEFAULT_PMIN,
+            cumulative_sums_forward_test,
+        );
+        rng_test_verification(
+            &mut rngs::testgens::OnlyOne::new(0),
+            DEFAULT_PMIN,
+            DEFAULT_PMIN,
+            cumulative_sums_backward_test,
+        );
+    }
+    #[test]
+    fn cumulative_sums_verification_random() {
+        rng_test_verification(
+            &mut rngs::ReferenceRand::new(0),
+            0.999,
+            0.001,
+            cumulative_sums_forward_test,
+        );
+        rng_test_verification(
+            &mut rngs::ReferenceRand::new(0),
+            0.999,
+            0.001,
+            cumulative_sums_backward_test,
+        );
+    }
+    #[test]
+    fn cumulative_sums_excursion() {
+        // Bitstream starts with 4 zeroes followed by 60 ones.
+        let test_data = [!0xfu64];
+        assert_eq!(max_partial_sum_excursion(&test_data, false), 56);
+        assert_eq!(max_partial_sum_excursion(&test_data, true), 60);
+    }
+
+    #[test]
+    fn walk_extreme_distributions_exact() {
+        // Compare to the extremes of all 2^12 walks of 12 steps.
+        const STEPS: usize = 12;
+        let mut excursion_counts = [0usize; STEPS + 1];
+        let mut range_counts = [0usize; STEPS + 1];
+        for walk in 0u64..1 << STEPS {
+            let (mut partial_sum, mut max, mut min): (i64, i64, i64) = (0, 0, 0);
+            for step in 0..STEPS {
+                partial_sum += ((walk >> step) & 1) as i64 * 2 - 1;
+                max = max.max(partial_sum);
+                min = min.min(partial_sum);
+            }
+            excursion_counts[max.max(-min) as usize] += 1;
+            range_counts[(max - min) as usize] += 1;
+        }
+        let (excursion_cdf, range_cdf) = walk_extreme_distributions(STEPS, 1e-9);
+        for (counts, cdf) in [(excursion_counts, excursion_cdf), (range_counts, range_cdf)] {
+            let mut cumulative: usize = 0;
+            for (&count, &p) in counts.iter().zip(&cdf) {
+                cumulative += count;
+                assert!((p - cumulative as f64 / (1 << STEPS) as f64).abs() < 1e-12);
+            }
+            assert!(*cdf.last().unwrap() > 1.0 - 1e-9);
+        }
+    }
+    #[test]
+    fn random_walk_extremes_verification_alternating_bits() {
+        // Every walk stays within -1..=0.
+        rng_test_verification(
+            &mut rngs::testgens::AlternatingBits::new(0),
+            DEFAULT_PMIN,
+            DEFAULT_PMIN,
+            random_walk_extremes_test,
+        );
+    }
+    #[test]
+    fn random_walk_extremes_verification_random() {
+        rng_test_verification(
+            &mut rngs::ReferenceRand::new(0),
+            0.999,
+            0.001,
+            random_walk_extremes_test,
+        );
+    }
+
+    #[test]
+    fn random_excursions_verification_onlyzero() {
+        // The walk never returns to zero.
+        rng_test_verification(
+            &mut rngs::testgens::OnlyZero::new(0),
+            0.01,
+            DEFAULT_PMIN,
+            random_excursions_test,
+        );
+    }
+    #[test]
+    fn random_excursions_verification_random() {
+        // Enough bits to reach the minimum cycle count.
+        let (test_data, _) = generate_test_data(&mut rngs::ReferenceRand::new(0), 1 << 14);
+        let p = random_excursions_test(&test_data).unwrap();
+        assert!((0.001..=0.999).contains(&p), "p-value out of range: {}", p);
+    }
+
+    #[test]
+    fn zero_count_verification_alternating_bytes() {
+        rng_test_verification(
+            &mut rngs::testgens::AlternatingBytes::new(0),
+            DEFAULT_PMIN,
+            DEFAULT_PMIN,
+            zero_count_distribution_test,
+        );
+    }
+    #[test]
+    fn zero_count_verification_random() {
+        rng_test_verification(
+            &mut rngs::ReferenceRand::new(0),
+            0.999,
+            0.001,
+            zero_count_distribution_test,
+        );
+    }
+
+    #[test]
+    fn random_excursions_variant_verification_alternating_bits() {
+        // Only ever visits the states 0 and 1.
+        let (test_data, _) =
+            generate_test_data(&mut rngs::testgens::AlternatingBits::new(0), 1 << 14);
+        assert_eq!(random_excursions_variant_test(&test_data), Some(0.0));
+    }
+    #[test]
+    fn random_excursions_variant_verification_random() {
+        // Enough bits to reach the minimum cycle count.
+        let (test_data, _) = generate_test_data(&mut rngs::ReferenceRand::new(0), 1 << 14);
+        let p = random_excursions_variant_test(&test_data).unwrap();
+        assert!((0.001..=0.999).contains(&p), "p-value out of range: {}", p);
+    }
+    #[test]
+    fn random_excursions_variant_verification_nist_example() {
+        // Example 2.15.4: the walk of 0110110101 has 3 cycles and visits 1 four times and -1 once.
+        assert!((excursion_visits_p_value(4, 3, 1) - 0.683091).abs() < 1e-6);
+        assert!((excursion_visits_p_value(1, 3, 1) - 0.414216).abs() < 1e-6);
+        assert_eq!(excursion_visits_p_value(3, 3, 9), 1.0);
+    }
+
+    #[test]
+    fn seed_avalanche_verification_xorshift() {
+        // Flipping a seed bit flips it in both state words.
+        let p = seed_avalanche_test(
+            &mut rngs::xorshift::XORShift128::new(0),
+            &testdata::rng_test::STATIC_TEST_SEEDS[0..4],
+            16,
+        )
+        .unwrap();
+        assert!(p < 1e-6, "p-value out of range: {}", p);
+    }
+    #[test]
+    fn seed_avalanche_verification_random() {
+        let p = seed_avalanche_test(
+            &mut rngs::ReferenceRand::new(0),
+            &testdata::rng_test::STATIC_TEST_SEEDS[0..4],
+            16,
+        )
+        .unwrap();
+        assert!((0.001..=0.999).contains(&p), "p-value out of range: {}", p);
+    }
+
+    /// SplitMix64 whose children continue the stream of the parent.
+    struct OverlappingSplit(rngs::splitmix::SplitMix64);
+
+    impl RNG for OverlappingSplit {
+        fn new(seed: u64) -> Self {
+            OverlappingSplit(rngs::splitmix::SplitMix64::new(seed))
+        }
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+        fn next(&mut self) -> u64 {
+            self.0.next()
+        }
+        fn advance(&mut self, delta: u64) {
+            self.0.advance(delta);
+        }
+        fn reseed(&mut self, seed: u64) {
+            self.0.reseed(seed);
+        }
+        fn split(&mut self) -> Self {
+            let mut child = OverlappingSplit(self.0);
+            child.advance(1);
+            child
+        }
+    }
+
+    #[test]
+    fn split_correlation_verification_overlap() {
+        // The child is the parent one output ahead.
+        let p = split_correlation_test(
+            &mut OverlappingSplit::new(0),
+            &testdata::rng_test::STATIC_TEST_SEEDS[0..4],
+            64,
+        )
+        .unwrap();
+        assert!(p < 1e-6, "p-value out of range: {}", p);
+    }
+    #[test]
+    fn split_correlation_verification_random() {
+        let p = split_correlation_test(
+            &mut rngs::ReferenceRand::new(0),
+            &testdata::rng_test::STATIC_TEST_SEEDS[0..4],
+            64,
+        )
+        .unwrap();
+        assert!((0.001..=0.999).contains(&p), "p-value out of range: {}", p);
+    }
+
+    #[test]
+    fn poker_verification_alternating_bytes() {
+        // Digits alternate between 0x0 and 0xf.
+        rng_test_verification(
+            &mut rngs::testgens::AlternatingBytes::new(0),
+            DEFAULT_PMIN,
+            DEFAULT_PMIN,
+            poker_test,
+        );
+    }
+    #[test]
+    fn poker_verification_random() {
+        rng_test_verification(&mut rngs::ReferenceRand::new(0), 0.999, 0.001, poker_test);
+        let (test_data, _) = generate_test_data(&mut rngs::ReferenceRand::new(1), 512);
+        let p = poker_test_with_params(&test_data, 2, 4).unwrap();
+        assert!((0.001..=0.999).contains(&p), "p-value out of range: {}", p);
+    }
+    #[test]
+    fn poker_invalid_params() {
+        let (test_data, _) = generate_test_data(&mut rngs::ReferenceRand::new(0), 512);
+        assert_eq!(poker_test_with_params(&test_data, 3, 5), None);
+        assert_eq!(poker_test_with_params(&test_data, 4, 1), None);
+        assert_eq!(poker_test_with_params(&test_data[..1], 4, 5), None);
+    }
+
+    #[test]
+    fn permutation_verification_alternating_blocks() {
+        // Constant ordering within every tuple.
+        rng_test_verification(
+            &mut rngs::testgens::AlternatingBlocks::new(0),
+            DEFAULT_PMIN,
+            DEFAULT_PMIN,
+            permutation_test,
+        );
+    }
+    #[test]
+    fn permutation_verification_random() {
+        rng_test_verification(
+            &mut rngs::ReferenceRand::new(0),
+            0.999,
+            0.001,
+            permutation_test,
+        );
+    }
+    #[test]
+    fn permutation_lehmer_code() {
+        // Ascending tuples all map to the identity permutation.
+        let test_data: Vec<u64> = (0..90).collect();
+        assert!(permutation_test_with_k(&test_data, 3).unwrap() < 1e-20);
+        // Fewer than 5 tuples per permutation.
+        assert_eq!(permutation_test_with_k(&test_data[..60], 3), None);
+        assert_eq!(permutation_test(&test_data[..60]), None);
+    }
+
+    #[test]
+    fn collision_distribution_sums_to_one() {
+        // Two balls collide with probability 1 / buckets.
+        let probabilities = collision_distribution(2, 8.0, 1);
+        assert!((probabilities[1] - 0.125).abs() < 1e-12);
+        let probabilities = collision_distribution(1 << 10, (1 << 16) as f64, 200);
+        assert!((probabilities.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        let mean: f64 = probabilities
+            .iter()
+            .enumerate()
+            .map(|(c, p)| c as f64 * p)
+            .sum();
+        // Exact mean: balls - buckets * (1 - (1 - 1 / buckets)^balls)
+        let buckets = (1 << 16) as f64;
+        let exact_mean = 1024.0 - buckets * (1.0 - (1.0 - 1.0 / buckets).powi(1024));
+        assert!((mean - exact_mean).abs() < 1e-6);
+    }
+
+    #[test]
+    fn collision_verification_random() {
+        let (test_data, _) = generate_test_data(&mut rngs::ReferenceRand::new(0), 1 << 16);
+        for shift in [0, 24, 48] {
+            let p = collision_test_with_params(&test_data, 16, shift, 1 << 10).unwrap();
+            assert!((0.001..=0.999).contains(&p), "p-value out of range: {}", p);
+        }
+    }
+
+    #[test]
+    fn collision_verification_reduced_range() {
+        // The lowest bit of RANDU outputs is constant, halving the low bucket range.
+        let (test_data, _) = generate_test_data(&mut rngs::lcg::Randu::new(1), 1 << 16);
+        assert!(collision_test_with_params(&test_data, 16, 0, 1 << 10).unwrap() < 0.001);
+        assert_eq!(
+            collision_test_with_params(&test_data, 16, 60, 1 << 10),
+            None
+        );
+        assert_eq!(
+            collision_test_with_params(&test_data[..100], 16, 0, 1 << 10),
+            None
+        );
+    }
+
+    #[test]
+    fn matrix_rank_distribution() {
+        let probabilities: Vec<f64> = (0..=32)
+            .map(|rank| binary_matrix_rank_probability(32, rank))
+            .collect();
+        assert!((probabilities.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        // NIST Special Publication 800-22 Section 3.5
+        assert!((probabilities[32] - 0.2888).abs() < 5e-5);
+        assert!((probabilities[31] - 0.5776).abs() < 5e-5);
+        assert!((probabilities[..31].iter().sum::<f64>() - 0.1336).abs() < 5e-5);
+        assert_eq!(binary_matrix_rank_probability(2, 1), 9.0 / 16.0);
+    }
+
+    #[test]
+    fn matrix_ranks_64x64() {
+        let probabilities: Vec<f64> = (0..=64)
+            .map(|rank| binary_matrix_rank_probability(64, rank))
+            .collect();
+        assert!((probabilities.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        // Both limits agree with 32x32 matrices to 9 digits.
+        assert!((probabilities[64] - 0.2887880950866).abs() < 1e-9);
+        let (test_data, _) = generate_test_data(&mut rngs::ReferenceRand::new(15), 1 << 14);
+        let p = matrix_ranks_64(&test_data).unwrap();
+        assert!((0.001..=0.999).contains(&p), "p = {}", p);
+        // The last row of every matrix repeats the first one.
+        let dependent: Vec<u64> = test_data
+            .chunks_exact(64)
+            .flat_map(|chunk| {
+                let mut matrix = chunk.to_vec();
+                matrix[63] = matrix[0];
+                matrix
+            })
+            .collect();
+        assert!(matrix_ranks_64(&dependent).unwrap() < 1e-9);
+        assert!(matrix_ranks(&dependent).unwrap() > 1e-9);
+        assert_eq!(matrix_ranks_64(&test_data[..63]), None);
+        assert_eq!(matrix_ranks_with_size(&test_data, 48), None);
+        assert!(matrix_ranks_with_size(&test_data, 16).is_some());
+    }
+
+    #[test]
+    fn matrix_ranks_p_value() {
+        // 100 matrices with exactly known ranks give a known chi2 statistic.
+        let ranks: Vec<usize> = [(32, 30), (31, 60), (30, 8), (5, 2)]
+            .iter()
+            .flat_map(|&(rank, count)| std::iter::repeat_n(rank, count))
+            .collect();
+        let test_data = testdata::fixtures::matrix_stream_with_ranks(0, &ranks);
+        let expected = [
+            binary_matrix_rank_probability(32, 32) * 100.0,
+            binary_matrix_rank_probability(32, 31) * 100.0,
+        ];
+        let expected = [expected[0], expected[1], 100.0 - expected[0] - expected[1]];
+        let chi_squared: f64 = [30.0, 60.0, 10.0]
+            .iter()
+            .zip(expected)
+            .map(|(count, exp)| (count - exp).powi(2) / exp)
+            .sum();
+        let p = matrix_ranks(&test_data).unwrap();
+        assert!((p - (-chi_squared / 2.0).exp()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn matrix_ranks_calibration() {
+        // Ranks drawn from the exact distribution must give uniform p values.
+        const RUNS: usize = 200;
+        let probabilities: Vec<f64> = (0..=32)
+            .map(|rank| binary_matrix_rank_probability(32, rank))
+            .collect();
+        let mut p_bins = [0usize; 10];
+        for seed in 0..RUNS as u64 {
+            let test_data =
+                testdata::fixtures::matrix_stream_with_rank_distribution(seed, &probabilities, 256);
+            let p = matrix_ranks(&test_data).unwrap();
+            p_bins[((p * 10.0) as usize).min(9)] += 1;
+        }
+        let expected = RUNS as f64 / 10.0;
+        let chi_squared: f64 = p_bins
+            .iter()
+            .map(|&count| (count as f64 - expected).powi(2) / expected)
+            .sum();
+        let p = statrs::function::gamma::gamma_ur(4.5, chi_squared / 2.0);
+        assert!(p > 0.001, "p values not uniform: {:?}", p_bins);
+        // A surplus of rank 31 matrices is detected.
+        let mut skewed = probabilities.clone();
+        skewed[31] *= 1.5;
+        let test_data = testdata::fixtures::matrix_stream_with_rank_distribution(0, &skewed, 1024);
+        assert!(matrix_ranks(&test_data).unwrap() < 0.001);
+    }
+
+    #[test]
+    fn reused_buffer() {
+        let mut buffer: Vec<u64> = Vec::with_capacity(1024);
+        let allocation = buffer.as_ptr();
+        for (seed, sample_size) in [(1, 1024), (2, 16), (3, 1024)] {
+            fill_test_data(
+                &mut rngs::xorshift::WyRand::new(seed),
+                &mut buffer,
+                sample_size,
+            );
+            let (expected, _) =
+                generate_test_data(&mut rngs::xorshift::WyRand::new(seed), sample_size);
+            assert_eq!(buffer, expected);
+            assert_eq!(buffer.as_ptr(), allocation);
+        }
+    }
+
+    #[test]
+    fn gorilla_tests() {
+        const WORD_BITS: u32 = 14;
+        let (test_data, _) =
+            generate_test_data(&mut rngs::ReferenceRand::new(3), (1 << WORD_BITS) + 13);
+        let p = gorilla_test_with_word_bits(&test_data, WORD_BITS).unwrap();
+        assert!((0.001..=0.999).contains(&p), "p = {}", p);
+        // The low bits of a power of two modulus LCG have short periods.
+        let (lcg_data, _) = generate_test_data(&mut rngs::lcg::Mmix::new(3), (1 << WORD_BITS) + 13);
+        assert!(gorilla_test_with_params(&lcg_data, WORD_BITS, 0).unwrap() > 1.0 - 1e-9);
+        assert!(gorilla_test_with_word_bits(&lcg_data, WORD_BITS).unwrap() < 1e-9);
+        assert_eq!(
+            gorilla_test_with_word_bits(&test_data[..1 << WORD_BITS], WORD_BITS),
+            None
+        );
+        assert_eq!(gorilla_test_with_params(&test_data, 5, 0), None);
+        assert_eq!(gorilla_test_with_params(&test_data, WORD_BITS, 64), None);
+        assert_eq!(gorilla_test(&test_data), Some(p));
+        assert_eq!(gorilla_test(&test_data[..(1 << 6) + 4]), None);
+    }
+
+    #[test]
+    fn gorilla_test_default_size() {
+        // The suite sample size of 2^22 u64s runs the test with 21-bit words.
+        let (test_data, _) = generate_test_data(&mut rngs::ReferenceRand::new(3), 1 << 22);
+        let p = gorilla_test(&test_data).unwrap();
+        assert!((0.001..=0.999).contains(&p), "p = {}", p);
+        assert_eq!(Some(p), gorilla_test_with_word_bits(&test_data, 21));
+    }
+
+    #[test]
+    fn monkey_tests() {
+        let (test_data, _) = generate_test_data(&mut rngs::ReferenceRand::new(3), MONKEY_WORDS + 9);
+        for test in [opso_test, oqso_test, dna_test] {
+            let p = test(&test_data).unwrap();
+            assert!((0.001..=0.999).contains(&p), "p = {}", p);
+            assert_eq!(test(&test_data[..MONKEY_WORDS]), None);
+        }
+        // A constant stream produces a single word.
+        let (constant, _) =
+            generate_test_data(&mut rngs::testgens::OnlyZero::new(0), MONKEY_WORDS + 9);
+        assert!(opso_test(&constant).is_some_and(|p| p > 1.0 - 1e-9));
+        // Letters of a counter repeat every 1024 outputs.
+        let counter: Vec<u64> = (0..MONKEY_WORDS as u64 + 1).collect();
+        assert_eq!(
+            monkey_test_with_params(&counter, 10, 2, 0, 290.0),
+            Some(1.0)
+        );
+        assert_eq!(monkey_test_with_params(&counter, 10, 3, 0, 290.0), None);
+    }
+
+    #[test]
+    fn bit_position_frequency() {
+        let (mut test_data, _) = generate_test_data(&mut rngs::ReferenceRand::new(5), 4096);
+        let p = bit_position_frequency_test(&test_data).unwrap();
+        assert!((0.001..=0.999).contains(&p), "p = {}", p);
+        // Bit 0 is set in a quarter of the outputs, which the monobit test does not notice.
+        for sample in test_data.iter_mut() {
+            let biased_bit = (*sample >> 1) & (*sample >> 2) & 1;
+            *sample = (*sample & !1) | biased_bit;
+        }
+        assert!(monobit_test(&test_data).unwrap() > 0.001);
+        assert!(bit_position_frequency_test(&test_data).unwrap() < 1e-9);
+        assert_eq!(bit_position_frequency_test(&test_data[..99]), None);
+    }
+
+    #[test]
+    fn hamming_weight_distribution() {
+        let (test_data, _) = generate_test_data(&mut rngs::ReferenceRand::new(6), 1 << 14);
+        let p = hamming_weight_test(&test_data).unwrap();
+        assert!((0.001..=0.999).contains(&p), "p = {}", p);
+        // Every word has weight 32, so the bit count is perfectly balanced.
+        let balanced = vec![0x5555555555555555u64; 1 << 10];
+        assert!(monobit_test(&balanced).unwrap() > 0.999);
+        assert!(hamming_weight_test(&balanced).unwrap() < 1e-9);
+        assert_eq!(hamming_weight_test(&[]), None);
+        assert_eq!(hamming_weight_test(&test_data[..1]), None);
+    }
+
+    #[test]
+    fn adjacent_distance_distribution() {
+        let (test_data, _) = generate_test_data(&mut rngs::ReferenceRand::new(7), 1 << 14);
+        let p = adjacent_distance_test(&test_data).unwrap();
+        assert!((0.001..=0.999).contains(&p), "p = {}", p);
+        // Each step flips random bits within one random byte, a random walk over all words.
+        let mut drifting = test_data.clone();
+        for i in 1..drifting.len() {
+            let byte_position = 8 * (test_data[i] >> 61);
+            drifting[i] = drifting[i - 1] ^ ((test_data[i] & 0xff) << byte_position);
+        }
+        assert!(adjacent_distance_test(&drifting).unwrap() < 1e-9);
+        assert_eq!(adjacent_distance_test(&test_data[..1]), None);
+    }
+
+    #[test]
+    fn compression_ratio() {
+        let (test_data, _) = generate_test_data(&mut rngs::ReferenceRand::new(8), 1 << 17);
+        let p = compression_test(&test_data).unwrap();
+        assert!((0.001..=0.999).contains(&p), "p = {}", p);
+        let (alternating, _) =
+            generate_test_data(&mut rngs::testgens::AlternatingBytes::new(0), 1 << 17);
+        assert!(compression_test(&alternating).unwrap() < 1e-9);
+        assert_eq!(compression_test(&test_data[..(1 << 17) - 1]), None);
+    }
+
+    #[test]
+    fn move_to_front() {
+        let (test_data, _) = generate_test_data(&mut rngs::ReferenceRand::new(9), 64);
+        let words: Vec<u16> = test_data
+            .iter()
+            .map(|&sample| sample as u16 & 0xff)
+            .collect();
+        let mut list: Vec<u16> = (0..256).collect();
+        let expected: Vec<usize> = words
+            .iter()
+            .map(|word| {
+                let position = list.iter().position(|x| x == word).unwrap();
+                list.remove(position);
+                list.insert(0, *word);
+                position
+            })
+            .collect();
+        assert_eq!(move_to_front_positions(&words, 8), expected);
+    }
+
+    #[test]
+    fn book_stack() {
+        let (test_data, _) = generate_test_data(&mut rngs::ReferenceRand::new(10), 1 << 16);
+        let p = book_stack_test(&test_data).unwrap();
+        assert!((0.001..=0.999).contains(&p), "p = {}", p);
+        // Only 4096 distinct words, all of them stay near the front.
+        let clustered: Vec<u64> = test_data
+            .iter()
+            .map(|sample| sample & 0x0fff0fff0fff0fff)
+            .collect();
+        assert!(book_stack_test(&clustered).unwrap() < 1e-9);
+        assert_eq!(book_stack_test(&test_data[..319]), None);
+    }
+
+    #[test]
+    fn borel_normality() {
+        let (test_data, _) = generate_test_data(&mut rngs::ReferenceRand::new(11), 1 << 14);
+        assert_eq!(borel_max_block_bits(test_data.len() * 64), 4);
+        assert_eq!(bit_block_counts(&[0b1110_0100], 2)[..], [29, 1, 1, 1]);
+        assert_eq!(bit_block_counts(&[u64::MAX; 3], 5)[31], 38);
+        assert!(borel_normal(&test_data));
+        let p = borel_normality_test(&test_data).unwrap();
+        assert!((0.001..=0.999).contains(&p), "p = {}", p);
+        // Every fourth bit is zero.
+        let biased: Vec<u64> = test_data
+            .iter()
+            .map(|sample| sample & 0xbbbbbbbbbbbbbbbb)
+            .collect();
+        assert!(!borel_normal(&biased));
+        assert_eq!(borel_normality_test(&biased), Some(0.0));
+        assert_eq!(borel_normality_test(&[]), None);
+    }
+
+    #[test]
+    fn kolmogorov_smirnov() {
+        // Critical values of the Kolmogorov distribution.
+        assert!((kolmogorov_p_value(1.3581) - 0.05).abs() < 1e-4);
+        assert!((kolmogorov_p_value(1.6276) - 0.01).abs() < 1e-4);
+        assert!((kolmogorov_p_value(0.8276) - 0.5).abs() < 1e-3);
+        assert!((kolmogorov_p_value(1.1799) - kolmogorov_p_value(1.1801)).abs() < 1e-3);
+        assert_eq!(kolmogorov_p_value(0.0), 1.0);
+        let (test_data, _) = generate_test_data(&mut rngs::ReferenceRand::new(12), 1 << 14);
+        let p = kolmogorov_smirnov_test(&test_data).unwrap();
+        assert!((0.001..=0.999).contains(&p), "p = {}", p);
+        // The minimum of two floats is not uniform, although every bit is still balanced in isolation.
+        let skewed: Vec<u64> = test_data
+            .chunks(2)
+            .map(|pair| (pair[0] & 0x000fffffffffffff).min(pair[1] & 0x000fffffffffffff))
+            .collect();
+        assert!(kolmogorov_smirnov_test(&skewed).unwrap() < 1e-9);
+        assert_eq!(kolmogorov_smirnov_test(&test_data[..99]), None);
+        let spread: Vec<f64> = (0..16).map(|i| (i as f64 + 0.5) / 16.0).collect();
+        assert!(second_level_ks_test(&spread).unwrap() > 0.999);
+        assert!(second_level_ks_test(&[0.01; 16]).unwrap() < 1e-6);
+        assert_eq!(second_level_ks_test(&spread[..7]), None);
+    }
+
+    #[test]
+    fn anderson_darling() {
+        // Critical values of the asymptotic distribution.
+        assert!((anderson_darling_p_value(2.492, 1_000_000) - 0.05).abs() < 1e-3);
+        assert!((anderson_darling_p_value(3.857, 1_000_000) - 0.01).abs() < 1e-3);
+        assert_eq!(anderson_darling_p_value(0.0, 10), 1.0);
+        let (test_data, _) = generate_test_data(&mut rngs::ReferenceRand::new(13), 1 << 14);
+        let p = anderson_darling_test(&test_data).unwrap();
+        assert!((0.001..=0.999).contains(&p), "p = {}", p);
+        // Too few values close to 0 and 1, hardly visible in the largest CDF distance.
+        let thin_tails: Vec<u64> = test_data
+            .iter()
+            .map(|&sample| {
+                let value = sample & 0x000fffffffffffff;
+                if value >> 48 == 0 || value >> 48 == 0xf {
+                    value ^ 0x0008000000000000
+                } else {
+                    value
+                }
+            })
+            .collect();
+        assert!(anderson_darling_test(&thin_tails).unwrap() < 1e-6);
+        assert_eq!(anderson_darling_test(&test_data[..99]), None);
+        let uniform: Vec<f64> = (0..64).map(|i| (i as f64 + 0.5) / 64.0).collect();
+        assert!(second_level_test(&uniform).unwrap() > 0.999);
+        let clustered: Vec<f64> = (0..64).map(|i| 0.4 + i as f64 / 640.0).collect();
+        assert!(second_level_test(&clustered).unwrap() < 1e-9);
+        assert_eq!(second_level_test(&uniform[..7]), None);
+    }
+
+    #[test]
+    fn overlapping_windows() {
+        let counts = overlapping_window_counts(&[0xffff, 0]);
+        assert_eq!(counts.iter().sum::<usize>(), 128);
+        assert_eq!(counts[0xffff], 1);
+        assert_eq!(counts[0], 128 - 2 * 16 + 1);
+        let (test_data, _) = generate_test_data(&mut rngs::ReferenceRand::new(14), 1 << 14);
+        let p = overlapping_window_test(&test_data).unwrap();
+        assert!((0.001..=0.999).contains(&p), "p = {}", p);
+        // Every 16 bit chunk has even parity, each byte on its own is still uniform.
+        let patterned: Vec<u64> = test_data
+            .iter()
+            .map(|&sample| {
+                let mut parity = sample;
+                for shift in [1, 2, 4, 8] {
+                    parity ^= parity >> shift;
+                }
+                sample ^ (parity & 0x0001000100010001) << 15
+            })
+            .collect();
+        assert!(overlapping_window_test(&patterned).unwrap() < 1e-9);
+        assert_eq!(overlapping_window_test(&test_data[..5119]), None);
     }
 }
@@ -7,7 +7,22 @@
 pub const FAIL_STR: &str = "FAILED!!";
 pub const MARGINAL_STR: &str = "MARGINAL!";
 pub const PASS_STR: &str = "PASSED";
+pub const EXPECTED_FAIL_STR: &str = "OK (expected fail)";
+pub const UNEXPECTED_PASS_STR: &str = "UNEXPECTED PASS!!";
+pub const NOT_APPLICABLE_STR: &str = "N/A";
 
-pub const TEST_NAMES: [&str; 7] = [
-    "Bytes", "LZ-Space", "Mono", "Runs", "Blocks", "MaxOnes", "Matrix",
+pub const TEST_NAMES: [&str; 33] = [
+    "Bytes", "LZ-Space", "Mono", "Runs", "Blocks", "MaxOnes", "Matrix", "ApEn", "CusumF", "CusumB",
+    "RndExc", "LZ-TZ", "RndExcV", "Poker", "Perm", "Collide", "OPSO", "OQSO", "DNA", "BitFreq",
+    "Weight", "AdjDist", "Compress", "BookStk", "Borel", "KS", "AD", "Window16", "Matrix64",
+    "BitCorr", "Linear", "Gorilla", "WalkExt",
 ];
+
+/// Name of the seed avalanche test, which tests the seeding routine instead of a single stream.
+pub const SEED_AVALANCHE_NAME: &str = "SeedAval";
+
+/// Name of the split correlation test, which compares the streams of parents and children of `RNG::split`.
+pub const SPLIT_CORRELATION_NAME: &str = "SplitCorr";
+
+/// Name of the second level test of the p values of all other results.
+pub const SECOND_LEVEL_NAME: &str = "PUnif";
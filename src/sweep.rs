@@ -0,0 +1,171 @@
+// Copyright 2025 N. Dornseif
+//
+// Dual-licensed under Apache 2.0 and MIT terms.
+
+//! Sample size sweeps: the battery is run at increasing sample sizes,
+//! and every test is summarized by the smallest size at which it failed.
+//! The resulting failure exponents are a fingerprint of a generator, comparable across generators.
+
+use crate::report::{SuiteReport, Verdict};
+
+/// Smallest sample size at which each test failed, for one generator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailureFingerprint {
+    pub rng_name: String,
+    /// log2 of the largest sample size that was run.
+    pub max_exponent: u32,
+    /// Test names in run order with the log2 of the smallest failing sample size,
+    /// `None` if the test did not fail at any size.
+    pub failures: Vec<(&'static str, Option<u32>)>,
+}
+
+/// Names of the tests with at least one failed result, expected failures included.
+fn failed_tests(report: &SuiteReport) -> Vec<&'static str> {
+    let mut names: Vec<&'static str> = vec![];
+    for row in report.rows() {
+        if matches!(row.record.verdict, Verdict::Fail | Verdict::ExpectedFail)
+            && !names.contains(&row.record.name)
+        {
+            names.push(row.record.name);
+        }
+    }
+    names
+}
+
+impl FailureFingerprint {
+    /// Summarize the reports of one generator, each with the log2 of its sample size.
+    pub fn from_reports(rng_name: &str, reports: &[(u32, SuiteReport)]) -> Self {
+        let mut failures: Vec<(&'static str, Option<u32>)> = vec![];
+        for (exponent, report) in reports {
+            let failed = failed_tests(report);
+            for row in report.rows() {
+                let name = row.record.name;
+                let position = match failures.iter().position(|(known, _)| *known == name) {
+                    Some(position) => position,
+                    None => {
+                        failures.push((name, None));
+                        failures.len() - 1
+                    }
+                };
+                if failed.contains(&name) {
+                    let first = &mut failures[position].1;
+                    *first = Some(first.map_or(*exponent, |first| first.min(*exponent)));
+                }
+            }
+        }
+        FailureFingerprint {
+            rng_name: rng_name.to_string(),
+            max_exponent: reports
+                .iter()
+                .map(|(exponent, _)| *exponent)
+                .max()
+                .unwrap_or(0),
+            failures,
+        }
+    }
+
+    /// log2 of the smallest sample size at which any test failed.
+    pub fn first_failure(&self) -> Option<u32> {
+        self.failures.iter().filter_map(|(_, first)| *first).min()
+    }
+
+    /// Check if every test failed, larger sample sizes can not add information.
+    pub fn all_failed(&self) -> bool {
+        self.failures.iter().all(|(_, first)| first.is_some())
+    }
+}
+
+/// Table with one line per generator and one column per test,
+/// each cell holding the failure exponent or `-` if the test never failed.
+pub fn format_fingerprints(fingerprints: &[FailureFingerprint]) -> String {
+    let mut test_names: Vec<&'static str> = vec![];
+    for fingerprint in fingerprints {
+        for (name, _) in &fingerprint.failures {
+            if !test_names.contains(name) {
+                test_names.push(name);
+            }
+        }
+    }
+    let cell = |exponent: Option<u32>| exponent.map_or("-".to_string(), |e| e.to_string());
+    let mut header = format!("{:<20}{:>6}{:>6}", "Name", "Max", "First");
+    for name in &test_names {
+        header.push_str(&format!(" {:>8}", name));
+    }
+    let mut lines = vec![header];
+    for fingerprint in fingerprints {
+        let mut line = format!(
+            "{:<20}{:>6}{:>6}",
+            fingerprint.rng_name,
+            fingerprint.max_exponent,
+            cell(fingerprint.first_failure())
+        );
+        for name in &test_names {
+            let first = fingerprint
+                .failures
+                .iter()
+                .find(|(known, _)| known == name)
+                .and_then(|(_, first)| *first);
+            line.push_str(&format!(" {:>8}", cell(first)));
+        }
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::{SeedReport, TestRecord};
+
+    fn report(verdicts: [Verdict; 2]) -> SuiteReport {
+        let record = |name, verdict| TestRecord {
+            name,
+            p: Some(0.5),
+            score: Some(0.4),
+            verdict,
+        };
+        SuiteReport {
+            rng_name: "MMIX".to_string(),
+            sample_size: 512,
+            scoring: "pls",
+            bytes_per_second: 1e9,
+            seeds: vec![SeedReport {
+                seed: 1,
+                results: vec![record("Mono", verdicts[0]), record("Runs", verdicts[1])],
+            }],
+            generator_results: vec![],
+            second_level_results: vec![],
+            period: None,
+            metadata: None,
+            seek_consistent: None,
+            state_restorable: None,
+            weak_seeds: None,
+            passed: false,
+        }
+    }
+
+    #[test]
+    fn fingerprint() {
+        let reports = [
+            (20, report([Verdict::Pass, Verdict::Pass])),
+            (22, report([Verdict::Pass, Verdict::Fail])),
+            (24, report([Verdict::Pass, Verdict::Marginal])),
+        ];
+        let fingerprint = FailureFingerprint::from_reports("MMIX", &reports);
+        assert_eq!(
+            fingerprint.failures,
+            vec![("Mono", None), ("Runs", Some(22))]
+        );
+        assert_eq!(fingerprint.first_failure(), Some(22));
+        assert_eq!(fingerprint.max_exponent, 24);
+        assert!(!fingerprint.all_failed());
+        let table = format_fingerprints(&[fingerprint]);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].ends_with("Mono     Runs"));
+        assert!(lines[1].starts_with("MMIX"));
+        assert!(lines[1].ends_with("24    22        -       22"));
+        let failing = [(20, report([Verdict::ExpectedFail, Verdict::Fail]))];
+        assert!(FailureFingerprint::from_reports("OnlyZero", &failing).all_failed());
+    }
+}
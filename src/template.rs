@@ -0,0 +1,293 @@
+// Copyright 2025 N. Dornseif
+//
+// Dual-licensed under Apache 2.0 and MIT terms.
+
+//! Rendering of reports with user supplied templates, to match external report formats.
+//! `{{name}}` inserts a value, `{{#name}}...{{/name}}` repeats the enclosed part for every item of a list.
+//! Values of enclosing lists stay accessible, so a CSV line can be written as
+//! `{{#generators}}{{#results}}{{rng_name}},{{test}},{{p}}` followed by a newline and `{{/results}}{{/generators}}`.
+//!
+//! | Scope | Values | Lists |
+//! |---|---|---|
+//! | Top level | `version`, `generator_count` | `generators` |
+//! | `generators` | `rng_name`, `sample_size`, `scoring`, `result`, `result_count`, `failed_count` | `results`, `failed`, `second_level` |
+//! | `results`, `failed`, `second_level` | `test`, `seed`, `p`, `score`, `verdict` | |
+
+use crate::report::{ResultRow, SuiteReport};
+
+/// Part of a parsed template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Node {
+    Text(String),
+    Value(String),
+    List(String, Vec<Node>),
+}
+
+/// A parsed template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    nodes: Vec<Node>,
+}
+
+/// Item a template part is rendered for.
+#[derive(Clone, Copy)]
+enum Scope<'a> {
+    Run(&'a [SuiteReport]),
+    Generator(&'a SuiteReport),
+    Row(ResultRow<'a>),
+}
+
+/// Format an optional p-value or score, missing values become `-`.
+fn format_optional(value: Option<f64>) -> String {
+    value.map_or("-".to_string(), |value| format!("{:.6}", value))
+}
+
+impl<'a> Scope<'a> {
+    fn value(&self, name: &str) -> Option<String> {
+        match (self, name) {
+            (Scope::Run(_), "version") => Some(env!("CARGO_PKG_VERSION").to_string()),
+            (Scope::Run(reports), "generator_count") => Some(reports.len().to_string()),
+            (Scope::Generator(report), "rng_name") => Some(report.rng_name.clone()),
+            (Scope::Generator(report), "sample_size") => Some(report.sample_size.to_string()),
+            (Scope::Generator(report), "scoring") => Some(report.scoring.to_string()),
+            (Scope::Generator(report), "result") => {
+                Some(if report.passed { "passed" } else { "failed" }.to_string())
+            }
+            (Scope::Generator(report), "result_count") => Some(report.rows().count().to_string()),
+            (Scope::Generator(report), "failed_count") => Some(report.failed().len().to_string()),
+            (Scope::Row(row), "test") => Some(row.record.name.to_string()),
+            (Scope::Row(row), "seed") => Some(
+                row.seed
+                    .map_or("generator".to_string(), |seed| format!("{:#018x}", seed)),
+            ),
+            (Scope::Row(row), "p") => Some(format_optional(row.record.p)),
+            (Scope::Row(row), "score") => Some(format_optional(row.record.score)),
+            (Scope::Row(row), "verdict") => Some(row.record.verdict.as_str().to_string()),
+            _ => None,
+        }
+    }
+
+    fn list(&self, name: &str) -> Option<Vec<Scope<'a>>> {
+        match (*self, name) {
+            (Scope::Run(reports), "generators") => {
+                Some(reports.iter().map(Scope::Generator).collect())
+            }
+            (Scope::Generator(report), "results") => Some(report.rows().map(Scope::Row).collect()),
+            (Scope::Generator(report), "failed") => {
+                Some(report.failed().into_iter().map(Scope::Row).collect())
+            }
+            (Scope::Generator(report), "second_level") => Some(
+                report
+                    .second_level_results
+                    .iter()
+                    .map(|record| {
+                        Scope::Row(ResultRow {
+                            rng_name: &report.rng_name,
+                            seed: None,
+                            record,
+                        })
+                    })
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// Parse nodes until the end of the template or the closing tag of `list`.
+/// Returns the nodes and the remaining template after the closing tag.
+fn parse_nodes<'a>(
+    mut template: &'a str,
+    list: Option<&str>,
+) -> Result<(Vec<Node>, &'a str), String> {
+    let mut nodes = vec![];
+    loop {
+        let Some(start) = template.find("{{") else {
+            if let Some(list) = list {
+                return Err(format!("Missing {{{{/{}}}}}", list));
+            }
+            if !template.is_empty() {
+                nodes.push(Node::Text(template.to_string()));
+            }
+            return Ok((nodes, ""));
+        };
+        if start > 0 {
+            nodes.push(Node::Text(template[..start].to_string()));
+        }
+        let Some(length) = template[start..].find("}}") else {
+            return Err("Unclosed {{".to_string());
+        };
+        let tag = template[start + 2..start + length].trim();
+        template = &template[start + length + 2..];
+        if let Some(name) = tag.strip_prefix('#') {
+            let (children, rest) = parse_nodes(template, Some(name.trim()))?;
+            nodes.push(Node::List(name.trim().to_string(), children));
+            template = rest;
+        } else if let Some(name) = tag.strip_prefix('/') {
+            return match list {
+                Some(list) if list == name.trim() => Ok((nodes, template)),
+                _ => Err(format!("Unexpected {{{{/{}}}}}", name.trim())),
+            };
+        } else if tag.is_empty() {
+            return Err("Empty {{}}".to_string());
+        } else {
+            nodes.push(Node::Value(tag.to_string()));
+        }
+    }
+}
+
+/// Render `nodes` for the innermost scope of `scopes`, names are looked up from the inside out.
+fn render_nodes<'a>(
+    nodes: &[Node],
+    scopes: &mut Vec<Scope<'a>>,
+    output: &mut String,
+) -> Result<(), String> {
+    for node in nodes {
+        match node {
+            Node::Text(text) => output.push_str(text),
+            Node::Value(name) => {
+                let value = scopes
+                    .iter()
+                    .rev()
+                    .find_map(|scope| scope.value(name))
+                    .ok_or_else(|| format!("Unknown value '{}'", name))?;
+                output.push_str(&value);
+            }
+            Node::List(name, children) => {
+                let items = scopes
+                    .iter()
+                    .rev()
+                    .find_map(|scope| scope.list(name))
+                    .ok_or_else(|| format!("Unknown list '{}'", name))?;
+                for item in items {
+                    scopes.push(item);
+                    let result = render_nodes(children, scopes, output);
+                    scopes.pop();
+                    result?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+impl Template {
+    pub fn parse(template: &str) -> Result<Self, String> {
+        let (nodes, _) = parse_nodes(template, None)?;
+        Ok(Template { nodes })
+    }
+
+    /// Render the template for `reports`.
+    /// Fails on unknown names, names inside lists without items are not checked.
+    pub fn render(&self, reports: &[SuiteReport]) -> Result<String, String> {
+        let mut output = String::new();
+        render_nodes(&self.nodes, &mut vec![Scope::Run(reports)], &mut output)?;
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::{SeedReport, TestRecord, Verdict};
+
+    fn example_report() -> SuiteReport {
+        SuiteReport {
+            rng_name: "WyRand".to_string(),
+            sample_size: 512,
+            scoring: "pls",
+            bytes_per_second: 1e9,
+            seeds: vec![SeedReport {
+                seed: 2,
+                results: vec![
+                    TestRecord {
+                        name: "Runs",
+                        p: Some(0.5),
+                        score: Some(0.4),
+                        verdict: Verdict::Pass,
+                    },
+                    TestRecord {
+                        name: "Perm",
+                        p: None,
+                        score: None,
+                        verdict: Verdict::NotApplicable,
+                    },
+                ],
+            }],
+            generator_results: vec![TestRecord {
+                name: "SeedAval",
+                p: Some(0.0),
+                score: Some(9.9999),
+                verdict: Verdict::Fail,
+            }],
+            second_level_results: vec![TestRecord {
+                name: "Runs",
+                p: Some(0.25),
+                score: Some(0.6),
+                verdict: Verdict::Pass,
+            }],
+            period: None,
+            metadata: None,
+            seek_consistent: None,
+            state_restorable: None,
+            weak_seeds: None,
+            passed: false,
+        }
+    }
+
+    #[test]
+    fn render_csv() {
+        let template = Template::parse(
+            "{{#generators}}{{#results}}{{ rng_name }},{{seed}},{{test}},{{p}},{{verdict}}\n{{/results}}{{/generators}}",
+        )
+        .unwrap();
+        assert_eq!(
+            template.render(&[example_report()]).unwrap(),
+            "WyRand,0x0000000000000002,Runs,0.500000,pass\n\
+             WyRand,0x0000000000000002,Perm,-,not_applicable\n\
+             WyRand,generator,SeedAval,0.000000,fail\n"
+        );
+        let summary = Template::parse(
+            "{{generator_count}}: {{#generators}}{{rng_name}} {{result}} {{failed_count}}/{{result_count}}{{#failed}} {{test}}{{/failed}}{{/generators}}",
+        )
+        .unwrap();
+        assert_eq!(
+            summary.render(&[example_report()]).unwrap(),
+            "1: WyRand failed 1/3 SeedAval"
+        );
+        let second_level = Template::parse(
+            "{{#generators}}{{#second_level}}{{test}} {{p}}{{/second_level}}{{/generators}}",
+        )
+        .unwrap();
+        assert_eq!(
+            second_level.render(&[example_report()]).unwrap(),
+            "Runs 0.250000"
+        );
+    }
+
+    #[test]
+    fn template_errors() {
+        assert!(Template::parse("{{#generators}}").is_err());
+        assert!(Template::parse("{{/generators}}").is_err());
+        assert!(Template::parse("{{#generators}}{{/results}}").is_err());
+        assert!(Template::parse("{{rng_name").is_err());
+        assert!(Template::parse("{{}}").is_err());
+        let reports = [example_report()];
+        assert!(Template::parse("{{rng_name}}")
+            .unwrap()
+            .render(&reports)
+            .is_err());
+        assert!(Template::parse("{{#results}}{{/results}}")
+            .unwrap()
+            .render(&reports)
+            .is_err());
+        assert!(Template::parse("{{#generators}}{{colour}}{{/generators}}")
+            .unwrap()
+            .render(&reports)
+            .is_err());
+        assert_eq!(
+            Template::parse("").unwrap().render(&reports),
+            Ok(String::new())
+        );
+    }
+}
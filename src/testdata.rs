@@ -5,7 +5,7 @@
 //! Data used for unit tests or integration tests.
 
 pub mod rng_test {
-    pub const WEAK_SEEDS: [u64; 3] = [u64::MIN, u64::MIN + 1, u64::MAX];
+    pub const WEAK_SEEDS: [u64; 3] = [u64::MIN, 1, u64::MAX];
 
     pub const STATIC_TEST_SEEDS: [u64; 128] = [
         0xa3ba1df0188d232b,
@@ -139,12070 +139,136 @@ pub mod rng_test {
     ];
 }
 
-#[cfg(test)]
-pub mod matrix_test {
-    #[derive(Debug, Copy, Clone)]
-    pub struct TestMatrix {
-        pub matrix: [u32; 32],
-        pub rank: usize,
+/// Reproducible structured fixtures generated from a seed.
+/// Uses WyRand, so the fixtures do not change with the version of the rand crate.
+pub mod fixtures {
+    use crate::conditioning::{rs_random_int, WeightedIndex};
+    use crate::rngs::{xorshift::WyRand, RNG};
+
+    /// Size of the square binary matrices.
+    pub const MATRIX_SIZE: usize = 32;
+
+    /// Random permutation of 0..n, Fisher–Yates shuffle.
+    pub fn random_permutation(seed: u64, n: usize) -> Vec<usize> {
+        let mut rng = WyRand::new(seed);
+        let mut permutation: Vec<usize> = (0..n).collect();
+        for i in (1..n).rev() {
+            let j = rs_random_int(&mut rng, 0, i as i64 + 1) as usize;
+            permutation.swap(i, j);
+        }
+        permutation
+    }
+
+    /// Random 32x32 binary matrix over GF(2) with exactly 'rank' linearly independent rows.
+    /// Rows are stored as u32, MSB first. 'rank' is clamped to 32.
+    /// Independent rows are drawn at random, the remaining rows are XORs
+    /// of random subsets of them (possibly zero), then all rows are shuffled.
+    pub fn random_matrix_with_rank(seed: u64, rank: usize) -> [u32; MATRIX_SIZE] {
+        let rank = rank.min(MATRIX_SIZE);
+        let mut rng = WyRand::new(seed);
+        // Rows reduced by their highest set bit, used to check independence.
+        let mut reduced_basis: [u32; 32] = [0; 32];
+        let mut independent: Vec<u32> = Vec::with_capacity(rank);
+        while independent.len() < rank {
+            let row = rng.next_u32();
+            let mut reduced = row;
+            while reduced != 0 {
+                let top = 31 - reduced.leading_zeros() as usize;
+                if reduced_basis[top] == 0 {
+                    reduced_basis[top] = reduced;
+                    independent.push(row);
+                    break;
+                }
+                reduced ^= reduced_basis[top];
+            }
+        }
+        let mut rows: Vec<u32> = independent.clone();
+        while rows.len() < MATRIX_SIZE {
+            let subset = rng.next_u32();
+            rows.push(
+                independent
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| (subset >> i) & 1 == 1)
+                    .fold(0, |combination, (_, &row)| combination ^ row),
+            );
+        }
+        let mut matrix: [u32; MATRIX_SIZE] = [0; MATRIX_SIZE];
+        for (target, source) in random_permutation(seed ^ 0x5bd1e995, MATRIX_SIZE)
+            .into_iter()
+            .enumerate()
+        {
+            matrix[target] = rows[source];
+        }
+        matrix
+    }
+
+    /// Bitstream of 32x32 matrices with the given ranks, in the layout read by `stats::matrix_ranks`:
+    /// each u64 holds two rows, the upper half first.
+    pub fn matrix_stream_with_ranks(seed: u64, ranks: &[usize]) -> Vec<u64> {
+        ranks
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &rank)| {
+                let matrix = random_matrix_with_rank(
+                    seed.wrapping_add((i as u64).wrapping_mul(0x9e3779b97f4a7c15)),
+                    rank,
+                );
+                (0..MATRIX_SIZE / 2)
+                    .map(move |row| (matrix[2 * row] as u64) << 32 | matrix[2 * row + 1] as u64)
+            })
+            .collect()
+    }
+
+    /// Bitstream of 'matrix_count' 32x32 matrices whose ranks are drawn independently
+    /// with probability proportional to 'rank_weights' (index = rank).
+    /// See `matrix_stream_with_ranks`.
+    pub fn matrix_stream_with_rank_distribution(
+        seed: u64,
+        rank_weights: &[f64],
+        matrix_count: usize,
+    ) -> Vec<u64> {
+        let mut rng = WyRand::new(seed);
+        let sampler = WeightedIndex::new(rank_weights).expect("invalid rank weights");
+        let ranks: Vec<usize> = (0..matrix_count)
+            .map(|_| sampler.sample(&mut rng))
+            .collect();
+        matrix_stream_with_ranks(rng.next(), &ranks)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn permutation_fixture() {
+            for n in [0, 1, 2, 10, 1000] {
+                let mut permutation = random_permutation(n as u64, n);
+                assert_eq!(permutation, random_permutation(n as u64, n));
+                permutation.sort();
+                assert_eq!(permutation, (0..n).collect::<Vec<usize>>());
+            }
+            assert_ne!(random_permutation(1, 100), random_permutation(2, 100));
+        }
+
+        #[test]
+        fn matrix_stream_fixture() {
+            let test_data = matrix_stream_with_ranks(1, &[32, 0, 17]);
+            assert_eq!(test_data.len(), 48);
+            assert!(test_data[16..32].iter().all(|&block| block == 0));
+        }
+
+        #[test]
+        fn matrix_fixture() {
+            assert_eq!(random_matrix_with_rank(3, 0), [0; MATRIX_SIZE]);
+            assert_eq!(
+                random_matrix_with_rank(3, 20),
+                random_matrix_with_rank(3, 20)
+            );
+            assert_ne!(
+                random_matrix_with_rank(3, 20),
+                random_matrix_with_rank(4, 20)
+            );
+        }
     }
-    pub const TEST_MATRICES: [TestMatrix; 1283] = [
-        TestMatrix {
-            matrix: [
-                2147483648, 1073741824, 536870912, 268435456, 134217728, 67108864, 33554432,
-                16777216, 8388608, 4194304, 2097152, 1048576, 524288, 262144, 131072, 65536, 32768,
-                16384, 8192, 4096, 2048, 1024, 512, 256, 128, 64, 32, 16, 8, 4, 2, 1,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                3524196331, 2134677577, 2920873776, 4294448504, 1913453270, 4227705642, 4005273793,
-                3003859243, 3814947212, 3096313889, 3211151203, 1147516189, 2068186255, 2598898618,
-                2442727988, 2138983826, 2699428832, 840474599, 1957262310, 542329945, 2579866507,
-                3797485625, 732676290, 2752242803, 3865089914, 2953655950, 3657897028, 1859213608,
-                3657566578, 3431023398, 417042886, 3778020584,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                3357392942, 699433470, 705298539, 3608110539, 1587145764, 3549627086, 847690929,
-                3460681946, 2148746196, 2880236878, 2966006529, 3751511252, 3766088079, 1252213981,
-                1295614717, 2258356259, 1990647451, 4027743221, 1562394537, 3532273028, 3540195795,
-                482951061, 3922469860, 857537834, 2581777136, 2845736753, 2321305805, 3933519546,
-                243887406, 2034358199, 2680016852, 3031689614,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2867926989, 2606226624, 781455023, 442910939, 3582027990, 2807807897, 250504698,
-                1615308224, 1600707913, 1231691990, 3475600911, 2776035617, 861132429, 1449211203,
-                1478810410, 296892432, 3013843216, 3914489014, 1024173426, 1178771549, 3328125476,
-                648669468, 3671660780, 1773210149, 2976731220, 576347126, 3113147915, 2922536794,
-                2626250855, 714190714, 2885665600, 2216034649,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3270080302, 3168272043, 1031283522, 1255067932, 937590776, 901948566, 2948795200,
-                2007972925, 2921202049, 3525288179, 4196466641, 3988256075, 3526527769, 1739915259,
-                2263567026, 3849082162, 2556148665, 1317774690, 1665199993, 1223690144, 2741175537,
-                848729122, 1658097562, 554992381, 961373475, 1327207087, 4216790802, 654582325,
-                999447945, 3442589299, 993093085, 447054305,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                2435558378, 2246224720, 855336178, 4269180831, 3388529002, 589437311, 2215680487,
-                2081444418, 2597324844, 2259061075, 1118063161, 1413909929, 438663329, 1077543477,
-                3053632230, 4145079115, 1560289314, 3437900756, 3235592920, 3932112666, 2420826957,
-                1350314403, 1842091894, 129103306, 2190390733, 1041718473, 3832398478, 4250153479,
-                146082948, 2324605334, 3309233065, 1815451969,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                2649285574, 845691559, 10713171, 3014439454, 3300537135, 4240424988, 1960007528,
-                2858472359, 2107183078, 3415637320, 3877610049, 2672827771, 4120465039, 4223338168,
-                1376878134, 3544002270, 3197981615, 4150750038, 4129544866, 3337903335, 3705929226,
-                3121227342, 3088455910, 1687955686, 694214686, 272924989, 1564155476, 718273038,
-                1814471453, 3072565537, 1577680818, 2585717704,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1541116290, 3516956677, 553856652, 681923017, 2353690374, 3683397235, 3348202700,
-                1107372387, 4124744962, 932135357, 222593695, 2507064104, 2521516630, 2986608911,
-                2808889229, 2215695383, 182988441, 2362032440, 1546138679, 2733471619, 2525961633,
-                1171073222, 276424411, 2149799729, 1549400192, 3662200947, 2822178604, 2166439307,
-                527992711, 701756547, 3706520957, 3220817598,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2895322406, 2368579755, 708121178, 3294051458, 2698289830, 3336987089, 3196787166,
-                3828154359, 3932278874, 3377689976, 2254010517, 1905767663, 3845665359, 2349824834,
-                880500418, 2623691795, 2025050370, 1825412189, 1810468603, 3820003847, 352535581,
-                3217878931, 2153671798, 4204770488, 1033826914, 3186109082, 2327164157, 991934418,
-                706706560, 2805477650, 4175888842, 1862692324,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3122144991, 2508511459, 3614054806, 116614671, 3812105362, 1055846913, 1721996428,
-                1413590959, 1793970376, 147712857, 3309639287, 2039737372, 703077159, 2965107642,
-                3748798717, 1664191425, 226122611, 34960678, 3311926210, 2167159969, 102720176,
-                3654695993, 3293664001, 2485201619, 2969474032, 3464140399, 1934095588, 3329951069,
-                1779514945, 449864012, 655055669, 3431075162,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3548747378, 383468440, 28398667, 2339888559, 154204476, 1719705340, 2834352762,
-                490107203, 1177429495, 3789728440, 1969389842, 3913668857, 3792205636, 3782521007,
-                743474539, 1898623984, 4003198118, 590763364, 2171443229, 1503255028, 3901981195,
-                964855940, 1958974944, 2370416996, 922872271, 1936359252, 615110374, 1083088327,
-                1577186200, 1553762886, 2403391833, 84053112,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                1081556890, 3186351368, 3030032115, 128090746, 2416997000, 2899816088, 3925647955,
-                4149771250, 2258852156, 4014093784, 3022583034, 2324646587, 2302137417, 2354643797,
-                3046061611, 2961647835, 2998716321, 1044469456, 3818666832, 2531649866, 2330278360,
-                1405824532, 1495764614, 769989859, 3203905461, 3622183205, 786950211, 101395715,
-                3399531672, 3036738985, 3278993578, 671088467,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                926525453, 1092403126, 3747328879, 681953279, 551627233, 4045036235, 1410410650,
-                351836736, 1953562030, 218686465, 2989341587, 3650055503, 1274545210, 4229035368,
-                3319445057, 4199885411, 3216701138, 509747998, 1103692927, 2170982107, 2716135348,
-                3279244827, 4102036429, 2877637251, 2728387003, 1786325186, 1865850893, 2718144542,
-                988885210, 3951401202, 1800733140, 788822704,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2884506151, 2996961463, 4284718113, 1871851062, 4072799141, 2790706270, 1138671883,
-                2717651975, 1663786560, 1409947843, 3075236772, 2506302359, 623197496, 2070778959,
-                3738452442, 100812435, 2108839301, 422842736, 1562436575, 3438813127, 4165012239,
-                1892675149, 3235872600, 2525950426, 1353097665, 1018786541, 102557198, 1817002858,
-                443920374, 3483733079, 2869176766, 2131711202,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3191978817, 2360816606, 3907979142, 1569972968, 2045097334, 3650856601, 3891252869,
-                2566708358, 3798591778, 2564072749, 2948175000, 895827065, 426903712, 1592277324,
-                1742897237, 3980010006, 3294576146, 2159728974, 3722259477, 3262155214, 2380083547,
-                242795066, 282078593, 1246536243, 834203902, 1623495708, 162459365, 3274823819,
-                2117805571, 1294989288, 3938270103, 679768511,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                165418652, 973953100, 2232557247, 4287034994, 1268679863, 122043337, 3264764606,
-                1448119166, 3953594516, 3157959719, 3261488887, 1760067191, 2665786240, 1051356892,
-                2733410795, 97431997, 566025552, 1045100172, 4150537516, 3192757152, 1216816766,
-                1448131637, 285673035, 454159299, 1669231698, 1778776748, 1407211321, 1523697952,
-                1899483263, 2272577022, 3567080571, 2602492171,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2626268584, 2476453984, 1568713856, 2373322191, 3328918692, 1084422435, 901557600,
-                2398872055, 2423555056, 2629230364, 2860825899, 1073438617, 3975970954, 1514893279,
-                2644577314, 4084717115, 2988028844, 4189994303, 2221439786, 2850352478, 2855848514,
-                343323789, 3593380274, 2922459476, 3786696854, 2574269140, 1998572047, 1837018108,
-                2946119493, 677643630, 1490801687, 3653915801,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2588124615, 3867127781, 2875326430, 3585986733, 317674539, 1154131060, 604008650,
-                3348754717, 153160563, 2535267494, 482105093, 3367887271, 1104393248, 4225420390,
-                2702991948, 936440840, 2362709910, 2748479372, 2318362794, 1261066438, 2512171952,
-                3695224805, 3854614959, 1075453651, 1995746247, 2591720517, 2692569293, 3343679626,
-                1356680452, 4025694762, 3843624956, 2005106516,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                3821628146, 1664424030, 2274784413, 3131691738, 81520326, 218427740, 4079961268,
-                511423319, 2856453060, 1085990455, 355697511, 2147703221, 1107380470, 867221532,
-                317756229, 2610219974, 257855387, 1434365091, 1084658211, 1271054657, 4180889422,
-                377976871, 315034859, 3602199967, 3790321163, 2432911411, 2494808939, 253574519,
-                204410067, 2753175118, 1302044768, 2846335737,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                647758633, 2211388430, 3259133295, 1097119926, 4111339367, 786830697, 3926259316,
-                1285287528, 1204345841, 1622965792, 1540941427, 1009334001, 4213416813, 2062076636,
-                2008879858, 3711850026, 3137388285, 1517857711, 4147846339, 3434958470, 120411892,
-                377270788, 673034344, 1813358976, 3928650705, 2952903750, 280483997, 2139364151,
-                248349860, 1745021858, 2606027219, 254016599,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                1598825657, 2318704785, 930854155, 2105508183, 429789919, 3775336620, 1522685353,
-                3186595568, 2503937508, 1244616179, 2612006986, 2888364074, 1850321280, 249125373,
-                399019369, 3335982414, 704698190, 1528927528, 1887713499, 4275161367, 4231145853,
-                1273830836, 70131952, 328916038, 2446393741, 2681671118, 2523373516, 4127054372,
-                1345634684, 4232240866, 762781002, 3745650490,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1246788335, 1957583051, 586768179, 909977895, 3094681890, 723696922, 3158612734,
-                1411268677, 1928856257, 1594893675, 1077816833, 3390850730, 3203526552, 1155094545,
-                2420904429, 3215108118, 621866949, 2640322792, 2402481969, 3094153978, 3044322883,
-                28398379, 1452924555, 2988448559, 4169207451, 171108498, 3244709629, 2598694030,
-                1928809357, 2672669350, 136012048, 816610611,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3276267496, 216554355, 500550069, 1078971784, 2302159421, 2088989511, 3419951265,
-                2306742926, 1457448623, 3408373655, 881118699, 3161776192, 1560994796, 1799767847,
-                221682124, 3486857380, 1980823280, 2514653025, 834845085, 3586038722, 767471902,
-                3826063573, 4255666978, 507227102, 2558857427, 3619871950, 2461020224, 1769124305,
-                3092001116, 30636554, 73405230, 4003298212,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1047568930, 2118329876, 86655933, 1753396654, 2566212510, 225331180, 2213637448,
-                3905590601, 4252704281, 1353204424, 3205985707, 2351459223, 1522409665, 1148520681,
-                4151037479, 2531839634, 867531261, 3887683273, 3546202463, 1960888646, 3534367306,
-                146552015, 2068788572, 2134337766, 919111447, 2162094090, 801283675, 3692696572,
-                1433301937, 3746200125, 1980237162, 3627801508,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                1057577420, 3600188960, 3447662466, 1048823710, 3160052637, 4268419277, 2260011817,
-                1420476825, 3997489702, 1384337739, 3092626416, 1951169955, 525242425, 3994025109,
-                201849673, 4069379779, 1254591152, 1382267028, 2531480198, 233531692, 3900693964,
-                1394800094, 2501029816, 702696572, 2745823796, 1472883655, 1194921040, 2104555954,
-                3115515901, 1953071336, 575650840, 775903938,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                994973603, 1303788582, 441401818, 1922352293, 1397407470, 597329285, 2531354218,
-                2922966763, 1617919922, 1562126369, 2149264988, 509362756, 434355223, 1769273036,
-                1716793318, 562099293, 242104358, 2325923192, 246409253, 2968222585, 3123082231,
-                3875584072, 226503618, 2785857113, 3668744483, 2423152508, 1535177831, 3859058588,
-                3995984160, 2349066105, 3583644028, 3351973598,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                3854297393, 2151666552, 3815118465, 2112836484, 1930850082, 1423698431, 1628598009,
-                1999962568, 699859657, 231217147, 3782982183, 1429449819, 2118176014, 293818200,
-                1630904909, 3387812936, 1535549203, 3280030066, 4288596880, 2567787596, 3841481367,
-                2703415830, 1632544829, 980073773, 724306288, 359772532, 4004576368, 913466846,
-                2609925039, 2583122360, 2359761941, 1026935461,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                3626965544, 2519910827, 806195007, 3776994385, 1510737210, 1549395227, 3797479615,
-                1952059635, 1187995982, 3207368796, 3724968786, 3949823800, 3108338371, 2451417839,
-                1514128453, 1561872534, 1440689252, 2593084371, 963699531, 1916544739, 3985538305,
-                627459713, 1876455407, 2836681354, 2407472129, 965008789, 3830305118, 2731978951,
-                4177693851, 3368322468, 4184265383, 2486106805,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                564662325, 3068310025, 1504289831, 3941114332, 1937084894, 324696072, 2755577858,
-                2672925956, 2831524727, 2443638848, 115085367, 3542144425, 3905534961, 296411403,
-                2818842013, 1039625606, 1367226045, 3971444961, 129891488, 3069790993, 3151311254,
-                598747537, 3228088272, 930326529, 3692576400, 995399137, 4231143524, 3037301942,
-                3166030299, 1759202199, 997810721, 719665807,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3835836367, 4046461781, 934013709, 3685186707, 567037252, 3744147765, 1355286029,
-                1713804198, 1961070306, 3179854427, 2944763497, 2124914556, 2372204108, 2869304341,
-                1353400202, 2488450190, 3698815135, 1705011599, 2423926769, 3845197655, 2688890634,
-                1984937130, 2217285652, 817652193, 782109527, 1751896333, 648589309, 2076290254,
-                2587928107, 781998989, 2044294481, 333166288,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3673825970, 1252952231, 3209678726, 4209704964, 3294441520, 981527866, 3178701367,
-                2245197878, 31403617, 2045396514, 4204219167, 2358215625, 3794556975, 606478825,
-                3084073704, 1950933337, 799365085, 1708171421, 3452006718, 4115950560, 2118795064,
-                1519058403, 4102044128, 3483146124, 744947502, 562199883, 1099641192, 1455046707,
-                631043175, 4115246676, 4274947525, 3040917665,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2538919585, 2327103995, 1211792207, 1727289812, 2140406173, 2519820320, 3287952034,
-                1702953436, 3171511220, 3618706717, 774849495, 3291688201, 2446228922, 1989560056,
-                2254269906, 1037278514, 739338864, 3880510353, 1440080454, 2574193532, 4117752208,
-                1291650162, 538706959, 973513370, 3845638548, 74499657, 1780323936, 971007906,
-                1226817312, 454450426, 3515826602, 2934308467,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1371699909, 591815878, 3993667904, 1018776980, 4049877568, 2312263350, 4040884569,
-                367996798, 1910553525, 2078920935, 441601741, 2396135936, 150899378, 1122237092,
-                1084029056, 1507786532, 2592632290, 1488803565, 2718966197, 2731289533, 1266807842,
-                751238525, 2636146508, 2018966134, 1732182708, 3372496729, 3089965725, 1105490922,
-                3490860567, 3027646903, 1210607730, 3613130401,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3205947637, 1660576227, 1777859695, 4083758587, 2480817697, 2836735511, 2226456934,
-                3179026120, 293679349, 2533659490, 3655988276, 914859737, 222111432, 1649699676,
-                3444511725, 2542204007, 3157681917, 3506762142, 1711830469, 1619947053, 2615935357,
-                2266666933, 2274493121, 1183356961, 2080616881, 728928710, 1906867247, 2969390755,
-                2835323898, 34139123, 1476891194, 1309768558,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                3823668561, 1490423065, 4231853208, 1878573008, 2108317076, 2225096919, 2552125197,
-                2536710028, 3049660059, 575793376, 108809764, 583280687, 596598709, 2449257938,
-                2649271331, 733571403, 3426890504, 2642026523, 3885298491, 3593225402, 899794157,
-                426259851, 636769832, 3218832387, 3891148715, 1797192275, 1846143896, 208689706,
-                870927799, 551091624, 1161777380, 11834394,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                94330170, 2669709533, 1545506196, 478595634, 2806350803, 250072758, 1521268214,
-                3364376376, 1276780760, 3815890179, 1972561124, 1528009627, 317703621, 3959342657,
-                2759844685, 1029041962, 4233113811, 2892280241, 1929295369, 2805917546, 3632876604,
-                2002796139, 2867169313, 2354769087, 50101025, 631016216, 3131503119, 1419832490,
-                4097543608, 3012486824, 3026046792, 2163345000,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1482493778, 2613246674, 686256520, 892721067, 256403314, 1941505871, 2399849974,
-                524458757, 860980428, 3668154203, 3583715198, 3743459714, 1865083893, 4178239338,
-                4200146302, 4256680263, 1152744619, 664304397, 1163742482, 559033078, 2841898134,
-                1304146253, 689645217, 907544502, 3888042574, 1926859931, 652966031, 101365466,
-                1799470756, 891923, 448635148, 2803440715,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3954281638, 245644536, 2941388348, 1662531586, 2858154490, 1125395871, 191476380,
-                3300209666, 1866100747, 786629764, 643061145, 3068275296, 2953543014, 852292498,
-                3000828565, 1998974141, 1604689277, 4153218132, 1174966490, 3646112364, 859711825,
-                2544966393, 1761328115, 682226257, 1252432642, 400010798, 688287571, 231489577,
-                3356750900, 3428866607, 1853811496, 1752970410,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                1002756800, 2762104792, 2989122850, 2661808805, 602362329, 524920999, 3696100937,
-                189274258, 2033527398, 3326522864, 1729201238, 1213427724, 18765997, 2584380190,
-                4277550101, 384100443, 2981757632, 992096297, 1761353361, 730732699, 2230815829,
-                1746424949, 224760624, 1228627820, 927815910, 166321933, 417216437, 2549946510,
-                3780837774, 4181742407, 2092357048, 200594556,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3682703215, 2620025578, 1637031669, 3526537400, 589462613, 1565297881, 1857616291,
-                1229532952, 2897174019, 2432924865, 955324377, 377506555, 3230685543, 3616032168,
-                483457654, 90408513, 3389417868, 372083733, 503420861, 2619257965, 2036386941,
-                355468944, 2460200783, 618514805, 3344555135, 1548668474, 2252823285, 3222500623,
-                1896977339, 537253006, 2493625025, 4082501062,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3048806262, 1998834403, 274923799, 891708596, 2034340596, 295442183, 1947449468,
-                3015367232, 367247652, 2031378816, 952103577, 2677880889, 2066981540, 1451065356,
-                1183776027, 1056834095, 56147087, 793898235, 4105595450, 814411578, 32795058,
-                1071668664, 851130264, 777369976, 418888750, 3429412033, 1010725474, 4112267616,
-                1742158195, 3865228426, 3946277630, 1882793627,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2996942173, 336490299, 614492750, 3751878803, 1407282536, 1229566737, 66898758,
-                1926041242, 1633161317, 3155000520, 2748948378, 792868520, 929461964, 99849464,
-                1103717361, 1841002737, 625594466, 2552751049, 4066655812, 81820141, 616693554,
-                1149424206, 1593542870, 1499566862, 212117813, 1274972616, 3478050421, 2220814355,
-                221737424, 2395406271, 2180807010, 3279044377,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2362812164, 3241319304, 1476182487, 1262421330, 506588340, 2063231278, 3399366057,
-                2820645508, 3952544919, 1343650480, 1473955281, 3041562183, 2360777, 1760036031,
-                1341494139, 2731373159, 1551849825, 914830139, 2864619247, 2731203412, 3843222313,
-                95507727, 1012731147, 1493184320, 41923355, 1704606812, 3917262343, 2612080199,
-                1004279637, 1676898637, 2016016644, 3318872176,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                913709013, 3511502584, 3820463928, 309846773, 719348007, 1128801751, 1013931256,
-                1330483653, 1556027493, 3644452258, 2240882439, 4252862003, 1053926358, 4173445753,
-                3218328441, 4135406068, 1015529155, 2385191222, 1646334232, 3782656354, 2166257053,
-                729889048, 2369625632, 2713204155, 4000736956, 1790474479, 3144650291, 3186590265,
-                2514821807, 2381058782, 4039088017, 1346962946,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                832197434, 4012662717, 3629568837, 1816525525, 1761409593, 622976130, 1875245040,
-                2925801118, 1383184538, 2734142670, 1872623770, 4167355987, 888831924, 1408514649,
-                2120725886, 3215275727, 2542137315, 3032942489, 4008375941, 3787860733, 4062939847,
-                2132739222, 3516588940, 3710360441, 2427726932, 1505287130, 1567850858, 1349169923,
-                3105917018, 453126585, 808146157, 2281736436,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3737190110, 1315125368, 2283385049, 1065058435, 3059823855, 3404243280, 1452689736,
-                3257441030, 2620134572, 1162918489, 1614798478, 804879255, 291470369, 929617800,
-                2700863912, 1787776065, 128869837, 3309510968, 691945862, 3575540478, 163864012,
-                209725594, 1108471478, 990946179, 2202234145, 1795144994, 1562714390, 2528011093,
-                3868022012, 3568523928, 1223818156, 1365669910,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3886550416, 1264016168, 1554916057, 820291322, 2294115130, 4149576888, 3587219102,
-                3287968249, 2100366096, 185109536, 496460341, 1328128924, 1757869021, 4156607667,
-                2525295143, 1371262111, 2263259883, 241206797, 1412134655, 2360756935, 2900276455,
-                1664729711, 1851136184, 490049224, 759013270, 1405411581, 2823282488, 2745640422,
-                2433433079, 3963290733, 3159689226, 3906536265,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2121564836, 3304231330, 875989744, 3957253776, 1253441187, 1129924469, 435137514,
-                2861343496, 746894906, 3296135986, 1268796802, 3371862212, 2501208806, 1715418582,
-                592961584, 3443044388, 823577754, 2734686776, 1125127125, 3227102606, 2951168866,
-                3580654772, 2335462870, 1659081239, 1781333432, 2429447807, 2499152262, 3354100304,
-                1075893306, 491755635, 2829896295, 2865352051,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                2655429329, 804795300, 1555586583, 1017913402, 2911384682, 3675373688, 1925960960,
-                2460258666, 2412420614, 2200421924, 2491255143, 3093272929, 1982794770, 2868163447,
-                3316225887, 788209523, 4004715187, 1593552348, 335986069, 2560172489, 4036432861,
-                3353340449, 874430974, 3496629921, 2995883258, 292907036, 210525140, 1964384262,
-                2652067262, 2126715734, 535789992, 4133173728,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3457692022, 782329907, 1104600322, 1535247589, 1132623229, 103702008, 639154324,
-                1051078567, 4278409837, 432936169, 1886904617, 3985926072, 321690764, 2618934532,
-                3170726968, 2148985816, 144966188, 2152354632, 3570293215, 1042217494, 1455611404,
-                1330567434, 3819795679, 4239345454, 693011307, 983151616, 912443669, 4121256933,
-                797981464, 2063744801, 356102351, 3982269844,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3852717735, 879146577, 2973344944, 1427740375, 575214899, 1529668508, 462601634,
-                2539884051, 919850398, 192538839, 3383428330, 94798687, 1458118252, 3339005079,
-                2287547648, 2420705149, 1656319372, 4010476832, 1009729533, 3775033106, 600655435,
-                3096595687, 2266821751, 2989770368, 397316976, 564693077, 1246713468, 3004062279,
-                3185385085, 3849430734, 2712959589, 3676712432,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3890242512, 432608863, 2210619577, 2859194182, 725220222, 3307347404, 1510078573,
-                4012622150, 2721342151, 2279595141, 3922778650, 295319505, 809477806, 881575004,
-                944091119, 1339189262, 604500909, 2737596470, 3179574068, 9656381, 2804774554,
-                2670551049, 192687877, 2537840562, 3078520326, 323938975, 2401291183, 2735597698,
-                3382088461, 582371060, 857226926, 601770460,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3945044774, 3458587709, 2059695082, 1779017377, 3127255233, 2505301421, 1137946156,
-                3287246940, 1728861228, 1488158539, 3355177718, 2178711196, 3361922067, 742208984,
-                4115498236, 3030678482, 3061001809, 2987525426, 4121852374, 1801124425, 2956865096,
-                3982344722, 1456878809, 3348777137, 200222779, 2656021936, 2933631079, 2829862969,
-                433490491, 1538579428, 1264414862, 688767759,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                4076057690, 1324133795, 1787309087, 2041466818, 3664656650, 3351780730, 407585126,
-                740374291, 3445734319, 927472027, 189029002, 2147823403, 842983415, 642598167,
-                3559987576, 4162472522, 2563189070, 3503755973, 1080781161, 1908677560, 1910438078,
-                3975627506, 2463838541, 1428047552, 1607843430, 265044150, 3405690471, 1921371925,
-                677327804, 3163129572, 1894740557, 2466053034,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                16005557, 577516742, 252081925, 4074450846, 801699186, 4156358094, 1574239610,
-                948447416, 3192549867, 4050657632, 1448992733, 4128966781, 3057628608, 4046851190,
-                54824908, 1084967369, 3936627332, 554704033, 3521582513, 2989017852, 2160351074,
-                3485044793, 1422727064, 1926873027, 739423909, 1241405397, 2202758129, 3772454159,
-                2621386089, 32142464, 1104052229, 160896083,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                2262417229, 1390665315, 2649754527, 2816500193, 2483671295, 2288468809, 2594645201,
-                1624715183, 3470204935, 1238209338, 1290907500, 3999121493, 3163244179, 3966996983,
-                3662290699, 3742182663, 3802509258, 1052313937, 1939292718, 2022600507, 1789242407,
-                3244241368, 2478054285, 3453999336, 1714321583, 210476068, 2443819657, 63209032,
-                2902203152, 1819394263, 2877907970, 3638486924,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                2492020652, 3887247947, 1860051540, 1996000668, 248620945, 3758347693, 2973570461,
-                1471603873, 380793669, 4101516768, 3067742713, 108900730, 3176826121, 4058928634,
-                1380733464, 533622299, 2462046094, 173500333, 740810584, 2212240278, 2168483009,
-                2210621634, 2274911953, 3790759342, 1971736863, 2752628723, 3842957243, 3876725932,
-                2953047043, 3952744151, 3034533381, 1646810987,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1465261659, 761828449, 728570280, 1572053641, 4262531517, 2877420314, 1777261989,
-                1617600928, 939573804, 3977970134, 557638122, 1559722088, 3885562765, 1375460818,
-                4165964525, 222829729, 3718403419, 2130076242, 1815687635, 1672050382, 3867703309,
-                3040456206, 541758702, 3571678705, 4014501216, 1311304979, 286298031, 2155051469,
-                3523315014, 2087732325, 1630672402, 3599069591,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                1439591810, 3286089276, 492848722, 212658705, 759546943, 397225524, 210234199,
-                2307181086, 4263282240, 604318150, 1042252625, 1958002677, 1402167134, 2387607840,
-                509978254, 1923966861, 3806908139, 1384647507, 309193545, 1147686589, 3138274088,
-                1405513752, 1607248824, 720910183, 2620331879, 2293925391, 2113327625, 2710742758,
-                650794595, 3202967563, 3611744768, 141450663,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2187971350, 4150979150, 2626615746, 331002052, 2780455086, 2739020218, 658915318,
-                627158567, 4165097784, 1190299219, 4215730717, 4015200425, 4008659137, 3998808463,
-                1991038829, 2813673629, 3481881101, 2880379868, 2088468290, 699556512, 3261405868,
-                3260087154, 1873673056, 3503066610, 1927238246, 4205016744, 2922572589, 2802284985,
-                1666307262, 850954436, 2060493106, 2296349145,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                351027871, 408694542, 1767689136, 2870502711, 1109121256, 1647894862, 3860766762,
-                1998356443, 1159819622, 143009412, 3770831734, 1028193136, 277886900, 3692510982,
-                1779697783, 4015708984, 1921690933, 4260439968, 3250753112, 567299829, 374141502,
-                1167196976, 4110210323, 427774740, 3289685821, 3373575209, 2402829075, 2129324767,
-                4294520925, 2625193482, 3544147541, 71172897,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                2583357305, 3868794566, 153678601, 1901534122, 1842606051, 3917615137, 3065473052,
-                2784890042, 4114963239, 3063907866, 3595325704, 3360966369, 2275265306, 2276174737,
-                2299389852, 3650107487, 1327622435, 2073390669, 2644502302, 3403899176, 2640254850,
-                3166788106, 773612329, 2388302740, 3582241592, 2880227493, 1725192193, 4194982611,
-                508736295, 2403937423, 4156286204, 3461653715,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3234711503, 582084201, 1019920372, 976441945, 3622620070, 1642060473, 2732811678,
-                4247139238, 1813792943, 3426903, 1920067170, 3029977285, 2775430227, 1717358056,
-                2845455771, 280936517, 1190102534, 2291588331, 2103379996, 95633614, 921064640,
-                2754519167, 640107106, 91448689, 3633494743, 3162245407, 1413038709, 3091586394,
-                3824226402, 2817412909, 3803447121, 3332622153,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                4271789299, 3149228309, 534150199, 2916684287, 3219035974, 4146944746, 181026407,
-                1850171287, 2950965009, 2881358465, 1583073933, 2743351142, 3664878431, 1912909488,
-                2314289399, 3507299857, 2752077393, 2610120980, 2847757243, 338893237, 2477331386,
-                2816013444, 3207555664, 1613634647, 3639955976, 1981296298, 3484036225, 1223543782,
-                752700336, 2088520791, 2116857795, 3359964576,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                871674815, 1971056357, 1498994464, 1850489875, 2504533646, 686195358, 3703342015,
-                2324179104, 2015376949, 3132876630, 1973720661, 1413180472, 1072529725, 3620664620,
-                871737949, 904199504, 609023417, 4206498668, 1290063636, 2725795095, 3605947973,
-                3630665673, 3962763074, 4222007924, 602822351, 529214709, 917232167, 254561978,
-                1942763740, 126794426, 2044595286, 255177156,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3971252276, 1921776940, 3224685143, 1654995060, 1507484784, 1657303283, 462246845,
-                383196045, 733966108, 2075224949, 1295516053, 82862003, 4030894819, 2587323181,
-                3852402794, 3797145627, 1027699054, 2149067412, 2558314305, 4233141683, 2415982344,
-                2796782769, 5344613, 2833888555, 2723847981, 1082778111, 4042945089, 3825828912,
-                2811892538, 3206944177, 3056581674, 905039498,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3435357872, 421242254, 960174224, 4283767272, 718316334, 2611710964, 1024700730,
-                995008330, 2956584064, 1391398905, 853370719, 1118661192, 1493232947, 1009390510,
-                3950033911, 2356459309, 2632702841, 217886754, 1304752608, 2970207164, 4072395536,
-                4139723403, 3411920466, 2951464154, 1746713164, 448869797, 3334520905, 1187176798,
-                2736816924, 1722625839, 2634450018, 1335982135,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                1686638137, 4031354530, 3806951516, 1748930, 3417430634, 4172047962, 2811885332,
-                2662561386, 554275789, 3427418042, 2100389662, 1184682041, 4044523663, 2287365684,
-                1944703925, 2124660697, 320886541, 183255274, 2445894486, 1495386214, 3464582375,
-                3945097183, 3450131682, 1877769797, 3961564366, 762632217, 1291558040, 3774624592,
-                3032478873, 2349577329, 4162806109, 1324255647,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                113707414, 1664099256, 3275529302, 2314149454, 4146743182, 2678492935, 2898843490,
-                2448802494, 329382782, 1354543876, 3186386545, 786045051, 1434389571, 1627410686,
-                3389675147, 3656786264, 885488096, 950722187, 2937215457, 1863911118, 3114789918,
-                2838781883, 1520131493, 3217851400, 1042902986, 1632306659, 317521025, 2607421753,
-                236583342, 967920678, 2943072193, 3683867814,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1100999784, 3897344282, 224721443, 1097716407, 2856522349, 2936661229, 1197373138,
-                1257301165, 365127911, 2486524010, 4051590100, 3026135808, 640290536, 77169559,
-                3512808333, 3282980608, 3497572731, 3773850678, 1613137173, 2564169829, 433205945,
-                2940331450, 3841502705, 3223764499, 1821459708, 1015030007, 1335888799, 1076407852,
-                2879203190, 2829221394, 985187474, 3626881735,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                210857844, 3528473724, 1022890787, 3429531484, 3325216456, 993699053, 3058225731,
-                3638718397, 1067009695, 2294624304, 1625134808, 2603651551, 1245809910, 423291898,
-                915522294, 3837922198, 3216388077, 1872242543, 3111887004, 3886408468, 4012916513,
-                3453224173, 739603508, 529973003, 3946380371, 624676517, 3991219200, 1502450496,
-                1901573719, 2799821984, 3800160297, 3901753469,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                4108198488, 161514601, 4104013649, 2370310170, 1508975852, 2597810432, 2590821257,
-                3880359984, 2428067470, 2765422477, 2419861360, 93556207, 2602077286, 2527125449,
-                2832930775, 812967775, 37672536, 556182193, 2999009945, 3548136151, 822451025,
-                3071073172, 523975712, 3267221893, 812811271, 3424129501, 57891381, 926461160,
-                4242633803, 3510478451, 3846707141, 3128973653,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                3160529825, 704068900, 1195214954, 3380563269, 3375633445, 680463212, 1069537928,
-                2918059145, 2294072208, 4228493093, 1158530801, 4155035338, 953318382, 1348269265,
-                2057614494, 1525395987, 1240982615, 2990126141, 3565193478, 940550217, 1674067555,
-                2782347886, 122876693, 2787594401, 2494103060, 2437210629, 3996592820, 3322847405,
-                1113620524, 1935272429, 1647703703, 3068657160,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                4200176287, 1201799491, 1151776180, 3714048403, 4049257498, 3019084670, 1612819011,
-                4026766325, 3443197527, 2005943176, 3424289520, 1382187620, 1170050880, 3499204980,
-                1172947361, 2346030111, 2048669971, 1108568594, 1500415164, 2358102311, 3020544338,
-                1051800340, 3384578015, 316034471, 3482131764, 3018303184, 941647960, 263970168,
-                4182456437, 3186278912, 2940406351, 1297007853,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                745353237, 1930101283, 2720516577, 369119184, 113093873, 452424229, 209083565,
-                3028782070, 247849224, 2257107944, 4195220773, 501742379, 3228858426, 2128097600,
-                699043151, 2545534087, 1566684848, 4062257105, 1634537293, 1544888285, 3153833169,
-                2180619967, 2015060201, 1764768402, 3392142350, 3924579995, 1648839492, 1948370739,
-                2850662654, 3863336639, 4220137909, 1489096509,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                4268173706, 1389212180, 820800776, 2921037775, 934198647, 4047866975, 2146172727,
-                3291642073, 1012481038, 3347548473, 47701639, 2074120029, 1661348836, 398337589,
-                2501844187, 2464484233, 3583914941, 1564432714, 1908408865, 2214994366, 129301159,
-                1233514591, 2340031156, 980041007, 2751354665, 3096044035, 575605574, 888771550,
-                1165585613, 1696476609, 3476538936, 1333019280,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1022363312, 1490796372, 2564748197, 2214497852, 4111251922, 729835814, 77384871,
-                826282995, 337779166, 1344915495, 589319961, 1052642533, 3388493308, 1906863034,
-                708329134, 2868706965, 2131401565, 455516160, 1478613642, 2535673513, 1131129242,
-                3441031176, 3214634778, 1833179756, 2571664418, 2970485241, 1023873397, 2257310336,
-                2169574134, 634228078, 3842065460, 1719833370,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                1219212395, 3984797578, 2105189059, 3249957690, 1343206200, 2654284877, 3432622459,
-                29020039, 2658352477, 2097027123, 3505759761, 3733583426, 248051962, 939782741,
-                102888695, 1169010761, 4198320341, 137254031, 1591632775, 3937632850, 3258148395,
-                81508869, 2305163417, 1718840424, 134396121, 1237512517, 1823626109, 989970432,
-                366776650, 1963310627, 2485776750, 2390766487,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1922294316, 2510058335, 3007396397, 1287093713, 2781918797, 1241034530, 2753226679,
-                2004503104, 1632396569, 3157477006, 2638179606, 44505256, 707846368, 3698264025,
-                3618315475, 4097077062, 3446082890, 4061894823, 414040588, 2598783383, 1926511332,
-                526396366, 728097149, 2815064931, 1651577738, 3119345493, 2160778361, 3132457067,
-                3007955643, 4101087464, 2391606565, 4052840613,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                612859743, 93010631, 259030989, 1319633573, 2341581902, 1535810247, 207284505,
-                4085530462, 2592114516, 819897357, 3345773389, 1418924643, 3614829034, 1751569608,
-                990913181, 3630060106, 494114894, 1904499071, 4241507503, 2376663615, 1609243955,
-                757838949, 2739285916, 965679108, 3130688443, 239527444, 2996548259, 3562120759,
-                3332571024, 2375382479, 2742341233, 3262386424,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2650892988, 4082371952, 3816876452, 1225043106, 214616056, 826070161, 3833331058,
-                270155594, 4153806799, 2974195389, 3602433786, 973563796, 687178614, 98654988,
-                2019183682, 1769283850, 4252412854, 4006877765, 3854652294, 993788202, 2776384024,
-                597213922, 2743355133, 3211451221, 3055175639, 1620502895, 1144299275, 392816408,
-                3716918411, 2117551190, 3276464811, 898614983,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                1859916743, 1373775747, 1542435846, 4126759174, 3433544814, 1269469594, 1648506304,
-                2302526194, 3768148837, 1172650931, 759133292, 2034016646, 3173738335, 2122721227,
-                4251194621, 3749307699, 708307989, 3186440415, 1628025170, 758856165, 90278554,
-                3959110518, 615061221, 1744471346, 1091068611, 889385231, 83882080, 4059182739,
-                438596708, 581639718, 796545791, 1632129725,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3129724892, 1156014337, 935501318, 493182268, 1858580970, 2162089568, 372831620,
-                1659666797, 2261782554, 1488028142, 3710333160, 946679976, 351645735, 1886708143,
-                1567658777, 1023385401, 1552826981, 2515976580, 1147234661, 1340590547, 3712240099,
-                945854987, 3223472081, 2338658617, 814209220, 1669657819, 3264842534, 3724892979,
-                1292014084, 2204241953, 2266323493, 1232890856,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1246033708, 3753369733, 2681024229, 1840717949, 885100246, 772039712, 322545773,
-                4027614978, 2390103981, 1385104447, 1149628049, 3782201975, 3197486713, 2288052481,
-                281440821, 1874049599, 665473384, 567415872, 400112835, 1091862212, 512711761,
-                3048523744, 155389257, 3392930765, 3882116510, 2691323002, 940188565, 157484961,
-                2165680136, 1052896488, 966052553, 1831388638,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3485065802, 1700498786, 3772871898, 2257366747, 3907463169, 1572688965, 3520270954,
-                915816328, 2371523471, 1775942121, 3028159290, 3014282868, 2071922553, 73905487,
-                3070318151, 2630288870, 2954151920, 923991294, 3849016341, 602311761, 2124517996,
-                1536085007, 3398848187, 3239815842, 2968813004, 1190956731, 2821782481, 786163945,
-                693322104, 3698869113, 1117121292, 919488269,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2699372787, 1100140822, 1191698918, 148095838, 4150664344, 3039089851, 4153780572,
-                1766865809, 2013439802, 2416770274, 951097688, 723092225, 1243908337, 2211523614,
-                3458393788, 967927578, 16541080, 3911401022, 2134448080, 3318108180, 1711816411,
-                512182401, 3123754232, 2722712630, 1524321041, 4143759429, 764426029, 4211611040,
-                375941899, 780229043, 495063915, 768492139,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                3961072705, 1623421224, 295191258, 4128740924, 2355509498, 1207810868, 325420709,
-                3456874784, 2312481715, 2428379898, 203905045, 2473843306, 2326966870, 1863242924,
-                1225036060, 3423234470, 3698988987, 4139008723, 3888862386, 2893981917, 2796380780,
-                170704642, 301407021, 91294633, 1951632406, 986064363, 1814809055, 2660970675,
-                367016706, 2976724167, 112542310, 3823892204,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1717260443, 1870921568, 3890098813, 547818557, 169140571, 3832307654, 1202456554,
-                2250396571, 3971006771, 2377652585, 3096986083, 796992148, 3931616138, 4053677208,
-                4160554778, 3622691563, 3043662125, 1047176714, 3196102007, 1020281336, 3882041594,
-                1732124754, 900872335, 2261334286, 956675303, 2566690517, 1992751201, 3004183327,
-                3819906900, 589133947, 3590696069, 674470422,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                1350476750, 3178647854, 1662084855, 2616914382, 3586650734, 1499680307, 2533557431,
-                3245674006, 1045988914, 2769367019, 859922738, 4185835182, 706944813, 1809468709,
-                2064551064, 230148016, 1433250946, 1358039214, 3872670681, 1908932817, 3321662411,
-                4038383073, 974181157, 62251621, 2085923353, 1096810907, 803303981, 3128404808,
-                1054879004, 3146541533, 4205522621, 950654272,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                698846549, 2621739599, 2236610626, 3364789590, 2795094691, 772752411, 775111470,
-                3697976765, 888424244, 460539982, 4270737152, 4039597097, 413590193, 4219667826,
-                834638111, 3726156454, 3586806805, 3188430252, 3790512223, 4246968488, 2203263259,
-                700162086, 2711588597, 1548163736, 1552851033, 600471505, 1823940472, 155528888,
-                34028597, 4258840555, 3724273594, 1855514047,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                4189849328, 3507755402, 2783567803, 2914963898, 1889642906, 139216476, 1075942341,
-                1533064916, 544189249, 2755093069, 2203405968, 3043492224, 215400383, 1422191302,
-                1320398239, 4217158322, 3435020588, 2138401030, 3561009846, 2103585220, 1207714499,
-                624811187, 2896018747, 3952826519, 3788277422, 1784569930, 1808430527, 204775021,
-                2513071352, 1064379705, 3726996779, 2245181254,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3171792691, 1187230537, 1321416270, 2382579511, 2276138617, 3214577089, 1853724423,
-                1881416705, 450786120, 4087818107, 3904778298, 1160950906, 2552017194, 1750061927,
-                2826874754, 1787946017, 3174818199, 2319210458, 1221708163, 4190315146, 2851571539,
-                2460846433, 1071377388, 4090301810, 2379594820, 2670786741, 3494504332, 4025919132,
-                647778808, 2790389181, 3779947671, 3243646585,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                71050444, 803002230, 1235552496, 1543803745, 2663082105, 1370662412, 3385642388,
-                1050049111, 1855676525, 1381854218, 2137748795, 4131953711, 4092672594, 4087376102,
-                3884116631, 3837661728, 2276855149, 2087743292, 1316176718, 74928751, 1215910584,
-                3372309871, 2166451657, 3968919124, 3201508816, 74096568, 3021264677, 392812594,
-                3053590397, 3427892432, 3356052503, 1145482790,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                1496855902, 803903984, 2313354565, 1105608971, 3820148708, 280408545, 2191673066,
-                2818645773, 2937763847, 27730745, 4146978319, 376923095, 615632581, 789037596,
-                3772059817, 2058267824, 2181856462, 252854130, 3719429122, 181417536, 1165875384,
-                508959414, 1386621227, 3134402292, 334247812, 2996587928, 2913740401, 1683101044,
-                1161453194, 2856242733, 1311906673, 2173470548,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                889183505, 3324098562, 4179649904, 810905095, 698441621, 1227747360, 2984902253,
-                1494593111, 1809074359, 1307618152, 1464696373, 1608716440, 1703738661, 84165598,
-                3712906543, 2894619099, 2083993437, 1208296940, 255337989, 2293799907, 3840736910,
-                2501720327, 3498264021, 3579521801, 781671362, 1429540404, 3094460216, 3964735293,
-                4242381517, 884320169, 3179705896, 1517378349,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                1414537437, 1368135402, 1552266353, 2853347683, 1284945190, 1577579119, 1213002310,
-                1291034949, 2380451280, 2512053411, 3675254492, 2624954095, 3435878660, 3878602219,
-                196603337, 3997349588, 2858719986, 2462981548, 699389427, 2663975102, 2464234437,
-                604259759, 4050408568, 686627528, 2338438504, 3596684279, 185823855, 1204007334,
-                2329164567, 61136746, 1821414422, 588838080,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2148442993, 3648328503, 740724630, 1825392365, 487515264, 119244283, 2148473562,
-                3673473499, 2535276136, 2580077418, 3746492572, 3388372357, 244999102, 3509870750,
-                2109658793, 2410754242, 2997865730, 2047285146, 2958782899, 2798783525, 3479873020,
-                2823963785, 3568375987, 1619576520, 96242833, 2619765369, 3867129280, 827754409,
-                1091376809, 1316780501, 3159204249, 721189747,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3672932432, 2707962269, 1741960914, 1939598001, 2634792667, 3770520519, 2110193035,
-                2904190449, 1743979507, 3333439119, 4091293347, 3613021061, 3889921681, 3747150437,
-                81279256, 4093988993, 2119397674, 3047357166, 498307143, 3403808419, 4210675739,
-                190828944, 1856103231, 2841104873, 4203913568, 2273169341, 3515656937, 6159223,
-                4096463094, 811348130, 3910711961, 1162947132,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1599000368, 1075436902, 3406830881, 1014998344, 3022165586, 1257578265, 11339066,
-                411739200, 4034527478, 788944706, 17575795, 1134551883, 4240963040, 4314257,
-                1414437492, 377653150, 3329515487, 4138674014, 99936391, 1766940707, 124655494,
-                1768565023, 300051813, 3224179360, 3349455090, 56132079, 3549288556, 3579663195,
-                2658506505, 1348045332, 867732914, 2015700638,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2392043475, 588991126, 1309654657, 554795570, 997691760, 2600041731, 3431864159,
-                1516037647, 3069942040, 2268128716, 2756498953, 429507124, 3466199008, 340413697,
-                1827535785, 1493557730, 1922189314, 4171163096, 200793974, 1910547902, 2485176667,
-                338149694, 1985102177, 2122224701, 2443165619, 2293622936, 2755992265, 846350562,
-                3377218396, 2872721541, 1082416919, 3034347368,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                4264329257, 1492461607, 2835999951, 2337080149, 117890355, 2584580798, 2547560988,
-                4109443405, 291128601, 3078184571, 2106376935, 1758847575, 1343330497, 306281934,
-                3411188223, 3873280507, 5852235, 289606315, 1811135608, 2847090872, 3701833589,
-                2397577647, 3322754294, 1292329523, 1677633091, 158832868, 2367669557, 3144103691,
-                989484080, 1380664811, 450163612, 1237958583,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1711844300, 476105083, 3399107984, 2382738374, 979553507, 878496321, 104057909,
-                1485518354, 2491731223, 1692523174, 300648300, 3849272291, 634510048, 2653438042,
-                3243901054, 53606977, 2061663213, 412604263, 3212559169, 2568513620, 629258989,
-                3316059257, 2909077072, 1729118532, 2869122203, 2215369610, 934165632, 2783487361,
-                2738898427, 4148588232, 1517747103, 1709630885,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2882353791, 2979403963, 2006721380, 244422262, 2683768407, 250840498, 1086309792,
-                3597013968, 3437717371, 3463703900, 2307432106, 2269455547, 566999428, 348941436,
-                4062417704, 4063941733, 2129329877, 3595109460, 4026085013, 27222280, 2707805803,
-                1118103384, 1389403155, 3387030845, 1446723288, 827419773, 2786047144, 1585656197,
-                577529618, 4037637410, 3318408351, 2604583817,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2166486789, 463862893, 103972614, 2941325375, 2562147951, 2703467830, 660457467,
-                1284061214, 1770366275, 978755262, 410121633, 3142531772, 225588341, 2153479881,
-                1724190498, 642723455, 2466831235, 314298396, 1477205205, 609444497, 2559250267,
-                3980418500, 250959627, 3483015225, 3908790161, 2229247462, 1557623650, 1310339526,
-                3825068358, 377958446, 1730494896, 1909673441,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2995316871, 346112500, 703137681, 2537025963, 2814991849, 726015732, 488478212,
-                225379145, 3135944535, 956620073, 1666611630, 2359280882, 3055949694, 3515003668,
-                2501138721, 1203378693, 3395397363, 2486669402, 1581823899, 3411345569, 3847240978,
-                1011948094, 2093426883, 3552141786, 566163702, 2368440183, 2860073446, 2386966516,
-                998235049, 3684640694, 412325502, 3912478156,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1821008087, 58334876, 1472252709, 3686845384, 680315485, 103669820, 1213513460,
-                380512312, 1491918250, 3773161230, 3793682763, 3527134739, 519272695, 970554582,
-                2150435760, 2452396524, 1528042675, 1830343805, 1278128407, 1180637284, 3246754394,
-                472797433, 3239510182, 2503397601, 2382079966, 1590049228, 779890123, 1385743887,
-                1322006313, 487755470, 3892858189, 2703503491,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2631201129, 1766106587, 1333903347, 2883082507, 1077524683, 1217985476, 676952203,
-                4219312354, 2103916110, 3646467876, 555847700, 175030582, 2922844499, 3384933217,
-                995326523, 2167256420, 2737219326, 698731157, 3415361640, 2490801915, 1133301664,
-                4195574688, 2127873133, 2669695548, 1270761167, 3673341017, 3545556209, 1874486445,
-                3678259686, 2126639404, 1673020763, 2660199657,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1770668444, 1475263667, 191715573, 2670634241, 1153058817, 929950537, 489025460,
-                3927678776, 599182145, 680644545, 2711063704, 1060601030, 3124763729, 1637894563,
-                407627355, 1391788538, 2116263468, 4062660663, 2473697366, 783113961, 1932147067,
-                1684153117, 4281431934, 1389075343, 3620242270, 3627361213, 820484273, 4182466002,
-                2464735422, 4198609525, 3419407174, 651571791,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                240580272, 2599235961, 3830016377, 254699218, 2578479856, 2501127234, 3060304250,
-                1665362547, 2413770729, 3533003344, 1568784051, 3374896770, 3335525807, 3161041398,
-                3693002687, 1957690680, 1165281748, 1657564288, 756170496, 704958822, 1699989591,
-                601016727, 2751655612, 2344475833, 4163788921, 3033300631, 107992254, 1473787415,
-                421027058, 2709451018, 2455427259, 538022183,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                4016901328, 1974682588, 2695781935, 146016710, 3658143942, 1597933924, 378506054,
-                2989080450, 2796289942, 2110443927, 2584157722, 377819754, 2922046919, 2531360657,
-                2728861281, 224716138, 3178177496, 3193733599, 949390320, 4290149414, 3778646738,
-                1906848274, 1448027995, 3503401, 547776039, 2112011770, 2225907776, 2455921736,
-                1324223013, 3668563697, 1933229138, 2866454587,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                3303093141, 3293168025, 2645628513, 314115275, 833661568, 3767338700, 2374315227,
-                718752469, 2643624506, 1838415981, 2932778539, 1526387263, 2001760056, 1957344121,
-                2230102194, 4285191751, 3387639709, 4149710749, 485930730, 662141719, 3484322419,
-                3354749159, 957218386, 2744368029, 2211278239, 54079720, 4191227017, 2268820758,
-                2342012561, 2317325129, 2527232135, 3019750351,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3029693032, 3318105307, 870093633, 1663659705, 3283640320, 1888809948, 3681393154,
-                51249848, 2884201588, 1612553193, 654674596, 249269555, 2989143018, 3312902925,
-                1474230604, 2513136604, 2879070973, 1686245322, 3802153795, 2191337395, 4090419841,
-                1605521768, 2919375513, 3660604762, 2406239642, 2888160222, 2656438387, 1924530200,
-                3001662561, 2258381695, 2111468815, 2725308831,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                864953205, 3596262499, 329040277, 602996924, 3360962358, 475198377, 3574819846,
-                298420268, 1744132065, 2462460604, 1338066828, 3748229268, 81143140, 103964532,
-                2401328698, 1664565013, 3823727579, 4153105895, 2927199744, 3366998582, 2835858548,
-                1269532891, 3433147595, 911240492, 477212111, 2134261119, 4040565280, 804492801,
-                4143187637, 3858932209, 2600433072, 1360937642,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                573937010, 716248467, 1724340537, 1196605488, 4107933565, 2474845802, 1471156746,
-                3305666129, 826329620, 1979985659, 4133760940, 107352317, 1265161717, 3384995573,
-                3763030575, 1565344612, 2041804638, 2451943225, 2041915389, 1286635713, 1931819164,
-                3051816909, 711506746, 2262273081, 1395840441, 1897672997, 3952654130, 4249905863,
-                540622858, 1780155942, 891880271, 4250499525,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                3331824960, 2541782070, 1651347193, 1375441546, 1813134296, 1181533925, 48471054,
-                806109305, 3993544692, 2150185959, 3920597127, 826761974, 1467623088, 767891307,
-                3803987591, 673107363, 68379970, 3579579226, 632855358, 3773416823, 1738676151,
-                3546331836, 3926698698, 224285755, 2907596444, 217450514, 1422144284, 4285037230,
-                3508065956, 515928575, 1623224479, 174428225,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2686301465, 3713207127, 1029837601, 515624498, 2062327257, 3168170196, 2798752096,
-                3963614005, 1308267315, 4154419552, 2608359342, 1073178963, 480845026, 1653601642,
-                2584464760, 1950317769, 4144784672, 1877181942, 3290558961, 1225727118, 4244977769,
-                2783832828, 1582389591, 910150275, 3377864985, 2233872866, 413788415, 2530739144,
-                2736766763, 3355013011, 2692657433, 984480933,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3042445339, 3989732271, 1489522022, 3430911333, 2233187860, 3097004902, 1946511580,
-                3719994269, 241652604, 3570628536, 1362524919, 2698946261, 902505326, 3610275481,
-                220022754, 1431832478, 2371544052, 2785325090, 4146821768, 3234244997, 1837379346,
-                3050070561, 2436985084, 550197711, 3806235131, 3423458476, 3145929745, 3874283366,
-                2620994306, 1126908206, 3261546855, 2000810369,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                1750519810, 3025115015, 658699271, 1772224181, 4278050828, 2360237921, 1534184151,
-                2459018488, 2727285265, 79704699, 151430391, 1950691063, 2526895167, 1679885235,
-                2650023598, 3117332489, 1734512461, 2729627735, 3193861943, 2566187839, 2321587615,
-                3107539863, 79386596, 4008284408, 1356152885, 954262442, 2872608016, 2159337138,
-                1182403956, 4025661046, 4103828559, 3893230527,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1169749775, 1170819896, 4155720999, 3289720236, 669988303, 3393670691, 1807900840,
-                3367294590, 1203048487, 658865783, 2375484712, 2666096824, 2735893361, 749129209,
-                2952914789, 968650083, 2697003548, 3722276804, 654125771, 789209314, 3620371530,
-                4277671067, 2558995981, 2182204245, 1717937479, 3566536134, 1304882063, 2052378003,
-                3356308092, 3490084688, 3784578224, 3161827918,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2100245924, 1215313625, 3784609673, 3477019282, 2735503832, 438265088, 2016830853,
-                2611570923, 3469866202, 3059328500, 2272574577, 3564886434, 190151003, 1292379560,
-                1474321535, 1675915567, 2125880744, 4183390424, 432687637, 3515161583, 658030491,
-                3382060452, 3418069730, 3380728334, 2823957624, 820615528, 1872419609, 533088331,
-                4005137589, 424076168, 586188600, 3723211486,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                781305168, 3995391661, 3311309700, 719265792, 1756696987, 2169847773, 1257283340,
-                2652903003, 2362038961, 1158111151, 4022907128, 2732483928, 202422668, 330373460,
-                1303048540, 1817562541, 1185972257, 160584634, 104426080, 3897041241, 4139990130,
-                1771978712, 2488667939, 2499466496, 3272676413, 1075806349, 4208863177, 931454099,
-                3788508421, 3031381957, 2319794839, 1018410804,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1069833184, 3315969794, 3995082579, 734591327, 3520309860, 3843640368, 1228423364,
-                2083537826, 833862661, 2559895135, 1849588570, 3961139580, 3706706365, 1651811900,
-                3000449348, 1912263993, 1001768607, 276062745, 1886380223, 418750626, 3780284364,
-                1466507129, 84113310, 3011693755, 1813279511, 1832041952, 61772946, 1637050762,
-                470158748, 1185801341, 3008287296, 2512084332,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                1407724274, 2358279515, 1811493294, 2619158497, 1188621812, 4257570940, 1724183888,
-                2607108072, 247698444, 1290057705, 3962970341, 4204277200, 107494681, 3318861898,
-                2604633120, 3356516267, 3005496249, 2224929105, 1616534193, 3459618836, 1849495402,
-                2615058631, 3503968534, 3303474292, 3776785099, 3112663166, 2376001316, 1721449658,
-                1869354968, 332122705, 131583773, 3588833315,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1945867140, 3671481211, 867846253, 3211076034, 2121751535, 2372097670, 3102519284,
-                2441820403, 3716735303, 1083427563, 2818959757, 124243617, 579161626, 4164242041,
-                2582744376, 416198988, 86988465, 558414145, 1398639746, 2213922380, 1783007862,
-                1078954791, 3282896838, 874170876, 2247003252, 4146237753, 2572824490, 3681620018,
-                548702923, 3775097083, 334586414, 3685460289,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1964395780, 3379106404, 4184453824, 787632871, 732812458, 2778355472, 1561230010,
-                292376694, 3322706011, 1592464042, 2672547186, 4130135582, 1743854108, 1911403653,
-                3786943006, 3303187476, 907531680, 3484327043, 648102816, 2615171961, 3804669969,
-                898064253, 1648226733, 958315342, 629506529, 3465404937, 2219836712, 3727243882,
-                3161737013, 511878693, 2784780137, 1731268821,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3712324958, 1617879885, 3250340889, 3495483898, 3964258125, 78484347, 4118244619,
-                3723226538, 115701819, 1023669480, 4274734038, 4240340980, 900326399, 158657852,
-                4069458224, 3507437222, 1099126917, 4212398035, 2722948986, 3315077151, 3652440647,
-                4258405511, 32130145, 2627648432, 3013504572, 966781638, 1340287906, 1651192145,
-                2442511554, 1805910852, 3552337703, 3823961247,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3394668955, 572112197, 3772183486, 1604508771, 2861994285, 175041900, 3802477268,
-                3062939085, 485410644, 2522714651, 1672112836, 1811914886, 607547173, 2992457398,
-                2142161810, 2049923511, 1567930504, 482282360, 623076287, 3072191777, 714759993,
-                3466851721, 1335866874, 396588648, 2963325088, 2296111161, 2465585503, 3135849560,
-                1705864570, 3686415362, 1712775797, 1965418485,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3853239917, 2464586584, 2746388153, 3899239438, 1889543340, 4226011929, 3164080551,
-                2900010572, 1101243337, 3291633920, 3905880184, 178279627, 4093565359, 2936214579,
-                1198485931, 4064013676, 1213813308, 3082916140, 1405049055, 2870896920, 1123693104,
-                1773327862, 3551389787, 528081343, 795791266, 1578507264, 2305963083, 3576632217,
-                2950779551, 2333946506, 2829577321, 4160387024,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                1782986141, 1101313046, 963917787, 3692414134, 4184977777, 3831752728, 1572793252,
-                1515141470, 477032331, 3556303610, 3020051244, 2760788705, 4218132677, 3491872729,
-                2960024168, 4287077305, 4237279235, 2802461995, 101145782, 965079658, 3663160838,
-                7590351, 1934989842, 198837434, 1068448816, 907100608, 340291945, 628099516,
-                1945352758, 3160693123, 1271838654, 2951885972,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                3861797942, 3666283840, 2480322339, 3870094053, 3923186365, 466956557, 1391974619,
-                2947039860, 10312978, 4222595876, 1586860128, 3215155249, 2292271499, 3766509690,
-                3662027024, 2617648587, 62613384, 3286898439, 1980459084, 1830763545, 416657993,
-                2102916735, 2400335630, 3738289, 1553799645, 2730436072, 3479987646, 3686725129,
-                2618514550, 507879779, 3967268599, 3541660422,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3471117722, 2897733806, 2604047650, 3916508004, 4086825240, 779433059, 4004668400,
-                815971215, 4234489832, 2063861664, 2294149297, 3429335196, 3853291519, 999753520,
-                1863399788, 1496671228, 1949746396, 860349690, 2556166946, 107823428, 3094139339,
-                2718682676, 2095836286, 2870783220, 1396849600, 3286859457, 3794642440, 1029317230,
-                39740988, 3416229650, 626116069, 1341774890,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2898909210, 414158821, 272899740, 3313098561, 3309697674, 709655882, 3740375340,
-                1706799357, 1193965147, 1465603708, 3729865324, 3486314556, 1265100762, 2370421594,
-                161781445, 2272569557, 2519590386, 2465891350, 3929530136, 3973744589, 353733210,
-                1379265598, 37634327, 753791537, 1700442943, 2883174511, 3692519348, 4087385869,
-                3767432197, 1102831450, 3859106309, 3852599086,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1350653410, 3387581860, 157795659, 1547857850, 3789555117, 2925048585, 1658672540,
-                1030113058, 3656007412, 3012222864, 3282325080, 1785345914, 1060756982, 3302136366,
-                1555012444, 845852735, 1784355038, 803626351, 2738321147, 577646811, 1333932128,
-                767468182, 2559481387, 4178764056, 3278575424, 2850708339, 1932992604, 1872577154,
-                1574154965, 3983734516, 3746457075, 3186073019,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1017074460, 799015039, 3854748998, 4086055867, 289549160, 3787242139, 3999901421,
-                2074036363, 285620308, 3502484511, 574321378, 3677107018, 458132953, 3240466870,
-                2451035155, 1528208454, 1251701725, 1058459527, 3651325165, 957582744, 3623866525,
-                2625641201, 884350692, 1724641169, 729428903, 2697950691, 3643874879, 4444531,
-                3414326767, 1035283006, 1407779966, 104550302,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3936855953, 3247511168, 3702434170, 2004790081, 1549504228, 450399455, 1018607757,
-                4262814298, 3916290849, 829759585, 3835169301, 1835062258, 2378372993, 2007416208,
-                4143977424, 3135415931, 4145759315, 803864309, 3213017074, 883917126, 2308819301,
-                4011504421, 2801103669, 249376150, 2213863178, 2622249042, 2683121286, 2318189081,
-                4014652484, 3624796740, 2949819799, 3146376771,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                960890342, 1172654332, 3246775090, 1403945945, 506800207, 1863961410, 2031526721,
-                1617607876, 675256076, 953529367, 3041889505, 982326792, 451010877, 2994629041,
-                3064458968, 2083778030, 45345469, 3295070698, 2980991423, 1864176908, 2094530031,
-                1350404389, 3448585789, 2456792079, 440029798, 1487511597, 1144864685, 1059446536,
-                922965110, 2497168638, 2758324883, 2359706407,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                439383544, 912586202, 1147238280, 1588217167, 4143794562, 1603223779, 721210255,
-                4196510524, 702183439, 974603797, 3940574940, 3123836503, 1449623941, 1584447153,
-                3516729742, 3573260078, 708024060, 3213330635, 1766528024, 3990348203, 3525170127,
-                1233847720, 2018292197, 2831525323, 1989124403, 3436187349, 1411937748, 3033527791,
-                2953392068, 3450976316, 30851063, 4233397395,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                2774791738, 13493554, 4144243648, 1507098948, 2760144904, 3860563936, 3685752348,
-                1028389610, 2348117296, 1127416211, 2701314574, 2820135482, 3027649332, 592169730,
-                1826181610, 515662225, 902164586, 3076572835, 2502185403, 3555526455, 685430787,
-                3759407322, 3967829160, 2707993511, 1647358786, 2259137056, 1393158932, 2268767352,
-                2669547601, 4011695763, 3624797377, 4145696035,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3452333562, 2904906946, 485454811, 3986704401, 2586383761, 2223824602, 3304694348,
-                1691042258, 3206196753, 1445827611, 2115963084, 4055373947, 2122654195, 3767015718,
-                3249437946, 425305908, 927649862, 1497188142, 3493031942, 3022263737, 1186101528,
-                3692453661, 2862653891, 4062020294, 345471821, 1641891419, 3268751980, 924104350,
-                4030253639, 1610809973, 1241391541, 1485184826,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                571631178, 2980024730, 1314775743, 2358243233, 2275684356, 50778004, 4108028896,
-                1927826567, 938180296, 729387491, 787623186, 249148039, 3940683007, 908585028,
-                1291515473, 2939865398, 2593345882, 871805771, 1054659670, 3625448334, 896928258,
-                3065451247, 2707118725, 2413722755, 3153125810, 3010197477, 3655431395, 3363581298,
-                547812752, 1043516179, 3838442282, 2751793157,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3975460743, 1458667684, 1943228079, 1041183508, 2719981375, 2022216046, 3563015066,
-                2849475168, 3133348903, 374639191, 3979090770, 2675366145, 2073297007, 1189133981,
-                4252061325, 822292467, 2581800688, 4187618125, 120432553, 1627768484, 3236081959,
-                900614183, 2464929185, 2679686892, 1121918400, 232765069, 3822323118, 3593157976,
-                554557343, 1028884137, 1000414335, 2048873920,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3287029189, 2574181342, 529593042, 2331251540, 4107365586, 1693177620, 1406346550,
-                2679360399, 4240687796, 3370070119, 4163817341, 2538010860, 2062493426, 3406566283,
-                1517660990, 3755062081, 2139518702, 785780624, 3235988709, 782432069, 987982052,
-                2568009157, 4220250630, 1170077851, 2705916336, 2794612065, 1976357058, 3404890410,
-                329454590, 1161207483, 2762376168, 1322978542,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                466595393, 1255760672, 2589224897, 2187897557, 3790935951, 514243936, 951141707,
-                1619923288, 1205187381, 3613382207, 2016669279, 3863858157, 951931486, 3982857535,
-                1056416225, 3353236848, 3337990855, 4292007764, 2827114317, 886166625, 790747871,
-                4006343835, 3071864860, 3085441121, 2824338070, 595708733, 485258199, 101189778,
-                1506783010, 3124745912, 2104537163, 188126783,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                1631980063, 3980540381, 1614771928, 2199471259, 3524816302, 3017993425, 3853950001,
-                1736564511, 3420707188, 1892110763, 2100734566, 968180502, 343070434, 3547900631,
-                3436982786, 2991246111, 1547025666, 59450393, 53601837, 3618455571, 1979762811,
-                2359557134, 3467910017, 3535292802, 3257376973, 3325923606, 3679413677, 2271704941,
-                2287437797, 476312592, 1596647900, 473052021,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2424322075, 951218971, 1910697714, 712153315, 3057770026, 3853800676, 282007731,
-                1729033428, 3468925889, 2790047410, 110028726, 1611571911, 2055160238, 2589183804,
-                280446303, 2751740064, 1320592437, 1816244960, 4262873691, 533974661, 1276504359,
-                2838463639, 2092945527, 1022318889, 1497180467, 3394445106, 1635369192, 3815286888,
-                259089361, 698596407, 4275209129, 857351197,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                1768391002, 1185792742, 1117580278, 2765212774, 2632839469, 983175097, 1890359144,
-                2234672479, 4014603213, 1965036447, 3435537738, 2525243527, 10717717, 3117619723,
-                3003670430, 1676804633, 3627796473, 1435236220, 2045770221, 4111400311, 1702292842,
-                3570840047, 1860428577, 2029575055, 2736896618, 2784580479, 2573306334, 2187342942,
-                1969152198, 3026885594, 1030958427, 4226655675,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                335658412, 2218887259, 2481186667, 2074101405, 16582214, 1960151421, 3454730236,
-                1944648291, 2364460159, 706891568, 2534115657, 3189649803, 2547339706, 972705239,
-                2382577439, 4195269025, 1202516190, 3833386996, 62894065, 2653815174, 2105567722,
-                2925576270, 630905959, 957614694, 1352622838, 340135439, 1936989349, 869936528,
-                2214355589, 4188423192, 3436650576, 3504082765,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                2677583229, 1570683998, 1083602343, 1664459681, 3812496805, 717742005, 2738627417,
-                2708578483, 2173085149, 222042765, 3911348569, 1221662742, 39340355, 854881719,
-                4210827420, 3631171156, 187867211, 3530471388, 391452492, 2108687817, 2387990001,
-                4018851452, 3900803239, 3279684875, 680342748, 4279336782, 174910466, 1281960954,
-                3671278862, 1217589713, 3135076527, 863390018,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2396325280, 3158094805, 3573583928, 2712261518, 129574140, 2070903042, 2886772380,
-                1371510438, 591583089, 932574543, 1631415818, 2058605731, 2274882821, 2207796901,
-                2236664666, 1923985501, 441412438, 3797881556, 406801308, 2348692167, 3352352077,
-                2380539815, 3797449810, 2398877444, 2496648143, 3710525397, 565649350, 18326762,
-                1723273271, 2915913422, 2208229608, 4225598930,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2804628973, 2507756956, 3992476463, 1509325414, 3685544442, 4088695542, 4086353110,
-                2700435365, 4135595431, 4031785725, 1815174337, 3095869938, 632926734, 3389834461,
-                1044779658, 297205767, 1994323534, 4244877748, 3940224877, 558462562, 1368113699,
-                2814328649, 1238119897, 552522625, 812087853, 3339354649, 2799820279, 4025542204,
-                4059335251, 1478915621, 3506477873, 3348316440,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                961203748, 1138224789, 955921270, 1520694075, 1786556842, 2307952499, 1470893302,
-                2124476609, 1705572195, 963028921, 411222463, 1953600904, 2050060166, 2888008050,
-                2130952900, 1787412569, 2367355290, 2369383534, 1272856751, 1252539331, 4236060668,
-                1113306526, 1859455872, 2760996603, 2637811022, 3921896281, 38591392, 3025347247,
-                3569373972, 3262458045, 3802750662, 4180414681,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1765902361, 4052784232, 3902584014, 878126895, 356767053, 2620746338, 1356420062,
-                2850022610, 3042211461, 2938280555, 3437220896, 3918543499, 3323749297, 2514599140,
-                7832324, 670826125, 1201438146, 3526631061, 3930046847, 4052351319, 569425209,
-                1703256679, 3312586297, 710127934, 615490067, 1158554921, 3909588215, 4267779523,
-                2706540579, 2540351727, 1322776569, 160725584,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                4246382062, 2352592156, 2057722290, 3012033665, 632621582, 3505916054, 2760979157,
-                3935472210, 2842227202, 4040134579, 1754866608, 518514244, 1777496766, 2748463226,
-                3984004643, 2419135528, 2398102376, 1398838712, 1991703809, 1094652945, 4119772941,
-                914842518, 3903285073, 1007406305, 3451977920, 828094336, 2378924443, 2762556527,
-                40719953, 1766102353, 611455187, 2298851546,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                1138274658, 551646516, 2115262351, 1067518824, 1422457626, 1151665369, 4010910025,
-                1634534099, 2167688370, 2425186206, 381815068, 1054895196, 682647440, 1650990552,
-                746191159, 3321704, 4120773082, 4283503870, 3303559349, 786600236, 225251296,
-                2853282048, 720574866, 2583929816, 847519491, 352131911, 1712101249, 944799056,
-                3233086288, 3014447580, 997082032, 2787641598,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2330529018, 1404232318, 692358139, 3937337832, 2935754381, 47310300, 1669768844,
-                2315050216, 2505898610, 3382243672, 3894573948, 3054303114, 1138811351, 3365482248,
-                4068341175, 3719203344, 1756245412, 3093796234, 641753531, 306923620, 1916750771,
-                547411666, 4031321835, 283172657, 1786347802, 127430032, 1531543400, 1810300065,
-                3500204955, 462106782, 2527756870, 1735041012,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3568907255, 3081999817, 1217465719, 1615036775, 45422522, 2146375078, 2138281014,
-                2348101665, 2927104253, 1077219996, 2149927938, 3523515514, 2021280723, 4053999946,
-                1964184555, 2781667328, 4052195260, 1776234187, 1050388429, 3402973157, 3577545731,
-                6454148, 2678029192, 1034830195, 1472403599, 1727395955, 1555797167, 2104899847,
-                2693776860, 2184445714, 2740595049, 4029296440,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1669362561, 4239725494, 1595702708, 2060001951, 1472404214, 4151728941, 3403986839,
-                9933103, 3917394733, 3336935606, 2967323465, 3542349750, 3457666525, 1643653804,
-                1953484861, 3276170149, 849837878, 525138782, 1879705505, 2373134534, 232484834,
-                3242603516, 3200993145, 806878123, 1889965805, 3361186578, 3922452780, 1354858758,
-                2330957235, 2654965139, 1505413708, 1084237920,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                881646911, 544939323, 1923349552, 2902010613, 3053280326, 224036618, 945698213,
-                3139836806, 3093387857, 2119134859, 748878966, 2173687363, 562157416, 467168176,
-                243292506, 2585903887, 1512019167, 274553209, 1013136307, 1550957380, 3026219773,
-                4172436329, 1013407841, 1194143344, 2571455430, 3022442377, 3906371389, 3065977088,
-                364247212, 3945198016, 2431810396, 3250735087,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1628642285, 2994386258, 1979809262, 2580976902, 1962778258, 4270862134, 4032702550,
-                2366015486, 2553050629, 1703646656, 2872888873, 2088521798, 2612567148, 3029336521,
-                892162650, 112446715, 3218455825, 3839678609, 3049124194, 780671255, 2457024429,
-                4117291427, 593078771, 1146986363, 3503543138, 2941270806, 3245304218, 2982121061,
-                918121697, 1278147081, 627789162, 880084912,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2542541178, 1553262044, 1355389148, 2865968081, 1258779153, 553113207, 3983991538,
-                1552821679, 373938952, 1233299520, 2310088738, 452732113, 4237071800, 2895045475,
-                3965940140, 1157027532, 3431133577, 2420298993, 3107004154, 941663377, 1805983718,
-                4008856168, 1495453579, 2355514926, 3309934920, 2498563453, 1054603946, 4272844036,
-                3629207791, 463233579, 348511268, 103109294,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1682998737, 532064717, 1227225254, 4065164385, 1076271724, 2835961546, 546425798,
-                2500299284, 672082846, 1440178960, 1478632750, 3710397902, 1991162108, 4233146632,
-                4137066814, 4083121073, 1561437246, 1523686171, 4259289057, 3749807606, 1264325989,
-                367059497, 3807253871, 4127216889, 1559174397, 2470487489, 903341275, 1361931172,
-                1242479363, 289142029, 1203935331, 3311870649,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1215188089, 1870358811, 2180519642, 2849164486, 1372745220, 625292479, 139142363,
-                1823725940, 3720477923, 4222690627, 3861179491, 88316010, 2630080883, 326088730,
-                3190958254, 2060425819, 3923973509, 2822244825, 2179947080, 2637961010, 3665254454,
-                1162799403, 561294290, 3583802549, 2052233224, 3408309879, 2002068282, 2156113026,
-                883171812, 2237445132, 174282677, 4219515286,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                34863937, 2777985902, 3696415195, 1057021969, 1951364528, 2768020752, 1261974125,
-                3582784726, 1905660497, 3537796713, 3019513904, 3997084570, 2588822008, 3838301917,
-                2591021801, 4220287359, 1362739113, 169997960, 4068329016, 101649839, 3729561775,
-                3406975884, 3500442156, 211478638, 2468187824, 591339978, 3530229557, 3947921647,
-                4154153219, 2627223135, 2151548320, 3844844890,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                2692693801, 33148589, 1538757828, 4069391645, 967259118, 2799852762, 668472479,
-                2686851458, 4131292263, 585367400, 1311131644, 1274068614, 3735833265, 1512118442,
-                444075071, 2443961716, 1227531178, 3541865920, 2557612984, 1598951226, 1981535021,
-                2083367979, 4206956761, 1419068497, 1808624229, 3814990831, 3553795659, 2367773453,
-                1827393244, 4231910231, 1958019222, 919861936,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3700970893, 825318827, 1340508929, 3276643489, 2661350279, 961009715, 1097498638,
-                1178614381, 2917400999, 796650451, 3180313285, 3401366628, 1757927138, 1770533447,
-                3352041663, 1086905327, 1711687288, 3620065006, 2518641889, 4112619821, 1591517343,
-                1093677609, 4015125446, 2187641360, 137789439, 2734859843, 2522999826, 4040956946,
-                2278043035, 2130306235, 1426180476, 3050522348,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2634640914, 2114625151, 1227279708, 2052185951, 2879647171, 862459659, 3309478924,
-                3075588223, 4235250152, 982229391, 1561997931, 1554793195, 1390815969, 1594084323,
-                4225818074, 3937574681, 465829976, 4237508174, 987652061, 2298787581, 693313920,
-                3261071141, 1942873086, 2510304114, 4016857428, 2649075914, 3222101791, 2962694857,
-                4047238807, 261076107, 426330499, 4283614242,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                533158432, 2195976089, 1534264002, 3872770285, 1644259382, 2660852405, 139277750,
-                1913047840, 3974787974, 589537323, 49944135, 3887503040, 1759113778, 958160448,
-                3927742384, 2665995163, 3438981653, 1061316312, 1601537265, 3399908015, 1587044949,
-                3859588414, 429553824, 1779200115, 1968448955, 1809306380, 2497607173, 1789759183,
-                2936920442, 413039916, 3572229278, 2986860321,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3243488078, 3133772850, 3123298296, 4047299910, 762744557, 1647286825, 1234869652,
-                4175475534, 1604888538, 3976960223, 3238000455, 1963619360, 1581441955, 2900759504,
-                857114055, 555214638, 1393550711, 2881286582, 2448637849, 3426137478, 366269962,
-                2567077804, 3406207933, 1858158978, 1178810692, 2761350969, 164258225, 598417657,
-                926126933, 3389377797, 36640749, 562188172,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3086398711, 4260979734, 3190446967, 1503260726, 4156476012, 4140246684, 4018737492,
-                1623859079, 1810127195, 1387699541, 2196961453, 4257805680, 14897926, 1652793716,
-                175441322, 2365512885, 3858651309, 944113737, 3143930978, 3798681009, 1598187912,
-                1799122062, 2692525772, 1518014404, 2776990098, 3088995190, 4024091868, 2760749211,
-                2593892743, 1538647047, 2797068901, 4059947358,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                4049452631, 3462548129, 2571518099, 2701015645, 3636405921, 1787900752, 493852276,
-                173957364, 3668788545, 1721642301, 3880881560, 1214044907, 2783491065, 1548434374,
-                1370894957, 4267169117, 3844081182, 1653226652, 3835720179, 1145340252, 1054785595,
-                1623667674, 3245967936, 186035051, 2051072303, 2927661122, 2247341210, 797395839,
-                3986248485, 770681789, 4236723103, 3626404939,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2146133911, 129188278, 2745468759, 113433937, 2773021959, 3504583879, 3520284642,
-                458083859, 2407393242, 574289801, 1019924772, 1689965600, 53971532, 2543296403,
-                3914276432, 1456985140, 3002016036, 3948044108, 2447184896, 3008969090, 3908402664,
-                3498698056, 2735380469, 2404414691, 508960044, 2026894261, 1915150988, 3993493246,
-                1445481686, 2012084394, 4192289603, 340071016,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                602348, 4261692342, 1002357919, 2589000347, 3762084254, 451869849, 491780969,
-                1417707115, 522148800, 2732240033, 4277100690, 1818770843, 2337278034, 2460542063,
-                3859045520, 7211055, 2092569646, 3529245297, 1397380183, 2657145667, 2562400978,
-                3525043889, 779872219, 3474403650, 2150202490, 1478521302, 3649105477, 2477461253,
-                333048781, 3997957328, 3879058971, 2694280985,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                1220175558, 4207361394, 4202925435, 1528166292, 1636911315, 1775335689, 4194498079,
-                3371977224, 1257931875, 2729221803, 1298490098, 2957294239, 571933088, 700576326,
-                1450742983, 1283904169, 2470652668, 563166582, 1731597329, 1967030166, 1905822287,
-                2441178414, 569603437, 3386184027, 2911624392, 1689649208, 1229904135, 2575264323,
-                2647889519, 729022917, 3528629379, 567673827,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3636214723, 2299387871, 57772685, 1670513942, 1143571067, 2563758723, 1273066848,
-                635930090, 1814293361, 1601806209, 1829995633, 718022371, 2432294811, 2067148750,
-                1552476715, 879241704, 1336425126, 1862847015, 1829393835, 3313872928, 381403106,
-                3765086220, 2649983588, 2680191174, 1416917237, 2565601295, 1689677148, 136966622,
-                415895004, 3713040025, 2783696191, 2871176779,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                4065746072, 718706394, 442087926, 771788636, 812130735, 1030119236, 2217720389,
-                2809098921, 2897792765, 3482723100, 3791553021, 1648109344, 718651532, 771394974,
-                4028998184, 1168927162, 99006342, 730672320, 2716426117, 4076541606, 3889700310,
-                2223075911, 3046877476, 1348168636, 1002680484, 2103957538, 2890129288, 2493120430,
-                1544864691, 2521178573, 1406665803, 1337346929,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2554778198, 1975960505, 18935295, 187323170, 3937254260, 946026960, 3131470910,
-                512571446, 2253210581, 1280771208, 2127402174, 1227259846, 395789597, 3262384796,
-                2571239887, 2834119807, 3195090789, 2499669034, 1378432570, 3736244474, 3594278447,
-                3018291551, 1274741455, 313207925, 2352759599, 660794128, 3503159671, 2128310711,
-                2145264055, 1235823956, 2056755957, 3650647886,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3583584400, 3249798303, 2510705013, 3145724934, 2357724651, 245330440, 3273671632,
-                3779877273, 1041298910, 2104119612, 4152263441, 2367621720, 3182547968, 2824152055,
-                439141619, 614441290, 2396992629, 2453895638, 1616409449, 2026359737, 2408442299,
-                1262121316, 2823413105, 2438515299, 430354148, 3449232271, 1328680264, 316143934,
-                3143084191, 2628167784, 2340125320, 213950902,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2505013288, 3817306669, 2579382090, 769441478, 1705162770, 3154029840, 3781037490,
-                2270453101, 1998056159, 823826575, 2597496125, 3821940834, 2507357835, 1688598608,
-                3818178902, 226458820, 2135739804, 2608105615, 2594890847, 4244783807, 4002107460,
-                4245472530, 3966138107, 2138053028, 2926055321, 3401857925, 1280792207, 4214263707,
-                2651396630, 2876249051, 2761325922, 1611163101,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2815799933, 4118552936, 2085397941, 359424795, 1252820516, 3360033612, 3197656943,
-                2483199703, 938109200, 946177761, 3721384883, 2214487117, 3314214967, 2233144385,
-                1419693555, 1966116306, 1675767740, 4047853628, 2765217880, 2668841900, 1830138126,
-                2208050168, 3184011129, 626210859, 3721660817, 690589718, 1266155736, 1655583962,
-                3380024373, 3067865488, 3123305504, 2221750275,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                2501872582, 1291703040, 3675953953, 559885297, 2272539983, 3809161082, 1899356077,
-                2373989739, 3749765469, 2612509233, 3196084451, 2355406911, 1827082396, 724213458,
-                4136148933, 1686526261, 2383653313, 191390283, 692489957, 12016564, 2993776751,
-                1759620829, 2903171283, 2376506843, 1592034456, 2750491024, 3036356253, 1847799201,
-                2892319101, 3907781628, 1574512350, 2298516916,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                3203119878, 2501417531, 261329960, 2788521652, 3306604117, 488375629, 3375547346,
-                1141668370, 321620726, 3276288354, 481351059, 3025213509, 2369760676, 3484540436,
-                601862576, 3836672835, 1986458706, 713582162, 2529567896, 4048246100, 1643394300,
-                666280325, 2201825088, 2444317949, 4167874124, 3414211043, 2113337856, 3153542517,
-                515121310, 3124451776, 3865060790, 3397700376,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3654372511, 3235889757, 3810497642, 1814849772, 2837116077, 2197599892, 17027221,
-                3184947021, 1719717750, 1308886031, 2295388266, 2198103626, 3263868462, 3050284958,
-                2991843889, 1278163884, 3952386312, 603551961, 46546236, 3601820442, 3282695499,
-                1733216519, 220249042, 1468794204, 3092267251, 2202311400, 699061584, 2123803705,
-                1307195230, 406233383, 246368214, 2155866929,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2867141400, 2577577779, 3451258537, 1874970697, 23446414, 1981467103, 2501453140,
-                1872974322, 1548314108, 4091958251, 471270059, 248974833, 3863248470, 3756008970,
-                1096809852, 636175521, 3403632427, 528670427, 2539954109, 1629603787, 796740054,
-                3031485348, 1249940763, 640568862, 2350562487, 3222129919, 383790233, 3034482530,
-                814523262, 3653800483, 2515134859, 438870406,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                1606779364, 1829169902, 1535927488, 1390481481, 727656336, 976646014, 1518314931,
-                4111871471, 4286354219, 3428125559, 722161086, 78230022, 1169600854, 3287912513,
-                438518081, 3863843403, 206014972, 3502293981, 333473710, 489508373, 1118092421,
-                1021109361, 1726891085, 2887070324, 3261651879, 2134966067, 3625656294, 292622429,
-                4066970958, 2889320749, 3588148720, 2305862613,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2317236127, 3181153772, 2536643668, 2811256002, 3316399561, 1143481982, 419255501,
-                946488778, 1263248640, 1390573936, 2337340748, 2382563391, 1773092250, 3400813058,
-                1821711039, 375054093, 1277571434, 873025688, 1862398357, 3981899001, 2178460229,
-                3894353661, 2389596164, 1511325081, 1287878845, 1051644896, 2286645628, 1456335054,
-                1525283129, 2400591622, 1268828880, 3465494146,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                617689804, 2225579737, 2994126629, 2812934765, 2624169767, 4184314986, 2080961036,
-                1491018774, 2837899801, 3154565111, 2256684703, 3407481483, 3673485846, 2746856964,
-                1949930261, 3182748369, 2030814623, 2004190591, 3630034018, 3937457690, 46115156,
-                3550813787, 65703099, 2977428484, 40296928, 3049157222, 2201333037, 2716185787,
-                1994953421, 3754777568, 2673177772, 1136025779,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                1246320005, 740286625, 4059344680, 342795811, 4062578984, 359153928, 4260372942,
-                3426012945, 3326834498, 2268291191, 1565375383, 2040231283, 175967807, 708823398,
-                2919007195, 2322664237, 2045362654, 3678493112, 3100216725, 1719029144, 2633519456,
-                1002443393, 2531880871, 757086617, 497962434, 1579021336, 3524112641, 1401338087,
-                620443859, 3626098193, 3292823018, 3060032478,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1462461173, 3044061046, 1280972576, 1151579429, 2251067008, 2713703090, 623857689,
-                3379437312, 472540340, 2915981140, 485926166, 529970196, 2583336694, 1931233633,
-                174271840, 2682248353, 1452424429, 1699722644, 3641326544, 1068262901, 4294944064,
-                3118628115, 3537233587, 2178976772, 576084257, 2188564806, 1919886130, 2082985330,
-                1616926624, 745698363, 91318158, 939519640,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1563907765, 2475156217, 317529028, 1545354003, 945106466, 3950275161, 1569974073,
-                3256839725, 2594239055, 3685491025, 3864607324, 4038434535, 2828336762, 1664071186,
-                1451004689, 317192132, 1069860000, 1503683967, 2654413899, 382977329, 991661262,
-                52173216, 1372522, 2214240750, 806726925, 112012148, 632411437, 374262153,
-                2088745506, 3538953440, 658851232, 2260762004,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3040624164, 3151027987, 3525293229, 4080668318, 2911376541, 350724420, 1287998960,
-                3433689383, 4140946558, 3521979341, 3460588122, 4238926194, 854016630, 1774780784,
-                2964459025, 4063127989, 1432769567, 2770156714, 4250957551, 3220753245, 386966850,
-                1355413628, 1344163892, 1227222771, 2279211422, 640430007, 3893445833, 3380216087,
-                2200959121, 84006041, 240979905, 2464659674,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                4088006976, 2379101589, 2954915067, 2164016179, 3524075321, 2090924274, 4112822285,
-                2040229995, 140189249, 2691317399, 2269877708, 1426484932, 871466879, 911716154,
-                478190380, 3335389185, 4008095341, 4033478365, 3116072580, 2856737249, 3038160306,
-                439847137, 3455147160, 1536523141, 376419069, 530385525, 796466660, 1250102401,
-                883569541, 811039968, 2125873228, 1045073447,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                1591352416, 1019912779, 3808316405, 3931640327, 4156315366, 2833023774, 3438629434,
-                94528738, 3304663826, 2379684113, 1519066671, 906366687, 2567492673, 668683390,
-                1158409440, 4106578453, 2705371503, 1938573921, 477758415, 1549522152, 765579725,
-                2834539315, 764689262, 2231593940, 32481982, 1823402383, 2406681016, 2110689593,
-                3476006873, 1509022960, 1367856538, 456067479,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                1998180500, 1820237559, 2357594272, 304169246, 230662339, 2105780956, 170603247,
-                2264761912, 3255724187, 2074192026, 298808023, 2405057056, 707140849, 1444566096,
-                935714697, 4251331999, 4095655763, 3768208092, 3192706586, 1119558069, 4009213119,
-                1724841249, 339377570, 3483721383, 2377885681, 3700551932, 1380439148, 1601420164,
-                2148521587, 3863012688, 3169252258, 2446874540,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3614910295, 2659789618, 4055306284, 3271258386, 2321388362, 1507920009, 3493205567,
-                3638980877, 3311107443, 497161729, 977721656, 1136564062, 3035080626, 1585790021,
-                3311270465, 2554448046, 4241857826, 308734039, 3573673811, 2494877757, 187231501,
-                1374882584, 3723239342, 996388763, 737025449, 2638603669, 1120435001, 1102955319,
-                4084913047, 3563314614, 3321068214, 3181630820,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                3222118038, 2078349294, 3225438595, 147971061, 3965646809, 2675860502, 2966522649,
-                2698479298, 1765758049, 68867161, 335607240, 3347994606, 1401831717, 3401099458,
-                1390208076, 3189296973, 3958686668, 1194285834, 3921533605, 4211162724, 1246757366,
-                2069709397, 2207937778, 3888012759, 2158256905, 3311358072, 1693945309, 2041243861,
-                1482822472, 2741685256, 992073791, 2229952248,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                3427924647, 1617034745, 3265765517, 3291325784, 3518228132, 322101038, 1251615374,
-                379402344, 1810410460, 464746918, 4269915495, 808640112, 1643087045, 3648216388,
-                1138382337, 353769168, 4265726082, 1953617484, 474179784, 1097631834, 4217617306,
-                386434869, 28254167, 3484835070, 657678747, 1845578509, 1280124212, 117342655,
-                1227493950, 4221092951, 1404121651, 2312426693,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                4044247974, 1102590530, 2172566368, 512617543, 1241487232, 741168016, 2463666836,
-                3526590857, 4223056150, 998354660, 3573704686, 801055914, 3448327901, 2779447178,
-                1590950410, 3374624463, 2823648744, 3455929257, 2173865554, 1996991878, 2987912404,
-                3570379263, 3051606255, 3251327813, 1066568693, 3992576185, 1017741415, 3901646098,
-                851562568, 3515448935, 1063887205, 3516312973,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                3118049639, 1881810571, 3947358131, 1760974432, 2977604647, 1181600965, 14958726,
-                2848527816, 4059545067, 3827831735, 2945735172, 3741128288, 2659027792, 2592049767,
-                913579241, 2069532966, 1348374784, 1352044055, 4133459578, 3957758088, 1433714757,
-                4225236362, 1939427861, 193483935, 3974643602, 1337429185, 1172221754, 3295642770,
-                2429746177, 1335932465, 4109412859, 1051814257,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1869269885, 2648460877, 3964676130, 1383952966, 1263974275, 2283693551, 4011179118,
-                2874719381, 1405409488, 2552914571, 3980872998, 2080214816, 119655038, 3766534212,
-                435674690, 3338286286, 3990688152, 3656242470, 2859861862, 4250998514, 2452328552,
-                451005081, 2090483248, 1691587530, 13910245, 3654914263, 2702816124, 342244246,
-                1241698783, 1887603161, 1956630168, 2180055839,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                2075467122, 3952595884, 176035235, 1374224617, 3213243618, 1681104422, 3666472754,
-                3986294314, 464740789, 635234731, 1471183070, 2416623617, 3520853145, 378332445,
-                2048930218, 1197661295, 1138676004, 1262722069, 1050846367, 2076228649, 2411043993,
-                2036745021, 3113818020, 3218390903, 4050661869, 2010032831, 2410487790, 943502686,
-                290914824, 378599331, 2651045870, 3135440517,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1960801925, 648057015, 1981809566, 386527450, 2018764289, 853492290, 1829130776,
-                444907876, 3846914333, 1547208908, 2795203104, 263865580, 1041985913, 3370995850,
-                2816196592, 3980648891, 1047542990, 1979631954, 3166666254, 424032761, 858567735,
-                3076263190, 2483165260, 4109472294, 1407951619, 1029228668, 3607307028, 967079679,
-                3633226391, 2975779188, 519298189, 3156316845,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                989161721, 4021823833, 154585240, 218308001, 535738982, 3590515592, 4146236606,
-                2872940331, 960373011, 3279509226, 2219150117, 1444700140, 835082276, 1001838711,
-                371619775, 1832165221, 960671492, 3008489849, 2574973744, 1149811262, 4219470553,
-                4099745370, 351352466, 137841296, 396263280, 1623108494, 2110825941, 3301874644,
-                2830684645, 575070751, 1308627286, 282267233,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                4132444280, 3820971135, 3707955636, 684336883, 3405186461, 3826650745, 1360011017,
-                4218601713, 103592378, 3596304257, 2482854619, 2634961295, 2235826198, 3522228835,
-                238475324, 2025198440, 1730024692, 3151796960, 2185595637, 3531494443, 3441300930,
-                1920401904, 2179014172, 1744482031, 3167718555, 1019055339, 1151958411, 3790641183,
-                206778923, 29203346, 3349820892, 2043025912,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1942815175, 3035564060, 495407896, 3011104646, 2484746553, 1438287688, 3601119890,
-                4009345856, 1516228669, 2077980305, 2260354170, 11007324, 1415870393, 3572217994,
-                836644547, 424449967, 147066793, 4230705066, 149733448, 1716669903, 294389817,
-                1447267750, 419604893, 3102927757, 3922256746, 109296050, 3553005846, 3302393316,
-                708130334, 1217235737, 1449234873, 3845514372,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                1200481455, 2201725671, 108144233, 2533801192, 633200271, 3588451991, 2565172036,
-                988965504, 4145691776, 3737304019, 736761828, 2258527759, 216582360, 1299527423,
-                676907396, 2692093564, 3950673751, 3920791330, 921155989, 853261183, 1844726250,
-                4146558918, 3966977753, 2759933183, 598119066, 1700830906, 913993246, 3460182330,
-                1588817417, 945410851, 1828808758, 2610966047,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                391827669, 2050822930, 99494737, 3988861079, 4153105198, 311314411, 1761554303,
-                3999105071, 4094021051, 2256120436, 3820233822, 1627071823, 612177044, 336543088,
-                1939074801, 3497464603, 1901160944, 2416165053, 3756635098, 3633906508, 1207466938,
-                52968008, 1206635770, 4131925190, 3460294397, 3127728533, 226920690, 3995841339,
-                3620258984, 1157450680, 3583143369, 824963227,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                574642221, 3003163642, 1396841619, 1377270735, 2188706705, 2321873971, 2028210761,
-                1904321734, 2522467897, 2819516555, 565064166, 4149443659, 1504699025, 1669328551,
-                1301177042, 2270858867, 2760951180, 243276083, 2233635719, 3168283422, 3792529855,
-                1613942074, 1058918403, 1572852379, 2426904010, 3843796707, 3802750663, 518263658,
-                2955816638, 2172903167, 4132326304, 4230601344,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3958634059, 2330468638, 79353350, 1381898601, 3617080240, 831203266, 1526088182,
-                1164108317, 1847843785, 1594681255, 2181330987, 1317474602, 3852543224, 3862124684,
-                1076872932, 1889527739, 2404329565, 2416118170, 3567298172, 1222744211, 3979706387,
-                1244757774, 29283864, 618392400, 1016797412, 3042009987, 3120923027, 2891740271,
-                2853786628, 4187443432, 3527633163, 3594243268,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                3718394946, 2811070104, 4140028630, 1238184510, 3552810802, 3033855195, 3231484481,
-                643915748, 3032465721, 3637230904, 4149208483, 3498156726, 3814480420, 2806657766,
-                1973198596, 1763694481, 2007894869, 2023439794, 3903021540, 2117426849, 4009914201,
-                1885948413, 2321654458, 1685818423, 2195179222, 1161861935, 3460475248, 536482410,
-                2964574825, 1542501878, 648263033, 637365127,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                3133671282, 3115593294, 189787391, 3179665650, 2557686629, 2698340838, 870108070,
-                586343609, 3592786414, 2955932041, 2764976009, 901158453, 2900188018, 3409111151,
-                898504333, 3905964382, 1102958341, 479353909, 2045543761, 4041311730, 1167717190,
-                253081190, 766735769, 3332726660, 3609436017, 832930474, 3326471845, 4027914083,
-                2612170785, 414676551, 1920801114, 3847135366,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                341121819, 4161399391, 6482626, 151484689, 856970140, 3232716344, 426838872,
-                2724670054, 3503678091, 3057569986, 3609882193, 957383810, 1893909236, 950422605,
-                1237133383, 3975438179, 3448448990, 1775173077, 3733632713, 3762290271, 4116880133,
-                2150795728, 1878663167, 3338323047, 43658680, 15594012, 825042403, 2349083560,
-                3755881322, 768870323, 102905008, 3150389461,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2333447343, 4005876411, 2408154474, 488785874, 1956125182, 3197816496, 1591261029,
-                1413294829, 1536494557, 3962585271, 3110022688, 3803545906, 4114024827, 3590531172,
-                1564835538, 1765773053, 733631446, 1374573247, 2006280616, 2588411574, 1639238521,
-                339109886, 3670997781, 2286272832, 3019008591, 211294006, 2383438830, 1159074422,
-                2466761665, 2359572312, 2903798837, 3482015020,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2813878865, 3789457578, 3631085774, 1562963996, 933961537, 1851672542, 3250186385,
-                2522679629, 2601609640, 644420445, 3079237803, 3335615874, 620291101, 340714153,
-                3782868016, 1541063564, 2796033024, 3460773200, 1210578757, 3950409699, 3371330055,
-                4222544997, 2760603450, 3506973470, 618982433, 232653461, 3181494545, 1019387588,
-                869490984, 1381793490, 1255346369, 179808775,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                363316572, 1723426850, 1770301320, 693288620, 2559379482, 1397035996, 137407855,
-                4255268273, 131091499, 1655461067, 189203173, 3260106652, 1127185520, 205151666,
-                4007502879, 2108253984, 397235462, 63872899, 2389047087, 2692006961, 2729088610,
-                506494901, 1537157192, 4048632653, 648010064, 1857971636, 2076760053, 2287816479,
-                2492069123, 482558179, 427828318, 2437351744,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                4050861346, 4111913614, 2435100667, 2923942305, 1996646117, 444674991, 2229256180,
-                2374465716, 525017286, 1839179333, 2876275335, 858932022, 4037932173, 2321755228,
-                4163551765, 2909641185, 4184119505, 3954681133, 2717360980, 894329437, 291118991,
-                720619511, 383581043, 1394051779, 3207380122, 3751957025, 3983632320, 2576230957,
-                843279059, 2935008377, 1196854135, 2324347504,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                2528213671, 1281838098, 2596893300, 800790223, 1832887222, 3570063100, 1431793159,
-                623339578, 2222457069, 3896853781, 2636891311, 3785033894, 2226224430, 421734437,
-                3606657002, 711200601, 2080495157, 3235172557, 1107790127, 749502762, 3435320728,
-                2479057130, 1113252424, 4043940566, 3384979393, 2816804054, 968560577, 86041305,
-                898447349, 818823363, 240175245, 1175942404,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1961415788, 2566103942, 2786437153, 361703793, 3985925359, 1929136495, 4279899622,
-                3518208555, 3860788394, 199014038, 1607407951, 180479447, 3145244100, 664030217,
-                1222006431, 3359907320, 1927763401, 2594463597, 1230810350, 2455566027, 1314239110,
-                3706211239, 864922779, 1345192182, 2825433763, 1804027987, 3194151711, 3992147097,
-                423807856, 2242853812, 4288659323, 2655389489,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1993603212, 481314818, 4043870022, 908456667, 912464384, 1116372161, 1543251151,
-                3031304982, 1269911640, 139626626, 3814053355, 741001302, 681516451, 3714364587,
-                1003661266, 1277173408, 2058677310, 1727457831, 3335932470, 2674898607, 750966387,
-                1596047076, 1784307486, 2814756562, 1531038152, 3297126993, 1142906024, 561421447,
-                4192515094, 3143740095, 1049552559, 68573342,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3918817540, 644385876, 1549900655, 1833792092, 1018004075, 1943727693, 1121573772,
-                3230123667, 1808776211, 2854207342, 25286677, 2397390282, 804645901, 4022113470,
-                1628688382, 3906969196, 1944070099, 2009651259, 3825681464, 1210614299, 2595117546,
-                805454330, 2980016927, 154456541, 1070848381, 3580672213, 3632755923, 3750087867,
-                3924908208, 1475076757, 1189129820, 1509533314,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1416200860, 1320539442, 199586856, 4053979540, 64492159, 1500550313, 608097982,
-                3925451228, 3824038356, 3413119082, 3550246244, 2470333038, 1015270434, 1192494502,
-                2902149658, 851959539, 3240764800, 3116578034, 3568904665, 701814452, 1479713768,
-                1310177405, 1019505042, 2949024733, 2966873155, 3342974686, 2164295515, 1966074299,
-                1271156385, 3239667764, 3323758812, 1044894908,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1064934333, 3543654689, 3564479135, 2539108340, 2387769471, 3557568790, 4068776192,
-                4202220477, 3360535495, 4192547561, 3594592041, 206953191, 1912364877, 1453924464,
-                2276595930, 1640144111, 2738047064, 3930113339, 1219771520, 2308835920, 4289521759,
-                3864776050, 1911680683, 4145924232, 1901869703, 3637050324, 3310495207, 2388851897,
-                680779213, 3667023649, 2157309603, 1200018672,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1612401925, 1539811392, 576332689, 335514910, 826766802, 1250593913, 2596101607,
-                384748980, 2230232078, 3016873065, 96169867, 1064099804, 2792880818, 3814477953,
-                2266182129, 1654293908, 472088767, 4289732169, 4169154398, 1535313651, 1559592428,
-                3429069012, 3359779247, 2260718136, 2717146042, 1327182558, 971712538, 2243259916,
-                2879352521, 2483342380, 1930381481, 3784060944,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                840521051, 1551167410, 1648325766, 3870606675, 2617808049, 1879719709, 1926223374,
-                1557264792, 4186091507, 3396236954, 252614795, 3379871023, 3893998533, 1423936493,
-                1443714263, 1112001132, 3420717713, 1523177080, 656100053, 2882276036, 2356009057,
-                1815663338, 1848480582, 737988413, 832517759, 929804853, 453784586, 2170776050,
-                1723178458, 3745970581, 700307113, 287462880,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                874758890, 2377395588, 381730351, 451896554, 2046578498, 1031535259, 477868765,
-                282379724, 3818188729, 3644441326, 3154804624, 1837466923, 2361329845, 252028786,
-                2430012620, 1400143552, 1055675983, 2503648499, 2247743324, 2282333227, 3901725866,
-                703969540, 1213617743, 698221391, 1921444262, 2717910266, 3669221952, 1293909224,
-                2955632425, 2490000359, 2318171761, 1421278879,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                86799187, 2699489110, 2027461252, 955349006, 3377467656, 805222179, 2061943055,
-                3463437473, 3609736225, 2029449792, 1944816994, 1535158110, 1042453389, 735142503,
-                1034464599, 2546583757, 3671401916, 648782993, 1295021239, 1188043791, 264629298,
-                2004982801, 3760849622, 2958729614, 44226848, 2413796641, 2872114895, 3075638572,
-                4116404664, 3514443928, 3367638524, 3999250546,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                38438809, 2255194047, 405651569, 355655601, 1230534372, 3249542502, 2511848777,
-                3188474131, 3904090131, 1308427093, 2960937437, 950782439, 3876378276, 3951923918,
-                3835484287, 1079610489, 1737665029, 1659970798, 1779228498, 3541060234, 2438810438,
-                2495129140, 1348252216, 920465203, 1831881366, 337594833, 4225405488, 366442158,
-                99677275, 1361438526, 1650193130, 4068002425,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                217825932, 667686482, 2766226510, 624285532, 2613043554, 2898702164, 3069415152,
-                1053215261, 3737744365, 489856615, 2634222136, 3370912243, 1571640438, 1713114146,
-                1032785631, 732419132, 3765928574, 1428390018, 1795711235, 346233083, 432545062,
-                1281648646, 1830773481, 800109286, 4179274342, 2620274387, 765949523, 2348459846,
-                1011621802, 388060950, 3255332790, 2757822235,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                278508010, 354727459, 1648514563, 4156095174, 1295155291, 1762466787, 3740388181,
-                549358533, 1198132797, 720142333, 1439429558, 3696385861, 3869518861, 1814508030,
-                1719906478, 2354706653, 1174394913, 1829119608, 1934875217, 1067239517, 3821452699,
-                234229367, 3452061015, 3219748086, 3688298310, 4127739212, 3451569519, 2349070740,
-                1712730976, 1930956616, 70321876, 2102798746,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2328100007, 1588330008, 896433556, 14952991, 267992496, 1148097510, 3930668703,
-                2064945262, 591259232, 3951658073, 3624385627, 1933751386, 2745143931, 3186764752,
-                2993224789, 834537465, 344133207, 1806051087, 3266370623, 1350026449, 2516905971,
-                2382321895, 4112971427, 2989655059, 499569633, 1875620912, 2246319607, 1712144983,
-                2332904042, 298872772, 1801572147, 2166656201,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1256985966, 1200942501, 123221160, 3708288797, 1953562103, 1227326435, 1403802555,
-                2292554703, 1034433063, 2088654386, 3895402456, 3032088636, 3447456171, 1350967845,
-                1776590248, 3607239894, 2223181573, 3535426227, 628322273, 829855303, 905209013,
-                3049158288, 1569126290, 2572497771, 3925869160, 2675753455, 3791515929, 3317677776,
-                1625624276, 3396796998, 532679829, 2713890843,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3766973263, 4174817372, 4211065118, 3020890466, 1280024858, 3013491787, 536077482,
-                3405003488, 3915751432, 2090220119, 871312725, 2523019923, 1979696320, 1698763291,
-                3756288463, 897982624, 1571005251, 163837868, 2130438323, 1110416998, 526917892,
-                3056091093, 1461986498, 2128167237, 2106449435, 1604533476, 3138865279, 713135373,
-                2675883732, 73381070, 605886707, 2172679100,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                2624464618, 3743809424, 3758939536, 3390111201, 1840406469, 2315470194, 4045961521,
-                3429582172, 148625378, 1722430259, 2299073254, 1316966096, 1347268657, 1527804385,
-                2638096549, 3919996881, 4169746898, 3720343856, 2448715852, 1034710846, 1017918083,
-                1547427124, 3524185327, 3483038653, 689886000, 1007712520, 578718248, 2869433129,
-                1187132941, 3742435966, 2982326778, 3508838591,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1689929366, 777403551, 3004811428, 2486491193, 999240576, 2449375337, 202796989,
-                3021485866, 2974467960, 816543136, 513967688, 137304013, 2893536478, 1700971911,
-                1575898745, 2404420536, 1158916030, 1289453700, 571493937, 1531451367, 762162504,
-                106987769, 2029083651, 761749707, 836495615, 1417729224, 491126918, 4004189952,
-                564032055, 4087953334, 1427414010, 3870781275,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                4047040260, 2829131945, 940890483, 1511613178, 269779047, 3722339427, 2447790801,
-                4202265508, 1623627880, 1200668348, 2953086792, 2311591357, 3644835129, 681520351,
-                3259656094, 4266401942, 2428533069, 4146126877, 1769666610, 3870211909, 2225780539,
-                3147195395, 2695064586, 608888687, 1646772867, 3950255150, 487127296, 2821167734,
-                360469119, 1770417428, 3700194704, 3633571133,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1238707698, 3530735686, 2554285191, 1347158546, 2278739247, 1358957921, 1793925440,
-                1143188070, 337362194, 1138594277, 2297051443, 543546553, 4063534856, 1733709067,
-                376156564, 2853203289, 2703478685, 1992482672, 1676645735, 3104918579, 3059770515,
-                3885373677, 2701811605, 1050549660, 1781861916, 2178622831, 949525350, 1358327977,
-                1183235450, 3975688570, 1169621150, 709624318,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                177891055, 1279213497, 375004470, 4134452767, 3569361616, 2796983485, 1382338089,
-                3425456459, 3235730940, 2640477217, 2710492414, 4161593012, 2421610887, 586178439,
-                638644448, 944480444, 436039373, 2950147306, 1015619787, 3873888673, 2501789644,
-                5348209, 1099503372, 2377736072, 477792633, 1033694522, 3714144640, 4262796534,
-                2858731035, 1973984344, 4255504182, 1989892649,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3370397720, 2430992785, 3958756407, 978712789, 2760940949, 1557022892, 1899447691,
-                1366535854, 473191312, 3883736946, 1996247284, 3606667549, 1726030912, 2688820783,
-                2722970060, 1034767812, 3813502402, 1499316673, 842887635, 2155415513, 1902969186,
-                3397918252, 432413161, 499799622, 2631739080, 386898004, 3649165993, 30119694,
-                800536112, 4164926885, 298884651, 2295119421,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1457595393, 3477407565, 1156254669, 1312929354, 4115367671, 2673495960, 2426344578,
-                4060487169, 2604664415, 1434864401, 465061138, 956950809, 1592065928, 3294768763,
-                1950573486, 1688126194, 773835565, 323913704, 1493276533, 1572420574, 2912428441,
-                744284193, 1746457553, 687113422, 3519606549, 3403805953, 1841386671, 3437060114,
-                4177406736, 1745416714, 748836264, 2684517307,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2743153509, 1147434830, 3667957624, 2891459103, 887674624, 3849580163, 2457879657,
-                1256116294, 1632844841, 1308951074, 2934176800, 2456393119, 4050377331, 4085327510,
-                1034887443, 2931085804, 2121200260, 3494563271, 1764315515, 3975245354, 1211790407,
-                1085127666, 3699142852, 4128825186, 1352490036, 2756072605, 3296438908, 2065091714,
-                1050088623, 2824038597, 3410756336, 1016165140,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1077390363, 3033444099, 3598898896, 2094361443, 148215579, 4010079309, 2701654311,
-                2993210444, 4131010988, 2137100223, 3876624663, 2271355074, 3333585589, 3851838515,
-                2764537507, 188712768, 4046009635, 3578902943, 858853306, 2070373985, 33319334,
-                3664389801, 554654761, 769027530, 2484307798, 347159574, 2073006313, 3415134371,
-                167888855, 3037875938, 130518704, 134270737,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3304079610, 2080831041, 2360095518, 2887628549, 2422343619, 1432330701, 693736084,
-                445177376, 3750621008, 2174525996, 1975539013, 1552050207, 4268614352, 1101630905,
-                190952633, 4049288510, 916678182, 2708958130, 129660635, 2334036858, 61794436,
-                1867059094, 2819273863, 2103871114, 2552730929, 1275809627, 825373613, 1224753329,
-                4230161659, 1215752982, 66931708, 337028217,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                3290543780, 822079595, 3242085223, 1912480387, 705767559, 932186136, 1675605872,
-                4252092550, 966919500, 1868447812, 3624205360, 2342332479, 1323110260, 3906871459,
-                4062638863, 2051549021, 3494101354, 379843962, 4149256290, 3506554693, 1504204806,
-                584166932, 23695921, 440846924, 2865439574, 1098839464, 1855760529, 2436976160,
-                318002181, 3588691888, 808709726, 3832457243,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3424568447, 89952510, 59443109, 830336191, 3729113088, 2114505763, 2000667517,
-                287097581, 1694872560, 1255629065, 3387411700, 1530737714, 200255508, 3145289992,
-                919915553, 1807618215, 3056753192, 3081792675, 3148426479, 3396294083, 824435239,
-                1163288713, 2991789586, 2540078224, 3228529667, 421782286, 3086768490, 1041323155,
-                2918425335, 19001349, 2890030205, 1503594791,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                1998234202, 4067279438, 3856814482, 1873158374, 3396700219, 2958808238, 2463979556,
-                2109498405, 1206223874, 201716206, 810591022, 1117958925, 1822717363, 90283557,
-                1194114462, 3964091252, 1943098473, 3058934728, 1790592893, 3926839942, 2263125285,
-                2222696945, 3543166186, 2783502938, 260590413, 1702757795, 1949777522, 3980997675,
-                3309524820, 844318104, 2486601513, 2730344744,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                111278922, 3535109042, 550269480, 682738540, 2086739741, 2357077116, 3384611808,
-                77409047, 1045609236, 1989318401, 2618257186, 364937092, 2027903324, 2927088913,
-                658195312, 1008995733, 2960410442, 1090703929, 2116699858, 1432376501, 535524234,
-                2350179708, 2974507964, 3381890603, 3618475754, 961669803, 399904228, 3000230514,
-                1912342470, 3431914140, 722746248, 3605132632,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                201766740, 4100442913, 1787668111, 1433102925, 398293321, 789009921, 1985467510,
-                625711905, 1010895969, 1993375918, 1312177210, 939432069, 402603707, 3531752615,
-                3814128426, 3571578740, 4029606106, 2729005350, 1902228935, 2040255057, 3612655347,
-                3585388201, 2510390644, 463653515, 4101780180, 1504393783, 3520419028, 1612499888,
-                2434283096, 1298692481, 3460401607, 2111876706,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3710052648, 3261073075, 4005585511, 2047173426, 2012231713, 4129007884, 3049503430,
-                791468293, 2721963115, 118476527, 3008961328, 2818457202, 3612039117, 1044420428,
-                2489543524, 3083674229, 238762024, 998292550, 2897340150, 3646396866, 907003282,
-                3851818247, 3958921092, 1366921053, 3506976281, 377661930, 491638512, 1281466000,
-                1599128300, 76149669, 1394549831, 1597748411,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                4288999981, 527427961, 2223582258, 2066060878, 2560772166, 2398374123, 2472003713,
-                1220878750, 474789642, 1901108293, 2232490767, 2137491678, 3262649104, 1592691098,
-                1526373972, 1577775224, 3681057916, 2704196080, 1058111174, 2868672439, 3987137783,
-                502084845, 53686658, 472069860, 1297510578, 1670742310, 2862610132, 114738639,
-                979062914, 1742664330, 2770367683, 1811234624,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2095021377, 2511453233, 64124248, 521210941, 50318127, 190585181, 798708105,
-                1798712981, 1008220789, 2936732760, 3008610556, 2418789001, 2146669081, 2234621293,
-                2092904584, 1453551170, 1804892714, 1352934096, 632230890, 4209309598, 1083287483,
-                1376335572, 2079568512, 2000675346, 1546314948, 1199835724, 3823743352, 987316250,
-                4245750991, 230443512, 87091210, 684569026,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                167626144, 3849823923, 715044721, 1893732633, 1032427127, 3678880632, 1939560763,
-                3730646745, 1391362777, 3438181825, 3990601386, 2554319929, 3605495993, 961218343,
-                2137769729, 291391546, 342836308, 3141535379, 2228620639, 4250704488, 4104959939,
-                2615791395, 756921898, 3548421273, 4021559532, 4114451394, 4121056820, 1997367996,
-                8616603, 3899130598, 535871912, 2566486532,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1291956576, 1473972617, 871623058, 4141946587, 2087951022, 544137239, 3493267643,
-                3995277668, 1994291225, 565253223, 1792833383, 902125986, 2767358388, 88050528,
-                2300866569, 3090527319, 1838699028, 1591039635, 1380279573, 2735927057, 543496185,
-                3251223342, 3774371825, 585608390, 2615072056, 3216326016, 206188680, 1193630395,
-                518266890, 3107418952, 4257913456, 2325860582,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                31639632, 1062110251, 172442244, 764477136, 2170203487, 1120840659, 1791434470,
-                406534082, 2941827777, 564762468, 3849255274, 294353046, 1823236190, 3788457637,
-                921939198, 2399299093, 1990060140, 2962132532, 2072479740, 448290079, 1281116238,
-                1339921638, 2746558965, 2399019937, 2089902370, 3197969505, 2148413031, 1000050949,
-                3002566166, 1108449551, 3283075538, 2453967743,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                1541801257, 1265610915, 4179855903, 2808057821, 525165167, 3965247866, 2201549520,
-                3070970761, 2565330266, 2517425765, 574478750, 2903404679, 1527072997, 3900607326,
-                1892371689, 720474049, 3776687192, 318383693, 1211232929, 1065336916, 478340274,
-                2295155399, 1933348374, 22418339, 707100971, 656870133, 1069092041, 2726327898,
-                1945366709, 3627003734, 3981079668, 3148149632,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2659675581, 1857627594, 3784895742, 2844751312, 2317681712, 4108866852, 1104405464,
-                2061148958, 509246469, 531064286, 3695759203, 102825108, 1029715808, 2943174600,
-                953255518, 3436022428, 3041123895, 3948103938, 3758184367, 3329897353, 3930577019,
-                2265968821, 3709950287, 2951420759, 660752801, 1750706757, 3677374257, 2661619456,
-                2061657885, 1574252183, 3780087235, 549328947,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1573496853, 302866750, 2011847968, 739146542, 3228997701, 2549621508, 561744142,
-                404877341, 3397856649, 3806193386, 1154216638, 375411211, 254061645, 53958508,
-                794188854, 1731990471, 3147765111, 1572820001, 1942878967, 3417853907, 8282128,
-                3563742523, 773413936, 449461730, 3609954264, 2384200192, 1210760641, 2996377384,
-                807178516, 628579469, 2909407528, 2186633351,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                242254828, 1836753096, 1299705426, 1699452818, 2501255785, 4227336732, 3499723810,
-                2993592228, 3955492203, 4086495386, 2505932568, 2954845644, 3112923911, 1747803987,
-                1591421120, 390974572, 1366585366, 862839275, 445061601, 2103623414, 4198373547,
-                3603607919, 2117280390, 3429927693, 3133457956, 1065532644, 3890163071, 3186460764,
-                2132042054, 2904350553, 2561593326, 2004966473,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                2658650620, 1491043020, 2786246584, 2811728101, 1687205430, 3014519724, 1363650714,
-                1889285474, 2421587381, 167180187, 3355958179, 3229513431, 1793666289, 1249188853,
-                538459371, 3141463874, 2026453057, 3517258685, 2743607797, 3818452368, 3313029063,
-                3555013146, 3713736195, 2324563042, 4200124872, 983556770, 1653888234, 2838963489,
-                1431155351, 833034767, 296658370, 1295151804,
-            ],
-            rank: 32,
-        },
-        TestMatrix {
-            matrix: [
-                295538037, 1903567910, 1072663871, 3453478269, 2572537120, 3683742628, 627576123,
-                633279277, 2642168320, 84517802, 3055281586, 3063490228, 4221115110, 4005838590,
-                104868634, 1892823616, 1260926951, 3097250235, 1085991593, 3535465636, 2897596249,
-                295538037, 552354216, 1844837589, 1881082140, 104235305, 3366041798, 943226424,
-                768690805, 2665151762, 2877066320, 1708306867,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1870267971, 2837888492, 2887723660, 2013526844, 2804291307, 2596997454, 3773126756,
-                937165972, 4196867498, 1870267971, 3373407600, 2675709507, 1733053366, 2309540260,
-                1191382072, 2214346860, 39006608, 501234471, 2559339454, 365725600, 1327105380,
-                138271941, 3565280955, 3470168219, 393065551, 2506114266, 1508690442, 2340334728,
-                267683348, 3628345519, 176460299, 1293955092,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1227810073, 3308394148, 2355148283, 3257614682, 2749602755, 1824016803, 781827165,
-                1126871163, 623978208, 203586082, 3479082805, 1459619075, 2953073188, 1742233281,
-                840135453, 1447400718, 1083292530, 873036495, 2449256357, 2339292934, 1701671103,
-                3276428033, 1789484252, 1437762625, 3708870485, 565984184, 3913885077, 454015489,
-                3418107662, 303625998, 2413841747, 1227810073,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3014316285, 3145549983, 3214963693, 4225657872, 1448412170, 2401090934, 368008580,
-                40254333, 558548063, 3995286, 1114077138, 1675787411, 902976808, 2787546222,
-                3102132346, 1536740685, 820385987, 2112519305, 1527024066, 2817638928, 86418456,
-                885218680, 2375887003, 3048065277, 4290229418, 3014316285, 1168830313, 2953537193,
-                3591509786, 4108020333, 1575558299, 1836569136,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3734878904, 2155772331, 2136065784, 465735167, 1072404838, 2562353194, 1771945973,
-                821877369, 2887271717, 952202831, 3975991234, 2544953813, 4181212417, 620059401,
-                4023218576, 987839991, 2762340832, 2164933776, 1584812027, 3136881906, 1714732295,
-                1223213021, 1715441247, 1732841889, 2596071810, 756529020, 1996572876, 3866416466,
-                2968621909, 4140435171, 2993153678, 3734878904,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1326376149, 616561634, 219738280, 2848192455, 1516635544, 1869637032, 3081424698,
-                741497336, 3062548744, 3657411891, 997716984, 786719961, 2074289677, 2532949132,
-                3786125385, 2959251998, 3265900911, 1326376149, 310417546, 836225551, 1410074772,
-                310848779, 2209546751, 2948720486, 2612108194, 3327973621, 1085233470, 4045947121,
-                3080157827, 2834103726, 2101523161, 1165699447,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2277897893, 3278826462, 3578008880, 2771779990, 3856009872, 2438826841, 3840351031,
-                1292030301, 50479311, 1913858577, 4268692505, 2123371070, 1056655193, 3433746142,
-                931990467, 2274171168, 296589786, 3705682508, 2277897893, 1236304221, 666743865,
-                2902929641, 869978393, 877568087, 2034553431, 3251999767, 4217997187, 4194454302,
-                2401222523, 2263976426, 1023402335, 2692956875,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                149953966, 1901769467, 5967763, 4100043021, 3664760167, 1656165024, 1075741931,
-                206548723, 2201717921, 4194524525, 1078075076, 1613945942, 628147488, 3410780631,
-                2636524474, 3778609711, 3473444280, 59633467, 3632302479, 3642104351, 149953966,
-                3033284982, 168379614, 3482634716, 1997626412, 625902099, 2379223311, 2716113508,
-                3386073089, 2235682538, 1448656519, 1142543104,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2595734628, 1896635194, 753243044, 2959245712, 2254509203, 3320618021, 3582983394,
-                770956286, 1801610437, 3232207256, 1617310192, 3175687268, 1366331822, 4165785724,
-                2340353159, 2644415632, 2959083888, 96657514, 1181733126, 516908776, 950414870,
-                155719369, 1624543944, 79312610, 2498342063, 3595586331, 996974054, 138021090,
-                2595734628, 121521384, 1721329658, 3796129354,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                65393813, 3265917921, 2358459305, 3636552679, 3466753423, 2101189080, 910579454,
-                1449193822, 1847997495, 1008974126, 1019178500, 2215279650, 1761640597, 1793603673,
-                653489562, 1501783550, 4270691017, 3205898400, 2780150355, 1655306251, 2245668990,
-                3236773370, 3942074734, 3520587826, 65393813, 1984989081, 3306930636, 3610854305,
-                2513958347, 438205388, 1214449933, 591631431,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1869962033, 927856474, 3007099240, 1703882296, 700741882, 267786549, 2198016339,
-                593851795, 1801765113, 3682138853, 118982534, 234915918, 2264108965, 392507792,
-                859444903, 339466855, 1769645879, 551258869, 3573025224, 3262522597, 2782175187,
-                2035290592, 490787467, 3039097770, 1869962033, 489586711, 1269419285, 646737270,
-                534098506, 4279013425, 1812946932, 1808091290,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3199074350, 2174885653, 1346024021, 1661122686, 3199074350, 1976971942, 248263281,
-                4207757694, 2064320208, 1734105118, 3401286748, 2513263078, 2071535819, 1264057313,
-                3366148380, 1791958778, 814447443, 1070155149, 3293387151, 3589747721, 3918305890,
-                2277088993, 1329124272, 3390322171, 94619979, 19830056, 239672563, 1635634702,
-                3150599773, 3194678817, 2313169148, 482928061,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3286111600, 2538867480, 1417458616, 71826748, 1727477707, 2180617517, 3850556185,
-                1830248019, 615821348, 2457122647, 941977176, 179228413, 3490059037, 3448152068,
-                21602926, 2410834823, 290606774, 3458079954, 3905553771, 3250584137, 3130354475,
-                235942494, 2938692633, 2579756253, 3597119209, 1496555600, 1703027745, 1449755353,
-                1658829458, 2474203121, 3286111600, 2331034427,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1516859361, 3130406399, 2712071345, 1036043048, 799437632, 943445919, 4208253509,
-                2133680237, 459362690, 28030668, 934453004, 950234871, 3065880722, 2176825463,
-                2290230789, 3954966659, 1095930401, 1942689304, 3825998548, 1718703079, 3332689454,
-                376624633, 3731423728, 2371710375, 1516859361, 1031381616, 456593968, 1445438133,
-                3034113570, 260214253, 2544060672, 4106768309,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1507628528, 1024431536, 4277272025, 2092299687, 1616767915, 2241856696, 342606345,
-                952040534, 1940714372, 1507628528, 2554009626, 1722566958, 1476711663, 1647008559,
-                4053608304, 4240316517, 2028223362, 365149017, 2371232257, 4215833606, 3591763589,
-                2669728308, 2661856144, 3095186918, 4200256206, 3112623364, 1251982241, 1432376295,
-                1729852432, 3041098044, 4174055725, 3626214266,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2000913130, 4220316731, 83629197, 2050188480, 40782467, 2855209297, 2000913130,
-                999214251, 79608304, 56586986, 3667469691, 2377016201, 3603706658, 3161674416,
-                260881081, 271652836, 3593382604, 1735396159, 1674257444, 3024188235, 3671084837,
-                2422699786, 3782742774, 1023481068, 76275283, 2210639569, 1611044347, 1475532213,
-                457765952, 867071715, 2458533447, 2121492444,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2033215857, 3507390716, 2942098113, 547802727, 174030736, 3545705406, 344431833,
-                1863614054, 2538138737, 162106654, 694040168, 3609718007, 3532491964, 1918403474,
-                2656160176, 3647648551, 3908034288, 1009407623, 569627177, 1225990255, 2033215857,
-                1931837364, 172852614, 3940937315, 515010116, 3392617410, 3373675163, 1358473078,
-                2194869078, 2814831920, 2443094572, 445545695,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                4183425579, 1488289206, 2471504863, 3696390307, 1919782707, 426762830, 1873064365,
-                1719268666, 317317847, 3382607784, 127918035, 2980069912, 3160937748, 3808441679,
-                202953343, 395783590, 1270979896, 3141934359, 3973851650, 2451291402, 621589122,
-                99077465, 1781092166, 2141097510, 3235177615, 2317501897, 1938492850, 1444473926,
-                4213043051, 2318375203, 4183425579, 1340908821,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                500922990, 809921728, 1798246883, 877736162, 3007616352, 1844535183, 3287269634,
-                1871617650, 3414469574, 2857078836, 130633339, 2305067407, 2523122553, 556287070,
-                4044239140, 3684320753, 500922990, 1377023373, 2932867611, 1054542361, 2952198910,
-                3035102404, 1118444174, 1777239355, 3309339498, 2907421917, 630136850, 733702158,
-                500257284, 3137300904, 3329602827, 2472215433,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                4284463498, 2082296973, 94647112, 4113275901, 314405276, 769436060, 3104727699,
-                195051197, 3919889161, 4203647288, 838912894, 96208598, 1435497192, 2900674620,
-                999752779, 3933495774, 366635429, 2383803568, 1066206461, 3819313770, 2978725432,
-                2801501354, 256016491, 2871911177, 2907019898, 1243975795, 343783754, 193615346,
-                4039365227, 3738719380, 2668186334, 522904466,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2990975250, 317106604, 2724447286, 2060480762, 3218835393, 2164314127, 1396963478,
-                549276538, 1867489390, 3631283618, 2467531, 3835651966, 2990975250, 2486288025,
-                817718079, 3252900186, 2165240486, 3573023046, 3320122690, 1751378424, 3530277557,
-                1825644466, 2118006185, 3023249298, 1644141490, 3003743792, 4143659329, 2566432212,
-                2626963233, 2682262365, 3565539218, 2056519127,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1764610627, 3530693980, 836662608, 4014068776, 1764610627, 109714024, 2623440466,
-                2827221337, 3774772522, 3227159914, 2439722111, 3118604017, 576873511, 3025648543,
-                2429548060, 2777435809, 3388702974, 4110156136, 4189305427, 4052786650, 1350808923,
-                3485178921, 174231483, 1906483983, 394053674, 1364386795, 155411567, 2196876207,
-                1967825712, 2567719409, 651172622, 228128355,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                650180837, 3314102616, 3229097431, 3654855149, 3937508192, 2507316365, 2932864881,
-                3578989217, 158919720, 1323777378, 835144210, 110907040, 3783566612, 3870863550,
-                3483904212, 3214341585, 1565793869, 2742414389, 2765056579, 3470661053, 775747345,
-                575059241, 3902361275, 2640824077, 4056170951, 3918257566, 764739874, 650180837,
-                777057068, 2660107204, 2493260662, 4058579761,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                538219086, 376690843, 3121948441, 212649286, 1489104776, 1913943328, 3302617592,
-                3735390848, 1512263040, 1566182106, 3348348709, 1965556811, 1881515609, 2262485700,
-                965595671, 2980060187, 2797466188, 759479068, 538219086, 4263593618, 1894988016,
-                1195371092, 2445470868, 8356257, 2908941014, 2785177129, 4057002568, 3049463122,
-                1791313869, 3203834827, 155695510, 1332482163,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2773541279, 3575879864, 760463027, 1423457723, 1008761729, 2414887069, 2824550497,
-                3995602000, 3011371585, 3257223284, 1982827436, 2157552622, 4027600492, 3300836165,
-                1318116404, 4086870616, 961358166, 706638827, 2773541279, 1978047259, 2826083320,
-                352120873, 1425491314, 2969459440, 2831111980, 945188337, 2357139850, 4111280300,
-                28255249, 353507745, 2344242031, 2425585780,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1040163283, 3213733211, 2613631829, 2390448243, 1223689940, 3241603722, 7401969,
-                1927693258, 1631101361, 1150735624, 1613146955, 2644625947, 3506660508, 14577876,
-                172296181, 3169263015, 1040163283, 2448251309, 2121483032, 1022808696, 515471579,
-                1775351135, 4248559815, 2879180398, 911897115, 193966039, 3003075167, 730450071,
-                3552969854, 1679751309, 4204353987, 660567402,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                281802616, 1854590410, 4125985278, 445528345, 695920639, 2038807092, 391193663,
-                2661540810, 214117205, 3306945780, 4177201099, 1546942692, 597976425, 1003026319,
-                947527040, 3432472057, 1267328450, 559598077, 4187012227, 432495419, 112012661,
-                1768015136, 2509716755, 3031195900, 2369425189, 2517047211, 1074239565, 3793090267,
-                281802616, 3970174451, 3599876867, 3372487441,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2954597530, 2420711244, 2954597530, 259325527, 1925292007, 2606268301, 2226154039,
-                2013186339, 710706954, 3109750906, 1605322614, 3909948155, 1335746693, 3548176854,
-                1060841617, 2059200614, 3164418847, 2370374802, 1286886798, 4052556384, 171512815,
-                2444538019, 2167711169, 4225694059, 4007408239, 4005032276, 1927468008, 636501340,
-                3330383648, 2518988626, 1091003084, 2619471899,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3424282506, 2256729834, 216849807, 1160990049, 3027401729, 3424282506, 3614612582,
-                4115172625, 2109166210, 1921561507, 3975828358, 3267671895, 3399223025, 2403139761,
-                2931818937, 8213736, 3931004527, 3135574553, 2752130825, 4173264355, 2019951362,
-                3079272296, 405117041, 2469910664, 1102955917, 1396988109, 158970948, 3482622448,
-                3479421588, 3350126801, 2668238470, 1283366681,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1040968921, 2259570974, 2889729650, 1975730473, 310053569, 2579635126, 3051475766,
-                3383555999, 1516773185, 2947335329, 1435225797, 2711708549, 1980880493, 1038099832,
-                3223113668, 1766387030, 1822245118, 4082194921, 1702926558, 1577460726, 4017712471,
-                578332510, 2592282907, 1742222577, 2059344510, 2912250991, 3970120768, 2368832595,
-                843414031, 1040968921, 3891937289, 2695428548,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                767065979, 1404374394, 627762534, 2820434388, 2178647436, 854460564, 1077471571,
-                2814479257, 4040770610, 620317343, 3681407301, 1127212668, 419540288, 370303107,
-                1738342354, 1521253428, 863144165, 608075374, 547016568, 3163217462, 1933265315,
-                3476165967, 3068311754, 148580923, 4067134938, 3186601770, 767065979, 168367605,
-                1855578808, 1481729414, 76097488, 2671319638,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                748367267, 3824048168, 3432210607, 1685656473, 928439130, 1457469181, 1758754498,
-                3034540766, 1432160913, 2061406353, 3091673234, 11236679, 3859987807, 3278014493,
-                626286453, 2890153048, 3871815651, 553375424, 1028417722, 4035647607, 2260472208,
-                358553201, 2950256034, 2901486674, 946733847, 3252602245, 1490174085, 3617884662,
-                2032058801, 3887948870, 748367267, 2542525641,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                339436811, 865013905, 3074818502, 814445691, 3131124640, 4061054917, 2085677183,
-                3286853299, 3018670613, 1389231664, 3585413050, 4203851185, 409734104, 3883377648,
-                877327827, 3073903565, 657574334, 607978930, 2823515700, 1965816569, 287498782,
-                2764898306, 1580037907, 589762894, 1791862989, 2145939811, 1119829480, 3657679285,
-                2129745340, 339436811, 896088114, 2182908169,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3480412754, 238153539, 3674037434, 885165654, 2078242890, 1008521711, 2102657830,
-                1393144627, 3026156747, 1117430563, 769744710, 3210265979, 4294127176, 1251517411,
-                2319054759, 2759782469, 2658822342, 3279388580, 3887331834, 1606657695, 237869822,
-                1553813191, 2218167575, 2187829643, 1134812203, 1070099726, 3618906532, 3480412754,
-                665787106, 377882472, 4289057665, 1181240796,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1088506669, 2579682780, 3624883728, 1752740194, 3442731818, 2492761559, 4087075999,
-                1826408771, 2828196058, 719277938, 4245598860, 371836947, 2061998595, 246612778,
-                2143740978, 2764452375, 3142837923, 1926122954, 3173876019, 1227843242, 3375548174,
-                56123841, 3927441880, 512890594, 1088506669, 2659912772, 2982357043, 4150711293,
-                1508271441, 1851570860, 1671788147, 84678010,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1918740019, 2763908540, 636362140, 3378039608, 2929762391, 3111280109, 3203548310,
-                534571759, 2496975545, 2872187913, 1779534597, 1134600548, 4123055558, 1918740019,
-                2686966249, 2036255334, 3403922700, 3136370230, 4167934415, 1108546541, 646110727,
-                450809052, 2865355948, 1609138064, 2088113457, 1793322196, 1628828484, 3765002933,
-                799774140, 3723046057, 2090002305, 3511409360,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1153768128, 3208614628, 4078224125, 2145170839, 2797525423, 446499316, 1105148724,
-                3421868268, 2528572928, 3177796249, 2867468489, 3010456012, 3824472656, 1107471118,
-                2020002098, 3868068479, 1406079807, 1153768128, 4095791196, 499833381, 1688225467,
-                1759232825, 1593194163, 3362780171, 308707912, 1820174598, 1701573820, 1681201362,
-                2855899882, 3016613620, 56816049, 377775929,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2405676887, 2465382868, 3980376479, 3395376717, 1625833367, 32881958, 2293667542,
-                3125874556, 583200810, 3883107992, 652114252, 1320842200, 2793461210, 3308360094,
-                2333660472, 1625531746, 449073108, 3068158238, 983459265, 4136024460, 1990657260,
-                790203950, 2292492253, 1850628694, 3737730908, 3706072832, 3657893637, 2405676887,
-                2981386300, 35229722, 552070319, 1094885018,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                4045180029, 2388888632, 4178415396, 1800573767, 372789995, 1651889874, 927452979,
-                440293987, 2671819815, 4211074144, 4104726023, 3458164440, 4262836908, 1357002448,
-                4154423158, 2666377199, 737757766, 1126279324, 2946342990, 163112361, 5637984,
-                1102278374, 1239503759, 23318358, 327003952, 898977852, 2079523746, 2688895387,
-                3128453224, 425285865, 4045180029, 2490445017,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2977225050, 1524804059, 912491714, 220810753, 1201321818, 496940856, 4269652152,
-                3690667000, 2387031518, 1440169918, 3345000265, 3797018187, 408807125, 2834945298,
-                940955660, 2415918856, 2240406122, 2846249932, 2977225050, 2695807311, 3233882211,
-                4190330391, 4243027954, 2924007117, 3105030301, 2645940104, 1104686567, 1082344710,
-                2734412582, 3865645430, 4155934140, 3683194819,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                679762547, 1866882010, 1370447646, 4259006229, 4266563597, 57134285, 1611618578,
-                1686227699, 4062687778, 1064152669, 2424896192, 1543911015, 679762547, 1846239482,
-                573035984, 4028621762, 1830630828, 1678665478, 3544165761, 1048252156, 314000123,
-                2096648607, 4066743048, 3406290996, 1276785910, 1353421395, 2005869057, 1562537009,
-                3639152145, 737034607, 3590562624, 2583540880,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1816286788, 3469909312, 2353080373, 3454318481, 2076782120, 1613212950, 2881065910,
-                4278175707, 138313405, 1761343776, 4241598251, 2789034083, 3384722630, 3508993590,
-                2744661215, 2390336768, 2029247952, 1999169612, 2529777516, 1273928310, 2796895261,
-                2381332568, 1816286788, 1982474853, 3461267262, 3301528128, 1762887750, 737274325,
-                1072579904, 4099855996, 352586533, 265174249,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3878144135, 2717635158, 2339603830, 1141947413, 3627881755, 1351042077, 2790186184,
-                702651229, 3633716549, 1746772873, 3539480812, 3531631962, 333944151, 4017177585,
-                3298578001, 2532461768, 1120069717, 2224726965, 1341487783, 1056422713, 479744731,
-                2507041316, 1990915334, 2971843905, 3878144135, 2976535995, 3135872456, 555888520,
-                1143009777, 1093090268, 4102696619, 3463742059,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2495697013, 2321786166, 3468266371, 3624522539, 2788379640, 2731096358, 1629539490,
-                2989949140, 1470926205, 439882752, 3580532688, 975052195, 731297480, 2596353359,
-                1685732278, 1460960053, 2279812572, 2495697013, 809770915, 2194364150, 854645221,
-                1140408044, 948382394, 2644434062, 1077998442, 1655054016, 1365164912, 3068086522,
-                644960530, 2904585393, 1382608743, 2241068757,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                63591058, 2379655437, 3754444407, 4233090326, 1034279636, 4159711635, 1953887490,
-                2776438567, 539543188, 2062331523, 3791554653, 3311491705, 3711634224, 3125168750,
-                2451357535, 994270104, 1571766524, 2802813108, 4212823670, 2794900404, 1889606739,
-                1528260393, 2689734313, 63591058, 2546627255, 2185219132, 2595564701, 3293842528,
-                1361727378, 3718809442, 2425162844, 3176550089,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2716012862, 2071121348, 4098642065, 1855236355, 1134095992, 1286387304, 4204575674,
-                4202753439, 945903963, 2669552486, 1413513248, 2905419708, 1324106820, 3421035920,
-                3821172024, 2473433431, 35733802, 148011206, 3105436508, 986494266, 385778563,
-                1768148485, 1278907391, 1979663035, 3295517737, 2716012862, 4270075318, 2528023963,
-                2528093926, 887948302, 1786387173, 3487647331,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                849482610, 1936738401, 4024262078, 2844146122, 3999417387, 3132351279, 185689632,
-                2584332404, 1321510024, 1207273083, 3911831826, 1946934141, 1919744108, 2717629882,
-                4137949339, 3680289688, 849482610, 978378737, 1537574327, 3215173201, 3855942172,
-                1467897477, 391478222, 4084317181, 1759244541, 4118070737, 3425246790, 63551104,
-                1935676613, 3244342910, 426372450, 3971051594,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2670145074, 3611082909, 135151105, 1075315922, 2262303591, 5932892, 3008871276,
-                3015276180, 668322278, 171521828, 247085760, 766473312, 2139674241, 888836403,
-                1477019532, 1431292363, 2532115211, 2770350554, 2670145074, 4200012363, 1633774308,
-                3125660042, 316158240, 3993077113, 1446070370, 1702960799, 3400650721, 3836561223,
-                3335935165, 1814939315, 359871080, 3858204196,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3709794079, 2778912391, 1572421338, 3894026626, 3451116165, 3597224908, 1773010068,
-                1928238017, 1085968001, 746555953, 1586101512, 1225810149, 3687962077, 2676104637,
-                3436971674, 3406308567, 1101315879, 673913865, 1858378761, 485270890, 3709794079,
-                3531902713, 2899673064, 770539598, 3978396561, 3289243271, 3249277646, 671531699,
-                2843375146, 4105412655, 1348613499, 2466924137,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                262628148, 2351629446, 665095991, 3979054684, 4269971502, 2285915901, 3707159423,
-                2990764199, 3844972233, 2527145341, 2369063833, 1976848531, 461423447, 42152690,
-                1619527789, 1816893466, 2007635495, 1011911839, 2562288129, 910576398, 1073883015,
-                3655557990, 744295648, 1237382524, 1147755613, 3989738293, 1180773438, 262628148,
-                1910858054, 1219458994, 1961129656, 1388708645,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                265480850, 2596399683, 821950611, 3947465689, 2455221534, 1379735367, 3575608271,
-                2991194613, 1304972916, 356761550, 248046064, 1300303176, 3624331822, 2094056875,
-                1238892453, 2049891628, 3709823500, 2793313639, 114994129, 3382159157, 3454040059,
-                71103068, 1041030964, 285281120, 1212968781, 3035725014, 265480850, 71607560,
-                3780400818, 33400090, 2180634852, 2767590068,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                4106524469, 1874898947, 1786175122, 2595305716, 700021080, 397372870, 3806192696,
-                1981427639, 2834646199, 4106524469, 2648525812, 2273830257, 3316389515, 756622394,
-                3497940022, 3200445071, 1763374541, 105411879, 1278988934, 2414830873, 3372188606,
-                2362154119, 997168136, 2219661277, 1483943290, 1201376221, 3892320408, 2011894094,
-                3756047398, 1130440607, 3756139407, 769162877,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1532286562, 3748516217, 592330619, 3015068780, 3266655064, 3771051297, 3566218408,
-                2419489753, 2105274883, 3807510586, 329025356, 779933523, 679012573, 1478309408,
-                3234101654, 4278214479, 2098901804, 3922204096, 2076688348, 4187946901, 2666118671,
-                295283091, 1532286562, 778185216, 558089855, 556416442, 3947026391, 1147267458,
-                1288101780, 2002968125, 3095898805, 2002210094,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3197463780, 3508226408, 1938111211, 2544409947, 2174058880, 2943139691, 1280015172,
-                1489656961, 861803059, 2573615117, 2808890589, 1219647688, 2948621639, 1498279494,
-                1949821375, 51184914, 289934103, 3197463780, 1181643546, 2905522735, 3171356712,
-                2600730946, 3436545458, 2034825821, 3370755502, 2558524067, 2120350025, 1006406460,
-                3371787481, 3262477775, 3965794554, 858681797,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                4199890528, 3271554514, 2935737395, 1063700193, 2783519220, 4039347452, 1779465119,
-                235147292, 79573585, 143899356, 2606559045, 1353510466, 3498175547, 3541611076,
-                4208835208, 4193339149, 1119835513, 4199890528, 2782781046, 3834688103, 603695045,
-                3096638190, 1773299697, 3541258441, 1737158192, 3783371707, 2221552107, 887546691,
-                3162479790, 801139478, 234228448, 2582334604,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1995997479, 2398553907, 1408452889, 2198360061, 2091675508, 1090965908, 3245092454,
-                3952368691, 3967149744, 1027326001, 1849863326, 3206328795, 2896834831, 3760652090,
-                3290099064, 1664634391, 212796865, 2560159467, 1995997479, 1865742314, 3588175703,
-                2213015174, 3005687868, 2613881751, 2392578212, 965351282, 941074505, 2329939304,
-                4131375607, 958752236, 2146090731, 347119010,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3904686225, 1290119442, 3694395265, 3411476500, 8383086, 110007242, 484931616,
-                1130262860, 3053297977, 2579626313, 1140233665, 3457543955, 3516027409, 1617431894,
-                75444046, 2348628010, 272387392, 511803045, 2314390, 3387036025, 3116129849,
-                3161728080, 1949837917, 719874901, 793938309, 253409461, 2991264371, 3849660510,
-                325858476, 1330841768, 1451722869, 3904686225,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2009446292, 127530245, 2790052127, 874737249, 4112748749, 567479679, 1307715384,
-                3170864881, 290918894, 1620526225, 3954959771, 1796415690, 3952686283, 1675276745,
-                3553677750, 4098487488, 2860162676, 3193637997, 1383162735, 2009446292, 490173145,
-                4060662853, 3334128559, 160464606, 1259434881, 2768967393, 2989175172, 593566162,
-                1527524622, 2480377249, 985133784, 270906981,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1439774144, 3037043786, 1016765619, 3206362907, 2913975795, 2594665247, 3623567445,
-                1439774144, 1391230741, 4097921832, 1045978759, 191523587, 3484411098, 3909211954,
-                3750437346, 1498406668, 738316416, 3017684996, 1279870114, 1334610660, 613127975,
-                2018309700, 1267609091, 3157259285, 4202514216, 1625632858, 3677216749, 1672798756,
-                1462717352, 299049153, 3365648260, 2758098461,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                3734612929, 764678866, 3637301324, 2961164015, 54499104, 820609024, 2702871023,
-                3182872333, 605988800, 281043900, 2849845261, 1586814639, 795437089, 3361084645,
-                50896868, 2921069368, 1758353592, 3349360128, 1890457711, 276742854, 1828595641,
-                3734612929, 1806025701, 486650173, 260528390, 3730684186, 1688549254, 2456730156,
-                1967776741, 1292185470, 220902703, 4069088944,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1602021187, 3136932716, 4074223409, 350427900, 1410859170, 3199941627, 3697035077,
-                1574354972, 1564866532, 172669270, 3404651373, 210262647, 1653514414, 1274234850,
-                2378593964, 1948822346, 2504088365, 1046568459, 1506446471, 1581837769, 2068761194,
-                2133873628, 2291564056, 1602021187, 2378650164, 2869198349, 3966551850, 1256765547,
-                980154960, 2771367762, 1041054499, 2841083648,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2908242558, 2018002146, 616832438, 1961344700, 3611994873, 1679453929, 732510941,
-                2355487643, 2333401644, 2908242558, 1573284549, 1687989778, 3069987274, 1629087820,
-                2513578946, 1657575899, 4108781609, 2262512694, 3560894456, 1853100679, 3926063256,
-                2959094110, 81480994, 2320322496, 940802792, 3478103485, 2895663414, 2281232416,
-                2125298147, 1056178187, 1733358517, 2752849951,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3391835918, 3838921964, 1204854007, 537739109, 441480826, 213542415, 2062436448,
-                674380533, 1471145064, 1030589985, 1806566425, 3399654232, 152095065, 1098370231,
-                1077196232, 2251362252, 3970414068, 3891529402, 1025548959, 3354408866, 793204770,
-                3882388712, 224888820, 2764122462, 1545425918, 555516718, 3391835918, 482930862,
-                325793851, 3223308127, 2747478164, 3631358802,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2416853380, 3370149710, 2262659091, 1802988441, 396718620, 3325928039, 3074279162,
-                957448431, 3132745626, 3097156504, 1718491104, 3039989022, 2423246705, 1387932868,
-                2652206083, 3349843930, 4093964000, 1829064632, 374255019, 273570728, 4238851580,
-                3975429057, 3931529091, 3258655155, 1204160329, 3112177121, 1482954804, 2416853380,
-                1483977813, 3969920089, 965273602, 913354120,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1592893433, 2910117992, 4262319078, 1108339079, 946569253, 470924408, 2653969634,
-                1312225982, 1834741667, 1040081965, 960038228, 3203406787, 2820819500, 2986187155,
-                875450284, 2454136661, 4174394041, 1592893433, 3484930721, 2213825050, 1404625934,
-                4138834479, 488475966, 1857156937, 2071235417, 3099589755, 84400503, 2928097321,
-                2187996124, 205537736, 2451187105, 2200796614,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                4190591646, 4190591646, 3948472575, 18856910, 2717853648, 233180643, 1825169607,
-                849340175, 4233094576, 1418438258, 227101351, 1790031036, 3439362768, 2923500529,
-                1470608191, 719505646, 4108272143, 2674326365, 3520713354, 1993519593, 1571706415,
-                337604096, 2760959097, 1944805708, 4280483254, 1902166642, 840207388, 1989884581,
-                1574659996, 2167120633, 2538477397, 3214805805,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                4274067026, 1386014264, 3922542345, 3024468084, 766055783, 2309636356, 2987785506,
-                2124302686, 3926370840, 1542720365, 244600056, 3269972205, 4100430586, 1539260571,
-                892460856, 613953659, 3340082320, 2814014249, 4195835091, 4133477755, 3567298694,
-                4274067026, 2842721733, 2304915332, 3755870405, 3955111457, 3835117036, 3967288144,
-                3486493546, 3366079396, 3973276915, 1397347502,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2821430975, 4169974458, 2301014777, 2470798857, 37482157, 2062452793, 113708370,
-                1136899132, 2850070212, 2613398804, 2921608773, 1865017218, 3171132888, 1995012517,
-                1633498639, 546402500, 3179587980, 1418944657, 857882237, 4158623548, 712444584,
-                1910365674, 1266150653, 99159232, 3842796431, 3930368706, 84966780, 2983967594,
-                2821430975, 292799470, 1205531658, 2485650811,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                109749784, 1921095149, 509396710, 1175341981, 3141569886, 2793633885, 2931573656,
-                812889950, 3554088063, 4001500732, 109749784, 2152918744, 3175808396, 450665571,
-                2507532531, 1563956146, 2471593584, 1366294344, 183455382, 1611383397, 3013360411,
-                1833988606, 1328256278, 2104163427, 3589460065, 1869878624, 1706871425, 3335309265,
-                1035029694, 35444398, 4200600137, 2857410307,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                130917924, 1950554858, 4182049874, 3618047338, 921262404, 1790303902, 3407773900,
-                2937109070, 1960305151, 2060236713, 3775983220, 1910617118, 2302086822, 158493951,
-                367902905, 615489619, 4250223170, 3554640516, 2633985104, 2358950055, 3118898619,
-                1092368486, 3862247236, 3231288307, 2932286320, 915224067, 38837361, 2138781998,
-                130917924, 2405843327, 1880678219, 3472904391,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1069384960, 3801851608, 3135766493, 3726391439, 1990927621, 1069384960, 3942858279,
-                4039804487, 4240576504, 3784432009, 2554039096, 3771918872, 1410526164, 216334656,
-                1329746543, 1173304298, 2160644913, 1198811200, 815462944, 2870111892, 4078027183,
-                1278534122, 2411701893, 3198771136, 1766127349, 114646969, 3737448135, 402135009,
-                3417443996, 81052029, 3241427225, 4160574480,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2732064445, 718662005, 1640896553, 3163699922, 2814810696, 287467009, 4149216921,
-                922422862, 3360267088, 628911559, 1430208320, 1525130280, 1211094788, 2583710380,
-                4045093015, 1508849421, 1998341026, 748303624, 2639582632, 955144380, 2235664135,
-                4070272992, 2931617825, 3683289799, 774329563, 2732064445, 2375420164, 200213761,
-                1488337843, 1829391395, 3370041408, 3872637725,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3580342522, 1083084700, 378947972, 3580342522, 399806415, 391032114, 4215506127,
-                246280026, 2146617206, 1735827509, 1446755832, 295809075, 4274639529, 2015675756,
-                3112883623, 1189147470, 2078772315, 11693490, 4229793591, 2772878800, 4018776467,
-                4127816173, 2917969318, 3459438480, 3141058213, 2909511138, 921287534, 3697007966,
-                2868479944, 2255902315, 1108052885, 4165624829,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2184819541, 305243638, 1334276056, 2184819541, 3114727594, 3882182015, 900540678,
-                3941510695, 4130871252, 4287867312, 3055335353, 644277241, 647490471, 2582362187,
-                1714140561, 2144818501, 1700001013, 2985757569, 1906478979, 2726002705, 3757049105,
-                3966757374, 1517316538, 2021828961, 1286590781, 2929004274, 3266318110, 189969388,
-                1169762435, 565008282, 2548857022, 4244202183,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1816860712, 2752420042, 399062848, 2347256410, 3574643039, 1816860712, 1995564688,
-                3468512217, 2599219814, 857460128, 1926790921, 1992406759, 1900919658, 3901961347,
-                1528834106, 3390141038, 221458657, 2783451551, 2844039333, 819021922, 3033416885,
-                3895757246, 2458899614, 2854235785, 39160822, 1372591065, 3993277857, 1437774454,
-                3577239208, 642301983, 2178797547, 1655042992,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3791703928, 2863434499, 1688111196, 1986298430, 1305677205, 3644438155, 2834176024,
-                657495747, 271880457, 3884972282, 596312144, 2200204248, 663902891, 601298976,
-                3791703928, 49758725, 1100572997, 2889188148, 2826179818, 2583823526, 3960284071,
-                3945672244, 1131679689, 1689939914, 274031845, 2088175564, 1901943023, 1481335928,
-                3569252821, 2181195045, 804399150, 2016692502,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3381277748, 313906483, 878928049, 3381277748, 681791980, 2327312222, 1750184182,
-                3949584329, 152649621, 3709262925, 937530416, 3974002824, 1713549629, 1476927195,
-                3840949590, 2767234139, 168903233, 2500793811, 925083251, 4053771823, 3498195665,
-                4185288168, 1382731234, 1112291620, 1028649110, 2549677200, 489033272, 666888906,
-                1886888267, 1429804376, 3412415020, 159563485,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                831275617, 1190477764, 3209187221, 4158333689, 1165835000, 1808306912, 942676385,
-                883052578, 2746914325, 2358171733, 3292253582, 2300136705, 3548711377, 533204842,
-                2093754809, 2588347818, 685656869, 1673952013, 367126337, 598256156, 970618815,
-                977395439, 1709724210, 950048217, 1427246923, 753160613, 831275617, 86194658,
-                2176078972, 686201183, 1505293515, 2712911014,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1331854661, 3682087938, 1363211910, 4225798762, 2810922355, 3525403432, 3450480244,
-                3891265184, 3767035090, 1331854661, 3536802858, 2823329377, 2976704009, 139439301,
-                2998551113, 2829743536, 2851964717, 1840848494, 1710695929, 1380101030, 1581102806,
-                804065865, 2587745621, 1287889841, 2227045028, 1517866865, 2534672503, 2963434392,
-                2985077519, 1541593209, 347731361, 3270284216,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                884783095, 3620746686, 540099785, 4070403896, 525002326, 1656345315, 1396035462,
-                2757699819, 1374754384, 2652820196, 3021201321, 884783095, 4228923881, 2341930151,
-                3108826165, 876326237, 1927523481, 4047865533, 1523712197, 2320396844, 2727071221,
-                2812364871, 1913797485, 1104229377, 2534178118, 1090823686, 3405996971, 3524813508,
-                1140440201, 694801628, 561607332, 3423935459,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1676672621, 3464697273, 574125132, 2468294513, 4172151618, 2532548775, 3589286876,
-                1676672621, 808309222, 2749339773, 4090358188, 2363826332, 4221549995, 2680981772,
-                737094237, 3868781227, 3110049573, 194354713, 4261752992, 436213397, 232310149,
-                1043508482, 3748541875, 501757216, 3919653753, 2991177924, 3111087147, 4233902259,
-                662816713, 238285029, 902453371, 758550384,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3693509117, 3510767939, 3607764709, 4079275299, 2060152786, 1237503564, 246837629,
-                2183181531, 86093105, 391280031, 111110845, 2103316249, 3989092821, 964471576,
-                2626081592, 1349981705, 1934559786, 2465124852, 3038876233, 2768693257, 615982791,
-                1999599791, 3831231066, 4197385040, 1913880869, 3051571312, 914549657, 3693509117,
-                1885514218, 967844328, 1382283213, 492186209,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2973365992, 2508615176, 3315747955, 945434472, 2832284074, 2874133756, 1435301050,
-                3021660281, 1847158752, 3212261541, 1978455305, 3394766307, 1651554769, 1429540497,
-                1261647468, 2918195896, 2866889091, 750658016, 2549303798, 2205105655, 166030860,
-                1242615028, 3068673546, 3336049054, 1907391473, 2646202719, 2973365992, 3216141331,
-                384207104, 3383408113, 4094328088, 2253072746,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1741036838, 544629226, 3743893717, 3498850979, 3458554981, 183027278, 1013599871,
-                3294629401, 1947998002, 2734897290, 2637403205, 3513830494, 1021964634, 4014618458,
-                3180603978, 2308763639, 2512258307, 629027085, 1741036838, 200219091, 3023801645,
-                4180030314, 4048635901, 2253621685, 3745495915, 2128102694, 494951499, 586915071,
-                1686788054, 4047047736, 4272952274, 1524899643,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3313356400, 3366467034, 3923164593, 2470803993, 916304924, 1095935090, 1730352680,
-                1295446043, 2113903494, 3125729787, 746879446, 1180771654, 3396179420, 379401262,
-                3251365465, 3971170540, 3932145217, 1486092360, 264784224, 1078827088, 3987880275,
-                1723263988, 393349410, 1447916993, 3248993513, 1047531011, 3313356400, 3253355576,
-                295850552, 1595307602, 879355632, 3671667194,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                336764922, 1035680838, 4248157983, 2470931122, 3600383308, 851036729, 2921394315,
-                2363122289, 3893512919, 3927267033, 2718976447, 1246778965, 336764922, 1712602733,
-                1379166373, 4066852169, 3657183317, 1402293172, 1052576686, 70766324, 2133433594,
-                4149702455, 4102554831, 1770350458, 1712357547, 2897549342, 1888058532, 1577213549,
-                956302099, 2957203898, 453590628, 3209794081,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                1393581982, 1393581982, 214413698, 3740262808, 3091500698, 1107748997, 1827551404,
-                2341367144, 1876309862, 329401679, 774955431, 1406108151, 4245375281, 2200247371,
-                1820234409, 2448971869, 2740277844, 2631509419, 2261634682, 1379278080, 3276792937,
-                755772884, 393957791, 1820945761, 1497283846, 523465208, 3398463676, 776817356,
-                3194229997, 4097051082, 1399806454, 4238663729,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3276685619, 3719942547, 1330217656, 4009320278, 1862380556, 603876234, 3249916931,
-                1397822370, 16802424, 3033804957, 1376301726, 3903530945, 3138157880, 3152132992,
-                856816043, 466569543, 1377059379, 2916217140, 2332556251, 301675863, 3387614316,
-                44985649, 854324928, 2250606860, 3276685619, 1866849114, 3015879214, 2748915478,
-                1069910833, 3223498261, 1142080341, 4064172081,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                531373210, 2301731030, 3377858528, 4036137367, 3675073179, 4244744397, 1945835067,
-                1019941595, 1726547703, 2795424578, 1490265514, 922126510, 300773511, 203405312,
-                486100612, 164378418, 202262518, 52639011, 2709526198, 531373210, 3724845824,
-                2586731322, 674197658, 4014446460, 4260298810, 2969635742, 499075452, 1626617769,
-                1112795872, 1288318290, 3406997720, 4086980339,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                556561391, 802915140, 514225319, 556561391, 3451135669, 457035266, 2839012509,
-                608079537, 2209921079, 2220696696, 1612337224, 2235768393, 2536653249, 1024691112,
-                3007614753, 1421004380, 141206211, 2033550059, 2009582852, 520664754, 111615946,
-                4011846108, 2608178888, 894330100, 3874455608, 3790335612, 2766816098, 545959514,
-                2956357822, 2331584559, 1558149067, 4057865704,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                911816277, 2173997158, 4177187921, 3475537162, 132645337, 381556656, 2117994377,
-                2113755018, 2070185120, 3846549103, 909806805, 1607527861, 3109120997, 3389855698,
-                1557576495, 2665415363, 50693379, 911816277, 2183182255, 3707716619, 3135332115,
-                4293162921, 41765941, 2985401025, 3869916412, 1776464278, 1705791716, 4071975249,
-                2996904301, 3008911415, 3091241108, 589721396,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2887586830, 1009845508, 1637223550, 4121490599, 3961127368, 90399553, 3780860257,
-                3689154310, 2238470639, 2887586830, 2617928122, 2988257465, 3168323011, 862924142,
-                692814959, 2900146233, 3372124065, 2965342110, 3099537752, 2015681757, 2843420915,
-                4200513055, 1530450859, 457571930, 3426092352, 977528114, 485822469, 1676934807,
-                4063367436, 2882415496, 1892940957, 2456295421,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2086267050, 606118496, 4195210975, 2910654909, 1450514075, 1820402519, 996328801,
-                1079058230, 383401160, 2789062012, 3872786738, 3452969190, 2086267050, 2474380814,
-                144015684, 136500115, 4181519998, 3078893734, 3652815755, 264797550, 3344326764,
-                376277906, 752841798, 1632280655, 3447911857, 2002091949, 1853209356, 3063178204,
-                3164878530, 2073623032, 1961483780, 124139603,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2389223744, 1802191294, 4129158322, 2185267183, 405862108, 4141629344, 2660124614,
-                819953756, 3690572020, 2823085415, 1938177148, 1432124907, 922884157, 370431093,
-                1506821586, 384456337, 2272651013, 508433626, 3728466941, 3768999345, 3558335199,
-                4278578996, 1346436426, 330214312, 4231393768, 522374816, 4083232324, 2389223744,
-                4067011984, 1914998510, 2899435574, 770133932,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2012529740, 3789917775, 2152963744, 4027110794, 2012529740, 3806657237, 3135680806,
-                4085621829, 3655606527, 2224282527, 3080641971, 4097849542, 2558396556, 2351856903,
-                174504800, 1407922707, 3104996015, 704836580, 3849955137, 3734822182, 3560728958,
-                635815954, 2821896479, 982375154, 1850000952, 1123705776, 471946114, 886146673,
-                1823536962, 3366163666, 1567151246, 3733150432,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1580377613, 1943291394, 116885098, 1486389957, 3719331405, 4064120425, 1266496205,
-                2418890676, 3322208617, 1806404391, 284507348, 1901836216, 2722919746, 2514726005,
-                2038580031, 1972429405, 2017654075, 62904235, 1317571069, 2128364754, 1580377613,
-                1815910739, 4017437301, 4161778492, 4151160286, 3333056536, 3116686400, 802357520,
-                3369610362, 385734166, 2591264923, 2983581128,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1908608441, 3609609310, 1942511395, 3420739423, 3432230838, 2300786310, 930077150,
-                41601098, 1908608441, 2364184368, 3716341356, 1953231387, 2601417142, 259442927,
-                2964695045, 3455864265, 1558455500, 1714603312, 3049522244, 2434584900, 181908556,
-                1956842697, 3419476426, 2465535255, 3228729259, 1398857690, 2078767811, 3433721078,
-                3439144975, 827228505, 1909672250, 1616752628,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1756751954, 1751430762, 3778595547, 3907577573, 1313466077, 1756751954, 3801571980,
-                2408381803, 4196174867, 614659035, 3545522131, 2476126144, 399918411, 1484856304,
-                3189092181, 952458702, 3921317529, 1867794810, 3112951988, 3237621857, 2674453080,
-                326801018, 3052191580, 2354386769, 1382059957, 1495659557, 2020402641, 1399229632,
-                634838156, 355310658, 4157799963, 4167007741,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3799375068, 891309856, 1604232982, 2535559166, 473677676, 953976263, 1294645235,
-                207690641, 3875897304, 3603243559, 426285194, 53048992, 1811189750, 3799375068,
-                2170362190, 2778399258, 3979085430, 3953548264, 3213687722, 1774762848, 2617549510,
-                1463706812, 2416328148, 1954145622, 272397227, 1081423157, 3553256825, 239238999,
-                3363636659, 1906904464, 1178963472, 922670781,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3411598616, 1635036006, 2054965332, 1490309103, 4027640355, 3766435776, 2559354762,
-                3953152159, 3411598616, 1764150619, 2738646031, 1358199869, 3791675201, 494940383,
-                2964493408, 288696042, 1598992777, 440375382, 1854439787, 3872639245, 1554623174,
-                3605710691, 1676333088, 3750598675, 637748571, 3472735125, 4175525895, 2123142356,
-                363760121, 2463009556, 3727439498, 13111736,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3516967696, 302672673, 2687347033, 3516967696, 3364998560, 2277535016, 3812012205,
-                1533385638, 4140863916, 3070466975, 3672780287, 1343210597, 838222105, 1955462320,
-                3341259889, 1817993816, 1897651269, 4148246670, 2312261755, 1282839722, 3529709735,
-                3067483876, 846335322, 616016944, 3432269577, 4060876685, 495022461, 612558238,
-                4019226524, 851118717, 313232137, 2784490078,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                612193922, 2518793318, 3858655865, 787123708, 4193550591, 3177071038, 2012303763,
-                422694556, 1438987887, 3821067573, 377590667, 612193922, 3355061852, 3804684693,
-                2859168632, 2122096715, 1050983414, 624274999, 1986439246, 1649969604, 1241573390,
-                1562536560, 4130511502, 191937019, 4046620903, 320430923, 311896883, 813214107,
-                424405063, 84556357, 1839007884, 3876204981,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3853107112, 2915131537, 4116694745, 15823241, 1839370341, 1870118611, 3782711971,
-                120109190, 1027356114, 2675407251, 3073366425, 1981439979, 2440809590, 1648511802,
-                3552904144, 3255353083, 4239066608, 2399261649, 1787047144, 3975760412, 474663537,
-                1010414784, 1496603908, 3576259749, 187206616, 1040096403, 280245539, 2575330053,
-                211969374, 3853107112, 283448666, 2502009842,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1838372690, 3563078131, 2690906077, 1044564245, 3288953341, 569848211, 4020578575,
-                2285760923, 3413510617, 1314185705, 369493251, 3575060534, 1878159399, 1105159705,
-                1387063055, 3030750016, 2885404402, 1838372690, 3522667627, 3417807736, 1679159527,
-                891767391, 526965964, 3063276977, 666298732, 364353200, 2578477632, 3737316290,
-                2915820851, 1841184558, 1179439957, 2530651693,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2198786132, 3083795412, 4222600849, 143668037, 3717210305, 3825278276, 3913040260,
-                3445069652, 2321885347, 2681664503, 3414654396, 592496190, 229820083, 991973848,
-                2715102862, 683851463, 3593060766, 453066364, 2823802642, 2174490841, 1499008262,
-                1098488548, 3164031940, 1945120509, 2198786132, 3162227493, 3467294727, 2200527157,
-                3617345085, 2508009642, 2923532061, 2423907985,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2872994198, 3655967646, 2108003565, 2467041170, 1375429283, 2195060412, 2626023851,
-                1319019723, 2339992790, 2344318481, 1300187450, 4050742936, 428660275, 2045165561,
-                993290049, 1723007591, 2049870239, 4246636133, 2903724782, 1090271546, 712599451,
-                772827137, 1860917199, 2872994198, 1859252917, 369755598, 4133713823, 4281443891,
-                3901259821, 1597527421, 34422401, 1423370809,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3735523902, 947862180, 1069958179, 369162374, 2605363304, 741107300, 3836406981,
-                1705682249, 3735523902, 3189107366, 2932805517, 3155404117, 862278197, 3583909675,
-                641988185, 2394418691, 1915482392, 415832672, 2130335742, 701340322, 4281085896,
-                690146533, 2381253760, 1374957844, 3155950949, 3184774044, 821935715, 2625551729,
-                2047941092, 1341075194, 358767185, 562359928,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2987367675, 1289705354, 2890185266, 3891761887, 3763231929, 1938847442, 1310491276,
-                4162752867, 3312084276, 2313914610, 4128982702, 61000463, 2049165926, 697551413,
-                1909626374, 1206065976, 2193634977, 3305716551, 2073661781, 2987367675, 3584903181,
-                2007907280, 3724147366, 295770878, 3636939814, 3563399220, 3791575390, 3102778743,
-                3786651413, 179140613, 4074040462, 240582850,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1884918269, 1437264474, 592220009, 1124774077, 255206740, 2784684621, 3729165764,
-                1602932154, 887468855, 85793690, 1756060006, 1002100452, 1800809245, 3818774870,
-                229068837, 2473149528, 3531628643, 3727823231, 3631313415, 3204062614, 425626855,
-                2023874312, 1815117062, 451788864, 2205648762, 146519814, 1483461426, 1572697299,
-                1187221439, 3260916158, 1884918269, 880902041,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1910881944, 3298830187, 1852430708, 3399044087, 4067666111, 486607817, 3882003464,
-                947845600, 2163823748, 1014872263, 2836121136, 1821865522, 2016246235, 2431498546,
-                3338155534, 11050389, 352159408, 3886009500, 1260923040, 1597142439, 315168042,
-                1018768204, 4075807102, 2441887670, 922294198, 3511410466, 531436931, 3960771490,
-                2943291373, 1910881944, 4146142634, 1615772452,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3466864983, 2683524080, 1670957224, 229325551, 4001457554, 1247939610, 3754763339,
-                4057300781, 817148732, 57367097, 2540387249, 2262083150, 3383408198, 1708250440,
-                309535659, 3056457075, 4114057303, 3466864983, 4186035001, 3218318183, 245678345,
-                1544814849, 4160712420, 1367617947, 1805433143, 1281641314, 102209340, 2168522557,
-                899140521, 1588570311, 3426319626, 3498805963,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1918457156, 3331356321, 3727055790, 1356595432, 657240820, 1494827506, 1690091569,
-                3215013572, 4179673444, 62725937, 2179787585, 1839900260, 738769725, 2940307358,
-                1652630541, 967038035, 1918457156, 50638141, 3392763558, 724559767, 4192614879,
-                241846108, 960153022, 3019471549, 3951668218, 2250554141, 3006714201, 3244549039,
-                7038362, 314238227, 322742316, 1098906425,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1832938467, 539314642, 3225750870, 2744221363, 683064552, 1037952990, 1832938467,
-                744209204, 1136503666, 1152564905, 1057475078, 2395616591, 29248323, 2563741851,
-                3365438969, 3996316117, 2232296967, 1347357858, 2397724694, 4186708854, 2119043143,
-                3583544576, 3725539555, 3350748219, 403381686, 1300325313, 3919941017, 4253691547,
-                1180074944, 4168926314, 2174938412, 729535337,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2292423206, 1803632896, 3520253824, 3273432540, 2229360668, 595205389, 1896109200,
-                3213228479, 1929681456, 3725672540, 1132232387, 2209338598, 4173355990, 72162643,
-                3246657714, 3047795442, 912954951, 658872583, 3164329329, 1536150802, 2024313102,
-                2711248541, 1546711408, 386966428, 684368728, 2499019789, 921895258, 249011648,
-                3293633916, 646174096, 2292423206, 1259928475,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3433968120, 877443619, 1391806638, 4128582671, 1008397808, 1955637562, 1297443891,
-                2376398183, 4243033597, 3444995034, 758558869, 39537296, 2552254393, 1690073385,
-                3355451974, 4060720581, 3341750561, 3053774560, 3898491590, 4211249597, 3433968120,
-                224860673, 761916464, 3699461761, 3733231822, 2294796051, 1387538780, 3700280152,
-                482133174, 2183702725, 3117046210, 1854768475,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3239521726, 3559931476, 1925665397, 2777959933, 1918660551, 977340228, 590667517,
-                1635068102, 4283363952, 3335091261, 3058159580, 3798856375, 3998858543, 1587962463,
-                3561311649, 1207260883, 1055479476, 1081074053, 3430124536, 1504697996, 3466668343,
-                1883193850, 320881505, 3670412617, 198394281, 821043712, 4025622407, 3492855170,
-                707329303, 38840046, 3632513771, 3239521726,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1757365744, 808482867, 1991973533, 312415113, 2417074822, 1265254577, 2345324018,
-                2356715405, 2101074632, 2976219598, 1731910856, 4200647433, 1757365744, 1713802372,
-                191565106, 2015540072, 983130587, 1116610792, 2390070811, 2642333683, 3144618468,
-                4160840806, 3636466098, 755479320, 3606266745, 1818923565, 1783812861, 3378789492,
-                966743260, 1542180419, 2399278473, 2468230793,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3016626859, 4068578802, 2399421771, 2847142259, 4075726972, 2251840423, 3854587689,
-                2578080225, 3016626859, 588857328, 449001995, 1192235933, 581399528, 3872257718,
-                1140152735, 1047966114, 1588012525, 3300203335, 109024340, 1475347886, 739227186,
-                2492314600, 172677016, 2646107619, 3292827576, 4135934349, 1398759576, 102130093,
-                956688324, 3411645027, 1432928547, 559750704,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                4259386836, 611934183, 3677716656, 2165862141, 609752484, 3928862538, 3638581934,
-                1138560086, 1595087374, 3925527156, 1575127445, 2340208946, 299452993, 3911650757,
-                259701161, 1133911235, 1954344330, 506916444, 2366922919, 1965845710, 4150509692,
-                1965035758, 2366901081, 158617599, 2320840668, 2414842905, 3096857593, 4259386836,
-                2639646520, 644787405, 184386498, 3425687255,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3774071141, 1428571835, 1483403168, 755432266, 4205648963, 564471778, 3911356442,
-                2973494086, 4187248731, 383743902, 3397731045, 1317916896, 2480338726, 3045464561,
-                1734088723, 3598181189, 1538606705, 238853493, 2076730395, 2123150785, 899404983,
-                1784602601, 3774071141, 1501316314, 3864234673, 805717398, 2330012414, 2237489919,
-                3295841256, 3327635442, 4099744579, 2554107956,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                3406441180, 3334100004, 1164876971, 4206433244, 2971100728, 1532066725, 2160096379,
-                3406441180, 1755860361, 2082044658, 976531343, 3381756259, 2678427884, 4219529072,
-                109924684, 3123418327, 903430771, 3117999809, 3011592944, 2031568001, 792283234,
-                1061475530, 2093713939, 496313219, 1225089621, 3740943221, 2942081973, 3106570790,
-                1063214765, 3240897877, 3902138151, 4197691643,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2762407892, 1489402335, 3743058308, 699799175, 126315431, 1693425285, 1732131073,
-                164261058, 1999730666, 1230503228, 582036431, 72129153, 835807851, 1540630108,
-                1617281699, 1726266151, 3391520308, 3369254844, 2762407892, 1325576059, 2274418020,
-                3539159102, 3195695071, 1825716192, 3920046133, 2388984997, 3258671948, 3907049775,
-                4056886244, 1213303661, 3956471980, 1716501409,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3004932047, 1498539136, 3070143523, 1770275462, 704740042, 2172615847, 2831765174,
-                769138268, 1502590715, 1244861753, 2378021055, 2302213087, 4125162686, 1467794598,
-                562693035, 2464935759, 228725418, 519153638, 2344365374, 3004932047, 1137965482,
-                1321143368, 3858850750, 339305932, 3109722773, 3874328500, 50452117, 711839003,
-                2834699748, 380170389, 3779296526, 183296019,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2635013595, 3554802028, 4191803938, 4057302341, 2977326684, 4182719672, 3041350066,
-                1363480572, 2796002212, 3969030797, 1243771600, 442064110, 2124335688, 1124145880,
-                2746668042, 780143171, 583693830, 3519386062, 3557581503, 1270607525, 564685825,
-                2029398436, 21627672, 1220872403, 4285496536, 1561379332, 4257412969, 2345145625,
-                3098895956, 2635013595, 3407532152, 2400482664,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                992059992, 1472818169, 1594898446, 329654851, 3064008101, 2730668873, 2931314557,
-                3165882262, 4027426635, 942020641, 3197650133, 2966842543, 3273398632, 445338367,
-                1127409863, 992059992, 2720257008, 1845915321, 2683724999, 1203293239, 625703712,
-                819845258, 249267231, 2299465718, 2526988714, 4130804019, 6556487, 84978053,
-                818732879, 2979115744, 3099216093, 2975622675,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2238383834, 1801087422, 2525054808, 1754079576, 3037847119, 316259158, 3910445280,
-                416124637, 2845833782, 743010303, 3951721250, 573167032, 3789813692, 1439499708,
-                796364720, 3558255023, 980067212, 701533344, 33179842, 340619982, 3944941351,
-                1599753847, 527463319, 3265038892, 2238383834, 2286962101, 2550272389, 3549409952,
-                3517881078, 3984779562, 978796460, 2007535953,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2188427192, 2994547342, 1275494139, 3068451147, 2011876915, 4274758728, 1633087151,
-                1756784994, 909610369, 2969549258, 1805208993, 841608210, 1538330806, 3728682070,
-                1732279348, 707146195, 2821870283, 3681306714, 999585482, 870818608, 3036885166,
-                546980779, 1665296019, 2450207675, 1263516599, 2420106803, 3031038170, 2007577477,
-                1558727422, 2188427192, 3961528441, 1769534591,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                315145066, 4128337256, 4175394194, 3012836973, 1175908103, 1277794053, 3356116327,
-                2339137177, 391700447, 3300159081, 2344503351, 2964265949, 2168493410, 2993112282,
-                2932928111, 3407615625, 769753487, 1952228147, 2593279009, 315145066, 3118063291,
-                3290640788, 2296382327, 846075154, 835720812, 1946491618, 1547128708, 2341903292,
-                217260344, 3310325970, 3748575152, 1171303313,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1207266474, 3086979691, 3448778435, 2411977470, 3086979691, 2003705934, 2577889324,
-                2100989351, 3574113539, 1530838649, 1068261569, 1102671576, 129595588, 2862485223,
-                3768930196, 1851617048, 107125760, 3496028127, 2211363925, 1207266474, 4250028455,
-                1943317321, 1547928099, 304982688, 3360398431, 2812411260, 1849532165, 1609288238,
-                2266883487, 2482314940, 3555825382, 1411755505,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2372426544, 2809743109, 3717697049, 2372426544, 3846059866, 763897218, 289164206,
-                2077287646, 483873746, 2424128227, 3907811709, 2869903840, 3260223162, 333184024,
-                4124078954, 2461515272, 1996876248, 1815437444, 920962456, 3069931032, 4093002345,
-                2916375363, 2089815760, 1427844616, 421380487, 3745789180, 2809743109, 1510761913,
-                3732313022, 3106255628, 3941515730, 402870847,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                4140954709, 3891345750, 3170887757, 379982727, 1964547241, 489282916, 2439032258,
-                641761692, 4140954709, 1518213807, 572431769, 796352208, 1884316251, 2935920079,
-                3534759035, 3873015780, 1601087944, 237395321, 1901367910, 672542684, 2386662666,
-                1673690624, 3985606259, 2278443994, 3891345750, 1005345347, 3326279469, 1141305826,
-                1132819403, 1723382462, 410495603, 2819035401,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                2396127064, 2415993200, 43983324, 923462052, 287053045, 2305631973, 1299265273,
-                2662170751, 3239491361, 2396127064, 2372830742, 3868365199, 2576255339, 3319140989,
-                2621736232, 2914417570, 4072790183, 3353021210, 1649912912, 2953799396, 266791045,
-                3149795913, 2721350068, 3010760664, 2323459132, 2169288691, 1836188863, 1688783871,
-                3161952944, 174856853, 3636928164, 2590296033,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2281061248, 331928263, 1616068572, 3815956980, 2188332599, 2824915528, 2980779442,
-                1690893182, 931234849, 1090371771, 3050498141, 1279501253, 2281061248, 800682233,
-                331928263, 102653343, 1042762749, 126917400, 3128778977, 239252457, 4001262358,
-                3956008988, 95228895, 524328295, 485293354, 3077256775, 980316731, 1242574286,
-                129298796, 261674740, 3235328001, 3382629305,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3936279852, 3802188146, 4003114441, 3936279852, 1180770247, 1260279302, 3670289526,
-                2438560832, 3339143861, 1856516933, 3802188146, 3682227025, 2905280354, 3297070424,
-                2455962391, 67433658, 2173771357, 2565332414, 786470488, 2242365412, 545103672,
-                3810472246, 3087870134, 1039072310, 3002058028, 3700293738, 4214120212, 4218886931,
-                1925866258, 3563263521, 3338318783, 171992075,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                903366462, 1953178906, 1512255632, 3211955372, 768369180, 3161089804, 2215738443,
-                3246075537, 3973069996, 451565379, 3477397183, 903366462, 3885573723, 444930906,
-                1878909964, 871785222, 2046516420, 2278943273, 4184169614, 806423458, 1953178906,
-                2204957911, 3253991180, 406658311, 2981232924, 2200689704, 1945036454, 2774126495,
-                1137147717, 33158523, 1812587182, 734927987,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1181116001, 1181116001, 342582128, 256761637, 714650756, 1360801046, 3781850849,
-                1728162085, 848023787, 1075686616, 1690075429, 3943285041, 1245858809, 3549620588,
-                1181116001, 2740709585, 1299891392, 205246373, 1021490079, 662058016, 3823734853,
-                3537347986, 3476861236, 1111768995, 3827162720, 1275141035, 898352961, 1989838809,
-                2014806014, 2168097341, 1152482249, 2240772707,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                761158874, 781716916, 2244362129, 4044143462, 3359924658, 3834244095, 1758821542,
-                4157593773, 640857457, 1124032370, 1798877622, 3144339700, 1416466406, 1775732823,
-                761158874, 1711024402, 1680809941, 3184009226, 947780376, 3547146345, 781716916,
-                3226684830, 570422157, 3205620316, 823368747, 168357003, 255931395, 1974951455,
-                1255326982, 228781583, 655132548, 3144605170,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                233084600, 1272885881, 2219546482, 1845114101, 1123138421, 233084600, 296588034,
-                2723917902, 1480036165, 2393435773, 3253047364, 1983203920, 2409573287, 2930867987,
-                2920150788, 362336327, 4165659141, 2125541456, 3755658700, 199385735, 82688943,
-                3343507030, 780430796, 440071954, 3915151112, 3649604233, 546607272, 405297341,
-                1272885881, 4268837315, 484048415, 3244118553,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2180126262, 2180126262, 3615741766, 1020082575, 1770167027, 168168621, 2266989392,
-                3206495613, 1184660270, 1229581096, 1941424334, 2899237414, 1179978884, 3562118394,
-                3590878093, 1660175799, 3308638194, 2180126262, 3086204493, 1218238619, 1318091795,
-                2427042339, 3298557858, 3022425903, 1888546604, 2273174826, 2757923627, 769509938,
-                601975648, 3307965786, 3749745331, 3710036461,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                925830008, 37947944, 1877957822, 37947944, 367907365, 982419970, 925830008,
-                4267336852, 3616463438, 3217449164, 830419020, 389603296, 3060926740, 461472843,
-                615581148, 493284643, 3507361149, 3487494167, 3711001910, 2448841344, 182936435,
-                723006721, 248519973, 4005081290, 1295165004, 2646039685, 902226898, 986424751,
-                1698404795, 2126055064, 378275702, 3470277820,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2300961984, 4167632863, 3968831161, 2716648687, 4132008776, 1127027475, 3722009647,
-                2424054329, 2300961984, 2129940800, 4269752145, 334229544, 2844790594, 357939230,
-                3613158703, 4167632863, 83908632, 1075814072, 2165869851, 3796476870, 843049948,
-                1772216142, 359892593, 1516848825, 552442468, 2840344479, 2777610337, 337408153,
-                1545315741, 1242429947, 2690555567, 3560953639,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2357126281, 3985291690, 3988976777, 3000317047, 1580758770, 2725306368, 3985291690,
-                2166331129, 2966712460, 4153272383, 1206469488, 3447501108, 1691879693, 2242643812,
-                1957175129, 2584534704, 467574874, 2166616960, 2357126281, 1336483611, 3379312395,
-                3952522738, 133723322, 684426528, 1442503555, 2724882194, 409161411, 2047097504,
-                2702067883, 1491886268, 1053838604, 359015942,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2629996294, 412550128, 307254374, 2097222844, 2869405758, 655138077, 1994661129,
-                3607678166, 415116708, 957037839, 336386000, 412550128, 4220944214, 1250577253,
-                2629996294, 3979123333, 3579140677, 3358374814, 4164326767, 2282914370, 2307491954,
-                1658669671, 2660225501, 2582446954, 1831847937, 1747077173, 2156735399, 2562772809,
-                1311583771, 1634108170, 4091424021, 390271718,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3985854135, 2092216593, 761509487, 3148964830, 1214514216, 1391346927, 256353316,
-                3352851828, 1817512149, 1375040302, 1781231477, 2603583200, 1395288905, 3935812094,
-                3985854135, 1742856909, 2428340135, 2862980042, 2210020101, 2105377571, 411072268,
-                1162781285, 2092216593, 2547282166, 533495259, 1439909342, 2317203906, 1234188827,
-                531136263, 4178548025, 913697400, 4221577159,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                2242209024, 524315436, 1836637835, 3043856387, 1686343783, 4193134067, 212252199,
-                4162596238, 1228388010, 3561827098, 148928435, 950549917, 1754118740, 2303750486,
-                577207437, 2242209024, 910507367, 2054660108, 502753866, 2823293807, 4018323658,
-                2078331394, 1188669482, 524315436, 3356083072, 3055740713, 1567406400, 232955182,
-                1355378244, 522712993, 911937467, 1545461152,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                969945026, 3442106877, 969945026, 716977291, 1976514243, 1500438997, 1402215544,
-                526008231, 2617370344, 1602825146, 1567538730, 1194403148, 723951554, 1963325556,
-                3276819517, 3317649296, 3972824480, 3324898481, 3359124608, 327865677, 2178840008,
-                2994631457, 3442106877, 1797909355, 1105194995, 2833503500, 3381529892, 2652467355,
-                3231195733, 1869421461, 810059950, 1064644405,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3878907031, 719697206, 407808664, 3214731969, 1512337532, 980686281, 3091084285,
-                33545222, 4122369056, 2204668544, 1478567983, 3082818243, 3878907031, 3808985653,
-                719697206, 2425556766, 3096474279, 2855285875, 423732309, 3362337251, 2919928565,
-                1718422402, 179252175, 3846414479, 659957354, 888802022, 3398109632, 205272740,
-                2440848843, 1107265303, 608887310, 2732328349,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1355574516, 1137092994, 4181752675, 449065090, 3466680497, 1355574516, 88963573,
-                769856414, 2564373205, 1308175569, 1938037443, 3673165537, 3773441574, 2288314102,
-                1137092994, 2076755063, 4229230960, 4171831110, 4241025887, 145464776, 2394490463,
-                4247455238, 2499906558, 2185071932, 3888201795, 1725653298, 2681229136, 2503304121,
-                682884161, 3080188571, 960202736, 317608003,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                115937071, 3482430540, 2781109166, 3628911495, 3244182759, 1471287897, 2897814540,
-                1389845001, 2931277623, 2397722760, 1445244687, 3739614962, 3402015755, 3892286653,
-                317829760, 115937071, 2519628513, 3503737761, 3482430540, 2447916931, 683187863,
-                196951393, 1476255985, 1114487268, 2951695674, 2572211966, 3016533471, 4244057183,
-                3035082698, 963280910, 3424403961, 433249291,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                3168108527, 1528342431, 4245223995, 4178001334, 3275003139, 2034746202, 3168108527,
-                3190205706, 1275675291, 138146815, 1394188576, 4238093922, 2128215986, 2733237235,
-                1897596445, 3775493707, 3106263448, 4078810941, 2391419207, 2686324633, 1682796518,
-                1881817160, 3691433922, 3474882628, 1528342431, 1160048861, 523958065, 2359715906,
-                2504875391, 399609886, 3167253817, 3867416213,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3338474592, 468186327, 4008280167, 4106955717, 233673318, 261248140, 2436383172,
-                2020411130, 2381481171, 3338474592, 4235988894, 3177820316, 673768088, 2253379719,
-                468186327, 1613752202, 2062657719, 2050082961, 573193088, 3077571222, 291189524,
-                4248206405, 3229952437, 3215178306, 1772380919, 3689464242, 67016382, 298927968,
-                3458834159, 675143287, 1737082414, 2426195660,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2667265921, 1911541791, 4098945407, 2667265921, 2368354669, 720543000, 4287384779,
-                2711952866, 37843771, 3025463855, 1257822638, 1284332269, 1323212147, 1798697917,
-                60018376, 3611611774, 1956238086, 1631345350, 2395981602, 1502082898, 1555506218,
-                1911541791, 979248566, 294884498, 4160748401, 56527552, 1308251167, 3251037664,
-                1772098454, 3989963344, 3896714595, 1205482774,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1562073201, 3159922231, 9620206, 4263360400, 121996272, 3552263090, 1440884778,
-                1585257100, 3054361663, 1593859244, 2704911268, 2893820623, 3159922231, 2950413389,
-                2549219191, 1562073201, 2857840855, 1334766327, 39614575, 3619585278, 471819981,
-                939971957, 3848703, 3459520050, 3613969681, 1367334333, 414281820, 3242916458,
-                4047418243, 904375693, 2372746240, 104942302,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1145953743, 2490190136, 1657113878, 1188862990, 211256485, 420800221, 2171346583,
-                2296361766, 963512940, 2804813701, 781328711, 2490190136, 1013643243, 3707028689,
-                1307811688, 3524471904, 523809944, 3552698220, 2139789175, 935073206, 358301880,
-                1145953743, 3331998937, 1762597822, 3545972915, 1259730944, 1328494673, 3075154108,
-                32296769, 2155147116, 3679660520, 194281151,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                989025008, 4102991267, 1589661377, 3955533730, 1683172230, 726739139, 3380954843,
-                210741866, 4102991267, 152489288, 4068014871, 2983514709, 989025008, 3367062446,
-                1102452120, 808732561, 360549377, 1803516636, 3889921112, 976082034, 261216504,
-                1779432500, 920559594, 651747978, 396918095, 3861853982, 3653432098, 2536917029,
-                3125750614, 282225230, 1455224363, 386233518,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3135872161, 2764445961, 1211461225, 2983092363, 1929132191, 1342941633, 2431639434,
-                797341977, 3135872161, 1126476711, 3280436648, 2083565912, 2922662940, 972577788,
-                604634596, 4206408934, 2009032587, 1785117869, 3632892921, 2764445961, 1200919122,
-                3911040465, 1768028472, 1228431440, 3618479125, 1877245964, 1834773977, 2666550236,
-                143946827, 3821108019, 1747557022, 2876267597,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2683922212, 1423174715, 2509590531, 1127925667, 1507750988, 3432446337, 1423174715,
-                3338482621, 2683922212, 1779028099, 691255054, 272537465, 2542852243, 3179464525,
-                694531263, 1936224654, 4209433598, 3887252684, 2191891656, 4082076489, 3747494471,
-                1306784920, 249360638, 3917022783, 3265813564, 1277905759, 2714455586, 2345229626,
-                3707957490, 1656913266, 2263200162, 2794317653,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3314979128, 2608902896, 4021219413, 179518595, 2855407635, 3034438966, 1440590052,
-                2392670206, 1756911421, 2199773292, 2166236939, 2782193171, 1673195355, 3191679951,
-                4050273740, 2812897157, 3339498109, 2608902896, 2587589186, 3223956956, 3950276547,
-                674328151, 1837324967, 3811427005, 3190939121, 1869032088, 2336895306, 3833460730,
-                3314979128, 764724818, 132035907, 3128347900,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                4170306010, 4210555575, 2005189524, 3469016720, 1796462810, 1114844517, 2529952509,
-                2110212474, 4104378562, 1542314474, 1996290131, 1871046262, 3281752059, 1843847526,
-                2233069155, 546179969, 1771808533, 3049897039, 1430744477, 2380315186, 4281452428,
-                409557251, 1491101147, 2487349909, 4034099329, 103674772, 1264102834, 4170306010,
-                3784563760, 771133497, 1525924388, 3822547417,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3775899948, 2305316606, 3990691400, 1890018812, 3134356906, 3377583430, 1884224979,
-                132756737, 2135248782, 2572814497, 2873742484, 2608728480, 4008521132, 4294308009,
-                544934482, 1923408726, 1968964098, 1092964688, 595915822, 170211637, 3173795452,
-                64625188, 3983723743, 4031850042, 2064479308, 2649305145, 2733734611, 3775899948,
-                1390132867, 2305316606, 693845939, 3206924343,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2788798333, 2098554266, 2278657903, 1644589152, 895098234, 2796276164, 3320663593,
-                2255579786, 2098554266, 1061876117, 444235140, 3856336407, 649549155, 282619114,
-                1452137862, 355944343, 151380888, 2716252440, 69095505, 2719831839, 268135465,
-                2465810593, 2227705356, 2788798333, 1713105215, 1933192618, 1895545082, 1421600470,
-                3396821534, 37388184, 3139539402, 1163704471,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                4079443959, 455065734, 2016345344, 1012396166, 792067797, 3172781618, 4079443959,
-                961381531, 2180328657, 4285493968, 4207825407, 3559273282, 2747613286, 1117623828,
-                3398074159, 204295711, 4118940396, 3248805841, 379043629, 2096693856, 3685967125,
-                4042514459, 2761908617, 3032640721, 3140086492, 3916783484, 2033961376, 455065734,
-                1375556759, 497003354, 2762937712, 1384526082,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2415746640, 2326794119, 3453977571, 223464671, 2351339154, 2404528751, 2369771464,
-                3322830247, 2597035706, 339597060, 4035527255, 2167474507, 1555775163, 386929598,
-                598512462, 1479864238, 24702869, 2836379033, 2415746640, 391671855, 368656158,
-                2558263653, 1731703468, 3945642016, 4085066767, 1939887414, 1755387543, 2326794119,
-                2824322984, 1159041651, 184692063, 2543432376,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1897620599, 4256902434, 2446044285, 1564421478, 890637063, 262644173, 1452426291,
-                3378763313, 777822996, 1834022709, 2954754616, 4150063714, 3273699649, 3997884874,
-                3928794227, 711226748, 1134637670, 431626623, 4205270032, 1237800664, 2993075412,
-                1456414646, 3187247464, 3137367107, 1939267614, 655218174, 2450178070, 3101458260,
-                943569301, 2239603763, 4256902434, 2139480292,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1124941485, 1507233616, 3862209845, 3092840229, 1661748392, 408967732, 4056549858,
-                4139768222, 1507233616, 305978118, 1189253041, 332478256, 3192945551, 2940255954,
-                2095125383, 3341372408, 2416103049, 3499375389, 1813071962, 3461226624, 1358015854,
-                1483289279, 4089491027, 1188554186, 565055210, 3353762984, 3088858971, 2328949243,
-                664249559, 2739929544, 3733595985, 1124941485,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1163159824, 4213559148, 3642742646, 3729790468, 2501736394, 3110594303, 3774246216,
-                4077329585, 3301998746, 4219622555, 3555218392, 580814741, 4213559148, 3627952237,
-                1775724628, 645425333, 505217549, 2223267155, 3768306943, 1146657687, 1603497328,
-                2703480684, 844517211, 4128493163, 1163159824, 2340194113, 1093927104, 4096992754,
-                4264566245, 1481649566, 888430745, 4104569430,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1241008590, 3391884334, 3699011776, 3391884334, 540483496, 2742946382, 3395076159,
-                1662089207, 3819927305, 1492058681, 3379900665, 1056081731, 734972420, 3445663123,
-                1661603007, 2910473551, 656988008, 3108172479, 2632652100, 1773418161, 620112845,
-                1170578925, 328581201, 106102085, 896658940, 1241008590, 2241805753, 2426073833,
-                3412836498, 3161516390, 1149701112, 3245223238,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                817333867, 2139095297, 3646475314, 1544017615, 1819272825, 817333867, 3308113849,
-                998859911, 1925813817, 4161529743, 2139095297, 3559971240, 1138176046, 3582818102,
-                1305990484, 1533309999, 2927470416, 1627450537, 962157487, 3555445462, 3278417105,
-                698643472, 749970280, 3020640369, 1841682027, 40020656, 944222504, 3391948978,
-                814349809, 735923980, 3365929132, 556753578,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1148932828, 3629940025, 3474698754, 3241573906, 582978543, 604031874, 3732344512,
-                1368063000, 3238626843, 75150475, 3466231732, 3848421184, 3629940025, 3053561768,
-                3603742379, 1741368808, 3807078885, 4231140569, 2358078362, 3038218982, 2856382074,
-                3138773687, 3781755898, 2080449361, 574863906, 820946937, 1148932828, 159103851,
-                3012186377, 1838305235, 52817528, 3317166823,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                3883794051, 216542584, 989727239, 861975382, 3328492761, 1607670206, 1828640074,
-                2825624840, 942184022, 3487178189, 206719693, 216542584, 3883794051, 3400799076,
-                3501800162, 2775474554, 432987917, 468694944, 4166481264, 1460298949, 2011217038,
-                816157875, 2746850057, 1289728951, 3570253922, 4260111583, 3022661992, 3406991728,
-                2740601698, 1166524040, 494171024, 1507911554,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                4124080560, 3780651489, 3737949485, 1944894758, 2590414821, 1581809278, 3830529729,
-                1777709256, 353499079, 2707369455, 3157184108, 3040585412, 1260995978, 70369380,
-                2938433376, 2763821873, 1601316284, 1963815242, 114479041, 3307880867, 1496106911,
-                199005464, 1914317791, 3447137611, 4124080560, 4272337921, 1821511176, 3780651489,
-                654189810, 1986748603, 2253723962, 1517217704,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1725922490, 2724443739, 2996022808, 2063817901, 2179136245, 2089228399, 1613188564,
-                3180125924, 488421406, 84859165, 2555539142, 2727897424, 1750964722, 1451316605,
-                3105210270, 2327057760, 1908388188, 729522946, 978587516, 1613418154, 2724443739,
-                3375084557, 4067822652, 837858593, 3583744756, 1725922490, 2842874425, 979970362,
-                1132701957, 1618046624, 4232066889, 369699271,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1301841110, 376666690, 3725765107, 129573748, 2528244072, 557254221, 1110018380,
-                1008446989, 2319622389, 1928524013, 3446067709, 376666690, 878037278, 2347008873,
-                1840963075, 270055563, 214950469, 4210448855, 1094315452, 974853599, 3380428304,
-                3575674196, 405780625, 1301841110, 2591269806, 220619563, 2424626091, 1328899347,
-                538768920, 593368255, 2637753008, 1717591013,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2220433787, 2493190925, 1995274148, 2091380378, 1132182244, 3458008158, 2493190925,
-                3539769108, 2256132647, 3673143714, 157860680, 4013821975, 2098417347, 3353286443,
-                2305378334, 2616205940, 4071041014, 223194950, 1832646996, 2891261526, 974414899,
-                1875995852, 2250774632, 97967092, 1740557685, 716183722, 4027968143, 2898847935,
-                2487393075, 2639320290, 855338529, 900275213,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                2392426183, 436947146, 205113531, 1455131019, 1818949392, 3750595156, 3394610239,
-                4236597988, 3895646219, 988039689, 3384886062, 3671191458, 3453414488, 591123925,
-                1995339705, 436947146, 3549034946, 4126967359, 1439908770, 210204353, 1828492214,
-                3143259524, 3230439702, 3207011744, 3186818533, 1864407895, 2392426183, 301153626,
-                3492966500, 3870892265, 3546484231, 535316419,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                938403797, 3696372405, 3554674852, 3758785031, 3994395224, 2271902160, 4238493341,
-                375475136, 1372099837, 3696372405, 2684394644, 2167322519, 4207472627, 2330309050,
-                1677473395, 1269634413, 1873427282, 2596350546, 1608383331, 436541622, 3780238280,
-                2649265737, 3532769682, 2824151933, 4008683486, 3862165171, 3822345904, 4173407733,
-                2188788221, 938403797, 2188302722, 666149171,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3938716214, 619234077, 2630701389, 867445658, 887353793, 260137360, 619234077,
-                1636602100, 2620129075, 3115417522, 2145072094, 2421197229, 4088313728, 2784163590,
-                1757340749, 236846244, 2575125878, 2475635188, 545715152, 2736960893, 2134765161,
-                2855651346, 877321702, 539714042, 2872015239, 402524759, 4273036233, 2938098296,
-                360654755, 764789720, 1837918564, 1244641273,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                3152905724, 1166963421, 133022947, 1520441444, 3758160103, 1397551194, 1977582716,
-                2032501729, 1559327310, 1133682011, 3618843391, 1897583545, 1728849164, 4276434897,
-                2238498017, 2909044043, 3606797741, 3152905724, 2533673369, 3283433090, 3155409975,
-                3372452077, 1139549961, 334530888, 3322215732, 3803355406, 1365525937, 4040170806,
-                2702009107, 1476048665, 1166963421, 3870911180,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2509214880, 1564555759, 2788209124, 1466639181, 1915259249, 2300117445, 2234570898,
-                2799720417, 507781677, 647044935, 2788574061, 663238304, 1597119958, 1357000487,
-                1564555759, 1039397895, 2310233765, 2874455768, 173430611, 387875834, 2113147417,
-                2394495818, 3182612993, 3653762420, 176954749, 2509214880, 3391986748, 1572745058,
-                5813594, 2033396433, 3778538034, 725855304,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3373142233, 3373142233, 3373142233, 4187399357, 2156775284, 1188877811, 75063126,
-                769854215, 1021268823, 2112457033, 3352673836, 3498337674, 1483096827, 481384344,
-                1507989966, 870932746, 3634264923, 1085553309, 3329542714, 4224160059, 722892229,
-                3071362331, 3059962647, 2553333659, 961140160, 3315233784, 1984589444, 2619909528,
-                2364370015, 4171408702, 2410236818, 4240766509,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2071279792, 993352578, 1128944521, 1342090053, 835647333, 2292257745, 993352578,
-                3990094197, 1923245889, 3850538908, 3808475416, 2071279792, 194028278, 3783692935,
-                1588874444, 383373150, 3154325122, 752823160, 1623599882, 279850822, 1462948265,
-                1547674841, 2629309258, 1487611946, 2822311024, 2847058720, 2578238766, 3289349017,
-                2731747446, 1226440257, 1275497288, 1849852029,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2453925783, 205436219, 3002210573, 2621051783, 4104136232, 451210202, 2367104574,
-                2140326680, 2649058276, 1095203073, 1519856658, 2910392283, 2581854035, 3483012659,
-                4197434772, 2611044796, 1453003991, 2807901641, 778924593, 760542567, 1303429803,
-                2453925783, 1601057099, 3779137605, 1170008245, 2687057362, 1185712925, 2372208773,
-                3987204934, 3068540661, 205436219, 580127895,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                971942459, 1484015304, 3264543878, 3573968942, 3136560232, 170606968, 50069037,
-                1143856169, 3370089155, 1484015304, 2787182743, 1077245350, 2367554958, 3881058707,
-                663015622, 1891359355, 878242861, 3559593782, 2061295055, 2201149850, 154718842,
-                1613943529, 435561881, 378546015, 569447812, 1061421056, 4206773058, 2336153585,
-                4050473256, 2825264536, 742444308, 3655077833,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                4280557364, 893272693, 2497045450, 3378926034, 702979174, 595342000, 4172837037,
-                1875178673, 350196687, 800602758, 3819174037, 946578603, 964489049, 316182936,
-                965501928, 1741137572, 526731726, 1100289070, 276884179, 879707639, 2842813023,
-                4022628496, 4280557364, 4283992504, 4281407438, 1635000454, 3045267177, 1646093126,
-                2101611568, 1170174602, 893272693, 2929336132,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1928427619, 132154434, 3897929663, 2405938127, 3124852596, 3823210823, 2653274997,
-                2140124284, 1438217750, 3720409308, 3945022115, 3338103434, 4118893135, 894619900,
-                1834308267, 2701018127, 2730578081, 3451515730, 2584152649, 4292320481, 132154434,
-                1544253882, 3022547289, 2503120745, 997492351, 3450136497, 1228699934, 227149561,
-                2112971707, 1470966594, 3303114006, 2916558894,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                133002095, 1733256649, 3571942016, 2198821091, 3479999957, 2606737066, 4011192668,
-                1961658667, 2724798954, 321745209, 133002095, 3678503197, 3040565756, 921934951,
-                1892259791, 3840040783, 361277519, 128435746, 1702265420, 3902602758, 1733256649,
-                2902294880, 4013610824, 3229215521, 1787440487, 718028134, 3076052220, 1714960761,
-                2664911567, 3676740800, 3081646451, 1454563655,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                469114294, 1550511100, 1943392469, 4170286579, 1575915522, 3153447031, 648581794,
-                846793965, 3738805089, 326188000, 3038278934, 651342663, 2283152995, 3544916512,
-                2968640418, 1211042446, 1483441177, 3942777668, 1811570184, 2788070763, 1550511100,
-                3452717548, 1278632575, 469114294, 4209276890, 951694490, 760978945, 2523399799,
-                1798499103, 3307361534, 2017796738, 3818732554,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                582832616, 490839916, 1082926878, 3667723405, 976083627, 115997399, 384598717,
-                648862072, 3435726610, 2170409174, 3634675838, 2988999920, 490839916, 1270450146,
-                2255703217, 615914168, 3644539939, 1481028855, 387126293, 4251929681, 1587136576,
-                1364312112, 971585451, 1102471575, 1486686691, 3052874886, 1930034867, 3876702115,
-                582832616, 1319458607, 145047674, 2952677454,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2464090692, 915402174, 2464090692, 2853913157, 3027021951, 2228989104, 1675344095,
-                3715925681, 4049097226, 2553837438, 1311358735, 2200543652, 2855338170, 2511998287,
-                222181604, 173361811, 2251439969, 3358702827, 2105610472, 915402174, 215516311,
-                2940886091, 2902174210, 1875657650, 1377647950, 1360597371, 4034231410, 3483305664,
-                399583997, 3873524008, 3449069873, 3061727840,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2447350752, 966313785, 2557848238, 2471645199, 1107154611, 1898720259, 3864555644,
-                2790133343, 526573200, 3863525144, 211509673, 1172376685, 3595255071, 3375624139,
-                2447350752, 1627177516, 2708275093, 2181189237, 1064542154, 966313785, 146540989,
-                2302374188, 3296416479, 3844971984, 2658917853, 2152231951, 3683430091, 1729025346,
-                2050491998, 3206899538, 275045198, 955327237,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                2591306649, 3657391, 345575314, 2972576325, 71864318, 2591306649, 122344419,
-                588933870, 692893753, 3657391, 3896373344, 2976652755, 568493074, 3875239678,
-                3397345100, 1834213560, 773443271, 3669323391, 2488365325, 2445945385, 93341713,
-                745051448, 3885374783, 509816336, 2603546353, 808315429, 62662831, 881313899,
-                3752308691, 3574564642, 2020673137, 3607676478,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                3547199114, 2097068489, 4225595954, 1469652494, 1554291535, 486796790, 1066213910,
-                288361503, 1024911077, 1646964130, 293852146, 2024062831, 3243954382, 2922859184,
-                928022509, 2269124892, 894723404, 1133540799, 2097068489, 763251003, 3410160937,
-                2741766856, 1756130185, 2128096567, 869410153, 1034058370, 969571194, 970801420,
-                3587156172, 3547199114, 1839777742, 1186307790,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2745569100, 2353447299, 3429516564, 2745569100, 1018957953, 3574287492, 2353447299,
-                3009518540, 1570275100, 662964922, 4100141624, 559316581, 2022860894, 598995176,
-                2769703735, 1963048731, 3285297568, 242882345, 2153588402, 2070364942, 3652250105,
-                2899555912, 2645130491, 4139033060, 3929123070, 3601620375, 2744360499, 2450779603,
-                615334777, 3521058340, 1780321089, 460341907,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                3792151876, 71572644, 3241937215, 2496453337, 228140676, 71572644, 385919876,
-                2108063536, 1868733745, 3809961279, 2335359592, 1051925316, 1306164012, 723492070,
-                1815312119, 3792151876, 4051542518, 1736317199, 3202897269, 476681511, 2585062103,
-                2221861565, 1890110594, 1405370069, 1775802469, 2133077301, 2041737479, 989299810,
-                3471188616, 601237390, 2014986402, 4007062980,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                1117482567, 3946726686, 3165747623, 3016231339, 657543308, 3905621981, 3769977808,
-                3923819571, 984993739, 348936555, 4179161722, 4121664378, 960527977, 2458677042,
-                1117482567, 437871049, 3305626237, 989876515, 2690677303, 3790121475, 2218638686,
-                4274479712, 1463229721, 3652095057, 3396522933, 976254899, 2943915734, 4203252169,
-                3946726686, 1752841179, 2146356840, 959569957,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2245440751, 930011618, 1193071216, 2072541264, 3426112011, 62367669, 1380373163,
-                2838351769, 1668616415, 1017833369, 2131156910, 3233617576, 3476353007, 2356991571,
-                298358341, 1385920639, 947232338, 2190958930, 2389519358, 3523124965, 1944699243,
-                960157185, 831011362, 930011618, 401359834, 1711406790, 3163120236, 2653928061,
-                1628794344, 1926892774, 2245440751, 1999616981,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3700932597, 2881146679, 1410794369, 3047124155, 1460712177, 3700932597, 3415922007,
-                3076925353, 2110339244, 395583242, 392217608, 2295292631, 3689142833, 4125583487,
-                2511309987, 4268444441, 2067066777, 4218829830, 577834740, 3425426659, 2881146679,
-                2850738249, 1026085786, 477640758, 3493863966, 3972765762, 1507766376, 3938904016,
-                2588487558, 2959008458, 2716873243, 1019651785,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                2603816715, 2105382169, 1655627257, 3664680538, 4122320699, 2341955525, 39365129,
-                1732790167, 1559535820, 729004069, 3963999922, 718877244, 223219607, 686611052,
-                410478387, 2905716829, 2162047049, 2603816715, 1706594789, 3739231526, 3883312050,
-                98892465, 1148908630, 2105382169, 2402563660, 1197469481, 839642981, 2975365082,
-                3667577607, 2478825967, 3316080654, 942396119,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                518336456, 831783421, 1584867346, 518336456, 772707922, 722134166, 2090997807,
-                3055443551, 3846312736, 1105565000, 1745575534, 3921638869, 831783421, 276853641,
-                3938581922, 945020659, 2979055756, 494460006, 4138712906, 1002148276, 427230048,
-                152846844, 1786678218, 4254081968, 3141416196, 357215268, 4211207704, 2605529412,
-                1298996853, 1562790444, 3974428125, 1805705325,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                224769807, 224769807, 3622945164, 1195170079, 1031545449, 3913385289, 712463042,
-                859060020, 2747055295, 1494130678, 452604571, 224769807, 1503110557, 1114673525,
-                3123894264, 1920400452, 1725767600, 14759210, 3755213929, 4116401589, 3763327075,
-                1833738287, 472538900, 3180449340, 1800260396, 3338170030, 3003118661, 2392364968,
-                3233564718, 2386448119, 131954230, 3315331256,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3206175811, 3107345709, 2945607421, 3748934104, 2736694047, 864591801, 3977618456,
-                2260509324, 684071786, 3026641429, 424041947, 1791380537, 3854300268, 2825993948,
-                3107345709, 812719544, 615707745, 3035146249, 1287480391, 857826677, 1683838073,
-                3498576474, 2264639920, 3938449724, 981846048, 228833285, 3206175811, 4057683021,
-                606870657, 218365692, 4035809786, 1596365314,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3884462783, 2088004882, 1955487050, 3082692199, 3372570689, 332580306, 160605854,
-                465141717, 3884462783, 2002768221, 3703496394, 2161085394, 707847699, 4197248644,
-                3104871987, 66948814, 425650576, 369012150, 4157773084, 75776297, 1283042041,
-                4062164169, 2088004882, 1757357194, 1012928285, 215257336, 734605926, 1239997740,
-                2369867955, 1584323466, 4028842944, 696316866,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                4002184210, 598674723, 579125877, 2557339227, 2860084060, 2930024702, 1809139998,
-                1576554805, 846413973, 2283749773, 3869850364, 214799101, 2984043291, 2297344521,
-                992651830, 126930115, 4285141081, 4002184210, 2583058071, 321343819, 2605199025,
-                3514907640, 3937545873, 3338587630, 3612341105, 106887639, 366107448, 1681439209,
-                1889081140, 429921100, 1904643841, 598674723,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                98749749, 1765176117, 4120882499, 2688944244, 3063261273, 3210779599, 1101555714,
-                1883575531, 1219345490, 1765176117, 3448638880, 2712571102, 965978561, 4212637189,
-                1186147135, 1195818499, 1829941244, 2935773257, 3951236010, 3853097589, 1676212135,
-                3498990258, 3212803797, 279583139, 4073218360, 4271391442, 1520165810, 3322138678,
-                4134540131, 3899378317, 98749749, 3506214021,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                1504329230, 3438537026, 872352121, 1725886030, 3860266699, 2972964401, 1896094637,
-                259006911, 2088659079, 3354634147, 1082681846, 3438537026, 1503316549, 316537572,
-                1261195232, 3659539707, 510700931, 1374191677, 1112421939, 3823989144, 1033804183,
-                3044076148, 394544942, 1504329230, 3406976339, 1692412410, 72534889, 4135201699,
-                174270517, 3334805277, 294000384, 1832624930,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2779472375, 1372721116, 477999438, 1258605411, 4173893213, 3528132559, 2508470159,
-                1116036488, 464930057, 263369662, 1183144047, 1123774073, 2171164845, 3759424427,
-                898715409, 2779472375, 2326358077, 3905753754, 2020950905, 1438940677, 1999150255,
-                3480819495, 1769651135, 124917456, 3917662340, 2514121067, 548911618, 4050039876,
-                1724789421, 684458582, 743610173, 1372721116,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                1372695279, 2439992533, 285399433, 2901583029, 2107817207, 2857076440, 1911447472,
-                1141169127, 1823545574, 2347641913, 985815474, 3455095198, 1322373278, 218189372,
-                3164774782, 3321567222, 2454456890, 1774827933, 2466320781, 2009607951, 3858865441,
-                2272771015, 4276039060, 3703456985, 2843530420, 4151090520, 2816301107, 2439992533,
-                3241719779, 1669558793, 4131026201, 2565821764,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                1078323556, 607288033, 1840714444, 2456744548, 303189017, 670135130, 31179851,
-                1876595923, 1717306857, 4011836335, 2361318068, 2986413603, 4008897469, 3328365664,
-                1866932790, 269262830, 2911445314, 3524801802, 838175742, 2724486161, 3363968923,
-                3936599544, 3586859171, 607288033, 3683855280, 1078323556, 3035896715, 2596469638,
-                1870139416, 1268173331, 1887772058, 471150309,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                2779397862, 1628465064, 1858392178, 3380880926, 4003200570, 2098818874, 90411873,
-                4104925274, 548456022, 3303046544, 1628465064, 2779397862, 3833490012, 3037727278,
-                4087156156, 4055018104, 3573248658, 2269121843, 908223730, 1404550822, 2706274217,
-                2865379474, 704318540, 3558516384, 3308908511, 351012356, 4156489977, 895654155,
-                1341077574, 2921063697, 929476063, 1259103502,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2883958236, 2883958236, 374847445, 2243355665, 4144108179, 2702146680, 1759321024,
-                3267214605, 1490064586, 438473092, 4133096856, 2963389247, 1462774951, 2036149119,
-                2118142417, 1864810408, 1912867568, 2461400398, 324259940, 4281228508, 1575926181,
-                3687331213, 3716951239, 3219137572, 3307468845, 1998365524, 2675680815, 3908020663,
-                1093957525, 816559023, 2883958236, 88875622,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                1484174708, 1794216586, 1221014233, 3293567856, 3790918054, 4088056896, 3173449231,
-                797878544, 3522280663, 1020645091, 3507845935, 1459966837, 1208664951, 4285070625,
-                778991379, 3815424013, 2307184232, 104005570, 1794216586, 2365284610, 1801394549,
-                3765788553, 3450046247, 1834087735, 2725563318, 876631308, 25769828, 4108014138,
-                457285109, 1484174708, 1642828060, 3595690221,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2714489642, 544485026, 2688646481, 3797534093, 1809593844, 1165712957, 2855673374,
-                628925544, 674594453, 3754779712, 3728617676, 2035817356, 2726298570, 1859895667,
-                1809051117, 447290403, 2747555123, 1546010492, 2714489642, 3199613198, 2180139989,
-                2664584487, 1592161924, 3672577707, 909440645, 1731780233, 381466993, 544485026,
-                1914832616, 1638896290, 2241822781, 1573835732,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                4265701739, 4141894741, 2191353855, 894576863, 1166807426, 4267548620, 2550681780,
-                4265701739, 592166384, 3238563474, 1939480353, 593968749, 1980744496, 3217147709,
-                1776320054, 4094651250, 1191592157, 679720294, 637201044, 93715482, 1789856617,
-                3755154798, 3012640467, 1095772649, 2200935282, 1458757266, 1328830531, 3262098229,
-                4141894741, 3284195340, 3602424854, 2973582455,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                924355388, 3899925169, 2401209535, 1908140364, 2028320394, 3943993487, 1250085487,
-                2651968629, 1324349749, 1176837598, 2728731358, 2998417700, 3623678664, 410100216,
-                1250912100, 1659617037, 924355388, 4258019675, 1752963895, 1481027754, 3154553405,
-                1940493406, 4248069285, 2925076467, 3899925169, 2803540052, 2900795929, 3181050337,
-                1907335524, 255822583, 1801823218, 2158006541,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                2174534921, 3855007210, 3855007210, 1236687268, 2082331557, 2650541242, 884596380,
-                3713370275, 3485575519, 1336893805, 3971234756, 1436809834, 199894551, 2081352343,
-                826340882, 18874562, 535589502, 2174534921, 1254957474, 3034368178, 1765628432,
-                109522471, 3269073004, 1454573872, 3634847198, 2375784926, 3251625106, 561454941,
-                1144439645, 3544820424, 1807306792, 1166332344,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1722789111, 3505566072, 3191309132, 3572044175, 2602115451, 3033012441, 800527890,
-                3510477063, 1423282174, 2100769564, 3196698162, 2799397548, 1470245130, 3336037253,
-                566503800, 2394765594, 3505566072, 4210137313, 2931631434, 2290957917, 3349282607,
-                312876806, 2020679078, 908723812, 1722789111, 2547505099, 1647430920, 2407789664,
-                3842812286, 985587375, 2447302021, 1006930859,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3238958368, 2110842256, 2032827955, 772576117, 947073865, 2832701057, 389693861,
-                3098952601, 1416961410, 231558824, 4196557831, 1916129333, 3591075737, 89034249,
-                1041573793, 2923916847, 98855571, 539008328, 1986908806, 1279162515, 2211225213,
-                2813831588, 1999361422, 178300745, 1877984003, 681628454, 2788146327, 3238958368,
-                1751825810, 3385470747, 2124156918, 1246150740,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                4107632117, 1367223507, 3208718279, 3005940984, 1228254657, 426039122, 3502826031,
-                2594549728, 3408766276, 4098132378, 683625115, 2169869459, 3524482206, 1669853203,
-                3923745563, 784173425, 938061617, 2113303827, 2167272365, 31710195, 2501101316,
-                2753708002, 4107632117, 693749243, 3619944422, 1367223507, 1410498009, 1371682799,
-                4224490455, 16449175, 4172197689, 2291253858,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3197084809, 3176186221, 3664137841, 678106676, 3197084809, 2959674646, 2599447443,
-                825652056, 1864672969, 1758525151, 1668417382, 110809252, 3056478271, 3889033364,
-                3670201340, 896225077, 3968415273, 1045966447, 962523489, 3962564323, 2282188313,
-                166408156, 1572353635, 255246996, 4122354465, 2485639819, 4128689733, 2298724886,
-                3176186221, 4252581506, 3497708233, 2884415384,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2754675382, 418214211, 45512969, 657543684, 1255824833, 853476929, 3388600753,
-                3658915092, 1870103870, 3641932051, 1363756257, 2832960475, 3451933477, 3230737664,
-                3438296666, 2727139418, 3379699012, 2606558347, 2681229483, 2864417788, 2342959032,
-                822004110, 1703959506, 983403371, 546015224, 1388862168, 1713251262, 418214211,
-                630283312, 1004257132, 2754675382, 1589210789,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2043516357, 1780522796, 2184493156, 3173637943, 1271745338, 4259427576, 85864789,
-                4150770534, 2936233400, 3967147606, 3124875120, 879492365, 2907318539, 1780522796,
-                149103228, 4218957067, 3634976972, 423101655, 120376963, 2043516357, 2506901432,
-                1359147859, 3205492500, 718766996, 1537085791, 534247304, 2738470205, 353762143,
-                1865710521, 3128957850, 3616244261, 1148093688,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                590598692, 1635278729, 1563095247, 1391653728, 2812223093, 1648319119, 12021464,
-                579588626, 158145124, 3000540986, 1678772206, 2053663253, 156140732, 1860655444,
-                2154327930, 3836722104, 355649981, 3635027962, 4258257962, 2790095211, 1635278729,
-                264974628, 3700350726, 2018427636, 287177614, 1093903061, 2920104967, 2107263403,
-                1636752291, 287727514, 590598692, 2776048462,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                705017559, 3167678127, 1383313417, 2817157882, 686937364, 27183357, 1786939614,
-                266498264, 1647594521, 705017559, 3995395887, 3167678127, 2690880107, 1979781994,
-                2743534080, 572024160, 1509452976, 395728063, 2275368325, 3751685207, 2479378944,
-                107705177, 63606389, 350397893, 1917567327, 902494217, 1451891576, 51703742,
-                3882371967, 938828623, 3270580665, 4265619386,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                789190635, 2840954308, 300652785, 237797019, 1158065996, 2840954308, 43572669,
-                4078824200, 1497862378, 789190635, 195354130, 3651717067, 4064808767, 3517940260,
-                1930653394, 397095516, 448109056, 109358851, 96887693, 1845152054, 2681099099,
-                3267914886, 3297414941, 3553101261, 1887993409, 1893422968, 3824003083, 835260748,
-                1789616641, 3566693760, 224104129, 2426965087,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                4075764335, 265622635, 3090721988, 514043887, 2369940863, 2299360631, 4075764335,
-                1089026582, 1273267793, 3844326330, 125830773, 3254454641, 1898792260, 4189821126,
-                3465094616, 966395559, 3141534886, 3958288590, 353573236, 1499446902, 1538373218,
-                265622635, 1562093432, 2023957676, 1034955012, 3582839299, 1832403715, 3445798787,
-                3611365814, 3031109393, 1931962003, 125409453,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2784423581, 541788797, 2269117067, 1754631939, 3219375555, 4005170190, 108168788,
-                2776725983, 420286776, 3990378378, 1966772799, 3408068147, 2712680057, 541788797,
-                3314851019, 711516864, 2355740511, 809355317, 3454502841, 884914329, 3587558622,
-                2920266348, 2462374231, 2957459333, 2784423581, 2817067213, 3429907362, 2142920772,
-                740484442, 260740352, 2323052016, 655540838,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2721733792, 3864758042, 3317153313, 722141348, 3654955385, 1389381565, 2721733792,
-                1176492561, 3971603852, 4213558633, 3864758042, 2385157450, 1776340244, 2476507679,
-                2218271569, 4018502789, 1603876876, 2549344874, 575173241, 362329264, 2968651582,
-                3165596748, 484660598, 4050641324, 2808085006, 3678850415, 724807968, 3929180192,
-                3901882641, 2351857636, 491742853, 207629167,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                2159437792, 1075682363, 1248964115, 2110756455, 490857489, 3074789037, 3562293712,
-                1852857200, 1370330124, 2374466041, 4138785307, 4050351887, 4291023541, 3073812827,
-                2975156995, 3098379669, 1075682363, 3202882825, 3401088381, 4124028493, 2389042760,
-                2305659421, 1453882410, 1936315651, 1792500732, 526647760, 3189867914, 1638760464,
-                418419460, 367539550, 1039767615, 2215175940,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                1379864394, 2513974860, 1555652562, 913064909, 2186522826, 4183894933, 799825764,
-                2137610386, 3500448297, 3372416300, 1616534071, 4014212675, 1415392983, 3336156358,
-                621908429, 3007514455, 750161616, 3316428298, 3597031221, 4111445192, 24752001,
-                1379864394, 2994094519, 3444070761, 2932542821, 2039188566, 3056727169, 3306681258,
-                2001116455, 4006424565, 2641770001, 2513974860,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                998633484, 1349160441, 2125668009, 2668147583, 3563307107, 256484173, 594190033,
-                998633484, 1911085968, 3591089467, 4031916369, 1433956027, 2946367405, 1349160441,
-                2543049999, 4041889288, 1533542282, 2435179260, 2995235035, 2084485768, 2901616625,
-                2748213082, 371767994, 912315214, 2752644209, 4192963083, 382376206, 2838447080,
-                1830424438, 2405859468, 4143841806, 1206446097,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                310441367, 1275555616, 1275555616, 3172568129, 2903841771, 3424676020, 2654755124,
-                3240747131, 2930380118, 92929355, 2503013720, 726139579, 2453726344, 2193085561,
-                3248418301, 3728747560, 184928725, 1500118540, 420213307, 1257013381, 2048737363,
-                3912398476, 2040044577, 4290211080, 3755172878, 310441367, 168554113, 1720267595,
-                2637312927, 240018924, 2197969428, 2959820730,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2776264529, 3219231571, 121558276, 4033458704, 107492474, 2239785914, 2776264529,
-                2565117946, 3116829554, 4204935551, 1999419359, 4088542069, 1753277829, 2929292332,
-                1212974260, 439300748, 774516181, 4226118020, 2137527182, 804771497, 1384488740,
-                1749237654, 3219231571, 4017202740, 1135416262, 540707492, 284967937, 3714043732,
-                289650566, 1576030758, 2680327553, 1364150276,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1506092219, 2472076190, 1445519831, 1476947158, 1915010024, 3307933221, 1183741972,
-                4178992976, 1623708365, 3931351366, 2168976482, 4145238443, 1506092219, 4110433617,
-                2722139823, 3954013298, 3889656224, 2725733187, 1554952638, 1242014021, 1443301016,
-                1083211793, 592580354, 264080488, 725517399, 1829546125, 277165663, 1891265293,
-                685369296, 2472076190, 3331289787, 3039178299,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                516661220, 1759566849, 3538554564, 4200922774, 3337995454, 16430355, 2592876346,
-                1780706089, 456539802, 492591962, 274693053, 741197255, 2742540411, 166478040,
-                2131888862, 2767914581, 516661220, 3173919978, 3862768845, 2302050700, 2199802861,
-                356160645, 2166410019, 1203466345, 1502126116, 3616220925, 3159780593, 1900415557,
-                2611106814, 3360066502, 369212783, 1759566849,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                3096880769, 663567645, 1246105647, 4012007782, 76489782, 551338922, 2292745360,
-                115334918, 2863619028, 2326330098, 3535394715, 1079804119, 3911691572, 61293952,
-                2549667056, 2415575273, 1616051375, 2386723527, 1602885449, 3108100312, 663567645,
-                3069080038, 1639824497, 1583485124, 2068542674, 1389874565, 3922497936, 3955519589,
-                835496620, 1672669304, 3335242373, 275495420,
-            ],
-            rank: 31,
-        },
-        TestMatrix {
-            matrix: [
-                4127597700, 311843044, 52214847, 3182644021, 4023848606, 3894256352, 24407733,
-                1372270531, 3767246144, 1995262818, 2613951877, 949192344, 794868304, 2788592938,
-                3398926212, 2810422976, 770111939, 1155244109, 1983669075, 1866767458, 2916342503,
-                2676920988, 2181542462, 1832811299, 412959568, 860872764, 4127597700, 4084392629,
-                990218464, 311843044, 1440653294, 2658132535,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3101364879, 1048941423, 66931445, 2150509640, 3275238758, 4008004751, 3776689502,
-                2869817034, 3101364879, 3783839643, 3635122635, 3426582215, 3219745946, 2406994207,
-                2050676757, 3298188988, 3187622766, 1817182696, 1235654623, 1976703627, 3397626120,
-                1910547525, 2401316359, 2376680493, 1172694376, 54379605, 772353497, 60062485,
-                3981124581, 3147739838, 2000496154, 1048941423,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2460025613, 1230194534, 2583325454, 221561081, 618595890, 642703098, 1897996011,
-                3993399917, 1170113501, 2113130036, 206864303, 1137965352, 1488981154, 2093692291,
-                1651888989, 2125484531, 1577069213, 2460025613, 1230194534, 481467605, 3072763889,
-                214545157, 699106454, 900974217, 3256495474, 3094559760, 3482685976, 464348404,
-                69740407, 1602000767, 1330964458, 3598875749,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2045993028, 1247508136, 3857529631, 715363100, 4575498, 2058253686, 3330180977,
-                1443281286, 3844904833, 1717354635, 446911749, 3812612221, 547915341, 1789936099,
-                986091935, 1393097676, 3590164522, 211527327, 2827758733, 656506436, 2176199435,
-                49964473, 1247508136, 3771802901, 1489436170, 2603527743, 113618322, 2336950990,
-                2975295917, 554274978, 2045993028, 334141988,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2620539592, 100591530, 2866233734, 2620539592, 78581564, 786331425, 3636976626,
-                1811524531, 1534296804, 2081232564, 3701277603, 3436964882, 1660113716, 266160588,
-                3914147285, 1223023062, 553227612, 3896051229, 964652418, 2913866975, 3720134461,
-                4154247732, 100591530, 1445880296, 2940102347, 1850191931, 1569649676, 3505954235,
-                3475212269, 3013351944, 2782513686, 3939733144,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1991092395, 3271002609, 4224934342, 994933208, 3665661486, 394385021, 1720254883,
-                2458418381, 2164803738, 883962407, 538199376, 1692659990, 3813687562, 1705711457,
-                87460051, 2578497323, 2954704864, 3271002609, 721138043, 1991092395, 4167722094,
-                969424257, 3594877020, 2159248323, 3367149426, 3908228614, 2513049555, 497651895,
-                2064619098, 348260890, 3424348810, 1829727002,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3542080658, 3141118727, 4237051844, 2419354291, 3892133472, 380401873, 3579228857,
-                3901027296, 3668819040, 2984146156, 2605315126, 2878793986, 3248056761, 339588554,
-                3044249183, 3768837841, 3141118727, 418201428, 3136844640, 2913751186, 4264890485,
-                209599660, 3542080658, 4113778613, 4034198644, 2623828640, 3867084572, 588693895,
-                379521579, 2038653778, 444333828, 2523141909,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                4176182033, 1781065287, 174647350, 3084346744, 4047436774, 4142767946, 104578741,
-                13028370, 4112150498, 182105218, 4176182033, 995924133, 822614657, 1555208055,
-                1837410991, 954434110, 1961256810, 916802333, 1788680640, 172297515, 1620592109,
-                122858687, 573529384, 3313668412, 2861849627, 1421992912, 3486033032, 3137731826,
-                1204087275, 1819408227, 4143846342, 3124936981,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                691010811, 2689013216, 2022546604, 1222205280, 1086623820, 3299042741, 1020292822,
-                1658399418, 2781149964, 691010811, 2362399604, 1696028241, 2808409682, 2689013216,
-                3756627761, 1595590639, 273341096, 1383491002, 3194497751, 964519776, 3571893491,
-                2800319892, 3847779571, 134756145, 2094557744, 1735908570, 1319927259, 719043432,
-                2786794232, 2635670127, 107266055, 1692981306,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2001764318, 1027783203, 3988153828, 2001764318, 1253342603, 1448013649, 956909158,
-                2693540424, 1027783203, 1453617195, 1614015130, 105218190, 873451144, 2028151802,
-                3822835374, 2560072865, 2764518395, 4146734558, 386106852, 1588982135, 538284461,
-                2798742733, 105404387, 1244303459, 4001063362, 1339600963, 4049933936, 1133876934,
-                1661145078, 805132369, 3479916724, 1177476872,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2459305515, 1280192319, 2459305515, 3990434945, 2540362494, 2408000212, 2357134435,
-                1069999566, 1288788446, 128244655, 729777252, 67898776, 4049721255, 1999726223,
-                690979420, 35932309, 1632714316, 1384017963, 3590528311, 4008490745, 1249803637,
-                3437110837, 579621169, 1339855877, 1280192319, 223756705, 519869192, 615312346,
-                3203372676, 470401324, 1081900154, 277159431,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                3608141768, 1628888098, 1041676277, 289631764, 823509329, 1968981952, 2147627082,
-                1477660857, 870957633, 4216070710, 922571197, 4035497406, 2243118143, 3645792078,
-                3661062922, 29760652, 3947503088, 2953806277, 2571817576, 3608141768, 207818074,
-                2664161544, 300224361, 879291084, 3683875733, 2707387842, 3254265892, 519658057,
-                1893932582, 3998921788, 3234300685, 1628888098,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3142110051, 2631322403, 1907151942, 417110153, 3570250768, 257551468, 2393312590,
-                3556338325, 1001238930, 1528783512, 781883627, 2279244927, 843968143, 2598204968,
-                461024380, 731910033, 2631322403, 1737146066, 308463149, 1566503837, 3099573282,
-                3142110051, 2698182510, 2482403908, 1015287639, 3187223115, 3995637483, 2942820589,
-                2946633376, 698314319, 2046682642, 4207288618,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                4155503970, 3865300238, 3865300238, 2341763634, 688478772, 2149378425, 1040347896,
-                3975835284, 659565902, 158392322, 4155503970, 443471611, 3989219811, 3583835061,
-                103001499, 4052231685, 961141354, 3689519406, 3191189124, 1917142854, 1281478441,
-                744213981, 495342558, 3007650278, 4192587418, 1970505266, 1005827743, 578528218,
-                642558428, 1441423599, 1594986713, 306684461,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                3265581410, 1446883832, 1407264045, 2993519174, 1244639595, 20514682, 3984379299,
-                3349991745, 2844116488, 4106902667, 210387579, 752471856, 3265581410, 3590188189,
-                2669992222, 2373080728, 1528473916, 2625698695, 1418063831, 4023250196, 2804509621,
-                682225033, 1446883832, 860796312, 57649081, 4085246716, 3149944534, 2227978623,
-                2500139039, 329568485, 1689808434, 2249527256,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1845092036, 472917624, 2779336967, 622481389, 2811373049, 4039921479, 472917624,
-                3280839994, 1455425844, 410020697, 3771999304, 4099761824, 2262942009, 159085826,
-                2565375240, 2574792596, 1322848361, 890100536, 2752246740, 2985932708, 872499957,
-                3072137902, 1550426765, 2415612651, 1649713299, 3459231866, 2707761393, 1845092036,
-                659790911, 1720271454, 4081064365, 2079760176,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3927289849, 107602248, 4080214658, 72773168, 2440094808, 906761801, 575425136,
-                1066342222, 3471207924, 573834982, 891740689, 3850491202, 1077746717, 4288293393,
-                1371972130, 3060043037, 521790908, 65323539, 3927289849, 1360995892, 3151198888,
-                107602248, 2961829863, 1653411510, 51032340, 1286832555, 2798975872, 1661193823,
-                2476248239, 3027213199, 2015292674, 641151622,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                775028823, 3562258405, 775028823, 4235608949, 927723627, 1394047871, 1543586369,
-                1040958718, 4060067782, 1688312251, 296105383, 2245394642, 899694122, 866305882,
-                247867940, 153568275, 2079994576, 1992157062, 1743871097, 3114519830, 3852471300,
-                719158736, 1247724597, 2292057430, 1378590529, 1139072825, 531631930, 3408256137,
-                1146928454, 18504365, 2053436710, 3562258405,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3977156790, 3471890065, 2717147434, 233248835, 4194613693, 916694050, 2518552304,
-                2753633711, 1893035017, 3177020872, 2636889787, 1344782105, 382266594, 1628111549,
-                3643431002, 3261931587, 1735952870, 2796457284, 1234983648, 4229204220, 3467878805,
-                3547293156, 3722794376, 3977156790, 4893943, 3591383263, 959892939, 1915812451,
-                3225210874, 3471890065, 2081313038, 2460134627,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1714455414, 1177564468, 2225949689, 1868171771, 920376973, 3601596846, 454643568,
-                3514756084, 3133407504, 3805793680, 2795720298, 3759222546, 1487869408, 2157498956,
-                162582037, 1235622427, 1001797728, 3068427695, 388276441, 527415010, 1177564468,
-                3397696318, 139534080, 2012591384, 2547479465, 3280132451, 4082540109, 230177594,
-                988394104, 3722123359, 1714455414, 1546020941,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                3343259102, 2815152080, 1057483478, 3838320699, 1059052953, 2745410374, 2816744622,
-                3889415926, 3517402702, 3149758218, 2432803981, 3982250461, 1718471748, 902130444,
-                646867569, 2990001157, 284855355, 2985899207, 3690995856, 1570834789, 3343259102,
-                1273223028, 2815152080, 2676884384, 296801952, 674152328, 723508433, 1166077359,
-                1489786334, 4288514220, 1159842980, 1176626065,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1118629529, 1118629529, 123462355, 3824465157, 798741090, 3303096208, 3964962534,
-                1717267512, 3900671671, 4150934160, 4182450853, 2980856891, 2478194115, 4169859547,
-                844352589, 2478614350, 2106848950, 2926337242, 850422784, 112888606, 123462355,
-                3053669740, 3498875304, 1519577306, 178720063, 1118629529, 647252508, 3453381532,
-                1589267513, 1470471682, 3704134447, 785881807,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                2380254105, 2380254105, 425157478, 786892863, 969245498, 428692725, 817832970,
-                425157478, 3866180607, 4159102834, 241018614, 2892341696, 4009221192, 1646805771,
-                4260822309, 1334815238, 4259613271, 1508775120, 3937683072, 2425859649, 142778938,
-                3010283131, 3701544104, 171146490, 2380254105, 3346089312, 3629214348, 1943785036,
-                1357529040, 1210829493, 1129876215, 160012140,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                715601532, 2459467676, 2654779455, 495592948, 1812652680, 3924539783, 1048846933,
-                1043567153, 916345054, 488963049, 2207569449, 841467054, 1553382832, 3283272692,
-                2676057300, 2018758310, 2419708235, 943396136, 465096845, 3957159091, 4031586549,
-                715601532, 3479933373, 2938338678, 2468708837, 430504770, 2201075578, 2248998649,
-                2459467676, 257200132, 3895038292, 2654779455,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                2301087706, 2169038276, 2301087706, 1550589630, 1045560321, 552638646, 1164605578,
-                2286883395, 2764641448, 3177585856, 1515917164, 1819932196, 794576955, 3773164487,
-                2301087706, 2169038276, 1640967010, 2713480378, 1302483854, 2393794975, 791690198,
-                2236983028, 3152551221, 949499877, 3606870100, 859020247, 2032972395, 16078978,
-                3739156217, 3810180820, 3797975405, 2718490429,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                171605296, 1729724189, 1729724189, 3823082356, 1663836412, 171605296, 2427090118,
-                459318839, 715838074, 3505327931, 3466669489, 311130337, 1332092988, 615576379,
-                3626822286, 3915683116, 1418713482, 1729724189, 235621327, 3496953447, 3211972129,
-                3699420419, 4151350725, 587544835, 1390389974, 791181598, 695699823, 3926346972,
-                3092614823, 3937379379, 1929886469, 3010358057,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                2365802008, 2270971023, 3869489348, 3977826798, 3781779294, 193067958, 2407247878,
-                944108607, 4172315346, 40009995, 3869489348, 2906225409, 1246927060, 1040498735,
-                2739770090, 939935432, 2727543705, 4126229227, 1401920299, 2379572319, 1806465877,
-                1148753913, 2544211581, 2365802008, 357081977, 1700021379, 1190902890, 3110676272,
-                2232826362, 926763543, 2260690299, 1488321060,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                1365245703, 548988358, 661228021, 1296437188, 548988358, 1272151994, 1418640493,
-                511846293, 2139164820, 2960184684, 1795594441, 760076922, 1432388126, 1365245703,
-                3588359389, 1288976983, 1257472542, 978205809, 2884528036, 2849245939, 1301366650,
-                2719831808, 4198180827, 2638628563, 422100799, 526839186, 1160010465, 531694520,
-                105896184, 2392836721, 1018992417, 661228021,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                1811999340, 941535143, 941535143, 1934660883, 849224228, 410061332, 768128174,
-                1093352516, 841061953, 3948144748, 239005857, 4219275247, 1139119152, 515267572,
-                553312412, 1004340612, 2216541031, 2607873025, 2165487558, 3148905878, 3414292965,
-                3582479034, 3883556089, 4080668285, 2493721677, 3740910446, 1501081398, 941535143,
-                1811999340, 243955328, 3386122669, 2979447770,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                697633584, 697633584, 697633584, 3609643004, 3009601105, 2080567275, 1138526983,
-                682598674, 843684143, 1618263295, 4048092060, 1137418885, 3460555876, 3082541985,
-                3426062959, 3320410734, 3179557809, 3853460763, 1996420606, 626284737, 723931119,
-                1791651606, 1999360722, 1312645675, 1091074736, 3129882823, 3937550513, 697633584,
-                3682170048, 3782289640, 3698719210, 3275329031,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                4010191399, 2831607122, 1771379391, 1581023594, 1127524079, 3579205380, 3121968203,
-                1171080138, 1492864412, 196856996, 1024460685, 2831607122, 3409532342, 2327207850,
-                4010191399, 415546311, 2323543982, 442848932, 1841022114, 1771379391, 2448948624,
-                2163990978, 3959050880, 1813838268, 3960859445, 2649697366, 148384215, 719802344,
-                2641783209, 3960676054, 2928632865, 1186311260,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                3100203880, 3678579906, 2259649174, 937981941, 3293016529, 3761022634, 3678579906,
-                1708440938, 3553939206, 2733003285, 1490145058, 2378086412, 4052510388, 2585681899,
-                4241951292, 633399532, 1930395708, 3606794026, 1170364389, 3592188618, 2507293649,
-                1752074533, 4076409266, 860091185, 3100203880, 540337542, 880798712, 785452760,
-                2898840978, 3064289026, 1363016293, 2259649174,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                4136990723, 4136990723, 2489754389, 79555073, 1041878722, 221959613, 1568427241,
-                1503710299, 4097506934, 3136798284, 2989782820, 570212543, 821703884, 628207127,
-                4136990723, 1629747937, 3773748075, 2408520995, 2112966081, 2617613794, 1032489077,
-                2784368227, 3287193174, 2424034089, 127864834, 1029363329, 2489754389, 1646904285,
-                2888551344, 2142642066, 2223519005, 2392808364,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                1979555563, 2012324198, 3549485558, 397449356, 1669392169, 3034757994, 2677977333,
-                506569982, 2897502349, 516901748, 2190764056, 3549485558, 1811322794, 3442718425,
-                3805097287, 2925770586, 3886579956, 2703911620, 2321929696, 2012324198, 2854532548,
-                1248072144, 364552720, 242113525, 795660087, 1979555563, 422153583, 733804220,
-                3976890015, 3679976475, 3838278072, 3414236746,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                450591322, 1032684003, 2738533362, 2036844331, 2020306156, 2579338947, 1032684003,
-                2434702743, 2121268719, 1071004206, 2755849169, 208857429, 2738533362, 3518003608,
-                2972851147, 4049714268, 2717745515, 953731402, 3136262339, 2279788288, 946809364,
-                3429242034, 3495000996, 44770357, 1584867183, 3853985840, 783226276, 1577169535,
-                1650612323, 3891092430, 4065132921, 434935236,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1758142985, 1758142985, 1997171209, 2132781059, 1940918311, 1997171209, 712598016,
-                317722540, 2570827229, 1675720993, 1758142985, 946714266, 4093555689, 2161024047,
-                577525052, 190786286, 3603215825, 2554061054, 3339178712, 1207461050, 518113375,
-                3308202685, 3751814341, 2637437279, 55400821, 4225578814, 3452358385, 3698410395,
-                3006260976, 2688374412, 2358903865, 3073580760,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                3323597922, 2160375918, 1522830838, 2999207011, 2006256307, 966550264, 2026038632,
-                1849777502, 2264208362, 3401170330, 2356156936, 927401975, 269883845, 2342412378,
-                1694228630, 3100989084, 1988835102, 2554081226, 1129100017, 4230522032, 1823544566,
-                2982109857, 27488212, 1231417769, 831171087, 1522830838, 473603911, 3216502137,
-                2602926035, 3659440746, 693903185, 2160375918,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                3062487331, 4081775706, 921455545, 289130474, 1991194774, 3601363332, 3698388997,
-                921455545, 4188715443, 3855625882, 1794348034, 1426375580, 3661608922, 3317776247,
-                1850866567, 1133605311, 3782158518, 1363657409, 2407659078, 3333288462, 2120929418,
-                1885627179, 3728052036, 3472995806, 1859887030, 4191346298, 4154970608, 60840493,
-                4081775706, 1938976619, 120428309, 3062487331,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                1891208041, 2135469005, 2281381938, 471724483, 2546927176, 1124306201, 501869323,
-                1705263097, 954183055, 351771336, 3582966051, 2135469005, 1590448713, 16041360,
-                4047223263, 2381279921, 727653326, 3538849733, 2881124865, 1544744317, 1653019289,
-                3250607877, 421884383, 850451568, 462879133, 368913292, 3704050890, 787643224,
-                1959084572, 2281381938, 4112743501, 1891208041,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                1709668059, 2785320807, 2600612480, 80263392, 2915264122, 4186087585, 3143434899,
-                2598253944, 4288674939, 2718762967, 2960986742, 4166885625, 2785320807, 2600612480,
-                2447024590, 1261574767, 1486714025, 1992139581, 4152655565, 1205212286, 1076385553,
-                771944394, 1849787032, 1953695364, 142065011, 1082944309, 3319950229, 286069536,
-                3399689358, 3745392285, 1709668059, 3850655516,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                1055533817, 364522745, 4147996723, 3051531366, 2900464248, 1970226300, 352916858,
-                4147996723, 431668283, 1796836215, 2266730522, 2006459702, 641084719, 724223780,
-                174312767, 3059852277, 2635911200, 560993380, 2642750418, 380645633, 993289220,
-                636492896, 3203710164, 2863246011, 3362267075, 1874923646, 3518919159, 3176026536,
-                1055533817, 3762627868, 1998106921, 2003891142,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                1281570127, 1327294711, 1281570127, 3720393175, 608807731, 2373668874, 1044331153,
-                961273493, 53525459, 1090920689, 1836088263, 2567291516, 449826191, 3934545332,
-                1243678809, 2220303942, 940735683, 1281570127, 3008140395, 4172285049, 1707326062,
-                1911525007, 1291470204, 2337468445, 324418249, 459747713, 2454347394, 1327294711,
-                3729247083, 2019240586, 471301925, 3449976324,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                2292774325, 2270051607, 628953559, 4284966106, 2292774325, 2959946369, 889013072,
-                1616467897, 3099736898, 700900512, 1076414046, 3178869182, 3506107022, 4127406533,
-                4171900033, 3933895075, 3857217406, 451684610, 3238174998, 1912574001, 4235373718,
-                2270051607, 628953559, 2996308072, 3071380303, 454483777, 2321326200, 1101442197,
-                2089911398, 1458816104, 2016917589, 1918222298,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                136008546, 2597278235, 3906078724, 3956109782, 2987468155, 3093477103, 212824902,
-                1671485137, 3871824009, 3906078724, 3429042598, 2612160034, 2926519147, 3795130684,
-                8279366, 136008546, 731896407, 4215786008, 1629096270, 564704368, 1492665807,
-                3863991912, 1554009403, 4185128389, 950059409, 1343485569, 3248227846, 1311895949,
-                4145387490, 4148363568, 3155151109, 3587947031,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                807249364, 2142148402, 2237571652, 3427607924, 2504728846, 2723567271, 187668173,
-                4260594565, 3411675314, 3571716477, 220367153, 2142148402, 2237571652, 2659553737,
-                2252632146, 2973772092, 2775032030, 3195866240, 2352631570, 462422473, 2473474728,
-                4055509523, 3570871530, 1431895817, 2346133442, 4061324510, 3997647299, 1800285992,
-                2410525549, 807249364, 1036737931, 2689599237,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                3448030050, 1423590343, 380003025, 4042101709, 1071885396, 4017220899, 1777528512,
-                1985926032, 544681546, 4285328972, 1404232744, 1396685937, 2624277828, 286770817,
-                2570688462, 1230409642, 1254344168, 1423590343, 3373038141, 3784063624, 3448030050,
-                724360275, 3861384441, 4072687255, 1934522129, 1392937554, 2437489790, 2474295793,
-                1299569329, 2082251977, 380003025, 3146096024,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                998348414, 2132276004, 877486153, 4285133634, 2382477610, 3488332463, 2354640897,
-                1184638315, 80380461, 877486153, 2733099377, 1442148678, 2132276004, 195604254,
-                1072386816, 998348414, 3186776447, 3202904789, 647879243, 1157801335, 2019704458,
-                1599362857, 1648923559, 810118607, 3183845520, 3815317840, 3940595971, 2553263163,
-                2104425613, 132080360, 634844952, 473941789,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                3177551710, 3852188510, 1732482065, 1399203911, 809177058, 128134208, 3852188510,
-                815784555, 851984300, 544635804, 1776078962, 4258906659, 2849568943, 990568843,
-                898013239, 461943845, 3291047267, 3593596668, 3518477322, 3918720031, 1922458339,
-                3177551710, 1378628734, 66312492, 2222577303, 2443232803, 3323732130, 1732482065,
-                3524095386, 1099618894, 953154887, 4151999424,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                3491712040, 1818949395, 3488792989, 176565424, 3452980941, 3406941936, 677288634,
-                1414525381, 1818949395, 1665775486, 1109253485, 3706966929, 3699686776, 3446214666,
-                432350931, 3488792989, 4016698401, 3584253176, 2572627112, 263322048, 4006389920,
-                1982792148, 611531899, 2485103558, 3154987447, 1143351828, 97316321, 3929315873,
-                4279727842, 3549261336, 3379527850, 3491712040,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                114990501, 3089579800, 2990608350, 3170177759, 2707351579, 2410025328, 114990501,
-                2777305944, 3336536373, 4027714594, 2697061574, 465700297, 4279836317, 2697543590,
-                1708660699, 3538139561, 3075428352, 3595158674, 2755744750, 1167447013, 3417936478,
-                3089579800, 3808145859, 3239891927, 2700691517, 3272805243, 3962535366, 1795474131,
-                227909607, 1893136062, 230446515, 2990608350,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                4143292776, 218205790, 924846034, 2251002978, 2324847035, 178461657, 924846034,
-                2616883339, 2970946741, 1989944955, 3195463985, 2425836451, 182344822, 3348955222,
-                3713067322, 3520589660, 2343697357, 2410192835, 2515300920, 3709522045, 3510383081,
-                3277386491, 471746257, 1932761400, 3396963303, 3567839734, 3834497945, 4143292776,
-                1441603570, 218205790, 1001433322, 1361063433,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                2210995264, 1935640605, 3232722058, 2184892888, 394978833, 817328568, 3716254778,
-                562940416, 2339870727, 196788001, 1935640605, 506916812, 1709285083, 3232722058,
-                2775324205, 2008022182, 4219761868, 664735469, 623205447, 2392547410, 1458553617,
-                2210995264, 4292769896, 131312802, 1313663964, 716481222, 1346091632, 104106695,
-                1539107547, 841049995, 578575133, 2445836583,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                3394323507, 3394323507, 2522609334, 2869157495, 2822099481, 2569714520, 4212252104,
-                3839856562, 3823544788, 2542963724, 1001667256, 988236437, 3394323507, 3458210744,
-                422012406, 2329380696, 3020765550, 802146666, 3535515482, 347859946, 3131172654,
-                2399546846, 4121341488, 2522609334, 2552093004, 3945075667, 1268953709, 2214460907,
-                3969830233, 567973208, 3651506437, 3713515398,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                2986291219, 3323632493, 4195182801, 1172529528, 1276293737, 2461949290, 1338980818,
-                2986291219, 755748430, 2839925180, 485244636, 3379303525, 2730157457, 901200126,
-                3257964200, 3244823007, 1273377526, 2628677492, 3330878657, 222783041, 841853600,
-                4195182801, 123502880, 3323632493, 1657446145, 2407553442, 3835453458, 2760066561,
-                841951539, 3794741570, 1086445227, 755315884,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                1982341646, 1693502147, 594961912, 1190555669, 1588065853, 2036481865, 1982341646,
-                343386938, 3554708578, 1938040090, 3894033044, 755502978, 2584496928, 1782934513,
-                2214239416, 4028652930, 2161902345, 432222439, 594961912, 1663355578, 1693502147,
-                1576285096, 1637188873, 2943093947, 1922665684, 777069280, 124925392, 143044148,
-                3087541805, 402129516, 2819877939, 1430767599,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                930194042, 2378575781, 3363562616, 930194042, 2346298400, 67963858, 2123240780,
-                2149308658, 4056442316, 2940373646, 2783060462, 1597942956, 2378575781, 2895034182,
-                1181242729, 894757511, 963542806, 606915054, 3547920690, 437614472, 2890046204,
-                3337338265, 2934652358, 3470053719, 1409849228, 1890522648, 3363562616, 3916955830,
-                869675015, 2112462258, 4255290018, 3673712858,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                2376823391, 2385786262, 2764043792, 2385786262, 987501756, 2713215095, 2650117146,
-                1989121815, 3705718527, 3663668333, 3124520279, 2572193885, 1581352816, 1518076463,
-                290617194, 2873773217, 851983146, 626070413, 2687782174, 1830157150, 1451739179,
-                4240398280, 3256599952, 609223302, 2241661317, 2764043792, 1648434163, 3849331462,
-                1136209428, 595435196, 2288390111, 2376823391,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                3957903145, 2503789914, 3776874402, 3613538608, 4165538977, 2776693608, 2327128052,
-                3235193989, 3764774671, 1712599332, 2646423097, 883160632, 3332729854, 724165583,
-                962972607, 4022902886, 3563087628, 72829076, 2713272767, 2655785112, 3776874402,
-                2942162974, 2503789914, 384909729, 1049334667, 2946357865, 3957903145, 2264852985,
-                609404145, 1641469465, 1044359442, 250030014,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                2099188460, 2357268378, 2581344659, 1681935620, 2581344659, 3897842651, 2370740283,
-                2099188460, 4113490823, 3651118614, 1433653606, 1784468560, 1021889993, 1029828761,
-                3195667045, 1635206924, 1098352293, 717501741, 2945587340, 2661959215, 3326341032,
-                532292414, 2503581647, 4294869917, 2357268378, 3194621227, 1146313236, 3144814573,
-                3791400126, 760676208, 2992166899, 325364308,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                627153030, 2340738371, 2494855468, 454387780, 2340738371, 3058124092, 1888862111,
-                52946324, 1413848165, 3254964849, 215748556, 2494855468, 4134316963, 3020772967,
-                627153030, 1175618912, 4123697688, 1745483282, 561193733, 318640277, 3221967029,
-                3460153257, 2119751586, 574321930, 1009681510, 10147224, 912235076, 3912685583,
-                1627449922, 4176743763, 2516323617, 3858848122,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                3846293894, 2193778238, 1262707093, 373168963, 4226235947, 743928999, 2225007332,
-                2304585840, 1555621842, 316609835, 3915516364, 2270382325, 3987642477, 1436391270,
-                3168830140, 487600080, 3952154626, 801803194, 1262707093, 2193778238, 1821022211,
-                1560361461, 1669395228, 1529683186, 3240020885, 3231224707, 4032666148, 1470348551,
-                605588193, 3846293894, 3252167172, 268445885,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                3469756874, 3836952493, 447684211, 4011082257, 3715724724, 1287660847, 3905508778,
-                3177672119, 3122024894, 2218114421, 3038788716, 2252381570, 4045575728, 2709804917,
-                825174594, 3140911469, 3469756874, 741140349, 3836952493, 3667316816, 2411544047,
-                2624129051, 2886656867, 3098371574, 1246364621, 1915422046, 447684211, 1499785028,
-                1008956915, 1238318615, 3684771303, 1020872614,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                2360687991, 130402422, 4120520050, 2764869133, 1756408900, 1386746319, 1147139999,
-                216661328, 2580116923, 3214229951, 1824952912, 2711408901, 599593874, 497759425,
-                778577847, 1786170850, 1107114819, 2360687991, 153049291, 4120520050, 185931657,
-                2182278592, 2815867316, 989766135, 1021888688, 3224547170, 3946338097, 767071095,
-                116593186, 130402422, 2844338981, 198908008,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                4145312258, 2816650420, 1061833253, 1761667379, 3225175559, 2816650420, 2346442885,
-                2274411885, 1192082033, 180990720, 2891030710, 2464287786, 2658739807, 3072219225,
-                1061833253, 2322410194, 3165898519, 3833352226, 2872967045, 1972218606, 3541381657,
-                1839337537, 1132966632, 131712123, 4145312258, 212137652, 2331180767, 786801695,
-                2557260195, 3002340667, 1174789600, 3094540921,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                3143669568, 3180431457, 198926577, 1742717715, 169448095, 198926577, 2530464439,
-                128857195, 1985426589, 3180431457, 1747940526, 2237088134, 3143669568, 1149736223,
-                3224039338, 1108346880, 4085514722, 100363269, 3578655240, 1504355751, 979920371,
-                348605891, 2527721171, 475894207, 2592817125, 1816590788, 1916764758, 1027531482,
-                1203933396, 2017239105, 125658136, 579065145,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                2735119553, 605208663, 1464816835, 4152936410, 2006498230, 1875252839, 2931555984,
-                76648534, 1577081190, 440234113, 381156970, 1827147315, 1464816835, 476242521,
-                3309123918, 2210554040, 2214165960, 188437445, 3932309243, 165481842, 285707369,
-                1123776810, 1556812149, 119856495, 477773674, 3804318245, 2847549810, 2735119553,
-                699766918, 933347244, 599725771, 400615947,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                2767106652, 1111005472, 2767106652, 483340125, 2658557591, 989958319, 1111005472,
-                1010236359, 1727625214, 3582981094, 2477846375, 1592635366, 3683103272, 2802150837,
-                354578741, 3202135128, 2767106652, 222157557, 2352685780, 396329452, 3681333662,
-                537880816, 844590446, 2327005690, 3897052505, 4071818590, 520256141, 1623175146,
-                983921931, 956365703, 608715674, 955423334,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                486331738, 3523585693, 1341148536, 3479617091, 2160638898, 3618362454, 2335819326,
-                3928623389, 4291441015, 486331738, 3269765053, 4024174335, 3025574328, 1486152194,
-                303455812, 97601449, 2682184558, 3523585693, 85644933, 1341148536, 182117862,
-                942936213, 2471632906, 1225390545, 553404480, 1713731950, 613392471, 439262042,
-                357222947, 1168844464, 2896723935, 1182549184,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                1136120163, 2225978662, 1472561477, 3868300146, 2827545137, 2304885272, 1481798440,
-                1996133886, 1869291825, 2225978662, 2789834946, 39615729, 2921677052, 2529801273,
-                1173705374, 2428646564, 1103450504, 2228071185, 1641800950, 1472561477, 2214213736,
-                1271501121, 1136120163, 4115681367, 3289703198, 2852189523, 2210683586, 3332456262,
-                3753063048, 2504385299, 3399628568, 4017807054,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                1572062346, 3518408649, 295461367, 609969820, 863956878, 2558298638, 1870358545,
-                243194493, 4268517781, 907925591, 157212275, 1316998491, 1572062346, 2849224626,
-                3465754812, 572474802, 2618527784, 3647430993, 2227527117, 356344115, 3518408649,
-                1508303481, 509388513, 422242202, 208078673, 3399790767, 734895422, 437901242,
-                3381127612, 295461367, 2287875719, 2297958510,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                3528139344, 3756733998, 3012157687, 3040147414, 1267576978, 2418134508, 2504400405,
-                3137295718, 1912647396, 1604979765, 3528139344, 2422171674, 3756733998, 639316208,
-                1323017503, 2921062908, 2225930221, 65187386, 2365341930, 3012157687, 3361194349,
-                3123291825, 1363598506, 469085732, 933711444, 4003543, 2397724043, 4081087753,
-                3471517469, 2184847586, 2039207077, 4185749421,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                2614058664, 1981172288, 1311750680, 3972175717, 2099042872, 3738430544, 1311750680,
-                4127173295, 386329039, 4275233346, 1935298664, 1355117136, 2876209693, 4105248043,
-                2614058664, 494355422, 958019651, 2758211755, 4123241307, 164515955, 2932482246,
-                1981172288, 1928534132, 465110649, 1468197199, 1934601615, 845956781, 912684993,
-                4150056266, 2751218954, 2319489881, 4249710927,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                3275485720, 3647334377, 246286261, 560436503, 1092105444, 3647334377, 4144702687,
-                2626506409, 4043146195, 246286261, 1899145580, 1934304626, 730093339, 4036528844,
-                1671624444, 1663665582, 767579019, 3275485720, 4287107096, 2648812588, 4177290285,
-                3417841345, 625147568, 4015573145, 1635212056, 762626054, 1631783236, 4182231964,
-                624047155, 1130907260, 1177488323, 2737142705,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                3187099707, 3267224273, 3267224273, 1695985500, 2506118716, 205290525, 2517733637,
-                1278008328, 2883452673, 16241746, 645215586, 3894945676, 3491441972, 3398386547,
-                3433821164, 4244946506, 3804794198, 3267224273, 3699111414, 1114941482, 2465753709,
-                1322058927, 167844201, 3153988707, 3467460006, 1313126949, 3888715451, 2075018075,
-                3913676885, 729191269, 1059704539, 3297504618,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                923378817, 2044328823, 928628833, 719550383, 1548282929, 928628833, 338533682,
-                4146013693, 390991823, 3301755831, 2044328823, 2691827518, 430581534, 1139417929,
-                3316742474, 344408092, 2753465477, 4104172105, 2909326417, 923378817, 3534531940,
-                2137397748, 2246209949, 4106626905, 1435795123, 2317862998, 2931348068, 712014773,
-                1624884641, 281278392, 1339789388, 716555772,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                1870169794, 4050123857, 1409947507, 1460460746, 747938171, 4215678454, 1999288403,
-                928068264, 3688982140, 3699000870, 579965232, 453574267, 534737396, 389834890,
-                4276360586, 3127779777, 2825282426, 455594922, 1660169245, 3753715248, 2365066619,
-                1870169794, 1409947507, 755753605, 2414198743, 1332066247, 75741959, 2581749208,
-                1612916873, 4261257555, 4050123857, 1511130163,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                3192642168, 443861270, 3100847806, 3100847806, 2029774112, 812619295, 1310635504,
-                149689623, 1840635495, 4146562356, 3440813417, 1583465840, 383614123, 518754766,
-                2770158131, 1533794587, 443861270, 667563319, 2537625740, 1826241632, 2512332343,
-                3764688793, 1474061706, 3787092307, 3192642168, 1526906696, 1234010187, 854215452,
-                1417508852, 3005153985, 4009770460, 1532352695,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                4144716243, 800131174, 764860022, 1159776580, 2140341470, 4144716243, 1712452484,
-                3938837674, 4072943187, 3569452823, 1290733798, 4167413804, 4109256609, 798569856,
-                1916989745, 3825919492, 2145020362, 2340926427, 1460005659, 919704451, 3175370323,
-                1418834936, 2875858547, 2493876932, 800131174, 2611772593, 1297798933, 1265998977,
-                764860022, 3636344834, 367359144, 416461263,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                852767881, 196537798, 2743445935, 876774391, 1724665946, 852767881, 196537798,
-                3504817630, 4246875841, 1690946064, 1463382587, 524285969, 2406475330, 2451122217,
-                3504336994, 1473532774, 553891054, 4114379832, 2981744001, 4134254589, 2743445935,
-                959284057, 2166780890, 126781620, 2055046382, 2978918003, 2832963068, 1840869584,
-                1389077575, 3343027154, 2025415946, 3699600617,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                757660906, 2792523234, 3478820246, 2628748588, 3549550331, 1663183470, 1984115918,
-                816984046, 2953311685, 1195259477, 2620674787, 1127949292, 4013820796, 2207449914,
-                3290217404, 2465123324, 2792523234, 1979295080, 2108555253, 1630073203, 2399421110,
-                1056200766, 999352302, 2453258479, 2248304820, 3478820246, 488364691, 3905883066,
-                744864630, 3490342240, 2630698791, 1083767008,
-            ],
-            rank: 30,
-        },
-        TestMatrix {
-            matrix: [
-                246145772, 127748772, 1228676169, 2872428813, 258111533, 1313936396, 3465068961,
-                393879679, 2290564003, 739835730, 1338135180, 3348177508, 1963629330, 3441512589,
-                127748772, 702698433, 3837615283, 1647651055, 234573577, 2927848384, 3030036775,
-                1351415475, 640354887, 246145772, 1322613636, 4074235113, 4201609413, 3461001429,
-                1222328524, 3915575593, 1443007125, 1228676169,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                2971579207, 927776112, 1473580954, 3954930968, 3094814336, 1941549245, 1270978820,
-                1911519435, 2554316805, 2906536405, 2765104630, 1361907037, 1473580954, 2971579207,
-                3532721548, 2715395117, 927776112, 1447255092, 4147762709, 2648260893, 3935676039,
-                3488034384, 3879573847, 1933797552, 2821738719, 232272911, 3801683273, 1948460653,
-                2378533903, 3628324297, 1166394879, 1630015769,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                3813759561, 2434092711, 3813759561, 445948877, 1592933871, 191510952, 2170948199,
-                3963240075, 1822623115, 493529062, 380318212, 2975840657, 1799711813, 1909949987,
-                3206958674, 763004636, 3530846797, 1935426070, 3310240973, 865561153, 613606970,
-                144635808, 659571282, 1159328217, 2434092711, 1548058221, 1876321052, 1090651903,
-                889413879, 841973052, 3551722968, 3813759561,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                2155910873, 4198855571, 3562160928, 2472969647, 2389785354, 2605297340, 4198855571,
-                2999010020, 3354759450, 673190702, 2155910873, 1637290433, 466902271, 3588511963,
-                2360685828, 1901610426, 2210752172, 1831918558, 418705966, 3757965085, 755195647,
-                822812091, 4014855592, 515215717, 162509010, 3562160928, 3412591906, 3764307519,
-                1532777710, 3239677752, 1220208137, 3086613321,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                2733886779, 3068773036, 2018796030, 3939976531, 174410837, 2711760208, 2896337383,
-                3665087295, 975950821, 928043107, 550765740, 4119902769, 2758282691, 1319916621,
-                3059780567, 4128287006, 2733886779, 3640621583, 2018796030, 3947090183, 898702386,
-                3474000987, 620463316, 3171127356, 3037201287, 2268193650, 3068773036, 36700835,
-                3652736121, 2274660967, 2974235981, 3268581247,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                4229638718, 49827639, 2218759366, 2892447137, 206435784, 3934937381, 2590017989,
-                4229638718, 3780749433, 1846518648, 636536644, 1439062507, 428594773, 4144112620,
-                2178703295, 2404040668, 3069105458, 2218759366, 2285072138, 74022229, 3770503636,
-                2892447137, 1742415112, 3515889529, 1416714047, 3136794731, 2365916666, 2153564065,
-                248318551, 1015121531, 2957000985, 4097369622,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                2695873577, 1487953201, 2695873577, 2508276308, 2220202353, 1487953201, 2132256623,
-                2242456057, 66436598, 966228090, 4066850705, 1920560886, 2837887503, 3513318676,
-                2508276308, 1208459998, 3524309476, 1597681361, 205154273, 269627744, 4134462601,
-                2695873577, 2733543232, 1807998780, 1031993472, 1353365928, 3464118811, 884512290,
-                3221153837, 3543385170, 373764096, 1807127864,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                1848045002, 2471038946, 440286298, 143738685, 1848045002, 1225960350, 440286298,
-                862597984, 3901606121, 3252031492, 3378633502, 143738685, 723614971, 1374237854,
-                1828574583, 3483724335, 2920747041, 4063734452, 2788638870, 1546774810, 1391789879,
-                3622957235, 2593045534, 2471038946, 2179735532, 2767257267, 4078972081, 1731609560,
-                85984710, 2022303617, 1374838728, 3887928321,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                1041252124, 1041252124, 3321487592, 2317254548, 2312480773, 468204616, 2513629050,
-                1011002923, 2317254548, 12604269, 1248250475, 2591311969, 3321487592, 407269239,
-                2632101610, 1994090810, 3229591052, 2016852657, 2908419093, 360046956, 3044146831,
-                3926848519, 3278463057, 3769242479, 1178167799, 1041252124, 1999594406, 845178802,
-                1018135787, 2439651652, 235814995, 2942026819,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                1826379522, 2483815468, 4117213176, 1826379522, 456009346, 2818388250, 4003216436,
-                1300295217, 556517265, 998968548, 2537991091, 4093706779, 4115497877, 2935430470,
-                4117213176, 3523879714, 2233346880, 2285540203, 3688358506, 4076139607, 1171248987,
-                2483815468, 722487730, 1826379522, 2080193044, 1175806018, 3727996822, 4258970160,
-                453034783, 3219079428, 1863222507, 1632713569,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                4219575138, 3937600114, 2808418075, 497649458, 2539000033, 3298308691, 1532328336,
-                2110554542, 2164598045, 3866491703, 2525018563, 497649458, 4219575138, 2560335860,
-                2009856407, 1586204980, 2808418075, 2199748773, 3937600114, 1984704500, 2383985403,
-                3494227944, 756682050, 1331776374, 1980822186, 3921156488, 2879457254, 3495642785,
-                4135740650, 3835282104, 3787724222, 3350130744,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                4046810004, 2235907311, 3582151776, 327011912, 1830275345, 4171562290, 3725569494,
-                3737959765, 4119267262, 2284006707, 3993884507, 3749079149, 1562794608, 4184662661,
-                2338078923, 2235907311, 948461875, 1711355639, 3837811882, 1174072544, 204570084,
-                327011912, 397006815, 3582151776, 2884182589, 6252687, 4046810004, 3580613775,
-                1200209186, 2427207992, 3734666849, 1860087396,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                941501976, 1367838428, 211535100, 491332897, 4219252848, 486154589, 754153993,
-                211535100, 1367838428, 2924365477, 3609116503, 2795699154, 1033962686, 1666257499,
-                1275774918, 3857415197, 756090048, 1920427272, 1351801349, 3391237297, 1616119870,
-                941501976, 3773688332, 2346652030, 814090543, 2950636380, 4058688216, 491332897,
-                2421585623, 669144300, 2579057210, 3284060620,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                2223046779, 2322013647, 2631181660, 1940350802, 1940350802, 2232095568, 1299368667,
-                2631181660, 3934452159, 1764614719, 2223046779, 238794525, 1771439064, 676199241,
-                3988285404, 1746789962, 1240056950, 3618442967, 2522237303, 577961505, 2322013647,
-                794215220, 45880198, 2499827549, 2197521122, 1415059145, 2046315144, 3157526762,
-                2968049524, 4063784115, 906693520, 3287116824,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                879309131, 878655039, 910348958, 3669759757, 2819230378, 1117067738, 3285360680,
-                2287246112, 878655039, 3461215423, 1862298366, 3440488259, 1623584496, 2020016339,
-                1281793765, 1069025855, 376183594, 914819492, 3942334136, 3253294169, 1310513718,
-                1522774634, 2154263454, 120416133, 2948079559, 910348958, 984015087, 1514801813,
-                2094317434, 541665102, 3679365817, 1698234468,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                2923475335, 4026789108, 2131856233, 4284899095, 4273336105, 4284899095, 725009475,
-                955408036, 3125554802, 1097219780, 2604179191, 1751684441, 3626639047, 4169784268,
-                3078857029, 1947256376, 1461288973, 2131856233, 2556399983, 668738547, 3481483210,
-                2136469064, 1489161583, 3973131031, 2545371392, 4026789108, 2866008278, 2757586693,
-                2923475335, 1232791473, 690937505, 3929096396,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                216535982, 3279508216, 351554090, 56293371, 2304001230, 1794331111, 1437433290,
-                2849448700, 3040838658, 3805850821, 2789926936, 845115867, 3279508216, 351554090,
-                2053997423, 1440236316, 4187271264, 2251190426, 56293371, 3524702730, 955688029,
-                4101159340, 478943123, 2242792009, 1647082702, 3259390584, 3873418932, 2217907607,
-                3600867507, 216535982, 1422489784, 205111707,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                2030351837, 323785142, 4057331024, 854374356, 4011790848, 4093759321, 2085385893,
-                4120927313, 3652928581, 1970266203, 323785142, 3633499885, 3190944261, 1309378243,
-                3537261188, 488238389, 4057331024, 1233781634, 854374356, 1894907477, 2067710960,
-                3915771713, 1069582908, 2739338941, 346499012, 2552721863, 2030351837, 765398489,
-                1406090719, 845733477, 718170678, 3674015196,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                2107676758, 1506141564, 1250369999, 1253211585, 3941657359, 3866815530, 2257772947,
-                1830987898, 1250369999, 3599272425, 3544221458, 3085752343, 705009206, 3859811731,
-                1612152172, 2107676758, 658958059, 2440855379, 3114657556, 1539884255, 3509951156,
-                1432678357, 2703059631, 220434390, 1253211585, 3921188506, 2533069199, 1506141564,
-                3377849976, 822444655, 147703525, 163876060,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                3898404131, 1711354928, 917890298, 3568011747, 518323426, 3792057599, 452807882,
-                3898404131, 4090443438, 3057936681, 3755086795, 2904115337, 796384756, 3239050276,
-                250657184, 802233606, 1070813776, 354882946, 564456669, 1276231039, 3640180899,
-                2145376133, 1529001810, 3713803248, 2135001622, 2290378711, 3568011747, 3411218393,
-                4030087828, 1711354928, 2089362635, 1616498506,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                2768150787, 3307868571, 1592611262, 1204240919, 2482526883, 3377092898, 516577996,
-                2280841968, 3143649242, 2987564688, 1345320672, 529105756, 2159849186, 2090741981,
-                724006123, 3416753356, 3777409022, 3863279277, 797953922, 935580413, 3013764113,
-                13946953, 4033322757, 557236981, 1592611262, 1204240919, 3484401189, 677348529,
-                832159556, 2768150787, 427720071, 3307868571,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                2017112413, 1707144502, 4196577611, 2017112413, 3125373394, 1183795538, 3466078788,
-                2740140930, 47865930, 1342731143, 4143545563, 3381946608, 1874442415, 475953494,
-                3906602215, 1604852716, 2845390537, 3834766996, 898519963, 3147574671, 1326947348,
-                990907213, 992548238, 2017112413, 1930487369, 3239419895, 1143403700, 2878845546,
-                4196577611, 2956326248, 4215474949, 2326712226,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                1771100715, 3381887490, 2742056003, 2533493156, 1000460473, 2912455776, 2300353694,
-                773988105, 486708042, 3161206872, 4204062260, 414606800, 1316228945, 2441268221,
-                3826442698, 2742056003, 3964809089, 1711715178, 1775235038, 940501996, 648253328,
-                25868451, 743935831, 3790243833, 2057761390, 1771100715, 2271055443, 2709727924,
-                2533493156, 1319287123, 2648572205, 3736999197,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                539752918, 3543357530, 3181369539, 795541329, 1724781286, 3543357530, 2462594405,
-                1348061447, 3754664229, 2509917127, 1904976549, 3181369539, 1264110818, 335887730,
-                2942765742, 795541329, 1346841549, 3569948711, 1795256942, 539752918, 4268739425,
-                2594229306, 3874001813, 2017937679, 2756841575, 2684459454, 2339439768, 4147255190,
-                2052496383, 203829309, 1771077044, 251599920,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                2941632235, 2695067697, 3136546401, 2941632235, 889380942, 2600863591, 2250390219,
-                2705868482, 3136546401, 3899432125, 2394793012, 232372520, 3991183440, 4182500134,
-                30947849, 2623403222, 3523110720, 1169380980, 2446403421, 3274492927, 1988860542,
-                2087036859, 3487683402, 1392052446, 3542599221, 3915018199, 1393753988, 1444638304,
-                3917266812, 2941632235, 3574216649, 843549190,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                202303281, 1234255497, 3193256900, 1234255497, 2731092309, 868665130, 1122038122,
-                3014391400, 2296811074, 3193256900, 2630020358, 1404173566, 3411260439, 523863648,
-                4210854190, 495379304, 3526202521, 3538444610, 22567572, 454567570, 2013319210,
-                1341632221, 1164950880, 3165657291, 546170794, 2106004445, 202303281, 2121008177,
-                1507713968, 1234255497, 3148739877, 2811079976,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                1738713383, 61710556, 3287702168, 1232655796, 1232655796, 3757452251, 3253668220,
-                2146166664, 1570501233, 61710556, 16652752, 2760992008, 1652095272, 1738713383,
-                2881397686, 522480016, 880656715, 434435193, 1731725454, 3675194927, 2356577061,
-                2622139672, 4282985422, 3143741289, 2281140388, 3378295903, 1198930834, 2358203949,
-                17634141, 3287702168, 2039752687, 2124177380,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                3824315212, 3269048518, 3939798701, 89203797, 3353681474, 3948518840, 2923967084,
-                3865763108, 3345432652, 824092910, 3791757476, 163328978, 3008380809, 3325472350,
-                3824315212, 727452178, 2368722710, 1927609540, 1803476530, 1413476110, 3939798701,
-                89203797, 3292285131, 3397857385, 3802386469, 1854759875, 2800877424, 1115590417,
-                3269048518, 4242461631, 3100901548, 3696791637,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                1991794234, 3277716522, 753511812, 1634693425, 543472574, 4127079177, 434351506,
-                1359552247, 1150518056, 3277716522, 753511812, 314309840, 1189104734, 1377938533,
-                2380461151, 208196290, 1634693425, 4029631101, 1583194831, 292605309, 696851463,
-                3217172426, 4287650399, 2005859462, 3663308738, 597736135, 878500740, 177891207,
-                3971933736, 2764381044, 2550562478, 1991794234,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                3010524869, 1927070774, 2884127378, 1095011246, 227537520, 1689671491, 4124602442,
-                1035378365, 2362333879, 515735492, 4265734938, 117652317, 2114657352, 1069193070,
-                2491029842, 2365120366, 1379655658, 2455492118, 126695735, 2884127378, 1095011246,
-                2421355385, 4187356369, 301807068, 3582649887, 1927070774, 1878245147, 3598286653,
-                4273904010, 781763471, 3010524869, 4292749187,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                84445722, 4203457257, 2858950529, 694667134, 1186819940, 3635563782, 3624647585,
-                420858268, 2858950529, 84445722, 1367048444, 714441202, 1802119872, 897012807,
-                2590494147, 4180598469, 2270525458, 2959992141, 1756917815, 2003365850, 1883837911,
-                4203457257, 186679443, 1753812370, 694667134, 1350912827, 355750299, 3825451533,
-                503995549, 4137021055, 3989727121, 3166135699,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                2850574256, 3555323190, 2942023924, 3731215124, 3789759196, 369919677, 2504599880,
-                2256189748, 2114909684, 939407905, 2827897230, 3731215124, 1433255367, 856929748,
-                4105828395, 1612126445, 3316422025, 2942023924, 385018155, 3555323190, 1189492015,
-                2218163096, 1504322348, 954353283, 4074746834, 332382511, 3582140986, 3186866289,
-                3635628975, 2804439761, 283886328, 1030650842,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                4114151098, 1835199097, 496822725, 4156067749, 3747966817, 325808851, 752413227,
-                1215927085, 1096856587, 4114151098, 3932408287, 273866528, 754911903, 1183275661,
-                1816623276, 4156067749, 1031291186, 194593398, 1383793544, 3493256628, 785445548,
-                3710020816, 496822725, 274590148, 4284317791, 3569725813, 1697247482, 952368080,
-                1470090464, 3227363460, 1835199097, 522091847,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                2730809120, 2349460220, 925671569, 430267986, 943842498, 2349460220, 3494898338,
-                851761860, 3246901036, 1296774667, 464477469, 1072928681, 1961944641, 1697146203,
-                430267986, 3145324290, 3838035306, 925671569, 4243351044, 2042708276, 2870197976,
-                3769518858, 2416817152, 494706116, 497227955, 1698794184, 3142144380, 2106275151,
-                164538675, 118829137, 888825909, 3523919315,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                1384806899, 583867211, 2962475928, 28660824, 1384806899, 40322570, 1770743229,
-                3778653119, 3384261249, 583867211, 1897536100, 2332958015, 1038489810, 1565910737,
-                572611471, 28660824, 456788005, 1142510924, 3282172256, 427534730, 3346383315,
-                2440637179, 2951377957, 3714685572, 51393984, 2962475928, 880865487, 3065443461,
-                2519032334, 1562947758, 1674345071, 4169730394,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                1734129197, 1635159219, 4007971073, 4154801805, 4260296026, 3650107495, 2122031018,
-                4111143107, 1499130287, 1260883823, 1881731227, 196168441, 1393165310, 2622148355,
-                640999864, 4146247413, 4256604902, 374202732, 4178950166, 3967762476, 2580258019,
-                2343031613, 2481738803, 2740443805, 4154801805, 1734129197, 968844056, 1635159219,
-                3279200922, 238324518, 1549386633, 4007971073,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                2564404490, 320517681, 2481257985, 2090744163, 454458538, 4067364687, 3364676335,
-                1444821538, 3754633703, 2481257985, 3370516200, 4018863768, 641228357, 4231014380,
-                2090744163, 3410916104, 3990572870, 3873225551, 1317945014, 4139212966, 3324429250,
-                2564404490, 3572263937, 1390745808, 148712157, 630711937, 49222220, 320517681,
-                2934886792, 2093290851, 3128287117, 1966390808,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                1198633979, 1354214464, 2636878686, 2636878686, 2162336858, 1571959290, 2793224757,
-                2515047698, 2636878686, 377935600, 2586293024, 597831187, 2220276197, 1742505489,
-                2140982080, 2609027473, 3533979367, 2357969599, 1198633979, 1609313750, 3250058756,
-                2549777908, 3426520844, 4011522674, 571752713, 2554926695, 1354214464, 4072187754,
-                2974845070, 865880380, 2590769051, 3791401554,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                3191236965, 3262330213, 3412470100, 1728952271, 1104666480, 1231850662, 754646240,
-                3133502695, 3412470100, 1963010431, 2543733488, 2003294969, 1297720499, 3277935101,
-                3691430548, 3030786560, 3126326789, 3280373523, 1858228304, 3787276803, 2006441248,
-                941053913, 1597600458, 1728952271, 4102594171, 3860934839, 3171694629, 211948956,
-                3908464383, 187351600, 1656897626, 3191236965,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                4201971893, 723052289, 532470884, 4201971893, 2979511550, 2129857765, 532470884,
-                2016602263, 3580493722, 2612632495, 934331274, 723052289, 4201971893, 2380268555,
-                2315953486, 1723819687, 4065339616, 3844325228, 2032227031, 1414439115, 504725655,
-                1907901560, 348644775, 193430299, 831108169, 2494178014, 1253722844, 940496170,
-                3137528040, 2743087570, 1711800496, 4150119648,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                1241133938, 366947161, 3145294206, 3959228732, 2925461231, 366947161, 1100772370,
-                3635154298, 720028300, 1838308111, 359833179, 3767547651, 3894799583, 3145294206,
-                3670386710, 680260419, 3365775062, 2735378040, 1877589792, 1779252404, 457194609,
-                374778886, 4154714736, 358155632, 303715808, 1782940111, 1974431088, 2283910279,
-                4057805378, 3447938271, 3959228732, 1222362591,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                2935065265, 2084929535, 2084929535, 186025898, 2084929535, 1497187206, 2458468541,
-                2098359083, 2287923242, 2935065265, 2116051806, 829365203, 1334842194, 2098630684,
-                3697163795, 16447073, 1882961475, 2263998053, 2174459537, 2940925554, 1374103403,
-                186025898, 1289715029, 3527150917, 547355197, 1487241666, 1271109535, 4281871288,
-                3360953465, 2107684283, 53709619, 935771360,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                3190800402, 767891197, 3805869989, 2596928332, 89927901, 2596928332, 3848173145,
-                767891197, 427673503, 3747346761, 4215113964, 3071542159, 1793313167, 752938940,
-                2826741553, 1938494749, 2054927400, 3898457008, 4143703258, 2197187624, 3805869989,
-                3198216426, 1523486652, 1176393868, 1844565281, 3273406120, 1444575319, 3027491005,
-                4161418334, 2269891923, 4132985302, 3190800402,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                3084668008, 3265020341, 3677741905, 2405676758, 4209976940, 451774341, 2646884213,
-                3265020341, 2405676758, 3677741905, 1634972949, 3220769985, 2155537800, 2737353749,
-                3102740585, 78828202, 664614365, 3406776568, 3527148698, 161843846, 806940117,
-                2984507446, 702452461, 903274380, 4266684632, 3312995595, 972815102, 3441793905,
-                3084668008, 3840541138, 2896926312, 845605812,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                3655928005, 3655928005, 624130504, 1718931429, 1777136628, 2793784270, 969963259,
-                2979408902, 3868853575, 3919504243, 2337878368, 4046605422, 1573490908, 1718931429,
-                2734578638, 3655928005, 3349839025, 2588471434, 2480987070, 1244355327, 3336259304,
-                970307078, 1297628617, 904665921, 2571761308, 237090199, 2876395335, 624130504,
-                1021591695, 1360608352, 896959820, 1178346502,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                400425688, 918653225, 1089336389, 1930498422, 1759416330, 3793305541, 3593521197,
-                1089336389, 1894062861, 807803960, 2527167036, 572537785, 3368573286, 1930498422,
-                3262548642, 792450322, 1995415294, 2942286666, 3224314135, 1645231047, 2865653021,
-                2591299138, 1870005410, 3240031706, 918653225, 829731219, 1036734970, 400425688,
-                1645666383, 775447115, 2489292692, 4287881283,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                3825483977, 629338308, 1699579077, 624518997, 2283592955, 1215252465, 566651479,
-                2452485961, 537864676, 2979899657, 2378450958, 1927459284, 140438935, 629338308,
-                1060027651, 1019259068, 1186850467, 415514765, 3825483977, 743938088, 3833716902,
-                4293537067, 624518997, 4018090700, 2285149527, 3810947227, 1699579077, 201117454,
-                4042431847, 1691015840, 954758913, 3510222550,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                221083993, 275534980, 2031657135, 2304128316, 3350635714, 3322078532, 521077064,
-                3949100281, 3414918560, 1985773909, 876216072, 3773378543, 2247294589, 422650943,
-                717620526, 3573486483, 1463586773, 2388689787, 1887902370, 221083993, 1941198316,
-                2031657135, 2844038608, 275534980, 2655196416, 2217325125, 245162400, 4057818834,
-                3506342436, 2304128316, 2172053082, 265062899,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                3753567574, 870801352, 2648407692, 3882851918, 191472072, 1587768327, 2221891329,
-                4139229713, 660650568, 2117787027, 3551887797, 483320910, 3558211233, 2648407692,
-                3773305057, 1928584487, 842991486, 4194956788, 428521867, 2381868567, 3689036052,
-                3957785825, 3753567574, 870801352, 3684355932, 2059396764, 3763140130, 1853091985,
-                2047891561, 1722154599, 3882851918, 1009290606,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                743340425, 2086759816, 1344738730, 1381260242, 4122491659, 127759351, 308462641,
-                1459229530, 1171838600, 3520538658, 4158809401, 809708174, 3588030930, 2086759816,
-                3568258530, 4068533920, 1094037215, 115771644, 1381260242, 13292165, 3209174171,
-                1413828900, 752097149, 1344738730, 3250508098, 2132165952, 1289270502, 2925035035,
-                3332955045, 3538620360, 2012564629, 3535102988,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                543088106, 2649955872, 2735802295, 617724810, 1473782480, 543088106, 1911589590,
-                2987296098, 2231967427, 1140397276, 4255498318, 2080794727, 1860297687, 3743395745,
-                3721330395, 2862550277, 1296149258, 649992731, 4194499672, 304765965, 533169397,
-                3038753565, 1453311205, 1102949096, 3587541840, 617724810, 546543672, 3597997480,
-                895885134, 2735802295, 3542274053, 1638078838,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                658335696, 3955622007, 1955428887, 2848666345, 256470024, 896670548, 1170774924,
-                4071307157, 4190809366, 2848666345, 3874222299, 2916778871, 2299077177, 4119351888,
-                74039877, 4184918220, 828043754, 113031444, 658335696, 3664452601, 3568291263,
-                1300878228, 1344952885, 2983007249, 237749883, 1955428887, 127936757, 660509758,
-                1656526162, 2629195176, 3955622007, 1541408998,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                2068542800, 1876599421, 45842132, 1671790658, 3863725599, 2660149300, 2393880645,
-                2409713959, 1571407200, 1300139625, 3005529719, 4192694316, 4211687356, 1637255196,
-                3512062713, 459274236, 2068542800, 3165523224, 1593579739, 2021824404, 1833887344,
-                45842132, 539352958, 2759441524, 1671790658, 1495202728, 1742036924, 349853372,
-                1876599421, 3869227751, 555523944, 151847561,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                3527951958, 224735263, 175660970, 969656639, 344932600, 3874639803, 175660970,
-                584952615, 2954988082, 1660342488, 1187091337, 199250182, 2885190462, 486348168,
-                724724672, 6530771, 4138702982, 4284649421, 587304704, 224735263, 3190896869,
-                1893269929, 1318499566, 1265216200, 253678753, 969656639, 389856778, 1347519358,
-                3527951958, 1873843864, 2939364017, 663688877,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                758153072, 2211098729, 3829549441, 2016535731, 780077327, 2478317644, 3829549441,
-                758153072, 2211098729, 3168545784, 451367368, 3422257019, 2016535731, 3287627881,
-                4165171574, 2092858102, 3189215330, 1102164089, 2199120812, 2558584386, 1766606691,
-                3615684789, 1646356487, 1786027047, 341039233, 2995949474, 2538971456, 847672325,
-                229844158, 3052455378, 531953352, 981222091,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                1522480459, 844679853, 844679853, 2136647552, 3772671678, 3299964369, 844679853,
-                870575551, 2136647552, 60984552, 3213449731, 2745518592, 2113045594, 141827189,
-                1716314997, 466022083, 335548162, 2859874243, 1308816030, 4051475123, 2071287085,
-                2470416889, 3973740501, 2229887581, 2421794437, 1394061523, 1522480459, 38700884,
-                3168503390, 3910648133, 1342436837, 3557578420,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                2606187577, 2422925603, 3015986487, 2422925603, 686183339, 2304355138, 578286597,
-                848656460, 2606187577, 3632761060, 1742066078, 326104224, 1942252665, 543510391,
-                4293288830, 1547562599, 3015986487, 744043225, 2413399996, 2552881182, 2459231590,
-                3859435468, 474428422, 3300678040, 3517283385, 3426361215, 2947806350, 2974453554,
-                3585431142, 3237129664, 4249151597, 3493139304,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                3423126181, 810498789, 924553896, 2757951234, 2116867235, 3353289725, 2671546703,
-                2572931753, 2258912854, 4239713222, 798674536, 992834187, 2757951234, 3878419871,
-                4027133546, 2842041815, 3134742127, 2450025951, 3254911001, 585351780, 488580567,
-                4052018359, 3537724905, 810498789, 3804927669, 1086512328, 924553896, 252238042,
-                4071588270, 1641573983, 733136460, 3702858936,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                921481281, 765087510, 944628485, 334040358, 792505146, 3995944680, 3107629651,
-                589725855, 4141133519, 765087510, 2313708799, 1790123831, 2752857912, 993805589,
-                2527670352, 3225542607, 944628485, 3515209986, 921481281, 367974468, 2117454622,
-                2209873624, 1921566606, 2578834528, 1484999029, 3509484247, 3630674082, 775583088,
-                2046375476, 401211298, 2549602306, 334040358,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                845403706, 77953683, 2194558433, 845403706, 1042923529, 3590539914, 2744517582,
-                77953683, 2388034116, 2194558433, 3301886909, 3023782521, 580953216, 1465055180,
-                3955885691, 2080291813, 3774053345, 1359568010, 3190686254, 4117520271, 172648375,
-                1622860197, 845403706, 825855763, 146188649, 1489578377, 3569095477, 1113810144,
-                3427498220, 2488589550, 2800357377, 2726508445,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                3032980484, 3032980484, 457574827, 3892929607, 1140535688, 1798717805, 3892929607,
-                3456368029, 1837513323, 3629003209, 2114707363, 3965679828, 633901314, 3114869704,
-                1378904857, 1307226683, 3806305135, 1637626787, 2887284599, 168807195, 3778263611,
-                2600826406, 1360727205, 3032980484, 881174528, 3192689489, 163193571, 2043915925,
-                694158413, 2999247395, 2240212579, 457574827,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                1282680239, 1797368752, 2993777942, 1728460585, 110124545, 1141033743, 1797368752,
-                1728460585, 2175352676, 667687960, 2947422428, 453235174, 269310212, 460963021,
-                3653795003, 1282680239, 672888326, 2634804130, 1626781107, 3019004057, 903432570,
-                361999605, 3875515999, 1253945361, 2681707195, 2670471263, 3737870651, 2073247075,
-                2624291937, 2926928867, 1919566279, 2993777942,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                483679667, 3510912058, 3069247614, 3572710903, 2505392843, 4277807314, 4092987588,
-                1379357179, 2832994011, 4242045268, 1540837583, 42447627, 1060377842, 300498944,
-                3572710903, 2572451172, 3965701812, 1841934255, 3876236448, 1173249123, 1505702753,
-                364290244, 1191795964, 614381306, 2014855544, 3510912058, 483679667, 607400625,
-                3432772196, 270844976, 419418018, 3748049301,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                1270480138, 1903620915, 146227926, 2391357202, 3193115579, 1903620915, 1787534915,
-                1980857726, 3270304036, 4274865725, 2440409803, 3681081186, 843001691, 1960947446,
-                1674211831, 1447238790, 2015500770, 3147632142, 1270480138, 2391357202, 203499160,
-                146227926, 180309613, 3172534584, 1493143599, 2318065838, 2823964725, 1840513829,
-                464301953, 1554144941, 190668504, 1337259975,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                3987109121, 3199523288, 4265505625, 3978757971, 2067617835, 573508955, 3537081978,
-                3978757971, 3797324948, 3748521086, 2991690231, 3986181409, 2011521716, 3187732207,
-                3661576751, 3199523288, 3862597492, 1385760321, 1831494013, 2790882939, 32164695,
-                4005054323, 403678793, 1438876052, 296396073, 1694234532, 2669638222, 2342879606,
-                97637594, 1226159860, 3987109121, 367348563,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                1783957708, 4008632615, 1783957708, 385114432, 1351161333, 3354973812, 2107533142,
-                1384144030, 385114432, 2705891562, 246226911, 3471571669, 310305332, 153297238,
-                338521568, 2919834864, 2740116892, 2390952639, 4186947628, 3719412918, 4008632615,
-                2447937709, 631725881, 1783957708, 792984124, 4126733381, 1895772034, 2533260126,
-                3587533277, 3903484776, 805455507, 2672680543,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                1711983509, 54145491, 526139974, 526139974, 1628183382, 3705566358, 2640771704,
-                478731597, 3096211284, 702412186, 1048558696, 4213800437, 1249757949, 1298788562,
-                35243817, 1599981431, 2555381154, 526139974, 4227156389, 357974803, 2610339594,
-                2805013230, 2250822273, 3621950125, 1093757970, 1348100865, 4173830692, 1157090216,
-                1711983509, 508715587, 1458695673, 1489697073,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                1178112464, 2713711950, 2794098559, 2802817427, 2138713832, 3590795217, 2694842362,
-                1150145999, 640060709, 1938174675, 1131623818, 1053379002, 2634647240, 243004629,
-                2802817427, 1354371703, 3737004055, 3243659678, 222018912, 2713711950, 1570896656,
-                3862746078, 2311452199, 4162412938, 3918234647, 1209483422, 3039686896, 1178112464,
-                1194174027, 2794098559, 1402391819, 82331365,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                3667768482, 1397543826, 3667768482, 3878089341, 2678744953, 3806621890, 608756735,
-                4028642053, 1561080059, 1464504940, 2419996152, 1136374007, 1756464754, 324816166,
-                2341654983, 1588410579, 3142491672, 1135442432, 568381247, 2328539124, 2438077659,
-                2024501465, 1984112012, 2783499473, 1638124624, 3667768482, 3095928395, 887772725,
-                3878089341, 2901371173, 771650654, 2648904925,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                2501832220, 2042100239, 3467336622, 3609804698, 3651545110, 4276877768, 961848212,
-                156276647, 2042100239, 3793385996, 2774428550, 3609804698, 2501832220, 238787880,
-                1096185068, 3025235734, 1611326217, 3109876343, 2223285823, 672328957, 3435857810,
-                969135674, 2739103953, 2119554855, 2185928860, 3725992807, 1717571257, 3850473413,
-                44272356, 3467336622, 3579163438, 2632588409,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                149930370, 526625525, 2660953428, 2660953428, 1231702805, 3207030750, 655082441,
-                747232961, 2841316063, 391975076, 2661821359, 2309759246, 3741974705, 2779771163,
-                2912432732, 1231702805, 1633212888, 2117879564, 3211899472, 149930370, 149845079,
-                2621833931, 3210630217, 2453125790, 4064728119, 1169450320, 526625525, 3154977198,
-                691087981, 2660953428, 3338651319, 525762977,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                55319083, 1248094675, 2033295209, 4119409673, 2469013616, 4152131969, 2469013616,
-                1850824082, 626202793, 1948003380, 55319083, 2018518880, 1936779532, 1104659542,
-                3558588450, 1320915694, 316494422, 2334371168, 4119409673, 987926028, 3009561720,
-                4071518733, 774193895, 342574226, 2033295209, 358349311, 1931324480, 1077283681,
-                3425389183, 98299986, 3318131516, 3748245999,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                4147410603, 2301311856, 1071813467, 1672159600, 264112657, 4271339835, 1701672918,
-                124865340, 3079984374, 812109427, 2097926703, 122335484, 1422936501, 3163260403,
-                4155758200, 2301311856, 2604205831, 265714219, 1071813467, 233032125, 2866151045,
-                264112657, 802266990, 3527705913, 2221042830, 976183142, 3578767032, 3696542008,
-                4256242930, 1315280445, 1672159600, 4147410603,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                3006969542, 3006969542, 4281053122, 1872116383, 1949681556, 2872002784, 1755565871,
-                1872116383, 2363473810, 2725736535, 879570158, 17988023, 2642899555, 3906544331,
-                3396065909, 844205299, 2270699959, 4281053122, 4034224962, 365432892, 4230710660,
-                1881510770, 1693032546, 2213803031, 1301690398, 3278836166, 801059699, 1858254359,
-                1949681556, 4040570108, 3006969542, 3894697128,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                3956266445, 2814611331, 2258855928, 2658228028, 3125931194, 2658228028, 2258855928,
-                3413185531, 883823556, 3125931194, 2961748034, 2720466935, 692576760, 2814611331,
-                1739534688, 3679442471, 736499852, 2464640517, 438474223, 1108560302, 3037445457,
-                1639830175, 1260721173, 357681430, 1698420207, 4073573658, 632100203, 1853417358,
-                3470040094, 3927381916, 1965260210, 706147278,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                794334590, 794334590, 2483531565, 2304185978, 3547966825, 2529632252, 3879832469,
-                450845619, 66796189, 1097449254, 914981596, 3270246976, 1161134476, 584904079,
-                2483531565, 2241961629, 4279114381, 1520167588, 1957434727, 2323615700, 3478303487,
-                869524412, 3244591227, 2285482886, 954556244, 1613354252, 855520230, 2211767236,
-                3547966825, 2304185978, 3863324128, 2639686408,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                433060118, 4227690362, 4227690362, 3672076381, 2676910833, 2354371544, 1923033313,
-                2135510958, 2676910833, 2700176145, 3592597034, 597964610, 183016751, 1714769215,
-                1495018019, 3584918139, 4227690362, 3874353549, 433060118, 2346529415, 2250643399,
-                2366544209, 1499145053, 1289171694, 1990799814, 2264506535, 91579344, 1346394011,
-                2476009846, 3672076381, 1123895230, 3264743148,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                3563300020, 3235291601, 2856386655, 3318362187, 1442534992, 436453974, 2540165331,
-                3318362187, 2658832303, 1569200866, 2615333812, 1869417855, 62592284, 3235291601,
-                1485852241, 3641582906, 2597326709, 1460470108, 3372344424, 1873621320, 839884883,
-                3791328105, 3563300020, 2856386655, 1442534992, 2084696482, 970692737, 1187134972,
-                3085340793, 465406786, 4170931689, 1762730612,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                2664135513, 2572139533, 2572139533, 48245664, 48245664, 2740094200, 2099416281,
-                1998752707, 3754181966, 4216965966, 2885816993, 1801319360, 3729688174, 2568598535,
-                840246056, 2972461268, 3594201990, 2565516812, 3361359206, 2538107150, 11118807,
-                48245664, 2555823214, 503764207, 2609094827, 3090827179, 2572139533, 2797278109,
-                988680077, 2704031566, 1030559449, 799642529,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                4013122217, 864064600, 1139661559, 4058619072, 1281163712, 2063854042, 1281163712,
-                3792941528, 3077252557, 1139661559, 4058619072, 2550146568, 2586228490, 2269837886,
-                744414858, 3025484488, 972311527, 2553564957, 2326240641, 2306498034, 3704512835,
-                1029210555, 2209343278, 3473413859, 2318159127, 627050416, 864064600, 2944630773,
-                647476462, 1204332801, 3949106584, 4013122217,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                3299588969, 1411611953, 163383588, 90128047, 3805411157, 2749778629, 1196552770,
-                90128047, 3480388689, 3387052394, 163383588, 2805625015, 1411611953, 597907892,
-                4024737364, 1353906788, 3601544690, 1717554806, 2900390619, 2741195139, 2852607242,
-                3299588969, 4024009128, 1762385521, 3805411157, 1275406673, 3973834627, 4004058415,
-                639703895, 1281745740, 2142221442, 3380107994,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                2910302706, 2648184007, 3992110639, 2910302706, 945971554, 538037223, 266108950,
-                3886664045, 3473357418, 1221972121, 1215001437, 517043362, 653049715, 4231258637,
-                2893665468, 2910302706, 3992110639, 2765831029, 945971554, 4055455832, 3701060763,
-                3709730631, 3434525056, 1606749484, 2787864561, 3676307288, 3562810126, 2648184007,
-                2655495850, 1657674300, 3032378987, 1928195862,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                2866033080, 4145381898, 1347178633, 2866033080, 2209464511, 4145381898, 1736409746,
-                1407357073, 3160545749, 3732932486, 3354686206, 2478569309, 281290893, 309683783,
-                2816499859, 366797840, 1334962259, 3443896291, 467022094, 1496492590, 1347178633,
-                1417970156, 2478252135, 2866033080, 1146497640, 3912582390, 2209464511, 3327932391,
-                4192240274, 3091717095, 1077894394, 854437417,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                2204555399, 849557651, 2513478925, 2204555399, 3495517039, 3076685604, 159949014,
-                2204555399, 2543406314, 2249375589, 2462861012, 3495517039, 2697578088, 2082865934,
-                3987540800, 4189291128, 2513478925, 1919737709, 268758177, 2037208005, 1142853137,
-                3619988889, 645457616, 1568441485, 618236201, 849557651, 1535804449, 3085211577,
-                3258830888, 100025130, 1926589875, 3388510676,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                390647925, 1510230408, 3847052560, 390647925, 254472306, 2118855169, 2315832704,
-                254472306, 1510230408, 44560717, 1849539042, 3939298156, 449405505, 2884890822,
-                3742450489, 1420648667, 3847052560, 1511377796, 1985569429, 1283640466, 390647925,
-                558007648, 3962552623, 144703414, 616705599, 2922253770, 3710745797, 1309977545,
-                2919023385, 311899065, 1845130834, 650938013,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                282931102, 2205567723, 1542944865, 875811570, 679319550, 2194564776, 3644771538,
-                1170604864, 3557784003, 2926437747, 3990509664, 591151088, 875811570, 3958388971,
-                2874767873, 131313406, 1992401517, 282931102, 861345394, 362817747, 1339256043,
-                196548821, 2902428340, 679319550, 3564086410, 1190793070, 908166776, 1218171345,
-                4262353869, 742760895, 281286887, 2848640127,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                115520226, 115520226, 432206618, 432206618, 158397126, 1026864133, 158397126,
-                3702006812, 2534387912, 3540887275, 3596025265, 3978650746, 9670562, 3619773478,
-                2549100398, 2646307630, 115520226, 1224149386, 915398999, 2805757580, 2866856923,
-                661764868, 1588582967, 3310021050, 4102188910, 2975531211, 2169684415, 432206618,
-                889298781, 492545225, 1900801412, 3324887778,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                1278493943, 416319115, 345770610, 2393710630, 376813103, 3508357675, 1214107328,
-                1574189059, 3747902538, 242974997, 2094266174, 961106551, 376813103, 4246944902,
-                2393710630, 416319115, 37424218, 1011494656, 154444896, 1641464993, 4041046905,
-                2835935516, 2483897703, 2953334498, 1932127835, 2341727718, 3740678333, 345770610,
-                1155806919, 3491105286, 1008103050, 1054827749,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                3302124671, 3589133728, 2042635587, 1297904756, 2993246513, 3974610051, 1113419165,
-                129558163, 125231526, 1402553701, 1291824061, 2746543540, 1345699582, 3007546665,
-                1834124272, 4267238265, 3159827412, 3589133728, 3204715607, 1157537846, 1450675058,
-                1446288264, 685817212, 1562435252, 4262121326, 3302124671, 3226578985, 4115726071,
-                3553041624, 2993246513, 1159822201, 2709599538,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                3643977342, 2762916360, 2499500244, 3643977342, 3092508916, 1289408621, 4220303127,
-                2325581445, 1809599190, 2295502792, 3654823498, 1033870268, 3636800129, 888033746,
-                2494900671, 3092508916, 1324569067, 2921632179, 4128446878, 2550058686, 1488093424,
-                1597192056, 4029488069, 453033213, 1448893555, 1180752612, 3760563208, 3388002782,
-                917847031, 1373490897, 2499500244, 1819768395,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                458291802, 2489422197, 2505255286, 2480042679, 1138697519, 2481099887, 3748033836,
-                2785754885, 772777978, 458291802, 4106532800, 2527809452, 2489422197, 2678854728,
-                3175820200, 3261140397, 3873522910, 1067484377, 2923587140, 2745756518, 1138697519,
-                4053412342, 1120019621, 4235015317, 3739055983, 2480042679, 1655076703, 2827859124,
-                2464298039, 3088690891, 2505255286, 1947981077,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                2816736966, 1238234924, 1941729623, 2379692426, 3924010344, 502892957, 1238234924,
-                604658717, 2133328264, 3879529207, 3924010344, 1147905266, 811263529, 433987114,
-                3925435758, 2379692426, 2816736966, 4073186433, 1941729623, 3005850814, 505191487,
-                700086718, 3062079882, 3138307012, 3603268282, 1664688701, 4091661694, 2951617803,
-                1218318129, 782830089, 1688841718, 2611435005,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                3555775681, 4002988386, 1876538859, 415227780, 517361153, 3791138532, 4150814401,
-                2566703140, 810656998, 4002988386, 969829312, 614439615, 4183232912, 1241222639,
-                3084724005, 2586999865, 842281013, 2496695949, 517361153, 3555775681, 4013755945,
-                3123997511, 1096445905, 3922364603, 2731359322, 3840530006, 2176079629, 2603609234,
-                3841055617, 415227780, 530813855, 3925449552,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                3468315346, 3062415290, 2768093494, 3468315346, 205128490, 3429501064, 2625618855,
-                1344380765, 3345487403, 176024061, 4146369990, 1664671948, 452377687, 409137852,
-                2447232092, 1011982125, 4184256772, 3468315346, 3062415290, 552143153, 3579616470,
-                3527223434, 205128490, 3813824178, 2573917362, 4206029813, 1622078052, 1530318088,
-                4096202388, 3509388052, 2768093494, 1856390738,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                3750251105, 2557336723, 1813525045, 3750251105, 2198862900, 460932418, 1692646766,
-                998839939, 3857198128, 2389760031, 401553579, 1785141422, 3606792195, 829251763,
-                236128092, 2179700977, 1040637413, 3031222878, 2912160390, 1354092106, 2036887704,
-                1373873896, 3750251105, 1813525045, 3377699133, 3770920197, 143210652, 2451791602,
-                2198862900, 592823877, 159470379, 747853213,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                2115808845, 2115808845, 3101049450, 3812787988, 2479059611, 2479059611, 1163404701,
-                204414782, 184343270, 1454472882, 2384570550, 2336436862, 76496281, 4111621364,
-                3019489285, 925130927, 4261523481, 3196967814, 1431832811, 1211791460, 2115808845,
-                3968370021, 3812787988, 4021856302, 3101049450, 1167298229, 1603695584, 1624984481,
-                4093227661, 1241641750, 3531990342, 1336077457,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                1171737616, 2931757277, 3677177141, 1717512115, 1261156399, 166072340, 597177584,
-                1160996501, 1717512115, 3657002667, 1261156399, 362542960, 923063456, 720180101,
-                1171737616, 3715127710, 2121229078, 1864622356, 1592304296, 2831514611, 2931757277,
-                2043179519, 4043502815, 4181800247, 1312876300, 536984760, 903162341, 2270890819,
-                949168857, 3677177141, 1811803157, 1621479022,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                93544599, 431716865, 2659215195, 93544599, 1913339796, 93544599, 1236165848,
-                3496003730, 2970112933, 1039521823, 431716865, 1338437843, 728165578, 992703658,
-                3016868753, 708693161, 45851448, 1846713929, 3464301644, 411087219, 3366475549,
-                1913339796, 18025596, 2659215195, 2171647451, 1565965073, 823997902, 4110798570,
-                589650678, 1156018799, 1474218836, 3510091407,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                223851942, 49902495, 3162367906, 208126698, 1039360573, 1127094750, 1930133333,
-                2959990069, 3162367906, 3953532171, 1466192013, 223851942, 2926982798, 196610233,
-                2698491642, 2336176760, 49902495, 2748575727, 3062790232, 2723112767, 1039360573,
-                1625216854, 2571632762, 1263730222, 4037758783, 3585828407, 2516591446, 2803689802,
-                1602707666, 208126698, 4287925837, 3587151666,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                2033232848, 1324273090, 2686370330, 563402517, 643910937, 68469933, 2623894699,
-                2664003060, 2505482156, 1324273090, 640272534, 3409803908, 3422271656, 643910937,
-                2014466642, 4026665838, 3785298416, 893774109, 2033232848, 1475595087, 4061430000,
-                863936337, 1308452246, 1090166768, 2604658413, 2798687611, 2046217629, 3274707031,
-                4251807762, 3293192157, 1523353366, 563402517,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                2031675096, 2031675096, 2853789809, 3754682823, 2853789809, 473253318, 1498263779,
-                1969388437, 3696766323, 1229497650, 3754682823, 1626404571, 1709087051, 417962950,
-                2033355967, 258836331, 951587068, 1456284905, 3115980929, 50712510, 754568696,
-                3006086715, 2853789809, 2084046642, 3583010901, 3879919331, 552724481, 355342843,
-                2581669893, 3462520966, 2031675096, 1905636367,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                648815774, 2574268142, 3753501162, 3674585975, 2205552619, 3143764680, 2104629005,
-                3424036954, 3060604336, 30420786, 549726153, 1602084414, 3674585975, 3064481555,
-                870656488, 208793041, 3746249514, 1621700381, 1794718950, 163144873, 2785862343,
-                2744087685, 4066907739, 3438852225, 2714266514, 2205552619, 2173448242, 3753501162,
-                1947966199, 3409849523, 990467823, 648815774,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                119384238, 2884909721, 871857801, 2884909721, 575742924, 2655819419, 610838401,
-                1180613121, 871857801, 3828257076, 2914054650, 1112725972, 1782759875, 1638846635,
-                3675176179, 2508392027, 3130253228, 4261438874, 1578811977, 2717025662, 119384238,
-                2450219947, 1239642705, 575742924, 2893011133, 687470076, 17150257, 3434743883,
-                2765728290, 1733027081, 1001561396, 776455544,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                3703912850, 3449999315, 2690474330, 2033350576, 2166613642, 1701702916, 4028627019,
-                1618544193, 3213814163, 3449999315, 2690474330, 3417441880, 4168804582, 3773332264,
-                1434107526, 3583270817, 4279038800, 2670249379, 1231143774, 2913258616, 1225296881,
-                3365925997, 3563304761, 3703912850, 2918495261, 2033350576, 1717794970, 175779009,
-                2166613642, 1610477836, 3218655586, 3779547616,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                2822589594, 1139679253, 1173444767, 3656675482, 1723322659, 3656675482, 539737793,
-                1387060765, 1723322659, 2623554594, 1643570440, 3419861262, 1664799077, 2263431763,
-                3790306868, 4173875561, 1139679253, 2701846151, 3088528466, 1566439450, 4115661297,
-                3651082395, 3414025825, 1337610409, 2771612926, 2822589594, 306854985, 1173444767,
-                1308059448, 2793863160, 115805410, 666424957,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                3114350553, 1274139604, 1558616302, 3945278240, 3668878802, 2462121211, 4262950571,
-                465167188, 3668878802, 2044457898, 4105593931, 381276, 3114350553, 892054968,
-                1274139604, 2866199657, 3233115210, 3876939138, 3945278240, 1558616302, 1721272788,
-                2154126947, 2979675621, 1942746729, 2982415912, 3065479859, 3372935117, 84719404,
-                2614855394, 1043174410, 1812805592, 2209023822,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                3282661897, 2660328825, 1104581090, 1959997863, 3749869579, 2646986504, 3239096384,
-                1485247289, 2902663778, 4100745392, 2412906558, 53271741, 3013826573, 2395305289,
-                622304618, 1311350810, 1378050332, 1104581090, 248425880, 4072343886, 3282661897,
-                2660328825, 3749869579, 2285585235, 2054013673, 310119392, 3017642812, 2487778651,
-                2506433344, 3707086059, 1959997863, 2620137770,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                3962927856, 966933330, 966933330, 1547404824, 1155161598, 1155161598, 4075023071,
-                2101263054, 1876688025, 3720938895, 1607049161, 1987318757, 2244279452, 3681575115,
-                2910959291, 1547404824, 1564014503, 3128349236, 612327823, 3714539207, 3744721470,
-                1766925805, 966933330, 2420775265, 210161025, 2956746887, 2884785317, 1988930383,
-                3298704186, 2092072913, 2627514791, 3962927856,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                2925644184, 3949374330, 1924735838, 4024705108, 1080225776, 1877408742, 262651439,
-                1924735838, 570629827, 1565307748, 3354374212, 2491265301, 1844857459, 2189210385,
-                1021713733, 2810279275, 874744066, 3927510093, 579980092, 1244150937, 2657268137,
-                161961898, 3949374330, 1080225776, 259395146, 3735060909, 3082961344, 3257578077,
-                2924063787, 1992130133, 664825944, 4024705108,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                559568899, 1228640975, 1147295101, 4058997320, 2213428002, 1538266562, 559568899,
-                1794711622, 1147295101, 511410099, 4170228125, 1228640975, 1828463584, 1040497152,
-                1670752367, 3329646903, 749396459, 4015668478, 427930471, 1853779154, 3604009902,
-                4850811, 3862113766, 577353682, 3810941483, 3194605287, 3726574231, 3357357720,
-                3677090832, 1442233612, 4058997320, 2213428002,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                1045635726, 1045635726, 1297120912, 1601419045, 2039773235, 1045635726, 1577511143,
-                2388714933, 3189832660, 715895561, 423470468, 4129116366, 3845679773, 1778565365,
-                3100280533, 2217483223, 3781976018, 211087727, 576236005, 2855497382, 111346398,
-                1297120912, 1676458752, 1884532944, 1601419045, 2269591501, 1539177715, 3606778687,
-                117892903, 948040688, 986718031, 1277552779,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                4139243850, 738669724, 1436826537, 2198347885, 2848540151, 1436826537, 2296929845,
-                3451368121, 738669724, 2975801625, 1296880362, 1321643719, 2198347885, 2740331460,
-                278927927, 691448348, 3177087867, 3153721098, 367177500, 3647476161, 3159602858,
-                2848540151, 2423621049, 652478490, 921579412, 850018750, 3574249144, 3446075024,
-                939531516, 62469038, 3953226246, 253890260,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                997024856, 3565734022, 3206047555, 97743463, 3022962976, 1148931637, 4286600139,
-                675734940, 3691982184, 1411821577, 4045688150, 1695607448, 105589081, 4232397065,
-                2638813554, 997024856, 3757976963, 3064168112, 1904738983, 2013831183, 852309352,
-                1494820429, 2824857643, 3022962976, 3206047555, 1602746676, 3687471490, 2958868695,
-                1514701134, 3565734022, 2432836143, 97743463,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                797920842, 1352447382, 1420153916, 3020598221, 3158002851, 3478578432, 1420153916,
-                2418951918, 3142461976, 2728898183, 1026731371, 2595798055, 731794851, 926419609,
-                1351632237, 4015614981, 1221968533, 3158002851, 3531174775, 450575135, 3766459645,
-                2717811967, 1352447382, 1346061999, 642831897, 2138247651, 1579159181, 797920842,
-                3020598221, 3961346313, 2055118010, 1087073351,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                142661132, 3451821963, 2550677736, 938089472, 2812778350, 444204218, 1471739078,
-                2161318268, 2930807149, 3575735243, 2880008809, 624648120, 432926052, 4164052822,
-                2002840827, 1869713208, 3312813572, 2698364351, 142661132, 3517154025, 614039326,
-                959924955, 608211698, 1725148103, 1087272070, 3430069509, 938089472, 3094737480,
-                2812778350, 972128791, 3838031184, 337186187,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                3338157783, 3777241707, 3282633075, 95985967, 801260733, 1387403088, 275394157,
-                3338157783, 2507637551, 773486966, 2170280279, 204769517, 2214057892, 2445413942,
-                436457380, 367770288, 2486443870, 4174166, 516847051, 4096106244, 3282633075,
-                3714871551, 801260733, 982285294, 993009632, 95985967, 2568438682, 3777241707,
-                2459200302, 2679874984, 2301829474, 58587847,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                2899738164, 3450871147, 1636082965, 2899738164, 2072474368, 238890433, 460798184,
-                976192737, 2063184872, 1506915330, 71295168, 2899738164, 1301251221, 2072474368,
-                2981414975, 3254115846, 1636082965, 2319754255, 2796913838, 249994504, 404617308,
-                2249326736, 3709514254, 743158215, 3206294317, 1137074342, 4195370888, 3450871147,
-                3987404786, 1678586982, 3139326575, 552090986,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                478712970, 76936918, 3013763696, 918627198, 2946082802, 3366579683, 919521111,
-                676988381, 918627198, 1635778560, 2624074597, 777495100, 3504446972, 3934287814,
-                3792502945, 2169575434, 1336832966, 3296120501, 478712970, 4053849519, 2998449179,
-                4245454128, 2825086641, 2427214724, 605952944, 3593643325, 2946082802, 3399775947,
-                3029684265, 1717874150, 1653216483, 3716514932,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                2333819471, 1271397008, 2333819471, 546667268, 1976015062, 3018132990, 3470918634,
-                546667268, 3103296595, 2877880518, 2793969774, 1271397008, 2529170520, 914706521,
-                2705995872, 1951371735, 3333108793, 2333819471, 955957688, 396142151, 2304517377,
-                1029700357, 3598904445, 1976015062, 230282050, 109495410, 1140174001, 360757052,
-                2701350160, 2616594080, 3633744175, 250181525,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                3755209913, 102267077, 1446416830, 1138893005, 45129200, 3755209913, 511417569,
-                4069546702, 3132394738, 543962578, 3954296690, 521501595, 670663142, 4235514065,
-                45129200, 3236591441, 279845681, 4153892474, 1468678883, 3164915191, 1261237920,
-                165981908, 2702669873, 412873351, 1984697122, 102267077, 3394570020, 1138893005,
-                1860130014, 2848484847, 2713391397, 4055144811,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                2856237671, 4175411610, 1398010358, 3223311577, 891122524, 4139517086, 411037180,
-                561234628, 2336545230, 4175411610, 3708917073, 2166861443, 2508295955, 3223311577,
-                2799571549, 2490428129, 663740761, 2837295770, 4144904155, 1398010358, 1141895269,
-                1848861133, 891122524, 930168566, 1440972903, 2892980267, 1727738702, 4273992374,
-                2543789974, 774110020, 3358527538, 198028947,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                4069856605, 4177610375, 1549761326, 747213961, 960134642, 2100055868, 143880491,
-                551472560, 960134642, 2546977926, 3118548662, 2590377886, 188394084, 477542020,
-                3318454379, 2394424651, 359448018, 747213961, 3841085042, 4069856605, 3573600117,
-                4177610375, 1966748919, 1549761326, 3653360420, 984942793, 1575076207, 918779564,
-                2920399644, 816793176, 4015903590, 571676776,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                1568684268, 690037948, 2373497763, 690037948, 4079929997, 1962951102, 2373497763,
-                3944677251, 1674187826, 529897513, 3401317395, 2372105191, 2788317714, 4079929997,
-                2516640794, 32852164, 2636773732, 1609203528, 690037948, 3394608090, 3708411119,
-                278990882, 567169242, 3881436802, 438991026, 1568684268, 1217412366, 4160121825,
-                2760800854, 2706808147, 4168803814, 161958614,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                1621263102, 4071004689, 3741819783, 2992282588, 814994047, 1621263102, 1639879758,
-                1965420722, 4102559313, 2217336668, 3630514769, 3741819783, 3273179270, 1574212314,
-                1810842332, 2688791115, 1889220041, 814994047, 2429564996, 4071004689, 506326769,
-                2639875455, 2992282588, 4175244827, 1869411192, 3993565126, 2680054810, 614817614,
-                452244704, 1261056595, 3186895890, 67736144,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                4244604719, 3828241674, 1501203717, 45791777, 3126932538, 258009949, 3481860467,
-                2012266178, 3887456215, 665576573, 1393617523, 1501203717, 1428210332, 1139876408,
-                371406615, 3081281665, 3578373175, 3576751948, 2867986622, 75886155, 1045585462,
-                3400127172, 4244604719, 3161332037, 172144020, 3469848224, 2712186967, 3126932538,
-                3795264886, 4170599818, 2209175573, 1597050574,
-            ],
-            rank: 29,
-        },
-        TestMatrix {
-            matrix: [
-                4182721564, 3171338413, 3214342062, 2683002791, 3171338413, 3991645347, 3660656718,
-                1419790115, 1631877465, 2932734663, 2707662727, 717525130, 2683002791, 229391407,
-                1135077676, 821333022, 3141408865, 4182721564, 1258289651, 3214342062, 1102063921,
-                3344978190, 1809335720, 3648542401, 2401371961, 3171338413, 405638463, 2937520531,
-                3229758975, 1920018478, 370966366, 1687205260,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                379555003, 1615138768, 452310048, 2919274449, 452310048, 1448638360, 54186619,
-                3035338695, 1006963567, 1981329984, 3612751682, 2919274449, 1187915400, 1650971807,
-                452310048, 2484661593, 2359333367, 1615138768, 56034966, 2674126942, 214752672,
-                3791478144, 1964864071, 379555003, 1693247627, 249285754, 3067372857, 749831883,
-                1500927031, 3309733420, 2721759200, 1201801894,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                2718218303, 1423612778, 4008649426, 844843016, 3863753580, 3094312341, 2452470000,
-                947740427, 3715475395, 1990729636, 4008649426, 3390784835, 150464444, 3496709078,
-                3807674854, 3308751068, 1234937990, 2060127880, 2041752550, 1423612778, 3705220960,
-                1299440646, 180215492, 844843016, 22275226, 3028695674, 2718218303, 1840122144,
-                3124848972, 2743749648, 659006983, 3863753580,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                4038733878, 1239194476, 957933033, 1268952701, 957933033, 3402429942, 1996569237,
-                1268952701, 569745555, 957933033, 1682504132, 2590369645, 547443082, 2116395276,
-                2161662829, 2077433235, 2752690854, 4048148281, 3136325634, 602192196, 1051438352,
-                3827509301, 4199966420, 115413309, 4256282742, 288468810, 283747157, 1296798581,
-                606518489, 1062830373, 4235595132, 4038733878,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                1739412657, 182835276, 4250827689, 362691500, 1739412657, 2557658430, 3525885047,
-                4250827689, 2948515867, 139512286, 3829308948, 2435647176, 3498761969, 3525601726,
-                421464813, 3691983033, 1557018134, 63615821, 1771601578, 1511222086, 4225845378,
-                4216515059, 4101110761, 3658738586, 362691500, 3917395338, 1739412657, 3023583938,
-                43988614, 182835276, 1213862956, 2435582168,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                3137481653, 4214279311, 2976000121, 2976000121, 2383506731, 2179300028, 3137481653,
-                1786609070, 3747162355, 370959461, 1814287309, 1707118492, 2383506731, 820396745,
-                4265686881, 3678233881, 101430158, 2976000121, 2077099784, 649185457, 2804930677,
-                3643367512, 1887765455, 4214279311, 2350863377, 2138995236, 3092244609, 2410285026,
-                108703935, 2747061735, 1875731866, 3900283856,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                3132521318, 3132521318, 2196760274, 3224208409, 1531652397, 1821452673, 2006533102,
-                366264740, 1531652397, 635265839, 3752523257, 3505791219, 1420801111, 4142122730,
-                165829527, 2539844433, 1698363086, 3224208409, 3520587275, 3022075573, 1452423313,
-                1725369, 650023580, 2196760274, 3132521318, 3301873132, 2508658042, 1630336966,
-                3388654688, 1987325005, 758233868, 3578390147,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                3135399918, 1556015294, 3135399918, 1961410941, 1556015294, 890059868, 2482550218,
-                1993893721, 4287674082, 1672430872, 1199787249, 3844778543, 3991202479, 2143689320,
-                3135399918, 3021017093, 434890660, 262427293, 1454868305, 3086437288, 865483642,
-                1394121428, 439941036, 211433654, 105681302, 31612039, 1556015294, 1961410941,
-                4294155577, 1483106633, 966634894, 1868173716,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                2013040326, 268725141, 268725141, 268725141, 2219950711, 2684421207, 1378690999,
-                1488529158, 1637373492, 3062041547, 2645823489, 2971669876, 4076818513, 3765209973,
-                4057908876, 1026323725, 3736769676, 1299123781, 3467825436, 2044494338, 2147495754,
-                1322614665, 333806680, 2994553497, 525282940, 342596491, 268725141, 3742903550,
-                2684421207, 3024517132, 2219950711, 2013040326,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                1355697045, 1610974332, 1822523676, 1109732752, 1822523676, 2310373507, 2609845540,
-                3736147674, 3291015731, 2140009708, 1407173550, 1822523676, 838179073, 1160257850,
-                4154013318, 1012554125, 1980932787, 3568678101, 2851534624, 345320566, 2829534185,
-                894528594, 1692817027, 1716467071, 4092682383, 2055858353, 2002411651, 1312603190,
-                2310373507, 1035352430, 2165058248, 1109732752,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                3890051401, 752473743, 1414245066, 3924504342, 3049732416, 744711013, 520782279,
-                4122746314, 3522536214, 2818339039, 3672756943, 3761623627, 90774414, 4260195220,
-                3924504342, 1414245066, 2171163209, 102298986, 371065289, 986599311, 779188659,
-                752473743, 2582490608, 3890051401, 2261949854, 744711013, 2482723811, 1582229453,
-                3049732416, 3750340030, 1385362024, 241381680,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                1809745382, 1794915618, 1092876352, 3346504958, 1149538877, 700006534, 3295584682,
-                1794915618, 1040272263, 1525968639, 1092876352, 807463717, 802103822, 1700244241,
-                335513128, 3750324351, 1239118644, 2438575393, 1937770532, 2738166698, 3132463933,
-                700006534, 4093719140, 1149538877, 1873726479, 3192540716, 1580722290, 2917713008,
-                1785216330, 396406653, 3485487693, 1767932408,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                856559536, 2277705471, 2338588353, 2277705471, 2338588353, 3685545587, 1305048846,
-                2338588353, 1017770742, 3100091437, 3553587959, 541693776, 626090267, 3667512257,
-                1474831993, 705647282, 2326415331, 856559536, 2745696732, 379630548, 26715981,
-                3962411822, 3245085060, 3911709605, 337059748, 391311756, 2277705471, 3685545587,
-                2389394468, 4139466399, 3012444424, 2771915520,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                2349045598, 2168927584, 1231276676, 2349045598, 3500154980, 2621486074, 2606788039,
-                1815050394, 3250837433, 89476923, 2165601997, 344479512, 241556286, 331221455,
-                2168927584, 785158648, 3206837069, 181992592, 3798699584, 1810815892, 1549479744,
-                4012905372, 3962124745, 3500154980, 1887500220, 2410474167, 3835358487, 2621486074,
-                1877168881, 1231276676, 2914792041, 3688590482,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                3025537872, 1550003632, 3937603936, 3025537872, 3025537872, 95927588, 192193565,
-                2071470023, 2570036802, 3953558625, 935241408, 870664538, 2721946470, 2052717744,
-                2575420308, 2948135350, 1918814706, 95927588, 2376577990, 918797744, 1811550283,
-                1270009403, 1168883316, 4017795352, 3025537872, 2110402974, 4278768459, 3817584526,
-                2025853996, 3937603936, 1550003632, 1959634522,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                303487218, 4187631481, 742182203, 2898179442, 162800158, 2245179899, 2784771902,
-                2340425503, 3620817696, 2245179899, 4274270349, 303487218, 4055017314, 552433431,
-                713270836, 4053726646, 4060097908, 914977924, 2773005043, 715450969, 3309508555,
-                1133902214, 614114453, 2097273955, 1076570403, 4187631481, 742182203, 874321336,
-                3855593513, 2898179442, 4242460980, 162800158,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                894647838, 2542367135, 483066109, 3634173593, 534587635, 894647838, 3514043345,
-                2542367135, 648117781, 1988967562, 483066109, 2616890783, 3471062116, 3946415101,
-                2774286435, 2325702320, 534587635, 3827084257, 3036745017, 2094344797, 2485904285,
-                1436670690, 3076865185, 3634173593, 186839939, 2515008854, 4063796762, 3287461540,
-                894647838, 3892494067, 1507417172, 4193157967,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                3925345876, 2777454135, 1389890857, 443137780, 2777454135, 443137780, 2777454135,
-                2396763397, 45515773, 1364406771, 2491608323, 3925345876, 723637014, 1897131698,
-                3414129811, 1247435451, 2929422919, 2681908048, 2868254082, 441072498, 1341887319,
-                1405502210, 4042199370, 3983289700, 4118112546, 3356428021, 4096851572, 2733012683,
-                1527552348, 443137780, 1280241180, 3422245394,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                1551063267, 2176872310, 2501286388, 2176872310, 3184881834, 558480785, 3392616243,
-                1557154603, 2803294459, 1778279624, 2459355304, 3612159937, 776460995, 2312783416,
-                3437284496, 3119585090, 3475000973, 3464260919, 2501286388, 935418801, 3244036815,
-                537596054, 631448691, 558480785, 4200927121, 157281209, 1255950336, 2838171901,
-                1394059911, 3916964871, 3184881834, 1957462054,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                2878264853, 221610587, 455225007, 2878264853, 3375458630, 221610587, 2225893008,
-                791482080, 3571100264, 221610587, 4044901893, 4233518217, 1293143698, 2116183013,
-                2775342891, 3451495399, 3848789720, 1675450941, 3540081596, 3115876938, 3302375165,
-                835466080, 2878264853, 778555758, 3102703471, 455225007, 2357493909, 2877177943,
-                131503098, 830373844, 1241552572, 160694486,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                4270250197, 1238945820, 3928568531, 1963595961, 2799849521, 1963595961, 3035866601,
-                1062434729, 3097047512, 3855666950, 1711983717, 418405308, 3116055359, 3928568531,
-                4200431306, 2702300333, 3284817110, 2799849521, 702901408, 972086405, 627385938,
-                2435168927, 1509995119, 2573222919, 1118824228, 1963595961, 585542641, 4132267761,
-                4270250197, 50773713, 3847933591, 2397705896,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                1803646761, 2856415544, 260433359, 3985057802, 3607967016, 1170448428, 1803646761,
-                3660809914, 1170448428, 3607967016, 260433359, 2000153240, 641020700, 2862687327,
-                2856415544, 41254393, 3181605072, 647238980, 2989014923, 1687765177, 292162285,
-                1867148497, 2718458051, 2231502071, 4091508487, 3066030559, 3784378753, 3985057802,
-                1846431874, 3563819062, 2006591174, 3913491150,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                1302684828, 3974892438, 2862241158, 2639815121, 3974892438, 377951774, 1877705968,
-                2418882623, 2390261134, 2862241158, 377951774, 2639815121, 1302684828, 183389257,
-                2969984863, 1220927308, 3165446646, 383346787, 3992906200, 2908295232, 1536526531,
-                765600146, 2470919937, 532468549, 1032539232, 3974892438, 3103838552, 1365757644,
-                2461929733, 1929412345, 681567456, 594605429,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                2696001954, 3933263598, 2696001954, 2095831612, 2095831612, 1793670220, 3601313969,
-                862310606, 1349372458, 3190871008, 3987007538, 3685800148, 1144267989, 2459462747,
-                546342622, 1793670220, 3933263598, 358687037, 3789368069, 3737462989, 2284770877,
-                3305956546, 3482366818, 4063081737, 1253257864, 3822944362, 2112663087, 2349933739,
-                3912815169, 2696001954, 2095831612, 1110701306,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                1213242245, 1079885903, 3713711632, 1213242245, 1213242245, 3849114691, 827976262,
-                4224050191, 2212066213, 1393761237, 1628198469, 1792971823, 3242044897, 1776959093,
-                2587037073, 1840504384, 2278673273, 2460917853, 2878109936, 1845500615, 3478086834,
-                1542616010, 2653952744, 1520715351, 1079885903, 48953447, 1213242245, 3713711632,
-                816608114, 1683128866, 3849114691, 3852244580,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                4092531910, 3865631105, 4092531910, 3454130912, 4092531910, 3454130912, 2694907522,
-                1425351009, 1907693939, 1632943145, 4109134961, 2561709652, 116535789, 1771493254,
-                2183444321, 1116279452, 1370578265, 968715730, 2533685984, 1462134993, 1232906517,
-                3197636896, 4092531910, 2466929675, 2705406342, 1711766182, 2247323118, 3865631105,
-                3454130912, 2329236636, 3021907975, 3870414450,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                487451774, 854288064, 1333190940, 389325816, 4240078872, 1702878165, 3890042974,
-                20381812, 1333190940, 1702878165, 3032704999, 3377155562, 3019665237, 4209876820,
-                2918999121, 392480947, 3116485242, 1761344020, 3879824761, 4256398549, 2495204445,
-                520340133, 3373651986, 3121054115, 1598941956, 592343944, 2777447283, 4169156773,
-                3905141289, 3137728472, 389325816, 599095565,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                2670235239, 2029152759, 1113526052, 2193544389, 2193544389, 2193544389, 2193544389,
-                2818969775, 4259619109, 4142752670, 2068361824, 1113526052, 3451681527, 1792024637,
-                2094657967, 441579315, 2846449318, 2666006231, 1887688750, 3069030601, 416768488,
-                2569848454, 4166812440, 2029152759, 1195213132, 189879091, 3442071015, 3146302253,
-                3507616712, 1405321832, 3922766644, 3011466036,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                1658779981, 206661515, 106387401, 32388353, 969305241, 49076559, 32388353,
-                3191022041, 2733186678, 916253350, 39960838, 2889944375, 959649555, 1781759725,
-                4056515874, 2428981638, 2464346525, 830625860, 2091626608, 2548641596, 3843838314,
-                969305241, 2238832574, 106387401, 282542277, 919374431, 3949736192, 1149824265,
-                1604879990, 49076559, 2021879297, 1658779981,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                1495708193, 1919709271, 1919709271, 1540065732, 217371239, 2936874402, 2241304085,
-                1603355773, 3392687541, 2215623468, 2490794603, 1495708193, 1995149020, 1540065732,
-                2868622343, 2306011792, 3254437750, 1091340927, 3671800263, 2534077203, 697536426,
-                2796498436, 792317944, 3281016176, 279057434, 2982274885, 1555216212, 1919709271,
-                3108390499, 2936874402, 4282246577, 2299066621,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                3351838891, 3351838891, 907719367, 3901740084, 2307535356, 2307535356, 4252215550,
-                1065049737, 1884227673, 213440417, 3286712290, 907719367, 3320856827, 3282401339,
-                3920630080, 576021221, 2307535356, 3764549617, 155326661, 3901740084, 3351838891,
-                2319695552, 4262971243, 4236114531, 2967944796, 2270764086, 679149344, 3917308518,
-                1251121519, 1832593234, 1176987618, 2075928006,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                68305288, 1034001891, 753477116, 331704428, 68305288, 350100008, 3069583739,
-                1681533503, 1252425712, 1525968042, 847328659, 732643492, 3048741148, 753477116,
-                779555780, 331704428, 3342676526, 1034001891, 383292715, 460060531, 156702564,
-                3573975543, 3754040724, 1986051933, 1423905551, 612241621, 68305288, 1463895010,
-                350100008, 3860268596, 2740920431, 1789932081,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                3530053278, 400465256, 4210499183, 498105384, 997370680, 82660141, 949430082,
-                549206811, 997370680, 3552095217, 454836951, 3334385734, 2560287207, 3501275159,
-                1336035010, 3530053278, 82660141, 1785044783, 3825576708, 1961436645, 3477182203,
-                2290427412, 1122597101, 2752545555, 2477229525, 400465256, 2953976206, 955586912,
-                2765053571, 3608906962, 2607891133, 498105384,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                289025267, 4116356190, 4116356190, 644529779, 4116356190, 1836140139, 393434181,
-                1923300944, 1761252626, 1836140139, 114555805, 3470404638, 3077052751, 1244924059,
-                3505638993, 4116356190, 3051911859, 1467974781, 289025267, 3141100328, 1844990879,
-                1062755125, 1229584947, 2113656315, 474802468, 644529779, 2960660011, 2392295984,
-                3609783416, 240704546, 3052822522, 2713167801,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                997458486, 3322118110, 1732580021, 2050936193, 2803232864, 1926107388, 997458486,
-                1526949589, 2050936193, 3002520730, 951006971, 1732580021, 3640332325, 1926107388,
-                2803232864, 145579797, 3322118110, 63762317, 63208482, 527234974, 2176221650,
-                1507291802, 3109760711, 2201266580, 497566760, 2410890519, 3256103122, 1765840594,
-                3743879795, 977430345, 2983534774, 2874499784,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                4262734660, 1945415589, 4205301971, 657105144, 1634591258, 1175966122, 4262734660,
-                3540346514, 657105144, 2166825365, 1175966122, 59863626, 582365662, 18724178,
-                1634591258, 3170881795, 3654621709, 4205301971, 1945415589, 2614354765, 1289868967,
-                1029288429, 3691613859, 829521061, 3989384969, 4160146531, 929798420, 824563282,
-                2642554160, 2731497283, 3395406389, 1832776556,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                3288328834, 1642024811, 4260253529, 242295459, 2612592496, 1642024811, 391930657,
-                1642024811, 4035454122, 3801578820, 2639653760, 2612592496, 2512121516, 3452225202,
-                1641637728, 4260253529, 2719315804, 3288328834, 3778980198, 2298773098, 3575526499,
-                480859445, 503964803, 1391454845, 242295459, 3310773603, 3300406668, 3296248811,
-                3072456761, 1265855076, 2667336769, 61992123,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                1497862108, 1981615206, 279921936, 548634495, 276776074, 2230858829, 276776074,
-                13592357, 2464912028, 3554502672, 2854244712, 2504243027, 1380695590, 1431372460,
-                246827172, 2455018341, 548634495, 1736163433, 2228914376, 909782914, 1497862108,
-                3324136031, 2782795455, 24097109, 929957157, 4120317932, 279921936, 2137478327,
-                2661576682, 4244748813, 2230858829, 3547426214,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                1842741960, 3648137513, 3074970446, 2214776566, 1807803979, 3131940847, 3802098342,
-                2917694941, 3131940847, 1923348440, 2387305686, 3410903039, 2961137232, 600610311,
-                3648137513, 221905195, 3074970446, 3636809571, 1807803979, 1610756443, 1840774707,
-                2787885982, 813605318, 2214776566, 3487351512, 1034751445, 4109104821, 2385657443,
-                1767467860, 2433876095, 259521644, 184995180,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                656999389, 656999389, 2378871407, 341509474, 1356375851, 1294450474, 2378871407,
-                2000275116, 3631625400, 341509474, 2299332231, 1294450474, 2120273470, 659507305,
-                983563457, 4217322322, 2918211256, 3640507843, 3124014354, 1356375851, 2220245774,
-                656999389, 3036152150, 2527673066, 1711244376, 726161955, 4276904, 1494527608,
-                2478406975, 1449334204, 1042737978, 1539615962,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                2365263827, 1348081281, 949742195, 2142636577, 943112014, 917172388, 1348081281,
-                2973325162, 163402618, 2393898322, 3281556296, 3661076789, 917172388, 746176790,
-                2858494399, 2541872402, 2174351802, 989121639, 2990642307, 4235171230, 2365263827,
-                3868134103, 3286959371, 2617914492, 2346367992, 949742195, 943112014, 2572262351,
-                2142636577, 1611897927, 3492683779, 2390442494,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                398193126, 2888327297, 1077497462, 3086134744, 3787442988, 1018895107, 1475267148,
-                2902915906, 4115092931, 398193126, 206802889, 3086134744, 4266580151, 3543435637,
-                3626496012, 3747759207, 1296949700, 127643156, 1744732562, 2768033452, 3563436901,
-                113007579, 4129933619, 2309143901, 730123591, 3645510368, 1077497462, 16105923,
-                2888327297, 1535546452, 3787442988, 1018895107,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                732283503, 2306185819, 732283503, 450098317, 2306185819, 1833521047, 2845548578,
-                852835989, 1833521047, 732283503, 189844933, 414881634, 2542298282, 1866001675,
-                3459057250, 2212709978, 1587183667, 450098317, 2306185819, 437914242, 2595167359,
-                2131743571, 3696464505, 1031934330, 652945459, 141069898, 4063805159, 781207822,
-                267028016, 2987766788, 3274649305, 3597780470,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                3912523937, 3204095608, 621439274, 671721012, 1922395178, 1922395178, 1245304898,
-                209062603, 1018582930, 3912523937, 1868701564, 3051739735, 2717623441, 2449329266,
-                2190903626, 4114722048, 3154351679, 2528412629, 3564738429, 1888954336, 621439274,
-                67737363, 596889912, 671721012, 1922395178, 672361088, 124967843, 110187926,
-                4285778746, 4228412850, 1975975700, 1660619110,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                958111600, 2780414170, 2309437245, 2404777825, 2404777825, 2404777825, 2404777825,
-                3072131759, 3864018293, 2557688011, 2997868030, 4040047431, 958111600, 2780414170,
-                2294963484, 3077945893, 2950468896, 2078919073, 3152959570, 3335180437, 283217938,
-                2222608565, 4082794034, 1779760460, 2452257948, 73389950, 214149970, 3877690819,
-                398531753, 1033696925, 4151774037, 3280675584,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                1056421454, 1056421454, 1997314977, 3818573747, 163548000, 3546906941, 1316043067,
-                2246678473, 3296676562, 2004931505, 1997314977, 2398357251, 1056421454, 3529496117,
-                3433870922, 1584166733, 163548000, 3328210682, 1649704365, 3738617614, 3546906941,
-                413634447, 2686742591, 2684972235, 3818573747, 2055155009, 3835352445, 1932952868,
-                872682319, 3509532666, 3616286558, 2477053409,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                3290086620, 3808188429, 494458023, 2589916100, 3808188429, 244015560, 473244866,
-                3054003125, 3416937308, 637165496, 4171091567, 3863680195, 3808188429, 244015560,
-                190413035, 2880582017, 2574757418, 2589916100, 1717821409, 1713318240, 3218310721,
-                548456545, 660186783, 2464881282, 3321820552, 2097332286, 2123789599, 545963148,
-                3398791073, 494458023, 501202968, 3126930666,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                973201853, 1577423997, 3679318178, 4236187523, 1726308889, 3426762211, 1775740865,
-                3622381790, 2785089854, 1916308631, 299700023, 1577423997, 4236187523, 1727366295,
-                4234141965, 2431914287, 292561957, 1126546517, 41555497, 3679318178, 3749044530,
-                2239451488, 493533959, 3363567657, 739348418, 1346125759, 829681759, 3426762211,
-                1726308889, 2332602924, 3953476456, 3650305200,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                1786579972, 995748618, 2393744839, 61801775, 1267309295, 4283456253, 2570216757,
-                2138513108, 3043024890, 2393744839, 3445095465, 143346723, 4283456253, 643181703,
-                2119717627, 1180937398, 647797435, 80722849, 2490223229, 1267309295, 323333531,
-                62645473, 2361838085, 1744340587, 460411094, 1786579972, 161508242, 2474280292,
-                3626793879, 995748618, 236309359, 2145194257,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                3008428645, 2319785481, 3524726833, 3008428645, 3526807416, 3150318949, 2084563128,
-                1288908465, 4200917356, 815656010, 2319785481, 3278322919, 373077975, 2345451719,
-                2836828038, 3008428645, 3125224413, 1990226724, 1969341340, 311516092, 373976452,
-                4258063596, 3526807416, 3563623694, 3832240256, 2288013613, 69706363, 316906643,
-                3567779557, 3150318949, 3083479134, 2092296628,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                1506629671, 2066769361, 1506629671, 2786664006, 3037208395, 3037208395, 4170532761,
-                4178890028, 414007558, 3062168525, 3439036291, 1504480994, 3536404837, 1225946363,
-                2066769361, 3329847084, 1937095543, 2478084655, 1591017758, 3765923578, 1554812766,
-                2975897066, 2786664006, 2581908624, 2851991957, 769491242, 3037208395, 3603977886,
-                1506629671, 2844629750, 3818911832, 2451240909,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                1950453218, 3593998037, 3265032027, 2041018378, 2219454107, 702021729, 2219454107,
-                611261683, 1551717469, 3313887140, 3265032027, 649923390, 2457039754, 702021729,
-                2041018378, 3348669996, 3063769148, 358233498, 365004033, 3593998037, 3057871428,
-                3794250562, 507848040, 2801403742, 2592651176, 24900185, 2700581964, 132106741,
-                4103668591, 3948715858, 479129253, 2729097308,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                3861973635, 1171566254, 3528056555, 3930785928, 33663204, 1981576530, 3528056555,
-                1411035356, 1159045462, 1325287109, 84764319, 1391133919, 1454880286, 373947422,
-                1883286488, 2106054258, 3930785928, 1981576530, 373866322, 1171566254, 1902036221,
-                3209504502, 566620950, 186315517, 1317269691, 33663204, 2242541401, 1879162712,
-                2497607309, 1320492719, 2481579404, 3845777789,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                988843502, 988843502, 988843502, 988843502, 1320725029, 3515107824, 1604704993,
-                988843502, 3806059231, 3145916422, 110300361, 4047703846, 3938043323, 3695867642,
-                3145251541, 91776951, 1836561816, 2318918612, 1460338555, 763881698, 3560261881,
-                947031598, 1197851444, 20372899, 3515107824, 1094785689, 4076000857, 2398727246,
-                2540604731, 1815175288, 2211110514, 94031759,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                536548428, 1097444415, 3539146284, 826166987, 646511808, 2620413634, 278965326,
-                1097444415, 643155373, 97884271, 826166987, 513815248, 3631274132, 4189265724,
-                3011570619, 399923987, 646511808, 2069682611, 3753025715, 4100757450, 637396246,
-                2344391750, 4287248939, 2076585889, 1621531585, 3750670620, 536548428, 3539146284,
-                4189560963, 126788096, 2620413634, 3917124566,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                2812001427, 3139022445, 3290008346, 4099790879, 1272682988, 2812001427, 2812001427,
-                3290008346, 2369037745, 2153005854, 1835632205, 341487370, 2846762254, 305936128,
-                3494061358, 3389594550, 795662635, 2464999954, 727123999, 209751259, 2399594800,
-                4099790879, 1832342088, 1643351855, 3240173515, 1272682988, 1559379255, 1877530830,
-                230427275, 3357810433, 3139022445, 4034738599,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                887449223, 3227934905, 4236388581, 4236388581, 1746989146, 4236388581, 1910907135,
-                648402757, 3657783249, 2407414034, 3372527459, 1128792538, 1789402975, 3322827511,
-                1907648576, 1453422818, 574186847, 2867565942, 2484763300, 3990063545, 887449223,
-                742788336, 427683935, 4236388581, 1232460983, 2367153522, 4086408022, 4199176599,
-                368137842, 1746989146, 3407755563, 340180091,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                3326464226, 3816404507, 2105148426, 3816404507, 1258768388, 2105148426, 3576614643,
-                2426816329, 4159079226, 603001617, 1075606284, 2201645866, 1258768388, 2774713973,
-                3504349382, 1419817484, 2105148426, 1474663850, 2005792962, 446113234, 4214995326,
-                3887818343, 2819797876, 3326464226, 1572786235, 3816404507, 319362334, 3846732511,
-                3372927593, 1471410183, 1536331769, 161468115,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                3372604538, 2155804748, 2254875886, 824350294, 824350294, 202096812, 200836840,
-                1892024200, 2254875886, 1259996499, 2139017381, 2445374842, 202096812, 3666912036,
-                2256714843, 2035240531, 2000348486, 1060298392, 2639221058, 965611575, 2677065576,
-                3372604538, 314131829, 1322027949, 3932605098, 2705671770, 3517904475, 2155804748,
-                824350294, 429083403, 787818947, 2560062308,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                1301238359, 3304080038, 3386764709, 1273060221, 1087283142, 1738808143, 3386764709,
-                3589442944, 3285618878, 2937374135, 4167274901, 2537857904, 3079582529, 3304080038,
-                3661029992, 3835168823, 3798535165, 65816066, 3661148920, 2019754291, 421619381,
-                1155604781, 1903038506, 4185483017, 2250014863, 625335640, 1738808143, 3796536138,
-                1301238359, 107661183, 2048234469, 3709625942,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                1885694037, 2491541476, 916687059, 1883354130, 1885694037, 1497830937, 1565759991,
-                2630469264, 2479127629, 1607408824, 1883354130, 916687059, 3595327893, 4264161196,
-                780813804, 1885694037, 2491541476, 504138933, 3900031631, 3149472549, 708907005,
-                1497830937, 1263795802, 1725045948, 3084744143, 2589631758, 709170584, 1319690214,
-                2841529638, 801540619, 352455370, 3866613877,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                2761190861, 6950408, 3768008820, 632818261, 1988994816, 3768008820, 3137213515,
-                2636654420, 2666146185, 2963500232, 196499238, 6950408, 13186832, 2809933868,
-                2690563364, 2348620624, 4124449280, 2711211384, 1558865114, 2836287704, 3606694962,
-                3959527653, 4168174764, 3768008820, 1988994816, 2172055446, 2420446326, 724538170,
-                1579902013, 3662864014, 273954007, 2761190861,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                963055240, 3398201732, 3299634807, 2019868153, 108635012, 3151962201, 2019868153,
-                1966145387, 3451770897, 3996000860, 3857160846, 2950259971, 984521196, 1173255039,
-                1287677050, 2864250494, 1166805537, 66232588, 1059352125, 963055240, 3812855250,
-                87707388, 3882829159, 3708545350, 2946717026, 1005539346, 796933397, 161237579,
-                3151962201, 102207424, 4084641501, 3299634807,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                4088245177, 3232544756, 3232544756, 4088245177, 1919705461, 3232544756, 366433542,
-                4088245177, 2592802581, 2581586148, 639200367, 1102367423, 2709212924, 2150557186,
-                1061254646, 3756591704, 3221528946, 687065867, 4064774291, 1379624756, 1380818084,
-                3195135945, 2610762727, 4080157271, 3232544756, 1900771738, 1643629977, 2178283676,
-                3766762937, 976544564, 1919705461, 2797722065,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                4153599742, 3909286981, 3909286981, 2934872884, 358399516, 28425846, 238740779,
-                769476219, 4076648807, 735598663, 4091028837, 358399516, 2220630727, 555018255,
-                3987015284, 569055883, 2122085972, 4165556585, 1007037914, 3909286981, 2239752814,
-                2064238994, 2181824185, 3316791297, 28425846, 1155973961, 970307102, 2111164197,
-                2934872884, 4153599742, 287255048, 1382713722,
-            ],
-            rank: 25,
-        },
-        TestMatrix {
-            matrix: [
-                2848767443, 1632767050, 1632767050, 3469375427, 362702776, 362702776, 4134507716,
-                989757731, 3824974673, 2977380420, 1632767050, 1366414589, 3724926810, 3850448371,
-                186315524, 1271197192, 2810896396, 2096964994, 1939750914, 3469375427, 2724056112,
-                1264940568, 1201069705, 362702776, 3185480517, 2848767443, 1311954841, 2216027075,
-                1753517336, 1150996090, 2855119525, 2653919733,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                76609912, 4100162829, 4100162829, 561599141, 564086022, 606290792, 4100162829,
-                248387453, 2715149081, 157353402, 1174482007, 2961102279, 2554585376, 1733197086,
-                1154943972, 2102029867, 579265596, 606290792, 2066294118, 3180410865, 2794073820,
-                3050426562, 864548236, 1289095661, 543056761, 1323135172, 564086022, 561599141,
-                3546874008, 80935035, 888904980, 440089889,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                3391783567, 506984697, 2700898755, 853324660, 1801188486, 757851617, 3391783567,
-                428902725, 1733141286, 1596969490, 1720648066, 757851617, 226815525, 2325237922,
-                3939322694, 4172422769, 1801188486, 809484412, 2502637117, 1769536743, 1771254264,
-                1915039176, 2798301736, 3326075667, 853324660, 2667300666, 1271570402, 2072057681,
-                506984697, 3398341776, 3386810466, 3351419573,
-            ],
-            rank: 27,
-        },
-        TestMatrix {
-            matrix: [
-                84470364, 3123776121, 1629954310, 3902595568, 3123776121, 660519900, 1455949348,
-                2420948912, 425321288, 3956176947, 25090556, 2743941836, 770212647, 3120509714,
-                1763916655, 2947578181, 1629954310, 3902595568, 1947962647, 322110258, 84470364,
-                3756146205, 1575969804, 1109354152, 2022976267, 1336181414, 3123776121, 986659852,
-                319306345, 3126712946, 3396931770, 660519900,
-            ],
-            rank: 26,
-        },
-        TestMatrix {
-            matrix: [
-                275243190, 950722420, 3871311392, 3772361862, 3782745844, 2978367822, 3772361862,
-                2978367822, 275243190, 3782745844, 3199567981, 3766920838, 72821193, 2609210371,
-                1367445019, 510128567, 3301741343, 4218373610, 4194507596, 2681892171, 1438850315,
-                2916465087, 2047541070, 3684562030, 1515354280, 3837610503, 1988792510, 1658631443,
-                748360179, 1359493950, 4149695473, 2068946505,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                1841019456, 4007655865, 2466398016, 1308348300, 4186309679, 3123940841, 3926129715,
-                4022380402, 3123940841, 1135418070, 2598876018, 4202600699, 2783998175, 1308348300,
-                1058980459, 1537845545, 3128791516, 2466398016, 3101534026, 3496123861, 3252792517,
-                1841019456, 1336438694, 553298847, 3270579286, 2561393873, 94051848, 3543024015,
-                3104176182, 4276161919, 3717450126, 927866975,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                2970986892, 1694202833, 650237959, 1514468787, 820783216, 2231101188, 3867170698,
-                3098924404, 4262493342, 917200521, 1619852447, 2189437910, 2688407122, 1162451977,
-                2231101188, 340701474, 1514468787, 650237959, 3069518916, 2908243954, 2829333585,
-                197155067, 2970986892, 221648894, 3778741439, 2512581625, 2156965033, 3203603202,
-                162612621, 1072917199, 237599639, 4221829884,
-            ],
-            rank: 28,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0,
-            ],
-            rank: 0,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                1605687137, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 902667239, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1593990433, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1012679028, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1397632099, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1921837324, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 1504809716, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 2634567171,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1434386135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1724171960, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4085475251, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                1404481923, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2650592014, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4091895679, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 74220995, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1184468627, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3122479810, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 1177182787, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 1847634504, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 412765372, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4129952044, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                2698779194, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 3229197829, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 464325588, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                2903939568, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                1411177946, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1674561149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 1438704462, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                3869860574, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2806708147,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 3708632893, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 968290399, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3581727407,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                727295045, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 1443071568, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 230211557, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                739950636, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2467449880, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4215390252, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 178822986, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 2702793672,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3817015834, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 3712092899,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 3435431372, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2340053799, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 3693848165, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 854916144,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 170453792, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 3316906908, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 1457761465, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1176801627, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 602413408, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                743682091, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4178981483, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 3997431908, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                4107070368, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 3970868926, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                2446767589, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2867190848, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                1202477236, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2188240248, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 3562234, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4256703718, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 771471850, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 88215672, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                1609060734, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 1594272320, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                2131369212, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 607210260, 0, 0, 0, 0,
-                0, 0, 0, 1239610323, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1323402314, 0, 0, 0,
-                0, 0, 0, 0, 2525044647, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 542684070, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 290124670, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3405430795, 0, 2814661215, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 2408010482, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 3219049407, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                3909895272, 0, 0, 0, 0, 1496802446, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 693277989, 0, 0, 0, 0, 0, 0, 0, 0, 1371910438, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 3601172332, 0, 0, 0, 0, 0, 0, 1068722513, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3324693402, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                2971778752, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2062955502,
-                0, 0, 0, 0, 4292796270, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2594000834, 0, 0, 0, 412928708,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2741056697, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 1891759782, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 165675980,
-                0, 2518660582, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 4087903769, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 560063839, 0,
-                2957970253, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                2748893394, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1523902390, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 3828163125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 1803694419, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 4281133833, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 3247721467, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3705935131, 0, 4057688992, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3543440909, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                1430404747, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3632786850, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                123369972, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 1661159503, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 597598779, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 3545388163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 332079449, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 2820698537, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                4193898213, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2029258804, 0, 2724345609, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 3779582538, 0, 0, 1269273529, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 3858292013, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3355578142, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1350128034, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 3690820397,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                2880064182, 0, 0, 3556222159,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 1990116865, 0, 0, 0, 0, 0, 2866026768, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 3132992174, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 3492633995, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2416812137, 0, 0, 0, 0, 0, 0,
-                924803912, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 755821497, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                2612149806, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                3314678463, 0, 0, 0, 0, 0, 0, 1199220601, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3038514458, 0, 0, 0, 0, 0, 2497726988, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                1897986230, 0, 0, 0, 0, 3482522232, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 3930016835, 0, 0, 0, 0, 0, 0, 0, 0, 4198795201, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 2611442784, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 4268584342, 0, 0, 0, 0, 0, 0, 86488764, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 705111506, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 3948883707,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3348990246, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 2451915903, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3568816457, 0, 0, 0, 251545534, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4213189283, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 1999839408, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 1637108379, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 499419389,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136799581, 0, 0, 0, 0, 0, 0,
-                0, 0, 1630225183, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 499841521, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 60885102, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                2018587030, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 1296939369, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                2624440180, 0, 0, 0, 0, 219074390, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 481025946, 0, 0, 0, 0, 0, 1745583705, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 601146938, 3314125052, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                1062832278, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2131488494, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3922852481, 0, 4204358779,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                488531408, 0, 0, 0, 0, 0, 0, 0, 1940707668, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 3767428578, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 4094722077, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                1045910717, 0, 0, 2195756155, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 1871488043, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                136661828, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2652889336, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                476620062, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2572552450, 1930433997, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2221902306, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 1227518131, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 655280408, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 826909071, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4139364288, 0, 0, 0,
-                0, 0, 0, 0, 444015383, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 183536937, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1271305506,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                1427314025, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1288882031, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 2108931410, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1247276002, 0, 0,
-                2696519058, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4275050196, 0, 0, 0, 0, 0, 0, 0, 0,
-                3896038698, 0, 0, 0, 0, 0, 2735148784,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2095445820, 2890708468, 0, 0, 0, 0, 0, 0,
-                3765934980, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 4188626196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2203371005,
-                0, 2031770591, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 3290718395, 0, 0, 0, 0, 0, 0, 2745832030, 0, 0, 0, 0, 642140731, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 732557961, 968313852, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 2501440075, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1749901124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                1647240581, 0, 3321583203, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 2943936505, 1078790563, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 845049537, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 2669442748, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2760885365, 0, 0, 0,
-                0, 3065993179, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 1711196202, 0, 1909164931, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 2875533048, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 1686391285, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 337754003, 0, 0, 0,
-                3429824796, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 867284249, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4211151448,
-                0, 0, 2588551642, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 1371248691, 1260437568, 467443046, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 3419102285, 0, 0, 0, 0, 3065055522, 0, 0, 0, 0, 0, 0,
-                506220957, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 4285955224, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                2285142893, 0, 0, 0, 0, 0, 0, 0, 0, 1184136592,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 828264835, 0, 0, 0, 0, 0, 0, 0, 2558856782, 0, 0, 3771583938, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 3916789859, 0, 0, 4082029215, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14535130, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 508417353, 0, 0, 0, 0, 0, 0, 3660661060, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 7713545, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                3663057600, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                764844376, 0, 0, 0, 0, 952168075, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 3937237919, 0, 0, 0, 0, 3988512358, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                5382055, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 3822152945, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1806178898, 0, 0,
-                0, 0, 0, 0, 0, 0, 2356442348, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2436015815, 2288780629, 0,
-                0, 0, 4289977574, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 422912504, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2472315623,
-                0, 0, 0, 0, 0, 0, 1378981638, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 4142023858, 0, 0, 0, 0, 0, 0, 0, 2350794743, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                1262217445, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2065886115, 0, 2910619660,
-                0, 3432126593, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 3305346989, 0, 0, 0, 1071050139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 3146066393, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                1862598217, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3374102125, 0, 0, 1386787724, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 456341607, 1577997584, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 617663557, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2128681472, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                4237079095, 0, 2050928398, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 3072184092, 0, 0, 0, 0, 3448772217, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                1243932159, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 1086259269, 0, 0, 0, 0, 0, 0, 0, 0, 4066926249, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 2769309414, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 3026130961, 0, 0, 0, 0, 0, 0, 0, 1676730691, 0, 0, 0, 0, 0, 0, 1942672043,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 1616444875, 0, 0, 914008394, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                1816697682, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 2118075055, 0, 0, 4218251826, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2777515899, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3462075184, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                2734861496, 3043979590, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 1601126906, 0, 0, 0, 0, 0, 0, 2593072084, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                3209203898, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 977683620, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 2474098524, 0, 0, 0, 1446194804,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1018721976, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                702917998, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 583790631, 162840670, 0, 0, 0, 0, 0, 3390965507, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 157328862, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                2755855175, 2223560599, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 2143987676, 0, 0, 3797625844, 0, 0, 0, 77180563, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3406689698, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                2740875257, 0, 0, 0, 0, 0, 0, 1560633820, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 3519274915, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2850428206, 0, 0, 0, 0, 0,
-                2547481702, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 703003471, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2669391496, 0, 0, 0, 0,
-                0, 0, 0, 763617159, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 3240638142, 0, 0, 0, 0, 0, 0, 0, 0, 3798317372, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 1750555585, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 2670629078, 0, 0, 0, 0, 0, 1728901441, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 3223754479, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 2160701278, 0, 0, 0, 0, 1584058963, 0, 0, 0, 0, 1093074966, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3207734132, 0, 0,
-                552941783, 3357314526, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 3448114354, 0, 0, 0, 0, 4274913961, 1097580160, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2808497471, 0, 0, 0, 335907056,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2017798755, 0, 0, 3121140439, 0, 0, 0,
-                1566537238, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2758207859, 816729528, 0, 0, 0, 169047529, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 2817379070, 0, 0, 0, 3446086071, 310263315, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3269612700, 0, 0, 0,
-                2659355288, 0, 0, 0, 2133565577, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 3415769142, 0, 0, 0, 0, 0, 2666669090, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 782324714, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                1951365877, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3039735857, 0, 0, 2404480650, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3719776073, 0, 2918077173, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 3938501268, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 3224903166, 0, 0, 0, 4093923373, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 3129676825, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 843717874, 0, 0, 0, 0, 0, 0, 2628502847, 0, 0, 2772001142, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 3367844721, 0, 0, 0, 0, 1817508195, 0, 0, 0, 0, 0, 0, 0, 3494691448, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 797189965, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                210173548, 0, 0, 0, 0, 379141997, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 2733414765, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3965500313, 2617564848, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 132000987, 0, 0, 0, 0, 0, 0, 4104532449, 0, 0, 0, 0, 0,
-                2648109609, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 287317993, 0, 0, 3743881099, 0, 0, 0, 3245915584, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 1772254275, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 1537433897, 0, 0, 0, 0, 0, 0, 0, 2380890502, 0, 2857036059, 0, 0,
-                4160162124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1919578936, 0, 3490252299,
-                0, 0, 0, 0, 0, 2478072115, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 138706438, 713071789, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 1605002793, 0, 0, 0, 1696041317, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 1958389387, 0, 0, 0, 0, 0, 0, 301338587, 0, 0, 0, 0, 0, 0, 0,
-                0, 1028067355, 0, 4242416482, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 3504460971, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1104347997,
-                0, 0, 0, 3733406173, 0, 0, 0, 0, 1538037298, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 395756973,
-                0, 0, 0, 1793075113, 763615389, 112297627, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 1979082238, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                1351357429, 0, 0, 1616445973, 0, 3328658648, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 309335048, 0, 0, 0, 0, 3803889609, 0, 0, 0, 0, 0, 1443363227, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 4229826523, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 4122569708, 0, 704023707, 0, 0, 0, 0, 0, 0, 0, 0, 1628083291, 0, 0,
-                359607825, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 3670290745, 1317475645, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2173286532, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4135947882, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 953818071, 0, 0, 0, 2426744157, 0, 0, 0, 0, 0, 0, 0, 1459965105, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1022253360, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 288687208, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 1222040008, 0, 1143960410, 0, 0, 2978946209,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1330872006, 0, 0, 0, 0, 0, 702477394, 0, 0,
-                0, 1265874449, 0, 0, 0, 0, 0, 2539090412, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1108599735, 0, 0, 0, 0, 0, 0, 1380641781, 0, 0,
-                0, 1682608359, 0, 0, 0, 0, 0, 0, 0, 2821663589,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1189234041, 0, 0, 0, 0, 0, 0, 0, 0, 0, 457247131,
-                0, 0, 0, 0, 0, 0, 0, 368372318, 0, 2501126177,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 1547646228, 0, 0, 0, 0, 0, 0, 0, 0, 1556885177, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 1914849578,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 797012365, 0, 787260859, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 1459827779, 0, 0, 799056170, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 3186952659, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2876419254, 0, 0, 1091959000, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 2784163139, 0, 218933225, 0, 0, 0, 0, 0, 0, 0,
-                2370537578, 4040754778, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 3166656025, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 327462726, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 645645394, 2713985044,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 1294611960, 811674164, 0, 0, 0, 0, 0, 3278191776, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 2003692863, 0, 0, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3876047859, 0, 0, 0, 0, 0, 0, 0, 0, 305545832, 0, 0,
-                0, 0, 0, 0, 2351277107, 0, 0, 0, 338021586, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 679816, 0, 0, 0, 0, 0, 0, 1022903309,
-                0, 0, 0, 0, 0, 0, 3934400566, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3605961070, 0, 0, 0, 0, 0, 1276635746, 0, 0, 0, 0,
-                0, 0, 0, 817099537, 0, 0, 0, 1258066196, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 3447296119, 0, 0, 0, 0, 0, 0, 0, 2532195562, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 973367987, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                2085056557, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3740394117, 0, 0, 0, 0, 0, 3276572311, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3617829502, 0, 0, 0, 0, 0, 3081594556, 0, 0, 0, 0,
-                0, 0, 0, 3781007539, 0, 4254308828, 0, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 3830237546, 0, 0, 0, 0, 0, 3699350032, 327180045, 0, 0, 0, 0, 0,
-                4185806375, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                2638268987, 0, 0, 0, 0, 0, 0, 0, 3976564704, 0, 0, 0, 0, 0, 0, 1741133777, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 752902291, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 1853083484, 0, 4277686859, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                1496991998, 0, 0, 0, 0, 0, 0, 639499756, 0, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 3243600493, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3099237021, 0, 0, 0, 0,
-                2823281235, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                3019170280, 0, 0, 3606805300, 0, 723663181, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 3224545121, 0, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 3074568731, 0, 0, 0, 0, 1194235678, 0, 0, 0, 0, 0, 4255120030,
-                1078456074, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                3123660933, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                2393823762, 2874069068, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 2845695355, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3805062473, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 551862483, 0, 0, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 778595889, 0, 0, 0, 0, 0, 0, 0, 2944017636, 0, 0, 0, 0, 0, 0, 0, 0,
-                1866917643, 0, 0, 0, 0, 0, 2480798244, 0, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                1813942759, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3325336898, 2798963232,
-                0, 0, 0, 0, 0, 0, 0, 3028171011, 0, 0, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 3532831347, 0, 0, 0, 0, 3596766942, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                1299052925, 0, 0, 0, 1132142933, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73086214, 3088162638, 0, 0, 1451784564, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 125836409, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 779824631, 2198090686, 0, 0, 0, 3048258575, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 3399883025, 0, 0, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 2711623376, 0, 0, 3689808023, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 2838617683, 0, 0, 0, 1709928735,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3539464835, 0, 0, 0, 0, 3550453639, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 768647285, 4115447083, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2106627419, 602108158, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 1495343464, 1033993159, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 3694063743, 0, 0, 0, 0, 0, 1742843326, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 1181217410, 0, 0, 0, 0, 0, 0, 979739874,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 2813471654, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1685140863, 0,
-                387048661, 0, 0, 3022173943, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 336630845, 0, 0, 0, 320624971, 0, 0, 0, 0, 0, 0, 0, 628408921, 0, 0, 0, 0, 0, 0,
-                0, 0, 1035168960, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                3183485512, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2512414784, 0, 0, 820899237, 0, 0,
-                0, 0, 0, 0, 0, 0, 106591426, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 561581417, 0, 0, 0, 0, 0, 3273726966, 1793986539, 0, 2588496798, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 789354311, 0, 0, 1884157658, 0, 0, 0, 0, 0, 0, 0, 0, 2020403431, 0,
-                0, 0, 0, 449418725, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1153647798, 2290067748, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 2153661779,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 1740106911, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179608272, 0, 0, 0, 0,
-                0, 2829232599, 0, 0, 0, 0, 0, 0, 0, 843869739,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 2309732197, 0, 0, 0, 0, 0, 0, 0, 0, 4084893324, 0, 0, 0, 4068436153, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 2653390997, 0, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 2361816710, 0, 0, 0, 0, 0, 0, 1243041908, 0, 0, 4077847905, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 1219015267, 0, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                3313050963, 0, 0, 0, 0, 3488388320, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                2516113499, 0, 0, 0, 0, 0, 0, 0, 892508362, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3141713360, 0, 0, 0,
-                2757440561, 0, 0, 4189305509, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 1390977721, 0, 0, 0, 0, 0, 0, 0, 0, 359703015, 2885423490,
-                0, 0, 0, 0, 0, 0, 0, 4216028746, 0, 0, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3205954779, 1246174357, 2812617812, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 2224523998, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 256002110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 337776156, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 1505125769, 0, 0, 690252555,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2947420335, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                1797616197, 0, 0, 0, 0, 907206689, 0, 2260337957, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2342892746, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                2639509575, 0, 0, 0, 0, 0, 0, 0, 958044281, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 879520832, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3835905615, 0, 0, 0,
-                24828581, 0, 0, 0, 0, 0, 0, 0, 0, 1287757983,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 907363366, 1498768713, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 711708962, 0, 0, 0,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                0, 628952839, 1125089283, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                1349025286, 0, 0, 0, 3918896465, 0, 0, 0, 0, 0, 0,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 1,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 622971444, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 473311515, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 3583266022,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 1672624161, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 765140030,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 1065898774, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 789685038,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 3845456201, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 1097502547, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 29011756,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                319595982, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                1115087795, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                2195257481, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 2461109003, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 595136112, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                1006650366, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 608795888,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                3867100489, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 1522908655,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                3118821321, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 2326996271, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                2581940635, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 1269409997, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                1955317126, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 3298980236, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 585268147, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                903944307, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 827455675, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 1305068378,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 2276338706, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 35415363, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 3764029773, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 3431554093, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 447754425, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 3534770159,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 819830681,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 3742064048,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                2642707864, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 229345030, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 214011811, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 411037125, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                1264662963, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 823744093, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 2197381333,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 2446710803, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 278382265, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 2806402934,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 2718014455,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 3543543625, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 3303722183, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 2713147086, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 2498052273, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 107673836, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 701460200, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 3303518427, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                3248282661, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 2361116088, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                2268252522, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 1652265556,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 2498451436, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 2859909364, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 1786571813,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 666897758, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 2335872677, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 134513920, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 3646807527, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 3810580423, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                1921437851, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 2788011555,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 1419623803,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 221455089, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 2352137329,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 1823398790, 2503538248,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 1356701929,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 1395789624,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 328606490, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                2974210325, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 3191494442, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 719608480, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 949128388, 4208546538, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 218419220,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4206562016, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 2605609368, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 795522585, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 1995168848,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                1786007526, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 3069690988, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 3035708678, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 3491436329, 4294967295, 4294967295, 4294967295, 3152116081, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 3617083860, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 3755132564,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 2305687716,
-                4294967295, 4294967295, 4294967295, 840450827, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 985117394, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 1861111310, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 1116500516, 4294967295, 4294967295, 4294967295, 1414496726,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                2878421167, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                2609467266, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 3183012615,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 2369205106,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 2398090893, 1462066161, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 3361606147, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 2274877704, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 862261860, 4294967295, 4294967295, 4294967295,
-                4294967295, 1750437794, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4261805539,
-                4294967295, 4294967295, 4294967295, 981830769, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 2087933387,
-                4294967295, 4294967295, 3936902302, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4000721062, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 1610344242, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 3391573745, 4294967295, 1288221705,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 3514051484,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 969814237,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 525910351,
-                4294967295, 4294967295, 3401846457, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 672350322, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 3584366798, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                3276717998, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 3962106396, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 3846631447, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 3845188585,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 1611620550, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 1054793073, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 2128096646, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 1892394402,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 710709481, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 3072026363, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 2837602537, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 760998869, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 609455834, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 1251642106, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 2861754687, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 812990623,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 1208723384, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 104505956,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 3330299287, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 2354699331, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 3433554483,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 3392830311, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 2381310303, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 2685275236, 4294967295, 4294967295, 4294967295, 306638016,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 3804518214, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 3395708900, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 1740254410,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 2853727796,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 105931966, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 1348140602, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 3354821593, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                720036682, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 987947595, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 2,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 2097605374,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                3014700049, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 112683222, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4098012225, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 3418572825, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 1186073042, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 216412001, 4294967295, 4294967295, 4294967295,
-                3537270630, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 411331062, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 2573710890, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 1706288287, 2830234655, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 863714213, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 3605587438, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                1010143242, 4294967295, 4294967295, 736767557, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 860039173, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 1201456527,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 3187884410,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                202860310, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                3791233836, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 3409822312,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                2627534394, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 3508888009,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 3756859156, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                3595367677, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 1038680607, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4114697548, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 1663413672, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 1383403504, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 2030206508,
-                4294967295, 512456803, 4294967295, 4294967295, 2598515758, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                3605520802, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 2965781396, 4294967295, 519542538,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 685974370, 4294967295, 4294967295, 4294967295, 786736719,
-                4294967295, 4294967295, 4294967295, 4294967295, 1997963604, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 592649173, 4294967295, 2600526100, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4243875691, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 191059030, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 1639510997,
-                4294967295, 4294967295, 4294967295, 301924920,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 1860958188, 4294967295,
-                4294967295, 3523891009, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 32020074, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 2806479887, 4294967295,
-                4294967295, 496046673, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 3557951352, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 2073636943, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                2832835216, 4294967295, 2503650960, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                1728904360, 4294967295, 4294967295, 495098655, 3195596638, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 1524475665, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4244154296, 4294967295, 4294967295, 4294967295,
-                4294967295, 3303840474, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4097718355, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 3965467999, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 1206460465, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                3123262244, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 1348574622, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 3398511089, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                2092631820, 1742966573, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 2318845896, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 2082715285, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 2180620668, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 559919037, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 658870194, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                1750553290, 3726654729, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 1170034737, 4294967295, 4294967295, 2983036513, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 1675217421, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 1019209495,
-                4294967295, 4294967295, 4294967295, 4294967295, 2342356035, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 1175785322, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 928292245,
-                4294967295, 4294967295, 4294967295, 4174025053,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 3106330296, 4294967295, 1768949758,
-                4294967295, 1707630658, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 2194304652, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 1431431181, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4208338667, 4294967295, 4294967295, 4294967295, 4294967295, 4124951963,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 2716297439, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4235216324,
-                4294967295, 4294967295, 4294967295, 3727106702,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 3975691351, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 747561934, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 3813521673,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 2086925999, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4093537431,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                1550323544, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 1180582411, 4294967295,
-                4294967295, 3824633931, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 2147328926, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 3896256741, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4106118063, 4294967295, 4294967295, 4294967295, 1015555553,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 2300128680, 4294967295, 4294967295, 4294967295, 1985857563,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 3534668258, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 2605137032, 4294967295, 4294967295, 4294967295,
-                2278378383, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 2145748912, 1237348366, 4294967295, 4294967295, 4294967295, 2062679328,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 715816347, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 2240110010, 4294967295, 4294967295,
-                4294967295, 3650857723, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 971395534, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 3618733920, 4294967295, 1408540810, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 2735006295, 4294967295, 3035717315, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 1669031947, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 3471100548, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 713088945, 4294967295, 4294967295,
-                4294967295, 4294967295, 3808197511, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 2726299654, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 1172509812, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 377817409, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4157337571, 4294967295, 4294967295, 4294967295,
-                4259066692, 4294967295, 4294967295, 398198314, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 3168809062, 4294967295, 4294967295, 164233773,
-                293524862, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 1448416212, 4294967295, 4294967295,
-                4294967295, 2593552408, 4294967295, 4294967295, 4294967295, 4294967295, 2915690415,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 918959499, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 3298469480, 4294967295,
-                4294967295, 3108117248, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 3713816840,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 2323373319, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 3639900888, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 846858075,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                1028461365, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4042124113, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 1767098865, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 1364425430, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 546683008,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 3223307710, 4294967295,
-                4294967295, 4294967295, 4294967295, 3137077959, 4294967295, 4294967295, 4294967295,
-                4294967295, 2632503338, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 1242612223, 4068144348, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 1491429350, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 2542119393, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 1683760957,
-                560006910, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 1315686316, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 1848730409, 4294967295, 2430694018, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4217177024, 2725368818,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 2130491051, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 824583563, 4294967295, 1549478667, 4294967295, 568550523,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 3284002203, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 3885428914, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 2007952411, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 1618936268, 4294967295, 4294967295, 667356144, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 2685390998, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 1253936617, 2786546209,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 571137176, 623591005, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                2136391768, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 1459211756, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 2248156582, 4294967295, 4294967295, 4294967295,
-                4294967295, 817650625, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4094106168, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 2317380971,
-            ],
-            rank: 3,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4126727431, 4294967295, 4294967295, 3304858183,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 737010798,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 183332127, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 1731766830, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 1172041098, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4188389990, 4294967295, 4294967295, 4294967295, 4294967295,
-                2057031100, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 158149516, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 2533461309,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 2097955452, 4294967295, 4294967295,
-                4294967295, 1429565666, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 1701473895, 4294967295, 4294967295, 4294967295, 1924895905,
-                4172593360, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                2785108113, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                1279272329, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 2040943766, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 2005203188, 4294967295, 4294967295, 4294967295, 4294967295,
-                2826477092, 2710457480, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 877117505, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 2260384275, 4294967295, 2930297871, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4051595059, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 711069178, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4145923765,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                201222483, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 542730209, 4294967295, 2443490334, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 1259018963, 2324360125, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 1085365546,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                3841237043, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 3472817205,
-                4294967295, 4294967295, 4294967295, 4294967295, 2523700855, 4294967295, 4294967295,
-                3018152227, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                1137258835, 1523474117, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4080618945, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 3197177588, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 928236204, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 1702748024, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 856313395, 4294967295, 4294967295, 4294967295, 4294967295,
-                2845254290, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 1308011561, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 929727682, 4294967295, 4294967295, 4294967295, 3453796769,
-                4294967295, 1356788900, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                3268109296, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 1195410411,
-                4294967295, 2733901559, 4294967295, 4294967295, 4294967295, 107475218, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 1255906816, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 2962625176, 4294967295, 4294967295, 1022664965, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4090221852, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 1759894443, 3516312368, 3593510032, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 3747933357,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 2375828472, 4294967295,
-                4294967295, 4294967295, 37219382, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 3757525318, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 937094462, 4294967295,
-                4294967295, 4294967295, 4294967295, 1157224582, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 568563541,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4282097828, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 2547262802, 4294967295, 1455814046, 4294967295,
-                4294967295, 4294967295, 4294967295, 2280909395,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                2373341450, 4294967295, 2086222155, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 2637972878,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                1454735675, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 764265962,
-                286523980, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                1471282432, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                2982226720, 1145294954, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4010582326,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 1721187338, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 3358645655, 4294967295, 3060028369,
-                4294967295, 4294967295, 4294967295, 2546491544, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4157844574, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 337111908, 4294967295,
-                4294967295, 1985924413, 3732068005, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 2370226759, 4294967295, 4294967295, 4294967295,
-                4294967295, 4221745850, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 1800219421, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 3278706946, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 1726463021, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 611247440, 4294967295, 4294967295,
-                1390965502, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 1448944650, 4294967295,
-                4294967295, 813488881, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                3142216066, 4294967295, 2600040384, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 2641094498, 4294967295, 1002756548,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                769508587, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                1813762848, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 3128347611, 4294967295, 4294967295, 2752092175,
-                4294967295, 4294967295, 4294967295, 3729656783, 2782826125, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 3532284154, 4294967295, 4294967295,
-                4294967295, 4294967295, 716285166, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 3501718170, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 1376009926, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 286446331, 4294967295, 4294967295, 4294967295,
-                4294967295, 2984683245, 4294967295, 4294967295, 4294967295, 470027274, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 2871309228, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 508971756,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 1911732140, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 46815711, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 3370535621, 4294967295, 4294967295, 4294967295, 3831797740, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 1562913277, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 3406146350, 4294967295, 3929218832, 4294967295, 4294967295,
-                4294967295, 4294967295, 3715676585, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 2761888313,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 1299787431, 4294967295, 4294967295, 4294967295, 729877305, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 964993434, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 3584567252, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 314187380,
-                4294967295, 3923515813, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 3279495974, 4294967295, 4294967295, 4294967295,
-                4294967295, 1119655522, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                3764178118, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 1772288494, 3933578074, 3991046471, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 909652030, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 705987762, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 3977925768, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 1574907377,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 2412557718, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 1124885674, 4294967295,
-                4294967295, 4294967295, 4294967295, 608358471,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4259297250, 4294967295, 4294967295, 3961918635, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 443128775, 4294967295, 4294967295,
-                4294967295, 4294967295, 2431567853, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                3835690016, 4294967295, 2652650868, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 720857279,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                1420582913, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 3959666775, 4294967295, 4294967295, 2746261139, 287256461,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 1123948073, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 1722620308, 4294967295, 1162746533, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 2005967340, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 3785899406,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 203317007, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 803953617, 3865944261, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 2464138378, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 1883064012, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 2513686923, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                236012200, 4294967295, 4294967295, 4294967295, 4294967295, 3606170470, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 2384761900, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 3348514378, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4210736575, 4294967295, 2896458622,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 3712686081, 4294967295,
-                4294967295, 4294967295, 4294967295, 2327776950, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 2762432062, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                3559105388, 4294967295, 4294967295, 4294967295, 4294967295, 3178160027, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 491306043, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 3964385085,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 1173389873, 4294967295, 4294967295, 1196467940, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 1396700021, 4294967295, 4294967295, 4257305874, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 3917730059, 4294967295, 4294967295, 4294967295,
-                2242914046, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 3144537695, 4294967295, 4294967295, 3030518548, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 1298645799, 4294967295,
-                4294967295, 1331842469, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 1316755767, 4294967295, 3966998572, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                739417933, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                1280136493, 1709044936, 4294967295, 4294967295, 4294967295, 1213108062, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 396141854, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 2645031817, 4294967295, 4294967295, 4294967295, 4294967295, 812052215,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 997557118, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 3994525287, 4294967295, 4294967295, 4294967295, 4294967295,
-                2053935554, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 2631535330, 910995601, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4129369597, 2914759516, 4294967295, 4037833372, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 13614947, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 3944349973, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 3390095362, 4294967295, 2631403663, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 1460024412, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 473239063, 3655870325,
-                4294967295, 4294967295, 4294967295, 1438730316, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 2860666479, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 1213363413, 2437791453, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                2667292375, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 2934178357, 4294967295, 4294967295, 4294967295,
-                4294967295, 3555206985, 4294967295, 423555650, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 2325868705,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                1389010740, 1174947514, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                1391272983, 4294967295, 4294967295, 2944325148,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 122962467, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 3771306422, 2011209388,
-                4294967295, 4294967295, 4294967295, 3672269213,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 2535295428, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 1949914699, 4294967295, 4294967295,
-                4294967295, 4294967295, 42833535, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4225437561, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 2587488971, 4294967295, 4294967295, 4294967295,
-                891441292, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                3237915611, 656344716, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 2871024709, 4294967295,
-                4294967295, 4294967295, 4294967295, 1836369754, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                262126150, 1315486223, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                668911904, 2532916548, 4294967295, 4294967295, 4294967295, 4294967295, 4250728949,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4275837247, 4294967295, 4294967295, 4294967295, 4294967295, 2061127555,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 404353108, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 4,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 826040504, 4294967295, 3888185589,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 3253895889, 4294967295,
-                4294967295, 56274939, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 3075009878, 4294967295, 4144022350,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 1293682175, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4162232910, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 538029775, 4294967295, 4294967295, 4294967295, 4294967295, 3786794695,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                587553722, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 2531711902,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                248015996, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 2674652529, 4294967295, 160665421, 4294967295, 646017377, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 2203904022, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 1616164085, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 2527217921,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 2255591894,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 3329993018, 4294967295, 4294967295, 4294967295,
-                4294967295, 2935587350, 4294967295, 893886156, 2173913363, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-        TestMatrix {
-            matrix: [
-                2660163623, 4294967295, 3515495169, 4294967295, 4294967295, 4294967295, 1937362187,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                3116244630, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-                4294967295, 4294967295, 4294967295, 4294967295,
-            ],
-            rank: 5,
-        },
-    ];
 }
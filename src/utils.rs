@@ -4,12 +4,9 @@
 
 //! Misc utility functions.
 
-use std::{
-    fs::{File, OpenOptions},
-    io::Write,
-    path::Path,
-    time::Duration,
-};
+#[cfg(feature = "images")]
+use std::fs::File;
+use std::{fs::OpenOptions, io::Write};
 
 pub const INV_ROOT2: f64 = 0.7071067811865475;
 
@@ -29,38 +26,6 @@ pub fn write_and_print(message: String, file_path: &str) {
     }
 }
 
-/// Format a duration to a fixed width.
-pub fn format_elapsed_time(duration: Duration) -> String {
-    const DECIMAL_DIGITS: usize = 4;
-    let round_mul: f64 = 10.0_f64.powi(DECIMAL_DIGITS as i32);
-    let secs = duration.as_secs_f64();
-
-    if secs >= 1.0 {
-        format!(
-            "{:<1$} s ",
-            (secs * round_mul).floor() / round_mul,
-            DECIMAL_DIGITS + 4
-        )
-    } else if secs >= 1e-3 {
-        format!(
-            "{:<1$} ms",
-            (secs * 1e3 * round_mul).floor() / round_mul,
-            DECIMAL_DIGITS + 4
-        )
-    } else if secs >= 1e-6 {
-        format!(
-            "{:<1$} µs",
-            (secs * 1e6 * round_mul).floor() / round_mul,
-            DECIMAL_DIGITS + 4
-        )
-    } else {
-        format!(
-            "{:<1$} ns",
-            (secs * 1e9 * round_mul).floor() / round_mul,
-            DECIMAL_DIGITS + 4
-        )
-    }
-}
 /// XOR two u64 slices in place.
 pub fn xor_in_place(a: &mut [u64], b: &[u64]) {
     for (b1, b2) in a.iter_mut().zip(b.iter()) {
@@ -79,6 +44,7 @@ pub fn fast_log2(in_int: u64) -> u32 {
 /// Create 24-bit color .ppm image from byte vec.
 /// pixels must contain height * width * 3 bytes.
 /// Useful for visually checking for patterns in data.
+#[cfg(feature = "images")]
 pub fn create_ppm(
     file_path: &str,
     width: usize,
@@ -86,31 +52,13 @@ pub fn create_ppm(
     image_data: &[u8],
 ) -> std::io::Result<()> {
     assert_eq!(image_data.len(), height * width * 3);
-    let path = Path::new(file_path);
-    let mut file = File::create(path)?;
+    let mut file = File::create(file_path)?;
     let header = format!("P6 {} {} 255\n", width, height);
     file.write_all(header.as_bytes())?;
     file.write_all(image_data)?;
     Ok(())
 }
 
-/// Format a number of bytes into a pretty String.
-/// e.g. 1048576 is 1 MiB
-pub fn format_byte_count(num_bytes: usize) -> String {
-    // 2**30 = 1073741824
-    if num_bytes >= 1073741824 {
-        format!("{:.2} GiB", (num_bytes as f64 / 1073741824.0))
-    // 2**20 = 1048576
-    } else if num_bytes >= 1048576 {
-        format!("{:.2} MiB", (num_bytes as f64 / 1048576.0))
-    // 2**10 = 1024
-    } else if num_bytes >= 1024 {
-        format!("{:.2} KiB", (num_bytes as f64 / 1024.0))
-    } else {
-        format!("{:.2} B", num_bytes as f64)
-    }
-}
-
 /// Print a binary matrix represented as list of u32 ints.
 pub fn print_matrix(matrix: &[u32]) {
     for &row in matrix {
@@ -124,21 +72,34 @@ pub fn print_matrix(matrix: &[u32]) {
 /// in Appendix F of NIST Special Publication 800-22.
 /// Speedup of around 2x observed.
 pub fn rank_binary_matrix(matrix: [u32; 32]) -> usize {
-    // Matrix must be square MAXTRIX_SIZE x MAXTRIX_SIZE
-    const MAXTRIX_SIZE: usize = 32;
-    let mut mat = matrix;
+    rank_binary_matrix_rows(&matrix.map(u64::from), 32)
+}
+
+/// Calculate the rank over GF(2) of a binary matrix with up to 64 rows and columns, one u64 per row.
+/// The columns are the lowest `columns` bits of each row, most significant first.
+/// Same procedure as `rank_binary_matrix`.
+/// Panics if there are more than 64 rows or columns.
+pub fn rank_binary_matrix_rows(rows: &[u64], columns: usize) -> usize {
+    const MAX_SIZE: usize = 64;
+    assert!(
+        rows.len() <= MAX_SIZE && columns <= MAX_SIZE,
+        "Matrix larger than 64x64"
+    );
+    let mut mat: [u64; MAX_SIZE] = [0; MAX_SIZE];
+    mat[..rows.len()].copy_from_slice(rows);
+    let row_count = rows.len();
     let mut rank = 0;
 
-    for col_index in 0..MAXTRIX_SIZE {
-        let mask: u32 = 1 << (MAXTRIX_SIZE - 1 - col_index);
+    for col_index in 0..columns {
+        let mask: u64 = 1 << (columns - 1 - col_index);
         // Find the pivot row in the current rank or below
-        if let Some(pivot_row) = (rank..MAXTRIX_SIZE).find(|&r| (mat[r] & mask) != 0) {
+        if let Some(pivot_row) = (rank..row_count).find(|&r| (mat[r] & mask) != 0) {
             // Swap the pivot row with the current rank row
             mat.swap(rank, pivot_row);
             let pivot_val = mat[rank];
 
             // Eliminate this column only in rows below the pivot row
-            for row in mat.iter_mut().take(MAXTRIX_SIZE).skip(rank + 1) {
+            for row in mat.iter_mut().take(row_count).skip(rank + 1) {
                 if (*row & mask) != 0 {
                     *row ^= pivot_val;
                 }
@@ -212,25 +173,58 @@ pub fn rank_binary_matrix_nist(matrix_input: [u32; 32]) -> usize {
 }
 #[cfg(test)]
 mod tests {
-    use crate::testdata;
+    use crate::testdata::fixtures;
 
     use super::*;
 
+    /// Identity, all ones and seeded matrices of every rank.
+    fn test_matrices() -> Vec<([u32; 32], usize)> {
+        const SEEDS_PER_RANK: u64 = 100;
+        let mut identity = [0; 32];
+        for (i, row) in identity.iter_mut().enumerate() {
+            *row = 1 << (31 - i);
+        }
+        let mut matrices = vec![(identity, 32), ([u32::MAX; 32], 1), ([0; 32], 0)];
+        for rank in 0..=32 {
+            for seed in 0..SEEDS_PER_RANK {
+                matrices.push((
+                    fixtures::random_matrix_with_rank(seed * 33 + rank as u64, rank),
+                    rank,
+                ));
+            }
+        }
+        matrices
+    }
+
     #[test]
     fn binary_matrix_rank_test_nist() {
-        for (i, test_matrix) in testdata::matrix_test::TEST_MATRICES.iter().enumerate() {
-            println!("Matrix: {}", i);
-            assert_eq!(
-                rank_binary_matrix_nist(test_matrix.matrix),
-                test_matrix.rank
-            );
+        for (i, (matrix, rank)) in test_matrices().into_iter().enumerate() {
+            assert_eq!(rank_binary_matrix_nist(matrix), rank, "Matrix: {}", i);
         }
     }
     #[test]
     fn binary_matrix_rank_test() {
-        for (i, test_matrix) in testdata::matrix_test::TEST_MATRICES.iter().enumerate() {
-            println!("Matrix: {}", i);
-            assert_eq!(rank_binary_matrix(test_matrix.matrix), test_matrix.rank);
+        for (i, (matrix, rank)) in test_matrices().into_iter().enumerate() {
+            assert_eq!(rank_binary_matrix(matrix), rank, "Matrix: {}", i);
+        }
+    }
+
+    #[test]
+    fn binary_matrix_rank_64() {
+        let identity: Vec<u64> = (0..64).map(|i| 1 << i).collect();
+        assert_eq!(rank_binary_matrix_rows(&identity, 64), 64);
+        let mut dependent = identity.clone();
+        dependent[63] = dependent[0] ^ dependent[1];
+        assert_eq!(rank_binary_matrix_rows(&dependent, 64), 63);
+        // Bits above the columns are ignored.
+        assert_eq!(rank_binary_matrix_rows(&identity, 8), 8);
+        assert_eq!(rank_binary_matrix_rows(&[], 64), 0);
+        for (matrix, rank) in test_matrices() {
+            let wide: Vec<u64> = matrix
+                .iter()
+                .map(|&row| (row as u64) << 32 | row as u64)
+                .collect();
+            assert_eq!(rank_binary_matrix_rows(&wide, 64), rank);
         }
     }
 }
@@ -0,0 +1,273 @@
+// Copyright 2025 N. Dornseif
+//
+// Dual-licensed under Apache 2.0 and MIT terms.
+
+//! Cross-validation against published results.
+//! Every entry pairs a qualitative outcome reported in the literature with a fixed, seeded run of the crate,
+//! so a silent change of a test's calibration shows up as a result that is no longer reproduced.
+//! MT19937 fails linear complexity tests only on sequences far longer than its 19937 bits of state,
+//! so its entry checks that it passes at the sizes the crate runs.
+
+use crate::{
+    rng_testing::{self, TestResult},
+    rngs::{adapters, lcg, mt, xorshift, RNG},
+    spectral::{self, LcgParameters, Modulus},
+    stats, strings,
+};
+
+/// Seed of every generator run by the cross-validation.
+const VALIDATION_SEED: u64 = 1;
+
+/// Outcome the crate has to reproduce.
+#[derive(Debug, Clone, Copy)]
+pub enum Expectation {
+    /// ν_t² of the spectral test in dimension `dimension`.
+    Spectral {
+        parameters: LcgParameters,
+        dimension: usize,
+        nu_squared: f64,
+    },
+    /// Verdict of a test of the battery on `2^sample_exponent` outputs of `generate`.
+    /// A `test` of `None` covers every applicable test of the battery.
+    Battery {
+        generate: fn(usize) -> Vec<u64>,
+        sample_exponent: u32,
+        test: Option<&'static str>,
+        fails: bool,
+    },
+}
+
+/// A published outcome.
+#[derive(Debug, Clone, Copy)]
+pub struct PublishedResult {
+    pub rng_name: &'static str,
+    pub claim: &'static str,
+    pub source: &'static str,
+    pub expectation: Expectation,
+}
+
+fn randu_output(sample_size: usize) -> Vec<u64> {
+    stats::generate_test_data(&mut lcg::Randu::new(VALIDATION_SEED), sample_size).0
+}
+
+fn mmix_low_bit_output(sample_size: usize) -> Vec<u64> {
+    let mut plane = adapters::BitPlane::from_rng(lcg::Mmix::new(VALIDATION_SEED), 0);
+    stats::generate_test_data(&mut plane, sample_size).0
+}
+
+fn lehmer64_output(sample_size: usize) -> Vec<u64> {
+    stats::generate_test_data(&mut lcg::Lehmer64::new(VALIDATION_SEED), sample_size).0
+}
+
+fn xorshift128_output(sample_size: usize) -> Vec<u64> {
+    stats::generate_test_data(
+        &mut xorshift::XORShift128::new(VALIDATION_SEED),
+        sample_size,
+    )
+    .0
+}
+
+fn wyrand_output(sample_size: usize) -> Vec<u64> {
+    stats::generate_test_data(&mut xorshift::WyRand::new(VALIDATION_SEED), sample_size).0
+}
+
+fn mt19937_output(sample_size: usize) -> Vec<u64> {
+    stats::generate_test_data(&mut mt::Mt19937::new(VALIDATION_SEED), sample_size).0
+}
+
+/// Published outcomes reproduced by the crate.
+pub const PUBLISHED_RESULTS: &[PublishedResult] = &[
+    PublishedResult {
+        rng_name: "RANDU",
+        claim: "nu_3^2 = 118, all triples lie on 15 planes",
+        source: "Knuth, TAOCP Vol. 2, 3.3.4",
+        expectation: Expectation::Spectral {
+            parameters: LcgParameters {
+                multiplier: lcg::Randu::MULTIPLIER as u128,
+                increment: 0,
+                modulus: Modulus::PowerOfTwo(lcg::Randu::MODULUS_BITS),
+            },
+            dimension: 3,
+            nu_squared: 118.0,
+        },
+    },
+    PublishedResult {
+        rng_name: "RANDU",
+        claim: "fails collision tests",
+        source: "L'Ecuyer and Simard, TestU01, 2007",
+        expectation: Expectation::Battery {
+            generate: randu_output,
+            sample_exponent: 20,
+            test: Some("Collide"),
+            fails: true,
+        },
+    },
+    PublishedResult {
+        rng_name: "MMIX",
+        claim: "lowest bit of a power of two modulus LCG alternates",
+        source: "Knuth, TAOCP Vol. 2, 3.2.1.1",
+        expectation: Expectation::Battery {
+            generate: mmix_low_bit_output,
+            sample_exponent: 12,
+            test: Some("Runs"),
+            fails: true,
+        },
+    },
+    PublishedResult {
+        rng_name: "XORShift",
+        claim: "fails linear complexity and matrix rank tests",
+        source: "L'Ecuyer and Simard, TestU01, 2007",
+        expectation: Expectation::Battery {
+            generate: xorshift128_output,
+            sample_exponent: 14,
+            test: Some("Linear"),
+            fails: true,
+        },
+    },
+    PublishedResult {
+        rng_name: "Lehmer64",
+        claim: "passes BigCrush",
+        source: "Lemire, Testing non-cryptographic random number generators, 2019",
+        expectation: Expectation::Battery {
+            generate: lehmer64_output,
+            sample_exponent: 18,
+            test: None,
+            fails: false,
+        },
+    },
+    PublishedResult {
+        rng_name: "WyRand",
+        claim: "passes BigCrush and PractRand",
+        source: "Wang Yi, wyhash",
+        expectation: Expectation::Battery {
+            generate: wyrand_output,
+            sample_exponent: 18,
+            test: None,
+            fails: false,
+        },
+    },
+    PublishedResult {
+        rng_name: "MT19937",
+        claim: "fails only the linear complexity tests of BigCrush",
+        source: "L'Ecuyer and Simard, TestU01, 2007",
+        expectation: Expectation::Battery {
+            generate: mt19937_output,
+            sample_exponent: 18,
+            test: None,
+            fails: false,
+        },
+    },
+];
+
+impl PublishedResult {
+    /// Run the check of the entry.
+    /// Returns a description of the observed outcome, as `Err` if it does not reproduce the published one.
+    pub fn check(&self) -> Result<String, String> {
+        match self.expectation {
+            Expectation::Spectral {
+                parameters,
+                dimension,
+                nu_squared,
+            } => {
+                let results = spectral::spectral_test(&parameters, dimension);
+                let observed = results
+                    .iter()
+                    .find(|result| result.dimension == dimension)
+                    .map(|result| result.nu_squared)
+                    .ok_or_else(|| format!("No spectral test in dimension {}", dimension))?;
+                let description = format!("nu_{}^2 = {}", dimension, observed);
+                if observed == nu_squared {
+                    Ok(description)
+                } else {
+                    Err(description)
+                }
+            }
+            Expectation::Battery {
+                generate,
+                sample_exponent,
+                test,
+                fails,
+            } => {
+                let data = generate(1 << sample_exponent);
+                let names: Vec<&'static str> = match test {
+                    Some(name) => vec![name],
+                    None => strings::TEST_NAMES.to_vec(),
+                };
+                let mut mismatches: Vec<String> = vec![];
+                let mut applicable = 0;
+                for name in names {
+                    let function = rng_testing::battery_test(name)
+                        .ok_or_else(|| format!("Unknown test '{}'", name))?;
+                    let result = TestResult::new(name, function(&data));
+                    if result.not_applicable() {
+                        continue;
+                    }
+                    applicable += 1;
+                    let reproduced = if fails {
+                        result.failed()
+                    } else {
+                        result.passed()
+                    };
+                    if !reproduced {
+                        mismatches.push(result.format());
+                    }
+                }
+                let verdict = if fails { "failed" } else { "passed" };
+                if applicable == 0 {
+                    Err(format!("No applicable test at 2^{}", sample_exponent))
+                } else if mismatches.is_empty() {
+                    Ok(format!(
+                        "{} test(s) {} at 2^{}",
+                        applicable, verdict, sample_exponent
+                    ))
+                } else {
+                    Err(format!(
+                        "{} of {} test(s) not {} at 2^{}: {}",
+                        mismatches.len(),
+                        applicable,
+                        verdict,
+                        sample_exponent,
+                        mismatches.join(", ")
+                    ))
+                }
+            }
+        }
+    }
+}
+
+/// Check every entry of `PUBLISHED_RESULTS`.
+/// Returns one line per entry and whether all of them were reproduced.
+pub fn cross_validate() -> (String, bool) {
+    let mut lines = vec![format!(
+        "{:<10}{:<16}{:<54}{}",
+        "Name", "Result", "Claim", "Source"
+    )];
+    let mut all_reproduced = true;
+    for published in PUBLISHED_RESULTS {
+        let (status, observed) = match published.check() {
+            Ok(observed) => ("reproduced", observed),
+            Err(observed) => {
+                all_reproduced = false;
+                ("NOT REPRODUCED", observed)
+            }
+        };
+        lines.push(format!(
+            "{:<10}{:<16}{:<54}{}",
+            published.rng_name, status, published.claim, published.source
+        ));
+        lines.push(format!("{:<26}{}", "", observed));
+    }
+    (lines.join("\n"), all_reproduced)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn published_results() {
+        let (table, all_reproduced) = cross_validate();
+        assert!(all_reproduced, "{}", table);
+        assert_eq!(table.lines().count(), 1 + 2 * PUBLISHED_RESULTS.len());
+    }
+}
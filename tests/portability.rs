@@ -0,0 +1,138 @@
+// Copyright 2025 N. Dornseif
+//
+// Dual-licensed under Apache 2.0 and MIT terms.
+
+//! Golden values that must be identical on every target,
+//! run them on 32-bit and big-endian targets with `cross test --target <target>`.
+
+use pearlacid::rngs;
+
+const GOLDEN_SEED: u64 = 0x0123456789abcdef;
+
+/// Digest of the first 1024 outputs of every reproducible generator in the registry.
+const REGISTRY_DIGESTS: [(&str, u64); 60] = [
+    ("Reference", 0xc772190c7e754d34),
+    ("OnlyOnes", 0xe0900bcb556d6106),
+    ("OnlyZero", 0x0000000000000000),
+    ("AlternatingBlocks", 0x74364bd263711c3c),
+    ("AlternatingBytes", 0x52955e00e39285a6),
+    ("AlternatingBits", 0x8f3584e2d0e131a1),
+    ("RijndaelStream", 0xe899d337474bdeba),
+    ("ChaCha8", 0x88dabe9ab64b7f90),
+    ("ChaCha12", 0x2faf3cd5994448d8),
+    ("ChaCha20", 0x3be811356074fc2e),
+    ("ISAAC", 0xebea98fd7f87f134),
+    ("RC4", 0x63230f137f61f009),
+    ("HashDRBG", 0xe728de4554a3f671),
+    ("HMACDRBG", 0x8bd03cbcfd814a28),
+    ("CTRDRBG", 0xc3de5faf89676808),
+    ("RapidHashRNG", 0xc16e1009b90bf39b),
+    ("RapidHashRNG2", 0x0e4205512f574898),
+    ("WyRand", 0x703f1253cfe53472),
+    ("JSF32", 0x01cbce5b3fd2351f),
+    ("JSF64", 0x8f6a8a1fc96e92c2),
+    ("gjrand64", 0x9747e027f5b065aa),
+    ("Lehmer64", 0x53dbc7b9c285c5ec),
+    ("JavaRandom", 0x13f80caa843122c3),
+    ("MINSTD", 0x026efb96bfefa0db),
+    ("MWC1", 0x861b57ccf8e50f29),
+    ("CMWC4096", 0xb17ca37d68a04da8),
+    ("KISS99", 0xc6e52daf36072695),
+    ("JKISS", 0xb671bb39e61e9a56),
+    ("KISS64", 0x3de799973b204ed5),
+    ("RANDU", 0x09c4159102788649),
+    ("RANDU-Native", 0x4beb2a6038c9306f),
+    ("MMIX-XOR-XORShift128", 0x59db93f698094260),
+    ("MMIX", 0xab006e378b1d2820),
+    ("UlsLcg512", 0x275da26bb54ca50b),
+    ("UlsLcg512H", 0xc550f8b34c8dcac2),
+    ("PCG32", 0x13648d64093f5f1b),
+    ("PCG64", 0x971d75cd01c06c13),
+    ("PCG64DXSM", 0x9acae7935815c126),
+    ("MT19937", 0x750f7d268d7ae814),
+    ("MT19937-64", 0xadebe50f78dda5dd),
+    ("WELL512a", 0x5be03b5d59ccfb28),
+    ("LFSR64-Fibonacci", 0xfd4863e62e2eea16),
+    ("LFSR64-Galois", 0x6f685388b3ffe098),
+    ("Philox4x32", 0x88d4d5bf1bbc0a39),
+    ("Threefry2x64", 0x0f809de528cbea47),
+    ("Threefry4x64", 0x69583e72f08aa666),
+    ("MSWS", 0xbafa67393e8784d9),
+    ("Squares32", 0x541b2b13049c14b1),
+    ("Squares64", 0xf39d7c35a0468884),
+    ("SipHash24", 0x1cb00669a5a03790),
+    ("SipHash13", 0xb60d81b05326d1f9),
+    ("SplitMix64", 0x00a7437427ae618e),
+    ("XORShift128", 0x055389cef40223d4),
+    ("Xorwow", 0x257ccefea43f2066),
+    ("Xorshift64", 0xe66bd83968239bf5),
+    ("Xoshiro256StarStar", 0xb6016196628a257c),
+    ("Xoshiro256PlusPlus", 0x984af560e94cc85f),
+    ("StreamNLARXu128", 0xfd8681ea74d196a0),
+    ("Tyche", 0x2f543530403c5625),
+    ("Tyche-i", 0x6ad62fd9f8ccf579),
+];
+
+fn output_digest(rng: &mut dyn rngs::RNG, count: usize) -> u64 {
+    (0..count).fold(0, |digest, _| {
+        (digest ^ rng.next())
+            .rotate_left(23)
+            .wrapping_mul(0x9e3779b97f4a7c15)
+    })
+}
+
+#[test]
+fn registry_golden_digests() {
+    for (name, _, factory) in rngs::registry() {
+        let mut rng = factory(GOLDEN_SEED);
+        if !rng.is_deterministic() {
+            continue;
+        }
+        let expected = REGISTRY_DIGESTS
+            .iter()
+            .find(|(known, _)| *known == name)
+            .unwrap_or_else(|| panic!("no golden digest for {}", name))
+            .1;
+        assert_eq!(output_digest(rng.as_mut(), 1024), expected, "{}", name);
+    }
+}
+
+#[cfg(feature = "suite")]
+#[test]
+fn battery_golden_p_values() {
+    use pearlacid::rngs::RNG;
+    use pearlacid::{rng_testing, stats};
+    // Tests with floating point heavy statistics, up to rounding differences of the math library.
+    const GOLDEN_P_VALUES: [(&str, Option<f64>); 12] = [
+        ("Bytes", Some(0.13313096853172712)),
+        ("Mono", Some(0.6937916883097353)),
+        ("Matrix", Some(0.25542511335171986)),
+        ("ApEn", Some(0.9359551446075028)),
+        ("RndExcV", Some(0.48024975622137167)),
+        ("Perm", Some(0.8534513109907511)),
+        ("OPSO", None),
+        ("Compress", Some(0.07010407613610421)),
+        ("KS", Some(0.8974235825384512)),
+        ("AD", Some(0.9844077593479712)),
+        ("Linear", Some(0.10234873240022188)),
+        ("WalkExt", Some(0.569984408094167)),
+    ];
+    let (test_data, _) =
+        stats::generate_test_data(&mut rngs::xorshift::WyRand::new(GOLDEN_SEED), 33 << 14);
+    for (name, expected) in GOLDEN_P_VALUES {
+        let test =
+            rng_testing::battery_test(name).unwrap_or_else(|| panic!("unknown test {}", name));
+        match (test(&test_data), expected) {
+            (Some(p), Some(expected)) => {
+                assert!(
+                    (p - expected).abs() < 1e-9,
+                    "{}: {} != {}",
+                    name,
+                    p,
+                    expected
+                )
+            }
+            (p, expected) => assert_eq!(p, expected, "{}", name),
+        }
+    }
+}